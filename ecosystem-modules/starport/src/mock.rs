@@ -122,6 +122,7 @@ pub type AdaptedBasicCurrency = module_currencies::BasicCurrencyAdapter<Runtime,
 parameter_types! {
 	pub const GetNativeCurrencyId: CurrencyId = ACALA;
 	pub Erc20HoldingAccount: H160 = H160::from_low_u64_be(1);
+	pub TreasuryAccount: AccountId = AccountId::new([255u8; 32]);
 }
 
 impl module_currencies::Config for Runtime {
@@ -130,6 +131,7 @@ impl module_currencies::Config for Runtime {
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type Erc20HoldingAccount = Erc20HoldingAccount;
+	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
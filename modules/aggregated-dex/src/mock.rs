@@ -122,6 +122,8 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<Admin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type CircuitBreaker = ();
+	type CircuitBreakerExemptAccounts = Nothing;
 }
 
 pub struct EnsurePoolAssetId;
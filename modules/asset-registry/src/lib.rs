@@ -32,16 +32,19 @@ use frame_support::{
 	transactional,
 };
 use frame_system::pallet_prelude::*;
-use module_support::{AssetIdMapping, BuyWeightRate, EVMBridge, Erc20InfoMapping, InvokeContext, Ratio};
+use module_support::{
+	AssetIdMapping, BuyWeightRate, EVMBridge, Erc20InfoMapping, InvokeContext, Ratio, VaultShareRegistry,
+};
 use primitives::{
 	currency::{
 		AssetIds, AssetMetadata, CurrencyIdType, DexShare, DexShareType, Erc20Id, ForeignAssetId, Lease,
-		StableAssetPoolId, TokenInfo,
+		StableAssetPoolId, TokenInfo, VaultId,
 	},
 	evm::{
 		is_system_contract, EvmAddress, H160_POSITION_CURRENCY_ID_TYPE, H160_POSITION_DEXSHARE_LEFT_FIELD,
 		H160_POSITION_DEXSHARE_LEFT_TYPE, H160_POSITION_DEXSHARE_RIGHT_FIELD, H160_POSITION_DEXSHARE_RIGHT_TYPE,
 		H160_POSITION_FOREIGN_ASSET, H160_POSITION_LIQUID_CROADLOAN, H160_POSITION_STABLE_ASSET, H160_POSITION_TOKEN,
+		H160_POSITION_VAULT_SHARE,
 	},
 	CurrencyId,
 };
@@ -524,6 +527,15 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn do_register_vault_share(vault_id: VaultId, metadata: &AssetMetadata<BalanceOf<T>>) -> DispatchResult {
+		AssetMetadatas::<T>::try_mutate(AssetIds::VaultId(vault_id), |maybe_asset_metadatas| -> DispatchResult {
+			ensure!(maybe_asset_metadatas.is_none(), Error::<T>::AssetIdExisted);
+
+			*maybe_asset_metadatas = Some(metadata.clone());
+			Ok(())
+		})
+	}
+
 	fn do_update_native_asset(currency_id: CurrencyId, metadata: &AssetMetadata<BalanceOf<T>>) -> DispatchResult {
 		AssetMetadatas::<T>::try_mutate(
 			AssetIds::NativeAssetId(currency_id),
@@ -537,6 +549,18 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> VaultShareRegistry<BalanceOf<T>> for Pallet<T> {
+	fn register_vault_share(vault_id: VaultId, metadata: &AssetMetadata<BalanceOf<T>>) -> DispatchResult {
+		Self::do_register_vault_share(vault_id, metadata)?;
+
+		Self::deposit_event(Event::<T>::AssetRegistered {
+			asset_id: AssetIds::VaultId(vault_id),
+			metadata: metadata.clone(),
+		});
+		Ok(())
+	}
+}
+
 pub struct AssetIdMaps<T>(sp_std::marker::PhantomData<T>);
 
 impl<T: Config> AssetIdMapping<ForeignAssetId, MultiLocation, AssetMetadata<BalanceOf<T>>> for AssetIdMaps<T> {
@@ -680,6 +704,7 @@ impl<T: Config> EvmErc20InfoMapping<T> {
 			DexShare::StableAssetPoolToken(stable_asset_pool_id) => {
 				AssetMetadatas::<T>::get(AssetIds::StableAssetId(stable_asset_pool_id)).map(|v| v.name)
 			}
+			DexShare::VaultShare(vault_id) => AssetMetadatas::<T>::get(AssetIds::VaultId(vault_id)).map(|v| v.name),
 		}
 	}
 
@@ -703,6 +728,7 @@ impl<T: Config> EvmErc20InfoMapping<T> {
 			DexShare::StableAssetPoolToken(stable_asset_pool_id) => {
 				AssetMetadatas::<T>::get(AssetIds::StableAssetId(stable_asset_pool_id)).map(|v| v.symbol)
 			}
+			DexShare::VaultShare(vault_id) => AssetMetadatas::<T>::get(AssetIds::VaultId(vault_id)).map(|v| v.symbol),
 		}
 	}
 
@@ -717,6 +743,7 @@ impl<T: Config> EvmErc20InfoMapping<T> {
 			DexShare::StableAssetPoolToken(stable_asset_pool_id) => {
 				AssetMetadatas::<T>::get(AssetIds::StableAssetId(stable_asset_pool_id)).map(|v| v.decimals)
 			}
+			DexShare::VaultShare(vault_id) => AssetMetadatas::<T>::get(AssetIds::VaultId(vault_id)).map(|v| v.decimals),
 		}
 	}
 
@@ -744,6 +771,10 @@ impl<T: Config> EvmErc20InfoMapping<T> {
 				let id = StableAssetPoolId::from_be_bytes(address[dex_share_field][..].try_into().ok()?);
 				Some(DexShare::StableAssetPoolToken(id))
 			}
+			DexShareType::VaultShare => {
+				let id = VaultId::from_be_bytes(address[dex_share_field][..].try_into().ok()?);
+				Some(DexShare::VaultShare(id))
+			}
 		}
 	}
 }
@@ -781,6 +812,7 @@ impl<T: Config> Erc20InfoMapping for EvmErc20InfoMapping<T> {
 			CurrencyId::ForeignAsset(foreign_asset_id) => {
 				AssetMetadatas::<T>::get(AssetIds::ForeignAssetId(foreign_asset_id)).map(|v| v.name)
 			}
+			CurrencyId::VaultShare(vault_id) => AssetMetadatas::<T>::get(AssetIds::VaultId(vault_id)).map(|v| v.name),
 		}?;
 
 		// More than 32 bytes will be truncated.
@@ -825,6 +857,7 @@ impl<T: Config> Erc20InfoMapping for EvmErc20InfoMapping<T> {
 			CurrencyId::ForeignAsset(foreign_asset_id) => {
 				AssetMetadatas::<T>::get(AssetIds::ForeignAssetId(foreign_asset_id)).map(|v| v.symbol)
 			}
+			CurrencyId::VaultShare(vault_id) => AssetMetadatas::<T>::get(AssetIds::VaultId(vault_id)).map(|v| v.symbol),
 		}?;
 
 		// More than 32 bytes will be truncated.
@@ -854,6 +887,9 @@ impl<T: Config> Erc20InfoMapping for EvmErc20InfoMapping<T> {
 			CurrencyId::ForeignAsset(foreign_asset_id) => {
 				AssetMetadatas::<T>::get(AssetIds::ForeignAssetId(foreign_asset_id)).map(|v| v.decimals)
 			}
+			CurrencyId::VaultShare(vault_id) => {
+				AssetMetadatas::<T>::get(AssetIds::VaultId(vault_id)).map(|v| v.decimals)
+			}
 		}
 	}
 
@@ -871,7 +907,8 @@ impl<T: Config> Erc20InfoMapping for EvmErc20InfoMapping<T> {
 					DexShare::Token(_)
 					| DexShare::LiquidCrowdloan(_)
 					| DexShare::ForeignAsset(_)
-					| DexShare::StableAssetPoolToken(_) => {}
+					| DexShare::StableAssetPoolToken(_)
+					| DexShare::VaultShare(_) => {}
 				};
 				match right {
 					DexShare::Erc20(address) => {
@@ -881,14 +918,16 @@ impl<T: Config> Erc20InfoMapping for EvmErc20InfoMapping<T> {
 					DexShare::Token(_)
 					| DexShare::LiquidCrowdloan(_)
 					| DexShare::ForeignAsset(_)
-					| DexShare::StableAssetPoolToken(_) => {}
+					| DexShare::StableAssetPoolToken(_)
+					| DexShare::VaultShare(_) => {}
 				};
 			}
 			CurrencyId::Token(_)
 			| CurrencyId::Erc20(_)
 			| CurrencyId::StableAssetPoolToken(_)
 			| CurrencyId::LiquidCrowdloan(_)
-			| CurrencyId::ForeignAsset(_) => {}
+			| CurrencyId::ForeignAsset(_)
+			| CurrencyId::VaultShare(_) => {}
 		};
 
 		EvmAddress::try_from(v).ok()
@@ -922,6 +961,10 @@ impl<T: Config> Erc20InfoMapping for EvmErc20InfoMapping<T> {
 				let id = ForeignAssetId::from_be_bytes(address[H160_POSITION_FOREIGN_ASSET].try_into().ok()?);
 				Some(CurrencyId::ForeignAsset(id))
 			}
+			CurrencyIdType::VaultShare => {
+				let id = VaultId::from_be_bytes(address[H160_POSITION_VAULT_SHARE].try_into().ok()?);
+				Some(CurrencyId::VaultShare(id))
+			}
 		};
 
 		// Make sure that every bit of the address is the same
@@ -99,6 +99,8 @@ impl module_evm::Config for Runtime {
 	type NewContractExtraBytes = ConstU32<1>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = ConstU128<10>;
+	type BaseFeeTargetGas = ConstU64<10_000_000>;
+	type MinBaseFeePerGas = ConstU128<0>;
 	type Event = Event;
 	type PrecompilesType = ();
 	type PrecompilesValue = ();
@@ -111,11 +113,14 @@ impl module_evm::Config for Runtime {
 	type PublicationFee = ConstU128<200>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type AbandonedContractTimeout = ConstU64<100>;
+	type CodeUpgradeDelay = ConstU64<10>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
 	type Task = ();
 	type IdleScheduler = ();
+	type CallFilter = ();
 	type WeightInfo = ();
 }
 
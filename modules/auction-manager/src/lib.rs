@@ -24,6 +24,8 @@
 //! business. Auction types include:
 //!   - `collateral auction`: sell collateral assets for getting stable currency to eliminate the
 //!     system's bad debit by auction
+//!   - `nft auction`: sell a liquidated NFT collateral in a forward auction, the surplus over the
+//!     target is refunded to the owner of the liquidated loan
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -31,7 +33,7 @@
 #![allow(clippy::unnecessary_unwrap)]
 
 use codec::MaxEncodedLen;
-use frame_support::{log, pallet_prelude::*, transactional};
+use frame_support::{log, pallet_prelude::*, traits::tokens::nonfungibles::Transfer, transactional};
 use frame_system::{
 	offchain::{SendTransactionTypes, SubmitTransaction},
 	pallet_prelude::*,
@@ -53,7 +55,10 @@ use sp_runtime::{
 	DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug,
 };
 use sp_std::prelude::*;
-use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, PriceProvider, Rate, SwapLimit};
+use support::{
+	AuctionManager, CDPTreasury, CDPTreasuryExtended, EmergencyShutdown, NFTAuctionManager, PriceProvider, Rate,
+	SwapLimit,
+};
 
 mod mock;
 mod tests;
@@ -126,6 +131,25 @@ impl<AccountId, BlockNumber> CollateralAuctionItem<AccountId, BlockNumber> {
 	}
 }
 
+/// Information of an NFT auction
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct NFTAuctionItem<AccountId, ClassId, TokenId, BlockNumber> {
+	/// Refund recipient for may receive the surplus of the winning bid
+	refund_recipient: AccountId,
+	/// The NFT for sale
+	token: (ClassId, TokenId),
+	/// Target sales amount of this auction, the winning bid over the target
+	/// is refunded to the refund recipient
+	#[codec(compact)]
+	target: Balance,
+	/// The first bid must be no less than the reserve price
+	#[codec(compact)]
+	reserve_price: Balance,
+	/// Auction start time
+	start_time: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -173,6 +197,19 @@ pub mod module {
 		/// Emergency shutdown.
 		type EmergencyShutdown: EmergencyShutdown;
 
+		/// The class id type of the NFT collaterals
+		type NFTClassId: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// The token id type of the NFT collaterals
+		type NFTTokenId: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// NFT to deliver the NFT in auction to the winner
+		type NFT: Transfer<Self::AccountId, CollectionId = Self::NFTClassId, ItemId = Self::NFTTokenId>;
+
+		/// The account receives the NFT of an NFT auction without bids
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -229,6 +266,29 @@ pub mod module {
 			target_stable_amount: Balance,
 			refund_recipient: T::AccountId,
 		},
+		/// NFT auction created.
+		NewNFTAuction {
+			auction_id: AuctionId,
+			class_id: T::NFTClassId,
+			token_id: T::NFTTokenId,
+			target_bid_price: Balance,
+			reserve_price: Balance,
+		},
+		/// NFT auction dealt.
+		NFTAuctionDealt {
+			auction_id: AuctionId,
+			class_id: T::NFTClassId,
+			token_id: T::NFTTokenId,
+			winner: T::AccountId,
+			payment_amount: Balance,
+		},
+		/// NFT auction ended without bids, the NFT is sent to the treasury.
+		NFTAuctionAborted {
+			auction_id: AuctionId,
+			class_id: T::NFTClassId,
+			token_id: T::NFTTokenId,
+			target_stable_amount: Balance,
+		},
 	}
 
 	/// Mapping from auction id to collateral auction info
@@ -254,6 +314,26 @@ pub mod module {
 	#[pallet::getter(fn total_target_in_auction)]
 	pub type TotalTargetInAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
 
+	/// Mapping from auction id to NFT auction info
+	///
+	/// NFTAuctions: map AuctionId => Option<NFTAuctionItem>
+	#[pallet::storage]
+	#[pallet::getter(fn nft_auctions)]
+	pub type NFTAuctions<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		AuctionId,
+		NFTAuctionItem<T::AccountId, T::NFTClassId, T::NFTTokenId, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Record of total target sales of all active NFT auctions
+	///
+	/// TotalTargetInNFTAuction: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_target_in_nft_auction)]
+	pub type TotalTargetInNFTAuction<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -312,7 +392,7 @@ pub mod module {
 							return InvalidTransaction::Stale.into();
 						}
 					}
-				} else {
+				} else if !NFTAuctions::<T>::contains_key(auction_id) {
 					return InvalidTransaction::Stale.into();
 				}
 
@@ -407,6 +487,14 @@ impl<T: Config> Pallet<T> {
 
 		if finished {
 			to_be_continue.clear();
+
+			// NFT auctions can always be cancelled and are removed once cancelled, the rest
+			// are cancelled in the next run.
+			let remaining_iterations = max_iterations.saturating_sub(iteration_count);
+			for nft_auction_id in <NFTAuctions<T>>::iter_keys().take(remaining_iterations as usize) {
+				Self::submit_cancel_auction_tx(nft_auction_id);
+				guard.extend_lock().map_err(|_| OffchainErr::OffchainLock)?;
+			}
 		} else {
 			to_be_continue.set(&iterator.last_raw_key());
 		}
@@ -473,6 +561,30 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn cancel_nft_auction(
+		id: AuctionId,
+		nft_auction: NFTAuctionItem<T::AccountId, T::NFTClassId, T::NFTTokenId, T::BlockNumber>,
+	) -> DispatchResult {
+		// if there's bid
+		if let Some((bidder, bid_price)) = Self::get_last_bid(id) {
+			// refund stable token to the bidder
+			T::CDPTreasury::issue_debit(&bidder, bid_price, false)?;
+
+			// decrease account ref of bidder
+			frame_system::Pallet::<T>::dec_consumers(&bidder);
+		}
+
+		// the NFT can be reprocessed by the treasury.
+		Self::try_transfer_nft(nft_auction.token, &T::TreasuryAccount::get());
+
+		// decrease account ref of refund recipient
+		frame_system::Pallet::<T>::dec_consumers(&nft_auction.refund_recipient);
+
+		TotalTargetInNFTAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(nft_auction.target));
+
+		Ok(())
+	}
+
 	/// Return `true` if price increment rate is greater than or equal to
 	/// minimum.
 	///
@@ -676,6 +788,115 @@ impl<T: Config> Pallet<T> {
 		TotalTargetInAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(collateral_auction.target));
 	}
 
+	/// Handles NFT auction new bid. Returns `Ok(new_auction_end_time)` if
+	/// bid accepted.
+	///
+	/// Ensured atomic.
+	#[transactional]
+	pub fn nft_auction_bid_handler(
+		now: T::BlockNumber,
+		id: AuctionId,
+		new_bid: (T::AccountId, Balance),
+		last_bid: Option<(T::AccountId, Balance)>,
+	) -> sp_std::result::Result<T::BlockNumber, DispatchError> {
+		let (new_bidder, new_bid_price) = new_bid;
+		ensure!(!new_bid_price.is_zero(), Error::<T>::InvalidBidPrice);
+
+		let nft_auction = Self::nft_auctions(id).ok_or(Error::<T>::AuctionNotExists)?;
+		let last_bid_price = last_bid.as_ref().map_or(Zero::zero(), |(_, price)| *price);
+
+		// NFT auction is always forward, ensure new bid price is valid
+		ensure!(new_bid_price >= nft_auction.reserve_price, Error::<T>::InvalidBidPrice);
+		ensure!(
+			Self::check_minimum_increment(
+				new_bid_price,
+				last_bid_price,
+				Zero::zero(),
+				Self::get_minimum_increment_size(now, nft_auction.start_time),
+			),
+			Error::<T>::InvalidBidPrice
+		);
+
+		let last_bidder = last_bid.as_ref().map(|(who, _)| who);
+		let mut payment = new_bid_price;
+
+		// if there's bid before, return stablecoin from new bidder to last bidder
+		if let Some(last_bidder) = last_bidder {
+			T::Currency::transfer(T::GetStableCurrencyId::get(), &new_bidder, last_bidder, last_bid_price)?;
+			payment = payment.checked_sub(last_bid_price).ok_or(Error::<T>::InvalidBidPrice)?;
+		}
+
+		// transfer remain payment from new bidder to CDP treasury
+		T::CDPTreasury::deposit_surplus(&new_bidder, payment)?;
+
+		Self::swap_bidders(&new_bidder, last_bidder);
+
+		Ok(now + Self::get_auction_time_to_close(now, nft_auction.start_time))
+	}
+
+	fn nft_auction_end_handler(
+		auction_id: AuctionId,
+		nft_auction: NFTAuctionItem<T::AccountId, T::NFTClassId, T::NFTTokenId, T::BlockNumber>,
+		last_bid: Option<(T::AccountId, Balance)>,
+	) {
+		let (class_id, token_id) = nft_auction.token;
+
+		if let Some((winner, bid_price)) = last_bid {
+			Self::try_transfer_nft(nft_auction.token, &winner);
+
+			// refund the surplus over target to the refund recipient from CDP treasury
+			let surplus = bid_price.saturating_sub(nft_auction.target);
+			if !surplus.is_zero() {
+				if let Err(e) = T::CDPTreasury::withdraw_surplus(&nft_auction.refund_recipient, surplus) {
+					log::warn!(
+						target: "auction-manager",
+						"withdraw_surplus: failed to withdraw {:?} from CDP treasury to {:?}: {:?}. \
+						This is unexpected but should be safe",
+						surplus, nft_auction.refund_recipient, e
+					);
+					debug_assert!(false);
+				}
+			}
+
+			Self::deposit_event(Event::NFTAuctionDealt {
+				auction_id,
+				class_id,
+				token_id,
+				winner,
+				payment_amount: bid_price,
+			});
+		} else {
+			// the NFT can be reprocessed by the treasury.
+			Self::try_transfer_nft(nft_auction.token, &T::TreasuryAccount::get());
+
+			Self::deposit_event(Event::NFTAuctionAborted {
+				auction_id,
+				class_id,
+				token_id,
+				target_stable_amount: nft_auction.target,
+			});
+		}
+
+		// decrement recipient account reference
+		frame_system::Pallet::<T>::dec_consumers(&nft_auction.refund_recipient);
+
+		TotalTargetInNFTAuction::<T>::mutate(|balance| *balance = balance.saturating_sub(nft_auction.target));
+	}
+
+	// Transfer the NFT in auction to `to`.
+	fn try_transfer_nft(token: (T::NFTClassId, T::NFTTokenId), to: &T::AccountId) {
+		// If failed, the NFT is left in the custody of the loans. It can be fixed by treasury council.
+		if let Err(e) = T::NFT::transfer(&token.0, &token.1, to) {
+			log::warn!(
+				target: "auction-manager",
+				"transfer NFT: failed to transfer {:?} to {:?}: {:?}. \
+				This is unexpected but should be safe",
+				token, to, e
+			);
+			debug_assert!(false);
+		}
+	}
+
 	// Refund stable to the last_bidder.
 	fn try_refund_bid(
 		collateral_auction: &CollateralAuctionItem<T::AccountId, T::BlockNumber>,
@@ -742,7 +963,11 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId>
 		new_bid: (T::AccountId, Balance),
 		last_bid: Option<(T::AccountId, Balance)>,
 	) -> OnNewBidResult<T::BlockNumber> {
-		let bid_result = Self::collateral_auction_bid_handler(now, id, new_bid, last_bid);
+		let bid_result = if NFTAuctions::<T>::contains_key(id) {
+			Self::nft_auction_bid_handler(now, id, new_bid, last_bid)
+		} else {
+			Self::collateral_auction_bid_handler(now, id, new_bid, last_bid)
+		};
 
 		match bid_result {
 			Ok(new_auction_end_time) => OnNewBidResult {
@@ -759,6 +984,8 @@ impl<T: Config> AuctionHandler<T::AccountId, Balance, T::BlockNumber, AuctionId>
 	fn on_auction_ended(id: AuctionId, winner: Option<(T::AccountId, Balance)>) {
 		if let Some(collateral_auction) = <CollateralAuctions<T>>::take(id) {
 			Self::collateral_auction_end_handler(id, collateral_auction, winner.clone());
+		} else if let Some(nft_auction) = <NFTAuctions<T>>::take(id) {
+			Self::nft_auction_end_handler(id, nft_auction, winner.clone());
 		}
 
 		if let Some((bidder, _)) = &winner {
@@ -833,8 +1060,12 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 	}
 
 	fn cancel_auction(id: Self::AuctionId) -> DispatchResult {
-		let collateral_auction = <CollateralAuctions<T>>::take(id).ok_or(Error::<T>::AuctionNotExists)?;
-		Self::cancel_collateral_auction(id, collateral_auction)?;
+		if let Some(nft_auction) = <NFTAuctions<T>>::take(id) {
+			Self::cancel_nft_auction(id, nft_auction)?;
+		} else {
+			let collateral_auction = <CollateralAuctions<T>>::take(id).ok_or(Error::<T>::AuctionNotExists)?;
+			Self::cancel_collateral_auction(id, collateral_auction)?;
+		}
 		T::Auction::remove_auction(id);
 		Ok(())
 	}
@@ -847,3 +1078,62 @@ impl<T: Config> AuctionManager<T::AccountId> for Pallet<T> {
 		Self::total_target_in_auction()
 	}
 }
+
+impl<T: Config> NFTAuctionManager<T::AccountId> for Pallet<T> {
+	type ClassId = T::NFTClassId;
+	type TokenId = T::NFTTokenId;
+	type Balance = Balance;
+
+	fn new_nft_auction(
+		refund_recipient: &T::AccountId,
+		token: (Self::ClassId, Self::TokenId),
+		target: Self::Balance,
+		reserve_price: Self::Balance,
+	) -> DispatchResult {
+		TotalTargetInNFTAuction::<T>::try_mutate(|total| -> DispatchResult {
+			*total = total.checked_add(target).ok_or(Error::<T>::InvalidAmount)?;
+			Ok(())
+		})?;
+
+		let start_time = <frame_system::Pallet<T>>::block_number();
+		let end_time = start_time.saturating_add(T::AuctionDurationSoftCap::get());
+		let auction_id = T::Auction::new_auction(start_time, Some(end_time))?;
+
+		<NFTAuctions<T>>::insert(
+			auction_id,
+			NFTAuctionItem {
+				refund_recipient: refund_recipient.clone(),
+				token,
+				target,
+				reserve_price,
+				start_time,
+			},
+		);
+
+		// increment recipient account reference
+		if frame_system::Pallet::<T>::inc_consumers(refund_recipient).is_err() {
+			// No providers for the locks. This is impossible under normal circumstances
+			// since the funds that are under the lock will themselves be stored in the
+			// account and therefore will need a reference.
+			log::warn!(
+				target: "auction-manager",
+				"Attempt to `inc_consumers` for {:?} failed. \
+				This is unexpected but should be safe.",
+				refund_recipient.clone()
+			);
+		}
+
+		Self::deposit_event(Event::NewNFTAuction {
+			auction_id,
+			class_id: token.0,
+			token_id: token.1,
+			target_bid_price: target,
+			reserve_price,
+		});
+		Ok(())
+	}
+
+	fn get_total_target_in_nft_auction() -> Self::Balance {
+		Self::total_target_in_nft_auction()
+	}
+}
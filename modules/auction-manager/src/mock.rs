@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{
 	construct_runtime, ord_parameter_types, parameter_types,
-	traits::{ConstU32, ConstU64, Everything, Nothing},
+	traits::{tokens::nonfungibles::Inspect, ConstU32, ConstU64, Everything, Nothing},
 	PalletId,
 };
 use frame_system::EnsureSignedBy;
@@ -34,7 +34,7 @@ use sp_runtime::{
 	testing::{Header, TestXt},
 	traits::{AccountIdConversion, IdentityLookup, One as OneT},
 };
-use sp_std::cell::RefCell;
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
 pub use support::Price;
 use support::{mocks::MockStableAsset, SpecificJointsSwap};
 
@@ -182,6 +182,8 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type CircuitBreaker = ();
+	type CircuitBreakerExemptAccounts = Nothing;
 }
 
 thread_local! {
@@ -199,6 +201,40 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+pub type ClassId = u32;
+pub type TokenId = u64;
+
+thread_local! {
+	static NFT_OWNERS: RefCell<BTreeMap<(ClassId, TokenId), AccountId>> = RefCell::new(BTreeMap::new());
+}
+
+pub struct MockNFT;
+impl MockNFT {
+	pub fn mint(token: (ClassId, TokenId), owner: AccountId) {
+		NFT_OWNERS.with(|v| v.borrow_mut().insert(token, owner));
+	}
+}
+impl Inspect<AccountId> for MockNFT {
+	type ItemId = TokenId;
+	type CollectionId = ClassId;
+
+	fn owner(class: &ClassId, instance: &TokenId) -> Option<AccountId> {
+		NFT_OWNERS.with(|v| v.borrow().get(&(*class, *instance)).cloned())
+	}
+}
+impl Transfer<AccountId> for MockNFT {
+	fn transfer(class: &ClassId, instance: &TokenId, destination: &AccountId) -> DispatchResult {
+		NFT_OWNERS.with(|v| {
+			let mut owners = v.borrow_mut();
+			let owner = owners
+				.get_mut(&(*class, *instance))
+				.ok_or(DispatchError::Other("token not found"))?;
+			*owner = *destination;
+			Ok(())
+		})
+	}
+}
+
 parameter_types! {
 	pub MinimumIncrementSize: Rate = Rate::saturating_from_rational(1, 20);
 }
@@ -215,6 +251,10 @@ impl Config for Runtime {
 	type PriceSource = MockPriceSource;
 	type UnsignedPriority = ConstU64<1048576>; // 1 << 20
 	type EmergencyShutdown = MockEmergencyShutdown;
+	type NFTClassId = ClassId;
+	type NFTTokenId = TokenId;
+	type NFT = MockNFT;
+	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 }
 
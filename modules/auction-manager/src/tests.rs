@@ -21,7 +21,7 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::tokens::nonfungibles::Inspect};
 use mock::{Call as MockCall, Event, *};
 use sp_core::offchain::{testing, DbExternalities, OffchainDbExt, OffchainWorkerExt, StorageKind, TransactionPoolExt};
 use sp_io::offchain;
@@ -720,3 +720,160 @@ fn offchain_default_max_iterator_works() {
 		assert_eq!(pool_state.write().transactions.len(), 1001);
 	});
 }
+
+#[test]
+fn new_nft_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let ref_count_0 = System::consumers(&ALICE);
+
+		assert_ok!(AuctionManagerModule::new_nft_auction(&ALICE, (0, 0), 100, 50));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NewNFTAuction {
+			auction_id: 0,
+			class_id: 0,
+			token_id: 0,
+			target_bid_price: 100,
+			reserve_price: 50,
+		}));
+		assert_eq!(
+			AuctionModule::auctions(0),
+			Some(orml_traits::AuctionInfo {
+				bid: None,
+				start: 1,
+				end: Some(2001)
+			})
+		);
+		assert_eq!(AuctionManagerModule::total_target_in_nft_auction(), 100);
+		assert_eq!(AuctionManagerModule::total_target_in_auction(), 0);
+		assert_eq!(System::consumers(&ALICE), ref_count_0 + 1);
+
+		assert_noop!(
+			AuctionManagerModule::new_nft_auction(&ALICE, (0, 1), Balance::max_value(), 50),
+			Error::<Runtime>::InvalidAmount,
+		);
+	});
+}
+
+#[test]
+fn nft_auction_bid_handler_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			AuctionManagerModule::nft_auction_bid_handler(1, 0, (BOB, 4), None),
+			Error::<Runtime>::AuctionNotExists,
+		);
+
+		assert_ok!(AuctionManagerModule::new_nft_auction(&ALICE, (0, 0), 100, 50));
+		assert_noop!(
+			AuctionManagerModule::nft_auction_bid_handler(1, 0, (BOB, 0), None),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		// below the reserve price
+		assert_noop!(
+			AuctionManagerModule::nft_auction_bid_handler(1, 0, (BOB, 49), None),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+
+		assert_eq!(
+			AuctionManagerModule::nft_auction_bid_handler(1, 0, (BOB, 80), None).unwrap(),
+			101
+		);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 80);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 920);
+
+		// the increment must be no less than 5% of the last bid
+		assert_noop!(
+			AuctionManagerModule::nft_auction_bid_handler(2, 0, (CAROL, 83), Some((BOB, 80))),
+			Error::<Runtime>::InvalidBidPrice,
+		);
+		assert_eq!(
+			AuctionManagerModule::nft_auction_bid_handler(2, 0, (CAROL, 120), Some((BOB, 80))).unwrap(),
+			102
+		);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 120);
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(Tokens::free_balance(AUSD, &CAROL), 880);
+	});
+}
+
+#[test]
+fn nft_auction_dealt() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		MockNFT::mint((0, 0), CDPTreasuryModule::account_id());
+		assert_ok!(AuctionManagerModule::new_nft_auction(&ALICE, (0, 0), 100, 50));
+		assert_eq!(
+			AuctionManagerModule::nft_auction_bid_handler(1, 0, (BOB, 120), None).unwrap(),
+			101
+		);
+		let alice_ref_count = System::consumers(&ALICE);
+		let bob_ref_count = System::consumers(&BOB);
+
+		AuctionManagerModule::on_auction_ended(0, Some((BOB, 120)));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NFTAuctionDealt {
+			auction_id: 0,
+			class_id: 0,
+			token_id: 0,
+			winner: BOB,
+			payment_amount: 120,
+		}));
+
+		assert_eq!(MockNFT::owner(&0, &0), Some(BOB));
+		// the surplus over target is refunded to ALICE
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 100);
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1020);
+		assert_eq!(AuctionManagerModule::nft_auctions(0), None);
+		assert_eq!(AuctionManagerModule::total_target_in_nft_auction(), 0);
+		assert_eq!(System::consumers(&ALICE), alice_ref_count - 1);
+		assert_eq!(System::consumers(&BOB), bob_ref_count - 1);
+	});
+}
+
+#[test]
+fn nft_auction_without_bid_aborted() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		MockNFT::mint((0, 0), CDPTreasuryModule::account_id());
+		assert_ok!(AuctionManagerModule::new_nft_auction(&ALICE, (0, 0), 100, 50));
+
+		AuctionManagerModule::on_auction_ended(0, None);
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::NFTAuctionAborted {
+			auction_id: 0,
+			class_id: 0,
+			token_id: 0,
+			target_stable_amount: 100,
+		}));
+		assert_eq!(MockNFT::owner(&0, &0), Some(TreasuryAccount::get()));
+		assert_eq!(AuctionManagerModule::nft_auctions(0), None);
+		assert_eq!(AuctionManagerModule::total_target_in_nft_auction(), 0);
+	});
+}
+
+#[test]
+fn cancel_nft_auction_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		MockNFT::mint((0, 0), CDPTreasuryModule::account_id());
+		assert_ok!(AuctionManagerModule::new_nft_auction(&ALICE, (0, 0), 100, 50));
+		assert_ok!(AuctionModule::bid(Origin::signed(BOB), 0, 80));
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 920);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 80);
+
+		let alice_ref_count_0 = System::consumers(&ALICE);
+		let bob_ref_count_0 = System::consumers(&BOB);
+
+		mock_shutdown();
+		assert_ok!(AuctionManagerModule::cancel(Origin::none(), 0));
+		System::assert_last_event(Event::AuctionManagerModule(crate::Event::CancelAuction {
+			auction_id: 0,
+		}));
+
+		assert_eq!(Tokens::free_balance(AUSD, &BOB), 1000);
+		assert_eq!(CDPTreasuryModule::debit_pool(), 80);
+		assert_eq!(MockNFT::owner(&0, &0), Some(TreasuryAccount::get()));
+		assert_eq!(AuctionManagerModule::nft_auctions(0), None);
+		assert_eq!(AuctionManagerModule::total_target_in_nft_auction(), 0);
+		assert!(AuctionModule::auction_info(0).is_none());
+		assert_eq!(System::consumers(&ALICE), alice_ref_count_0 - 1);
+		assert_eq!(System::consumers(&BOB), bob_ref_count_0 - 1);
+	});
+}
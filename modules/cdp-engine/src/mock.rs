@@ -266,6 +266,8 @@ impl dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type CircuitBreaker = ();
+	type CircuitBreakerExemptAccounts = Nothing;
 }
 
 impl pallet_timestamp::Config for Runtime {
@@ -281,6 +283,10 @@ impl evm_accounts::Config for Runtime {
 	type ChainId = ();
 	type AddressMapping = evm_accounts::EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type Call = Call;
+	type Currencies = Currencies;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type EvmCallTarget = ();
 	type WeightInfo = ();
 }
 
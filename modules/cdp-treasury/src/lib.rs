@@ -34,13 +34,13 @@ use frame_system::pallet_prelude::*;
 use nutsfinance_stable_asset::traits::StableAsset;
 use nutsfinance_stable_asset::RedeemProportionResult;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
-use primitives::{Balance, CurrencyId};
+use primitives::{task::TaskResult, Balance, CurrencyId};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, Zero},
+	traits::{AccountIdConversion, One, Saturating, Zero},
 	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber,
 };
-use sp_std::prelude::*;
-use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, Ratio, Swap, SwapLimit};
+use sp_std::{marker::PhantomData, prelude::*};
+use support::{AuctionManager, CDPTreasury, CDPTreasuryExtended, DEXManager, DispatchableTask, Ratio, Swap, SwapLimit};
 
 mod mock;
 mod tests;
@@ -540,3 +540,44 @@ impl<T: Config> CDPTreasuryExtended<T::AccountId> for Pallet<T> {
 		T::MaxAuctionsCount::get()
 	}
 }
+
+#[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub enum CdpTreasuryTask<T: Config> {
+	/// Transfer the surplus exceeding `buffer` to the treasury account.
+	SweepSurplus { buffer: Balance },
+	#[doc(hidden)]
+	#[codec(skip)]
+	__Ignore(PhantomData<T>),
+}
+
+impl<T: Config> DispatchableTask for CdpTreasuryTask<T> {
+	fn dispatch(self, _weight: Weight) -> TaskResult {
+		let used_weight = self.max_weight();
+		let result = match self {
+			CdpTreasuryTask::SweepSurplus { buffer } => {
+				let amount = Pallet::<T>::surplus_pool().saturating_sub(buffer);
+				if amount.is_zero() {
+					Ok(())
+				} else {
+					T::Currency::transfer(
+						T::GetStableCurrencyId::get(),
+						&Pallet::<T>::account_id(),
+						&T::TreasuryAccount::get(),
+						amount,
+					)
+				}
+			}
+			CdpTreasuryTask::__Ignore(_) => Ok(()),
+		};
+		TaskResult {
+			result,
+			used_weight,
+			finished: true,
+		}
+	}
+
+	fn max_weight(&self) -> Weight {
+		<T as Config>::WeightInfo::extract_surplus_to_treasury()
+	}
+}
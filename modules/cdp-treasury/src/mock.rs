@@ -153,6 +153,8 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<One, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type CircuitBreaker = ();
+	type CircuitBreakerExemptAccounts = Nothing;
 }
 
 thread_local! {
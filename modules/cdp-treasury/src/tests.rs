@@ -512,6 +512,26 @@ fn extract_surplus_to_treasury_work() {
 	});
 }
 
+#[test]
+fn sweep_surplus_task_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CDPTreasuryModule::on_system_surplus(1000));
+
+		let task = CdpTreasuryTask::<Runtime>::SweepSurplus { buffer: 300 };
+		let result = task.clone().dispatch(task.max_weight());
+		assert_eq!(result.result, Ok(()));
+		assert!(result.finished);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 300);
+		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 700);
+
+		// nothing to sweep
+		let result = task.clone().dispatch(task.max_weight());
+		assert_eq!(result.result, Ok(()));
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 300);
+		assert_eq!(Currencies::free_balance(AUSD, &TreasuryAccount::get()), 700);
+	});
+}
+
 #[test]
 fn auction_collateral_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -24,11 +24,14 @@
 
 use codec::Codec;
 use frame_support::{
+	log,
 	pallet_prelude::*,
+	parameter_types,
 	traits::{
 		tokens::{fungible, fungibles, DepositConsequence, WithdrawConsequence},
 		BalanceStatus as Status, Currency as PalletCurrency, ExistenceRequirement, Get, Imbalance,
-		LockableCurrency as PalletLockableCurrency, ReservableCurrency as PalletReservableCurrency, WithdrawReasons,
+		LockableCurrency as PalletLockableCurrency, NamedReservableCurrency as PalletNamedReservableCurrency,
+		ReservableCurrency as PalletReservableCurrency, WithdrawReasons,
 	},
 	transactional,
 };
@@ -37,10 +40,10 @@ use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
 	currency::TransferAll,
 	BalanceStatus, BasicCurrency, BasicCurrencyExtended, BasicLockableCurrency, BasicReservableCurrency,
-	LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency, OnDust,
+	LockIdentifier, MultiCurrency, MultiCurrencyExtended, MultiLockableCurrency, MultiReservableCurrency,
+	NamedBasicReservableCurrency, NamedMultiReservableCurrency, OnDust,
 };
-use primitives::{evm::EvmAddress, CurrencyId};
-use sp_io::hashing::blake2_256;
+use primitives::{evm::EvmAddress, CurrencyId, ReserveIdentifier};
 use sp_runtime::{
 	traits::{CheckedAdd, CheckedSub, Convert, MaybeSerializeDeserialize, Saturating, StaticLookup, Zero},
 	DispatchError, DispatchResult,
@@ -48,6 +51,7 @@ use sp_runtime::{
 use sp_std::{fmt::Debug, marker, result, vec::Vec};
 use support::{evm::limits::erc20, AddressMapping, EVMBridge, InvokeContext};
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -59,6 +63,11 @@ type AmountOf<T> =
 	<<T as Config>::MultiCurrency as MultiCurrencyExtended<<T as frame_system::Config>::AccountId>>::Amount;
 type BalanceOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<<T as frame_system::Config>::AccountId>>::Balance;
 
+parameter_types! {
+	/// Max number of locks of an account for an ERC20 token.
+	pub const MaxErc20Locks: u32 = 50;
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -90,10 +99,15 @@ pub mod module {
 		#[pallet::constant]
 		type GetNativeCurrencyId: Get<CurrencyId>;
 
-		/// Used as temporary account for ERC20 token `withdraw` and `deposit`.
+		/// Used as temporary account for ERC20 token `withdraw` and `deposit`, and escrow account
+		/// for the reserved and locked ERC20 token.
 		#[pallet::constant]
 		type Erc20HoldingAccount: Get<EvmAddress>;
 
+		/// The receiver of the slashed reserved ERC20 token, which can't be burned.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
 		/// Weight information for extrinsics in this module.
 		type WeightInfo: WeightInfo;
 
@@ -125,6 +139,8 @@ pub mod module {
 		RealOriginNotFound,
 		/// Deposit result is not expected
 		DepositFailed,
+		/// Number of locks exceeded MaxErc20Locks
+		MaxLocksExceeded,
 	}
 
 	#[pallet::event]
@@ -157,7 +173,42 @@ pub mod module {
 		},
 	}
 
+	/// The reserved ERC20 token of accounts, escrowed in `Erc20HoldingAccount`.
+	///
+	/// Erc20Reserves: double_map EvmAddress, AccountId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_reserves)]
+	pub type Erc20Reserves<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EvmAddress, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	/// The locks of ERC20 token of accounts, the max locked amount is escrowed in
+	/// `Erc20HoldingAccount`.
+	///
+	/// Erc20Locks: double_map EvmAddress, AccountId => Vec<(LockIdentifier, Balance)>
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_locks)]
+	pub type Erc20Locks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EvmAddress,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<(LockIdentifier, BalanceOf<T>), MaxErc20Locks>,
+		ValueQuery,
+	>;
+
+	/// The total amount of ERC20 token escrowed in `Erc20HoldingAccount` for reserves and locks,
+	/// which can't be used by `deposit`.
+	///
+	/// Erc20Escrowed: map EvmAddress => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn erc20_escrowed)]
+	pub type Erc20Escrowed<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, BalanceOf<T>, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -274,15 +325,7 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	fn total_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		match currency_id {
 			CurrencyId::Erc20(contract) => {
-				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					let context = InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					};
-					return T::EVMBridge::balance_of(context, address).unwrap_or_default();
-				}
-				Default::default()
+				Self::free_balance(currency_id, who).saturating_add(Self::erc20_reserves(contract, who))
 			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::total_balance(who),
 			_ => T::MultiCurrency::total_balance(currency_id, who),
@@ -291,16 +334,9 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 
 	fn free_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		match currency_id {
+			// the locked token is escrowed, but still a part of free balance
 			CurrencyId::Erc20(contract) => {
-				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					let context = InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					};
-					return T::EVMBridge::balance_of(context, address).unwrap_or_default();
-				}
-				Default::default()
+				Self::erc20_balance(contract, who).saturating_add(Self::erc20_locked(contract, who))
 			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::free_balance(who),
 			_ => T::MultiCurrency::free_balance(currency_id, who),
@@ -314,17 +350,11 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 					return Ok(());
 				}
 
-				let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
-				let balance = T::EVMBridge::balance_of(
-					InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					},
-					address,
-				)
-				.unwrap_or_default();
-				ensure!(balance >= amount, Error::<T>::BalanceTooLow);
+				ensure!(
+					T::AddressMapping::get_evm_address(who).is_some(),
+					Error::<T>::EvmAccountNotFound
+				);
+				ensure!(Self::erc20_balance(contract, who) >= amount, Error::<T>::BalanceTooLow);
 				Ok(())
 			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::ensure_can_withdraw(who, amount),
@@ -382,8 +412,11 @@ impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 				// token to charge storage fee.
 				let sender = T::Erc20HoldingAccount::get();
 				let from = T::AddressMapping::get_account_id(&sender);
+				let holding_balance = Self::free_balance(currency_id, &from);
+				ensure!(!holding_balance.is_zero(), Error::<T>::DepositFailed);
+				// the reserved and locked token escrowed in holding account can't be deposited.
 				ensure!(
-					!Self::free_balance(currency_id, &from).is_zero(),
+					holding_balance.saturating_sub(Self::erc20_escrowed(contract)) >= amount,
 					Error::<T>::DepositFailed
 				);
 				let receiver = T::AddressMapping::get_or_create_evm_address(who);
@@ -475,10 +508,15 @@ impl<T: Config> MultiCurrencyExtended<T::AccountId> for Pallet<T> {
 	fn update_balance(currency_id: Self::CurrencyId, who: &T::AccountId, by_amount: Self::Amount) -> DispatchResult {
 		match currency_id {
 			CurrencyId::Erc20(_) => {
-				if by_amount.is_zero() {
-					Ok(())
+				// ERC20 token can't be minted or burned, deposit from or withdraw to the holding account.
+				let by_balance: BalanceOf<T> = by_amount
+					.abs()
+					.try_into()
+					.map_err(|_| Error::<T>::AmountIntoBalanceFailed)?;
+				if by_amount.is_positive() {
+					Self::deposit(currency_id, who, by_balance)
 				} else {
-					Err(Error::<T>::Erc20InvalidOperation.into())
+					Self::withdraw(currency_id, who, by_balance)
 				}
 			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::update_balance(who, by_amount),
@@ -487,6 +525,9 @@ impl<T: Config> MultiCurrencyExtended<T::AccountId> for Pallet<T> {
 	}
 }
 
+/// ERC20 locks escrow the max locked amount in `Erc20HoldingAccount`, the token of the contract
+/// can't be frozen in place. Unlike the locks of the other currencies, locking more ERC20 token
+/// than the free balance fails, and the locked token isn't counted in the free balance.
 impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 	type Moment = T::BlockNumber;
 
@@ -497,7 +538,20 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => {
+				if amount.is_zero() {
+					return Ok(());
+				}
+				let mut locks = Self::erc20_locks(contract, who);
+				if let Some((_, locked)) = locks.iter_mut().find(|(id, _)| *id == lock_id) {
+					*locked = amount;
+				} else {
+					locks
+						.try_push((lock_id, amount))
+						.map_err(|_| Error::<T>::MaxLocksExceeded)?;
+				}
+				Self::update_erc20_locks(contract, who, locks)
+			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::set_lock(lock_id, who, amount),
 			_ => T::MultiCurrency::set_lock(lock_id, currency_id, who, amount),
 		}
@@ -510,7 +564,20 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 		amount: Self::Balance,
 	) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => {
+				if amount.is_zero() {
+					return Ok(());
+				}
+				let mut locks = Self::erc20_locks(contract, who);
+				if let Some((_, locked)) = locks.iter_mut().find(|(id, _)| *id == lock_id) {
+					*locked = (*locked).max(amount);
+				} else {
+					locks
+						.try_push((lock_id, amount))
+						.map_err(|_| Error::<T>::MaxLocksExceeded)?;
+				}
+				Self::update_erc20_locks(contract, who, locks)
+			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::extend_lock(lock_id, who, amount),
 			_ => T::MultiCurrency::extend_lock(lock_id, currency_id, who, amount),
 		}
@@ -518,7 +585,11 @@ impl<T: Config> MultiLockableCurrency<T::AccountId> for Pallet<T> {
 
 	fn remove_lock(lock_id: LockIdentifier, currency_id: Self::CurrencyId, who: &T::AccountId) -> DispatchResult {
 		match currency_id {
-			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			CurrencyId::Erc20(contract) => {
+				let mut locks = Self::erc20_locks(contract, who);
+				locks.retain(|(id, _)| *id != lock_id);
+				Self::update_erc20_locks(contract, who, locks)
+			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::remove_lock(lock_id, who),
 			_ => T::MultiCurrency::remove_lock(lock_id, currency_id, who),
 		}
@@ -536,7 +607,18 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 
 	fn slash_reserved(currency_id: Self::CurrencyId, who: &T::AccountId, value: Self::Balance) -> Self::Balance {
 		match currency_id {
-			CurrencyId::Erc20(_) => value,
+			CurrencyId::Erc20(contract) => {
+				Self::erc20_migrate_legacy_reserve(contract, who);
+				// the slashed token is transferred out of the holding account to the treasury.
+				let actual = Self::erc20_reserves(contract, who).min(value);
+				match Self::erc20_release(contract, &T::TreasuryAccount::get(), actual) {
+					Ok(_) => {
+						Erc20Reserves::<T>::mutate(contract, who, |reserved| *reserved -= actual);
+						value - actual
+					}
+					Err(_) => value,
+				}
+			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::slash_reserved(who, value),
 			_ => T::MultiCurrency::slash_reserved(currency_id, who, value),
 		}
@@ -545,18 +627,7 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 	fn reserved_balance(currency_id: Self::CurrencyId, who: &T::AccountId) -> Self::Balance {
 		match currency_id {
 			CurrencyId::Erc20(contract) => {
-				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					return T::EVMBridge::balance_of(
-						InvokeContext {
-							contract,
-							sender: Default::default(),
-							origin: Default::default(),
-						},
-						reserve_address(address),
-					)
-					.unwrap_or_default();
-				}
-				Default::default()
+				Self::erc20_reserves(contract, who).saturating_add(Self::erc20_legacy_reserved(contract, who))
 			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::reserved_balance(who),
 			_ => T::MultiCurrency::reserved_balance(currency_id, who),
@@ -569,16 +640,9 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 				if value.is_zero() {
 					return Ok(());
 				}
-				let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
-				T::EVMBridge::transfer(
-					InvokeContext {
-						contract,
-						sender: address,
-						origin: address,
-					},
-					reserve_address(address),
-					value,
-				)
+				Self::erc20_escrow(contract, who, value)?;
+				Erc20Reserves::<T>::mutate(contract, who, |reserved| *reserved = reserved.saturating_add(value));
+				Ok(())
 			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::reserve(who, value),
 			_ => T::MultiCurrency::reserve(currency_id, who, value),
@@ -591,32 +655,14 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 				if value.is_zero() {
 					return value;
 				}
-				if let Some(address) = T::AddressMapping::get_evm_address(who) {
-					let sender = reserve_address(address);
-					let reserved_balance = T::EVMBridge::balance_of(
-						InvokeContext {
-							contract,
-							sender: Default::default(),
-							origin: Default::default(),
-						},
-						sender,
-					)
-					.unwrap_or_default();
-					let actual = reserved_balance.min(value);
-					match T::EVMBridge::transfer(
-						InvokeContext {
-							contract,
-							sender,
-							origin: address,
-						},
-						address,
-						actual,
-					) {
-						Ok(_) => value - actual,
-						Err(_) => value,
+				Self::erc20_migrate_legacy_reserve(contract, who);
+				let actual = Self::erc20_reserves(contract, who).min(value);
+				match Self::erc20_release(contract, who, actual) {
+					Ok(_) => {
+						Erc20Reserves::<T>::mutate(contract, who, |reserved| *reserved -= actual);
+						value - actual
 					}
-				} else {
-					value
+					Err(_) => value,
 				}
 			}
 			id if id == T::GetNativeCurrencyId::get() => T::NativeCurrency::unreserve(who, value),
@@ -645,43 +691,16 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 					};
 				}
 
-				let slashed_address =
-					T::AddressMapping::get_evm_address(slashed).ok_or(Error::<T>::EvmAccountNotFound)?;
-				let beneficiary_address = T::AddressMapping::get_or_create_evm_address(beneficiary);
-
-				let slashed_reserve_address = reserve_address(slashed_address);
-				let beneficiary_reserve_address = reserve_address(beneficiary_address);
-
-				let slashed_reserved_balance = T::EVMBridge::balance_of(
-					InvokeContext {
-						contract,
-						sender: Default::default(),
-						origin: Default::default(),
-					},
-					slashed_reserve_address,
-				)
-				.unwrap_or_default();
-				let actual = slashed_reserved_balance.min(value);
+				Self::erc20_migrate_legacy_reserve(contract, slashed);
+				let actual = Self::erc20_reserves(contract, slashed).min(value);
 				match status {
-					BalanceStatus::Free => T::EVMBridge::transfer(
-						InvokeContext {
-							contract,
-							sender: slashed_reserve_address,
-							origin: slashed_address,
-						},
-						beneficiary_address,
-						actual,
-					),
-					BalanceStatus::Reserved => T::EVMBridge::transfer(
-						InvokeContext {
-							contract,
-							sender: slashed_reserve_address,
-							origin: slashed_address,
-						},
-						beneficiary_reserve_address,
-						actual,
-					),
-				}?;
+					BalanceStatus::Free => Self::erc20_release(contract, beneficiary, actual)?,
+					// the token is kept in the holding account, only move the reserves.
+					BalanceStatus::Reserved => Erc20Reserves::<T>::mutate(contract, beneficiary, |reserved| {
+						*reserved = reserved.saturating_add(actual)
+					}),
+				}
+				Erc20Reserves::<T>::mutate(contract, slashed, |reserved| *reserved -= actual);
 				Ok(value - actual)
 			}
 			id if id == T::GetNativeCurrencyId::get() => {
@@ -692,6 +711,85 @@ impl<T: Config> MultiReservableCurrency<T::AccountId> for Pallet<T> {
 	}
 }
 
+/// Named reserves are not supported by ERC20 token.
+impl<T: Config> NamedMultiReservableCurrency<T::AccountId> for Pallet<T>
+where
+	T::MultiCurrency: NamedMultiReservableCurrency<T::AccountId, ReserveIdentifier = ReserveIdentifier>,
+	T::NativeCurrency: NamedBasicReservableCurrency<T::AccountId, ReserveIdentifier, Balance = BalanceOf<T>>,
+{
+	type ReserveIdentifier = ReserveIdentifier;
+
+	fn slash_reserved_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> Self::Balance {
+		match currency_id {
+			CurrencyId::Erc20(_) => value,
+			native if native == T::GetNativeCurrencyId::get() => {
+				T::NativeCurrency::slash_reserved_named(id, who, value)
+			}
+			_ => T::MultiCurrency::slash_reserved_named(id, currency_id, who, value),
+		}
+	}
+
+	fn reserved_balance_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+	) -> Self::Balance {
+		match currency_id {
+			CurrencyId::Erc20(_) => Zero::zero(),
+			native if native == T::GetNativeCurrencyId::get() => T::NativeCurrency::reserved_balance_named(id, who),
+			_ => T::MultiCurrency::reserved_balance_named(id, currency_id, who),
+		}
+	}
+
+	fn reserve_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> DispatchResult {
+		match currency_id {
+			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			native if native == T::GetNativeCurrencyId::get() => T::NativeCurrency::reserve_named(id, who, value),
+			_ => T::MultiCurrency::reserve_named(id, currency_id, who, value),
+		}
+	}
+
+	fn unreserve_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		who: &T::AccountId,
+		value: Self::Balance,
+	) -> Self::Balance {
+		match currency_id {
+			CurrencyId::Erc20(_) => value,
+			native if native == T::GetNativeCurrencyId::get() => T::NativeCurrency::unreserve_named(id, who, value),
+			_ => T::MultiCurrency::unreserve_named(id, currency_id, who, value),
+		}
+	}
+
+	fn repatriate_reserved_named(
+		id: &Self::ReserveIdentifier,
+		currency_id: Self::CurrencyId,
+		slashed: &T::AccountId,
+		beneficiary: &T::AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> result::Result<Self::Balance, DispatchError> {
+		match currency_id {
+			CurrencyId::Erc20(_) => Err(Error::<T>::Erc20InvalidOperation.into()),
+			native if native == T::GetNativeCurrencyId::get() => {
+				T::NativeCurrency::repatriate_reserved_named(id, slashed, beneficiary, value, status)
+			}
+			_ => T::MultiCurrency::repatriate_reserved_named(id, currency_id, slashed, beneficiary, value, status),
+		}
+	}
+}
+
 /// impl fungiles for Pallet<T>
 impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 	type AssetId = CurrencyId;
@@ -715,7 +813,7 @@ impl<T: Config> fungibles::Inspect<T::AccountId> for Pallet<T> {
 
 	fn reducible_balance(asset_id: Self::AssetId, who: &T::AccountId, keep_alive: bool) -> Self::Balance {
 		match asset_id {
-			CurrencyId::Erc20(_) => <Self as MultiCurrency<_>>::free_balance(asset_id, who),
+			CurrencyId::Erc20(contract) => Self::erc20_balance(contract, who),
 			id if id == T::GetNativeCurrencyId::get() => {
 				<T::NativeCurrency as fungible::Inspect<_>>::reducible_balance(who, keep_alive)
 			}
@@ -1365,6 +1463,41 @@ where
 	}
 }
 
+// Adapt `frame_support::traits::NamedReservableCurrency`
+impl<T, AccountId, Currency, Amount, Moment, ReserveId> NamedBasicReservableCurrency<AccountId, ReserveId>
+	for BasicCurrencyAdapter<T, Currency, Amount, Moment>
+where
+	Currency: PalletNamedReservableCurrency<AccountId, ReserveIdentifier = ReserveId>,
+	T: Config,
+{
+	fn slash_reserved_named(id: &ReserveId, who: &AccountId, value: Self::Balance) -> Self::Balance {
+		let (_, gap) = Currency::slash_reserved_named(id, who, value);
+		gap
+	}
+
+	fn reserved_balance_named(id: &ReserveId, who: &AccountId) -> Self::Balance {
+		Currency::reserved_balance_named(id, who)
+	}
+
+	fn reserve_named(id: &ReserveId, who: &AccountId, value: Self::Balance) -> DispatchResult {
+		Currency::reserve_named(id, who, value)
+	}
+
+	fn unreserve_named(id: &ReserveId, who: &AccountId, value: Self::Balance) -> Self::Balance {
+		Currency::unreserve_named(id, who, value)
+	}
+
+	fn repatriate_reserved_named(
+		id: &ReserveId,
+		slashed: &AccountId,
+		beneficiary: &AccountId,
+		value: Self::Balance,
+		status: BalanceStatus,
+	) -> result::Result<Self::Balance, DispatchError> {
+		Currency::repatriate_reserved_named(id, slashed, beneficiary, value, status)
+	}
+}
+
 type FungibleBalanceOf<A, Currency> = <Currency as fungible::Inspect<A>>::Balance;
 
 impl<T, AccountId, Currency, Amount, Moment> fungible::Inspect<AccountId>
@@ -1489,9 +1622,143 @@ impl<T: Config> TransferAll<T::AccountId> for Pallet<T> {
 	}
 }
 
-fn reserve_address(address: EvmAddress) -> EvmAddress {
-	let payload = (b"erc20:", address);
-	EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
+impl<T: Config> Pallet<T> {
+	/// The ERC20 token balance of `who` in the contract, excluding the reserved and locked token.
+	fn erc20_balance(contract: EvmAddress, who: &T::AccountId) -> BalanceOf<T> {
+		if let Some(address) = T::AddressMapping::get_evm_address(who) {
+			let context = InvokeContext {
+				contract,
+				sender: Default::default(),
+				origin: Default::default(),
+			};
+			return T::EVMBridge::balance_of(context, address).unwrap_or_default();
+		}
+		Default::default()
+	}
+
+	/// The ERC20 token reserved by `who` at the legacy reserve address, not escrowed in the
+	/// holding account yet.
+	fn erc20_legacy_reserved(contract: EvmAddress, who: &T::AccountId) -> BalanceOf<T> {
+		if let Some(address) = T::AddressMapping::get_evm_address(who) {
+			let context = InvokeContext {
+				contract,
+				sender: Default::default(),
+				origin: Default::default(),
+			};
+			return T::EVMBridge::balance_of(context, migrations::legacy_reserve_address(address)).unwrap_or_default();
+		}
+		Default::default()
+	}
+
+	/// Escrow the ERC20 token reserved by `who` at the legacy reserve address in the holding
+	/// account, and record it in the reserves of `who`. The legacy reserves not listed in the
+	/// migration are escrowed when they are used.
+	pub(crate) fn erc20_migrate_legacy_reserve(contract: EvmAddress, who: &T::AccountId) -> BalanceOf<T> {
+		let reserved = Self::erc20_legacy_reserved(contract, who);
+		if reserved.is_zero() {
+			return reserved;
+		}
+		let address = match T::AddressMapping::get_evm_address(who) {
+			Some(address) => address,
+			None => return Zero::zero(),
+		};
+
+		match T::EVMBridge::transfer(
+			InvokeContext {
+				contract,
+				sender: migrations::legacy_reserve_address(address),
+				origin: address,
+			},
+			T::Erc20HoldingAccount::get(),
+			reserved,
+		) {
+			Ok(_) => {
+				Erc20Reserves::<T>::mutate(contract, who, |total| *total = total.saturating_add(reserved));
+				Erc20Escrowed::<T>::mutate(contract, |escrowed| *escrowed = escrowed.saturating_add(reserved));
+				reserved
+			}
+			Err(e) => {
+				log::error!(
+					target: "currencies",
+					"Failed to escrow the ERC20 {:?} reserved by {:?} at the legacy reserve address: {:?}",
+					contract,
+					who,
+					e,
+				);
+				Zero::zero()
+			}
+		}
+	}
+
+	/// The max locked amount of ERC20 token of `who`.
+	fn erc20_locked(contract: EvmAddress, who: &T::AccountId) -> BalanceOf<T> {
+		Self::erc20_locks(contract, who)
+			.iter()
+			.map(|(_, amount)| *amount)
+			.max()
+			.unwrap_or_default()
+	}
+
+	/// Escrow `amount` ERC20 token of `who` into the holding account.
+	fn erc20_escrow(contract: EvmAddress, who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		let address = T::AddressMapping::get_evm_address(who).ok_or(Error::<T>::EvmAccountNotFound)?;
+		T::EVMBridge::transfer(
+			InvokeContext {
+				contract,
+				sender: address,
+				origin: address,
+			},
+			T::Erc20HoldingAccount::get(),
+			amount,
+		)?;
+		Erc20Escrowed::<T>::mutate(contract, |escrowed| *escrowed = escrowed.saturating_add(amount));
+		Ok(())
+	}
+
+	/// Release `amount` escrowed ERC20 token from the holding account to `who`.
+	fn erc20_release(contract: EvmAddress, who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		let address = T::AddressMapping::get_or_create_evm_address(who);
+		T::EVMBridge::transfer(
+			InvokeContext {
+				contract,
+				sender: T::Erc20HoldingAccount::get(),
+				origin: address,
+			},
+			address,
+			amount,
+		)?;
+		Erc20Escrowed::<T>::mutate(contract, |escrowed| *escrowed = escrowed.saturating_sub(amount));
+		Ok(())
+	}
+
+	/// Update the locks of ERC20 token of `who`, escrow or release the token for the change of
+	/// the max locked amount.
+	fn update_erc20_locks(
+		contract: EvmAddress,
+		who: &T::AccountId,
+		locks: BoundedVec<(LockIdentifier, BalanceOf<T>), MaxErc20Locks>,
+	) -> DispatchResult {
+		let locked = Self::erc20_locked(contract, who);
+		let new_locked = locks.iter().map(|(_, amount)| *amount).max().unwrap_or_default();
+		if new_locked > locked {
+			Self::erc20_escrow(contract, who, new_locked - locked)?;
+		} else if new_locked < locked {
+			Self::erc20_release(contract, who, locked - new_locked)?;
+		}
+
+		if locks.is_empty() {
+			Erc20Locks::<T>::remove(contract, who);
+		} else {
+			Erc20Locks::<T>::insert(contract, who, locks);
+		}
+		Ok(())
+	}
 }
 
 pub struct TransferDust<T, GetAccountId>(marker::PhantomData<(T, GetAccountId)>);
@@ -0,0 +1,127 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{BalanceOf, Config, Pallet};
+use codec::Encode;
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use primitives::evm::EvmAddress;
+use sp_io::hashing::blake2_256;
+use sp_runtime::traits::{Convert, Zero};
+use sp_std::{marker::PhantomData, vec::Vec};
+use support::{evm::limits::erc20, AddressMapping, EVMBridge, InvokeContext};
+
+/// The address the ERC20 token reserved by `address` was transferred to, before the reserves
+/// are escrowed in `Erc20HoldingAccount`.
+pub fn legacy_reserve_address(address: EvmAddress) -> EvmAddress {
+	let payload = (b"erc20:", address);
+	EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
+}
+
+/// Migrate the ERC20 reserves to v1. The legacy reserve addresses can't be enumerated on chain,
+/// `LegacyReserves` lists the ERC20 contracts and the accounts which reserved them. The legacy
+/// reserves not listed are still counted by `reserved_balance`, and are escrowed when they are
+/// unreserved, slashed or repatriated.
+pub struct MigrateToV1<T, LegacyReserves>(PhantomData<(T, LegacyReserves)>);
+impl<T: Config, LegacyReserves: Get<Vec<(EvmAddress, T::AccountId)>>> OnRuntimeUpgrade
+	for MigrateToV1<T, LegacyReserves>
+{
+	fn on_runtime_upgrade() -> Weight {
+		v1::migrate::<T>(LegacyReserves::get())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		v1::pre_migrate::<T>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		v1::post_migrate::<T>(LegacyReserves::get())
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Moves the ERC20 token reserved at the legacy reserve addresses of `legacy_reserves` into
+	/// `Erc20HoldingAccount`, and records them in `Erc20Reserves` and `Erc20Escrowed`.
+	pub fn migrate<T: Config>(legacy_reserves: Vec<(EvmAddress, T::AccountId)>) -> Weight {
+		let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_storage_version >= 1 {
+			log::warn!(
+				target: "currencies",
+				"Attempted to apply migration to v1 but failed because storage version is {:?}",
+				on_chain_storage_version,
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: "currencies", "Migrating currencies to v1");
+
+		let mut migrated: Weight = 0;
+		for (contract, who) in legacy_reserves.iter() {
+			if !Pallet::<T>::erc20_migrate_legacy_reserve(*contract, who).is_zero() {
+				migrated += 1;
+			}
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!(target: "currencies", "Completed currencies migration to v1, {:?} reserves", migrated);
+
+		let count = legacy_reserves.len() as Weight;
+		T::GasToWeight::convert(erc20::BALANCE_OF.gas.saturating_add(erc20::TRANSFER.gas))
+			.saturating_mul(count)
+			.saturating_add(
+				T::DbWeight::get().reads_writes(count.saturating_add(1), migrated.saturating_mul(2).saturating_add(1)),
+			)
+	}
+
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() < 1,
+			"currencies already migrated to v1."
+		);
+		Ok(())
+	}
+
+	pub fn post_migrate<T: Config>(legacy_reserves: Vec<(EvmAddress, T::AccountId)>) -> Result<(), &'static str> {
+		assert_eq!(
+			Pallet::<T>::on_chain_storage_version(),
+			1,
+			"currencies storage version not set."
+		);
+		for (contract, who) in legacy_reserves.iter() {
+			if let Some(address) = T::AddressMapping::get_evm_address(who) {
+				let left: BalanceOf<T> = T::EVMBridge::balance_of(
+					InvokeContext {
+						contract: *contract,
+						sender: Default::default(),
+						origin: Default::default(),
+					},
+					legacy_reserve_address(address),
+				)
+				.unwrap_or_default();
+				assert!(left.is_zero(), "ERC20 left in the legacy reserve address.");
+			}
+		}
+		Ok(())
+	}
+}
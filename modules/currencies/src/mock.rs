@@ -91,8 +91,8 @@ impl tokens::Config for Runtime {
 	type OnDust = tokens::TransferDust<Runtime, DustAccount>;
 	type WeightInfo = ();
 	type MaxLocks = ConstU32<100>;
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
 	type DustRemovalWhitelist = Nothing;
 	type OnNewTokenAccount = ();
 	type OnKilledTokenAccount = ();
@@ -155,6 +155,8 @@ impl module_evm::Config for Runtime {
 	type NewContractExtraBytes = ConstU32<1>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = TxFeePerGas;
+	type BaseFeeTargetGas = ConstU64<10_000_000>;
+	type MinBaseFeePerGas = ConstU128<0>;
 	type Event = Event;
 	type PrecompilesType = ();
 	type PrecompilesValue = ();
@@ -167,11 +169,14 @@ impl module_evm::Config for Runtime {
 	type PublicationFee = PublicationFee;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
+	type AbandonedContractTimeout = ConstU64<100>;
+	type CodeUpgradeDelay = ConstU64<10>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
 	type Task = ();
 	type IdleScheduler = ();
+	type CallFilter = ();
 	type WeightInfo = ();
 }
 
@@ -189,6 +194,7 @@ impl Config for Runtime {
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type Erc20HoldingAccount = Erc20HoldingAccount;
+	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
@@ -21,7 +21,8 @@
 #![cfg(test)]
 
 use super::*;
-use crate::mock::Erc20HoldingAccount;
+use crate::mock::{Erc20HoldingAccount, TreasuryAccount};
+use frame_support::traits::{GetStorageVersion, StorageVersion};
 use frame_support::{assert_noop, assert_ok, weights::GetDispatchInfo};
 use mock::{
 	alice, bob, deploy_contracts, erc20_address, eva, AccountId, AdaptedBasicCurrency, CouncilAccount, Currencies,
@@ -67,6 +68,49 @@ fn multi_reservable_currency_should_work() {
 		});
 }
 
+#[test]
+fn named_multi_reservable_currency_should_work() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			let id = ReserveIdentifier::Nft;
+			assert_ok!(Currencies::reserve_named(&id, X_TOKEN_ID, &alice(), 30));
+			assert_ok!(Currencies::reserve_named(&id, NATIVE_CURRENCY_ID, &alice(), 40));
+			assert_ok!(Currencies::reserve(X_TOKEN_ID, &alice(), 10));
+			assert_eq!(Currencies::reserved_balance_named(&id, X_TOKEN_ID, &alice()), 30);
+			assert_eq!(
+				Currencies::reserved_balance_named(&id, NATIVE_CURRENCY_ID, &alice()),
+				40
+			);
+			assert_eq!(Currencies::reserved_balance(X_TOKEN_ID, &alice()), 40);
+
+			// only the named reserve is unreserved
+			assert_eq!(Currencies::unreserve_named(&id, X_TOKEN_ID, &alice(), 35), 5);
+			assert_eq!(Currencies::reserved_balance_named(&id, X_TOKEN_ID, &alice()), 0);
+			assert_eq!(Currencies::reserved_balance(X_TOKEN_ID, &alice()), 10);
+
+			assert_eq!(
+				Currencies::repatriate_reserved_named(
+					&id,
+					NATIVE_CURRENCY_ID,
+					&alice(),
+					&bob(),
+					40,
+					BalanceStatus::Free
+				),
+				Ok(0)
+			);
+			assert_eq!(Currencies::reserved_balance_named(&id, NATIVE_CURRENCY_ID, &alice()), 0);
+			assert_eq!(NativeCurrency::free_balance(&bob()), 140);
+
+			assert_noop!(
+				Currencies::reserve_named(&id, CurrencyId::Erc20(erc20_address()), &alice(), 1),
+				Error::<Runtime>::Erc20InvalidOperation
+			);
+		});
+}
+
 #[test]
 fn native_currency_lockable_should_work() {
 	ExtBuilder::default()
@@ -577,8 +621,168 @@ fn erc20_slash_reserve_should_work() {
 			assert_ok!(Currencies::reserve(CurrencyId::Erc20(erc20_address()), &alice(), 100));
 			assert_eq!(
 				Currencies::slash_reserved(CurrencyId::Erc20(erc20_address()), &alice(), 10),
+				0
+			);
+			assert_eq!(
+				Currencies::reserved_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				90
+			);
+			assert_eq!(Currencies::erc20_escrowed(erc20_address()), 90);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &TreasuryAccount::get()),
+				10
+			);
+			assert_eq!(
+				Currencies::slash_reserved(CurrencyId::Erc20(erc20_address()), &alice(), 100),
 				10
 			);
+			assert_eq!(
+				Currencies::reserved_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				0
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				ALICE_BALANCE - 100
+			);
+			// the slashed token is moved out of the holding account
+			assert_eq!(Currencies::erc20_escrowed(erc20_address()), 0);
+			assert_eq!(
+				Currencies::free_balance(
+					CurrencyId::Erc20(erc20_address()),
+					&MockAddressMapping::get_account_id(&Erc20HoldingAccount::get())
+				),
+				0
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &TreasuryAccount::get()),
+				100
+			);
+		});
+}
+
+#[test]
+fn migrate_legacy_erc20_reserves_to_v1() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 100000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			// reserve with the legacy scheme
+			let alice_address = MockAddressMapping::get_evm_address(&alice()).unwrap();
+			let legacy_reserve_address = migrations::legacy_reserve_address(alice_address);
+			assert_ok!(<Runtime as Config>::EVMBridge::transfer(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: alice_address,
+					origin: alice_address,
+				},
+				legacy_reserve_address,
+				100
+			));
+			StorageVersion::new(0).put::<Currencies>();
+
+			migrations::v1::migrate::<Runtime>(vec![(erc20_address(), alice()), (erc20_address(), bob())]);
+			assert_eq!(Currencies::on_chain_storage_version(), 1);
+			assert_eq!(
+				Currencies::reserved_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				100
+			);
+			assert_eq!(
+				Currencies::reserved_balance(CurrencyId::Erc20(erc20_address()), &bob()),
+				0
+			);
+			assert_eq!(Currencies::erc20_escrowed(erc20_address()), 100);
+			assert_eq!(
+				Currencies::free_balance(
+					CurrencyId::Erc20(erc20_address()),
+					&MockAddressMapping::get_account_id(&legacy_reserve_address)
+				),
+				0
+			);
+			assert_eq!(
+				Currencies::free_balance(
+					CurrencyId::Erc20(erc20_address()),
+					&MockAddressMapping::get_account_id(&Erc20HoldingAccount::get())
+				),
+				100
+			);
+
+			// the migrated reserve can be unreserved
+			assert_eq!(
+				Currencies::unreserve(CurrencyId::Erc20(erc20_address()), &alice(), 100),
+				0
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				ALICE_BALANCE
+			);
+		});
+}
+
+#[test]
+fn legacy_erc20_reserves_not_migrated_can_be_unreserved() {
+	ExtBuilder::default()
+		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 100000)])
+		.build()
+		.execute_with(|| {
+			deploy_contracts();
+			// reserve with the legacy scheme, not listed in the migration
+			let alice_address = MockAddressMapping::get_evm_address(&alice()).unwrap();
+			let legacy_reserve_address = migrations::legacy_reserve_address(alice_address);
+			assert_ok!(<Runtime as Config>::EVMBridge::transfer(
+				InvokeContext {
+					contract: erc20_address(),
+					sender: alice_address,
+					origin: alice_address,
+				},
+				legacy_reserve_address,
+				100
+			));
+			StorageVersion::new(0).put::<Currencies>();
+			migrations::v1::migrate::<Runtime>(vec![]);
+
+			// the legacy reserve is still counted
+			assert_eq!(
+				Currencies::reserved_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				100
+			);
+			assert_eq!(Currencies::erc20_escrowed(erc20_address()), 0);
+
+			// and escrowed in the holding account when it's used
+			assert_eq!(
+				Currencies::unreserve(CurrencyId::Erc20(erc20_address()), &alice(), 40),
+				0
+			);
+			assert_eq!(
+				Currencies::reserved_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				60
+			);
+			assert_eq!(Currencies::erc20_reserves(erc20_address(), &alice()), 60);
+			assert_eq!(Currencies::erc20_escrowed(erc20_address()), 60);
+			assert_eq!(
+				Currencies::free_balance(
+					CurrencyId::Erc20(erc20_address()),
+					&MockAddressMapping::get_account_id(&legacy_reserve_address)
+				),
+				0
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				ALICE_BALANCE - 60
+			);
+
+			assert_eq!(
+				Currencies::repatriate_reserved(
+					CurrencyId::Erc20(erc20_address()),
+					&alice(),
+					&bob(),
+					60,
+					BalanceStatus::Free
+				),
+				Ok(0)
+			);
+			assert_eq!(Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &bob()), 60);
+			assert_eq!(Currencies::erc20_escrowed(erc20_address()), 0);
 		});
 }
 
@@ -684,23 +888,86 @@ fn erc20_should_not_slash() {
 }
 
 #[test]
-fn erc20_should_not_be_lockable() {
+fn erc20_lockable_should_work() {
+	const ID_2: LockIdentifier = *b"2       ";
 	ExtBuilder::default()
 		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 100000)])
 		.build()
 		.execute_with(|| {
 			deploy_contracts();
-			assert_noop!(
-				Currencies::set_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), 1),
-				Error::<Runtime>::Erc20InvalidOperation
+			let erc20_holding_account = MockAddressMapping::get_account_id(&Erc20HoldingAccount::get());
+
+			assert_ok!(Currencies::set_lock(
+				ID_1,
+				CurrencyId::Erc20(erc20_address()),
+				&alice(),
+				100
+			));
+			assert_eq!(
+				Currencies::erc20_locks(erc20_address(), alice()).into_inner(),
+				vec![(ID_1, 100)]
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				ALICE_BALANCE
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &erc20_holding_account),
+				100
 			);
 			assert_noop!(
-				Currencies::extend_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), 1),
-				Error::<Runtime>::Erc20InvalidOperation
+				Currencies::ensure_can_withdraw(CurrencyId::Erc20(erc20_address()), &alice(), ALICE_BALANCE - 99),
+				Error::<Runtime>::BalanceTooLow
 			);
+
+			// the max lock is escrowed
+			assert_ok!(Currencies::set_lock(
+				ID_2,
+				CurrencyId::Erc20(erc20_address()),
+				&alice(),
+				50
+			));
+			assert_ok!(Currencies::extend_lock(
+				ID_2,
+				CurrencyId::Erc20(erc20_address()),
+				&alice(),
+				150
+			));
+			assert_eq!(
+				Currencies::erc20_locks(erc20_address(), alice()).into_inner(),
+				vec![(ID_1, 100), (ID_2, 150)]
+			);
+			assert_eq!(Currencies::erc20_escrowed(erc20_address()), 150);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &erc20_holding_account),
+				150
+			);
+
+			assert_ok!(Currencies::remove_lock(
+				ID_2,
+				CurrencyId::Erc20(erc20_address()),
+				&alice()
+			));
+			assert_eq!(Currencies::erc20_escrowed(erc20_address()), 100);
+			assert_ok!(Currencies::remove_lock(
+				ID_1,
+				CurrencyId::Erc20(erc20_address()),
+				&alice()
+			));
+			assert_eq!(Currencies::erc20_escrowed(erc20_address()), 0);
+			assert!(!Erc20Locks::<Runtime>::contains_key(erc20_address(), alice()));
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &erc20_holding_account),
+				0
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				ALICE_BALANCE
+			);
+
 			assert_noop!(
-				Currencies::remove_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice()),
-				Error::<Runtime>::Erc20InvalidOperation
+				Currencies::set_lock(ID_1, CurrencyId::Erc20(erc20_address()), &alice(), ALICE_BALANCE + 1),
+				module_evm_bridge::Error::<Runtime>::ExecutionRevert
 			);
 		});
 }
@@ -842,7 +1109,7 @@ fn erc20_repatriate_reserved_should_work() {
 }
 
 #[test]
-fn erc20_invalid_operation() {
+fn erc20_update_balance_should_work() {
 	ExtBuilder::default()
 		.balances(vec![(alice(), NATIVE_CURRENCY_ID, 100000)])
 		.build()
@@ -850,9 +1117,31 @@ fn erc20_invalid_operation() {
 			deploy_contracts();
 			<EVM as EVMTrait<AccountId>>::set_origin(alice());
 
+			// holding account has no token to deposit
 			assert_noop!(
 				Currencies::update_balance(Origin::root(), alice(), CurrencyId::Erc20(erc20_address()), 1),
-				Error::<Runtime>::Erc20InvalidOperation,
+				Error::<Runtime>::DepositFailed,
+			);
+
+			assert_ok!(Currencies::update_balance(
+				Origin::root(),
+				alice(),
+				CurrencyId::Erc20(erc20_address()),
+				-100
+			));
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				ALICE_BALANCE - 100
+			);
+			assert_ok!(Currencies::update_balance(
+				Origin::root(),
+				alice(),
+				CurrencyId::Erc20(erc20_address()),
+				60
+			));
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &alice()),
+				ALICE_BALANCE - 40
 			);
 		});
 }
@@ -914,8 +1203,20 @@ fn erc20_withdraw_deposit_works() {
 			// deposit failed, because erc20 holding account balance not enough
 			assert_noop!(
 				Currencies::deposit(CurrencyId::Erc20(erc20_address()), &bob(), 101),
-				module_evm_bridge::Error::<Runtime>::ExecutionRevert
+				Error::<Runtime>::DepositFailed
+			);
+
+			// the reserved token escrowed in holding account can't be deposited
+			assert_ok!(Currencies::reserve(CurrencyId::Erc20(erc20_address()), &alice(), 50));
+			assert_eq!(
+				150,
+				Currencies::free_balance(CurrencyId::Erc20(erc20_address()), &erc20_holding_account)
+			);
+			assert_noop!(
+				Currencies::deposit(CurrencyId::Erc20(erc20_address()), &bob(), 101),
+				Error::<Runtime>::DepositFailed
 			);
+			assert_ok!(Currencies::deposit(CurrencyId::Erc20(erc20_address()), &bob(), 100));
 		});
 }
 
@@ -2101,7 +2402,7 @@ fn fungible_inspect_hold_and_hold_trait_should_work() {
 
 			assert_eq!(
 				<Currencies as fungibles::Inspect<_>>::balance(CurrencyId::Erc20(erc20_address()), &alice()),
-				ALICE_BALANCE - 8000
+				ALICE_BALANCE
 			);
 			assert_eq!(
 				<Currencies as fungibles::InspectHold<_>>::balance_on_hold(
@@ -2160,7 +2461,7 @@ fn fungible_inspect_hold_and_hold_trait_should_work() {
 
 			assert_eq!(
 				<Currencies as fungibles::Inspect<_>>::balance(CurrencyId::Erc20(erc20_address()), &alice()),
-				ALICE_BALANCE - 8000
+				ALICE_BALANCE
 			);
 			assert_eq!(
 				<Currencies as fungibles::InspectHold<_>>::balance_on_hold(
@@ -2204,7 +2505,7 @@ fn fungible_inspect_hold_and_hold_trait_should_work() {
 
 			assert_eq!(
 				<Currencies as fungibles::Inspect<_>>::balance(CurrencyId::Erc20(erc20_address()), &alice()),
-				ALICE_BALANCE - 8000
+				ALICE_BALANCE - 2000
 			);
 			assert_eq!(
 				<Currencies as fungibles::InspectHold<_>>::balance_on_hold(
@@ -2215,7 +2516,7 @@ fn fungible_inspect_hold_and_hold_trait_should_work() {
 			);
 			assert_eq!(
 				<Currencies as fungibles::Inspect<_>>::balance(CurrencyId::Erc20(erc20_address()), &bob()),
-				0
+				2000
 			);
 			assert_eq!(
 				<Currencies as fungibles::InspectHold<_>>::balance_on_hold(CurrencyId::Erc20(erc20_address()), &bob()),
@@ -2246,7 +2547,7 @@ fn fungible_inspect_hold_and_hold_trait_should_work() {
 			);
 			assert_eq!(
 				<Currencies as fungibles::Inspect<_>>::balance(CurrencyId::Erc20(erc20_address()), &bob()),
-				0
+				8000
 			);
 			assert_eq!(
 				<Currencies as fungibles::InspectHold<_>>::balance_on_hold(CurrencyId::Erc20(erc20_address()), &bob()),
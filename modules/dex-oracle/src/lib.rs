@@ -25,14 +25,14 @@
 use frame_support::{pallet_prelude::*, traits::Time, transactional};
 use frame_system::pallet_prelude::*;
 use orml_traits::Happened;
-use primitives::{Balance, CurrencyId, TradingPair};
+use primitives::{task::TaskResult, Balance, CurrencyId, TradingPair};
 use sp_core::U256;
 use sp_runtime::{
 	traits::{Saturating, Zero},
 	FixedPointNumber, SaturatedConversion,
 };
 use sp_std::marker::PhantomData;
-use support::{DEXManager, DEXPriceProvider, ExchangeRate};
+use support::{DEXManager, DEXPriceProvider, DispatchableTask, ExchangeRate};
 
 mod mock;
 mod tests;
@@ -313,6 +313,42 @@ impl<T: Config> Happened<(TradingPair, Balance, Balance)> for Pallet<T> {
 	}
 }
 
+#[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub enum DexOracleTask<T: Config> {
+	/// Update the cumulative prices of the trading pair with the current liquidity pool, so that
+	/// the average price keeps up when the pair is not swapped for a while.
+	UpdateCumulative { trading_pair: TradingPair },
+	#[doc(hidden)]
+	#[codec(skip)]
+	__Ignore(PhantomData<T>),
+}
+
+impl<T: Config> DexOracleTask<T> {
+	fn weight() -> Weight {
+		// AveragePrices (r: 1), LiquidityPool (r: 1), Cumulatives (r: 1, w: 1)
+		<T as frame_system::Config>::DbWeight::get().reads_writes(3, 1)
+	}
+}
+
+impl<T: Config> DispatchableTask for DexOracleTask<T> {
+	fn dispatch(self, _weight: Weight) -> TaskResult {
+		if let DexOracleTask::UpdateCumulative { trading_pair } = self {
+			let (pool_0, pool_1) = T::DEX::get_liquidity_pool(trading_pair.first(), trading_pair.second());
+			Pallet::<T>::try_update_cumulative(&trading_pair, pool_0, pool_1);
+		}
+		TaskResult {
+			result: Ok(()),
+			used_weight: Self::weight(),
+			finished: true,
+		}
+	}
+
+	fn max_weight(&self) -> Weight {
+		Self::weight()
+	}
+}
+
 /// CurrentDEXPriceProvider that always provider real-time prices from dex.
 pub struct CurrentDEXPriceProvider<T>(PhantomData<T>);
 impl<T: Config> DEXPriceProvider<CurrencyId> for CurrentDEXPriceProvider<T> {
@@ -621,3 +621,38 @@ fn dex_price_providers_work() {
 		);
 	});
 }
+
+#[test]
+fn update_cumulative_task_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		set_pool(&AUSDDOTPair::get(), 1_000, 100);
+		assert_ok!(DexOracle::enable_average_price(Origin::signed(1), AUSD, DOT, 1000));
+
+		Timestamp::set_timestamp(100);
+		let task = DexOracleTask::<Runtime>::UpdateCumulative {
+			trading_pair: AUSDDOTPair::get(),
+		};
+		let result = task.clone().dispatch(task.max_weight());
+		assert_eq!(result.result, Ok(()));
+		assert!(result.finished);
+		assert_eq!(result.used_weight, task.max_weight());
+		assert_eq!(
+			DexOracle::cumulatives(AUSDDOTPair::get()),
+			(
+				U256::from(10_000_000_000_000_000_000u128),
+				U256::from(1_000_000_000_000_000_000_000u128),
+				100
+			)
+		);
+
+		// not enabled
+		DexOracleTask::<Runtime>::UpdateCumulative {
+			trading_pair: ACADOTPair::get(),
+		}
+		.dispatch(task.max_weight());
+		assert_eq!(
+			DexOracle::cumulatives(ACADOTPair::get()),
+			(U256::from(0), U256::from(0), 0)
+		);
+	});
+}
@@ -34,7 +34,7 @@
 #![allow(clippy::collapsible_if)]
 
 use codec::MaxEncodedLen;
-use frame_support::{log, pallet_prelude::*, transactional, PalletId};
+use frame_support::{log, pallet_prelude::*, traits::Contains, transactional, PalletId};
 use frame_system::pallet_prelude::*;
 use orml_traits::{Happened, MultiCurrency, MultiCurrencyExtended};
 use primitives::{Balance, CurrencyId, TradingPair};
@@ -45,7 +45,7 @@ use sp_runtime::{
 	ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::{prelude::*, vec};
-use support::{DEXIncentives, DEXManager, Erc20InfoMapping, ExchangeRate, Ratio, SwapLimit};
+use support::{CircuitBreaker, DEXIncentives, DEXManager, Erc20InfoMapping, ExchangeRate, Ratio, SwapLimit};
 
 mod mock;
 mod tests;
@@ -67,6 +67,15 @@ pub struct ProvisioningParameters<Balance, BlockNumber> {
 	not_before: BlockNumber,
 }
 
+/// Parameters of the circuit breaker of TradingPair
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct CircuitBreakerParameters<BlockNumber> {
+	/// The max price change since the reference price, the swaps exceeding it are rejected.
+	pub threshold: Ratio,
+	/// The number of blocks the reference price is kept for.
+	pub window: BlockNumber,
+}
+
 /// Status for TradingPair
 #[derive(Clone, Copy, Encode, Decode, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
 pub enum TradingPairStatus<Balance, BlockNumber> {
@@ -133,6 +142,15 @@ pub mod module {
 
 		/// Event handler which calls when update liquidity pool.
 		type OnLiquidityPoolUpdated: Happened<(TradingPair, Balance, Balance)>;
+
+		/// The circuit breaker of trading pairs, swaps through a tripped
+		/// trading pair are rejected. It's tripped when the swap of an exempt
+		/// account moves the price more than the threshold.
+		type CircuitBreaker: CircuitBreaker<TradingPair>;
+
+		/// The accounts whose swaps are not limited by the price change threshold or the tripped
+		/// circuit breakers, such as the CDP treasury which swaps collaterals for liquidations.
+		type CircuitBreakerExemptAccounts: Contains<Self::AccountId>;
 	}
 
 	#[pallet::error]
@@ -183,6 +201,10 @@ pub mod module {
 		NotAllowedRefund,
 		/// Cannot swap
 		CannotSwap,
+		/// The circuit breaker of the trading pair is tripped
+		CircuitBreakerTripped,
+		/// The price change of the trading pair exceeds the threshold
+		ExceedPriceChangeThreshold,
 	}
 
 	#[pallet::event]
@@ -247,6 +269,11 @@ pub mod module {
 			accumulated_provision_0: Balance,
 			accumulated_provision_1: Balance,
 		},
+		/// The circuit breaker parameters of trading pair updated.
+		CircuitBreakerUpdated {
+			trading_pair: TradingPair,
+			parameters: Option<CircuitBreakerParameters<T::BlockNumber>>,
+		},
 	}
 
 	/// Liquidity pool for TradingPair.
@@ -281,6 +308,24 @@ pub mod module {
 	pub type InitialShareExchangeRates<T: Config> =
 		StorageMap<_, Twox64Concat, TradingPair, (ExchangeRate, ExchangeRate), ValueQuery>;
 
+	/// The circuit breaker parameters of TradingPair, the price change of swaps is unlimited
+	/// if not set.
+	///
+	/// CircuitBreakers: map TradingPair => Option<CircuitBreakerParameters<BlockNumber>>
+	#[pallet::storage]
+	#[pallet::getter(fn circuit_breakers)]
+	pub type CircuitBreakers<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, CircuitBreakerParameters<T::BlockNumber>, OptionQuery>;
+
+	/// The reference price of TradingPair and the block it's recorded at, the price change of
+	/// swaps is checked against it until the window of the circuit breaker has passed.
+	///
+	/// ReferencePrices: map TradingPair => Option<(BlockNumber, Ratio)>
+	#[pallet::storage]
+	#[pallet::getter(fn reference_prices)]
+	pub type ReferencePrices<T: Config> =
+		StorageMap<_, Twox64Concat, TradingPair, (T::BlockNumber, Ratio), OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub initial_listing_trading_pairs: Vec<(TradingPair, (Balance, Balance), (Balance, Balance), T::BlockNumber)>,
@@ -539,11 +584,12 @@ pub mod module {
 			);
 
 			let check_asset_registry = |currency_id: CurrencyId| match currency_id {
-				CurrencyId::Erc20(_) | CurrencyId::ForeignAsset(_) | CurrencyId::StableAssetPoolToken(_) => {
-					T::Erc20InfoMapping::name(currency_id)
-						.map(|_| ())
-						.ok_or(Error::<T>::AssetUnregistered)
-				}
+				CurrencyId::Erc20(_)
+				| CurrencyId::ForeignAsset(_)
+				| CurrencyId::StableAssetPoolToken(_)
+				| CurrencyId::VaultShare(_) => T::Erc20InfoMapping::name(currency_id)
+					.map(|_| ())
+					.ok_or(Error::<T>::AssetUnregistered),
 				CurrencyId::Token(_) | CurrencyId::DexShare(_, _) | CurrencyId::LiquidCrowdloan(_) => Ok(()), /* No registration required */
 			};
 			check_asset_registry(currency_id_a)?;
@@ -843,6 +889,34 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Set the circuit breaker parameters of a trading pair, `None` removes the limit on
+		/// the price change of swaps.
+		#[pallet::weight((<T as Config>::WeightInfo::set_circuit_breaker(), DispatchClass::Operational))]
+		#[transactional]
+		pub fn set_circuit_breaker(
+			origin: OriginFor<T>,
+			currency_id_a: CurrencyId,
+			currency_id_b: CurrencyId,
+			parameters: Option<CircuitBreakerParameters<T::BlockNumber>>,
+		) -> DispatchResult {
+			T::ListingOrigin::ensure_origin(origin)?;
+			let trading_pair =
+				TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
+
+			match parameters {
+				Some(parameters) => CircuitBreakers::<T>::insert(trading_pair, parameters),
+				None => CircuitBreakers::<T>::remove(trading_pair),
+			}
+			// the reference price is recorded again by the next swap.
+			ReferencePrices::<T>::remove(trading_pair);
+
+			Self::deposit_event(Event::CircuitBreakerUpdated {
+				trading_pair,
+				parameters,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -868,6 +942,47 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Ensure the price of the trading pair doesn't move more than the threshold of its circuit
+	/// breaker since the reference price. The swap of an exempt account exceeding it trips the
+	/// circuit breaker instead.
+	fn check_price_change(
+		trading_pair: &TradingPair,
+		old_pool: (Balance, Balance),
+		new_pool: (Balance, Balance),
+		exempt: bool,
+	) -> DispatchResult {
+		let parameters = match Self::circuit_breakers(trading_pair) {
+			Some(parameters) => parameters,
+			None => return Ok(()),
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		let reference_price = match Self::reference_prices(trading_pair) {
+			Some((recorded_at, price)) if now < recorded_at.saturating_add(parameters.window) => price,
+			_ => match Ratio::checked_from_rational(old_pool.1, old_pool.0) {
+				Some(price) if !price.is_zero() => {
+					// the window has passed, the price before this swap is the new reference.
+					ReferencePrices::<T>::insert(trading_pair, (now, price));
+					price
+				}
+				_ => return Ok(()),
+			},
+		};
+
+		if let Some(new_price) = Ratio::checked_from_rational(new_pool.1, new_pool.0) {
+			let change = if new_price > reference_price {
+				new_price.saturating_sub(reference_price)
+			} else {
+				reference_price.saturating_sub(new_price)
+			};
+			if change > parameters.threshold.saturating_mul(reference_price) {
+				// the rejected swap is reverted, only the swap of an exempt account can trip it.
+				ensure!(exempt, Error::<T>::ExceedPriceChangeThreshold);
+				T::CircuitBreaker::trip(trading_pair);
+			}
+		}
+		Ok(())
+	}
+
 	fn do_claim_dex_share(who: &T::AccountId, currency_id_a: CurrencyId, currency_id_b: CurrencyId) -> DispatchResult {
 		let trading_pair =
 			TradingPair::from_currency_ids(currency_id_a, currency_id_b).ok_or(Error::<T>::InvalidCurrencyId)?;
@@ -1318,9 +1433,15 @@ impl<T: Config> Pallet<T> {
 		target_currency_id: CurrencyId,
 		supply_increment: Balance,
 		target_decrement: Balance,
+		exempt: bool,
 	) -> DispatchResult {
 		if let Some(trading_pair) = TradingPair::from_currency_ids(supply_currency_id, target_currency_id) {
+			ensure!(
+				exempt || !T::CircuitBreaker::is_tripped(&trading_pair),
+				Error::<T>::CircuitBreakerTripped
+			);
 			Self::try_mutate_liquidity_pool(&trading_pair, |(pool_0, pool_1)| -> DispatchResult {
+				let old_pool = (*pool_0, *pool_1);
 				let invariant_before_swap: U256 = U256::from(*pool_0).saturating_mul(U256::from(*pool_1));
 
 				if supply_currency_id == trading_pair.first() {
@@ -1337,13 +1458,14 @@ impl<T: Config> Pallet<T> {
 					invariant_after_swap >= invariant_before_swap,
 					Error::<T>::InvariantCheckFailed,
 				);
-				Ok(())
+
+				Self::check_price_change(&trading_pair, old_pool, (*pool_0, *pool_1), exempt)
 			})?;
 		}
 		Ok(())
 	}
 
-	fn _swap_by_path(path: &[CurrencyId], amounts: &[Balance]) -> DispatchResult {
+	fn _swap_by_path(path: &[CurrencyId], amounts: &[Balance], exempt: bool) -> DispatchResult {
 		let mut i: usize = 0;
 		while i + 1 < path.len() {
 			let (supply_currency_id, target_currency_id) = (path[i], path[i + 1]);
@@ -1353,6 +1475,7 @@ impl<T: Config> Pallet<T> {
 				target_currency_id,
 				supply_increment,
 				target_decrement,
+				exempt,
 			)?;
 			i += 1;
 		}
@@ -1376,7 +1499,7 @@ impl<T: Config> Pallet<T> {
 		let actual_target_amount = amounts[amounts.len() - 1];
 
 		T::Currency::transfer(path[0], who, &module_account_id, supply_amount)?;
-		Self::_swap_by_path(path, &amounts)?;
+		Self::_swap_by_path(path, &amounts, T::CircuitBreakerExemptAccounts::contains(who))?;
 		T::Currency::transfer(path[path.len() - 1], &module_account_id, who, actual_target_amount)?;
 
 		Self::deposit_event(Event::Swap {
@@ -1401,7 +1524,7 @@ impl<T: Config> Pallet<T> {
 		let actual_supply_amount = amounts[0];
 
 		T::Currency::transfer(path[0], who, &module_account_id, actual_supply_amount)?;
-		Self::_swap_by_path(path, &amounts)?;
+		Self::_swap_by_path(path, &amounts, T::CircuitBreakerExemptAccounts::contains(who))?;
 		T::Currency::transfer(path[path.len() - 1], &module_account_id, who, target_amount)?;
 
 		Self::deposit_event(Event::Swap {
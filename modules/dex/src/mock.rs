@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::{
 	construct_runtime, ord_parameter_types, parameter_types,
-	traits::{ConstU32, ConstU64, Everything, Nothing},
+	traits::{ConstU32, ConstU64, Everything, IsInVec, Nothing},
 };
 use frame_system::EnsureSignedBy;
 use orml_traits::{parameter_type_with_key, MultiReservableCurrency};
@@ -125,10 +125,27 @@ parameter_types! {
 	pub AlternativeSwapPathJointList: Vec<Vec<CurrencyId>> = vec![
 		vec![DOT],
 	];
+	pub CircuitBreakerExemptAccounts: Vec<AccountId> = vec![ALICE];
 }
 
 thread_local! {
 	pub static AUSD_DOT_POOL_RECORD: RefCell<(Balance, Balance)> = RefCell::new((0, 0));
+	pub static TRIPPED_TRADING_PAIRS: RefCell<Vec<TradingPair>> = RefCell::new(vec![]);
+}
+
+pub struct MockCircuitBreaker;
+impl CircuitBreaker<TradingPair> for MockCircuitBreaker {
+	fn is_tripped(trading_pair: &TradingPair) -> bool {
+		TRIPPED_TRADING_PAIRS.with(|v| v.borrow().contains(trading_pair))
+	}
+
+	fn trip(trading_pair: &TradingPair) {
+		TRIPPED_TRADING_PAIRS.with(|v| {
+			if !v.borrow().contains(trading_pair) {
+				v.borrow_mut().push(*trading_pair);
+			}
+		});
+	}
 }
 
 pub struct MockOnLiquidityPoolUpdated;
@@ -153,6 +170,8 @@ impl Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<2000>;
 	type OnLiquidityPoolUpdated = MockOnLiquidityPoolUpdated;
+	type CircuitBreaker = MockCircuitBreaker;
+	type CircuitBreakerExemptAccounts = IsInVec<CircuitBreakerExemptAccounts>;
 }
 
 parameter_types! {
@@ -24,7 +24,8 @@ use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{
 	ACAJointSwap, AUSDBTCPair, AUSDDOTPair, AUSDJointSwap, DOTBTCPair, DexModule, Event, ExtBuilder, ListingOrigin,
-	Origin, Runtime, System, Tokens, ACA, ALICE, AUSD, AUSD_DOT_POOL_RECORD, BOB, BTC, CAROL, DOT,
+	MockCircuitBreaker, Origin, Runtime, System, Tokens, ACA, ALICE, AUSD, AUSD_DOT_POOL_RECORD, BOB, BTC, CAROL, DOT,
+	TRIPPED_TRADING_PAIRS,
 };
 use orml_traits::MultiReservableCurrency;
 use sp_core::H160;
@@ -761,6 +762,176 @@ fn on_liquidity_pool_updated_work() {
 		});
 }
 
+#[test]
+fn set_circuit_breaker_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		let parameters = CircuitBreakerParameters {
+			threshold: Ratio::saturating_from_rational(10, 100),
+			window: 2,
+		};
+		assert_noop!(
+			DexModule::set_circuit_breaker(Origin::signed(ALICE), AUSD, DOT, Some(parameters)),
+			BadOrigin
+		);
+		assert_noop!(
+			DexModule::set_circuit_breaker(Origin::signed(ListingOrigin::get()), AUSD, AUSD, Some(parameters)),
+			Error::<Runtime>::InvalidCurrencyId
+		);
+
+		ReferencePrices::<Runtime>::insert(AUSDDOTPair::get(), (1, Ratio::one()));
+		assert_ok!(DexModule::set_circuit_breaker(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			Some(parameters)
+		));
+		System::assert_last_event(Event::DexModule(crate::Event::CircuitBreakerUpdated {
+			trading_pair: AUSDDOTPair::get(),
+			parameters: Some(parameters),
+		}));
+		assert_eq!(DexModule::circuit_breakers(AUSDDOTPair::get()), Some(parameters));
+		assert_eq!(DexModule::reference_prices(AUSDDOTPair::get()), None);
+
+		assert_ok!(DexModule::set_circuit_breaker(
+			Origin::signed(ListingOrigin::get()),
+			AUSD,
+			DOT,
+			None
+		));
+		assert_eq!(DexModule::circuit_breakers(AUSDDOTPair::get()), None);
+	});
+}
+
+#[test]
+fn circuit_breaker_work() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+			assert_ok!(DexModule::set_circuit_breaker(
+				Origin::signed(ListingOrigin::get()),
+				AUSD,
+				DOT,
+				Some(CircuitBreakerParameters {
+					threshold: Ratio::saturating_from_rational(10, 100),
+					window: 2,
+				})
+			));
+			assert_eq!(DexModule::reference_prices(AUSDDOTPair::get()), None);
+
+			assert_ok!(DexModule::do_swap_with_exact_supply(
+				&BOB,
+				&[DOT, AUSD],
+				1_000_000_000_000,
+				0
+			));
+			assert_eq!(
+				DexModule::reference_prices(AUSDDOTPair::get()),
+				Some((1, Ratio::saturating_from_rational(1, 5)))
+			);
+
+			// only the swap exceeding the threshold is rejected.
+			assert_noop!(
+				DexModule::do_swap_with_exact_supply(&BOB, &[DOT, AUSD], 20_000_000_000_000, 0),
+				Error::<Runtime>::ExceedPriceChangeThreshold
+			);
+			assert_noop!(
+				DexModule::do_swap_with_exact_target(&BOB, &[DOT, AUSD], 80_000_000_000_000, u128::MAX),
+				Error::<Runtime>::ExceedPriceChangeThreshold
+			);
+			assert_ok!(DexModule::do_swap_with_exact_supply(
+				&BOB,
+				&[AUSD, DOT],
+				1_000_000_000_000,
+				0
+			));
+
+			// the swaps of the exempt accounts are not limited, but trip the circuit breaker.
+			assert!(!MockCircuitBreaker::is_tripped(&AUSDDOTPair::get()));
+			let pool = DexModule::get_liquidity(AUSD, DOT);
+			assert_ok!(DexModule::do_swap_with_exact_supply(
+				&ALICE,
+				&[DOT, AUSD],
+				20_000_000_000_000,
+				0
+			));
+			assert_ne!(DexModule::get_liquidity(AUSD, DOT), pool);
+			assert_eq!(
+				DexModule::reference_prices(AUSDDOTPair::get()),
+				Some((1, Ratio::saturating_from_rational(1, 5)))
+			);
+			assert!(MockCircuitBreaker::is_tripped(&AUSDDOTPair::get()));
+			assert_noop!(
+				DexModule::do_swap_with_exact_supply(&BOB, &[AUSD, DOT], 1_000_000_000_000, 0),
+				Error::<Runtime>::CircuitBreakerTripped
+			);
+			TRIPPED_TRADING_PAIRS.with(|v| v.borrow_mut().clear());
+
+			// the price before the swap is the new reference after the window.
+			System::set_block_number(3);
+			let (pool_0, pool_1) = DexModule::get_liquidity(AUSD, DOT);
+			assert_ok!(DexModule::do_swap_with_exact_supply(
+				&BOB,
+				&[AUSD, DOT],
+				1_000_000_000_000,
+				0
+			));
+			assert_eq!(
+				DexModule::reference_prices(AUSDDOTPair::get()),
+				Some((3, Ratio::saturating_from_rational(pool_1, pool_0)))
+			);
+		});
+}
+
+#[test]
+fn swap_through_tripped_trading_pair_fails() {
+	ExtBuilder::default()
+		.initialize_enabled_trading_pairs()
+		.build()
+		.execute_with(|| {
+			assert_ok!(DexModule::add_liquidity(
+				Origin::signed(ALICE),
+				AUSD,
+				DOT,
+				500_000_000_000_000,
+				100_000_000_000_000,
+				0,
+				false,
+			));
+
+			MockCircuitBreaker::trip(&AUSDDOTPair::get());
+			assert_noop!(
+				DexModule::do_swap_with_exact_supply(&BOB, &[AUSD, DOT], 1_000_000_000_000, 0),
+				Error::<Runtime>::CircuitBreakerTripped
+			);
+			assert_noop!(
+				DexModule::do_swap_with_exact_target(&BOB, &[AUSD, DOT], 1_000_000_000_000, u128::MAX),
+				Error::<Runtime>::CircuitBreakerTripped
+			);
+
+			// the exempt accounts can swap through the tripped trading pair.
+			assert_ok!(DexModule::do_swap_with_exact_supply(
+				&ALICE,
+				&[AUSD, DOT],
+				1_000_000_000_000,
+				0
+			));
+		});
+}
+
 #[test]
 fn add_provision_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -48,6 +48,7 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn enable_trading_pair() -> Weight;
 	fn disable_trading_pair() -> Weight;
+	fn set_circuit_breaker() -> Weight;
 	fn list_provisioning() -> Weight;
 	fn update_provisioning_parameters() -> Weight;
 	fn end_provisioning() -> Weight;
@@ -76,6 +77,10 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	fn set_circuit_breaker() -> Weight {
+		(21_352_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	fn list_provisioning() -> Weight {
 		(37_619_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(3 as Weight))
@@ -126,18 +131,18 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			// Standard Error: 117_000
 			.saturating_add((16_008_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
 	fn swap_with_exact_target(u: u32, ) -> Weight {
 		(93_966_000 as Weight)
 			// Standard Error: 226_000
 			.saturating_add((16_058_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
 	fn refund_provision() -> Weight {
 		(105_716_000 as Weight)
@@ -163,6 +168,10 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn set_circuit_breaker() -> Weight {
+		(21_352_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 	fn list_provisioning() -> Weight {
 		(37_619_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
@@ -213,18 +222,18 @@ impl WeightInfo for () {
 			// Standard Error: 117_000
 			.saturating_add((16_008_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
 	fn swap_with_exact_target(u: u32, ) -> Weight {
 		(93_966_000 as Weight)
 			// Standard Error: 226_000
 			.saturating_add((16_058_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
-			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
 	fn refund_provision() -> Weight {
 		(105_716_000 as Weight)
@@ -22,22 +22,36 @@
 //!
 //! Evm Accounts module provide a two way mapping between Substrate accounts and
 //! EVM accounts so user only have deal with one account / private key.
+//!
+//! The mapping could be relinked to another EvmAddress with the consent of both EVM keys, the
+//! EVM nonce of the previous address is migrated to the new one. The mapping could also be
+//! removed by the account itself with the consent of the EVM key, or by governance to recover an
+//! account whose key is lost. The consents are signed with a nonce and a deadline so they can't be
+//! replayed.
+//!
+//! Accounts can also authorize session keys: temporary secp256k1 keys which can dispatch calls
+//! on behalf of the account through `session_call`, restricted to the allowed pallets and
+//! contracts, limited by the spend limits and expired after the expiry block. Calls which
+//! dispatch other calls are rejected by `SessionCallFilter`, and a deposit is reserved for each
+//! session key until it's removed.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 
 use codec::Encode;
 use frame_support::{
+	dispatch::{DispatchErrorWithPostInfo, GetDispatchInfo, PostDispatchInfo},
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, IsType, OnKilledAccount},
+	parameter_types,
+	traits::{Contains, IsType, NamedReservableCurrency, OnKilledAccount, PalletInfoAccess},
 	transactional,
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
 use module_evm_utility_macro::keccak256;
-use module_support::{AddressMapping, EVMAccountsManager};
-use orml_traits::currency::TransferAll;
-use primitives::{evm::EvmAddress, to_bytes, AccountIndex};
+use module_support::{AddressMapping, EVMAccountMigration, EVMAccountsManager};
+use orml_traits::{currency::TransferAll, MultiCurrency};
+use primitives::{evm::EvmAddress, to_bytes, AccountIndex, Balance, CurrencyId, ReserveIdentifier};
 use sp_core::crypto::AccountId32;
 use sp_core::{H160, H256};
 use sp_io::{
@@ -45,10 +59,10 @@ use sp_io::{
 	hashing::{blake2_256, keccak_256},
 };
 use sp_runtime::{
-	traits::{LookupError, StaticLookup, Zero},
+	traits::{Dispatchable, LookupError, Saturating, StaticLookup, UniqueSaturatedInto, Zero},
 	MultiAddress,
 };
-use sp_std::{marker::PhantomData, vec::Vec};
+use sp_std::{boxed::Box, marker::PhantomData, vec::Vec};
 
 mod mock;
 mod tests;
@@ -60,16 +74,77 @@ pub use weights::WeightInfo;
 /// A signature (a 512-bit value, plus 8 bits for recovery ID).
 pub type Eip712Signature = [u8; 65];
 
+parameter_types! {
+	/// Max number of pallets, contracts or spend limits in a session key scope.
+	pub const MaxSessionKeyScopes: u32 = 16;
+	/// Max number of currencies of the owner tracked by a session call.
+	pub const MaxSessionTrackedCurrencies: u32 = 32;
+}
+
+/// The calls a session key is allowed to dispatch.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SessionKeyScope {
+	/// Indices of the pallets the session key can call.
+	pub pallets: BoundedVec<u8, MaxSessionKeyScopes>,
+	/// Contracts the session key can interact with.
+	pub contracts: BoundedVec<EvmAddress, MaxSessionKeyScopes>,
+	/// Max amount of the currencies the session key can spend. Currencies not listed can't be
+	/// spent.
+	pub spend_limits: BoundedVec<(CurrencyId, Balance), MaxSessionKeyScopes>,
+}
+
+/// Session key authorized by an account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct SessionKeyInfo<BlockNumber> {
+	/// The session key can't be used after this block.
+	pub expiry: BlockNumber,
+	/// The calls the session key is allowed to dispatch.
+	pub scope: SessionKeyScope,
+	/// The amount of the currencies spent by the session key.
+	pub spent: BoundedVec<(CurrencyId, Balance), MaxSessionKeyScopes>,
+}
+
+/// Inspect the EVM contract a call interacts with.
+pub trait EvmCallTarget<Call> {
+	/// Returns the contract `call` interacts with, `None` if it's not a contract call.
+	fn evm_call_target(call: &Call) -> Option<EvmAddress>;
+}
+
+impl<Call> EvmCallTarget<Call> for () {
+	fn evm_call_target(_call: &Call) -> Option<EvmAddress> {
+		None
+	}
+}
+
+/// List the currencies held by an account.
+pub trait AccountCurrencies<AccountId> {
+	/// Returns the currencies `who` has a balance of, at most `limit` currencies.
+	fn account_currencies(who: &AccountId, limit: u32) -> Vec<CurrencyId>;
+}
+
+impl<AccountId> AccountCurrencies<AccountId> for () {
+	fn account_currencies(_who: &AccountId, _limit: u32) -> Vec<CurrencyId> {
+		Vec::new()
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
 
+	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::EvmAccountsSessionKey;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
-		/// The Currency for managing Evm account assets.
-		type Currency: Currency<Self::AccountId>;
+		/// The Currency for managing Evm account assets, and reserving the deposit of session
+		/// keys.
+		type Currency: NamedReservableCurrency<
+			Self::AccountId,
+			Balance = Balance,
+			ReserveIdentifier = ReserveIdentifier,
+		>;
 
 		/// Mapping from address to account id.
 		type AddressMapping: AddressMapping<Self::AccountId>;
@@ -81,6 +156,38 @@ pub mod module {
 		/// Merge free balance from source to dest.
 		type TransferAll: TransferAll<Self::AccountId>;
 
+		/// Migrate the EVM nonce when an account is relinked.
+		type AccountMigration: EVMAccountMigration;
+
+		/// The origin which may remove the mapping of any account.
+		type RecoveryOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The overarching call type, dispatched by session keys.
+		type Call: Parameter + Dispatchable<Origin = Self::Origin, PostInfo = PostDispatchInfo> + GetDispatchInfo;
+
+		/// Currency to track the spending of session keys.
+		type Currencies: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The native currency id, which is always tracked for session keys.
+		#[pallet::constant]
+		type GetNativeCurrencyId: Get<CurrencyId>;
+
+		/// Inspect the contract a call interacts with, to check the contracts scope of session
+		/// keys.
+		type EvmCallTarget: EvmCallTarget<<Self as Config>::Call>;
+
+		/// The calls session keys are allowed to dispatch. The scope only checks the outer call,
+		/// so calls dispatching other calls (batch, proxy, multisig, scheduler...) must be
+		/// rejected.
+		type SessionCallFilter: Contains<<Self as Config>::Call>;
+
+		/// The currencies held by the owner, the spending of them is tracked for session keys.
+		type AccountCurrencies: AccountCurrencies<Self::AccountId>;
+
+		/// The deposit reserved for each session key.
+		#[pallet::constant]
+		type SessionKeyDeposit: Get<Balance>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -94,6 +201,33 @@ pub mod module {
 			account_id: T::AccountId,
 			evm_address: EvmAddress,
 		},
+		/// The account is relinked to another EVM address.
+		AccountRelinked {
+			account_id: T::AccountId,
+			old_evm_address: EvmAddress,
+			new_evm_address: EvmAddress,
+		},
+		/// The mapping between the account and the EVM address is removed.
+		AccountUnlinked {
+			account_id: T::AccountId,
+			evm_address: EvmAddress,
+		},
+		/// Session key authorized.
+		SessionKeyAdded {
+			owner: T::AccountId,
+			session_key: EvmAddress,
+			expiry: T::BlockNumber,
+		},
+		/// Session key revoked.
+		SessionKeyRemoved {
+			owner: T::AccountId,
+			session_key: EvmAddress,
+		},
+		/// A call dispatched by session key.
+		SessionCallExecuted {
+			owner: T::AccountId,
+			session_key: EvmAddress,
+		},
 	}
 
 	/// Error for evm accounts module.
@@ -109,6 +243,24 @@ pub mod module {
 		InvalidSignature,
 		/// Account ref count is not zero
 		NonZeroRefCount,
+		/// AccountId has not mapped
+		AccountIdNotMapped,
+		/// Session key not found
+		SessionKeyNotFound,
+		/// Session key has expired
+		SessionKeyExpired,
+		/// Expiry must be in the future
+		InvalidExpiry,
+		/// Call is not allowed by the session key scope
+		CallNotAllowed,
+		/// Contract is not allowed by the session key scope
+		ContractNotAllowed,
+		/// Spend limit of the session key exceeded
+		SpendLimitExceeded,
+		/// The owner holds too many currencies to track the spending of session keys
+		TooManyCurrencies,
+		/// The deadline of the consent has passed
+		ConsentExpired,
 	}
 
 	/// The Substrate Account for EvmAddresses
@@ -125,6 +277,28 @@ pub mod module {
 	#[pallet::getter(fn evm_addresses)]
 	pub type EvmAddresses<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, EvmAddress, OptionQuery>;
 
+	/// The nonce of the relink and unlink consents of accounts.
+	///
+	/// ConsentNonces: map AccountId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn consent_nonces)]
+	pub type ConsentNonces<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The session keys authorized by accounts.
+	///
+	/// SessionKeys: double_map AccountId, EvmAddress => Option<SessionKeyInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn session_keys)]
+	pub type SessionKeys<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		EvmAddress,
+		SessionKeyInfo<T::BlockNumber>,
+		OptionQuery,
+	>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -165,6 +339,15 @@ pub mod module {
 				T::TransferAll::transfer_all(&account_id, &who)?;
 			}
 
+			// the default address of `origin` could have been used before the mapping was removed,
+			// merge its balance and migrate its nonce
+			let default_eth_address = account_to_default_evm_address(&who);
+			let default_account_id = T::AddressMapping::get_account_id(&default_eth_address);
+			if frame_system::Pallet::<T>::account_exists(&default_account_id) {
+				T::TransferAll::transfer_all(&default_account_id, &who)?;
+			}
+			T::AccountMigration::migrate_nonce(&default_eth_address, &eth_address)?;
+
 			Accounts::<T>::insert(eth_address, &who);
 			EvmAddresses::<T>::insert(&who, eth_address);
 
@@ -185,6 +368,201 @@ pub mod module {
 			let _ = Self::do_claim_default_evm_address(who)?;
 			Ok(())
 		}
+
+		/// Relink the caller's account to another EVM address, both the current and the new
+		/// address must sign the consent. The current address doesn't sign if it's the default
+		/// address of the caller, which has no key.
+		///
+		/// The balances of the new address are merged into the caller, and the EVM nonce of the
+		/// current address is migrated to the new address.
+		///
+		/// - `new_eth_address`: The address to bind to the caller's account
+		/// - `deadline`: The consent can't be used after this block
+		/// - `old_eth_signature`: The consent signed by the current address
+		/// - `new_eth_signature`: The consent signed by the new address
+		#[pallet::weight(T::WeightInfo::relink_account())]
+		#[transactional]
+		pub fn relink_account(
+			origin: OriginFor<T>,
+			new_eth_address: EvmAddress,
+			deadline: T::BlockNumber,
+			old_eth_signature: Option<Eip712Signature>,
+			new_eth_signature: Eip712Signature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let old_eth_address = EvmAddresses::<T>::get(&who).ok_or(Error::<T>::AccountIdNotMapped)?;
+			ensure!(
+				!Accounts::<T>::contains_key(new_eth_address),
+				Error::<T>::EthAddressHasMapped
+			);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= deadline,
+				Error::<T>::ConsentExpired
+			);
+
+			let nonce = Self::consent_nonces(&who);
+			let msg = Self::eip712_relink_signable_message(&who, &old_eth_address, &new_eth_address, nonce, deadline);
+			// the default address has no key to sign
+			if old_eth_address != account_to_default_evm_address(&who) {
+				let old_eth_signature = old_eth_signature.ok_or(Error::<T>::BadSignature)?;
+				let address = recover_signer(&old_eth_signature, &keccak_256(&msg)).ok_or(Error::<T>::BadSignature)?;
+				ensure!(old_eth_address == address, Error::<T>::InvalidSignature);
+			}
+			let address = recover_signer(&new_eth_signature, &keccak_256(&msg)).ok_or(Error::<T>::BadSignature)?;
+			ensure!(new_eth_address == address, Error::<T>::InvalidSignature);
+			ConsentNonces::<T>::insert(&who, nonce.saturating_add(1));
+
+			// merge balance from `evm padded address` to `origin`
+			let account_id = T::AddressMapping::get_account_id(&new_eth_address);
+			if frame_system::Pallet::<T>::account_exists(&account_id) {
+				T::TransferAll::transfer_all(&account_id, &who)?;
+			}
+			T::AccountMigration::migrate_nonce(&old_eth_address, &new_eth_address)?;
+
+			Accounts::<T>::remove(old_eth_address);
+			Accounts::<T>::insert(new_eth_address, &who);
+			EvmAddresses::<T>::insert(&who, new_eth_address);
+
+			Self::deposit_event(Event::AccountRelinked {
+				account_id: who,
+				old_evm_address: old_eth_address,
+				new_evm_address: new_eth_address,
+			});
+			Ok(())
+		}
+
+		/// Remove the mapping of the caller's account, the current address must sign the consent.
+		/// The current address doesn't sign if it's the default address of the caller, which has no
+		/// key.
+		///
+		/// - `deadline`: The consent can't be used after this block
+		/// - `eth_signature`: The consent signed by the current address
+		#[pallet::weight(T::WeightInfo::unlink_account())]
+		#[transactional]
+		pub fn unlink_account(
+			origin: OriginFor<T>,
+			deadline: T::BlockNumber,
+			eth_signature: Option<Eip712Signature>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let eth_address = EvmAddresses::<T>::get(&who).ok_or(Error::<T>::AccountIdNotMapped)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= deadline,
+				Error::<T>::ConsentExpired
+			);
+
+			let nonce = Self::consent_nonces(&who);
+			// the default address has no key to sign
+			if eth_address != account_to_default_evm_address(&who) {
+				let eth_signature = eth_signature.ok_or(Error::<T>::BadSignature)?;
+				let msg = Self::eip712_unlink_signable_message(&who, &eth_address, nonce, deadline);
+				let address = recover_signer(&eth_signature, &keccak_256(&msg)).ok_or(Error::<T>::BadSignature)?;
+				ensure!(eth_address == address, Error::<T>::InvalidSignature);
+			}
+			ConsentNonces::<T>::insert(&who, nonce.saturating_add(1));
+
+			Self::do_unlink_account(who)
+		}
+
+		/// Remove the mapping of `who`, to recover the account whose key is lost.
+		///
+		/// The dispatch origin of this call must be `RecoveryOrigin`.
+		#[pallet::weight(T::WeightInfo::force_unlink_account())]
+		pub fn force_unlink_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::RecoveryOrigin::ensure_origin(origin)?;
+			Self::do_unlink_account(who)
+		}
+
+		/// Authorize a session key to dispatch calls on behalf of the caller, replace the
+		/// previous authorization of the session key. `SessionKeyDeposit` is reserved for a new
+		/// session key.
+		///
+		/// - `session_key`: The EVM address of the session key
+		/// - `scope`: The calls the session key is allowed to dispatch
+		/// - `expiry`: The session key can't be used after this block
+		#[pallet::weight(T::WeightInfo::add_session_key())]
+		#[transactional]
+		pub fn add_session_key(
+			origin: OriginFor<T>,
+			session_key: EvmAddress,
+			scope: SessionKeyScope,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+
+			if !SessionKeys::<T>::contains_key(&who, session_key) {
+				T::Currency::reserve_named(&RESERVE_ID, &who, T::SessionKeyDeposit::get())?;
+			}
+			SessionKeys::<T>::insert(
+				&who,
+				session_key,
+				SessionKeyInfo {
+					expiry,
+					scope,
+					spent: Default::default(),
+				},
+			);
+
+			Self::deposit_event(Event::SessionKeyAdded {
+				owner: who,
+				session_key,
+				expiry,
+			});
+			Ok(())
+		}
+
+		/// Revoke the session key of the caller, the deposit is unreserved.
+		///
+		/// - `session_key`: The EVM address of the session key
+		#[pallet::weight(T::WeightInfo::remove_session_key())]
+		pub fn remove_session_key(origin: OriginFor<T>, session_key: EvmAddress) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				SessionKeys::<T>::contains_key(&who, session_key),
+				Error::<T>::SessionKeyNotFound
+			);
+
+			SessionKeys::<T>::remove(&who, session_key);
+			T::Currency::unreserve_named(&RESERVE_ID, &who, T::SessionKeyDeposit::get());
+
+			Self::deposit_event(Event::SessionKeyRemoved {
+				owner: who,
+				session_key,
+			});
+			Ok(())
+		}
+
+		/// Dispatch a call on behalf of `owner`, signed by the session key authorized by
+		/// `owner`. The call fails if it's out of the session key scope or exceeds the spend
+		/// limits. Any decrease of the owner's balance of a currency counts as spending, increases
+		/// of other currencies don't offset it.
+		///
+		/// - `owner`: The account authorized the session key
+		/// - `call`: The call to dispatch
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				T::WeightInfo::session_call(MaxSessionTrackedCurrencies::get()).saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		#[transactional]
+		pub fn session_call(
+			origin: OriginFor<T>,
+			owner: T::AccountId,
+			call: Box<<T as Config>::Call>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let session_key = T::AddressMapping::get_evm_address(&who).ok_or(Error::<T>::SessionKeyNotFound)?;
+
+			Self::do_session_call(owner, session_key, *call)
+		}
 	}
 }
 
@@ -213,6 +591,47 @@ impl<T: Config> Pallet<T> {
 		r
 	}
 
+	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
+	// Constructs a relink consent message with the current nonce of `who` and signs it.
+	pub fn eth_sign_relink(
+		secret: &libsecp256k1::SecretKey,
+		who: &T::AccountId,
+		old_address: &EvmAddress,
+		new_address: &EvmAddress,
+		deadline: T::BlockNumber,
+	) -> Eip712Signature {
+		let nonce = Self::consent_nonces(who);
+		let msg = keccak_256(&Self::eip712_relink_signable_message(
+			who,
+			old_address,
+			new_address,
+			nonce,
+			deadline,
+		));
+		let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+		let mut r = [0u8; 65];
+		r[0..64].copy_from_slice(&sig.serialize()[..]);
+		r[64] = recovery_id.serialize();
+		r
+	}
+
+	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
+	// Constructs an unlink consent message with the current nonce of `who` and signs it.
+	pub fn eth_sign_unlink(
+		secret: &libsecp256k1::SecretKey,
+		who: &T::AccountId,
+		address: &EvmAddress,
+		deadline: T::BlockNumber,
+	) -> Eip712Signature {
+		let nonce = Self::consent_nonces(who);
+		let msg = keccak_256(&Self::eip712_unlink_signable_message(who, address, nonce, deadline));
+		let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+		let mut r = [0u8; 65];
+		r[0..64].copy_from_slice(&sig.serialize()[..]);
+		r[64] = recovery_id.serialize();
+		r
+	}
+
 	fn verify_eip712_signature(who: &T::AccountId, sig: &[u8; 65]) -> Option<H160> {
 		let msg = Self::eip712_signable_message(who);
 		let msg_hash = keccak_256(msg.as_slice());
@@ -238,6 +657,57 @@ impl<T: Config> Pallet<T> {
 		keccak_256(tx_msg.as_slice())
 	}
 
+	// Eip-712 relink consent to be signed by both the old and the new address
+	fn eip712_relink_signable_message(
+		who: &T::AccountId,
+		old_address: &EvmAddress,
+		new_address: &EvmAddress,
+		nonce: u32,
+		deadline: T::BlockNumber,
+	) -> Vec<u8> {
+		let domain_separator = Self::evm_account_domain_separator();
+
+		let tx_type_hash = keccak256!(
+			"Relink(bytes substrateAddress,address oldEvmAddress,address newEvmAddress,uint256 nonce,uint256 deadline)"
+		);
+		let mut tx_msg = tx_type_hash.to_vec();
+		tx_msg.extend_from_slice(&keccak_256(&who.encode()));
+		tx_msg.extend_from_slice(H256::from(*old_address).as_bytes());
+		tx_msg.extend_from_slice(H256::from(*new_address).as_bytes());
+		tx_msg.extend_from_slice(&to_bytes(nonce));
+		tx_msg.extend_from_slice(&to_bytes::<u64>(deadline.unique_saturated_into()));
+		let payload_hash = keccak_256(tx_msg.as_slice());
+
+		let mut msg = b"\x19\x01".to_vec();
+		msg.extend_from_slice(&domain_separator);
+		msg.extend_from_slice(&payload_hash);
+		msg
+	}
+
+	// Eip-712 unlink consent to be signed by the linked address
+	fn eip712_unlink_signable_message(
+		who: &T::AccountId,
+		address: &EvmAddress,
+		nonce: u32,
+		deadline: T::BlockNumber,
+	) -> Vec<u8> {
+		let domain_separator = Self::evm_account_domain_separator();
+
+		let tx_type_hash =
+			keccak256!("Unlink(bytes substrateAddress,address evmAddress,uint256 nonce,uint256 deadline)");
+		let mut tx_msg = tx_type_hash.to_vec();
+		tx_msg.extend_from_slice(&keccak_256(&who.encode()));
+		tx_msg.extend_from_slice(H256::from(*address).as_bytes());
+		tx_msg.extend_from_slice(&to_bytes(nonce));
+		tx_msg.extend_from_slice(&to_bytes::<u64>(deadline.unique_saturated_into()));
+		let payload_hash = keccak_256(tx_msg.as_slice());
+
+		let mut msg = b"\x19\x01".to_vec();
+		msg.extend_from_slice(&domain_separator);
+		msg.extend_from_slice(&payload_hash);
+		msg
+	}
+
 	fn evm_account_domain_separator() -> [u8; 32] {
 		let domain_hash = keccak256!("EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)");
 		let mut domain_seperator_msg = domain_hash.to_vec();
@@ -248,6 +718,108 @@ impl<T: Config> Pallet<T> {
 		keccak_256(domain_seperator_msg.as_slice())
 	}
 
+	fn do_session_call(
+		owner: T::AccountId,
+		session_key: EvmAddress,
+		call: <T as Config>::Call,
+	) -> DispatchResultWithPostInfo {
+		let mut info = SessionKeys::<T>::get(&owner, session_key).ok_or(Error::<T>::SessionKeyNotFound)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <= info.expiry,
+			Error::<T>::SessionKeyExpired
+		);
+
+		// session key can't manage the session keys of the owner, or dispatch calls escaping the
+		// scope check
+		let pallet_index = call.using_encoded(|c| c.first().copied().unwrap_or_default());
+		ensure!(
+			pallet_index as usize != <Self as PalletInfoAccess>::index()
+				&& info.scope.pallets.contains(&pallet_index)
+				&& T::SessionCallFilter::contains(&call),
+			Error::<T>::CallNotAllowed
+		);
+		if let Some(contract) = T::EvmCallTarget::evm_call_target(&call) {
+			ensure!(info.scope.contracts.contains(&contract), Error::<T>::ContractNotAllowed);
+		}
+
+		// track all the currencies of the owner, and the currencies of the spend limits in case the
+		// owner doesn't hold them yet
+		let max_currencies = MaxSessionTrackedCurrencies::get();
+		let mut tracked = T::AccountCurrencies::account_currencies(&owner, max_currencies.saturating_add(1));
+		for currency_id in sp_std::iter::once(T::GetNativeCurrencyId::get())
+			.chain(info.scope.spend_limits.iter().map(|(currency_id, _)| *currency_id))
+		{
+			if !tracked.contains(&currency_id) {
+				tracked.push(currency_id);
+			}
+		}
+		ensure!(tracked.len() as u32 <= max_currencies, Error::<T>::TooManyCurrencies);
+		// reserved balance is counted, unreserving can't offset the spending
+		let balances_before: Vec<Balance> = tracked
+			.iter()
+			.map(|currency_id| T::Currencies::total_balance(*currency_id, &owner))
+			.collect();
+
+		let session_call_weight = T::WeightInfo::session_call(tracked.len() as u32);
+		let with_session_call_weight = |post_info: PostDispatchInfo| PostDispatchInfo {
+			actual_weight: post_info.actual_weight.map(|w| w.saturating_add(session_call_weight)),
+			pays_fee: post_info.pays_fee,
+		};
+
+		let post_info = call
+			.dispatch(frame_system::RawOrigin::Signed(owner.clone()).into())
+			.map_err(|e| DispatchErrorWithPostInfo {
+				post_info: with_session_call_weight(e.post_info),
+				error: e.error,
+			})?;
+
+		for (currency_id, balance_before) in tracked.into_iter().zip(balances_before) {
+			let spent_amount = balance_before.saturating_sub(T::Currencies::total_balance(currency_id, &owner));
+			if spent_amount.is_zero() {
+				continue;
+			}
+
+			// currencies not in the spend limits can't be spent
+			let limit = info
+				.scope
+				.spend_limits
+				.iter()
+				.find(|(id, _)| *id == currency_id)
+				.map(|(_, limit)| *limit)
+				.unwrap_or_default();
+			let exceeded = match info.spent.iter_mut().find(|(id, _)| *id == currency_id) {
+				Some((_, spent)) => {
+					*spent = spent.saturating_add(spent_amount);
+					*spent > limit
+				}
+				// only the currencies of the spend limits can be spent, won't exceed the bound
+				None => spent_amount > limit || info.spent.try_push((currency_id, spent_amount)).is_err(),
+			};
+			if exceeded {
+				return Err(DispatchErrorWithPostInfo {
+					post_info: with_session_call_weight(post_info),
+					error: Error::<T>::SpendLimitExceeded.into(),
+				});
+			}
+		}
+		SessionKeys::<T>::insert(&owner, session_key, info);
+
+		Self::deposit_event(Event::SessionCallExecuted { owner, session_key });
+
+		Ok(with_session_call_weight(post_info))
+	}
+
+	fn do_unlink_account(who: T::AccountId) -> DispatchResult {
+		let evm_address = EvmAddresses::<T>::take(&who).ok_or(Error::<T>::AccountIdNotMapped)?;
+		Accounts::<T>::remove(evm_address);
+
+		Self::deposit_event(Event::AccountUnlinked {
+			account_id: who,
+			evm_address,
+		});
+		Ok(())
+	}
+
 	fn do_claim_default_evm_address(who: T::AccountId) -> Result<EvmAddress, DispatchError> {
 		// ensure account_id has not been mapped
 		ensure!(!EvmAddresses::<T>::contains_key(&who), Error::<T>::AccountIdHasMapped);
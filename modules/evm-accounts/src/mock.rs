@@ -25,8 +25,9 @@ use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{ConstU128, ConstU64, Everything, Nothing},
 };
+use frame_system::EnsureRoot;
 use orml_traits::parameter_type_with_key;
-use primitives::{Amount, Balance, CurrencyId, TokenSymbol};
+use primitives::{Amount, Balance, CurrencyId, ReserveIdentifier, TokenSymbol};
 use sp_core::{crypto::AccountId32, H256};
 use sp_io::hashing::keccak_256;
 use sp_runtime::{testing::Header, traits::IdentityLookup};
@@ -74,8 +75,8 @@ impl pallet_balances::Config for Runtime {
 	type ExistentialDeposit = ConstU128<1>;
 	type AccountStore = frame_system::Pallet<Runtime>;
 	type MaxLocks = ();
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
 	type WeightInfo = ();
 }
 
@@ -113,12 +114,46 @@ impl orml_currencies::Config for Runtime {
 }
 pub type AdaptedBasicCurrency = orml_currencies::BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
 
+impl pallet_utility::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = ();
+}
+
+pub struct SessionCallFilter;
+impl Contains<Call> for SessionCallFilter {
+	fn contains(call: &Call) -> bool {
+		!matches!(call, Call::Utility(_))
+	}
+}
+
+pub struct MockAccountCurrencies;
+impl AccountCurrencies<AccountId> for MockAccountCurrencies {
+	fn account_currencies(who: &AccountId, limit: u32) -> Vec<CurrencyId> {
+		orml_tokens::Accounts::<Runtime>::iter_key_prefix(who)
+			.take(limit as usize)
+			.collect()
+	}
+}
+
+pub const SESSION_KEY_DEPOSIT: Balance = 10;
+
 impl Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type ChainId = ();
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type AccountMigration = ();
+	type RecoveryOrigin = EnsureRoot<AccountId>;
+	type Call = Call;
+	type Currencies = Currencies;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type EvmCallTarget = ();
+	type SessionCallFilter = SessionCallFilter;
+	type AccountCurrencies = MockAccountCurrencies;
+	type SessionKeyDeposit = ConstU128<SESSION_KEY_DEPOSIT>;
 	type WeightInfo = ();
 }
 
@@ -136,6 +171,7 @@ construct_runtime!(
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Currencies: orml_currencies::{Pallet, Call},
+		Utility: pallet_utility::{Pallet, Call, Event},
 	}
 );
 
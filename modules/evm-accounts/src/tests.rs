@@ -22,7 +22,12 @@
 
 use super::*;
 use frame_support::{assert_noop, assert_ok};
-use mock::{alice, bob, Event, EvmAccountsModule, ExtBuilder, Origin, Runtime, System, ALICE, BOB};
+use mock::{
+	alice, bob, bob_account_id, Balances, Call, Event, EvmAccountsModule, ExtBuilder, GetNativeCurrencyId, Origin,
+	Runtime, System, Tokens, ALICE, BOB, SESSION_KEY_DEPOSIT,
+};
+use primitives::TokenSymbol;
+use sp_runtime::traits::BadOrigin;
 use std::str::FromStr;
 
 #[test]
@@ -187,3 +192,543 @@ fn account_to_evm_with_create_default() {
 		);
 	});
 }
+
+#[test]
+fn relink_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let alice_evm_account = EvmAccountsModule::eth_address(&alice());
+		let bob_evm_account = EvmAccountsModule::eth_address(&bob());
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			alice_evm_account,
+			EvmAccountsModule::eth_sign(&alice(), &ALICE)
+		));
+
+		assert_ok!(EvmAccountsModule::relink_account(
+			Origin::signed(ALICE),
+			bob_evm_account,
+			10,
+			Some(EvmAccountsModule::eth_sign_relink(
+				&alice(),
+				&ALICE,
+				&alice_evm_account,
+				&bob_evm_account,
+				10
+			)),
+			EvmAccountsModule::eth_sign_relink(&bob(), &ALICE, &alice_evm_account, &bob_evm_account, 10)
+		));
+		System::assert_last_event(Event::EvmAccountsModule(crate::Event::AccountRelinked {
+			account_id: ALICE,
+			old_evm_address: alice_evm_account,
+			new_evm_address: bob_evm_account,
+		}));
+		assert_eq!(EvmAccountsModule::accounts(alice_evm_account), None);
+		assert_eq!(EvmAccountsModule::accounts(bob_evm_account), Some(ALICE));
+		assert_eq!(EvmAccountsModule::evm_addresses(ALICE), Some(bob_evm_account));
+		assert_eq!(EvmAccountsModule::consent_nonces(ALICE), 1);
+
+		// the balance of the evm padded address is merged
+		assert_eq!(Balances::free_balance(ALICE), 100000);
+		assert_eq!(Balances::free_balance(bob_account_id()), 0);
+	});
+}
+
+#[test]
+fn relink_account_from_default_address_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let default_evm_account = EvmAddressMapping::<Runtime>::get_or_create_evm_address(&ALICE);
+		let alice_evm_account = EvmAccountsModule::eth_address(&alice());
+
+		// the default address has no key to sign
+		assert_ok!(EvmAccountsModule::relink_account(
+			Origin::signed(ALICE),
+			alice_evm_account,
+			10,
+			None,
+			EvmAccountsModule::eth_sign_relink(&alice(), &ALICE, &default_evm_account, &alice_evm_account, 10)
+		));
+		assert_eq!(EvmAccountsModule::accounts(default_evm_account), None);
+		assert_eq!(EvmAccountsModule::evm_addresses(ALICE), Some(alice_evm_account));
+	});
+}
+
+#[test]
+fn relink_account_should_not_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let alice_evm_account = EvmAccountsModule::eth_address(&alice());
+		let bob_evm_account = EvmAccountsModule::eth_address(&bob());
+		let old_signature =
+			EvmAccountsModule::eth_sign_relink(&alice(), &ALICE, &alice_evm_account, &bob_evm_account, 10);
+		let new_signature =
+			EvmAccountsModule::eth_sign_relink(&bob(), &ALICE, &alice_evm_account, &bob_evm_account, 10);
+
+		assert_noop!(
+			EvmAccountsModule::relink_account(
+				Origin::signed(ALICE),
+				bob_evm_account,
+				10,
+				Some(old_signature),
+				new_signature
+			),
+			Error::<Runtime>::AccountIdNotMapped
+		);
+
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			alice_evm_account,
+			EvmAccountsModule::eth_sign(&alice(), &ALICE)
+		));
+		assert_noop!(
+			EvmAccountsModule::relink_account(Origin::signed(ALICE), bob_evm_account, 10, None, new_signature),
+			Error::<Runtime>::BadSignature
+		);
+		assert_noop!(
+			EvmAccountsModule::relink_account(
+				Origin::signed(ALICE),
+				bob_evm_account,
+				10,
+				Some(new_signature),
+				new_signature
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+		assert_noop!(
+			EvmAccountsModule::relink_account(
+				Origin::signed(ALICE),
+				bob_evm_account,
+				10,
+				Some(old_signature),
+				old_signature
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+
+		let bob_default_evm_account = EvmAddressMapping::<Runtime>::get_or_create_evm_address(&BOB);
+		assert_noop!(
+			EvmAccountsModule::relink_account(
+				Origin::signed(ALICE),
+				bob_default_evm_account,
+				10,
+				Some(old_signature),
+				new_signature
+			),
+			Error::<Runtime>::EthAddressHasMapped
+		);
+
+		// the deadline has passed
+		System::set_block_number(11);
+		assert_noop!(
+			EvmAccountsModule::relink_account(
+				Origin::signed(ALICE),
+				bob_evm_account,
+				10,
+				Some(old_signature),
+				new_signature
+			),
+			Error::<Runtime>::ConsentExpired
+		);
+	});
+}
+
+#[test]
+fn relink_consent_can_not_be_replayed() {
+	ExtBuilder::default().build().execute_with(|| {
+		let alice_evm_account = EvmAccountsModule::eth_address(&alice());
+		let bob_evm_account = EvmAccountsModule::eth_address(&bob());
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			alice_evm_account,
+			EvmAccountsModule::eth_sign(&alice(), &ALICE)
+		));
+
+		let alice_to_bob = (
+			EvmAccountsModule::eth_sign_relink(&alice(), &ALICE, &alice_evm_account, &bob_evm_account, 10),
+			EvmAccountsModule::eth_sign_relink(&bob(), &ALICE, &alice_evm_account, &bob_evm_account, 10),
+		);
+		assert_ok!(EvmAccountsModule::relink_account(
+			Origin::signed(ALICE),
+			bob_evm_account,
+			10,
+			Some(alice_to_bob.0),
+			alice_to_bob.1
+		));
+		assert_ok!(EvmAccountsModule::relink_account(
+			Origin::signed(ALICE),
+			alice_evm_account,
+			10,
+			Some(EvmAccountsModule::eth_sign_relink(
+				&bob(),
+				&ALICE,
+				&bob_evm_account,
+				&alice_evm_account,
+				10
+			)),
+			EvmAccountsModule::eth_sign_relink(&alice(), &ALICE, &bob_evm_account, &alice_evm_account, 10)
+		));
+		assert_eq!(EvmAccountsModule::consent_nonces(ALICE), 2);
+
+		// the consent signed with the previous nonce is rejected
+		assert_noop!(
+			EvmAccountsModule::relink_account(
+				Origin::signed(ALICE),
+				bob_evm_account,
+				10,
+				Some(alice_to_bob.0),
+				alice_to_bob.1
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn unlink_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let alice_evm_account = EvmAccountsModule::eth_address(&alice());
+		assert_noop!(
+			EvmAccountsModule::unlink_account(Origin::signed(ALICE), 10, None),
+			Error::<Runtime>::AccountIdNotMapped
+		);
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			alice_evm_account,
+			EvmAccountsModule::eth_sign(&alice(), &ALICE)
+		));
+
+		let signature = EvmAccountsModule::eth_sign_unlink(&alice(), &ALICE, &alice_evm_account, 10);
+		assert_noop!(
+			EvmAccountsModule::unlink_account(Origin::signed(ALICE), 10, None),
+			Error::<Runtime>::BadSignature
+		);
+		assert_noop!(
+			EvmAccountsModule::unlink_account(
+				Origin::signed(ALICE),
+				10,
+				Some(EvmAccountsModule::eth_sign_unlink(
+					&bob(),
+					&ALICE,
+					&alice_evm_account,
+					10
+				))
+			),
+			Error::<Runtime>::InvalidSignature
+		);
+		assert_noop!(
+			EvmAccountsModule::unlink_account(Origin::signed(ALICE), 9, Some(signature)),
+			Error::<Runtime>::InvalidSignature
+		);
+		System::set_block_number(11);
+		assert_noop!(
+			EvmAccountsModule::unlink_account(Origin::signed(ALICE), 10, Some(signature)),
+			Error::<Runtime>::ConsentExpired
+		);
+		System::set_block_number(10);
+
+		assert_ok!(EvmAccountsModule::unlink_account(
+			Origin::signed(ALICE),
+			10,
+			Some(signature)
+		));
+		System::assert_last_event(Event::EvmAccountsModule(crate::Event::AccountUnlinked {
+			account_id: ALICE,
+			evm_address: alice_evm_account,
+		}));
+		assert_eq!(EvmAccountsModule::accounts(alice_evm_account), None);
+		assert_eq!(EvmAccountsModule::evm_addresses(ALICE), None);
+
+		assert_eq!(EvmAccountsModule::consent_nonces(ALICE), 1);
+
+		// the address could be claimed again
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(BOB),
+			alice_evm_account,
+			EvmAccountsModule::eth_sign(&alice(), &BOB)
+		));
+	});
+}
+
+#[test]
+fn unlink_default_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let default_evm_account = EvmAddressMapping::<Runtime>::get_or_create_evm_address(&ALICE);
+
+		// the default address has no key to sign
+		assert_ok!(EvmAccountsModule::unlink_account(Origin::signed(ALICE), 10, None));
+		assert_eq!(EvmAccountsModule::accounts(default_evm_account), None);
+		assert_eq!(EvmAccountsModule::evm_addresses(ALICE), None);
+	});
+}
+
+#[test]
+fn claim_account_merge_default_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		let default_evm_account = EvmAddressMapping::<Runtime>::get_or_create_evm_address(&ALICE);
+		assert_ok!(EvmAccountsModule::unlink_account(Origin::signed(ALICE), 10, None));
+
+		// the balance sent to the default address after the mapping is removed
+		let default_account_id = EvmAddressMapping::<Runtime>::get_account_id(&default_evm_account);
+		assert_ok!(Balances::transfer(
+			Origin::signed(bob_account_id()),
+			default_account_id.clone(),
+			100
+		));
+
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			EvmAccountsModule::eth_address(&alice()),
+			EvmAccountsModule::eth_sign(&alice(), &ALICE)
+		));
+		assert_eq!(Balances::free_balance(ALICE), 100);
+		assert_eq!(Balances::free_balance(default_account_id), 0);
+	});
+}
+
+#[test]
+fn force_unlink_account_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let alice_evm_account = EvmAccountsModule::eth_address(&alice());
+		assert_ok!(EvmAccountsModule::claim_account(
+			Origin::signed(ALICE),
+			alice_evm_account,
+			EvmAccountsModule::eth_sign(&alice(), &ALICE)
+		));
+
+		assert_noop!(
+			EvmAccountsModule::force_unlink_account(Origin::signed(BOB), ALICE),
+			BadOrigin
+		);
+		assert_ok!(EvmAccountsModule::force_unlink_account(Origin::root(), ALICE));
+		System::assert_last_event(Event::EvmAccountsModule(crate::Event::AccountUnlinked {
+			account_id: ALICE,
+			evm_address: alice_evm_account,
+		}));
+		assert_eq!(EvmAccountsModule::evm_addresses(ALICE), None);
+	});
+}
+
+#[test]
+fn add_and_remove_session_key_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let session_key = EvmAccountsModule::eth_address(&alice());
+
+		assert_noop!(
+			EvmAccountsModule::add_session_key(Origin::signed(BOB), session_key, Default::default(), 1),
+			Error::<Runtime>::InvalidExpiry
+		);
+		assert_noop!(
+			EvmAccountsModule::add_session_key(Origin::signed(BOB), session_key, Default::default(), 10),
+			pallet_balances::Error::<Runtime>::InsufficientBalance
+		);
+
+		assert_ok!(Balances::set_balance(Origin::root(), BOB, 100, 0));
+		assert_ok!(EvmAccountsModule::add_session_key(
+			Origin::signed(BOB),
+			session_key,
+			Default::default(),
+			10
+		));
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &BOB), SESSION_KEY_DEPOSIT);
+		System::assert_last_event(Event::EvmAccountsModule(crate::Event::SessionKeyAdded {
+			owner: BOB,
+			session_key,
+			expiry: 10,
+		}));
+		assert_eq!(
+			EvmAccountsModule::session_keys(BOB, session_key),
+			Some(SessionKeyInfo {
+				expiry: 10,
+				scope: Default::default(),
+				spent: Default::default(),
+			})
+		);
+
+		// replacing the authorization doesn't reserve the deposit again
+		assert_ok!(EvmAccountsModule::add_session_key(
+			Origin::signed(BOB),
+			session_key,
+			Default::default(),
+			20
+		));
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &BOB), SESSION_KEY_DEPOSIT);
+
+		assert_noop!(
+			EvmAccountsModule::remove_session_key(Origin::signed(ALICE), session_key),
+			Error::<Runtime>::SessionKeyNotFound
+		);
+		assert_ok!(EvmAccountsModule::remove_session_key(Origin::signed(BOB), session_key));
+		System::assert_last_event(Event::EvmAccountsModule(crate::Event::SessionKeyRemoved {
+			owner: BOB,
+			session_key,
+		}));
+		assert_eq!(EvmAccountsModule::session_keys(BOB, session_key), None);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &BOB), 0);
+		assert_eq!(Balances::free_balance(BOB), 100);
+	});
+}
+
+#[test]
+fn session_call_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let session_key = EvmAccountsModule::eth_address(&alice());
+		let session_account = EvmAddressMapping::<Runtime>::get_account_id(&session_key);
+		let transfer = |value| Box::new(Call::Balances(pallet_balances::Call::transfer { dest: ALICE, value }));
+
+		assert_noop!(
+			EvmAccountsModule::session_call(Origin::signed(session_account.clone()), bob_account_id(), transfer(100)),
+			Error::<Runtime>::SessionKeyNotFound
+		);
+
+		let scope = SessionKeyScope {
+			pallets: vec![<Balances as PalletInfoAccess>::index() as u8].try_into().unwrap(),
+			contracts: Default::default(),
+			spend_limits: vec![(GetNativeCurrencyId::get(), 150)].try_into().unwrap(),
+		};
+		assert_ok!(EvmAccountsModule::add_session_key(
+			Origin::signed(bob_account_id()),
+			session_key,
+			scope,
+			10
+		));
+
+		assert_ok!(EvmAccountsModule::session_call(
+			Origin::signed(session_account.clone()),
+			bob_account_id(),
+			transfer(100)
+		));
+		System::assert_last_event(Event::EvmAccountsModule(crate::Event::SessionCallExecuted {
+			owner: bob_account_id(),
+			session_key,
+		}));
+		assert_eq!(Balances::free_balance(ALICE), 100);
+		assert_eq!(
+			EvmAccountsModule::session_keys(bob_account_id(), session_key)
+				.unwrap()
+				.spent
+				.into_inner(),
+			vec![(GetNativeCurrencyId::get(), 100)]
+		);
+
+		// exceeds the spend limit
+		assert_noop!(
+			EvmAccountsModule::session_call(Origin::signed(session_account.clone()), bob_account_id(), transfer(100)),
+			Error::<Runtime>::SpendLimitExceeded
+		);
+
+		// pallet not in the scope
+		assert_noop!(
+			EvmAccountsModule::session_call(
+				Origin::signed(session_account.clone()),
+				bob_account_id(),
+				Box::new(Call::System(frame_system::Call::remark { remark: vec![] }))
+			),
+			Error::<Runtime>::CallNotAllowed
+		);
+
+		// session key can't manage session keys
+		assert_noop!(
+			EvmAccountsModule::session_call(
+				Origin::signed(session_account.clone()),
+				bob_account_id(),
+				Box::new(Call::EvmAccountsModule(crate::Call::remove_session_key { session_key }))
+			),
+			Error::<Runtime>::CallNotAllowed
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			EvmAccountsModule::session_call(Origin::signed(session_account), bob_account_id(), transfer(10)),
+			Error::<Runtime>::SessionKeyExpired
+		);
+	});
+}
+
+#[test]
+fn session_call_reject_call_wrappers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let session_key = EvmAccountsModule::eth_address(&alice());
+		let session_account = EvmAddressMapping::<Runtime>::get_account_id(&session_key);
+		let scope = SessionKeyScope {
+			pallets: vec![
+				<Balances as PalletInfoAccess>::index() as u8,
+				<mock::Utility as PalletInfoAccess>::index() as u8,
+			]
+			.try_into()
+			.unwrap(),
+			contracts: Default::default(),
+			spend_limits: vec![(GetNativeCurrencyId::get(), 150)].try_into().unwrap(),
+		};
+		assert_ok!(EvmAccountsModule::add_session_key(
+			Origin::signed(bob_account_id()),
+			session_key,
+			scope,
+			10
+		));
+
+		// the calls in the batch are not checked against the scope
+		assert_noop!(
+			EvmAccountsModule::session_call(
+				Origin::signed(session_account),
+				bob_account_id(),
+				Box::new(Call::Utility(pallet_utility::Call::batch {
+					calls: vec![Call::EvmAccountsModule(crate::Call::remove_session_key { session_key })]
+				}))
+			),
+			Error::<Runtime>::CallNotAllowed
+		);
+	});
+}
+
+#[test]
+fn session_call_track_all_currencies() {
+	ExtBuilder::default().build().execute_with(|| {
+		let ausd = CurrencyId::Token(TokenSymbol::AUSD);
+		let session_key = EvmAccountsModule::eth_address(&alice());
+		let session_account = EvmAddressMapping::<Runtime>::get_account_id(&session_key);
+		let transfer = |currency_id, amount| {
+			Box::new(Call::Currencies(orml_currencies::Call::transfer {
+				dest: ALICE,
+				currency_id,
+				amount,
+			}))
+		};
+		assert_ok!(Tokens::deposit(ausd, &bob_account_id(), 1000));
+
+		let scope = SessionKeyScope {
+			pallets: vec![<mock::Currencies as PalletInfoAccess>::index() as u8]
+				.try_into()
+				.unwrap(),
+			contracts: Default::default(),
+			spend_limits: vec![(GetNativeCurrencyId::get(), 150)].try_into().unwrap(),
+		};
+		assert_ok!(EvmAccountsModule::add_session_key(
+			Origin::signed(bob_account_id()),
+			session_key,
+			scope,
+			10
+		));
+
+		// currencies not in the spend limits can't be spent
+		assert_noop!(
+			EvmAccountsModule::session_call(
+				Origin::signed(session_account.clone()),
+				bob_account_id(),
+				transfer(ausd, 100)
+			),
+			Error::<Runtime>::SpendLimitExceeded
+		);
+
+		assert_ok!(EvmAccountsModule::session_call(
+			Origin::signed(session_account),
+			bob_account_id(),
+			transfer(GetNativeCurrencyId::get(), 100)
+		));
+		assert_eq!(Tokens::free_balance(ausd, &bob_account_id()), 1000);
+		assert_eq!(
+			EvmAccountsModule::session_keys(bob_account_id(), session_key)
+				.unwrap()
+				.spent
+				.into_inner(),
+			vec![(GetNativeCurrencyId::get(), 100)]
+		);
+	});
+}
@@ -19,7 +19,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::all)]
 
-use primitives::evm::{AccessListItem, BlockLimits, CallInfo, CreateInfo, EstimateResourcesRequest};
+use primitives::evm::{
+	AccessListItem, BlockLimits, BlockLogs, CallInfo, CreateInfo, EstimateResourcesRequest, LegacyBlockLimits,
+};
 use sp_core::H160;
 use sp_runtime::{
 	codec::Codec,
@@ -28,7 +30,7 @@ use sp_runtime::{
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(2)]
+	#[api_version(3)]
 	pub trait EVMRuntimeRPCApi<Balance> where
 		Balance: Codec + MaybeDisplay + MaybeFromStr,
 	{
@@ -55,6 +57,13 @@ sp_api::decl_runtime_apis! {
 
 		fn get_estimate_resources_request(data: Vec<u8>) -> Result<EstimateResourcesRequest, sp_runtime::DispatchError>;
 
+		#[changed_in(3)]
+		fn block_limits() -> LegacyBlockLimits;
+
 		fn block_limits() -> BlockLimits;
+
+		fn base_fee_per_gas() -> Balance;
+
+		fn block_logs() -> BlockLogs;
 	}
 }
@@ -40,11 +40,13 @@ use sp_runtime::{
 use std::{marker::PhantomData, sync::Arc};
 
 use call_request::{CallRequest, EstimateResourcesResponse};
+pub use logs::{index_block_logs, EvmLogs, EvmLogsApiServer, LogFilter, LogResponse};
 pub use module_evm::{ExitError, ExitReason};
 pub use module_evm_rpc_runtime_api::EVMRuntimeRPCApi;
 use primitives::evm::{BlockLimits, EstimateResourcesRequest};
 
 mod call_request;
+mod logs;
 
 /// EVM rpc interface.
 #[rpc(client, server)]
@@ -65,6 +67,10 @@ pub trait EVMApi<BlockHash> {
 	/// Get max gas and storage limits per transaction
 	#[method(name = "evm_blockLimits")]
 	fn block_limits(&self, at: Option<BlockHash>) -> RpcResult<BlockLimits>;
+
+	/// Get the base fee per gas of the given block
+	#[method(name = "evm_baseFeePerGas")]
+	fn base_fee_per_gas(&self, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
 }
 
 fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
@@ -534,20 +540,63 @@ where
 				))
 			})?;
 
-		let block_limits = if version > 1 {
+		let block_limits = if version > 2 {
 			self.client
 				.runtime_api()
 				.block_limits(&block_id)
 				.map_err(|e| internal_err(format!("runtime error: Unable to query block limits {:?}", e)))?
+		} else if version > 1 {
+			#[allow(deprecated)]
+			let legacy_block_limits = self
+				.client
+				.runtime_api()
+				.block_limits_before_version_3(&block_id)
+				.map_err(|e| internal_err(format!("runtime error: Unable to query block limits {:?}", e)))?;
+			BlockLimits {
+				max_gas_limit: legacy_block_limits.max_gas_limit,
+				max_storage_limit: legacy_block_limits.max_storage_limit,
+				base_fee_per_gas: 0,
+			}
 		} else {
 			BlockLimits {
 				max_gas_limit: 20_000_000,    // 20M
 				max_storage_limit: 4_194_304, // 4Mb
+				base_fee_per_gas: 0,
 			}
 		};
 
 		Ok(block_limits)
 	}
+
+	fn base_fee_per_gas(&self, at: Option<<B as BlockT>::Hash>) -> RpcResult<NumberOrHex> {
+		let hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let block_id = BlockId::Hash(hash);
+
+		let version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn EVMRuntimeRPCApi<B, Balance>>(&block_id)
+			.map_err(|err| internal_err(format!("runtime error: {:?}", err)))?
+			.ok_or_else(|| {
+				internal_err(format!(
+					"Could not find `EVMRuntimeRPCApi` api for block `{:?}`.",
+					&block_id
+				))
+			})?;
+
+		let base_fee_per_gas = if version > 2 {
+			self.client
+				.runtime_api()
+				.base_fee_per_gas(&block_id)
+				.map_err(|e| internal_err(format!("runtime error: Unable to query base fee per gas {:?}", e)))?
+				.into()
+		} else {
+			U256::zero()
+		};
+
+		Ok(NumberOrHex::Hex(base_fee_per_gas))
+	}
 }
 
 #[test]
@@ -0,0 +1,514 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! `eth_getLogs` compatible rpc, served from the EVM logs offchain storage maintained by
+//! `index_block_logs`.
+
+use crate::{internal_err, invalid_params};
+use futures::StreamExt;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use module_evm_rpc_runtime_api::EVMRuntimeRPCApi;
+use primitives::{
+	evm::{evm_logs_index_key, BlockLogs, IndexedLog, LogsBloom},
+	Balance,
+};
+use sc_client_api::{backend::Backend, BlockBackend, BlockchainEvents};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+	offchain::{OffchainStorage, STORAGE_PREFIX},
+	Bytes, Decode, Encode, H160, H256, U256,
+};
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, Hash as HashT, Header as HeaderT, UniqueSaturatedInto},
+};
+use std::{marker::PhantomData, sync::Arc};
+
+/// Max number of blocks can be queried in one request.
+pub const MAX_BLOCK_RANGE: u32 = 1_000;
+
+/// The first version of `EVMRuntimeRPCApi` with `block_logs`.
+const BLOCK_LOGS_API_VERSION: u32 = 3;
+
+/// A single value or a list of values.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum VariadicValue<T> {
+	Single(T),
+	Multiple(Vec<T>),
+}
+
+impl<T> VariadicValue<T> {
+	fn into_vec(self) -> Vec<T> {
+		match self {
+			VariadicValue::Single(v) => vec![v],
+			VariadicValue::Multiple(v) => v,
+		}
+	}
+}
+
+/// Log filter of `eth_getLogs`.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct LogFilter<BlockHash> {
+	/// The first block of the range, the best block if not provided.
+	pub from_block: Option<NumberOrHex>,
+	/// The last block of the range, the best block if not provided.
+	pub to_block: Option<NumberOrHex>,
+	/// Only return logs of the block, can't be used with `from_block` and `to_block`.
+	pub block_hash: Option<BlockHash>,
+	/// Contract addresses the logs should be emitted from.
+	pub address: Option<VariadicValue<H160>>,
+	/// Topics of the logs by position, `None` matches any topic.
+	pub topics: Option<Vec<Option<VariadicValue<H256>>>>,
+}
+
+/// Log returned by `eth_getLogs`, the same as the Ethereum log object. The transaction is the
+/// extrinsic which emitted the log, `None` if the log isn't emitted by an extrinsic.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogResponse<BlockHash> {
+	pub address: H160,
+	pub topics: Vec<H256>,
+	pub data: Bytes,
+	pub block_hash: BlockHash,
+	pub block_number: U256,
+	pub transaction_hash: Option<BlockHash>,
+	pub transaction_index: Option<U256>,
+	pub log_index: U256,
+	pub removed: bool,
+}
+
+/// Matcher of the logs created from a `LogFilter`.
+struct LogMatcher {
+	addresses: Vec<H160>,
+	topics: Vec<Vec<H256>>,
+}
+
+impl LogMatcher {
+	fn new<BlockHash>(filter: &LogFilter<BlockHash>) -> Self {
+		Self {
+			addresses: filter.address.clone().map(VariadicValue::into_vec).unwrap_or_default(),
+			topics: filter
+				.topics
+				.clone()
+				.unwrap_or_default()
+				.into_iter()
+				.map(|topics| topics.map(VariadicValue::into_vec).unwrap_or_default())
+				.collect(),
+		}
+	}
+
+	/// Check whether the block may contain logs matching the filter.
+	fn maybe_matches_bloom(&self, bloom: &LogsBloom) -> bool {
+		let addresses_match =
+			self.addresses.is_empty() || self.addresses.iter().any(|a| bloom.contains_input(a.as_bytes()));
+		addresses_match
+			&& self
+				.topics
+				.iter()
+				.all(|topics| topics.is_empty() || topics.iter().any(|t| bloom.contains_input(t.as_bytes())))
+	}
+
+	fn matches(&self, log: &IndexedLog) -> bool {
+		let log = &log.log;
+		if !self.addresses.is_empty() && !self.addresses.contains(&log.address) {
+			return false;
+		}
+		self.topics.iter().enumerate().all(|(position, topics)| {
+			topics.is_empty() || log.topics.get(position).map_or(false, |topic| topics.contains(topic))
+		})
+	}
+
+	/// Returns the logs of the block matching the filter, `transaction_hash` returns the hash of
+	/// the extrinsic at the index.
+	fn filter_block_logs<BlockHash: Copy>(
+		&self,
+		block_number: u32,
+		block_hash: BlockHash,
+		block_logs: BlockLogs,
+		transaction_hash: impl Fn(u32) -> Option<BlockHash>,
+	) -> Vec<LogResponse<BlockHash>> {
+		if !self.maybe_matches_bloom(&block_logs.bloom) {
+			return vec![];
+		}
+
+		block_logs
+			.logs
+			.into_iter()
+			.enumerate()
+			.filter(|(_, indexed_log)| self.matches(indexed_log))
+			.map(|(log_index, indexed_log)| LogResponse {
+				address: indexed_log.log.address,
+				topics: indexed_log.log.topics,
+				data: indexed_log.log.data.into(),
+				block_hash,
+				block_number: block_number.into(),
+				transaction_hash: indexed_log.extrinsic_index.and_then(&transaction_hash),
+				transaction_index: indexed_log.extrinsic_index.map(Into::into),
+				log_index: log_index.into(),
+				removed: false,
+			})
+			.collect()
+	}
+}
+
+/// Index the EVM logs of the imported blocks in the offchain storage by block hash, so the logs
+/// of all forks are kept. Runs until the import notification stream ends.
+pub async fn index_block_logs<B, C, BE>(client: Arc<C>, backend: Arc<BE>)
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + BlockchainEvents<B> + Send + Sync + 'static,
+	C::Api: EVMRuntimeRPCApi<B, Balance>,
+	BE: Backend<B> + Send + Sync + 'static,
+{
+	let mut storage = match backend.offchain_storage() {
+		Some(storage) => storage,
+		None => {
+			log::warn!(target: "evm", "offchain storage is not available, EVM logs are not indexed");
+			return;
+		}
+	};
+
+	let mut notifications = client.import_notification_stream();
+	while let Some(notification) = notifications.next().await {
+		let block_id = BlockId::Hash(notification.hash);
+		let has_block_logs = client
+			.runtime_api()
+			.api_version::<dyn EVMRuntimeRPCApi<B, Balance>>(&block_id)
+			.ok()
+			.flatten()
+			.map_or(false, |version| version >= BLOCK_LOGS_API_VERSION);
+		if !has_block_logs {
+			continue;
+		}
+
+		match client.runtime_api().block_logs(&block_id) {
+			Ok(block_logs) => storage.set(
+				STORAGE_PREFIX,
+				&evm_logs_index_key(notification.hash.as_ref()),
+				&block_logs.encode(),
+			),
+			Err(e) => log::warn!(
+				target: "evm",
+				"unable to index EVM logs of block {:?}: {:?}",
+				notification.hash,
+				e
+			),
+		}
+	}
+}
+
+/// EVM logs rpc interface.
+#[rpc(client, server)]
+pub trait EvmLogsApi<BlockHash> {
+	/// Returns the logs matching the filter. The logs are indexed by `index_block_logs`, logs of
+	/// blocks not indexed are read from the state if available, otherwise the blocks are skipped.
+	#[method(name = "eth_getLogs")]
+	fn get_logs(&self, filter: LogFilter<BlockHash>) -> RpcResult<Vec<LogResponse<BlockHash>>>;
+}
+
+/// EVM logs rpc implementation.
+pub struct EvmLogs<B, C, BE> {
+	client: Arc<C>,
+	backend: Arc<BE>,
+	_marker: PhantomData<B>,
+}
+
+impl<B, C, BE> EvmLogs<B, C, BE> {
+	pub fn new(client: Arc<C>, backend: Arc<BE>) -> Self {
+		Self {
+			client,
+			backend,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn to_block_number(val: NumberOrHex) -> RpcResult<u32> {
+	val.into_u256()
+		.try_into()
+		.map_err(|_| invalid_params("block number overflow"))
+}
+
+impl<B, C, BE> EvmLogs<B, C, BE>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + Send + Sync + 'static,
+	C::Api: EVMRuntimeRPCApi<B, Balance>,
+	BE: Backend<B> + Send + Sync + 'static,
+{
+	/// The logs of the block from the offchain storage, or the state if the block is not indexed.
+	fn block_logs(&self, storage: &Option<BE::OffchainStorage>, hash: <B as BlockT>::Hash) -> Option<BlockLogs> {
+		let indexed = storage
+			.as_ref()
+			.and_then(|storage| storage.get(STORAGE_PREFIX, &evm_logs_index_key(hash.as_ref())))
+			.and_then(|encoded| BlockLogs::decode(&mut &encoded[..]).ok());
+		if indexed.is_some() {
+			return indexed;
+		}
+
+		let block_id = BlockId::Hash(hash);
+		let version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn EVMRuntimeRPCApi<B, Balance>>(&block_id)
+			.ok()
+			.flatten()?;
+		if version < BLOCK_LOGS_API_VERSION {
+			return None;
+		}
+		self.client.runtime_api().block_logs(&block_id).ok()
+	}
+}
+
+impl<B, C, BE> EvmLogsApiServer<<B as BlockT>::Hash> for EvmLogs<B, C, BE>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + BlockBackend<B> + Send + Sync + 'static,
+	C::Api: EVMRuntimeRPCApi<B, Balance>,
+	BE: Backend<B> + Send + Sync + 'static,
+{
+	fn get_logs(&self, filter: LogFilter<<B as BlockT>::Hash>) -> RpcResult<Vec<LogResponse<<B as BlockT>::Hash>>> {
+		let (from_block, to_block) = if let Some(hash) = filter.block_hash {
+			if filter.from_block.is_some() || filter.to_block.is_some() {
+				return Err(invalid_params(
+					"`blockHash` can't be used with `fromBlock` or `toBlock`",
+				));
+			}
+			let header = self
+				.client
+				.header(BlockId::Hash(hash))
+				.map_err(|e| internal_err(format!("blockchain error: {:?}", e)))?
+				.ok_or_else(|| invalid_params(format!("unknown block `{:?}`", hash)))?;
+			let number: u32 = (*header.number()).unique_saturated_into();
+			(number, number)
+		} else {
+			let best_number: u32 = self.client.info().best_number.unique_saturated_into();
+			let from_block = filter.from_block.clone().map_or(Ok(best_number), to_block_number)?;
+			let to_block = filter
+				.to_block
+				.clone()
+				.map_or(Ok(best_number), to_block_number)?
+				.min(best_number);
+			(from_block, to_block)
+		};
+
+		if from_block > to_block {
+			return Ok(vec![]);
+		}
+		if to_block - from_block >= MAX_BLOCK_RANGE {
+			return Err(invalid_params(format!(
+				"block range is too large, max range is {}",
+				MAX_BLOCK_RANGE
+			)));
+		}
+
+		let storage = self.backend.offchain_storage();
+		let matcher = LogMatcher::new(&filter);
+
+		let mut result = vec![];
+		for number in from_block..=to_block {
+			let hash = match filter.block_hash {
+				Some(hash) => hash,
+				None => self
+					.client
+					.hash(number.into())
+					.map_err(|e| internal_err(format!("blockchain error: {:?}", e)))?
+					.ok_or_else(|| internal_err(format!("unknown block number `{}`", number)))?,
+			};
+
+			let block_logs = match self.block_logs(&storage, hash) {
+				Some(block_logs) => block_logs,
+				None => {
+					log::warn!(
+						target: "evm",
+						"eth_getLogs skipped block {:?}, its logs are neither indexed nor in the state",
+						hash
+					);
+					continue;
+				}
+			};
+			if block_logs.logs.is_empty() {
+				continue;
+			}
+
+			let extrinsics = self.client.block_body(&BlockId::Hash(hash)).ok().flatten();
+			let transaction_hash = |extrinsic_index: u32| {
+				extrinsics
+					.as_ref()?
+					.get(extrinsic_index as usize)
+					.map(<<B as BlockT>::Header as HeaderT>::Hashing::hash_of)
+			};
+			result.extend(matcher.filter_block_logs(number, hash, block_logs, transaction_hash));
+		}
+
+		Ok(result)
+	}
+}
+
+#[test]
+fn log_matcher_works() {
+	let address = H160::repeat_byte(1);
+	let topic = H256::repeat_byte(2);
+	let indexed_log = IndexedLog {
+		extrinsic_index: Some(1),
+		log: primitives::evm::Log {
+			address,
+			topics: vec![topic, H256::repeat_byte(3)],
+			data: vec![],
+		},
+	};
+	let mut bloom = LogsBloom::default();
+	bloom.accrue_log(&indexed_log.log);
+
+	let filter = LogFilter::<H256>::default();
+	assert!(LogMatcher::new(&filter).matches(&indexed_log));
+
+	let filter = LogFilter::<H256> {
+		address: Some(VariadicValue::Multiple(vec![H160::repeat_byte(4), address])),
+		topics: Some(vec![None, Some(VariadicValue::Single(H256::repeat_byte(3)))]),
+		..Default::default()
+	};
+	assert!(LogMatcher::new(&filter).matches(&indexed_log));
+	assert!(LogMatcher::new(&filter).maybe_matches_bloom(&bloom));
+
+	let filter = LogFilter::<H256> {
+		topics: Some(vec![Some(VariadicValue::Single(H256::repeat_byte(3)))]),
+		..Default::default()
+	};
+	assert!(!LogMatcher::new(&filter).matches(&indexed_log));
+
+	let filter = LogFilter::<H256> {
+		address: Some(VariadicValue::Single(H160::repeat_byte(4))),
+		..Default::default()
+	};
+	assert!(!LogMatcher::new(&filter).matches(&indexed_log));
+}
+
+#[test]
+fn filter_block_logs_works() {
+	let log = |address: H160, topic: H256| primitives::evm::Log {
+		address,
+		topics: vec![topic],
+		data: vec![1],
+	};
+	let logs = vec![
+		IndexedLog {
+			extrinsic_index: Some(0),
+			log: log(H160::repeat_byte(1), H256::repeat_byte(1)),
+		},
+		IndexedLog {
+			extrinsic_index: Some(1),
+			log: log(H160::repeat_byte(2), H256::repeat_byte(2)),
+		},
+		IndexedLog {
+			extrinsic_index: None,
+			log: log(H160::repeat_byte(1), H256::repeat_byte(3)),
+		},
+	];
+	let mut bloom = LogsBloom::default();
+	logs.iter().for_each(|indexed_log| bloom.accrue_log(&indexed_log.log));
+	let block_logs = BlockLogs { bloom, logs };
+	let block_hash = H256::repeat_byte(0xff);
+	let transaction_hash = |index: u32| Some(H256::from_low_u64_be(index.into()));
+
+	let filter = LogFilter::<H256> {
+		address: Some(VariadicValue::Single(H160::repeat_byte(1))),
+		..Default::default()
+	};
+	assert_eq!(
+		LogMatcher::new(&filter).filter_block_logs(10, block_hash, block_logs.clone(), transaction_hash),
+		vec![
+			LogResponse {
+				address: H160::repeat_byte(1),
+				topics: vec![H256::repeat_byte(1)],
+				data: vec![1].into(),
+				block_hash,
+				block_number: 10.into(),
+				transaction_hash: Some(H256::from_low_u64_be(0)),
+				transaction_index: Some(0.into()),
+				log_index: 0.into(),
+				removed: false,
+			},
+			LogResponse {
+				address: H160::repeat_byte(1),
+				topics: vec![H256::repeat_byte(3)],
+				data: vec![1].into(),
+				block_hash,
+				block_number: 10.into(),
+				transaction_hash: None,
+				transaction_index: None,
+				log_index: 2.into(),
+				removed: false,
+			},
+		]
+	);
+
+	// the log index is the index in the block
+	let filter = LogFilter::<H256> {
+		topics: Some(vec![Some(VariadicValue::Single(H256::repeat_byte(2)))]),
+		..Default::default()
+	};
+	let result = LogMatcher::new(&filter).filter_block_logs(10, block_hash, block_logs.clone(), transaction_hash);
+	assert_eq!(result.len(), 1);
+	assert_eq!(result[0].log_index, 1.into());
+	assert_eq!(result[0].transaction_index, Some(1.into()));
+
+	// skipped by the bloom filter
+	let filter = LogFilter::<H256> {
+		address: Some(VariadicValue::Single(H160::repeat_byte(4))),
+		..Default::default()
+	};
+	assert!(LogMatcher::new(&filter)
+		.filter_block_logs(10, block_hash, block_logs, transaction_hash)
+		.is_empty());
+}
+
+#[test]
+fn log_response_is_eth_compatible() {
+	let response = LogResponse {
+		address: H160::repeat_byte(1),
+		topics: vec![H256::repeat_byte(2)],
+		data: vec![1, 2].into(),
+		block_hash: H256::repeat_byte(3),
+		block_number: 16.into(),
+		transaction_hash: Some(H256::repeat_byte(4)),
+		transaction_index: Some(1.into()),
+		log_index: 2.into(),
+		removed: false,
+	};
+	assert_eq!(
+		serde_json::to_value(&response).unwrap(),
+		serde_json::json!({
+			"address": "0x0101010101010101010101010101010101010101",
+			"topics": ["0x0202020202020202020202020202020202020202020202020202020202020202"],
+			"data": "0x0102",
+			"blockHash": "0x0303030303030303030303030303030303030303030303030303030303030303",
+			"blockNumber": "0x10",
+			"transactionHash": "0x0404040404040404040404040404040404040404040404040404040404040404",
+			"transactionIndex": "0x1",
+			"logIndex": "0x2",
+			"removed": false,
+		})
+	);
+}
@@ -28,7 +28,7 @@ use frame_support::{
 };
 use frame_system::EnsureSignedBy;
 use module_support::{
-	mocks::{MockAddressMapping, MockErc20InfoMapping},
+	mocks::{MockAddressMapping, MockErc20InfoMapping, MockStableAsset},
 	DEXIncentives, Price, PriceProvider, SpecificJointsSwap,
 };
 use orml_traits::{parameter_type_with_key, MultiReservableCurrency};
@@ -153,6 +153,13 @@ impl module_idle_scheduler::Config for Runtime {
 	type MinimumWeightRemainInBlock = ConstU64<0>;
 	type RelayChainBlockNumberProvider = MockBlockNumberProvider;
 	type DisableBlockThreshold = ConstU32<6>;
+	type MaxRetries = ConstU32<3>;
+	type RetryBackoff = ConstU32<10>;
+	type Currency = Balances;
+	type RecurringTaskDeposit = ConstU128<10>;
+	type RecurringTaskFilter = Nothing;
+	type MaxRecurringTasks = ConstU32<10>;
+	type MaxTaskHistory = ConstU32<10>;
 }
 
 pub struct GasToWeight;
@@ -190,6 +197,8 @@ impl Config for Runtime {
 	type NewContractExtraBytes = ConstU32<100>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = ConstU128<20_000_000>;
+	type BaseFeeTargetGas = ConstU64<10_000_000>;
+	type MinBaseFeePerGas = ConstU128<0>;
 
 	type Event = Event;
 	type PrecompilesType = ();
@@ -203,11 +212,14 @@ impl Config for Runtime {
 	type PublicationFee = ConstU128<200>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
+	type AbandonedContractTimeout = ConstU32<100>;
+	type CodeUpgradeDelay = ConstU32<10>;
 
 	type Runner = crate::runner::stack::Runner<Self>;
 	type FindAuthor = AuthorGiven;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type CallFilter = ();
 	type WeightInfo = ();
 }
 
@@ -253,6 +265,8 @@ impl module_transaction_payment::Config for Runtime {
 	type TransactionByteFee = ConstU128<10>;
 	type FeeMultiplierUpdate = ();
 	type Swap = SpecificJointsSwap<Dex, AlternativeSwapPathJointList>;
+	type DEX = Dex;
+	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId32, BlockNumber>;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = MockPriceSource;
@@ -263,6 +277,11 @@ impl module_transaction_payment::Config for Runtime {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type AddressMapping = MockAddressMapping;
+	type SponsoredTargetOf = ();
+	type MaxSponsoredTargets = ConstU32<10>;
+	type DynamicBaseFee = ();
+	type OnDynamicBaseFee = ();
 }
 
 pub struct MockDEXIncentives;
@@ -294,6 +313,8 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId32>;
 	type ExtendedProvisioningBlocks = ConstU32<0>;
 	type OnLiquidityPoolUpdated = ();
+	type CircuitBreaker = ();
+	type CircuitBreakerExemptAccounts = Nothing;
 }
 
 pub type SignedExtra = (frame_system::CheckWeight<Runtime>,);
@@ -52,17 +52,17 @@ pub use module_evm_utility::{
 	Account,
 };
 pub use module_support::{
-	AddressMapping, DispatchableTask, EVMManager, ExecutionMode, IdleScheduler, InvokeContext, TransactionPayment,
-	EVM as EVMTrait,
+	AddressMapping, DispatchableTask, EVMAccountMigration, EVMManager, EvmCallFilter, ExecutionMode, IdleScheduler,
+	InvokeContext, TransactionPayment, EVM as EVMTrait,
 };
 pub use orml_traits::{currency::TransferAll, MultiCurrency};
 use primitive_types::{H160, H256, U256};
 pub use primitives::{
 	evm::{
-		convert_decimals_from_evm, convert_decimals_to_evm, CallInfo, CreateInfo, EvmAddress, ExecutionInfo, Vicinity,
-		MIRRORED_NFT_ADDRESS_START, MIRRORED_TOKENS_ADDRESS_START,
+		convert_decimals_from_evm, convert_decimals_to_evm, BlockLogs, CallInfo, CreateInfo, EvmAddress, ExecutionInfo,
+		IndexedLog, LogsBloom, Vicinity, MIRRORED_NFT_ADDRESS_START, MIRRORED_TOKENS_ADDRESS_START,
 	},
-	task::TaskResult,
+	task::{TaskPriority, TaskResult},
 	Balance, CurrencyId, ReserveIdentifier,
 };
 use scale_info::TypeInfo;
@@ -70,12 +70,15 @@ use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Keccak256};
 use sp_runtime::{
-	traits::{Convert, DispatchInfoOf, One, PostDispatchInfoOf, SignedExtension, UniqueSaturatedInto, Zero},
+	traits::{
+		Convert, DispatchInfoOf, One, PostDispatchInfoOf, Saturating, SignedExtension, UniqueSaturatedInto, Zero,
+	},
 	transaction_validity::TransactionValidityError,
-	Either, TransactionOutcome,
+	Either, FixedPointNumber, FixedU128, PerThing, Perquintill, TransactionOutcome,
 };
 use sp_std::{cmp, collections::btree_map::BTreeMap, fmt::Debug, marker::PhantomData, prelude::*};
 
+pub mod migrations;
 pub mod precompiles;
 pub mod runner;
 
@@ -112,12 +115,24 @@ pub const BASE_CREATE_GAS: u64 = 67_066;
 /// Call function that just set a storage `function store(uint256 num) public { number = num; }`.
 pub const BASE_CALL_GAS: u64 = 43_702;
 
+/// Bounds the amount the base fee can change between blocks, same as EIP-1559.
+pub const BASE_FEE_MAX_CHANGE_DENOMINATOR: u32 = 8;
+
+/// The gas used to convert the base fee per gas to the base fee per weight.
+const BASE_FEE_SAMPLE_GAS: u64 = 1_000_000;
+
+/// Helper method to calculate the weight of recording the gas used in `BlockGasUsed`.
+fn record_gas_used_weight<T: Config>() -> Weight {
+	<T as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+}
+
 /// Helper method to calculate `create` weight.
 fn create_weight<T: Config>(gas: u64) -> Weight {
 	<T as Config>::WeightInfo::create()
 		// during `create` benchmark an additional of `BASE_CREATE_GAS` was used
 		// so user will be extra charged only for extra gas usage
 		.saturating_add(T::GasToWeight::convert(gas.saturating_sub(BASE_CREATE_GAS)))
+		.saturating_add(record_gas_used_weight::<T>())
 }
 
 /// Helper method to calculate `create2` weight.
@@ -126,6 +141,7 @@ fn create2_weight<T: Config>(gas: u64) -> Weight {
 		// during `create2` benchmark an additional of `BASE_CREATE_GAS` was used
 		// so user will be extra charged only for extra gas usage
 		.saturating_add(T::GasToWeight::convert(gas.saturating_sub(BASE_CREATE_GAS)))
+		.saturating_add(record_gas_used_weight::<T>())
 }
 
 /// Helper method to calculate `create_predeploy_contract` weight.
@@ -134,6 +150,7 @@ fn create_predeploy_contract<T: Config>(gas: u64) -> Weight {
 		// during `create_predeploy_contract` benchmark an additional of `BASE_CREATE_GAS`
 		// was used so user will be extra charged only for extra gas usage
 		.saturating_add(T::GasToWeight::convert(gas.saturating_sub(BASE_CREATE_GAS)))
+		.saturating_add(record_gas_used_weight::<T>())
 }
 
 /// Helper method to calculate `create_nft_contract` weight.
@@ -142,6 +159,7 @@ fn create_nft_contract<T: Config>(gas: u64) -> Weight {
 		// during `create_nft_contract` benchmark an additional of `BASE_CREATE_GAS`
 		// was used so user will be extra charged only for extra gas usage
 		.saturating_add(T::GasToWeight::convert(gas.saturating_sub(BASE_CREATE_GAS)))
+		.saturating_add(record_gas_used_weight::<T>())
 }
 
 /// Helper method to calculate `call` weight.
@@ -150,6 +168,7 @@ fn call_weight<T: Config>(gas: u64) -> Weight {
 		// during `call` benchmark an additional of `BASE_CALL_GAS` was used
 		// so user will be extra charged only for extra gas usage
 		.saturating_add(T::GasToWeight::convert(gas.saturating_sub(BASE_CALL_GAS)))
+		.saturating_add(record_gas_used_weight::<T>())
 }
 
 #[frame_support::pallet]
@@ -191,6 +210,14 @@ pub mod module {
 		#[pallet::constant]
 		type TxFeePerGas: Get<BalanceOf<Self>>;
 
+		/// The EVM gas usage per block the base fee is adjusted towards.
+		#[pallet::constant]
+		type BaseFeeTargetGas: Get<u64>;
+
+		/// The minimum base fee per gas, in native decimals.
+		#[pallet::constant]
+		type MinBaseFeePerGas: Get<BalanceOf<Self>>;
+
 		/// The overarching event type.
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 
@@ -229,6 +256,14 @@ pub mod module {
 
 		type FreePublicationOrigin: EnsureOrigin<Self::Origin>;
 
+		/// The period after which an unpublished contract can be removed by governance.
+		#[pallet::constant]
+		type AbandonedContractTimeout: Get<Self::BlockNumber>;
+
+		/// The delay before a proposed code upgrade can be enacted.
+		#[pallet::constant]
+		type CodeUpgradeDelay: Get<Self::BlockNumber>;
+
 		/// EVM execution runner.
 		type Runner: Runner<Self>;
 
@@ -241,6 +276,9 @@ pub mod module {
 		/// Idle scheduler for the evm task.
 		type IdleScheduler: IdleScheduler<Self::Task>;
 
+		/// Filter of the paused calls, checked on every call including the nested ones.
+		type CallFilter: EvmCallFilter;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -270,6 +308,16 @@ pub mod module {
 		pub ref_count: u32,
 	}
 
+	#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+	pub struct CodeUpgrade<BlockNumber> {
+		pub code_hash: H256,
+		pub effective_at: BlockNumber,
+		/// The proposer who reserved the storage deposit for the proposed code.
+		pub proposer: EvmAddress,
+		/// The code size the storage deposit is reserved for.
+		pub code_size: u32,
+	}
+
 	#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, Default)]
 	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	/// Account definition used for genesis block construction.
@@ -346,6 +394,49 @@ pub mod module {
 	#[pallet::getter(fn extrinsic_origin)]
 	pub type ExtrinsicOrigin<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// The block number at which the unpublished contracts were created.
+	///
+	/// UnpublishedContracts: map EvmAddress => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn unpublished_contracts)]
+	pub type UnpublishedContracts<T: Config> = StorageMap<_, Twox64Concat, EvmAddress, T::BlockNumber, OptionQuery>;
+
+	/// The block number since which the creation block of unpublished contracts is tracked, set
+	/// by the v1 migration. Unpublished contracts created before it are treated as created at it.
+	///
+	/// UnpublishedContractsTrackedSince: Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn unpublished_contracts_tracked_since)]
+	pub type UnpublishedContractsTrackedSince<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	/// The pending code upgrades of contracts.
+	///
+	/// CodeUpgrades: map EvmAddress => Option<CodeUpgrade<BlockNumber>>
+	#[pallet::storage]
+	#[pallet::getter(fn code_upgrades)]
+	pub type CodeUpgrades<T: Config> =
+		StorageMap<_, Twox64Concat, EvmAddress, CodeUpgrade<T::BlockNumber>, OptionQuery>;
+
+	/// The audited code hashes network contracts are allowed to be upgraded to.
+	///
+	/// AuditedCodeHashes: map H256 => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn audited_code_hashes)]
+	pub type AuditedCodeHashes<T: Config> = StorageMap<_, Identity, H256, (), OptionQuery>;
+
+	/// The base fee per gas charged for EVM transactions, in native decimals.
+	///
+	/// BaseFeePerGas: Balance
+	#[pallet::storage]
+	pub type BaseFeePerGas<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The total gas used by EVM transactions in the current block.
+	///
+	/// BlockGasUsed: u64
+	#[pallet::storage]
+	#[pallet::getter(fn block_gas_used)]
+	pub type BlockGasUsed<T: Config> = StorageValue<_, u64, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub chain_id: u64,
@@ -489,6 +580,26 @@ pub mod module {
 		ContractSetCode { contract: EvmAddress },
 		/// Selfdestructed contract code.
 		ContractSelfdestructed { contract: EvmAddress },
+		/// The base fee per gas has been updated.
+		BaseFeePerGasUpdated { base_fee_per_gas: BalanceOf<T> },
+		/// Removed an abandoned unpublished contract.
+		AbandonedContractRemoved { contract: EvmAddress },
+		/// Proposed a code upgrade for a contract.
+		CodeUpgradeProposed {
+			contract: EvmAddress,
+			code_hash: H256,
+			effective_at: T::BlockNumber,
+		},
+		/// Enacted a code upgrade for a contract.
+		CodeUpgradeEnacted { contract: EvmAddress, code_hash: H256 },
+		/// Vetoed a code upgrade for a contract.
+		CodeUpgradeVetoed { contract: EvmAddress, code_hash: H256 },
+		/// Dropped a code upgrade which is no longer allowed for a contract.
+		CodeUpgradeDropped { contract: EvmAddress, code_hash: H256 },
+		/// Added a code hash to the audited code hashes.
+		AuditedCodeHashAdded { code_hash: H256 },
+		/// Removed a code hash from the audited code hashes.
+		AuditedCodeHashRemoved { code_hash: H256 },
 	}
 
 	#[pallet::error]
@@ -525,14 +636,46 @@ pub mod module {
 		InvalidDecimals,
 		/// Strict call failed
 		StrictCallFailed,
+		/// Contract is not abandoned yet
+		ContractNotAbandoned,
+		/// No pending code upgrade for the contract
+		CodeUpgradeNotFound,
+		/// The code upgrade is not effective yet
+		CodeUpgradeNotEffective,
+		/// The code hash is not audited
+		CodeHashNotAudited,
+		/// The call is paused
+		CallPaused,
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(_: T::BlockNumber) -> Weight {
+			// `on_finalize` reads and writes `BlockGasUsed` and `BaseFeePerGas`
+			<T as frame_system::Config>::DbWeight::get().reads_writes(2, 2)
+		}
+
+		fn on_finalize(_: T::BlockNumber) {
+			let gas_used = BlockGasUsed::<T>::take();
+			let base_fee_per_gas = Self::base_fee_per_gas();
+			let next_base_fee_per_gas = Self::next_base_fee_per_gas(base_fee_per_gas, gas_used);
+
+			if next_base_fee_per_gas != base_fee_per_gas {
+				BaseFeePerGas::<T>::put(next_base_fee_per_gas);
+				Self::deposit_event(Event::<T>::BaseFeePerGasUpdated {
+					base_fee_per_gas: next_base_fee_per_gas,
+				});
+			}
+		}
+
 		fn integrity_test() {
 			assert!(convert_decimals_from_evm(T::StorageDepositPerByte::get()).is_some());
 		}
@@ -606,11 +749,13 @@ pub mod module {
 						used_gas: gas_limit,
 						used_storage: Default::default(),
 					});
+					Self::record_gas_used(gas_limit);
 
 					Ok(().into())
 				}
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
+					Self::record_gas_used(used_gas);
 
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Executed {
@@ -649,7 +794,7 @@ pub mod module {
 		/// - `value`: the amount sent for payable calls
 		/// - `gas_limit`: the maximum gas the call can use
 		/// - `storage_limit`: the total bytes the contract's storage can increase by
-		#[pallet::weight(T::GasToWeight::convert(*gas_limit))]
+		#[pallet::weight(T::GasToWeight::convert(*gas_limit).saturating_add(record_gas_used_weight::<T>()))]
 		#[transactional]
 		// TODO: create benchmark
 		pub fn scheduled_call(
@@ -696,11 +841,13 @@ pub mod module {
 						used_gas: gas_limit,
 						used_storage: Default::default(),
 					});
+					Self::record_gas_used(gas_limit);
 
 					Ok(().into())
 				}
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
+					Self::record_gas_used(used_gas);
 
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Executed {
@@ -784,11 +931,13 @@ pub mod module {
 						used_gas: gas_limit,
 						used_storage: Default::default(),
 					});
+					Self::record_gas_used(gas_limit);
 
 					Ok(().into())
 				}
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
+					Self::record_gas_used(used_gas);
 
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Created {
@@ -858,11 +1007,13 @@ pub mod module {
 						used_gas: gas_limit,
 						used_storage: Default::default(),
 					});
+					Self::record_gas_used(gas_limit);
 
 					Ok(().into())
 				}
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
+					Self::record_gas_used(used_gas);
 
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Created {
@@ -944,11 +1095,13 @@ pub mod module {
 						used_gas: gas_limit,
 						used_storage: Default::default(),
 					});
+					Self::record_gas_used(gas_limit);
 
 					Ok(().into())
 				}
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
+					Self::record_gas_used(used_gas);
 
 					if info.exit_reason.is_succeed() {
 						NetworkContractIndex::<T>::mutate(|v| *v = v.saturating_add(One::one()));
@@ -1034,11 +1187,13 @@ pub mod module {
 						used_gas: gas_limit,
 						used_storage: Default::default(),
 					});
+					Self::record_gas_used(gas_limit);
 
 					Ok(().into())
 				}
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
+					Self::record_gas_used(used_gas);
 					let contract = info.value;
 
 					if info.exit_reason.is_succeed() {
@@ -1147,7 +1302,8 @@ pub mod module {
 			Ok(().into())
 		}
 
-		/// Set the code of a contract at a given address.
+		/// Set the code of a contract at a given address. The maintainer of a published contract
+		/// upgrades it through `propose_code_upgrade` instead.
 		///
 		/// - `contract`: The contract whose code is being set, must not be marked as published
 		/// - `code`: The new ABI bundle for the contract
@@ -1177,6 +1333,110 @@ pub mod module {
 			Ok(().into())
 		}
 
+		/// Remove an unpublished contract which is not published within
+		/// `AbandonedContractTimeout` after creation. The contract storages are removed
+		/// lazily by the idle scheduler, and the storage deposit is refunded to the maintainer.
+		///
+		/// - `contract`: The abandoned contract to remove, must not be marked as published
+		#[pallet::weight(<T as Config>::WeightInfo::remove_abandoned_contract())]
+		#[transactional]
+		pub fn remove_abandoned_contract(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
+			T::NetworkContractOrigin::ensure_origin(origin)?;
+			Self::do_remove_abandoned_contract(&contract)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::AbandonedContractRemoved { contract });
+
+			Ok(().into())
+		}
+
+		/// Propose a time-locked code upgrade for a contract. The upgrade can be enacted after
+		/// `CodeUpgradeDelay`, and can be vetoed by governance before that. Upgrades of network
+		/// contracts can only be proposed by root, and the code hash must be audited. The storage
+		/// deposit for the proposed code is reserved from the proposer until the upgrade is
+		/// enacted, vetoed or replaced.
+		///
+		/// - `contract`: The contract whose code is being upgraded, the caller must be the
+		///   maintainer of the contract
+		/// - `code`: The new code for the contract
+		#[pallet::weight(<T as Config>::WeightInfo::propose_code_upgrade(code.len() as u32))]
+		#[transactional]
+		pub fn propose_code_upgrade(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			code: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let root_or_signed = Self::ensure_root_or_signed(origin)?;
+			let (code_hash, effective_at) = Self::do_propose_code_upgrade(root_or_signed, contract, code)?;
+
+			Pallet::<T>::deposit_event(Event::<T>::CodeUpgradeProposed {
+				contract,
+				code_hash,
+				effective_at,
+			});
+
+			Ok(().into())
+		}
+
+		/// Enact a pending code upgrade after it becomes effective. Anyone can call this. The
+		/// upgrade is dropped instead if its code hash is no longer allowed.
+		///
+		/// - `contract`: The contract with a pending code upgrade
+		#[pallet::weight(<T as Config>::WeightInfo::enact_code_upgrade())]
+		#[transactional]
+		pub fn enact_code_upgrade(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let (code_hash, enacted) = Self::do_enact_code_upgrade(contract)?;
+
+			if enacted {
+				Pallet::<T>::deposit_event(Event::<T>::CodeUpgradeEnacted { contract, code_hash });
+			} else {
+				Pallet::<T>::deposit_event(Event::<T>::CodeUpgradeDropped { contract, code_hash });
+			}
+
+			Ok(().into())
+		}
+
+		/// Veto a pending code upgrade.
+		///
+		/// - `contract`: The contract with a pending code upgrade
+		#[pallet::weight(<T as Config>::WeightInfo::veto_code_upgrade())]
+		#[transactional]
+		pub fn veto_code_upgrade(origin: OriginFor<T>, contract: EvmAddress) -> DispatchResultWithPostInfo {
+			T::NetworkContractOrigin::ensure_origin(origin)?;
+			let upgrade = CodeUpgrades::<T>::take(contract).ok_or(Error::<T>::CodeUpgradeNotFound)?;
+			let code_hash = upgrade.code_hash;
+			Self::release_code_upgrade(upgrade);
+
+			Pallet::<T>::deposit_event(Event::<T>::CodeUpgradeVetoed { contract, code_hash });
+
+			Ok(().into())
+		}
+
+		/// Add or remove a code hash from the audited code hashes network contracts are allowed
+		/// to be upgraded to.
+		///
+		/// - `code_hash`: The code hash
+		/// - `audited`: Whether the code hash is audited
+		#[pallet::weight(<T as Config>::WeightInfo::set_audited_code_hash())]
+		#[transactional]
+		pub fn set_audited_code_hash(
+			origin: OriginFor<T>,
+			code_hash: H256,
+			audited: bool,
+		) -> DispatchResultWithPostInfo {
+			T::NetworkContractOrigin::ensure_origin(origin)?;
+
+			if audited {
+				AuditedCodeHashes::<T>::insert(code_hash, ());
+				Pallet::<T>::deposit_event(Event::<T>::AuditedCodeHashAdded { code_hash });
+			} else {
+				AuditedCodeHashes::<T>::remove(code_hash);
+				Pallet::<T>::deposit_event(Event::<T>::AuditedCodeHashRemoved { code_hash });
+			}
+
+			Ok(().into())
+		}
+
 		/// Issue an EVM call operation in `Utility::batch_all`. This is same as the evm.call but
 		/// returns error when it failed. The current evm.call always success and emit event to
 		/// indicate it failed.
@@ -1217,6 +1477,7 @@ pub mod module {
 				}),
 				Ok(info) => {
 					let used_gas: u64 = info.used_gas.unique_saturated_into();
+					Self::record_gas_used(used_gas);
 
 					if info.exit_reason.is_succeed() {
 						Pallet::<T>::deposit_event(Event::<T>::Executed {
@@ -1259,6 +1520,83 @@ impl<T: Config> Pallet<T> {
 		convert_decimals_from_evm(T::StorageDepositPerByte::get()).expect("checked in integrity_test; qed")
 	}
 
+	/// Get the base fee per gas in native decimals, never less than `MinBaseFeePerGas`.
+	pub fn base_fee_per_gas() -> BalanceOf<T> {
+		BaseFeePerGas::<T>::get().max(T::MinBaseFeePerGas::get())
+	}
+
+	/// Calculate the base fee per gas for the next block, moving it towards
+	/// `BaseFeeTargetGas` by at most 1/`BASE_FEE_MAX_CHANGE_DENOMINATOR` per block.
+	pub fn next_base_fee_per_gas(base_fee_per_gas: BalanceOf<T>, gas_used: u64) -> BalanceOf<T> {
+		let target = T::BaseFeeTargetGas::get();
+		if target.is_zero() {
+			return base_fee_per_gas.max(T::MinBaseFeePerGas::get());
+		}
+
+		let next = match gas_used.cmp(&target) {
+			cmp::Ordering::Equal => base_fee_per_gas,
+			cmp::Ordering::Greater => {
+				let delta = Perquintill::from_rational(gas_used - target, target).mul_floor(base_fee_per_gas)
+					/ BalanceOf::<T>::from(BASE_FEE_MAX_CHANGE_DENOMINATOR);
+				// the base fee should always increase if the block is above the target
+				base_fee_per_gas.saturating_add(delta.max(One::one()))
+			}
+			cmp::Ordering::Less => {
+				let delta = Perquintill::from_rational(target - gas_used, target).mul_floor(base_fee_per_gas)
+					/ BalanceOf::<T>::from(BASE_FEE_MAX_CHANGE_DENOMINATOR);
+				base_fee_per_gas.saturating_sub(delta)
+			}
+		};
+
+		next.max(T::MinBaseFeePerGas::get())
+	}
+
+	/// Get the logs of the successful EVM executions in the current block from the events. The
+	/// node indexes the logs of imported blocks with it, so the logs are kept out of the state.
+	pub fn block_logs() -> BlockLogs
+	where
+		<T as frame_system::Config>::Event: TryInto<Event<T>>,
+	{
+		let logs: Vec<IndexedLog> = frame_system::Pallet::<T>::read_events_no_consensus()
+			.into_iter()
+			.filter_map(|record| {
+				let extrinsic_index = match record.phase {
+					frame_system::Phase::ApplyExtrinsic(index) => Some(index),
+					_ => None,
+				};
+				match record.event.clone().try_into().ok()? {
+					Event::<T>::Executed { logs, .. } | Event::<T>::Created { logs, .. } => {
+						Some((extrinsic_index, logs))
+					}
+					_ => None,
+				}
+			})
+			.flat_map(|(extrinsic_index, logs)| logs.into_iter().map(move |log| IndexedLog { extrinsic_index, log }))
+			.collect();
+
+		let mut bloom = LogsBloom::default();
+		logs.iter().for_each(|indexed_log| bloom.accrue_log(&indexed_log.log));
+		BlockLogs { bloom, logs }
+	}
+
+	/// Get the base fee per weight of EVM transactions. The transaction fee path charges it
+	/// instead of the adjusted weight fee when it's higher, so the base fee is paid once with the
+	/// transaction fee.
+	pub fn base_fee_per_weight() -> FixedU128 {
+		let base_fee_per_gas: u128 = Self::base_fee_per_gas().unique_saturated_into();
+		FixedU128::checked_from_rational(
+			base_fee_per_gas.saturating_mul(BASE_FEE_SAMPLE_GAS.into()),
+			T::GasToWeight::convert(BASE_FEE_SAMPLE_GAS),
+		)
+		.unwrap_or_default()
+	}
+
+	/// Record the gas used by an EVM transaction, the base fee of the next block is adjusted by
+	/// the gas used in the block.
+	fn record_gas_used(used_gas: u64) {
+		BlockGasUsed::<T>::mutate(|v| *v = v.saturating_add(used_gas));
+	}
+
 	/// Check whether an account is empty.
 	pub fn is_account_empty(address: &H160) -> bool {
 		let account_id = T::AddressMapping::get_account_id(address);
@@ -1306,6 +1644,10 @@ impl<T: Config> Pallet<T> {
 			});
 
 			ContractStorageSizes::<T>::take(contract);
+			UnpublishedContracts::<T>::remove(contract);
+			if let Some(upgrade) = CodeUpgrades::<T>::take(contract) {
+				Self::release_code_upgrade(upgrade);
+			}
 
 			T::IdleScheduler::schedule(
 				EvmTask::Remove {
@@ -1401,6 +1743,10 @@ impl<T: Config> Pallet<T> {
 			}
 		});
 
+		if !contract_info.published {
+			UnpublishedContracts::<T>::insert(address, frame_system::Pallet::<T>::block_number());
+		}
+
 		Accounts::<T>::mutate(address, |maybe_account_info| {
 			if let Some(account_info) = maybe_account_info.as_mut() {
 				account_info.contract_info = Some(contract_info.clone());
@@ -1553,6 +1899,7 @@ impl<T: Config> Pallet<T> {
 				}
 				ensure!(!contract_info.published, Error::<T>::ContractAlreadyPublished);
 				contract_info.published = true;
+				UnpublishedContracts::<T>::remove(contract);
 				Ok(())
 			} else {
 				Err(Error::<T>::ContractNotFound.into())
@@ -1563,6 +1910,7 @@ impl<T: Config> Pallet<T> {
 	/// Set the code of a contract at a given address.
 	///
 	/// - Ensures signer is maintainer or root.
+	/// - Ensures the code hash is audited if the contract is a network contract.
 	/// - Update codes info.
 	/// - Save `code` if not saved yet.
 	fn do_set_code(root_or_signed: Either<(), T::AccountId>, contract: EvmAddress, code: Vec<u8>) -> DispatchResult {
@@ -1582,12 +1930,10 @@ impl<T: Config> Pallet<T> {
 				T::NetworkContractSource::get()
 			};
 
-			let old_code_info = Self::code_infos(&contract_info.code_hash).ok_or(Error::<T>::ContractNotFound)?;
-
 			let bounded_code: BoundedVec<u8, MaxCodeSize> =
 				code.try_into().map_err(|_| Error::<T>::ContractExceedsMaxCodeSize)?;
 			let code_hash = code_hash(bounded_code.as_slice());
-			let code_size = bounded_code.len() as u32;
+			Self::ensure_code_hash_allowed(contract_info, &code_hash)?;
 			// The code_hash of the same contract is definitely different.
 			// The `contract_info.code_hash` hashed by on_contract_initialization which constructed.
 			// Still check it here.
@@ -1595,44 +1941,182 @@ impl<T: Config> Pallet<T> {
 				return Ok(());
 			}
 
-			let storage_size_changed: i32 =
-				code_size.saturating_add(T::NewContractExtraBytes::get()) as i32 - old_code_info.code_size as i32;
+			Self::inc_code_ref(code_hash, bounded_code);
+			Self::replace_code(&source, &contract, contract_info, code_hash)
+		})
+	}
+
+	/// Propose a code upgrade of a contract at a given address.
+	///
+	/// - Ensures signer is maintainer of the contract or root.
+	/// - Ensures the code hash is audited if the contract is a network contract.
+	/// - Reserve the storage deposit for `code` from the proposer.
+	/// - Save `code` if not saved yet, the pending upgrade holds a reference to it.
+	fn do_propose_code_upgrade(
+		root_or_signed: Either<(), T::AccountId>,
+		contract: EvmAddress,
+		code: Vec<u8>,
+	) -> Result<(H256, T::BlockNumber), DispatchError> {
+		let contract_info = Self::accounts(contract)
+			.and_then(|account_info| account_info.contract_info)
+			.ok_or(Error::<T>::ContractNotFound)?;
+
+		let proposer = if let Either::Right(signer) = root_or_signed {
+			let maintainer = T::AddressMapping::get_evm_address(&signer).ok_or(Error::<T>::AddressNotMapped)?;
+			ensure!(contract_info.maintainer == maintainer, Error::<T>::NoPermission);
+			maintainer
+		} else {
+			T::NetworkContractSource::get()
+		};
+
+		let bounded_code: BoundedVec<u8, MaxCodeSize> =
+			code.try_into().map_err(|_| Error::<T>::ContractExceedsMaxCodeSize)?;
+		let code_hash = code_hash(bounded_code.as_slice());
+		Self::ensure_code_hash_allowed(&contract_info, &code_hash)?;
+
+		let effective_at = frame_system::Pallet::<T>::block_number().saturating_add(T::CodeUpgradeDelay::get());
+		let code_size = bounded_code.len() as u32;
+
+		Self::reserve_storage(&proposer, code_size)?;
+		Self::inc_code_ref(code_hash, bounded_code);
+		let upgrade = CodeUpgrade {
+			code_hash,
+			effective_at,
+			proposer,
+			code_size,
+		};
+		// replace the previous pending upgrade
+		if let Some(previous) = CodeUpgrades::<T>::mutate(contract, |v| v.replace(upgrade)) {
+			Self::release_code_upgrade(previous);
+		}
+
+		Ok((code_hash, effective_at))
+	}
+
+	/// Release a pending code upgrade: deref the proposed code, and unreserve the storage deposit
+	/// of the proposer.
+	fn release_code_upgrade(upgrade: CodeUpgrade<T::BlockNumber>) {
+		Self::dec_code_ref(&upgrade.code_hash);
+		// should always be able to unreserve the deposit
+		let _ = Self::unreserve_storage(&upgrade.proposer, upgrade.code_size, 0, 0);
+	}
+
+	/// Enact the pending code upgrade of a contract at a given address.
+	///
+	/// The storage deposit of the proposer is released, and the storage deposit for the code size
+	/// change is charged from the maintainer. Returns whether the upgrade is enacted, the upgrade
+	/// is dropped if its code hash is no longer allowed.
+	fn do_enact_code_upgrade(contract: EvmAddress) -> Result<(H256, bool), DispatchError> {
+		let CodeUpgrade {
+			code_hash,
+			effective_at,
+			proposer,
+			code_size,
+		} = Self::code_upgrades(contract).ok_or(Error::<T>::CodeUpgradeNotFound)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= effective_at,
+			Error::<T>::CodeUpgradeNotEffective
+		);
+		CodeUpgrades::<T>::remove(contract);
+		Self::unreserve_storage(&proposer, code_size, 0, 0)?;
+
+		Accounts::<T>::mutate(contract, |maybe_account_info| -> Result<bool, DispatchError> {
+			let account_info = maybe_account_info.as_mut().ok_or(Error::<T>::ContractNotFound)?;
+			let contract_info = account_info
+				.contract_info
+				.as_mut()
+				.ok_or(Error::<T>::ContractNotFound)?;
+
+			// the code hash could be removed from the audited code hashes during the delay
+			if Self::ensure_code_hash_allowed(contract_info, &code_hash).is_err() {
+				Self::dec_code_ref(&code_hash);
+				return Ok(false);
+			}
+
+			if code_hash == contract_info.code_hash {
+				Self::dec_code_ref(&code_hash);
+				return Ok(true);
+			}
+
+			let source = contract_info.maintainer;
+			Self::replace_code(&source, &contract, contract_info, code_hash)?;
+			Ok(true)
+		})
+		.map(|enacted| (code_hash, enacted))
+	}
+
+	/// Network contracts can only be upgraded to audited code hashes.
+	fn ensure_code_hash_allowed(contract_info: &ContractInfo, code_hash: &H256) -> DispatchResult {
+		if contract_info.maintainer == T::NetworkContractSource::get() {
+			ensure!(
+				AuditedCodeHashes::<T>::contains_key(code_hash),
+				Error::<T>::CodeHashNotAudited
+			);
+		}
+		Ok(())
+	}
+
+	/// Replace the code of a contract with code already referenced by the caller.
+	///
+	/// - Charge or refund the storage deposit from `source` for the code size change.
+	/// - Deref the old code, and remove it if ref count is zero.
+	fn replace_code(
+		source: &EvmAddress,
+		contract: &EvmAddress,
+		contract_info: &mut ContractInfo,
+		code_hash: H256,
+	) -> DispatchResult {
+		let old_code_info = Self::code_infos(&contract_info.code_hash).ok_or(Error::<T>::ContractNotFound)?;
+		let new_code_info = Self::code_infos(&code_hash).ok_or(Error::<T>::ContractNotFound)?;
+
+		let storage_size_changed: i32 = new_code_info.code_size.saturating_add(T::NewContractExtraBytes::get()) as i32
+			- old_code_info.code_size as i32;
+
+		if storage_size_changed.is_positive() {
+			Self::reserve_storage(source, storage_size_changed as u32)?;
+		}
+		Self::charge_storage(source, contract, storage_size_changed)?;
+		Self::update_contract_storage_size(contract, storage_size_changed);
+
+		// try remove old codes
+		Self::dec_code_ref(&contract_info.code_hash);
+		// update code_hash
+		contract_info.code_hash = code_hash;
 
-			if storage_size_changed.is_positive() {
-				Self::reserve_storage(&source, storage_size_changed as u32)?;
+		Ok(())
+	}
+
+	/// Increase the ref count of the code, and save `code` if not saved yet.
+	fn inc_code_ref(code_hash: H256, code: BoundedVec<u8, MaxCodeSize>) {
+		CodeInfos::<T>::mutate_exists(&code_hash, |maybe_code_info| {
+			if let Some(code_info) = maybe_code_info.as_mut() {
+				code_info.ref_count = code_info.ref_count.saturating_add(1);
+			} else {
+				let new = CodeInfo {
+					code_size: code.len() as u32,
+					ref_count: 1,
+				};
+				*maybe_code_info = Some(new);
+
+				Codes::<T>::insert(&code_hash, code);
 			}
-			Self::charge_storage(&source, &contract, storage_size_changed)?;
-			Self::update_contract_storage_size(&contract, storage_size_changed);
+		});
+	}
 
-			// try remove old codes
-			CodeInfos::<T>::mutate_exists(&contract_info.code_hash, |maybe_code_info| -> DispatchResult {
-				let code_info = maybe_code_info.as_mut().ok_or(Error::<T>::ContractNotFound)?;
+	/// Decrease the ref count of the code, and remove it if ref count is zero.
+	fn dec_code_ref(code_hash: &H256) {
+		CodeInfos::<T>::mutate_exists(code_hash, |maybe_code_info| {
+			if let Some(code_info) = maybe_code_info.as_mut() {
 				code_info.ref_count = code_info.ref_count.saturating_sub(1);
 				if code_info.ref_count == 0 {
-					Codes::<T>::remove(&contract_info.code_hash);
+					Codes::<T>::remove(code_hash);
 					*maybe_code_info = None;
 				}
-				Ok(())
-			})?;
-
-			CodeInfos::<T>::mutate_exists(&code_hash, |maybe_code_info| {
-				if let Some(code_info) = maybe_code_info.as_mut() {
-					code_info.ref_count = code_info.ref_count.saturating_add(1);
-				} else {
-					let new = CodeInfo {
-						code_size,
-						ref_count: 1,
-					};
-					*maybe_code_info = Some(new);
-
-					Codes::<T>::insert(&code_hash, bounded_code);
-				}
-			});
-			// update code_hash
-			contract_info.code_hash = code_hash;
-
-			Ok(())
-		})
+			} else {
+				// code info removed while still having reference to it?
+				debug_assert!(false);
+			}
+		});
 	}
 
 	/// Selfdestruct a contract at a given address.
@@ -1649,6 +2133,31 @@ impl<T: Config> Pallet<T> {
 		Self::remove_contract(caller, contract)
 	}
 
+	/// Remove an abandoned contract.
+	///
+	/// The contract is removed on behalf of its maintainer so that the storage deposit is
+	/// refunded to the maintainer.
+	fn do_remove_abandoned_contract(contract: &EvmAddress) -> DispatchResult {
+		let account_info = Self::accounts(contract).ok_or(Error::<T>::ContractNotFound)?;
+		let contract_info = account_info
+			.contract_info
+			.as_ref()
+			.ok_or(Error::<T>::ContractNotFound)?;
+		ensure!(!contract_info.published, Error::<T>::ContractAlreadyPublished);
+
+		// contracts created before the creation block was tracked are treated as created at the
+		// block since which it's tracked
+		let created_at = Self::unpublished_contracts(contract)
+			.or_else(Self::unpublished_contracts_tracked_since)
+			.ok_or(Error::<T>::ContractNotAbandoned)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= created_at.saturating_add(T::AbandonedContractTimeout::get()),
+			Error::<T>::ContractNotAbandoned
+		);
+
+		Self::remove_contract(&contract_info.maintainer, contract)
+	}
+
 	fn ensure_root_or_signed(o: T::Origin) -> Result<Either<(), T::AccountId>, BadOrigin> {
 		EitherOfDiverse::<EnsureRoot<T::AccountId>, EnsureSigned<T::AccountId>>::try_origin(o)
 			.map_or(Err(BadOrigin), Ok)
@@ -1863,6 +2372,20 @@ impl<T: Config> Get<u64> for EvmChainId<T> {
 	}
 }
 
+impl<T: Config> EVMAccountMigration for Pallet<T> {
+	/// Raise the nonce of `to` to the nonce of `from`. The nonce of `from` is kept, the contracts
+	/// it created can't be recreated.
+	fn migrate_nonce(from: &EvmAddress, to: &EvmAddress) -> DispatchResult {
+		if let Some(from_account) = Accounts::<T>::get(from) {
+			Accounts::<T>::mutate(to, |maybe_account_info| match maybe_account_info {
+				Some(account_info) => account_info.nonce = account_info.nonce.max(from_account.nonce),
+				None => *maybe_account_info = Some(AccountInfo::new(from_account.nonce, None)),
+			});
+		}
+		Ok(())
+	}
+}
+
 impl<T: Config> EVMManager<T::AccountId, BalanceOf<T>> for Pallet<T> {
 	fn query_new_contract_extra_bytes() -> u32 {
 		T::NewContractExtraBytes::get()
@@ -2083,6 +2606,22 @@ impl<T: Config> DispatchableTask for EvmTask<T> {
 			}
 		}
 	}
+
+	fn priority(&self) -> TaskPriority {
+		match self {
+			EvmTask::Schedule { .. } => TaskPriority::Normal,
+			// clearing the storages of removed contracts is not urgent
+			EvmTask::Remove { .. } => TaskPriority::Low,
+		}
+	}
+
+	fn max_weight(&self) -> Weight {
+		match self {
+			EvmTask::Schedule { .. } => 0,
+			// at least one storage is cleared in a dispatch
+			EvmTask::Remove { .. } => <T as frame_system::Config>::DbWeight::get().write,
+		}
+	}
 }
 
 #[cfg(feature = "std")]
@@ -0,0 +1,93 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Config, Pallet, UnpublishedContractsTrackedSince};
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+/// Migrate the EVM storage to v1.
+pub struct MigrateToV1<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		v1::migrate::<T>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		v1::pre_migrate::<T>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		v1::post_migrate::<T>()
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Starts tracking the creation block of the unpublished contracts at the current block, the
+	/// unpublished contracts created before are treated as created at it, so they get the full
+	/// `AbandonedContractTimeout` to be published without iterating all the accounts.
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_storage_version >= 1 {
+			log::warn!(
+				target: "evm",
+				"Attempted to apply migration to v1 but failed because storage version is {:?}",
+				on_chain_storage_version,
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: "evm", "Migrating evm to v1");
+
+		let now = frame_system::Pallet::<T>::block_number();
+		UnpublishedContractsTrackedSince::<T>::put(now);
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!(target: "evm", "Completed evm migration to v1, tracking unpublished contracts since {:?}", now);
+
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() < 1,
+			"evm already migrated to v1."
+		);
+		Ok(())
+	}
+
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		assert_eq!(
+			Pallet::<T>::on_chain_storage_version(),
+			1,
+			"evm storage version not set."
+		);
+		assert!(
+			Pallet::<T>::unpublished_contracts_tracked_since().is_some(),
+			"unpublished contracts not tracked."
+		);
+		Ok(())
+	}
+}
@@ -35,7 +35,7 @@ use sp_runtime::{
 	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup},
 	AccountId32,
 };
-use std::{collections::BTreeMap, str::FromStr};
+use std::{cell::RefCell, collections::BTreeMap, str::FromStr};
 
 type Balance = u128;
 
@@ -147,6 +147,13 @@ impl module_idle_scheduler::Config for Runtime {
 	type MinimumWeightRemainInBlock = ConstU64<0>;
 	type RelayChainBlockNumberProvider = MockBlockNumberProvider;
 	type DisableBlockThreshold = ConstU32<6>;
+	type MaxRetries = ConstU32<3>;
+	type RetryBackoff = ConstU64<10>;
+	type Currency = Balances;
+	type RecurringTaskDeposit = ConstU128<10>;
+	type RecurringTaskFilter = Nothing;
+	type MaxRecurringTasks = ConstU32<10>;
+	type MaxTaskHistory = ConstU32<10>;
 }
 
 impl pallet_utility::Config for Runtime {
@@ -187,6 +194,22 @@ ord_parameter_types! {
 	pub const StorageDepositPerByte: Balance = convert_decimals_to_evm(10);
 }
 
+thread_local! {
+	static PAUSED_SELECTORS: RefCell<Vec<[u8; 4]>> = RefCell::new(vec![]);
+}
+
+pub struct MockCallFilter;
+impl MockCallFilter {
+	pub fn pause(selector: [u8; 4]) {
+		PAUSED_SELECTORS.with(|v| v.borrow_mut().push(selector));
+	}
+}
+impl EvmCallFilter for MockCallFilter {
+	fn is_paused(_target: &EvmAddress, input: &[u8]) -> bool {
+		PAUSED_SELECTORS.with(|v| v.borrow().iter().any(|selector| input.starts_with(selector)))
+	}
+}
+
 pub const NEW_CONTRACT_EXTRA_BYTES: u32 = 100;
 pub const DEVELOPER_DEPOSIT: u128 = 1000;
 pub const PUBLICATION_FEE: u128 = 200;
@@ -197,6 +220,8 @@ impl Config for Runtime {
 	type NewContractExtraBytes = ConstU32<NEW_CONTRACT_EXTRA_BYTES>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = ConstU128<20_000_000>;
+	type BaseFeeTargetGas = ConstU64<10_000_000>;
+	type MinBaseFeePerGas = ConstU128<0>;
 
 	type Event = Event;
 	type PrecompilesType = ();
@@ -210,11 +235,14 @@ impl Config for Runtime {
 	type PublicationFee = ConstU128<PUBLICATION_FEE>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureSignedBy<CouncilAccount, AccountId32>;
+	type AbandonedContractTimeout = ConstU64<100>;
+	type CodeUpgradeDelay = ConstU64<10>;
 
 	type Runner = crate::runner::stack::Runner<Self>;
 	type FindAuthor = AuthorGiven;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type CallFilter = MockCallFilter;
 	type WeightInfo = ();
 }
 
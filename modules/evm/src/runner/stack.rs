@@ -37,9 +37,9 @@ use module_evm_utility::{
 	ethereum::Log,
 	evm::{self, backend::Backend as BackendT, ExitError, ExitReason, Transfer},
 };
-use module_support::AddressMapping;
+use module_support::{AddressMapping, EvmCallFilter};
 pub use primitives::{
-	evm::{convert_decimals_from_evm, EvmAddress, Vicinity, MIRRORED_NFT_ADDRESS_START},
+	evm::{convert_decimals_from_evm, convert_decimals_to_evm, EvmAddress, Vicinity, MIRRORED_NFT_ADDRESS_START},
 	ReserveIdentifier,
 };
 use sha3::{Digest, Keccak256};
@@ -77,9 +77,11 @@ impl<T: Config> Runner<T> {
 		) -> (ExitReason, R),
 	{
 		let gas_price = U256::one();
+		let base_fee_per_gas = convert_decimals_to_evm(Pallet::<T>::base_fee_per_gas());
 		let vicinity = Vicinity {
 			gas_price,
 			origin,
+			block_base_fee_per_gas: (!base_fee_per_gas.is_zero()).then(|| U256::from(base_fee_per_gas)),
 			..Default::default()
 		};
 
@@ -231,6 +233,7 @@ impl<T: Config> RunnerT<T> for Runner<T> {
 			Pallet::<T>::can_call_contract(&target, &source),
 			Error::<T>::NoPermission
 		);
+		ensure!(!T::CallFilter::is_paused(&target, &input), Error::<T>::CallPaused);
 
 		let precompiles = T::PrecompilesValue::get();
 		let value = U256::from(UniqueSaturatedInto::<u128>::unique_saturated_into(value));
@@ -903,6 +906,13 @@ impl<'vicinity, 'config, T: Config> StackStateT<'config> for SubstrateStackState
 		self.substate.mark_account_dirty(_address);
 	}
 
+	fn check_call(&self, address: H160, input: &[u8]) -> Result<(), ExitError> {
+		if T::CallFilter::is_paused(&address, input) {
+			return Err(ExitError::Other(Into::<&str>::into(Error::<T>::CallPaused).into()));
+		}
+		Ok(())
+	}
+
 	fn is_cold(&self, address: H160) -> bool {
 		self.substate
 			.recursive_is_cold(&|a| a.accessed_addresses.contains(&address))
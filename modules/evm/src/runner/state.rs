@@ -333,6 +333,7 @@ pub trait StackState<'config>: Backend {
 	fn transfer(&mut self, transfer: Transfer) -> Result<(), ExitError>;
 	fn reset_balance(&mut self, address: H160);
 	fn touch(&mut self, address: H160);
+	fn check_call(&self, address: H160, input: &[u8]) -> Result<(), ExitError>;
 }
 
 /// Data returned by a precompile on success.
@@ -999,6 +1000,11 @@ impl<'config, 'precompiles, S: StackState<'config>, P: PrecompileSet> StackExecu
 			}
 		}
 
+		if let Err(e) = self.state.check_call(code_address, &input) {
+			let _ = self.exit_substate(StackExitKind::Reverted);
+			return Capture::Exit((ExitReason::Error(e), Vec::new()));
+		}
+
 		if let Some(transfer) = transfer {
 			match self.state.transfer(transfer) {
 				Ok(()) => (),
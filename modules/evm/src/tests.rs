@@ -25,7 +25,11 @@ use crate::runner::{
 	stack::SubstrateStackState,
 	state::{StackExecutor, StackState, StackSubstateMetadata},
 };
-use frame_support::{assert_noop, assert_ok, dispatch::DispatchErrorWithPostInfo};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchErrorWithPostInfo,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use module_support::AddressMapping;
 use sp_core::{
 	bytes::{from_hex, to_hex},
@@ -744,6 +748,86 @@ fn publish_factory() {
 	});
 }
 
+#[test]
+fn should_reject_paused_calls() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+	// multiply(2, 3)
+	let multiply = from_hex(
+		"0x165c4a1600000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000003"
+	).unwrap();
+	// pragma solidity ^0.5.0;
+	//
+	// contract Factory {
+	//     Contract c;
+	//     constructor() public {
+	//         c = new Contract();
+	//         c.foo();
+	//     }
+	// }
+	//
+	// contract Contract {
+	//     function foo() public pure returns (uint) {
+	//         return 123;
+	//     }
+	// }
+	let factory = from_hex(
+		"0x608060405234801561001057600080fd5b5060405161001d90610121565b604051809103906000f080158015610039573d6000803e3d6000fd5b506000806101000a81548173ffffffffffffffffffffffffffffffffffffffff021916908373ffffffffffffffffffffffffffffffffffffffff1602179055506000809054906101000a900473ffffffffffffffffffffffffffffffffffffffff1673ffffffffffffffffffffffffffffffffffffffff1663c29855786040518163ffffffff1660e01b815260040160206040518083038186803b1580156100e057600080fd5b505afa1580156100f4573d6000803e3d6000fd5b505050506040513d602081101561010a57600080fd5b81019080805190602001909291905050505061012d565b60a58061017983390190565b603e8061013b6000396000f3fe6080604052600080fdfea265627a7a7231582064177030ee644a03aaf8d65027df9e0331c8bc4b161de25bfb8aa3142848e0f864736f6c634300051100326080604052348015600f57600080fd5b5060878061001e6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063c298557814602d575b600080fd5b60336049565b6040518082815260200191505060405180910390f35b6000607b90509056fea265627a7a7231582031e5a4abae00962cfe9875df1b5b0d3ce6624e220cb8c714a948794fcddb6b4f64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			1000000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+
+		MockCallFilter::pause([0x16, 0x5c, 0x4a, 0x16]);
+		assert_noop!(
+			<Runtime as Config>::Runner::call(
+				alice(),
+				alice(),
+				contract_address,
+				multiply,
+				0,
+				1000000,
+				1000000,
+				vec![],
+				<Runtime as Config>::config(),
+			),
+			Error::<Runtime>::CallPaused
+		);
+
+		// the nested call of `foo` is rejected
+		MockCallFilter::pause([0xc2, 0x98, 0x55, 0x78]);
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			factory,
+			0,
+			2_000_000,
+			5000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		assert_eq!(result.exit_reason, ExitReason::Revert(ExitRevert::Reverted));
+	});
+}
+
 #[test]
 fn create_nft_contract_works() {
 	// pragma solidity ^0.5.0;
@@ -2436,3 +2520,561 @@ fn strict_call_works() {
 		));
 	})
 }
+
+#[test]
+fn next_base_fee_per_gas_should_work() {
+	new_test_ext().execute_with(|| {
+		// BaseFeeTargetGas is 10_000_000
+		assert_eq!(EVM::next_base_fee_per_gas(800, 10_000_000), 800);
+		assert_eq!(EVM::next_base_fee_per_gas(800, 15_000_000), 850);
+		assert_eq!(EVM::next_base_fee_per_gas(800, 20_000_000), 900);
+		assert_eq!(EVM::next_base_fee_per_gas(800, 5_000_000), 750);
+		assert_eq!(EVM::next_base_fee_per_gas(800, 0), 700);
+
+		// always increase when the block is above the target
+		assert_eq!(EVM::next_base_fee_per_gas(0, 10_000_001), 1);
+		assert_eq!(EVM::next_base_fee_per_gas(0, 0), 0);
+	});
+}
+
+#[test]
+fn base_fee_per_gas_should_update_on_finalize() {
+	new_test_ext().execute_with(|| {
+		BaseFeePerGas::<Runtime>::put(800);
+		BlockGasUsed::<Runtime>::put(20_000_000);
+
+		EVM::on_finalize(1);
+		assert_eq!(EVM::base_fee_per_gas(), 900);
+		assert_eq!(EVM::block_gas_used(), 0);
+		System::assert_last_event(Event::EVM(crate::Event::BaseFeePerGasUpdated { base_fee_per_gas: 900 }));
+
+		EVM::on_finalize(2);
+		assert_eq!(EVM::base_fee_per_gas(), 788);
+		System::assert_last_event(Event::EVM(crate::Event::BaseFeePerGasUpdated { base_fee_per_gas: 788 }));
+	});
+}
+
+#[test]
+fn call_should_record_block_gas_used() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(EVM::block_gas_used(), 0);
+
+		assert_ok!(EVM::call(
+			Origin::signed(<Runtime as Config>::AddressMapping::get_account_id(&alice())),
+			contract_a(),
+			Vec::new(),
+			0,
+			1000000,
+			0,
+			vec![]
+		));
+
+		let used_gas = match System::events().last().map(|r| r.event.clone()) {
+			Some(Event::EVM(crate::Event::Executed { used_gas, .. }))
+			| Some(Event::EVM(crate::Event::ExecutedFailed { used_gas, .. })) => used_gas,
+			_ => panic!("EVM call event not found"),
+		};
+		assert_eq!(EVM::block_gas_used(), used_gas);
+	});
+}
+
+#[test]
+fn base_fee_per_weight_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(EVM::base_fee_per_weight(), FixedU128::zero());
+
+		// GasToWeight is 1:1
+		BaseFeePerGas::<Runtime>::put(800);
+		assert_eq!(EVM::base_fee_per_weight(), FixedU128::saturating_from_integer(800));
+	});
+}
+
+#[test]
+fn should_remove_abandoned_contract() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	// 	 uint value;
+	// 	 constructor(uint a) public payable {
+	// 		value = a;
+	// 	 }
+	//
+	//   function getValue() public payable returns (uint) {
+	// 	     return value;
+	// 	 }
+	// }
+	let mut contract = from_hex(
+		"0x60806040526040516100c73803806100c783398181016040526020811015602557600080fd5b81019080805190602001909291905050508060008190555050607b8061004c6000396000f3fe608060405260043610601c5760003560e01c806320965255146021575b600080fd5b6027603d565b6040518082815260200191505060405180910390f35b6000805490509056fea265627a7a72315820b832564a9db725638dcef03d07bfbdd2dc818020ea359630317e2126e95c314964736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+
+		let mut stored_value: Vec<u8> =
+			from_hex("0x000000000000000000000000000000000000000000000000000000000000007b").unwrap();
+		contract.append(&mut stored_value);
+
+		// create contract
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			100000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+
+		let contract_address = result.value;
+		assert_eq!(result.used_storage, 287);
+		assert_eq!(EVM::unpublished_contracts(&contract_address), Some(1));
+
+		let alice_balance = INITIAL_BALANCE - 287 * EVM::get_storage_deposit_per_byte();
+		assert_eq!(balance(alice()), alice_balance);
+
+		assert_noop!(
+			EVM::remove_abandoned_contract(Origin::signed(alice_account_id), contract_address),
+			BadOrigin
+		);
+		assert_noop!(
+			EVM::remove_abandoned_contract(Origin::signed(NetworkContractAccount::get()), contract_address),
+			Error::<Runtime>::ContractNotAbandoned
+		);
+
+		System::set_block_number(101);
+		assert_noop!(
+			EVM::remove_abandoned_contract(Origin::signed(NetworkContractAccount::get()), contract_a()),
+			Error::<Runtime>::ContractNotFound
+		);
+		assert_ok!(EVM::remove_abandoned_contract(
+			Origin::signed(NetworkContractAccount::get()),
+			contract_address
+		));
+		System::assert_last_event(Event::EVM(crate::Event::AbandonedContractRemoved {
+			contract: contract_address,
+		}));
+		assert_eq!(EVM::unpublished_contracts(&contract_address), None);
+		assert!(!EVM::is_contract(&contract_address));
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 1);
+
+		IdleScheduler::on_idle(0, 1_000_000_000_000);
+
+		// refund storage deposit to the maintainer
+		assert_eq!(AccountStorages::<Runtime>::iter_prefix(&contract_address).count(), 0);
+		assert_eq!(balance(alice()), INITIAL_BALANCE);
+		assert_eq!(reserved_balance(contract_address), 0);
+	});
+}
+
+#[test]
+fn migrate_unpublished_contracts_to_v1() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let unpublished = <Runtime as Config>::Runner::create(
+			alice(),
+			contract.clone(),
+			0,
+			1000000,
+			1000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap()
+		.value;
+		let published = <Runtime as Config>::Runner::create(
+			alice(),
+			contract,
+			0,
+			1000000,
+			1000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap()
+		.value;
+		assert_ok!(EVM::publish_free(Origin::signed(CouncilAccount::get()), published));
+
+		// simulate a legacy unpublished contract
+		UnpublishedContracts::<Runtime>::remove(unpublished);
+		StorageVersion::new(0).put::<EVM>();
+
+		System::set_block_number(50);
+		assert_ok!(crate::migrations::v1::pre_migrate::<Runtime>());
+		assert_eq!(
+			crate::migrations::MigrateToV1::<Runtime>::on_runtime_upgrade(),
+			<Runtime as frame_system::Config>::DbWeight::get().reads_writes(2, 2)
+		);
+		assert_ok!(crate::migrations::v1::post_migrate::<Runtime>());
+
+		assert_eq!(EVM::unpublished_contracts_tracked_since(), Some(50));
+		assert_eq!(EVM::unpublished_contracts(&unpublished), None);
+		assert_eq!(EVM::unpublished_contracts(&published), None);
+		assert_eq!(EVM::on_chain_storage_version(), 1);
+
+		// not abandoned until `AbandonedContractTimeout` after the migration
+		System::set_block_number(149);
+		assert_noop!(
+			EVM::remove_abandoned_contract(Origin::signed(NetworkContractAccount::get()), unpublished),
+			Error::<Runtime>::ContractNotAbandoned
+		);
+		System::set_block_number(150);
+		assert_ok!(EVM::remove_abandoned_contract(
+			Origin::signed(NetworkContractAccount::get()),
+			unpublished
+		));
+
+		// only migrate once
+		System::set_block_number(200);
+		assert_eq!(
+			crate::migrations::v1::migrate::<Runtime>(),
+			<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+		);
+		assert_eq!(EVM::unpublished_contracts_tracked_since(), Some(50));
+	});
+}
+
+#[test]
+fn should_upgrade_code_after_delay() {
+	// pragma solidity ^0.5.0;
+	//
+	// contract Test {
+	//	 function multiply(uint a, uint b) public pure returns(uint) {
+	// 	 	return a * b;
+	// 	 }
+	// }
+	let contract = from_hex(
+		"0x608060405234801561001057600080fd5b5060b88061001f6000396000f3fe6080604052348015600f57600080fd5b506004361060285760003560e01c8063165c4a1614602d575b600080fd5b606060048036036040811015604157600080fd5b8101908080359060200190929190803590602001909291905050506076565b6040518082815260200191505060405180910390f35b600081830290509291505056fea265627a7a723158201f3db7301354b88b310868daf4395a6ab6cd42d16b1d8e68cdf4fdd9d34fffbf64736f6c63430005110032"
+	).unwrap();
+
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+		let bob_account_id = <Runtime as Config>::AddressMapping::get_account_id(&bob());
+
+		// create contract
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			contract.clone(),
+			0,
+			21_000_000,
+			21_000_000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+		assert_eq!(reserved_balance(contract_address), 2840);
+
+		let code_hash = H256::from_str("164981e02df203a0fb32a0af7c2cd1cc7f9df7bb49a4d2b0219307bb68a4b603").unwrap();
+		let new_code_hash = H256::from_str("9061d510f6235de4eae304e1a2a2ae22e1610ba893c018b7fabc1f1635f49877").unwrap();
+
+		assert_noop!(
+			EVM::propose_code_upgrade(
+				Origin::signed(bob_account_id.clone()),
+				contract_address,
+				contract.clone()
+			),
+			Error::<Runtime>::NoPermission
+		);
+		let alice_reserved = reserved_balance(alice());
+		assert_ok!(EVM::propose_code_upgrade(
+			Origin::signed(alice_account_id.clone()),
+			contract_address,
+			contract.clone()
+		));
+		System::assert_last_event(Event::EVM(crate::Event::CodeUpgradeProposed {
+			contract: contract_address,
+			code_hash: new_code_hash,
+			effective_at: 11,
+		}));
+		assert_eq!(
+			EVM::code_upgrades(&contract_address),
+			Some(CodeUpgrade {
+				code_hash: new_code_hash,
+				effective_at: 11,
+				proposer: alice(),
+				code_size: 215,
+			})
+		);
+		// the storage deposit of the proposed code is reserved
+		assert_eq!(reserved_balance(alice()), alice_reserved + 2150);
+		assert_eq!(
+			CodeInfos::<Runtime>::get(&new_code_hash),
+			Some(CodeInfo {
+				code_size: 215,
+				ref_count: 1,
+			})
+		);
+
+		assert_noop!(
+			EVM::enact_code_upgrade(Origin::signed(bob_account_id.clone()), contract_address),
+			Error::<Runtime>::CodeUpgradeNotEffective
+		);
+
+		System::set_block_number(11);
+		assert_ok!(EVM::enact_code_upgrade(
+			Origin::signed(bob_account_id.clone()),
+			contract_address
+		));
+		System::assert_last_event(Event::EVM(crate::Event::CodeUpgradeEnacted {
+			contract: contract_address,
+			code_hash: new_code_hash,
+		}));
+		assert_eq!(EVM::code_upgrades(&contract_address), None);
+		assert_eq!(EVM::code_hash_at_address(&contract_address), new_code_hash);
+		assert_eq!(CodeInfos::<Runtime>::get(&code_hash), None);
+		assert!(!Codes::<Runtime>::contains_key(&code_hash));
+		assert_eq!(reserved_balance(contract_address), 4150);
+		assert_eq!(reserved_balance(alice()), alice_reserved);
+
+		assert_noop!(
+			EVM::enact_code_upgrade(Origin::signed(bob_account_id), contract_address),
+			Error::<Runtime>::CodeUpgradeNotFound
+		);
+
+		// the maintainer can only upgrade the published contract through the delay
+		assert_ok!(EVM::publish_free(
+			Origin::signed(CouncilAccount::get()),
+			contract_address
+		));
+		assert_noop!(
+			EVM::set_code(
+				Origin::signed(alice_account_id.clone()),
+				contract_address,
+				vec![0x60, 0x00]
+			),
+			Error::<Runtime>::ContractAlreadyPublished
+		);
+		assert_ok!(EVM::propose_code_upgrade(
+			Origin::signed(alice_account_id),
+			contract_address,
+			vec![0x60, 0x00]
+		));
+		System::assert_last_event(Event::EVM(crate::Event::CodeUpgradeProposed {
+			contract: contract_address,
+			code_hash: code_hash(&[0x60, 0x00]),
+			effective_at: 21,
+		}));
+	});
+}
+
+#[test]
+fn should_veto_code_upgrade() {
+	new_test_ext().execute_with(|| {
+		let alice_account_id = <Runtime as Config>::AddressMapping::get_account_id(&alice());
+
+		// create contract
+		let result = <Runtime as Config>::Runner::create(
+			alice(),
+			from_hex(
+				"0x6080604052348015600f57600080fd5b50603580601d6000396000f3fe6080604052600080fdfea165627a7a72305820",
+			)
+			.unwrap(),
+			0,
+			21_000_000,
+			21_000_000,
+			vec![],
+			<Runtime as Config>::config(),
+		)
+		.unwrap();
+		let contract_address = result.value;
+		let new_code = vec![0x60, 0x00];
+		let new_code_hash = code_hash(&new_code);
+		let alice_reserved = reserved_balance(alice());
+
+		assert_ok!(EVM::propose_code_upgrade(
+			Origin::signed(alice_account_id.clone()),
+			contract_address,
+			new_code
+		));
+		assert!(Codes::<Runtime>::contains_key(&new_code_hash));
+		assert_eq!(reserved_balance(alice()), alice_reserved + 20);
+
+		assert_noop!(
+			EVM::veto_code_upgrade(Origin::signed(alice_account_id.clone()), contract_address),
+			BadOrigin
+		);
+		assert_ok!(EVM::veto_code_upgrade(
+			Origin::signed(NetworkContractAccount::get()),
+			contract_address
+		));
+		System::assert_last_event(Event::EVM(crate::Event::CodeUpgradeVetoed {
+			contract: contract_address,
+			code_hash: new_code_hash,
+		}));
+		assert_eq!(EVM::code_upgrades(&contract_address), None);
+		assert!(!Codes::<Runtime>::contains_key(&new_code_hash));
+		assert_eq!(CodeInfos::<Runtime>::get(&new_code_hash), None);
+		assert_eq!(reserved_balance(alice()), alice_reserved);
+
+		// replacing the pending upgrade releases the previous one
+		assert_ok!(EVM::propose_code_upgrade(
+			Origin::signed(alice_account_id.clone()),
+			contract_address,
+			vec![0x60, 0x00]
+		));
+		assert_ok!(EVM::propose_code_upgrade(
+			Origin::signed(alice_account_id),
+			contract_address,
+			vec![0x60, 0x01, 0x00]
+		));
+		assert!(!Codes::<Runtime>::contains_key(&new_code_hash));
+		assert_eq!(reserved_balance(alice()), alice_reserved + 30);
+
+		// publishing keeps the pending upgrade
+		assert_ok!(EVM::publish_free(
+			Origin::signed(CouncilAccount::get()),
+			contract_address
+		));
+		assert_eq!(
+			EVM::code_upgrades(&contract_address).map(|upgrade| upgrade.code_hash),
+			Some(code_hash(&[0x60, 0x01, 0x00]))
+		);
+	});
+}
+
+#[test]
+fn network_contract_code_upgrade_requires_audited_code_hash() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EVM::create_predeploy_contract(
+			Origin::signed(NetworkContractAccount::get()),
+			H160::from_low_u64_be(0x800),
+			from_hex(
+				"0x6080604052348015600f57600080fd5b50603580601d6000396000f3fe6080604052600080fdfea165627a7a72305820"
+			)
+			.unwrap(),
+			0,
+			1000000,
+			1000000,
+			vec![],
+		));
+		let contract_address = H160::from_low_u64_be(0x800);
+		let new_code = vec![0x60, 0x00];
+		let new_code_hash = code_hash(&new_code);
+
+		assert_noop!(
+			EVM::propose_code_upgrade(Origin::root(), contract_address, new_code.clone()),
+			Error::<Runtime>::CodeHashNotAudited
+		);
+		assert_noop!(
+			EVM::set_code(Origin::root(), contract_address, new_code.clone()),
+			Error::<Runtime>::CodeHashNotAudited
+		);
+
+		assert_noop!(
+			EVM::set_audited_code_hash(Origin::root(), new_code_hash, true),
+			BadOrigin
+		);
+		assert_ok!(EVM::set_audited_code_hash(
+			Origin::signed(NetworkContractAccount::get()),
+			new_code_hash,
+			true
+		));
+		System::assert_last_event(Event::EVM(crate::Event::AuditedCodeHashAdded {
+			code_hash: new_code_hash,
+		}));
+		assert_ok!(EVM::propose_code_upgrade(Origin::root(), contract_address, new_code));
+
+		// removed from the audited code hashes during the delay
+		assert_ok!(EVM::set_audited_code_hash(
+			Origin::signed(NetworkContractAccount::get()),
+			new_code_hash,
+			false
+		));
+		System::set_block_number(11);
+		assert_ok!(EVM::enact_code_upgrade(
+			Origin::signed(AccountId32::from([2u8; 32])),
+			contract_address
+		));
+		System::assert_last_event(Event::EVM(crate::Event::CodeUpgradeDropped {
+			contract: contract_address,
+			code_hash: new_code_hash,
+		}));
+		assert_eq!(EVM::code_upgrades(&contract_address), None);
+		assert!(!Codes::<Runtime>::contains_key(&new_code_hash));
+		assert_ne!(EVM::code_hash_at_address(&contract_address), new_code_hash);
+	});
+}
+
+#[test]
+fn block_logs_should_work() {
+	new_test_ext().execute_with(|| {
+		let log = |byte: u8| Log {
+			address: contract_a(),
+			topics: vec![H256::repeat_byte(byte)],
+			data: vec![byte],
+		};
+		assert_eq!(EVM::block_logs(), BlockLogs::default());
+
+		System::note_finished_initialize();
+		System::deposit_event(Event::EVM(crate::Event::Executed {
+			from: alice(),
+			contract: contract_a(),
+			logs: vec![log(1), log(2)],
+			used_gas: 0,
+			used_storage: 0,
+		}));
+		// the logs of failed executions are reverted
+		System::deposit_event(Event::EVM(crate::Event::ExecutedFailed {
+			from: alice(),
+			contract: contract_a(),
+			exit_reason: ExitReason::Revert(ExitRevert::Reverted),
+			output: vec![],
+			logs: vec![log(3)],
+			used_gas: 0,
+			used_storage: 0,
+		}));
+		System::note_applied_extrinsic(&Ok(().into()), Default::default());
+		System::deposit_event(Event::EVM(crate::Event::Created {
+			from: alice(),
+			contract: contract_b(),
+			logs: vec![log(4)],
+			used_gas: 0,
+			used_storage: 0,
+		}));
+		System::note_finished_extrinsics();
+		System::deposit_event(Event::EVM(crate::Event::Executed {
+			from: alice(),
+			contract: contract_a(),
+			logs: vec![log(5)],
+			used_gas: 0,
+			used_storage: 0,
+		}));
+
+		let block_logs = EVM::block_logs();
+		assert_eq!(
+			block_logs.logs,
+			vec![
+				IndexedLog {
+					extrinsic_index: Some(0),
+					log: log(1),
+				},
+				IndexedLog {
+					extrinsic_index: Some(0),
+					log: log(2),
+				},
+				IndexedLog {
+					extrinsic_index: Some(1),
+					log: log(4),
+				},
+				IndexedLog {
+					extrinsic_index: None,
+					log: log(5),
+				},
+			]
+		);
+		assert!(block_logs.bloom.contains_input(contract_a().as_bytes()));
+		assert!(block_logs.bloom.contains_input(H256::repeat_byte(4).as_bytes()));
+		assert!(!block_logs.bloom.contains_input(H256::repeat_byte(3).as_bytes()));
+	});
+}
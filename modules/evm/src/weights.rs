@@ -59,6 +59,11 @@ pub trait WeightInfo {
 	fn disable_contract_development() -> Weight;
 	fn set_code(c: u32, ) -> Weight;
 	fn selfdestruct() -> Weight;
+	fn remove_abandoned_contract() -> Weight;
+	fn propose_code_upgrade(c: u32, ) -> Weight;
+	fn enact_code_upgrade() -> Weight;
+	fn veto_code_upgrade() -> Weight;
+	fn set_audited_code_hash() -> Weight;
 }
 
 /// Weights for module_evm using the Acala node and recommended hardware.
@@ -201,6 +206,34 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	// Placeholder weight until `remove_abandoned_contract` is benchmarked.
+	fn remove_abandoned_contract() -> Weight {
+		(150_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Placeholder weights until the code upgrade calls are benchmarked, including the storage
+	// deposit reserved for the proposed code.
+	fn propose_code_upgrade(c: u32, ) -> Weight {
+		(100_000_000 as Weight)
+			.saturating_add((8_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn enact_code_upgrade() -> Weight {
+		(180_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+	}
+	fn veto_code_upgrade() -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn set_audited_code_hash() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -267,4 +300,32 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
+	// Placeholder weight until `remove_abandoned_contract` is benchmarked.
+	fn remove_abandoned_contract() -> Weight {
+		(150_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// Placeholder weights until the code upgrade calls are benchmarked, including the storage
+	// deposit reserved for the proposed code.
+	fn propose_code_upgrade(c: u32, ) -> Weight {
+		(100_000_000 as Weight)
+			.saturating_add((8_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn enact_code_upgrade() -> Weight {
+		(180_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+	}
+	fn veto_code_upgrade() -> Weight {
+		(60_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn set_audited_code_hash() -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 }
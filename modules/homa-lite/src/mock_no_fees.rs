@@ -203,6 +203,7 @@ impl module_currencies::Config for NoFeeRuntime {
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type Erc20HoldingAccount = Erc20HoldingAccount;
+	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
@@ -23,9 +23,11 @@
 
 use frame_support::{log, pallet_prelude::*, transactional, PalletId};
 use frame_system::{ensure_signed, pallet_prelude::*};
-use module_support::{ExchangeRate, ExchangeRateProvider, HomaManager, HomaSubAccountXcm, Rate, Ratio};
+use module_support::{
+	DispatchableTask, ExchangeRate, ExchangeRateProvider, HomaManager, HomaSubAccountXcm, Rate, Ratio,
+};
 use orml_traits::MultiCurrency;
-use primitives::{Balance, CurrencyId, EraIndex};
+use primitives::{task::TaskResult, Balance, CurrencyId, EraIndex};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
@@ -34,7 +36,7 @@ use sp_runtime::{
 	},
 	ArithmeticError, FixedPointNumber,
 };
-use sp_std::{cmp::Ordering, convert::From, prelude::*, vec, vec::Vec};
+use sp_std::{cmp::Ordering, convert::From, marker::PhantomData, prelude::*, vec, vec::Vec};
 
 pub use module::*;
 pub use weights::WeightInfo;
@@ -238,6 +240,8 @@ pub mod module {
 		LastEraBumpedBlockUpdated { last_era_bumped_block: T::BlockNumber },
 		/// The frequency to bump era has been updated.
 		BumpEraFrequencyUpdated { frequency: T::BlockNumber },
+		/// The total bonded has been synced with the ledgers of subaccounts.
+		TotalStakingBondedSynced { total_staking_bonded: Balance },
 	}
 
 	/// The current era of relaychain
@@ -1061,6 +1065,22 @@ pub mod module {
 				.unwrap_or_else(Zero::zero)
 		}
 
+		/// Sync TotalStakingBonded with the sum of bonded of the subaccounts' ledgers, returns
+		/// the number of ledgers.
+		pub fn sync_total_staking_bonded() -> u32 {
+			let mut count: u32 = 0;
+			let total_staking_bonded =
+				StakingLedgers::<T>::iter_values().fold(Zero::zero(), |total: Balance, ledger| {
+					count = count.saturating_add(1);
+					total.saturating_add(ledger.bonded)
+				});
+			if TotalStakingBonded::<T>::get() != total_staking_bonded {
+				TotalStakingBonded::<T>::put(total_staking_bonded);
+				Self::deposit_event(Event::<T>::TotalStakingBondedSynced { total_staking_bonded });
+			}
+			count
+		}
+
 		/// Bump current era.
 		/// The rebalance will send XCM messages to relaychain. Once the XCM message is sent,
 		/// the execution result cannot be obtained and cannot be rolled back. So the process
@@ -1125,6 +1145,37 @@ impl<T: Config> HomaManager<T::AccountId, Balance> for Pallet<T> {
 	}
 }
 
+#[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub enum HomaTask<T: Config> {
+	/// Sync the total bonded with the ledgers of subaccounts.
+	SyncLedgers,
+	#[doc(hidden)]
+	#[codec(skip)]
+	__Ignore(PhantomData<T>),
+}
+
+impl<T: Config> DispatchableTask for HomaTask<T> {
+	fn dispatch(self, _weight: Weight) -> TaskResult {
+		let count = match self {
+			HomaTask::SyncLedgers => Pallet::<T>::sync_total_staking_bonded(),
+			HomaTask::__Ignore(_) => 0,
+		};
+		TaskResult {
+			result: Ok(()),
+			// StakingLedgers (r: count), TotalStakingBonded (r: 1, w: 1)
+			used_weight: <T as frame_system::Config>::DbWeight::get().reads_writes(count.saturating_add(1).into(), 1),
+			finished: true,
+		}
+	}
+
+	fn max_weight(&self) -> Weight {
+		// the ledgers are kept for the active subaccounts
+		let count = T::ActiveSubAccountsIndexList::get().len() as Weight;
+		<T as frame_system::Config>::DbWeight::get().reads_writes(count.saturating_add(1), 1)
+	}
+}
+
 /// Helpers for distribute increment/decrement to as possible to keep the list balanced after
 /// distribution.
 pub fn distribute_increment<Index>(
@@ -314,6 +314,42 @@ fn update_bump_era_params_works() {
 	});
 }
 
+#[test]
+fn sync_ledgers_task_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		StakingLedgers::<Runtime>::insert(
+			0,
+			StakingLedger {
+				bonded: 1_000_000,
+				unlocking: vec![],
+			},
+		);
+		StakingLedgers::<Runtime>::insert(
+			1,
+			StakingLedger {
+				bonded: 2_000_000,
+				unlocking: vec![],
+			},
+		);
+		assert_eq!(Homa::get_total_bonded(), 0);
+
+		let task = HomaTask::<Runtime>::SyncLedgers;
+		let result = task.clone().dispatch(task.max_weight());
+		assert_eq!(result.result, Ok(()));
+		assert!(result.finished);
+		assert!(result.used_weight <= task.max_weight());
+		assert_eq!(Homa::get_total_bonded(), 3_000_000);
+		System::assert_last_event(Event::Homa(crate::Event::TotalStakingBondedSynced {
+			total_staking_bonded: 3_000_000,
+		}));
+
+		// no event if already synced
+		System::reset_events();
+		task.clone().dispatch(task.max_weight());
+		assert_eq!(System::events(), vec![]);
+	});
+}
+
 #[test]
 fn reset_ledgers_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -130,6 +130,7 @@ impl module_currencies::Config for Runtime {
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type Erc20HoldingAccount = Erc20HoldingAccount;
+	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
@@ -154,6 +155,8 @@ impl module_evm::Config for Runtime {
 	type NewContractExtraBytes = ConstU32<1>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = ConstU128<10>;
+	type BaseFeeTargetGas = ConstU64<10_000_000>;
+	type MinBaseFeePerGas = ConstU128<0>;
 	type Event = Event;
 	type PrecompilesType = ();
 	type PrecompilesValue = ();
@@ -166,11 +169,14 @@ impl module_evm::Config for Runtime {
 	type PublicationFee = ConstU128<200>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureRoot<AccountId>;
+	type AbandonedContractTimeout = ConstU64<100>;
+	type CodeUpgradeDelay = ConstU64<10>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
 	type Task = ();
 	type IdleScheduler = ();
+	type CallFilter = ();
 	type WeightInfo = ();
 }
 
@@ -184,6 +190,12 @@ impl module_evm_accounts::Config for Runtime {
 	type ChainId = ();
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type AccountMigration = EVM;
+	type RecoveryOrigin = EnsureRoot<AccountId>;
+	type Call = Call;
+	type Currencies = Currencies;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type EvmCallTarget = ();
 	type WeightInfo = ();
 }
 
@@ -237,6 +237,10 @@ impl evm_accounts::Config for Runtime {
 	type ChainId = ();
 	type AddressMapping = evm_accounts::EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type Call = Call;
+	type Currencies = Currencies;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type EvmCallTarget = ();
 	type WeightInfo = ();
 }
 
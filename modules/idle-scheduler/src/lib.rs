@@ -19,33 +19,92 @@
 //! # Idle scheduler Module
 //!
 //! Allow pallets and chain maintainer to schedule a task to be dispatched when chain is idle.
+//!
+//! Tasks are dispatched in the order of their priority, which root can override when scheduling,
+//! a task declaring its max weight is skipped until enough weight remains, so that smaller tasks
+//! can fill the block. A failed task which is not finished is retried with exponential backoff for
+//! up to MaxRetries times, and then moved to the dead letter store. Root can cancel the queued or
+//! dead letter tasks.
+//!
+//! Recurring tasks are dispatched every `period` relay chain blocks, registered by root or by
+//! accounts reserving RecurringTaskDeposit for the tasks allowed by RecurringTaskFilter. A
+//! recurring task which is not finished is dispatched again on the next idle block, and its next
+//! period starts after it's finished. The results of the last MaxTaskHistory executions are kept,
+//! and the owner or root can pause or cancel the recurring tasks.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(unused_must_use)]
 
-use acala_primitives::{task::TaskResult, BlockNumber, Nonce};
+use acala_primitives::{
+	task::{TaskPriority, TaskResult},
+	BlockNumber, Nonce, ReserveIdentifier,
+};
 use codec::FullCodec;
-use frame_support::{log, pallet_prelude::*};
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	traits::{Contains, Currency, NamedReservableCurrency},
+	transactional,
+};
 use frame_system::pallet_prelude::*;
 pub use module_support::{DispatchableTask, IdleScheduler};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{BlockNumberProvider, One},
+	traits::{BlockNumberProvider, One, Saturating, Zero},
 	ArithmeticError,
 };
 use sp_std::{cmp::PartialEq, fmt::Debug, prelude::*};
 
+pub mod migrations;
 mod mock;
 mod tests;
 mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The retry state of a failed task.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TaskRetry<BlockNumber> {
+	/// The number of failed dispatches.
+	pub retries: u32,
+	/// The task is not dispatched before this block.
+	pub retry_at: BlockNumber,
+}
+
+/// A task dispatched every `period` relay chain blocks.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RecurringTask<AccountId, Balance, Task> {
+	pub task: Task,
+	/// The account registered the task, `None` if registered by root.
+	pub owner: Option<AccountId>,
+	/// The deposit reserved from the owner.
+	pub deposit: Balance,
+	/// The relay chain blocks between dispatches.
+	pub period: BlockNumber,
+	/// The task is dispatched at or after this relay chain block.
+	pub next_run_at: BlockNumber,
+	pub paused: bool,
+}
+
+/// The execution record of a recurring task.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct TaskExecution {
+	pub relay_block: BlockNumber,
+	pub result: DispatchResult,
+	pub used_weight: Weight,
+}
+
+type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::IdleScheduler;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
@@ -68,6 +127,47 @@ pub mod module {
 		/// relaychain blocks.
 		#[pallet::constant]
 		type DisableBlockThreshold: Get<BlockNumber>;
+
+		/// The max number of retries of a failed task before it's moved to the dead letter
+		/// store.
+		#[pallet::constant]
+		type MaxRetries: Get<u32>;
+
+		/// The delay before the first retry of a failed task, doubled on every retry.
+		#[pallet::constant]
+		type RetryBackoff: Get<Self::BlockNumber>;
+
+		/// The currency for the deposit of recurring tasks.
+		type Currency: NamedReservableCurrency<Self::AccountId, ReserveIdentifier = ReserveIdentifier>;
+
+		/// The deposit reserved for a recurring task registered by an account.
+		#[pallet::constant]
+		type RecurringTaskDeposit: Get<BalanceOf<Self>>;
+
+		/// The tasks can be registered as recurring tasks by accounts.
+		type RecurringTaskFilter: Contains<Self::Task>;
+
+		/// The max number of recurring tasks.
+		#[pallet::constant]
+		type MaxRecurringTasks: Get<u32>;
+
+		/// The max number of execution records kept for a recurring task.
+		#[pallet::constant]
+		type MaxTaskHistory: Get<u32>;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The task is not found.
+		TaskNotFound,
+		/// The period of recurring task is zero.
+		InvalidPeriod,
+		/// The recurring tasks exceed MaxRecurringTasks.
+		TooManyRecurringTasks,
+		/// The task is not allowed to be registered by accounts.
+		TaskNotAllowed,
+		/// The origin is not the owner of the recurring task.
+		NoPermission,
 	}
 
 	#[pallet::event]
@@ -77,6 +177,29 @@ pub mod module {
 		TaskDispatched { task_id: Nonce, result: DispatchResult },
 		/// A task is added.
 		TaskAdded { task_id: Nonce, task: T::Task },
+		/// A task failed and will be retried.
+		TaskRetryScheduled {
+			task_id: Nonce,
+			error: DispatchError,
+			retries: u32,
+			retry_at: T::BlockNumber,
+		},
+		/// A task failed too many times and is moved to the dead letter store.
+		TaskDeadLettered { task_id: Nonce, error: DispatchError },
+		/// A task is cancelled.
+		TaskCancelled { task_id: Nonce },
+		/// A recurring task is registered.
+		RecurringTaskScheduled {
+			task_id: Nonce,
+			owner: Option<T::AccountId>,
+			period: BlockNumber,
+		},
+		/// A recurring task has been dispatched on_idle.
+		RecurringTaskDispatched { task_id: Nonce, result: DispatchResult },
+		/// A recurring task is paused or resumed.
+		RecurringTaskPaused { task_id: Nonce, paused: bool },
+		/// A recurring task is cancelled.
+		RecurringTaskCancelled { task_id: Nonce },
 	}
 
 	/// The schedule tasks waiting to dispatch. After task is dispatched, it's removed.
@@ -86,6 +209,50 @@ pub mod module {
 	#[pallet::getter(fn tasks)]
 	pub type Tasks<T: Config> = StorageMap<_, Twox64Concat, Nonce, T::Task, OptionQuery>;
 
+	/// The queues of the task ids by priority.
+	///
+	/// TaskQueues: double_map TaskPriority, Nonce => ()
+	#[pallet::storage]
+	pub type TaskQueues<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, TaskPriority, Twox64Concat, Nonce, (), OptionQuery>;
+
+	/// The retry state of the failed tasks.
+	///
+	/// TaskRetries: map Nonce => Option<TaskRetry<BlockNumber>>
+	#[pallet::storage]
+	#[pallet::getter(fn task_retries)]
+	pub type TaskRetries<T: Config> = StorageMap<_, Twox64Concat, Nonce, TaskRetry<T::BlockNumber>, OptionQuery>;
+
+	/// The tasks failed more than MaxRetries times, with the last error.
+	///
+	/// DeadLetterTasks: map Nonce => Option<(Task, DispatchError)>
+	#[pallet::storage]
+	#[pallet::getter(fn dead_letter_tasks)]
+	pub type DeadLetterTasks<T: Config> = StorageMap<_, Twox64Concat, Nonce, (T::Task, DispatchError), OptionQuery>;
+
+	/// The recurring tasks.
+	///
+	/// RecurringTasks: map Nonce => Option<RecurringTask<AccountId, Balance, Task>>
+	#[pallet::storage]
+	#[pallet::getter(fn recurring_tasks)]
+	pub type RecurringTasks<T: Config> =
+		StorageMap<_, Twox64Concat, Nonce, RecurringTask<T::AccountId, BalanceOf<T>, T::Task>, OptionQuery>;
+
+	/// The number of recurring tasks.
+	///
+	/// RecurringTaskCount: u32
+	#[pallet::storage]
+	#[pallet::getter(fn recurring_task_count)]
+	pub type RecurringTaskCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The last execution records of the recurring tasks.
+	///
+	/// RecurringTaskHistory: map Nonce => BoundedVec<TaskExecution, MaxTaskHistory>
+	#[pallet::storage]
+	#[pallet::getter(fn recurring_task_history)]
+	pub type RecurringTaskHistory<T: Config> =
+		StorageMap<_, Twox64Concat, Nonce, BoundedVec<TaskExecution, T::MaxTaskHistory>, ValueQuery>;
+
 	/// The task id used to index tasks.
 	#[pallet::storage]
 	#[pallet::getter(fn next_task_id)]
@@ -98,6 +265,7 @@ pub mod module {
 
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -128,7 +296,7 @@ pub mod module {
 				// IdleScheduler won't execute)
 				remaining_weight
 			} else {
-				Self::do_dispatch_tasks(remaining_weight)
+				Self::do_dispatch_tasks(current_relay_block_number, remaining_weight)
 			}
 		}
 
@@ -140,19 +308,138 @@ pub mod module {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
+		/// Schedule a task to be dispatched on idle.
+		///
+		/// The dispatch origin of this call must be Root.
+		///
+		/// - `task`: the task to schedule.
+		/// - `priority`: the dispatch priority, the priority of the task if `None`.
 		#[pallet::weight(< T as Config >::WeightInfo::schedule_task())]
-		pub fn schedule_task(origin: OriginFor<T>, task: T::Task) -> DispatchResult {
+		pub fn schedule_task(origin: OriginFor<T>, task: T::Task, priority: Option<TaskPriority>) -> DispatchResult {
 			ensure_root(origin)?;
-			Self::do_schedule_task(task)
+			let priority = priority.unwrap_or_else(|| task.priority());
+			Self::do_schedule_task(task, priority)
+		}
+
+		/// Cancel a queued or dead letter task.
+		///
+		/// The dispatch origin of this call must be Root.
+		///
+		/// - `task_id`: the task to cancel.
+		#[pallet::weight(< T as Config >::WeightInfo::cancel_task())]
+		pub fn cancel_task(origin: OriginFor<T>, task_id: Nonce) -> DispatchResult {
+			ensure_root(origin)?;
+			if Tasks::<T>::take(task_id).is_some() {
+				// the task may be queued with a priority other than its own
+				for priority in TaskPriority::all() {
+					TaskQueues::<T>::remove(priority, task_id);
+				}
+				TaskRetries::<T>::remove(task_id);
+			} else {
+				DeadLetterTasks::<T>::take(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			}
+			Self::deposit_event(Event::<T>::TaskCancelled { task_id });
+			Ok(())
+		}
+
+		/// Register a task dispatched every `period` relay chain blocks.
+		///
+		/// The dispatch origin of this call must be Root, or `Signed` to reserve
+		/// RecurringTaskDeposit for the task allowed by RecurringTaskFilter.
+		///
+		/// - `task`: the recurring task.
+		/// - `period`: the relay chain blocks between dispatches.
+		#[pallet::weight(< T as Config >::WeightInfo::schedule_recurring_task())]
+		#[transactional]
+		pub fn schedule_recurring_task(origin: OriginFor<T>, task: T::Task, period: BlockNumber) -> DispatchResult {
+			let owner = Self::ensure_signed_or_root(origin)?;
+			ensure!(!period.is_zero(), Error::<T>::InvalidPeriod);
+			let count = Self::recurring_task_count();
+			ensure!(count < T::MaxRecurringTasks::get(), Error::<T>::TooManyRecurringTasks);
+
+			let deposit = match &owner {
+				Some(who) => {
+					ensure!(T::RecurringTaskFilter::contains(&task), Error::<T>::TaskNotAllowed);
+					let deposit = T::RecurringTaskDeposit::get();
+					T::Currency::reserve_named(&RESERVE_ID, who, deposit)?;
+					deposit
+				}
+				None => Zero::zero(),
+			};
+
+			let id = Self::get_next_task_id()?;
+			let next_run_at = T::RelayChainBlockNumberProvider::current_block_number().saturating_add(period);
+			RecurringTasks::<T>::insert(
+				id,
+				RecurringTask {
+					task,
+					owner: owner.clone(),
+					deposit,
+					period,
+					next_run_at,
+					paused: false,
+				},
+			);
+			RecurringTaskCount::<T>::put(count.saturating_add(1));
+
+			Self::deposit_event(Event::<T>::RecurringTaskScheduled {
+				task_id: id,
+				owner,
+				period,
+			});
+			Ok(())
+		}
+
+		/// Pause or resume a recurring task.
+		///
+		/// The dispatch origin of this call must be Root or the owner of the task.
+		///
+		/// - `task_id`: the recurring task.
+		/// - `paused`: pause or resume the task.
+		#[pallet::weight(< T as Config >::WeightInfo::set_recurring_task_paused())]
+		pub fn set_recurring_task_paused(origin: OriginFor<T>, task_id: Nonce, paused: bool) -> DispatchResult {
+			let who = Self::ensure_signed_or_root(origin)?;
+			RecurringTasks::<T>::try_mutate(task_id, |maybe_task| -> DispatchResult {
+				let recurring = maybe_task.as_mut().ok_or(Error::<T>::TaskNotFound)?;
+				Self::ensure_owner_or_root(&who, recurring)?;
+				recurring.paused = paused;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::RecurringTaskPaused { task_id, paused });
+			Ok(())
+		}
+
+		/// Cancel a recurring task and unreserve the deposit.
+		///
+		/// The dispatch origin of this call must be Root or the owner of the task.
+		///
+		/// - `task_id`: the recurring task.
+		#[pallet::weight(< T as Config >::WeightInfo::cancel_recurring_task())]
+		pub fn cancel_recurring_task(origin: OriginFor<T>, task_id: Nonce) -> DispatchResult {
+			let who = Self::ensure_signed_or_root(origin)?;
+			let recurring = Self::recurring_tasks(task_id).ok_or(Error::<T>::TaskNotFound)?;
+			Self::ensure_owner_or_root(&who, &recurring)?;
+
+			if let Some(owner) = &recurring.owner {
+				T::Currency::unreserve_named(&RESERVE_ID, owner, recurring.deposit);
+			}
+			RecurringTasks::<T>::remove(task_id);
+			RecurringTaskHistory::<T>::remove(task_id);
+			RecurringTaskCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+
+			Self::deposit_event(Event::<T>::RecurringTaskCancelled { task_id });
+			Ok(())
 		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	/// Add the task to the queue to be dispatched later.
-	fn do_schedule_task(task: T::Task) -> DispatchResult {
+	/// Add the task to the queue of `priority` to be dispatched later.
+	fn do_schedule_task(task: T::Task, priority: TaskPriority) -> DispatchResult {
 		let id = Self::get_next_task_id()?;
 		Tasks::<T>::insert(id, &task);
+		TaskQueues::<T>::insert(priority, id, ());
 		Self::deposit_event(Event::<T>::TaskAdded { task_id: id, task });
 		Ok(())
 	}
@@ -166,50 +453,190 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	fn ensure_signed_or_root(origin: OriginFor<T>) -> Result<Option<T::AccountId>, DispatchError> {
+		match origin.into() {
+			Ok(frame_system::RawOrigin::Signed(who)) => Ok(Some(who)),
+			Ok(frame_system::RawOrigin::Root) => Ok(None),
+			_ => Err(DispatchError::BadOrigin),
+		}
+	}
+
+	fn ensure_owner_or_root(
+		who: &Option<T::AccountId>,
+		recurring: &RecurringTask<T::AccountId, BalanceOf<T>, T::Task>,
+	) -> DispatchResult {
+		if who.is_some() {
+			ensure!(*who == recurring.owner, Error::<T>::NoPermission);
+		}
+		Ok(())
+	}
+
+	/// Dispatch the recurring tasks due at `relay_block`, until insufficient weight remains.
+	pub fn do_dispatch_recurring_tasks(relay_block: BlockNumber, total_weight: Weight) -> Weight {
+		let mut weight_remaining = total_weight.saturating_sub(T::DbWeight::get().reads(1));
+		if Self::recurring_task_count().is_zero() || weight_remaining <= T::MinimumWeightRemainInBlock::get() {
+			return total_weight.saturating_sub(weight_remaining);
+		}
+
+		let mut dispatched_tasks: Vec<(Nonce, TaskResult)> = vec![];
+		for (id, recurring) in RecurringTasks::<T>::iter() {
+			weight_remaining = weight_remaining.saturating_sub(T::DbWeight::get().reads(1));
+			if recurring.paused || recurring.next_run_at > relay_block {
+				continue;
+			}
+			if recurring.task.max_weight() > weight_remaining.saturating_sub(T::MinimumWeightRemainInBlock::get()) {
+				continue;
+			}
+
+			let result = recurring.task.dispatch(weight_remaining);
+			weight_remaining = weight_remaining
+				.saturating_sub(result.used_weight)
+				.saturating_sub(T::DbWeight::get().reads_writes(1, 2));
+			dispatched_tasks.push((id, result));
+
+			// If remaining weight falls below the minimmum, break from the loop.
+			if weight_remaining <= T::MinimumWeightRemainInBlock::get() {
+				break;
+			}
+		}
+
+		for (id, result) in dispatched_tasks {
+			// the unfinished task is dispatched again on the next idle block
+			if result.finished || result.result.is_err() {
+				RecurringTasks::<T>::mutate(id, |maybe_task| {
+					if let Some(recurring) = maybe_task {
+						recurring.next_run_at = relay_block.saturating_add(recurring.period);
+					}
+				});
+			}
+			RecurringTaskHistory::<T>::mutate(id, |history| {
+				if !history.is_empty() && history.len() as u32 >= T::MaxTaskHistory::get() {
+					history.remove(0);
+				}
+				let _ = history.try_push(TaskExecution {
+					relay_block,
+					result: result.result,
+					used_weight: result.used_weight,
+				});
+			});
+			Self::deposit_event(Event::<T>::RecurringTaskDispatched {
+				task_id: id,
+				result: result.result,
+			});
+		}
+
+		total_weight.saturating_sub(weight_remaining)
+	}
+
 	/// Keep dispatching tasks in Storage, until insufficient weight remains.
-	pub fn do_dispatch_tasks(total_weight: Weight) -> Weight {
+	///
+	/// The high priority tasks are dispatched before the recurring tasks due at `relay_block`, so
+	/// the recurring tasks can't starve them. The normal and low priority tasks are dispatched
+	/// with the weight left.
+	pub fn do_dispatch_tasks(relay_block: BlockNumber, total_weight: Weight) -> Weight {
 		let mut weight_remaining = total_weight.saturating_sub(T::WeightInfo::on_idle_base());
 		if weight_remaining <= T::MinimumWeightRemainInBlock::get() {
 			// return total weight so no `on_idle` hook will execute after IdleScheduler
 			return total_weight;
 		}
 
-		let mut completed_tasks: Vec<(Nonce, TaskResult)> = vec![];
+		let now = frame_system::Pallet::<T>::block_number();
+		let mut dispatched_tasks: Vec<(Nonce, TaskPriority, TaskResult)> = vec![];
 
-		for (id, task) in Tasks::<T>::iter() {
-			let result = task.dispatch(weight_remaining);
-			weight_remaining = weight_remaining.saturating_sub(result.used_weight);
-			if result.finished {
-				completed_tasks.push((id, result));
-				weight_remaining = weight_remaining.saturating_sub(T::WeightInfo::clear_tasks());
+		for priority in TaskPriority::all() {
+			if priority == TaskPriority::Normal && weight_remaining > T::MinimumWeightRemainInBlock::get() {
+				let used_weight = Self::do_dispatch_recurring_tasks(relay_block, weight_remaining);
+				weight_remaining = weight_remaining.saturating_sub(used_weight);
 			}
-
-			// If remaining weight falls below the minimmum, break from the loop.
 			if weight_remaining <= T::MinimumWeightRemainInBlock::get() {
 				break;
 			}
+
+			for (id, ()) in TaskQueues::<T>::iter_prefix(priority) {
+				weight_remaining = weight_remaining.saturating_sub(T::DbWeight::get().reads(2));
+
+				let task = match Tasks::<T>::get(id) {
+					Some(task) => task,
+					None => continue,
+				};
+				if TaskRetries::<T>::get(id).map_or(false, |retry| retry.retry_at > now) {
+					continue;
+				}
+				// skip the task until enough weight remains, the smaller tasks may fit
+				if task.max_weight() > weight_remaining.saturating_sub(T::MinimumWeightRemainInBlock::get()) {
+					continue;
+				}
+
+				let result = task.dispatch(weight_remaining);
+				weight_remaining = weight_remaining.saturating_sub(result.used_weight);
+				if result.finished || result.result.is_err() {
+					weight_remaining = weight_remaining.saturating_sub(T::WeightInfo::clear_tasks());
+				}
+				dispatched_tasks.push((id, priority, result));
+
+				// If remaining weight falls below the minimmum, break from the loop.
+				if weight_remaining <= T::MinimumWeightRemainInBlock::get() {
+					break;
+				}
+			}
 		}
 
-		Self::remove_completed_tasks(completed_tasks);
+		Self::on_tasks_dispatched(now, dispatched_tasks);
 
 		total_weight.saturating_sub(weight_remaining)
 	}
 
-	/// Removes completed tasks and deposits events.
-	pub fn remove_completed_tasks(completed_tasks: Vec<(Nonce, TaskResult)>) {
-		// Deposit event and remove completed tasks.
-		for (id, result) in completed_tasks {
-			Self::deposit_event(Event::<T>::TaskDispatched {
-				task_id: id,
-				result: result.result,
-			});
-			Tasks::<T>::remove(id);
+	/// Removes finished tasks, retries or dead letters failed tasks and deposits events.
+	pub fn on_tasks_dispatched(now: T::BlockNumber, dispatched_tasks: Vec<(Nonce, TaskPriority, TaskResult)>) {
+		for (id, priority, result) in dispatched_tasks {
+			// a finished task is removed even if it failed, dispatching it again can't succeed
+			if result.finished {
+				Self::remove_task(id, priority);
+				Self::deposit_event(Event::<T>::TaskDispatched {
+					task_id: id,
+					result: result.result,
+				});
+				continue;
+			}
+
+			match result.result {
+				// the task made progress, reset the retries
+				Ok(()) => TaskRetries::<T>::remove(id),
+				Err(error) => {
+					let retries = TaskRetries::<T>::get(id)
+						.map_or(0, |retry| retry.retries)
+						.saturating_add(1);
+					if retries > T::MaxRetries::get() {
+						if let Some(task) = Self::remove_task(id, priority) {
+							DeadLetterTasks::<T>::insert(id, (task, error));
+						}
+						Self::deposit_event(Event::<T>::TaskDeadLettered { task_id: id, error });
+					} else {
+						let backoff = T::RetryBackoff::get().saturating_mul(2u32.saturating_pow(retries - 1).into());
+						let retry_at = now.saturating_add(backoff);
+						TaskRetries::<T>::insert(id, TaskRetry { retries, retry_at });
+						Self::deposit_event(Event::<T>::TaskRetryScheduled {
+							task_id: id,
+							error,
+							retries,
+							retry_at,
+						});
+					}
+				}
+			}
 		}
 	}
+
+	fn remove_task(id: Nonce, priority: TaskPriority) -> Option<T::Task> {
+		TaskQueues::<T>::remove(priority, id);
+		TaskRetries::<T>::remove(id);
+		Tasks::<T>::take(id)
+	}
 }
 
 impl<T: Config> IdleScheduler<T::Task> for Pallet<T> {
 	fn schedule(task: T::Task) -> DispatchResult {
-		Self::do_schedule_task(task)
+		let priority = task.priority();
+		Self::do_schedule_task(task, priority)
 	}
 }
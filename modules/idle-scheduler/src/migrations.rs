@@ -0,0 +1,105 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{Config, DispatchableTask, Pallet, TaskQueues, Tasks};
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+/// Migrate the idle scheduler storage to v1. At most `MaxTasks` tasks are queued, the tasks
+/// left out are not dispatched and can be cancelled by `cancel_task`.
+pub struct MigrateToV1<T, MaxTasks>(PhantomData<(T, MaxTasks)>);
+impl<T: Config, MaxTasks: Get<u32>> OnRuntimeUpgrade for MigrateToV1<T, MaxTasks> {
+	fn on_runtime_upgrade() -> Weight {
+		v1::migrate::<T>(MaxTasks::get())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		v1::pre_migrate::<T>(MaxTasks::get())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		v1::post_migrate::<T>()
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Queues up to `max_tasks` of the tasks scheduled before the priority queues in
+	/// `TaskQueues`.
+	pub fn migrate<T: Config>(max_tasks: u32) -> Weight {
+		let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_storage_version >= 1 {
+			log::warn!(
+				target: "idle-scheduler",
+				"Attempted to apply migration to v1 but failed because storage version is {:?}",
+				on_chain_storage_version,
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: "idle-scheduler", "Migrating idle-scheduler to v1");
+
+		let mut count: Weight = 0;
+		for (id, task) in Tasks::<T>::iter().take(max_tasks as usize) {
+			TaskQueues::<T>::insert(task.priority(), id, ());
+			count += 1;
+		}
+		if Tasks::<T>::iter_keys().nth(max_tasks as usize).is_some() {
+			log::error!(
+				target: "idle-scheduler",
+				"More than {:?} tasks scheduled, the tasks left out are not queued",
+				max_tasks,
+			);
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!(target: "idle-scheduler", "Completed idle-scheduler migration to v1, {:?} tasks", count);
+
+		T::DbWeight::get().reads_writes(count.saturating_add(2), count.saturating_add(1))
+	}
+
+	pub fn pre_migrate<T: Config>(max_tasks: u32) -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() < 1,
+			"idle-scheduler already migrated to v1."
+		);
+		assert!(
+			Tasks::<T>::iter_keys().count() <= max_tasks as usize,
+			"too many tasks to migrate."
+		);
+		Ok(())
+	}
+
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		assert_eq!(
+			Pallet::<T>::on_chain_storage_version(),
+			1,
+			"idle-scheduler storage version not set."
+		);
+		for (id, task) in Tasks::<T>::iter() {
+			assert!(TaskQueues::<T>::contains_key(task.priority(), id), "task not queued.");
+		}
+		Ok(())
+	}
+}
@@ -25,7 +25,7 @@ use acala_primitives::{define_combined_task, task::TaskResult};
 use frame_support::weights::Weight;
 use frame_support::{
 	construct_runtime,
-	traits::{ConstU32, ConstU64, Everything},
+	traits::{ConstU128, ConstU32, ConstU64, Contains, Everything},
 };
 use module_support::DispatchableTask;
 pub use sp_runtime::offchain::storage::StorageValueRef;
@@ -38,6 +38,10 @@ pub const BASE_WEIGHT: Weight = 1_000_000;
 pub const RELAY_BLOCK_KEY: [u8; 32] = [0; 32];
 
 pub type AccountId = u32;
+pub type Balance = u128;
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
 impl frame_system::Config for Runtime {
 	type BaseCallFilter = Everything;
 	type Origin = Origin;
@@ -56,7 +60,7 @@ impl frame_system::Config for Runtime {
 	type DbWeight = ();
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -65,6 +69,18 @@ impl frame_system::Config for Runtime {
 	type MaxConsumers = ConstU32<16>;
 }
 
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = frame_system::Pallet<Runtime>;
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
+	type WeightInfo = ();
+}
+
 pub struct MockBlockNumberProvider;
 
 impl BlockNumberProvider for MockBlockNumberProvider {
@@ -83,6 +99,20 @@ impl module_idle_scheduler::Config for Runtime {
 	type MinimumWeightRemainInBlock = ConstU64<100_000_000_000>;
 	type RelayChainBlockNumberProvider = MockBlockNumberProvider;
 	type DisableBlockThreshold = ConstU32<6>;
+	type MaxRetries = ConstU32<3>;
+	type RetryBackoff = ConstU64<10>;
+	type Currency = Balances;
+	type RecurringTaskDeposit = ConstU128<100>;
+	type RecurringTaskFilter = RecurringTaskFilter;
+	type MaxRecurringTasks = ConstU32<2>;
+	type MaxTaskHistory = ConstU32<2>;
+}
+
+pub struct RecurringTaskFilter;
+impl Contains<ScheduledTasks> for RecurringTaskFilter {
+	fn contains(task: &ScheduledTasks) -> bool {
+		matches!(task, ScheduledTasks::BalancesTask(_))
+	}
 }
 
 // Mock dispatachable tasks
@@ -116,11 +146,41 @@ impl DispatchableTask for HomaLiteTask {
 	}
 }
 
+#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
+pub struct PriorityTask {
+	pub priority: TaskPriority,
+	pub max_weight: Weight,
+	pub fail: bool,
+	pub finished: bool,
+}
+impl DispatchableTask for PriorityTask {
+	fn dispatch(self, _weight: Weight) -> TaskResult {
+		TaskResult {
+			result: if self.fail {
+				Err(DispatchError::BadOrigin)
+			} else {
+				Ok(())
+			},
+			used_weight: BASE_WEIGHT,
+			finished: self.finished,
+		}
+	}
+
+	fn priority(&self) -> TaskPriority {
+		self.priority
+	}
+
+	fn max_weight(&self) -> Weight {
+		self.max_weight
+	}
+}
+
 define_combined_task! {
 	#[derive(Clone, Debug, PartialEq, Encode, Decode, TypeInfo)]
 	pub enum ScheduledTasks {
 		BalancesTask(BalancesTask),
 		HomaLiteTask(HomaLiteTask),
+		PriorityTask(PriorityTask),
 	}
 }
 
@@ -134,6 +194,7 @@ construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic
 	{
 		System: frame_system::{Pallet, Call, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		IdleScheduler: module_idle_scheduler::{Pallet, Call, Event<T>, Storage},
 	}
 );
@@ -142,10 +203,16 @@ construct_runtime!(
 pub struct ExtBuilder;
 impl ExtBuilder {
 	pub fn build(self) -> sp_io::TestExternalities {
-		let t = frame_system::GenesisConfig::default()
+		let mut t = frame_system::GenesisConfig::default()
 			.build_storage::<Runtime>()
 			.unwrap();
 
+		pallet_balances::GenesisConfig::<Runtime> {
+			balances: vec![(ALICE, 1000), (BOB, 1000)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext.execute_with(|| sp_io::storage::set(&RELAY_BLOCK_KEY, &0_u32.encode()));
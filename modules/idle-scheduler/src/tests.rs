@@ -22,7 +22,7 @@
 
 use super::*;
 use crate::mock::{Event, IdleScheduler, *};
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 
 // Can schedule tasks
 #[test]
@@ -32,7 +32,8 @@ fn can_schedule_tasks() {
 
 		assert_ok!(IdleScheduler::schedule_task(
 			Origin::root(),
-			ScheduledTasks::BalancesTask(BalancesTask::OnIdle)
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			None
 		));
 		assert_eq!(
 			Tasks::<Runtime>::get(0),
@@ -45,7 +46,8 @@ fn can_schedule_tasks() {
 
 		assert_ok!(IdleScheduler::schedule_task(
 			Origin::root(),
-			ScheduledTasks::HomaLiteTask(HomaLiteTask::OnIdle)
+			ScheduledTasks::HomaLiteTask(HomaLiteTask::OnIdle),
+			None
 		));
 		assert_eq!(
 			Tasks::<Runtime>::get(1),
@@ -62,15 +64,18 @@ fn can_process_tasks_up_to_weight_limit() {
 	ExtBuilder::default().build().execute_with(|| {
 		assert_ok!(IdleScheduler::schedule_task(
 			Origin::root(),
-			ScheduledTasks::BalancesTask(BalancesTask::OnIdle)
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			None
 		));
 		assert_ok!(IdleScheduler::schedule_task(
 			Origin::root(),
-			ScheduledTasks::BalancesTask(BalancesTask::OnIdle)
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			None
 		));
 		assert_ok!(IdleScheduler::schedule_task(
 			Origin::root(),
-			ScheduledTasks::HomaLiteTask(HomaLiteTask::OnIdle)
+			ScheduledTasks::HomaLiteTask(HomaLiteTask::OnIdle),
+			None
 		));
 
 		// Given enough weights for only 2 tasks: MinimumWeightRemainInBlock::get() + BASE_WEIGHT*2 +
@@ -103,7 +108,8 @@ fn can_increment_next_task_id() {
 		assert_eq!(NextTaskId::<Runtime>::get(), 0);
 		assert_ok!(IdleScheduler::schedule_task(
 			Origin::root(),
-			ScheduledTasks::BalancesTask(BalancesTask::OnIdle)
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			None
 		));
 
 		assert_eq!(NextTaskId::<Runtime>::get(), 1);
@@ -116,7 +122,8 @@ fn on_idle_works() {
 		IdleScheduler::on_initialize(0);
 		assert_ok!(IdleScheduler::schedule_task(
 			Origin::root(),
-			ScheduledTasks::BalancesTask(BalancesTask::OnIdle)
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			None
 		));
 		// simulate relay block number jumping 10 blocks
 		sp_io::storage::set(&RELAY_BLOCK_KEY, &10_u32.encode());
@@ -132,3 +139,454 @@ fn on_idle_works() {
 		assert!(!PreviousRelayBlockNumber::<Runtime>::exists());
 	});
 }
+
+#[test]
+fn dispatch_tasks_by_priority_and_max_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		let task = |priority, max_weight| {
+			ScheduledTasks::PriorityTask(PriorityTask {
+				priority,
+				max_weight,
+				fail: false,
+				finished: true,
+			})
+		};
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			task(TaskPriority::Low, 0),
+			None
+		));
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			task(TaskPriority::High, 1_000_000_000),
+			None
+		));
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			task(TaskPriority::Normal, 0),
+			None
+		));
+		assert!(TaskQueues::<Runtime>::contains_key(TaskPriority::High, 1));
+
+		// Given enough weight for only 1 task, the high priority task declares more weight and is skipped.
+		IdleScheduler::on_idle(0, 100_001_000_000 + <()>::on_idle_base() + <()>::clear_tasks());
+		assert!(Tasks::<Runtime>::contains_key(0));
+		assert!(Tasks::<Runtime>::contains_key(1));
+		assert!(!Tasks::<Runtime>::contains_key(2));
+		assert!(!TaskQueues::<Runtime>::contains_key(TaskPriority::Normal, 2));
+
+		// the high priority task is dispatched first
+		System::reset_events();
+		IdleScheduler::on_idle(0, u64::MAX);
+		let dispatched: Vec<Nonce> = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				Event::IdleScheduler(crate::Event::TaskDispatched { task_id, .. }) => Some(task_id),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(dispatched, vec![1, 0]);
+		assert_eq!(Tasks::<Runtime>::iter().count(), 0);
+		assert_eq!(TaskQueues::<Runtime>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn high_priority_tasks_are_dispatched_before_recurring_tasks() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_recurring_task(
+			Origin::root(),
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			5
+		));
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			ScheduledTasks::HomaLiteTask(HomaLiteTask::OnIdle),
+			Some(TaskPriority::High)
+		));
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			ScheduledTasks::HomaLiteTask(HomaLiteTask::OnIdle),
+			Some(TaskPriority::Normal)
+		));
+
+		// Given enough weight for only 1 task, the high priority task is dispatched.
+		IdleScheduler::do_dispatch_tasks(5, 100_001_000_000 + <()>::on_idle_base() + <()>::clear_tasks());
+		assert_eq!(Tasks::<Runtime>::get(0), None);
+		assert!(Tasks::<Runtime>::contains_key(1));
+		assert_eq!(IdleScheduler::recurring_tasks(0).unwrap().next_run_at, 5);
+
+		// the recurring task is dispatched before the normal priority task
+		IdleScheduler::do_dispatch_tasks(5, 100_001_000_000 + <()>::on_idle_base());
+		assert_eq!(IdleScheduler::recurring_tasks(0).unwrap().next_run_at, 10);
+		assert!(Tasks::<Runtime>::contains_key(1));
+
+		IdleScheduler::do_dispatch_tasks(5, 100_001_000_000 + <()>::on_idle_base() + <()>::clear_tasks());
+		assert_eq!(Tasks::<Runtime>::get(1), None);
+	});
+}
+
+#[test]
+fn migrate_to_v1_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let task = |priority| {
+			ScheduledTasks::PriorityTask(PriorityTask {
+				priority,
+				max_weight: 0,
+				fail: false,
+				finished: true,
+			})
+		};
+		Tasks::<Runtime>::insert(0, task(TaskPriority::High));
+		Tasks::<Runtime>::insert(1, task(TaskPriority::Low));
+		Tasks::<Runtime>::insert(2, task(TaskPriority::Normal));
+		StorageVersion::new(0).put::<IdleScheduler>();
+
+		crate::migrations::v1::migrate::<Runtime>(2);
+		assert_eq!(IdleScheduler::on_chain_storage_version(), 1);
+		assert_eq!(TaskQueues::<Runtime>::iter().count(), 2);
+
+		// the tasks left out can be cancelled
+		let (left_out, _) = Tasks::<Runtime>::iter()
+			.find(|(id, task)| !TaskQueues::<Runtime>::contains_key(task.priority(), id))
+			.unwrap();
+		assert_ok!(IdleScheduler::cancel_task(Origin::root(), left_out));
+
+		// migrating again is a no-op
+		crate::migrations::v1::migrate::<Runtime>(2);
+		assert_eq!(TaskQueues::<Runtime>::iter().count(), 2);
+		for (id, task) in Tasks::<Runtime>::iter() {
+			assert!(TaskQueues::<Runtime>::contains_key(task.priority(), id));
+		}
+	});
+}
+
+#[test]
+fn failed_tasks_retry_and_dead_letter() {
+	ExtBuilder::default().build().execute_with(|| {
+		let task = ScheduledTasks::PriorityTask(PriorityTask {
+			priority: TaskPriority::Normal,
+			max_weight: 0,
+			fail: true,
+			finished: false,
+		});
+		assert_ok!(IdleScheduler::schedule_task(Origin::root(), task.clone(), None));
+
+		IdleScheduler::on_idle(1, u64::MAX);
+		System::assert_last_event(Event::IdleScheduler(crate::Event::TaskRetryScheduled {
+			task_id: 0,
+			error: DispatchError::BadOrigin,
+			retries: 1,
+			retry_at: 11,
+		}));
+
+		// not dispatched before retry_at
+		System::reset_events();
+		IdleScheduler::on_idle(1, u64::MAX);
+		assert_eq!(System::events(), vec![]);
+
+		System::set_block_number(11);
+		IdleScheduler::on_idle(11, u64::MAX);
+		assert_eq!(
+			IdleScheduler::task_retries(0),
+			Some(TaskRetry {
+				retries: 2,
+				retry_at: 31
+			})
+		);
+
+		System::set_block_number(31);
+		IdleScheduler::on_idle(31, u64::MAX);
+		assert_eq!(
+			IdleScheduler::task_retries(0),
+			Some(TaskRetry {
+				retries: 3,
+				retry_at: 71
+			})
+		);
+
+		System::set_block_number(71);
+		IdleScheduler::on_idle(71, u64::MAX);
+		System::assert_last_event(Event::IdleScheduler(crate::Event::TaskDeadLettered {
+			task_id: 0,
+			error: DispatchError::BadOrigin,
+		}));
+		assert_eq!(Tasks::<Runtime>::get(0), None);
+		assert_eq!(IdleScheduler::task_retries(0), None);
+		assert!(!TaskQueues::<Runtime>::contains_key(TaskPriority::Normal, 0));
+		assert_eq!(
+			IdleScheduler::dead_letter_tasks(0),
+			Some((task, DispatchError::BadOrigin))
+		);
+	});
+}
+
+#[test]
+fn finished_failed_tasks_are_not_retried() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			ScheduledTasks::PriorityTask(PriorityTask {
+				priority: TaskPriority::Normal,
+				max_weight: 0,
+				fail: true,
+				finished: true,
+			}),
+			None
+		));
+
+		IdleScheduler::on_idle(1, u64::MAX);
+		System::assert_last_event(Event::IdleScheduler(crate::Event::TaskDispatched {
+			task_id: 0,
+			result: Err(DispatchError::BadOrigin),
+		}));
+		assert_eq!(Tasks::<Runtime>::get(0), None);
+		assert_eq!(IdleScheduler::task_retries(0), None);
+		assert_eq!(IdleScheduler::dead_letter_tasks(0), None);
+		assert_eq!(TaskQueues::<Runtime>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn schedule_task_with_priority_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			Some(TaskPriority::Low)
+		));
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			ScheduledTasks::HomaLiteTask(HomaLiteTask::OnIdle),
+			Some(TaskPriority::High)
+		));
+		assert!(TaskQueues::<Runtime>::contains_key(TaskPriority::Low, 0));
+		assert!(TaskQueues::<Runtime>::contains_key(TaskPriority::High, 1));
+
+		assert_ok!(IdleScheduler::cancel_task(Origin::root(), 0));
+		assert_eq!(TaskQueues::<Runtime>::iter().count(), 1);
+
+		IdleScheduler::on_idle(1, u64::MAX);
+		System::assert_last_event(Event::IdleScheduler(crate::Event::TaskDispatched {
+			task_id: 1,
+			result: Ok(()),
+		}));
+	});
+}
+
+#[test]
+fn cancel_task_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_task(
+			Origin::root(),
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			None
+		));
+		DeadLetterTasks::<Runtime>::insert(
+			1,
+			(
+				ScheduledTasks::HomaLiteTask(HomaLiteTask::OnIdle),
+				DispatchError::BadOrigin,
+			),
+		);
+
+		assert_noop!(
+			IdleScheduler::cancel_task(Origin::signed(1), 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			IdleScheduler::cancel_task(Origin::root(), 2),
+			Error::<Runtime>::TaskNotFound
+		);
+
+		assert_ok!(IdleScheduler::cancel_task(Origin::root(), 0));
+		System::assert_last_event(Event::IdleScheduler(crate::Event::TaskCancelled { task_id: 0 }));
+		assert_eq!(Tasks::<Runtime>::get(0), None);
+		assert!(!TaskQueues::<Runtime>::contains_key(TaskPriority::Normal, 0));
+
+		assert_ok!(IdleScheduler::cancel_task(Origin::root(), 1));
+		assert_eq!(IdleScheduler::dead_letter_tasks(1), None);
+	});
+}
+
+#[test]
+fn schedule_recurring_task_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_recurring_task(
+			Origin::root(),
+			ScheduledTasks::HomaLiteTask(HomaLiteTask::OnIdle),
+			5
+		));
+		System::assert_last_event(Event::IdleScheduler(crate::Event::RecurringTaskScheduled {
+			task_id: 0,
+			owner: None,
+			period: 5,
+		}));
+		assert_eq!(
+			IdleScheduler::recurring_tasks(0),
+			Some(RecurringTask {
+				task: ScheduledTasks::HomaLiteTask(HomaLiteTask::OnIdle),
+				owner: None,
+				deposit: 0,
+				period: 5,
+				next_run_at: 5,
+				paused: false,
+			})
+		);
+
+		assert_noop!(
+			IdleScheduler::schedule_recurring_task(
+				Origin::signed(ALICE),
+				ScheduledTasks::HomaLiteTask(HomaLiteTask::OnIdle),
+				5
+			),
+			Error::<Runtime>::TaskNotAllowed
+		);
+		assert_noop!(
+			IdleScheduler::schedule_recurring_task(
+				Origin::signed(ALICE),
+				ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+				0
+			),
+			Error::<Runtime>::InvalidPeriod
+		);
+
+		assert_ok!(IdleScheduler::schedule_recurring_task(
+			Origin::signed(ALICE),
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			10
+		));
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &ALICE), 100);
+		assert_eq!(IdleScheduler::recurring_task_count(), 2);
+		assert_noop!(
+			IdleScheduler::schedule_recurring_task(
+				Origin::root(),
+				ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+				10
+			),
+			Error::<Runtime>::TooManyRecurringTasks
+		);
+
+		assert_noop!(
+			IdleScheduler::set_recurring_task_paused(Origin::signed(BOB), 1, true),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			IdleScheduler::set_recurring_task_paused(Origin::signed(ALICE), 0, true),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(IdleScheduler::set_recurring_task_paused(Origin::signed(ALICE), 1, true));
+		System::assert_last_event(Event::IdleScheduler(crate::Event::RecurringTaskPaused {
+			task_id: 1,
+			paused: true,
+		}));
+		assert!(IdleScheduler::recurring_tasks(1).unwrap().paused);
+
+		assert_noop!(
+			IdleScheduler::cancel_recurring_task(Origin::signed(BOB), 1),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			IdleScheduler::cancel_recurring_task(Origin::root(), 2),
+			Error::<Runtime>::TaskNotFound
+		);
+		assert_ok!(IdleScheduler::cancel_recurring_task(Origin::root(), 1));
+		System::assert_last_event(Event::IdleScheduler(crate::Event::RecurringTaskCancelled {
+			task_id: 1,
+		}));
+		assert_eq!(IdleScheduler::recurring_tasks(1), None);
+		assert_eq!(IdleScheduler::recurring_task_count(), 1);
+		assert_eq!(Balances::reserved_balance_named(&RESERVE_ID, &ALICE), 0);
+	});
+}
+
+#[test]
+fn dispatch_recurring_tasks_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(IdleScheduler::schedule_recurring_task(
+			Origin::root(),
+			ScheduledTasks::BalancesTask(BalancesTask::OnIdle),
+			5
+		));
+		let execution = |relay_block| TaskExecution {
+			relay_block,
+			result: Ok(()),
+			used_weight: BASE_WEIGHT,
+		};
+
+		// not due yet
+		System::reset_events();
+		IdleScheduler::do_dispatch_recurring_tasks(4, u64::MAX);
+		assert_eq!(System::events(), vec![]);
+
+		assert_eq!(IdleScheduler::do_dispatch_recurring_tasks(5, u64::MAX), BASE_WEIGHT);
+		System::assert_last_event(Event::IdleScheduler(crate::Event::RecurringTaskDispatched {
+			task_id: 0,
+			result: Ok(()),
+		}));
+		assert_eq!(IdleScheduler::recurring_tasks(0).unwrap().next_run_at, 10);
+		assert_eq!(
+			IdleScheduler::recurring_task_history(0).into_inner(),
+			vec![execution(5)]
+		);
+
+		IdleScheduler::do_dispatch_recurring_tasks(9, u64::MAX);
+		IdleScheduler::do_dispatch_recurring_tasks(12, u64::MAX);
+		assert_eq!(IdleScheduler::recurring_tasks(0).unwrap().next_run_at, 17);
+		assert_eq!(
+			IdleScheduler::recurring_task_history(0).into_inner(),
+			vec![execution(5), execution(12)]
+		);
+
+		// keeps the last MaxTaskHistory executions
+		IdleScheduler::do_dispatch_recurring_tasks(17, u64::MAX);
+		assert_eq!(
+			IdleScheduler::recurring_task_history(0).into_inner(),
+			vec![execution(12), execution(17)]
+		);
+
+		// paused task is not dispatched
+		assert_ok!(IdleScheduler::set_recurring_task_paused(Origin::root(), 0, true));
+		IdleScheduler::do_dispatch_recurring_tasks(22, u64::MAX);
+		assert_eq!(IdleScheduler::recurring_tasks(0).unwrap().next_run_at, 22);
+
+		assert_ok!(IdleScheduler::set_recurring_task_paused(Origin::root(), 0, false));
+		IdleScheduler::do_dispatch_recurring_tasks(30, u64::MAX);
+		assert_eq!(IdleScheduler::recurring_tasks(0).unwrap().next_run_at, 35);
+		assert_eq!(
+			IdleScheduler::recurring_task_history(0).into_inner(),
+			vec![execution(17), execution(30)]
+		);
+
+		// not dispatched if weight is not enough
+		assert_eq!(IdleScheduler::do_dispatch_recurring_tasks(35, 100_000_000_000), 0);
+		assert_eq!(IdleScheduler::recurring_tasks(0).unwrap().next_run_at, 35);
+	});
+}
+
+#[test]
+fn unfinished_recurring_tasks_are_dispatched_until_finished() {
+	ExtBuilder::default().build().execute_with(|| {
+		let task = |fail| {
+			ScheduledTasks::PriorityTask(PriorityTask {
+				priority: TaskPriority::Normal,
+				max_weight: 0,
+				fail,
+				finished: false,
+			})
+		};
+		assert_ok!(IdleScheduler::schedule_recurring_task(Origin::root(), task(false), 5));
+		assert_ok!(IdleScheduler::schedule_recurring_task(Origin::root(), task(true), 5));
+
+		IdleScheduler::do_dispatch_recurring_tasks(5, u64::MAX);
+		// the unfinished task is dispatched again on the next idle block
+		assert_eq!(IdleScheduler::recurring_tasks(0).unwrap().next_run_at, 5);
+		// the failed task waits for the next period
+		assert_eq!(IdleScheduler::recurring_tasks(1).unwrap().next_run_at, 10);
+
+		IdleScheduler::do_dispatch_recurring_tasks(6, u64::MAX);
+		assert_eq!(IdleScheduler::recurring_tasks(0).unwrap().next_run_at, 5);
+		assert_eq!(IdleScheduler::recurring_task_history(0).len(), 2);
+		assert_eq!(IdleScheduler::recurring_task_history(1).len(), 1);
+	});
+}
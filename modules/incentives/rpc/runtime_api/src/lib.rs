@@ -0,0 +1,55 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::all)]
+
+use primitives::CurrencyId;
+use sp_runtime::{
+	codec::{Codec, Decode, Encode},
+	RuntimeDebug,
+};
+use sp_std::vec::Vec;
+use support::{PoolId, Rate};
+
+/// The incentives of an account in a pool.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct PoolRewardsInfo<Balance> {
+	/// The rewards pool.
+	pub pool_id: PoolId,
+	/// The shares of the account in the pool.
+	pub shares: Balance,
+	/// The rewards can be claimed, after the claim reward deduction.
+	pub pending_rewards: Vec<(CurrencyId, Balance)>,
+	/// The annual percentage rate of the pool rewards, `None` if the pool value or the
+	/// reward prices are not available.
+	pub apr: Option<Rate>,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait IncentivesApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// The pools with shares or pending rewards of `who`.
+		fn get_account_rewards(who: AccountId) -> Vec<PoolRewardsInfo<Balance>>;
+
+		/// The annual percentage rate of the pool rewards.
+		fn get_pool_apr(pool_id: PoolId) -> Option<Rate>;
+	}
+}
@@ -0,0 +1,120 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::error::{CallError, ErrorCode, ErrorObject},
+};
+use primitives::CurrencyId;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{codec::Codec, generic::BlockId, traits::Block as BlockT};
+use std::{marker::PhantomData, sync::Arc};
+use support::{PoolId, Rate};
+
+pub use module_incentives_rpc_runtime_api::{IncentivesApi as IncentivesRuntimeApi, PoolRewardsInfo};
+
+/// The incentives of an account in a pool.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolRewards {
+	pub pool_id: PoolId,
+	pub shares: NumberOrHex,
+	pub pending_rewards: Vec<(CurrencyId, NumberOrHex)>,
+	pub apr: Option<Rate>,
+}
+
+impl<Balance: Into<NumberOrHex>> From<PoolRewardsInfo<Balance>> for PoolRewards {
+	fn from(info: PoolRewardsInfo<Balance>) -> Self {
+		Self {
+			pool_id: info.pool_id,
+			shares: info.shares.into(),
+			pending_rewards: info
+				.pending_rewards
+				.into_iter()
+				.map(|(currency_id, amount)| (currency_id, amount.into()))
+				.collect(),
+			apr: info.apr,
+		}
+	}
+}
+
+/// Incentives rpc interface.
+#[rpc(client, server)]
+pub trait IncentivesApi<BlockHash, AccountId> {
+	/// Get the pools with shares or pending rewards of the account, with the claimable rewards
+	/// and the APR of the pools.
+	#[method(name = "incentives_getAccountRewards")]
+	fn get_account_rewards(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<PoolRewards>>;
+
+	/// Get the annual percentage rate of the pool rewards.
+	#[method(name = "incentives_getPoolApr")]
+	fn get_pool_apr(&self, pool_id: PoolId, at: Option<BlockHash>) -> RpcResult<Option<Rate>>;
+}
+
+fn internal_err<T: ToString>(message: T) -> JsonRpseeError {
+	JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+		ErrorCode::InternalError.code(),
+		message.to_string(),
+		None::<()>,
+	)))
+}
+
+pub struct Incentives<B, C, Balance> {
+	client: Arc<C>,
+	_marker: PhantomData<(B, Balance)>,
+}
+
+impl<B, C, Balance> Incentives<B, C, Balance> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+#[async_trait]
+impl<B, C, AccountId, Balance> IncentivesApiServer<<B as BlockT>::Hash, AccountId> for Incentives<B, C, Balance>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: IncentivesRuntimeApi<B, AccountId, Balance>,
+	AccountId: Codec + Send + Sync + 'static,
+	Balance: Codec + Into<NumberOrHex> + Send + Sync + 'static,
+{
+	fn get_account_rewards(&self, who: AccountId, at: Option<<B as BlockT>::Hash>) -> RpcResult<Vec<PoolRewards>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.get_account_rewards(&at, who)
+			.map(|rewards| rewards.into_iter().map(Into::into).collect())
+			.map_err(|err| internal_err(format!("Unable to get account rewards: {:?}", err)))
+	}
+
+	fn get_pool_apr(&self, pool_id: PoolId, at: Option<<B as BlockT>::Hash>) -> RpcResult<Option<Rate>> {
+		let api = self.client.runtime_api();
+		let at = BlockId::Hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		api.get_pool_apr(&at, pool_id)
+			.map_err(|err| internal_err(format!("Unable to get pool apr: {:?}", err)))
+	}
+}
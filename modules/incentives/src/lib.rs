@@ -37,22 +37,46 @@
 //! 2. DexSaving: periodicly(AccumulatePeriod), the reward currency is Stable(KUSD/AUSD),
 //! the accumulation amount is the multiplier of DexSavingRewardRates and the stable amount of
 //! corresponding liquidity pool. CDPTreasury will issue the stable currency to RewardsSource.
+//!
+//! Gauges:
+//! Users lock native currency in vote escrow for up to MaxLockPeriod to get voting power, which
+//! decays linearly to zero at the unlock time. Voting power is allocated across PoolId::Dex and
+//! PoolId::Loans gauges, and at every epoch boundary(EpochPeriod) the GaugeRewardAmount of
+//! native currency is split across the pools according to their gauge weights, which updates
+//! the native currency IncentiveRewardAmounts of the pools.
+//!
+//! Boost:
+//! The shares of PoolId::Dex in rewards are the working shares, which are boosted up to MaxBoost
+//! by the vote escrow locked amount relative to the total locked amount. Working shares are
+//! recalculated on deposit, withdraw and claim, and anyone can `kick` to recalculate them.
+//!
+//! Campaigns:
+//! Any account can fund an incentive campaign for a pool with reward tokens and start/end blocks.
+//! The campaign streams the rewards per block periodically(AccumulatePeriod) alongside other
+//! incentives, and the leftovers are refunded to the creator after the campaign ended. The
+//! campaign lasts at most MaxCampaignDuration, streams at least MinCampaignRewardPerBlock, and
+//! the CampaignDeposit of native currency is kept in the module account until the refund. Each
+//! pool has at most MaxCampaignsPerPool campaigns which have not been refunded.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use frame_support::{log, pallet_prelude::*, transactional, PalletId};
+use frame_support::{log, pallet_prelude::*, traits::LockIdentifier, transactional, PalletId};
 use frame_system::pallet_prelude::*;
-use orml_traits::{Happened, MultiCurrency, RewardHandler};
+use orml_traits::{GetByKey, Happened, MultiCurrency, MultiLockableCurrency, RewardHandler};
 use primitives::{Amount, Balance, CurrencyId};
 use sp_runtime::{
-	traits::{AccountIdConversion, One, UniqueSaturatedInto, Zero},
-	DispatchResult, FixedPointNumber, Permill,
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, One, Saturating, UniqueSaturatedInto, Zero},
+	ArithmeticError, DispatchResult, FixedPointNumber, Permill, RuntimeDebug,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
-use support::{CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, IncentivesManager, PoolId, Rate};
+use support::{
+	CDPTreasury, DEXIncentives, DEXManager, EmergencyShutdown, IncentivesManager, PoolId, PriceProvider, Rate,
+};
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -60,6 +84,83 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+pub const VOTE_ESCROW_LOCK_ID: LockIdentifier = *b"aca/vesc";
+
+pub type CampaignId = u32;
+
+/// Incentive campaign funded by third party.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct IncentiveCampaign<AccountId, BlockNumber> {
+	/// The account funded the campaign, the leftovers are refunded to it.
+	pub creator: AccountId,
+	/// The pool to incentivize.
+	pub pool_id: PoolId,
+	/// The reward currency.
+	pub reward_currency_id: CurrencyId,
+	/// The reward amount streamed per block.
+	pub reward_amount_per_block: Balance,
+	/// The reward amount not accumulated to the pool yet.
+	pub remaining: Balance,
+	/// The native currency deposit, refunded to the creator with the leftovers.
+	pub deposit: Balance,
+	/// The campaign starts after this block.
+	pub start: BlockNumber,
+	/// The campaign ends at this block.
+	pub end: BlockNumber,
+	/// The rewards have been accumulated until this block.
+	pub accumulated_until: BlockNumber,
+}
+
+/// Native currency locked in vote escrow.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct EscrowLock<BlockNumber> {
+	/// The locked amount.
+	pub amount: Balance,
+	/// The epoch boundary at which the lock expires.
+	pub unlock_at: BlockNumber,
+}
+
+/// Aggregated gauge votes, the gauge weight at block `n` is `amount_end - amount * n`.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct GaugePoint {
+	/// The sum of voted escrow amounts.
+	pub amount: Balance,
+	/// The sum of voted escrow amounts multiplied by their unlock block.
+	pub amount_end: Balance,
+}
+
+impl GaugePoint {
+	fn new(amount: Balance, unlock_at: Balance) -> Self {
+		Self {
+			amount,
+			amount_end: amount.saturating_mul(unlock_at),
+		}
+	}
+
+	fn saturating_add(self, other: Self) -> Self {
+		Self {
+			amount: self.amount.saturating_add(other.amount),
+			amount_end: self.amount_end.saturating_add(other.amount_end),
+		}
+	}
+
+	fn saturating_sub(self, other: Self) -> Self {
+		Self {
+			amount: self.amount.saturating_sub(other.amount),
+			amount_end: self.amount_end.saturating_sub(other.amount_end),
+		}
+	}
+
+	fn is_zero(&self) -> bool {
+		self.amount.is_zero()
+	}
+
+	/// The gauge weight at block `now`.
+	pub fn weight_at(&self, now: Balance) -> Balance {
+		self.amount_end.saturating_sub(self.amount.saturating_mul(now))
+	}
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -97,8 +198,8 @@ pub mod module {
 		/// CDP treasury to issue rewards in stable token
 		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
 
-		/// Currency for transfer assets
-		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+		/// Currency for transfer and lock assets
+		type Currency: MultiLockableCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
 
 		/// DEX to supply liquidity info
 		type DEX: DEXManager<Self::AccountId, Balance, CurrencyId>;
@@ -110,6 +211,41 @@ pub mod module {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The period of gauge epochs, gauge weights are applied at epoch boundaries.
+		#[pallet::constant]
+		type EpochPeriod: Get<Self::BlockNumber>;
+
+		/// The maximum period native currency can be locked in vote escrow.
+		#[pallet::constant]
+		type MaxLockPeriod: Get<Self::BlockNumber>;
+
+		/// The maximum number of gauges an account can vote for.
+		#[pallet::constant]
+		type MaxGaugeVotes: Get<u32>;
+
+		/// The maximum boost of the working shares of PoolId::Dex, no boost if it's one.
+		#[pallet::constant]
+		type MaxBoost: Get<Rate>;
+
+		/// The maximum number of incentive campaigns which have not been refunded.
+		#[pallet::constant]
+		type MaxCampaigns: Get<u32>;
+
+		/// The maximum number of incentive campaigns of a pool which have not been refunded.
+		#[pallet::constant]
+		type MaxCampaignsPerPool: Get<u32>;
+
+		/// The maximum period of incentive campaigns.
+		#[pallet::constant]
+		type MaxCampaignDuration: Get<Self::BlockNumber>;
+
+		/// The minimum reward amount per block of incentive campaigns, by reward currency.
+		type MinCampaignRewardPerBlock: GetByKey<CurrencyId, Balance>;
+
+		/// The native currency deposit to create an incentive campaign.
+		#[pallet::constant]
+		type CampaignDeposit: Get<Balance>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -124,6 +260,35 @@ pub mod module {
 		InvalidPoolId,
 		/// Invalid rate
 		InvalidRate,
+		/// The unlock time is not at least one epoch later or exceeds MaxLockPeriod
+		InvalidLockPeriod,
+		/// The lock amount is zero
+		ZeroLockAmount,
+		/// The free balance is too low to lock
+		BalanceTooLow,
+		/// The escrow lock does not exist
+		EscrowLockNotFound,
+		/// The escrow lock has expired
+		EscrowLockExpired,
+		/// The escrow lock has not expired yet
+		EscrowLockNotExpired,
+		/// Vote for too many gauges
+		TooManyGaugeVotes,
+		/// The gauge votes exceed 100% or contain duplicated pools
+		InvalidGaugeVotes,
+		/// The campaign start is in the past, not before the end, or the campaign lasts longer
+		/// than MaxCampaignDuration
+		InvalidCampaignPeriod,
+		/// The campaign reward amount per block is less than MinCampaignRewardPerBlock
+		InvalidCampaignAmount,
+		/// Too many campaigns have not been refunded
+		TooManyCampaigns,
+		/// Too many campaigns of the pool have not been refunded
+		TooManyPoolCampaigns,
+		/// The campaign does not exist
+		CampaignNotFound,
+		/// The campaign has not ended yet
+		CampaignNotEnded,
 	}
 
 	#[pallet::event]
@@ -159,6 +324,44 @@ pub mod module {
 		SavingRewardRateUpdated { pool: PoolId, reward_rate_per_period: Rate },
 		/// Payout deduction rate updated.
 		ClaimRewardDeductionRateUpdated { pool: PoolId, deduction_rate: Rate },
+		/// Native currency locked in vote escrow.
+		EscrowLocked {
+			who: T::AccountId,
+			amount: Balance,
+			unlock_at: T::BlockNumber,
+		},
+		/// Native currency unlocked from vote escrow.
+		EscrowUnlocked { who: T::AccountId, amount: Balance },
+		/// Gauge votes updated.
+		GaugeVoted {
+			who: T::AccountId,
+			votes: Vec<(PoolId, Permill)>,
+		},
+		/// Gauge reward amount updated.
+		GaugeRewardAmountUpdated { reward_amount_per_period: Balance },
+		/// Working shares recalculated.
+		WorkingSharesUpdated {
+			who: T::AccountId,
+			pool: PoolId,
+			working_shares: Balance,
+		},
+		/// Incentive campaign created.
+		CampaignCreated {
+			campaign_id: CampaignId,
+			creator: T::AccountId,
+			pool: PoolId,
+			reward_currency_id: CurrencyId,
+			reward_amount: Balance,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+		},
+		/// The leftovers and the deposit of ended incentive campaign refunded.
+		CampaignRefunded {
+			campaign_id: CampaignId,
+			creator: T::AccountId,
+			refund_amount: Balance,
+			deposit: Balance,
+		},
 	}
 
 	/// Mapping from pool to its fixed incentive amounts of multi currencies per period.
@@ -187,6 +390,7 @@ pub mod module {
 	///
 	/// PendingMultiRewards: double_map PoolId, AccountId => BTreeMap<CurrencyId, Balance>
 	#[pallet::storage]
+	#[pallet::unbounded]
 	#[pallet::getter(fn pending_multi_rewards)]
 	pub type PendingMultiRewards<T: Config> = StorageDoubleMap<
 		_,
@@ -198,13 +402,114 @@ pub mod module {
 		ValueQuery,
 	>;
 
+	/// The vote escrow locks of native currency.
+	///
+	/// EscrowLocks: map AccountId => Option<EscrowLock>
+	#[pallet::storage]
+	#[pallet::getter(fn escrow_locks)]
+	pub type EscrowLocks<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, EscrowLock<T::BlockNumber>, OptionQuery>;
+
+	/// The total amount of native currency locked in vote escrow.
+	///
+	/// TotalEscrowLocked: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_escrow_locked)]
+	pub type TotalEscrowLocked<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The gauge votes of accounts.
+	///
+	/// GaugeVotes: map AccountId => Vec<(PoolId, Proportion)>
+	#[pallet::storage]
+	#[pallet::getter(fn gauge_votes)]
+	pub type GaugeVotes<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<(PoolId, Permill), T::MaxGaugeVotes>, ValueQuery>;
+
+	/// The aggregated votes of gauges.
+	///
+	/// GaugePoints: map PoolId => GaugePoint
+	#[pallet::storage]
+	#[pallet::getter(fn gauge_points)]
+	pub type GaugePoints<T: Config> = StorageMap<_, Twox64Concat, PoolId, GaugePoint, ValueQuery>;
+
+	/// The gauge votes which expire at the epoch boundary.
+	///
+	/// GaugePointChanges: double_map UnlockAt, PoolId => GaugePoint
+	#[pallet::storage]
+	#[pallet::getter(fn gauge_point_changes)]
+	pub type GaugePointChanges<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, PoolId, GaugePoint, ValueQuery>;
+
+	/// The native currency reward amount per period split across gauges, gauges are disabled if
+	/// it's zero.
+	///
+	/// GaugeRewardAmount: Balance
+	#[pallet::storage]
+	#[pallet::getter(fn gauge_reward_amount)]
+	pub type GaugeRewardAmount<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// The staked DEX share amounts, the shares of PoolId::Dex in rewards are the boosted working
+	/// shares.
+	///
+	/// DexDepositedShares: double_map PoolId, AccountId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn deposited_shares)]
+	pub type DexDepositedShares<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, PoolId, Twox64Concat, T::AccountId, Balance, ValueQuery>;
+
+	/// The total staked DEX share amounts.
+	///
+	/// TotalDexDepositedShares: map PoolId => Balance
+	#[pallet::storage]
+	#[pallet::getter(fn total_deposited_shares)]
+	pub type TotalDexDepositedShares<T: Config> = StorageMap<_, Twox64Concat, PoolId, Balance, ValueQuery>;
+
+	/// The incentive campaigns which have not been refunded.
+	///
+	/// Campaigns: map CampaignId => Option<IncentiveCampaign>
+	#[pallet::storage]
+	#[pallet::getter(fn campaigns)]
+	pub type Campaigns<T: Config> =
+		StorageMap<_, Twox64Concat, CampaignId, IncentiveCampaign<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// The number of incentive campaigns which have not been refunded.
+	///
+	/// CampaignCount: u32
+	#[pallet::storage]
+	#[pallet::getter(fn campaign_count)]
+	pub type CampaignCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The number of incentive campaigns of pools which have not been refunded.
+	///
+	/// PoolCampaignCount: map PoolId => u32
+	#[pallet::storage]
+	#[pallet::getter(fn pool_campaign_count)]
+	pub type PoolCampaignCount<T: Config> = StorageMap<_, Twox64Concat, PoolId, u32, ValueQuery>;
+
+	/// The next incentive campaign id.
+	///
+	/// NextCampaignId: CampaignId
+	#[pallet::storage]
+	#[pallet::getter(fn next_campaign_id)]
+	pub type NextCampaignId<T: Config> = StorageValue<_, CampaignId, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
-	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
 		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let mut weight: Weight = 0;
+
+			// apply gauge weights at epoch boundaries
+			if now % T::EpochPeriod::get() == Zero::zero() {
+				let (expired_count, count) = Self::apply_gauge_weights(now);
+				weight = weight.saturating_add(T::WeightInfo::on_initialize_epoch(expired_count, count));
+			}
+
 			// accumulate reward periodically
 			if now % T::AccumulatePeriod::get() == Zero::zero() {
 				let mut count: u32 = 0;
@@ -231,9 +536,13 @@ pub mod module {
 					}
 				}
 
-				T::WeightInfo::on_initialize(count)
+				let campaign_count = Self::accumulate_campaigns(now, shutdown);
+
+				weight
+					.saturating_add(T::WeightInfo::on_initialize(count))
+					.saturating_add(T::WeightInfo::on_initialize_campaigns(campaign_count))
 			} else {
-				0
+				weight
 			}
 		}
 	}
@@ -309,23 +618,7 @@ pub mod module {
 				}
 
 				for (currency_id, amount) in update_list {
-					IncentiveRewardAmounts::<T>::mutate_exists(pool_id, currency_id, |maybe_amount| {
-						let mut v = maybe_amount.unwrap_or_default();
-						if amount != v {
-							v = amount;
-							Self::deposit_event(Event::IncentiveRewardAmountUpdated {
-								pool: pool_id,
-								reward_currency_id: currency_id,
-								reward_amount_per_period: amount,
-							});
-						}
-
-						if v.is_zero() {
-							*maybe_amount = None;
-						} else {
-							*maybe_amount = Some(v);
-						}
-					});
+					Self::set_incentive_reward_amount(pool_id, currency_id, amount);
 				}
 			}
 			Ok(())
@@ -403,6 +696,314 @@ pub mod module {
 			}
 			Ok(())
 		}
+
+		/// Lock native currency in vote escrow to get voting power for gauges, or increase the
+		/// amount and extend the unlock time of the existing lock.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `amount`: the additional amount to lock
+		/// - `unlock_at`: the unlock time, rounded down to the epoch boundary
+		#[pallet::weight(<T as Config>::WeightInfo::escrow_lock())]
+		#[transactional]
+		pub fn escrow_lock(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: Balance,
+			unlock_at: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let unlock_at = unlock_at.saturating_sub(unlock_at % T::EpochPeriod::get());
+			ensure!(
+				unlock_at > now && unlock_at <= now.saturating_add(T::MaxLockPeriod::get()),
+				Error::<T>::InvalidLockPeriod
+			);
+
+			let old_lock = Self::escrow_locks(&who);
+			match old_lock {
+				Some(lock) => {
+					ensure!(lock.unlock_at > now, Error::<T>::EscrowLockExpired);
+					ensure!(unlock_at >= lock.unlock_at, Error::<T>::InvalidLockPeriod);
+				}
+				None => ensure!(!amount.is_zero(), Error::<T>::ZeroLockAmount),
+			}
+
+			let native_currency_id = T::NativeCurrencyId::get();
+			let mut new_lock = old_lock.unwrap_or_default();
+			new_lock.amount = new_lock.amount.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+			new_lock.unlock_at = unlock_at;
+			ensure!(
+				T::Currency::free_balance(native_currency_id, &who) >= new_lock.amount,
+				Error::<T>::BalanceTooLow
+			);
+			T::Currency::set_lock(VOTE_ESCROW_LOCK_ID, native_currency_id, &who, new_lock.amount)?;
+
+			// move the gauge votes to the new lock
+			let votes = Self::gauge_votes(&who);
+			if let Some(lock) = old_lock {
+				Self::update_gauge_points(&votes, &lock, GaugePoint::saturating_sub);
+			}
+			Self::update_gauge_points(&votes, &new_lock, GaugePoint::saturating_add);
+
+			EscrowLocks::<T>::insert(&who, new_lock);
+			TotalEscrowLocked::<T>::mutate(|total| *total = total.saturating_add(amount));
+
+			Self::deposit_event(Event::EscrowLocked {
+				who,
+				amount: new_lock.amount,
+				unlock_at,
+			});
+			Ok(())
+		}
+
+		/// Unlock the expired vote escrow lock and remove the gauge votes.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		#[pallet::weight(<T as Config>::WeightInfo::escrow_unlock())]
+		#[transactional]
+		pub fn escrow_unlock(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lock = Self::escrow_locks(&who).ok_or(Error::<T>::EscrowLockNotFound)?;
+			ensure!(
+				lock.unlock_at <= frame_system::Pallet::<T>::block_number(),
+				Error::<T>::EscrowLockNotExpired
+			);
+
+			// the gauge votes of expired lock have been removed at the unlock epoch boundary
+			T::Currency::remove_lock(VOTE_ESCROW_LOCK_ID, T::NativeCurrencyId::get(), &who)?;
+			EscrowLocks::<T>::remove(&who);
+			GaugeVotes::<T>::remove(&who);
+			TotalEscrowLocked::<T>::mutate(|total| *total = total.saturating_sub(lock.amount));
+
+			Self::deposit_event(Event::EscrowUnlocked {
+				who,
+				amount: lock.amount,
+			});
+			Ok(())
+		}
+
+		/// Allocate the voting power of vote escrow lock across gauges, replacing the previous
+		/// votes.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `votes`: Vec<(PoolId, Proportion)>, the sum of proportions can't exceed 100%
+		#[pallet::weight(<T as Config>::WeightInfo::vote_gauges(votes.len() as u32))]
+		#[transactional]
+		pub fn vote_gauges(origin: OriginFor<T>, votes: Vec<(PoolId, Permill)>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lock = Self::escrow_locks(&who).ok_or(Error::<T>::EscrowLockNotFound)?;
+			ensure!(
+				lock.unlock_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::EscrowLockExpired
+			);
+			let votes: BoundedVec<(PoolId, Permill), T::MaxGaugeVotes> =
+				votes.try_into().map_err(|_| Error::<T>::TooManyGaugeVotes)?;
+
+			let mut total: u64 = 0;
+			for (i, (pool_id, proportion)) in votes.iter().enumerate() {
+				match pool_id {
+					PoolId::Dex(currency_id) if currency_id.is_dex_share_currency_id() => {}
+					PoolId::Loans(_) => {}
+					_ => return Err(Error::<T>::InvalidPoolId.into()),
+				}
+				ensure!(
+					!votes.iter().skip(i + 1).any(|(other, _)| other == pool_id),
+					Error::<T>::InvalidGaugeVotes
+				);
+				total = total.saturating_add(proportion.deconstruct().into());
+			}
+			ensure!(
+				total <= Permill::one().deconstruct().into(),
+				Error::<T>::InvalidGaugeVotes
+			);
+
+			Self::update_gauge_points(&Self::gauge_votes(&who), &lock, GaugePoint::saturating_sub);
+			Self::update_gauge_points(&votes, &lock, GaugePoint::saturating_add);
+
+			if votes.is_empty() {
+				GaugeVotes::<T>::remove(&who);
+			} else {
+				GaugeVotes::<T>::insert(&who, &votes);
+			}
+
+			Self::deposit_event(Event::GaugeVoted {
+				who,
+				votes: votes.into_inner(),
+			});
+			Ok(())
+		}
+
+		/// Update the native currency reward amount per period split across gauges. Gauge
+		/// weights override the native currency incentive reward amounts at epoch boundaries
+		/// unless it's zero.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `amount`: reward amount per period
+		#[pallet::weight(<T as Config>::WeightInfo::update_gauge_reward_amount())]
+		#[transactional]
+		pub fn update_gauge_reward_amount(origin: OriginFor<T>, #[pallet::compact] amount: Balance) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			GaugeRewardAmount::<T>::put(amount);
+			Self::deposit_event(Event::GaugeRewardAmountUpdated {
+				reward_amount_per_period: amount,
+			});
+			Ok(())
+		}
+
+		/// Recalculate the working shares of `who` in PoolId::Dex, e.g. after the vote escrow
+		/// lock of `who` or the total locked amount changed.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `who`: the account to recalculate
+		/// - `pool_id`: pool type
+		#[pallet::weight(<T as Config>::WeightInfo::kick())]
+		#[transactional]
+		pub fn kick(origin: OriginFor<T>, who: T::AccountId, pool_id: PoolId) -> DispatchResult {
+			ensure_signed(origin)?;
+			match pool_id {
+				PoolId::Dex(currency_id) if currency_id.is_dex_share_currency_id() => {}
+				_ => return Err(Error::<T>::InvalidPoolId.into()),
+			}
+
+			let working_shares = Self::adjust_dex_share(&who, pool_id, true, Zero::zero())?;
+
+			Self::deposit_event(Event::WorkingSharesUpdated {
+				who,
+				pool: pool_id,
+				working_shares,
+			});
+			Ok(())
+		}
+
+		/// Fund an incentive campaign which streams the rewards to the pool from `start` to
+		/// `end`. `CampaignDeposit` of native currency is kept until the campaign is refunded.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `pool_id`: pool type
+		/// - `reward_currency_id`: the reward currency
+		/// - `reward_amount`: the total reward amount
+		/// - `start`: the campaign starts after this block
+		/// - `end`: the campaign ends at this block
+		#[pallet::weight(<T as Config>::WeightInfo::create_campaign())]
+		#[transactional]
+		pub fn create_campaign(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			reward_currency_id: CurrencyId,
+			#[pallet::compact] reward_amount: Balance,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			match pool_id {
+				PoolId::Dex(currency_id) if currency_id.is_dex_share_currency_id() => {}
+				PoolId::Loans(_) => {}
+				_ => return Err(Error::<T>::InvalidPoolId.into()),
+			}
+			ensure!(
+				start >= frame_system::Pallet::<T>::block_number()
+					&& start < end && end - start <= T::MaxCampaignDuration::get(),
+				Error::<T>::InvalidCampaignPeriod
+			);
+			let reward_amount_per_block =
+				reward_amount / UniqueSaturatedInto::<Balance>::unique_saturated_into(end - start);
+			ensure!(
+				!reward_amount_per_block.is_zero()
+					&& reward_amount_per_block >= T::MinCampaignRewardPerBlock::get(&reward_currency_id),
+				Error::<T>::InvalidCampaignAmount
+			);
+
+			let campaign_count = Self::campaign_count();
+			ensure!(campaign_count < T::MaxCampaigns::get(), Error::<T>::TooManyCampaigns);
+			let pool_campaign_count = Self::pool_campaign_count(pool_id);
+			ensure!(
+				pool_campaign_count < T::MaxCampaignsPerPool::get(),
+				Error::<T>::TooManyPoolCampaigns
+			);
+			let campaign_id = NextCampaignId::<T>::try_mutate(|id| -> Result<CampaignId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+
+			let deposit = T::CampaignDeposit::get();
+			T::Currency::transfer(T::NativeCurrencyId::get(), &who, &Self::account_id(), deposit)?;
+			T::Currency::transfer(reward_currency_id, &who, &Self::account_id(), reward_amount)?;
+
+			Campaigns::<T>::insert(
+				campaign_id,
+				IncentiveCampaign {
+					creator: who.clone(),
+					pool_id,
+					reward_currency_id,
+					reward_amount_per_block,
+					remaining: reward_amount,
+					deposit,
+					start,
+					end,
+					accumulated_until: start,
+				},
+			);
+			CampaignCount::<T>::put(campaign_count + 1);
+			PoolCampaignCount::<T>::insert(pool_id, pool_campaign_count + 1);
+
+			Self::deposit_event(Event::CampaignCreated {
+				campaign_id,
+				creator: who,
+				pool: pool_id,
+				reward_currency_id,
+				reward_amount,
+				start,
+				end,
+			});
+			Ok(())
+		}
+
+		/// Refund the leftovers and the deposit of the ended incentive campaign to the creator.
+		///
+		/// The dispatch origin of this call must be `Signed` by the transactor.
+		///
+		/// - `campaign_id`: the campaign id
+		#[pallet::weight(<T as Config>::WeightInfo::refund_campaign())]
+		#[transactional]
+		pub fn refund_campaign(origin: OriginFor<T>, campaign_id: CampaignId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let campaign = Self::campaigns(campaign_id).ok_or(Error::<T>::CampaignNotFound)?;
+			// the rewards until the end must have been accumulated
+			ensure!(campaign.accumulated_until >= campaign.end, Error::<T>::CampaignNotEnded);
+
+			T::Currency::transfer(
+				campaign.reward_currency_id,
+				&Self::account_id(),
+				&campaign.creator,
+				campaign.remaining,
+			)?;
+			T::Currency::transfer(
+				T::NativeCurrencyId::get(),
+				&Self::account_id(),
+				&campaign.creator,
+				campaign.deposit,
+			)?;
+			Campaigns::<T>::remove(campaign_id);
+			CampaignCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+			PoolCampaignCount::<T>::mutate_exists(campaign.pool_id, |maybe_count| {
+				*maybe_count = maybe_count
+					.and_then(|count| count.checked_sub(1))
+					.filter(|count| *count > 0);
+			});
+
+			Self::deposit_event(Event::CampaignRefunded {
+				campaign_id,
+				creator: campaign.creator,
+				refund_amount: campaign.remaining,
+				deposit: campaign.deposit,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -411,6 +1012,266 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// The working shares are `min(deposited / MaxBoost + (1 - 1 / MaxBoost) * total_deposited *
+	/// locked / total_locked, deposited)`, only unexpired vote escrow locks are counted.
+	pub fn calculate_working_shares(who: &T::AccountId, deposited: Balance, total_deposited: Balance) -> Balance {
+		let base_ratio = T::MaxBoost::get()
+			.max(Rate::one())
+			.reciprocal()
+			.unwrap_or_else(Rate::one);
+		let now = frame_system::Pallet::<T>::block_number();
+		let locked = Self::escrow_locks(who)
+			.filter(|lock| lock.unlock_at > now)
+			.map(|lock| lock.amount)
+			.unwrap_or_default();
+		let boosted = Rate::checked_from_rational(locked, Self::total_escrow_locked())
+			.unwrap_or_default()
+			.saturating_mul(Rate::one().saturating_sub(base_ratio))
+			.saturating_mul_int(total_deposited);
+
+		base_ratio
+			.saturating_mul_int(deposited)
+			.saturating_add(boosted)
+			.min(deposited)
+	}
+
+	// adjust the staked DEX share of `who` and recalculate the working shares in rewards
+	pub(crate) fn adjust_dex_share(
+		who: &T::AccountId,
+		pool_id: PoolId,
+		increase: bool,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let deposited = Self::deposited_shares(&pool_id, who);
+		let total_deposited = Self::total_deposited_shares(&pool_id);
+		let (deposited, total_deposited) = if increase {
+			(deposited.saturating_add(amount), total_deposited.saturating_add(amount))
+		} else {
+			ensure!(deposited >= amount, Error::<T>::NotEnough);
+			(deposited - amount, total_deposited.saturating_sub(amount))
+		};
+
+		TotalDexDepositedShares::<T>::insert(&pool_id, total_deposited);
+		if deposited.is_zero() {
+			DexDepositedShares::<T>::remove(&pool_id, who);
+		} else {
+			DexDepositedShares::<T>::insert(&pool_id, who, deposited);
+		}
+
+		let working_shares = Self::calculate_working_shares(who, deposited, total_deposited);
+		let current_shares = <orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&pool_id, who).0;
+		if working_shares > current_shares {
+			<orml_rewards::Pallet<T>>::add_share(who, &pool_id, working_shares - current_shares);
+		} else if working_shares < current_shares {
+			<orml_rewards::Pallet<T>>::remove_share(who, &pool_id, current_shares - working_shares);
+		}
+
+		Ok(working_shares)
+	}
+
+	/// The current voting power of vote escrow lock, decays linearly to zero at the unlock time.
+	pub fn voting_power(who: &T::AccountId) -> Balance {
+		let now = frame_system::Pallet::<T>::block_number();
+		Self::escrow_locks(who)
+			.filter(|lock| lock.unlock_at > now)
+			.map(|lock| {
+				Rate::checked_from_rational(
+					UniqueSaturatedInto::<Balance>::unique_saturated_into(lock.unlock_at - now),
+					UniqueSaturatedInto::<Balance>::unique_saturated_into(T::MaxLockPeriod::get()),
+				)
+				.unwrap_or_default()
+				.min(Rate::one())
+				.saturating_mul_int(lock.amount)
+			})
+			.unwrap_or_default()
+	}
+
+	/// The pools `who` has shares or pending rewards in, with the shares of `who` and the rewards
+	/// that can be claimed after the claim reward deduction.
+	pub fn account_rewards(who: &T::AccountId) -> Vec<(PoolId, Balance, Vec<(CurrencyId, Balance)>)> {
+		orml_rewards::PoolInfos::<T>::iter()
+			.filter_map(|(pool_id, pool_info)| {
+				let mut pending_rewards = PendingMultiRewards::<T>::get(&pool_id, who);
+
+				// the rewards not yet claimed from orml_rewards
+				let (share, withdrawn_rewards) = <orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&pool_id, who);
+				if !share.is_zero() && !pool_info.total_shares.is_zero() {
+					for (currency_id, (total_reward, _)) in pool_info.rewards {
+						let withdrawn = withdrawn_rewards.get(&currency_id).copied().unwrap_or_default();
+						let reward = multiply_by_rational(total_reward, share, pool_info.total_shares)
+							.unwrap_or_default()
+							.saturating_sub(withdrawn);
+						let pending = pending_rewards.entry(currency_id).or_default();
+						*pending = pending.saturating_add(reward);
+					}
+				}
+
+				let shares = Self::get_shares(pool_id, who.clone());
+				let deduction_rate = Self::claim_reward_deduction_rates(&pool_id);
+				let rewards: Vec<(CurrencyId, Balance)> = pending_rewards
+					.into_iter()
+					.filter(|(_, pending_reward)| !pending_reward.is_zero())
+					.map(|(currency_id, pending_reward)| {
+						let deduction_amount = deduction_rate.saturating_mul_int(pending_reward).min(pending_reward);
+						(currency_id, pending_reward.saturating_sub(deduction_amount))
+					})
+					.collect();
+
+				if shares.is_zero() && rewards.is_empty() {
+					None
+				} else {
+					Some((pool_id, shares, rewards))
+				}
+			})
+			.collect()
+	}
+
+	/// The reward amounts of the pool accumulated per AccumulatePeriod, including the incentive
+	/// rewards, the DEX saving rewards and the active incentive campaigns.
+	pub fn pool_rewards_per_period(pool_id: PoolId) -> BTreeMap<CurrencyId, Balance> {
+		let mut rewards: BTreeMap<CurrencyId, Balance> = BTreeMap::new();
+		let mut add_reward = |currency_id: CurrencyId, amount: Balance| {
+			if !amount.is_zero() {
+				let reward = rewards.entry(currency_id).or_default();
+				*reward = reward.saturating_add(amount);
+			}
+		};
+
+		let shutdown = T::EmergencyShutdown::is_shutdown();
+		if !(shutdown && matches!(pool_id, PoolId::Loans(_))) {
+			for (currency_id, amount) in IncentiveRewardAmounts::<T>::iter_prefix(pool_id) {
+				add_reward(currency_id, amount);
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let period: Balance = T::AccumulatePeriod::get().unique_saturated_into();
+			for campaign in Campaigns::<T>::iter_values() {
+				if campaign.pool_id == pool_id && campaign.start <= now && now < campaign.end {
+					add_reward(
+						campaign.reward_currency_id,
+						campaign
+							.reward_amount_per_block
+							.saturating_mul(period)
+							.min(campaign.remaining),
+					);
+				}
+			}
+		}
+
+		if let PoolId::Dex(lp_currency_id) = pool_id {
+			if !shutdown {
+				add_reward(
+					T::StableCurrencyId::get(),
+					Self::dex_saving_reward_amount(lp_currency_id, pool_id),
+				);
+			}
+		}
+
+		rewards
+	}
+
+	/// The annual percentage rate of the pool rewards, `pool_value` is the value of the total
+	/// shares of the pool, the rewards are valued by the prices of `P`.
+	pub fn pool_apr<P: PriceProvider<CurrencyId>>(
+		pool_id: PoolId,
+		pool_value: Balance,
+		blocks_per_year: T::BlockNumber,
+	) -> Option<Rate> {
+		let periods_per_year: Balance = (blocks_per_year / T::AccumulatePeriod::get()).unique_saturated_into();
+		let rewards_value = Self::pool_rewards_per_period(pool_id).into_iter().try_fold(
+			Balance::zero(),
+			|acc, (currency_id, amount)| {
+				P::get_price(currency_id).map(|price| acc.saturating_add(price.saturating_mul_int(amount)))
+			},
+		)?;
+
+		Rate::checked_from_rational(rewards_value.saturating_mul(periods_per_year), pool_value)
+	}
+
+	fn set_incentive_reward_amount(pool_id: PoolId, currency_id: CurrencyId, amount: Balance) {
+		IncentiveRewardAmounts::<T>::mutate_exists(pool_id, currency_id, |maybe_amount| {
+			let mut v = maybe_amount.unwrap_or_default();
+			if amount != v {
+				v = amount;
+				Self::deposit_event(Event::IncentiveRewardAmountUpdated {
+					pool: pool_id,
+					reward_currency_id: currency_id,
+					reward_amount_per_period: amount,
+				});
+			}
+
+			if v.is_zero() {
+				*maybe_amount = None;
+			} else {
+				*maybe_amount = Some(v);
+			}
+		});
+	}
+
+	// add or remove the gauge votes of the escrow lock
+	fn update_gauge_points(
+		votes: &[(PoolId, Permill)],
+		lock: &EscrowLock<T::BlockNumber>,
+		f: impl Fn(GaugePoint, GaugePoint) -> GaugePoint,
+	) {
+		for (pool_id, proportion) in votes {
+			let point = GaugePoint::new(*proportion * lock.amount, lock.unlock_at.unique_saturated_into());
+			let update = |maybe_point: &mut Option<GaugePoint>| {
+				let new_point = f(maybe_point.unwrap_or_default(), point);
+				*maybe_point = if new_point.is_zero() { None } else { Some(new_point) };
+			};
+			GaugePoints::<T>::mutate_exists(pool_id, update);
+			GaugePointChanges::<T>::mutate_exists(lock.unlock_at, pool_id, update);
+		}
+	}
+
+	// remove the expired gauge votes and split GaugeRewardAmount across gauges by weight, returns
+	// the number of the expired gauge point changes and the number of the updated pools
+	fn apply_gauge_weights(now: T::BlockNumber) -> (u32, u32) {
+		let mut expired_count: u32 = 0;
+		for (pool_id, change) in GaugePointChanges::<T>::drain_prefix(now) {
+			expired_count = expired_count.saturating_add(1);
+			GaugePoints::<T>::mutate_exists(pool_id, |maybe_point| {
+				let new_point = maybe_point.unwrap_or_default().saturating_sub(change);
+				*maybe_point = if new_point.is_zero() { None } else { Some(new_point) };
+			});
+		}
+
+		let reward_amount = Self::gauge_reward_amount();
+		if reward_amount.is_zero() {
+			return (expired_count, 0);
+		}
+
+		let native_currency_id = T::NativeCurrencyId::get();
+		let now_balance: Balance = now.unique_saturated_into();
+		let gauge_weights: Vec<(PoolId, Balance)> = GaugePoints::<T>::iter()
+			.map(|(pool_id, point)| (pool_id, point.weight_at(now_balance)))
+			.collect();
+		let total_weight = gauge_weights
+			.iter()
+			.fold(Balance::zero(), |total, (_, weight)| total.saturating_add(*weight));
+
+		// the native currency incentives of pools without gauge weight are removed
+		let mut allocations: Vec<(PoolId, Balance)> = IncentiveRewardAmounts::<T>::iter()
+			.filter(|(pool_id, currency_id, _)| {
+				*currency_id == native_currency_id && !gauge_weights.iter().any(|(p, _)| p == pool_id)
+			})
+			.map(|(pool_id, _, _)| (pool_id, Zero::zero()))
+			.collect();
+		for (pool_id, weight) in gauge_weights {
+			let amount = Rate::checked_from_rational(weight, total_weight)
+				.unwrap_or_default()
+				.saturating_mul_int(reward_amount);
+			allocations.push((pool_id, amount));
+		}
+
+		let count = allocations.len() as u32;
+		for (pool_id, amount) in allocations {
+			Self::set_incentive_reward_amount(pool_id, native_currency_id, amount);
+		}
+		(expired_count, count)
+	}
+
 	// accumulate incentive rewards of multi currencies
 	fn accumulate_incentives(pool_id: PoolId) {
 		for (reward_currency_id, reward_amount) in IncentiveRewardAmounts::<T>::iter_prefix(pool_id) {
@@ -452,59 +1313,118 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	// accumulate the rewards of incentive campaigns streamed since the last accumulation
+	fn accumulate_campaigns(now: T::BlockNumber, shutdown: bool) -> u32 {
+		let mut count: u32 = 0;
+
+		for (campaign_id, mut campaign) in Campaigns::<T>::iter().collect::<Vec<_>>() {
+			if now <= campaign.start || campaign.accumulated_until >= campaign.end {
+				continue;
+			}
+			count += 1;
+
+			let until = now.min(campaign.end);
+			let blocks: Balance = (until - campaign.accumulated_until).unique_saturated_into();
+			let reward_amount = campaign
+				.reward_amount_per_block
+				.saturating_mul(blocks)
+				.min(campaign.remaining);
+			campaign.accumulated_until = until;
+
+			// the rewards are kept for refund if the pool has no shares,
+			// do not accumulate campaigns for PoolId::Loans after shutdown
+			let pool_info = orml_rewards::PoolInfos::<T>::get(campaign.pool_id);
+			let paused = shutdown && matches!(campaign.pool_id, PoolId::Loans(_));
+			if !reward_amount.is_zero() && !pool_info.total_shares.is_zero() && !paused {
+				match <orml_rewards::Pallet<T>>::accumulate_reward(
+					&campaign.pool_id,
+					campaign.reward_currency_id,
+					reward_amount,
+				) {
+					Ok(_) => campaign.remaining = campaign.remaining.saturating_sub(reward_amount),
+					Err(e) => {
+						log::error!(
+							target: "incentives",
+							"accumulate_reward: failed to accumulate campaign {:?} reward to pool {:?}, reward_currency_id {:?}, reward_amount {:?}: {:?}",
+							campaign_id, campaign.pool_id, campaign.reward_currency_id, reward_amount, e
+						);
+					}
+				}
+			}
+
+			Campaigns::<T>::insert(campaign_id, campaign);
+		}
+
+		count
+	}
+
 	// accumulate DEX saving reward(stable currency) for Dex Pool
 	fn accumulate_dex_saving(lp_currency_id: CurrencyId, pool_id: PoolId) {
 		let stable_currency_id = T::StableCurrencyId::get();
-		let dex_saving_reward_rate = Self::dex_saving_reward_rates(&pool_id);
+		let dex_saving_reward_amount = Self::dex_saving_reward_amount(lp_currency_id, pool_id);
 
-		if !dex_saving_reward_rate.is_zero() {
-			if let Some((currency_id_a, currency_id_b)) = lp_currency_id.split_dex_share_currency_id() {
-				// accumulate saving reward only for liquidity pool of stable currency id
-				let dex_saving_reward_base = if currency_id_a == stable_currency_id {
-					T::DEX::get_liquidity_pool(stable_currency_id, currency_id_b).0
-				} else if currency_id_b == stable_currency_id {
-					T::DEX::get_liquidity_pool(stable_currency_id, currency_id_a).0
-				} else {
-					Zero::zero()
-				};
-				let dex_saving_reward_amount = dex_saving_reward_rate.saturating_mul_int(dex_saving_reward_base);
-
-				// issue stable currency without backing.
-				if !dex_saving_reward_amount.is_zero() {
-					let res = T::CDPTreasury::issue_debit(&Self::account_id(), dex_saving_reward_amount, false);
-					match res {
-						Ok(_) => {
-							let _ = <orml_rewards::Pallet<T>>::accumulate_reward(
-								&pool_id,
-								stable_currency_id,
-								dex_saving_reward_amount,
-							)
-							.map_err(|e| {
-								log::error!(
-									target: "incentives",
-									"accumulate_reward: failed to accumulate reward to non-existen pool {:?}, reward_currency {:?}, amount {:?}: {:?}",
-									pool_id, stable_currency_id, dex_saving_reward_amount, e
-								);
-							});
-						}
-						Err(e) => {
-							log::warn!(
-								target: "incentives",
-								"issue_debit: failed to issue {:?} unbacked stable to {:?}: {:?}. \
-								This is unexpected but should be safe",
-								dex_saving_reward_amount, Self::account_id(), e
-							);
-						}
-					}
+		// issue stable currency without backing.
+		if !dex_saving_reward_amount.is_zero() {
+			let res = T::CDPTreasury::issue_debit(&Self::account_id(), dex_saving_reward_amount, false);
+			match res {
+				Ok(_) => {
+					let _ = <orml_rewards::Pallet<T>>::accumulate_reward(
+						&pool_id,
+						stable_currency_id,
+						dex_saving_reward_amount,
+					)
+					.map_err(|e| {
+						log::error!(
+							target: "incentives",
+							"accumulate_reward: failed to accumulate reward to non-existen pool {:?}, reward_currency {:?}, amount {:?}: {:?}",
+							pool_id, stable_currency_id, dex_saving_reward_amount, e
+						);
+					});
+				}
+				Err(e) => {
+					log::warn!(
+						target: "incentives",
+						"issue_debit: failed to issue {:?} unbacked stable to {:?}: {:?}. \
+						This is unexpected but should be safe",
+						dex_saving_reward_amount, Self::account_id(), e
+					);
 				}
 			}
 		}
 	}
 
+	// the DEX saving reward amount of stable currency per period for Dex Pool
+	fn dex_saving_reward_amount(lp_currency_id: CurrencyId, pool_id: PoolId) -> Balance {
+		let stable_currency_id = T::StableCurrencyId::get();
+		let dex_saving_reward_rate = Self::dex_saving_reward_rates(&pool_id);
+
+		if dex_saving_reward_rate.is_zero() {
+			return Zero::zero();
+		}
+
+		// saving reward only for liquidity pool of stable currency id
+		let dex_saving_reward_base = match lp_currency_id.split_dex_share_currency_id() {
+			Some((currency_id_a, currency_id_b)) if currency_id_a == stable_currency_id => {
+				T::DEX::get_liquidity_pool(stable_currency_id, currency_id_b).0
+			}
+			Some((currency_id_a, currency_id_b)) if currency_id_b == stable_currency_id => {
+				T::DEX::get_liquidity_pool(stable_currency_id, currency_id_a).0
+			}
+			_ => Zero::zero(),
+		};
+		dex_saving_reward_rate.saturating_mul_int(dex_saving_reward_base)
+	}
+
 	fn do_claim_rewards(who: T::AccountId, pool_id: PoolId) -> DispatchResult {
 		// orml_rewards will claim rewards for all currencies rewards
 		<orml_rewards::Pallet<T>>::claim_rewards(&who, &pool_id);
 
+		if let PoolId::Dex(lp_currency_id) = pool_id {
+			if lp_currency_id.is_dex_share_currency_id() {
+				Self::adjust_dex_share(&who, pool_id, true, Zero::zero())?;
+			}
+		}
+
 		let pending_multi_rewards: BTreeMap<CurrencyId, Balance> = PendingMultiRewards::<T>::take(&pool_id, &who);
 		let deduction_rate = Self::claim_reward_deduction_rates(&pool_id);
 
@@ -559,7 +1479,7 @@ impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 		ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
 
 		T::Currency::transfer(lp_currency_id, who, &Self::account_id(), amount)?;
-		<orml_rewards::Pallet<T>>::add_share(who, &PoolId::Dex(lp_currency_id), amount.unique_saturated_into());
+		Self::adjust_dex_share(who, PoolId::Dex(lp_currency_id), true, amount)?;
 
 		Self::deposit_event(Event::DepositDexShare {
 			who: who.clone(),
@@ -571,13 +1491,9 @@ impl<T: Config> DEXIncentives<T::AccountId, CurrencyId, Balance> for Pallet<T> {
 
 	fn do_withdraw_dex_share(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
 		ensure!(lp_currency_id.is_dex_share_currency_id(), Error::<T>::InvalidCurrencyId);
-		ensure!(
-			<orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&PoolId::Dex(lp_currency_id), &who).0 >= amount,
-			Error::<T>::NotEnough,
-		);
 
+		Self::adjust_dex_share(who, PoolId::Dex(lp_currency_id), false, amount)?;
 		T::Currency::transfer(lp_currency_id, &Self::account_id(), who, amount)?;
-		<orml_rewards::Pallet<T>>::remove_share(who, &PoolId::Dex(lp_currency_id), amount.unique_saturated_into());
 
 		Self::deposit_event(Event::WithdrawDexShare {
 			who: who.clone(),
@@ -622,6 +1538,13 @@ impl<T: Config> IncentivesManager<T::AccountId, Balance, CurrencyId, PoolId> for
 		}
 		reward_balances
 	}
+
+	fn get_shares(pool_id: PoolId, who: T::AccountId) -> Balance {
+		match pool_id {
+			PoolId::Dex(_) => Self::deposited_shares(&pool_id, &who),
+			_ => <orml_rewards::Pallet<T>>::shares_and_withdrawn_rewards(&pool_id, &who).0,
+		}
+	}
 }
 
 pub struct OnUpdateLoan<T>(sp_std::marker::PhantomData<T>);
@@ -0,0 +1,143 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{Config, DexDepositedShares, Pallet, TotalDexDepositedShares};
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use primitives::Balance;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::{marker::PhantomData, vec::Vec};
+use support::PoolId;
+
+/// Migrate the incentives storage to v1.
+pub struct MigrateToV1<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		v1::migrate::<T>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		v1::pre_migrate::<T>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		v1::post_migrate::<T>()
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Records the shares in rewards of the accounts staked DEX shares before boost as their
+	/// `DexDepositedShares`, sums up `TotalDexDepositedShares` and recalculates the working
+	/// shares of every staker.
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_storage_version >= 1 {
+			log::warn!(
+				target: "incentives",
+				"Attempted to apply migration to v1 but failed because storage version is {:?}",
+				on_chain_storage_version,
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: "incentives", "Migrating incentives to v1");
+
+		let mut rewards_shares_count: Weight = 0;
+		for (pool_id, who, (shares, _)) in orml_rewards::SharesAndWithdrawnRewards::<T>::iter() {
+			rewards_shares_count += 1;
+			if matches!(pool_id, PoolId::Dex(currency_id) if currency_id.is_dex_share_currency_id())
+				&& !DexDepositedShares::<T>::contains_key(&pool_id, &who)
+			{
+				DexDepositedShares::<T>::insert(&pool_id, &who, shares);
+			}
+		}
+
+		let mut stakers: Vec<(PoolId, T::AccountId)> = Vec::new();
+		let mut total_deposited: Vec<(PoolId, Balance)> = Vec::new();
+		for (pool_id, who, deposited) in DexDepositedShares::<T>::iter() {
+			match total_deposited.iter_mut().find(|(id, _)| *id == pool_id) {
+				Some((_, total)) => *total = total.saturating_add(deposited),
+				None => total_deposited.push((pool_id, deposited)),
+			}
+			stakers.push((pool_id, who));
+		}
+
+		for (pool_id, total) in total_deposited.iter() {
+			TotalDexDepositedShares::<T>::insert(pool_id, total);
+		}
+
+		// the working shares depend on the total deposited shares of the pool
+		for (pool_id, who) in stakers.iter() {
+			if let Err(e) = Pallet::<T>::adjust_dex_share(who, *pool_id, true, Zero::zero()) {
+				log::warn!(
+					target: "incentives",
+					"failed to recalculate the working shares of {:?} in {:?}: {:?}",
+					who, pool_id, e,
+				);
+			}
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		let count = stakers.len() as Weight;
+		log::info!(target: "incentives", "Completed incentives migration to v1, {:?} stakers", count);
+
+		// `adjust_dex_share` reads the deposited shares, the total deposited shares, the escrow
+		// lock and the shares in rewards, and writes all but the escrow lock
+		T::DbWeight::get().reads_writes(
+			rewards_shares_count
+				.saturating_mul(2)
+				.saturating_add(count.saturating_mul(6))
+				.saturating_add(2),
+			rewards_shares_count
+				.saturating_add(count.saturating_mul(4))
+				.saturating_add(total_deposited.len() as Weight)
+				.saturating_add(1),
+		)
+	}
+
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() < 1,
+			"incentives already migrated to v1."
+		);
+		Ok(())
+	}
+
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		assert_eq!(
+			Pallet::<T>::on_chain_storage_version(),
+			1,
+			"incentives storage version not set."
+		);
+		for (pool_id, who, _) in orml_rewards::SharesAndWithdrawnRewards::<T>::iter() {
+			if matches!(pool_id, PoolId::Dex(currency_id) if currency_id.is_dex_share_currency_id()) {
+				assert!(
+					DexDepositedShares::<T>::contains_key(&pool_id, &who),
+					"deposited shares not set."
+				);
+			}
+		}
+		Ok(())
+	}
+}
@@ -25,7 +25,7 @@ use frame_support::{
 	construct_runtime,
 	dispatch::{DispatchError, DispatchResult},
 	ord_parameter_types, parameter_types,
-	traits::{ConstU64, Everything, Nothing},
+	traits::{ConstU128, ConstU64, Everything, Nothing},
 	weights::constants::RocksDbWeight,
 };
 use frame_system::EnsureSignedBy;
@@ -98,6 +98,15 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_type_with_key! {
+	pub MinCampaignRewardPerBlock: |currency_id: CurrencyId| -> Balance {
+		match currency_id {
+			&DOT => 10,
+			_ => 1,
+		}
+	};
+}
+
 impl orml_tokens::Config for Runtime {
 	type Event = Event;
 	type Balance = Balance;
@@ -106,7 +115,7 @@ impl orml_tokens::Config for Runtime {
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
-	type MaxLocks = ();
+	type MaxLocks = ConstU32<50>;
 	type MaxReserves = ();
 	type ReserveIdentifier = [u8; 8];
 	type DustRemovalWhitelist = Nothing;
@@ -230,6 +239,17 @@ impl DEXManager<AccountId, Balance, CurrencyId> for MockDEX {
 	}
 }
 
+pub struct MockPriceSource;
+impl PriceProvider<CurrencyId> for MockPriceSource {
+	fn get_price(currency_id: CurrencyId) -> Option<Price> {
+		match currency_id {
+			ACA => Some(Price::saturating_from_rational(1, 2)),
+			AUSD => Some(Price::one()),
+			_ => None,
+		}
+	}
+}
+
 thread_local! {
 	static IS_SHUTDOWN: RefCell<bool> = RefCell::new(false);
 }
@@ -245,6 +265,22 @@ impl EmergencyShutdown for MockEmergencyShutdown {
 	}
 }
 
+thread_local! {
+	static MAX_BOOST: RefCell<Rate> = RefCell::new(Rate::one());
+}
+
+pub struct MaxBoost;
+impl MaxBoost {
+	pub fn set(max_boost: Rate) {
+		MAX_BOOST.with(|v| *v.borrow_mut() = max_boost);
+	}
+}
+impl Get<Rate> for MaxBoost {
+	fn get() -> Rate {
+		MAX_BOOST.with(|v| *v.borrow())
+	}
+}
+
 impl orml_rewards::Config for Runtime {
 	type Share = Balance;
 	type Balance = Balance;
@@ -277,6 +313,15 @@ impl Config for Runtime {
 	type DEX = MockDEX;
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type EpochPeriod = ConstU64<100>;
+	type MaxLockPeriod = ConstU64<1000>;
+	type MaxGaugeVotes = ConstU32<3>;
+	type MaxBoost = MaxBoost;
+	type MaxCampaigns = ConstU32<2>;
+	type MaxCampaignsPerPool = ConstU32<1>;
+	type MaxCampaignDuration = ConstU64<100>;
+	type MinCampaignRewardPerBlock = MinCampaignRewardPerBlock;
+	type CampaignDeposit = ConstU128<100>;
 	type WeightInfo = ();
 }
 
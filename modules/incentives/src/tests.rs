@@ -749,7 +749,9 @@ fn claim_rewards_works() {
 			vec![(ACA, 500), (AUSD, 1000)].into_iter().collect()
 		);
 
-		// alice claim rewards for PoolId::Dex(BTC_AUSD_LP)
+		// alice claim rewards for PoolId::Dex(BTC_AUSD_LP), the working shares are recalculated
+		// from the staked DEX shares
+		DexDepositedShares::<Runtime>::insert(PoolId::Dex(BTC_AUSD_LP), ALICE::get(), 50);
 		assert_ok!(IncentivesModule::claim_rewards(
 			Origin::signed(ALICE::get()),
 			PoolId::Dex(BTC_AUSD_LP)
@@ -1073,3 +1075,767 @@ fn earning_booster_should_work() {
 		);
 	});
 }
+
+#[test]
+fn escrow_lock_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &ALICE::get(), 2000));
+
+		assert_noop!(
+			IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 1000, 99),
+			Error::<Runtime>::InvalidLockPeriod
+		);
+		assert_noop!(
+			IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 1000, 1100),
+			Error::<Runtime>::InvalidLockPeriod
+		);
+		assert_noop!(
+			IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 0, 500),
+			Error::<Runtime>::ZeroLockAmount
+		);
+		assert_noop!(
+			IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 2001, 500),
+			Error::<Runtime>::BalanceTooLow
+		);
+
+		// unlock time is rounded down to the epoch boundary
+		assert_ok!(IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 1000, 550));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::EscrowLocked {
+			who: ALICE::get(),
+			amount: 1000,
+			unlock_at: 500,
+		}));
+		assert_eq!(
+			IncentivesModule::escrow_locks(&ALICE::get()),
+			Some(EscrowLock {
+				amount: 1000,
+				unlock_at: 500
+			})
+		);
+		assert_eq!(IncentivesModule::total_escrow_locked(), 1000);
+		assert_eq!(TokensModule::accounts(&ALICE::get(), ACA).frozen, 1000);
+		assert_noop!(
+			TokensModule::transfer(Origin::signed(ALICE::get()), BOB::get(), ACA, 1001),
+			orml_tokens::Error::<Runtime>::LiquidityRestrictions
+		);
+
+		// can't shorten the lock
+		assert_noop!(
+			IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 500, 400),
+			Error::<Runtime>::InvalidLockPeriod
+		);
+		assert_ok!(IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 500, 700));
+		assert_eq!(
+			IncentivesModule::escrow_locks(&ALICE::get()),
+			Some(EscrowLock {
+				amount: 1500,
+				unlock_at: 700
+			})
+		);
+		assert_eq!(IncentivesModule::total_escrow_locked(), 1500);
+		assert_eq!(TokensModule::accounts(&ALICE::get(), ACA).frozen, 1500);
+
+		// voting power decays linearly
+		assert_eq!(IncentivesModule::voting_power(&ALICE::get()), 1048);
+		System::set_block_number(350);
+		assert_eq!(IncentivesModule::voting_power(&ALICE::get()), 525);
+		System::set_block_number(700);
+		assert_eq!(IncentivesModule::voting_power(&ALICE::get()), 0);
+		assert_noop!(
+			IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 500, 800),
+			Error::<Runtime>::EscrowLockExpired
+		);
+	});
+}
+
+#[test]
+fn escrow_unlock_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &ALICE::get(), 2000));
+		assert_noop!(
+			IncentivesModule::escrow_unlock(Origin::signed(ALICE::get())),
+			Error::<Runtime>::EscrowLockNotFound
+		);
+
+		assert_ok!(IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 1000, 500));
+		assert_ok!(IncentivesModule::vote_gauges(
+			Origin::signed(ALICE::get()),
+			vec![(PoolId::Dex(BTC_AUSD_LP), Permill::one())]
+		));
+		assert_noop!(
+			IncentivesModule::escrow_unlock(Origin::signed(ALICE::get())),
+			Error::<Runtime>::EscrowLockNotExpired
+		);
+
+		System::set_block_number(500);
+		IncentivesModule::on_initialize(500);
+		assert_eq!(
+			IncentivesModule::gauge_points(PoolId::Dex(BTC_AUSD_LP)),
+			Default::default()
+		);
+
+		assert_ok!(IncentivesModule::escrow_unlock(Origin::signed(ALICE::get())));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::EscrowUnlocked {
+			who: ALICE::get(),
+			amount: 1000,
+		}));
+		assert_eq!(IncentivesModule::escrow_locks(&ALICE::get()), None);
+		assert_eq!(IncentivesModule::gauge_votes(&ALICE::get()).into_inner(), vec![]);
+		assert_eq!(IncentivesModule::total_escrow_locked(), 0);
+		assert_eq!(TokensModule::accounts(&ALICE::get(), ACA).frozen, 0);
+	});
+}
+
+#[test]
+fn vote_gauges_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &ALICE::get(), 2000));
+		assert_noop!(
+			IncentivesModule::vote_gauges(
+				Origin::signed(ALICE::get()),
+				vec![(PoolId::Dex(BTC_AUSD_LP), Permill::one())]
+			),
+			Error::<Runtime>::EscrowLockNotFound
+		);
+
+		assert_ok!(IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 1000, 500));
+		assert_noop!(
+			IncentivesModule::vote_gauges(Origin::signed(ALICE::get()), vec![(PoolId::Dex(BTC), Permill::one())]),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::vote_gauges(
+				Origin::signed(ALICE::get()),
+				vec![
+					(PoolId::Dex(BTC_AUSD_LP), Permill::from_percent(50)),
+					(PoolId::Dex(BTC_AUSD_LP), Permill::from_percent(50))
+				]
+			),
+			Error::<Runtime>::InvalidGaugeVotes
+		);
+		assert_noop!(
+			IncentivesModule::vote_gauges(
+				Origin::signed(ALICE::get()),
+				vec![
+					(PoolId::Dex(BTC_AUSD_LP), Permill::from_percent(60)),
+					(PoolId::Loans(DOT), Permill::from_percent(50))
+				]
+			),
+			Error::<Runtime>::InvalidGaugeVotes
+		);
+		assert_noop!(
+			IncentivesModule::vote_gauges(
+				Origin::signed(ALICE::get()),
+				vec![
+					(PoolId::Dex(BTC_AUSD_LP), Permill::from_percent(10)),
+					(PoolId::Dex(DOT_AUSD_LP), Permill::from_percent(10)),
+					(PoolId::Loans(DOT), Permill::from_percent(10)),
+					(PoolId::Loans(BTC), Permill::from_percent(10))
+				]
+			),
+			Error::<Runtime>::TooManyGaugeVotes
+		);
+
+		assert_ok!(IncentivesModule::vote_gauges(
+			Origin::signed(ALICE::get()),
+			vec![
+				(PoolId::Dex(BTC_AUSD_LP), Permill::from_percent(60)),
+				(PoolId::Loans(DOT), Permill::from_percent(40))
+			]
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::GaugeVoted {
+			who: ALICE::get(),
+			votes: vec![
+				(PoolId::Dex(BTC_AUSD_LP), Permill::from_percent(60)),
+				(PoolId::Loans(DOT), Permill::from_percent(40)),
+			],
+		}));
+		assert_eq!(
+			IncentivesModule::gauge_points(PoolId::Dex(BTC_AUSD_LP)),
+			GaugePoint {
+				amount: 600,
+				amount_end: 300_000
+			}
+		);
+		assert_eq!(
+			IncentivesModule::gauge_points(PoolId::Loans(DOT)),
+			GaugePoint {
+				amount: 400,
+				amount_end: 200_000
+			}
+		);
+		assert_eq!(
+			IncentivesModule::gauge_point_changes(500, PoolId::Loans(DOT)),
+			GaugePoint {
+				amount: 400,
+				amount_end: 200_000
+			}
+		);
+
+		// extending the lock moves the votes
+		assert_ok!(IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 1000, 800));
+		assert_eq!(
+			IncentivesModule::gauge_points(PoolId::Dex(BTC_AUSD_LP)),
+			GaugePoint {
+				amount: 1200,
+				amount_end: 960_000
+			}
+		);
+		assert_eq!(
+			IncentivesModule::gauge_point_changes(500, PoolId::Loans(DOT)),
+			Default::default()
+		);
+		assert_eq!(
+			IncentivesModule::gauge_point_changes(800, PoolId::Loans(DOT)),
+			GaugePoint {
+				amount: 800,
+				amount_end: 640_000
+			}
+		);
+
+		// revote replaces the previous votes
+		assert_ok!(IncentivesModule::vote_gauges(
+			Origin::signed(ALICE::get()),
+			vec![(PoolId::Loans(DOT), Permill::one())]
+		));
+		assert_eq!(
+			IncentivesModule::gauge_points(PoolId::Dex(BTC_AUSD_LP)),
+			Default::default()
+		);
+		assert_eq!(
+			IncentivesModule::gauge_points(PoolId::Loans(DOT)),
+			GaugePoint {
+				amount: 2000,
+				amount_end: 1_600_000
+			}
+		);
+	});
+}
+
+#[test]
+fn gauge_weights_update_incentive_rewards() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(ACA, &ALICE::get(), 1000));
+		assert_ok!(TokensModule::deposit(ACA, &BOB::get(), 1000));
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Loans(BTC), vec![(ACA, 50), (AUSD, 10)])]
+		));
+
+		assert_noop!(
+			IncentivesModule::update_gauge_reward_amount(Origin::signed(ALICE::get()), 1000),
+			BadOrigin
+		);
+		assert_ok!(IncentivesModule::update_gauge_reward_amount(
+			Origin::signed(ROOT::get()),
+			1000
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::GaugeRewardAmountUpdated {
+			reward_amount_per_period: 1000,
+		}));
+		assert_eq!(IncentivesModule::gauge_reward_amount(), 1000);
+
+		assert_ok!(IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 1000, 500));
+		assert_ok!(IncentivesModule::escrow_lock(Origin::signed(BOB::get()), 1000, 1000));
+		assert_ok!(IncentivesModule::vote_gauges(
+			Origin::signed(ALICE::get()),
+			vec![(PoolId::Dex(BTC_AUSD_LP), Permill::one())]
+		));
+		assert_ok!(IncentivesModule::vote_gauges(
+			Origin::signed(BOB::get()),
+			vec![(PoolId::Loans(DOT), Permill::one())]
+		));
+
+		// gauge weights are only applied at epoch boundaries
+		IncentivesModule::on_initialize(50);
+		assert_eq!(IncentivesModule::incentive_reward_amounts(PoolId::Loans(BTC), ACA), 50);
+		assert_eq!(IncentivesModule::incentive_reward_amounts(PoolId::Loans(DOT), ACA), 0);
+
+		IncentivesModule::on_initialize(100);
+		assert_eq!(
+			IncentivesModule::incentive_reward_amounts(PoolId::Dex(BTC_AUSD_LP), ACA),
+			307
+		);
+		assert_eq!(IncentivesModule::incentive_reward_amounts(PoolId::Loans(DOT), ACA), 692);
+		assert_eq!(IncentivesModule::incentive_reward_amounts(PoolId::Loans(BTC), ACA), 0);
+		assert_eq!(IncentivesModule::incentive_reward_amounts(PoolId::Loans(BTC), AUSD), 10);
+
+		// the votes of expired lock are removed, the removal is weighted
+		assert!(IncentivesModule::on_initialize(500) >= <() as WeightInfo>::on_initialize_epoch(1, 2));
+		assert_eq!(GaugePointChanges::<Runtime>::iter_prefix(500).count(), 0);
+		assert_eq!(
+			IncentivesModule::incentive_reward_amounts(PoolId::Dex(BTC_AUSD_LP), ACA),
+			0
+		);
+		assert_eq!(
+			IncentivesModule::incentive_reward_amounts(PoolId::Loans(DOT), ACA),
+			1000
+		);
+
+		// gauges are disabled
+		assert_ok!(IncentivesModule::update_gauge_reward_amount(
+			Origin::signed(ROOT::get()),
+			0
+		));
+		IncentivesModule::on_initialize(600);
+		assert_eq!(
+			IncentivesModule::incentive_reward_amounts(PoolId::Loans(DOT), ACA),
+			1000
+		);
+	});
+}
+
+#[test]
+fn boost_working_shares_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		MaxBoost::set(Rate::saturating_from_rational(5, 2));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &ALICE::get(), 1000));
+		assert_ok!(TokensModule::deposit(BTC_AUSD_LP, &BOB::get(), 1000));
+		assert_ok!(TokensModule::deposit(ACA, &ALICE::get(), 300));
+		assert_ok!(TokensModule::deposit(ACA, &BOB::get(), 100));
+
+		// no boost without vote escrow lock
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+		assert_ok!(IncentivesModule::deposit_dex_share(
+			Origin::signed(BOB::get()),
+			BTC_AUSD_LP,
+			1000
+		));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()).0,
+			400
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), BOB::get()).0,
+			400
+		);
+		assert_eq!(
+			IncentivesModule::get_shares(PoolId::Dex(BTC_AUSD_LP), ALICE::get()),
+			1000
+		);
+		assert_eq!(
+			IncentivesModule::total_deposited_shares(&PoolId::Dex(BTC_AUSD_LP)),
+			2000
+		);
+
+		// working shares are capped at the deposited shares
+		assert_ok!(IncentivesModule::escrow_lock(Origin::signed(BOB::get()), 100, 500));
+		assert_noop!(
+			IncentivesModule::kick(Origin::signed(ALICE::get()), BOB::get(), PoolId::Loans(BTC)),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_ok!(IncentivesModule::kick(
+			Origin::signed(ALICE::get()),
+			BOB::get(),
+			PoolId::Dex(BTC_AUSD_LP)
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::WorkingSharesUpdated {
+			who: BOB::get(),
+			pool: PoolId::Dex(BTC_AUSD_LP),
+			working_shares: 1000,
+		}));
+
+		assert_ok!(IncentivesModule::escrow_lock(Origin::signed(ALICE::get()), 300, 500));
+		assert_ok!(IncentivesModule::kick(
+			Origin::signed(ALICE::get()),
+			ALICE::get(),
+			PoolId::Dex(BTC_AUSD_LP)
+		));
+		assert_ok!(IncentivesModule::kick(
+			Origin::signed(ALICE::get()),
+			BOB::get(),
+			PoolId::Dex(BTC_AUSD_LP)
+		));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()).0,
+			1000
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), BOB::get()).0,
+			700
+		);
+		assert_eq!(RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)).total_shares, 1700);
+
+		// working shares are recalculated on withdraw
+		assert_noop!(
+			IncentivesModule::withdraw_dex_share(Origin::signed(ALICE::get()), BTC_AUSD_LP, 1001),
+			Error::<Runtime>::NotEnough
+		);
+		assert_ok!(IncentivesModule::withdraw_dex_share(
+			Origin::signed(ALICE::get()),
+			BTC_AUSD_LP,
+			500
+		));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), ALICE::get()).0,
+			500
+		);
+		assert_eq!(TokensModule::free_balance(BTC_AUSD_LP, &ALICE::get()), 500);
+
+		// expired locks don't boost, working shares are recalculated on claim
+		System::set_block_number(500);
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(BOB::get()),
+			PoolId::Dex(BTC_AUSD_LP)
+		));
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(BTC_AUSD_LP), BOB::get()).0,
+			400
+		);
+		assert_eq!(IncentivesModule::get_shares(PoolId::Dex(BTC_AUSD_LP), BOB::get()), 1000);
+	});
+}
+
+#[test]
+fn migrate_shares_staked_before_boost_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Dex(DOT_AUSD_LP), 100);
+		RewardsModule::add_share(&BOB::get(), &PoolId::Dex(DOT_AUSD_LP), 100);
+		RewardsModule::add_share(&BOB::get(), &PoolId::Loans(DOT), 100);
+		// bob staked 50 more after boost
+		DexDepositedShares::<Runtime>::insert(PoolId::Dex(DOT_AUSD_LP), BOB::get(), 150);
+		assert_eq!(IncentivesModule::get_shares(PoolId::Dex(DOT_AUSD_LP), ALICE::get()), 0);
+		StorageVersion::new(0).put::<IncentivesModule>();
+
+		MaxBoost::set(Rate::saturating_from_rational(5, 2));
+		crate::migrations::v1::migrate::<Runtime>();
+		assert_eq!(IncentivesModule::on_chain_storage_version(), 1);
+		assert_eq!(
+			IncentivesModule::get_shares(PoolId::Dex(DOT_AUSD_LP), ALICE::get()),
+			100
+		);
+		assert_eq!(IncentivesModule::get_shares(PoolId::Dex(DOT_AUSD_LP), BOB::get()), 150);
+		assert_eq!(IncentivesModule::total_deposited_shares(&PoolId::Dex(DOT_AUSD_LP)), 250);
+		assert_eq!(IncentivesModule::deposited_shares(&PoolId::Loans(DOT), BOB::get()), 0);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(DOT_AUSD_LP), ALICE::get()).0,
+			40
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Dex(DOT_AUSD_LP), BOB::get()).0,
+			60
+		);
+		assert_eq!(
+			RewardsModule::shares_and_withdrawn_rewards(PoolId::Loans(DOT), BOB::get()).0,
+			100
+		);
+	});
+}
+
+#[test]
+fn create_campaign_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+		assert_ok!(TokensModule::deposit(DOT, &ALICE::get(), 3000));
+		assert_ok!(TokensModule::deposit(ACA, &ALICE::get(), 1000));
+
+		assert_noop!(
+			IncentivesModule::create_campaign(Origin::signed(ALICE::get()), PoolId::Dex(BTC), DOT, 1000, 10, 60),
+			Error::<Runtime>::InvalidPoolId
+		);
+		assert_noop!(
+			IncentivesModule::create_campaign(Origin::signed(ALICE::get()), PoolId::Dex(BTC_AUSD_LP), DOT, 1000, 9, 60),
+			Error::<Runtime>::InvalidCampaignPeriod
+		);
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(ALICE::get()),
+				PoolId::Dex(BTC_AUSD_LP),
+				DOT,
+				1000,
+				60,
+				60
+			),
+			Error::<Runtime>::InvalidCampaignPeriod
+		);
+		// longer than MaxCampaignDuration
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(ALICE::get()),
+				PoolId::Dex(BTC_AUSD_LP),
+				DOT,
+				2020,
+				10,
+				111
+			),
+			Error::<Runtime>::InvalidCampaignPeriod
+		);
+		assert_noop!(
+			IncentivesModule::create_campaign(Origin::signed(ALICE::get()), PoolId::Dex(BTC_AUSD_LP), DOT, 49, 10, 60),
+			Error::<Runtime>::InvalidCampaignAmount
+		);
+		// less than MinCampaignRewardPerBlock
+		assert_noop!(
+			IncentivesModule::create_campaign(Origin::signed(ALICE::get()), PoolId::Dex(BTC_AUSD_LP), DOT, 499, 10, 60),
+			Error::<Runtime>::InvalidCampaignAmount
+		);
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(ALICE::get()),
+				PoolId::Dex(BTC_AUSD_LP),
+				DOT,
+				3001,
+				10,
+				60
+			),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+
+		assert_ok!(IncentivesModule::create_campaign(
+			Origin::signed(ALICE::get()),
+			PoolId::Dex(BTC_AUSD_LP),
+			DOT,
+			1010,
+			10,
+			60
+		));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::CampaignCreated {
+			campaign_id: 0,
+			creator: ALICE::get(),
+			pool: PoolId::Dex(BTC_AUSD_LP),
+			reward_currency_id: DOT,
+			reward_amount: 1010,
+			start: 10,
+			end: 60,
+		}));
+		assert_eq!(
+			IncentivesModule::campaigns(0),
+			Some(IncentiveCampaign {
+				creator: ALICE::get(),
+				pool_id: PoolId::Dex(BTC_AUSD_LP),
+				reward_currency_id: DOT,
+				reward_amount_per_block: 20,
+				remaining: 1010,
+				deposit: 100,
+				start: 10,
+				end: 60,
+				accumulated_until: 10,
+			})
+		);
+		assert_eq!(IncentivesModule::campaign_count(), 1);
+		assert_eq!(IncentivesModule::next_campaign_id(), 1);
+		assert_eq!(TokensModule::free_balance(DOT, &ALICE::get()), 1990);
+		assert_eq!(TokensModule::free_balance(DOT, &VAULT::get()), 1010);
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 900);
+		assert_eq!(TokensModule::free_balance(ACA, &VAULT::get()), 100);
+		assert_eq!(IncentivesModule::pool_campaign_count(PoolId::Dex(BTC_AUSD_LP)), 1);
+
+		assert_noop!(
+			IncentivesModule::create_campaign(
+				Origin::signed(ALICE::get()),
+				PoolId::Dex(BTC_AUSD_LP),
+				DOT,
+				1010,
+				10,
+				60
+			),
+			Error::<Runtime>::TooManyPoolCampaigns
+		);
+
+		assert_ok!(IncentivesModule::create_campaign(
+			Origin::signed(ALICE::get()),
+			PoolId::Loans(DOT),
+			DOT,
+			500,
+			20,
+			30
+		));
+		assert_noop!(
+			IncentivesModule::create_campaign(Origin::signed(ALICE::get()), PoolId::Loans(DOT), DOT, 500, 20, 30),
+			Error::<Runtime>::TooManyCampaigns
+		);
+	});
+}
+
+#[test]
+fn campaign_accumulate_and_refund_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TokensModule::deposit(DOT, &ALICE::get(), 2000));
+		assert_ok!(TokensModule::deposit(ACA, &ALICE::get(), 200));
+		RewardsModule::add_share(&BOB::get(), &PoolId::Dex(BTC_AUSD_LP), 100);
+
+		assert_ok!(IncentivesModule::create_campaign(
+			Origin::signed(ALICE::get()),
+			PoolId::Dex(BTC_AUSD_LP),
+			DOT,
+			1010,
+			5,
+			55
+		));
+		// no shares in PoolId::Loans(DOT)
+		assert_ok!(IncentivesModule::create_campaign(
+			Origin::signed(ALICE::get()),
+			PoolId::Loans(DOT),
+			DOT,
+			500,
+			5,
+			15
+		));
+		assert_eq!(TokensModule::free_balance(DOT, &ALICE::get()), 490);
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 0);
+
+		IncentivesModule::on_initialize(10);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)),
+			PoolInfo {
+				total_shares: 100,
+				rewards: vec![(DOT, (100, 0))].into_iter().collect(),
+			}
+		);
+		assert_eq!(IncentivesModule::campaigns(0).unwrap().remaining, 910);
+		assert_eq!(IncentivesModule::campaigns(1).unwrap().remaining, 500);
+		assert_eq!(IncentivesModule::campaigns(1).unwrap().accumulated_until, 10);
+
+		assert_noop!(
+			IncentivesModule::refund_campaign(Origin::signed(BOB::get()), 2),
+			Error::<Runtime>::CampaignNotFound
+		);
+		assert_noop!(
+			IncentivesModule::refund_campaign(Origin::signed(BOB::get()), 1),
+			Error::<Runtime>::CampaignNotEnded
+		);
+
+		IncentivesModule::on_initialize(20);
+		assert_eq!(IncentivesModule::campaigns(0).unwrap().remaining, 710);
+		assert_eq!(IncentivesModule::campaigns(1).unwrap().accumulated_until, 15);
+
+		// anyone can refund the ended campaign to the creator
+		assert_ok!(IncentivesModule::refund_campaign(Origin::signed(BOB::get()), 1));
+		System::assert_last_event(Event::IncentivesModule(crate::Event::CampaignRefunded {
+			campaign_id: 1,
+			creator: ALICE::get(),
+			refund_amount: 500,
+			deposit: 100,
+		}));
+		assert_eq!(IncentivesModule::campaigns(1), None);
+		assert_eq!(IncentivesModule::campaign_count(), 1);
+		assert_eq!(IncentivesModule::pool_campaign_count(PoolId::Loans(DOT)), 0);
+		assert_eq!(TokensModule::free_balance(DOT, &ALICE::get()), 990);
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 100);
+
+		IncentivesModule::on_initialize(60);
+		assert_eq!(
+			RewardsModule::pool_infos(PoolId::Dex(BTC_AUSD_LP)),
+			PoolInfo {
+				total_shares: 100,
+				rewards: vec![(DOT, (1000, 0))].into_iter().collect(),
+			}
+		);
+		assert_eq!(IncentivesModule::campaigns(0).unwrap().remaining, 10);
+		assert_eq!(IncentivesModule::campaigns(0).unwrap().accumulated_until, 55);
+
+		// the ended campaign is not accumulated any more
+		IncentivesModule::on_initialize(70);
+		assert_eq!(IncentivesModule::campaigns(0).unwrap().remaining, 10);
+
+		assert_ok!(IncentivesModule::refund_campaign(Origin::signed(ALICE::get()), 0));
+		assert_eq!(TokensModule::free_balance(DOT, &ALICE::get()), 1000);
+		assert_eq!(TokensModule::free_balance(ACA, &ALICE::get()), 200);
+		assert_eq!(IncentivesModule::campaign_count(), 0);
+
+		assert_ok!(IncentivesModule::claim_rewards(
+			Origin::signed(BOB::get()),
+			PoolId::Dex(BTC_AUSD_LP)
+		));
+		assert_eq!(TokensModule::free_balance(DOT, &BOB::get()), 1000);
+		assert_eq!(TokensModule::free_balance(DOT, &VAULT::get()), 0);
+	});
+}
+
+#[test]
+fn account_rewards_and_pool_apr_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		RewardsModule::add_share(&ALICE::get(), &PoolId::Loans(BTC), 100);
+		RewardsModule::add_share(&BOB::get(), &PoolId::Loans(BTC), 300);
+		assert_ok!(RewardsModule::accumulate_reward(&PoolId::Loans(BTC), ACA, 400));
+		PendingMultiRewards::<Runtime>::mutate(PoolId::Loans(BTC), ALICE::get(), |v| {
+			v.insert(AUSD, 50);
+		});
+		assert_ok!(IncentivesModule::update_claim_reward_deduction_rates(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Loans(BTC), Rate::saturating_from_rational(20, 100))]
+		));
+
+		assert_eq!(
+			IncentivesModule::account_rewards(&ALICE::get()),
+			vec![(PoolId::Loans(BTC), 100, vec![(ACA, 80), (AUSD, 40)])]
+		);
+		assert_eq!(
+			IncentivesModule::account_rewards(&BOB::get()),
+			vec![(PoolId::Loans(BTC), 300, vec![(ACA, 240)])]
+		);
+		assert_eq!(IncentivesModule::account_rewards(&VAULT::get()), vec![]);
+
+		// the claimed rewards are not pending any more
+		RewardsModule::claim_rewards(&BOB::get(), &PoolId::Loans(BTC));
+		assert_eq!(
+			IncentivesModule::account_rewards(&BOB::get()),
+			vec![(PoolId::Loans(BTC), 300, vec![(ACA, 240)])]
+		);
+		PendingMultiRewards::<Runtime>::remove(PoolId::Loans(BTC), BOB::get());
+		assert_eq!(
+			IncentivesModule::account_rewards(&BOB::get()),
+			vec![(PoolId::Loans(BTC), 300, vec![])]
+		);
+
+		assert_ok!(IncentivesModule::update_incentive_rewards(
+			Origin::signed(ROOT::get()),
+			vec![
+				(PoolId::Loans(BTC), vec![(ACA, 100)]),
+				(PoolId::Dex(DOT_AUSD_LP), vec![(DOT, 100)]),
+			]
+		));
+		assert_ok!(IncentivesModule::update_dex_saving_rewards(
+			Origin::signed(ROOT::get()),
+			vec![(PoolId::Dex(BTC_AUSD_LP), Rate::saturating_from_rational(1, 100))]
+		));
+		assert_eq!(
+			IncentivesModule::pool_rewards_per_period(PoolId::Loans(BTC)),
+			vec![(ACA, 100)].into_iter().collect()
+		);
+		assert_eq!(
+			IncentivesModule::pool_rewards_per_period(PoolId::Dex(BTC_AUSD_LP)),
+			vec![(AUSD, 5)].into_iter().collect()
+		);
+
+		// 100 periods per year
+		assert_eq!(
+			IncentivesModule::pool_apr::<MockPriceSource>(PoolId::Loans(BTC), 10_000, 1_000),
+			Some(Rate::saturating_from_rational(1, 2))
+		);
+		assert_eq!(
+			IncentivesModule::pool_apr::<MockPriceSource>(PoolId::Dex(BTC_AUSD_LP), 1_000, 1_000),
+			Some(Rate::saturating_from_rational(1, 2))
+		);
+		assert_eq!(
+			IncentivesModule::pool_apr::<MockPriceSource>(PoolId::Loans(BTC), 0, 1_000),
+			None
+		);
+		// no price for DOT rewards
+		assert_eq!(
+			IncentivesModule::pool_apr::<MockPriceSource>(PoolId::Dex(DOT_AUSD_LP), 1_000, 1_000),
+			None
+		);
+
+		// no incentives for PoolId::Loans after shutdown
+		mock_shutdown();
+		assert_eq!(
+			IncentivesModule::pool_rewards_per_period(PoolId::Loans(BTC)),
+			BTreeMap::new()
+		);
+		assert_eq!(
+			IncentivesModule::pool_rewards_per_period(PoolId::Dex(BTC_AUSD_LP)),
+			BTreeMap::new()
+		);
+	});
+}
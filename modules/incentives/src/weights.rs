@@ -55,6 +55,15 @@ pub trait WeightInfo {
 	fn update_incentive_rewards(c: u32, ) -> Weight;
 	fn update_dex_saving_rewards(c: u32, ) -> Weight;
 	fn update_claim_reward_deduction_rates(c: u32, ) -> Weight;
+	fn on_initialize_epoch(e: u32, c: u32, ) -> Weight;
+	fn escrow_lock() -> Weight;
+	fn escrow_unlock() -> Weight;
+	fn vote_gauges(c: u32, ) -> Weight;
+	fn update_gauge_reward_amount() -> Weight;
+	fn kick() -> Weight;
+	fn on_initialize_campaigns(c: u32, ) -> Weight;
+	fn create_campaign() -> Weight;
+	fn refund_campaign() -> Weight;
 }
 
 /// Weights for module_incentives using the Acala node and recommended hardware.
@@ -99,6 +108,60 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn on_initialize_epoch(e: u32, c: u32, ) -> Weight {
+		(9_412_000 as Weight)
+			.saturating_add((6_954_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add((12_873_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn escrow_lock() -> Weight {
+		(61_275_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn escrow_unlock() -> Weight {
+		(38_106_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn vote_gauges(c: u32, ) -> Weight {
+		(24_318_000 as Weight)
+			.saturating_add((8_265_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_gauge_reward_amount() -> Weight {
+		(4_711_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn kick() -> Weight {
+		(36_524_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn on_initialize_campaigns(c: u32, ) -> Weight {
+		(3_215_000 as Weight)
+			.saturating_add((18_624_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn create_campaign() -> Weight {
+		(49_390_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn refund_campaign() -> Weight {
+		(43_270_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -142,4 +205,58 @@ impl WeightInfo for () {
 			.saturating_add((1_829_000 as Weight).saturating_mul(c as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	fn on_initialize_epoch(e: u32, c: u32, ) -> Weight {
+		(9_412_000 as Weight)
+			.saturating_add((6_954_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add((12_873_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn escrow_lock() -> Weight {
+		(61_275_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn escrow_unlock() -> Weight {
+		(38_106_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn vote_gauges(c: u32, ) -> Weight {
+		(24_318_000 as Weight)
+			.saturating_add((8_265_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
+	fn update_gauge_reward_amount() -> Weight {
+		(4_711_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn kick() -> Weight {
+		(36_524_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn on_initialize_campaigns(c: u32, ) -> Weight {
+		(3_215_000 as Weight)
+			.saturating_add((18_624_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn create_campaign() -> Weight {
+		(49_390_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn refund_campaign() -> Weight {
+		(43_270_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
 }
@@ -0,0 +1,474 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Multi Vesting Module
+//!
+//! ## Overview
+//!
+//! Vesting schedules of any `CurrencyId`, including tokens, DEX shares and ERC20. The vesting
+//! tokens are locked by `MultiLockableCurrency`, for ERC20 the locked tokens are escrowed by
+//! `module_currencies`.
+//!
+//! A vesting schedule releases `per_period` amount every `period` of blocks after `start`, and
+//! nothing can be claimed before the cliff. An account could have at most `MaxVestingSchedules`
+//! schedules per currency, and two schedules could be merged into one to free the slot.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::LockIdentifier, transactional, BoundedVec};
+use frame_system::pallet_prelude::*;
+use orml_traits::{GetByKey, MultiCurrency, MultiLockableCurrency};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{
+	traits::{BlockNumberProvider, StaticLookup, Zero},
+	ArithmeticError,
+};
+use sp_std::{cmp::max, vec::Vec};
+use support::{VestingManager, VestingSchedule};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub const VESTING_LOCK_ID: LockIdentifier = *b"aca/mvst";
+
+pub type VestingScheduleOf<T> = VestingSchedule<<T as frame_system::Config>::BlockNumber, Balance>;
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency to lock the vesting tokens.
+		type Currency: MultiLockableCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The minimum amount transferred to call `vested_transfer`.
+		type MinVestedTransfer: GetByKey<CurrencyId, Balance>;
+
+		/// The origin which may update vesting schedules and force vested transfers.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum vesting schedules of an account per currency.
+		#[pallet::constant]
+		type MaxVestingSchedules: Get<u32>;
+
+		/// The block number provider of the vesting schedules.
+		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Vesting period is zero.
+		ZeroVestingPeriod,
+		/// Number of vests is zero.
+		ZeroVestingPeriodCount,
+		/// Insufficient amount of balance to lock.
+		InsufficientBalanceToLock,
+		/// The vested transfer amount is too low.
+		AmountLow,
+		/// Failed because the maximum vesting schedules was exceeded.
+		MaxVestingSchedulesExceeded,
+		/// The vesting schedule does not exist.
+		InvalidScheduleIndex,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Added a new vesting schedule.
+		VestingScheduleAdded {
+			from: T::AccountId,
+			to: T::AccountId,
+			currency_id: CurrencyId,
+			vesting_schedule: VestingScheduleOf<T>,
+		},
+		/// Claimed vested tokens, `locked` is the amount still locked.
+		Claimed {
+			who: T::AccountId,
+			currency_id: CurrencyId,
+			locked: Balance,
+		},
+		/// Updated vesting schedules.
+		VestingSchedulesUpdated { who: T::AccountId, currency_id: CurrencyId },
+		/// Merged two vesting schedules into one.
+		VestingSchedulesMerged {
+			who: T::AccountId,
+			currency_id: CurrencyId,
+			vesting_schedule: VestingScheduleOf<T>,
+		},
+	}
+
+	/// Vesting schedules of an account per currency.
+	///
+	/// VestingSchedules: double_map AccountId, CurrencyId => Vec<VestingSchedule>
+	#[pallet::storage]
+	#[pallet::getter(fn vesting_schedules)]
+	pub type VestingSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		CurrencyId,
+		BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules>,
+		ValueQuery,
+	>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Unlock the vested tokens of the caller.
+		///
+		/// - `currency_id`: the vesting currency.
+		#[pallet::weight(T::WeightInfo::claim(T::MaxVestingSchedules::get()))]
+		#[transactional]
+		pub fn claim(origin: OriginFor<T>, currency_id: CurrencyId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_claim(&who, currency_id)?;
+			Ok(())
+		}
+
+		/// Unlock the vested tokens of `dest`.
+		///
+		/// - `dest`: the account to claim for.
+		/// - `currency_id`: the vesting currency.
+		#[pallet::weight(T::WeightInfo::claim(T::MaxVestingSchedules::get()))]
+		#[transactional]
+		pub fn claim_for(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(dest)?;
+			Self::do_claim(&who, currency_id)?;
+			Ok(())
+		}
+
+		/// Transfer the total amount of `schedule` from the caller to `dest`, and lock it by the
+		/// schedule.
+		///
+		/// - `dest`: the receiver.
+		/// - `currency_id`: the vesting currency.
+		/// - `schedule`: the vesting schedule.
+		#[pallet::weight(T::WeightInfo::vested_transfer())]
+		#[transactional]
+		pub fn vested_transfer(
+			origin: OriginFor<T>,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			let to = T::Lookup::lookup(dest)?;
+			Self::do_vested_transfer(&from, &to, currency_id, schedule)
+		}
+
+		/// Transfer the total amount of `schedule` from `source` to `dest`, and lock it by the
+		/// schedule.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `source`: the payer.
+		/// - `dest`: the receiver.
+		/// - `currency_id`: the vesting currency.
+		/// - `schedule`: the vesting schedule.
+		#[pallet::weight(T::WeightInfo::vested_transfer())]
+		#[transactional]
+		pub fn force_vested_transfer(
+			origin: OriginFor<T>,
+			source: <T::Lookup as StaticLookup>::Source,
+			dest: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+			schedule: VestingScheduleOf<T>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let from = T::Lookup::lookup(source)?;
+			let to = T::Lookup::lookup(dest)?;
+			Self::do_vested_transfer(&from, &to, currency_id, schedule)
+		}
+
+		/// Replace the vesting schedules of `who`, the total amount of the schedules must be
+		/// covered by the balance of `who`.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `who`: the account to update.
+		/// - `currency_id`: the vesting currency.
+		/// - `vesting_schedules`: the new vesting schedules.
+		#[pallet::weight(T::WeightInfo::update_vesting_schedules(vesting_schedules.len() as u32))]
+		#[transactional]
+		pub fn update_vesting_schedules(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+			vesting_schedules: Vec<VestingScheduleOf<T>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			let bounded_schedules: BoundedVec<VestingScheduleOf<T>, T::MaxVestingSchedules> = vesting_schedules
+				.try_into()
+				.map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
+			let total_amount = bounded_schedules
+				.iter()
+				.try_fold::<_, _, Result<Balance, DispatchError>>(Zero::zero(), |acc, schedule| {
+					let amount = Self::ensure_valid_vesting_schedule(schedule)?;
+					Ok(acc.checked_add(amount).ok_or(ArithmeticError::Overflow)?)
+				})?;
+			ensure!(
+				T::Currency::free_balance(currency_id, &who) >= total_amount,
+				Error::<T>::InsufficientBalanceToLock,
+			);
+
+			if bounded_schedules.is_empty() {
+				VestingSchedules::<T>::remove(&who, currency_id);
+			} else {
+				VestingSchedules::<T>::insert(&who, currency_id, bounded_schedules);
+			}
+			Self::update_lock(&who, currency_id)?;
+
+			Self::deposit_event(Event::VestingSchedulesUpdated { who, currency_id });
+			Ok(())
+		}
+
+		/// Merge two vesting schedules of the caller into one, the merged schedule never unlocks
+		/// earlier than the two. Schedules of the same shape are merged by summing `per_period`,
+		/// otherwise the sum of their locked amount is locked until the later end of the two.
+		///
+		/// - `currency_id`: the vesting currency.
+		/// - `schedule1_index`: the index of the first schedule.
+		/// - `schedule2_index`: the index of the second schedule.
+		#[pallet::weight(T::WeightInfo::merge_schedules(T::MaxVestingSchedules::get()))]
+		#[transactional]
+		pub fn merge_schedules(
+			origin: OriginFor<T>,
+			currency_id: CurrencyId,
+			schedule1_index: u32,
+			schedule2_index: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(schedule1_index != schedule2_index, Error::<T>::InvalidScheduleIndex);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let merged = VestingSchedules::<T>::try_mutate(&who, currency_id, |schedules| {
+				let schedule1 = *schedules
+					.get(schedule1_index as usize)
+					.ok_or(Error::<T>::InvalidScheduleIndex)?;
+				let schedule2 = *schedules
+					.get(schedule2_index as usize)
+					.ok_or(Error::<T>::InvalidScheduleIndex)?;
+				let merged = Self::merge_vesting_schedules(now, &schedule1, &schedule2)?;
+
+				// remove the larger index first to keep the smaller one valid
+				schedules.remove(max(schedule1_index, schedule2_index) as usize);
+				schedules.remove(schedule1_index.min(schedule2_index) as usize);
+				if let Some(merged) = merged {
+					schedules
+						.try_push(merged)
+						.map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
+				}
+				Ok::<_, DispatchError>(merged)
+			})?;
+			Self::update_lock(&who, currency_id)?;
+
+			if let Some(vesting_schedule) = merged {
+				Self::deposit_event(Event::VestingSchedulesMerged {
+					who,
+					currency_id,
+					vesting_schedule,
+				});
+			}
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The amount locked by the vesting schedules at `now`.
+	fn locked_balance_at(who: &T::AccountId, currency_id: CurrencyId, now: T::BlockNumber) -> Balance {
+		Self::vesting_schedules(who, currency_id)
+			.iter()
+			.fold(Zero::zero(), |acc: Balance, schedule| {
+				acc.saturating_add(schedule.locked_amount(now))
+			})
+	}
+
+	/// Set the lock of `who` to the amount locked by the vesting schedules.
+	fn update_lock(who: &T::AccountId, currency_id: CurrencyId) -> Result<Balance, DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
+		let locked = Self::locked_balance_at(who, currency_id, now);
+		if locked.is_zero() {
+			VestingSchedules::<T>::remove(who, currency_id);
+			T::Currency::remove_lock(VESTING_LOCK_ID, currency_id, who)?;
+		} else {
+			T::Currency::set_lock(VESTING_LOCK_ID, currency_id, who, locked)?;
+		}
+		Ok(locked)
+	}
+
+	/// Unlock the vested tokens and remove the finished schedules, returns the amount still
+	/// locked.
+	fn do_claim(who: &T::AccountId, currency_id: CurrencyId) -> Result<Balance, DispatchError> {
+		let now = T::BlockNumberProvider::current_block_number();
+		VestingSchedules::<T>::mutate_exists(who, currency_id, |maybe_schedules| {
+			if let Some(schedules) = maybe_schedules {
+				schedules.retain(|schedule| !schedule.locked_amount(now).is_zero());
+				if schedules.is_empty() {
+					*maybe_schedules = None;
+				}
+			}
+		});
+		let locked = Self::update_lock(who, currency_id)?;
+
+		Self::deposit_event(Event::Claimed {
+			who: who.clone(),
+			currency_id,
+			locked,
+		});
+		Ok(locked)
+	}
+
+	fn do_vested_transfer(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		currency_id: CurrencyId,
+		schedule: VestingScheduleOf<T>,
+	) -> DispatchResult {
+		let schedule_amount = Self::ensure_valid_vesting_schedule(&schedule)?;
+		ensure!(
+			schedule_amount >= T::MinVestedTransfer::get(&currency_id),
+			Error::<T>::AmountLow
+		);
+
+		VestingSchedules::<T>::try_append(to, currency_id, schedule)
+			.map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)?;
+		T::Currency::transfer(currency_id, from, to, schedule_amount)?;
+		Self::update_lock(to, currency_id)?;
+
+		Self::deposit_event(Event::VestingScheduleAdded {
+			from: from.clone(),
+			to: to.clone(),
+			currency_id,
+			vesting_schedule: schedule,
+		});
+		Ok(())
+	}
+
+	/// Returns the total amount of a valid schedule.
+	fn ensure_valid_vesting_schedule(schedule: &VestingScheduleOf<T>) -> Result<Balance, DispatchError> {
+		ensure!(!schedule.period.is_zero(), Error::<T>::ZeroVestingPeriod);
+		ensure!(!schedule.period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+		schedule.end().ok_or(ArithmeticError::Overflow)?;
+		schedule.cliff_end().ok_or(ArithmeticError::Overflow)?;
+
+		let total_amount = schedule.total_amount().ok_or(ArithmeticError::Overflow)?;
+		Ok(total_amount)
+	}
+
+	/// Merge the remaining of two schedules into one which is locked no less than the two at any
+	/// block after `now`, returns `None` if nothing is locked.
+	fn merge_vesting_schedules(
+		now: T::BlockNumber,
+		schedule1: &VestingScheduleOf<T>,
+		schedule2: &VestingScheduleOf<T>,
+	) -> Result<Option<VestingScheduleOf<T>>, DispatchError> {
+		let locked1 = schedule1.locked_amount(now);
+		let locked2 = schedule2.locked_amount(now);
+		if locked1.is_zero() && locked2.is_zero() {
+			return Ok(None);
+		}
+		if locked2.is_zero() {
+			return Ok(Some(*schedule1));
+		}
+		if locked1.is_zero() {
+			return Ok(Some(*schedule2));
+		}
+
+		// the same shape, releases the sum per period
+		let same_shape = VestingSchedule {
+			per_period: schedule2.per_period,
+			..*schedule1
+		} == *schedule2;
+		if same_shape {
+			let per_period = schedule1
+				.per_period
+				.checked_add(schedule2.per_period)
+				.ok_or(ArithmeticError::Overflow)?;
+			return Ok(Some(VestingSchedule {
+				per_period,
+				..*schedule1
+			}));
+		}
+
+		// otherwise lock all until the last block anything of the two is locked
+		let locked = locked1.checked_add(locked2).ok_or(ArithmeticError::Overflow)?;
+		let unlock_at = max(Self::fully_unlocked_at(schedule1)?, Self::fully_unlocked_at(schedule2)?);
+
+		Ok(Some(VestingSchedule {
+			start: now,
+			cliff: Zero::zero(),
+			period: unlock_at.saturating_sub(now),
+			period_count: 1,
+			per_period: locked,
+		}))
+	}
+
+	/// The first block that nothing is locked by `schedule`.
+	fn fully_unlocked_at(schedule: &VestingScheduleOf<T>) -> Result<T::BlockNumber, DispatchError> {
+		Ok(max(
+			schedule.end().ok_or(ArithmeticError::Overflow)?,
+			schedule.cliff_end().ok_or(ArithmeticError::Overflow)?,
+		))
+	}
+}
+
+impl<T: Config> VestingManager<T::AccountId, CurrencyId, Balance, T::BlockNumber> for Pallet<T> {
+	#[transactional]
+	fn vested_transfer(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		currency_id: CurrencyId,
+		schedule: VestingScheduleOf<T>,
+	) -> DispatchResult {
+		Self::do_vested_transfer(from, to, currency_id, schedule)
+	}
+
+	#[transactional]
+	fn claim(who: &T::AccountId, currency_id: CurrencyId) -> Result<Balance, DispatchError> {
+		Self::do_claim(who, currency_id)
+	}
+
+	fn locked_balance(who: &T::AccountId, currency_id: CurrencyId) -> Balance {
+		Self::locked_balance_at(who, currency_id, T::BlockNumberProvider::current_block_number())
+	}
+}
@@ -0,0 +1,432 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the multi vesting module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use orml_tokens::BalanceLock;
+use sp_runtime::traits::BadOrigin;
+use sp_std::ops::RangeInclusive;
+
+fn schedule(
+	start: BlockNumber,
+	cliff: BlockNumber,
+	period: BlockNumber,
+	period_count: u32,
+	per_period: Balance,
+) -> VestingScheduleOf<Runtime> {
+	VestingSchedule {
+		start,
+		cliff,
+		period,
+		period_count,
+		per_period,
+	}
+}
+
+fn locked(who: AccountId, currency_id: CurrencyId) -> Balance {
+	Tokens::locks(who, currency_id)
+		.iter()
+		.find(|lock| lock.id == VESTING_LOCK_ID)
+		.map_or(0, |lock| lock.amount)
+}
+
+#[test]
+fn vesting_schedule_works() {
+	let s = schedule(10, 20, 10, 4, 25);
+	assert_eq!(s.end(), Some(50));
+	assert_eq!(s.cliff_end(), Some(30));
+	assert_eq!(s.total_amount(), Some(100));
+	assert_eq!(s.locked_amount(0), 100);
+	assert_eq!(s.locked_amount(29), 100);
+	assert_eq!(s.locked_amount(30), 50);
+	assert_eq!(s.locked_amount(39), 50);
+	assert_eq!(s.locked_amount(40), 25);
+	assert_eq!(s.locked_amount(50), 0);
+	assert_eq!(s.locked_amount(100), 0);
+
+	assert_eq!(schedule(u64::MAX, 0, 1, 1, 1).end(), None);
+	assert_eq!(schedule(u64::MAX, 1, 1, 0, 1).cliff_end(), None);
+	assert_eq!(schedule(0, 0, 1, 2, u128::MAX).total_amount(), None);
+}
+
+#[test]
+fn vested_transfer_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let s = schedule(1, 0, 10, 2, 50);
+		assert_ok!(MultiVestingModule::vested_transfer(Origin::signed(ALICE), BOB, DOT, s));
+		System::assert_last_event(Event::MultiVestingModule(crate::Event::VestingScheduleAdded {
+			from: ALICE,
+			to: BOB,
+			currency_id: DOT,
+			vesting_schedule: s,
+		}));
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 900);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 100);
+		assert_eq!(MultiVestingModule::vesting_schedules(BOB, DOT).into_inner(), vec![s]);
+		assert_eq!(
+			Tokens::locks(BOB, DOT).into_inner(),
+			vec![BalanceLock {
+				id: VESTING_LOCK_ID,
+				amount: 100
+			}]
+		);
+		assert_noop!(
+			Tokens::transfer(Origin::signed(BOB), ALICE, DOT, 1),
+			orml_tokens::Error::<Runtime>::LiquidityRestrictions
+		);
+
+		// schedules of other currencies are independent
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			LDOT,
+			schedule(1, 0, 5, 10, 10)
+		));
+		assert_eq!(locked(BOB, DOT), 100);
+		assert_eq!(locked(BOB, LDOT), 100);
+		assert_eq!(MultiVestingModule::locked_balance(&BOB, LDOT), 100);
+	});
+}
+
+#[test]
+fn vested_transfer_fails_if_invalid_schedule() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			MultiVestingModule::vested_transfer(Origin::signed(ALICE), BOB, DOT, schedule(1, 0, 0, 2, 50)),
+			Error::<Runtime>::ZeroVestingPeriod
+		);
+		assert_noop!(
+			MultiVestingModule::vested_transfer(Origin::signed(ALICE), BOB, DOT, schedule(1, 0, 10, 0, 50)),
+			Error::<Runtime>::ZeroVestingPeriodCount
+		);
+		assert_noop!(
+			MultiVestingModule::vested_transfer(Origin::signed(ALICE), BOB, DOT, schedule(u64::MAX, 0, 10, 2, 50)),
+			ArithmeticError::Overflow
+		);
+		assert_noop!(
+			MultiVestingModule::vested_transfer(Origin::signed(ALICE), BOB, DOT, schedule(1, 0, 10, 2, 2)),
+			Error::<Runtime>::AmountLow
+		);
+		assert_noop!(
+			MultiVestingModule::vested_transfer(Origin::signed(ALICE), BOB, DOT, schedule(1, 0, 10, 2, 1_000)),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn vested_transfer_fails_if_exceed_max_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(MultiVestingModule::vested_transfer(
+				Origin::signed(ALICE),
+				BOB,
+				DOT,
+				schedule(1, 0, 10, 2, 50)
+			));
+		}
+		assert_noop!(
+			MultiVestingModule::vested_transfer(Origin::signed(ALICE), BOB, DOT, schedule(1, 0, 10, 2, 50)),
+			Error::<Runtime>::MaxVestingSchedulesExceeded
+		);
+	});
+}
+
+#[test]
+fn claim_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DOT,
+			schedule(1, 20, 10, 4, 25)
+		));
+
+		// nothing vested before the cliff
+		System::set_block_number(20);
+		assert_ok!(MultiVestingModule::claim(Origin::signed(BOB), DOT));
+		System::assert_last_event(Event::MultiVestingModule(crate::Event::Claimed {
+			who: BOB,
+			currency_id: DOT,
+			locked: 100,
+		}));
+		assert_eq!(locked(BOB, DOT), 100);
+
+		System::set_block_number(21);
+		assert_ok!(MultiVestingModule::claim(Origin::signed(BOB), DOT));
+		assert_eq!(locked(BOB, DOT), 50);
+		assert_ok!(Tokens::transfer(Origin::signed(BOB), ALICE, DOT, 50));
+		assert_noop!(
+			Tokens::transfer(Origin::signed(BOB), ALICE, DOT, 1),
+			orml_tokens::Error::<Runtime>::LiquidityRestrictions
+		);
+
+		// anyone could claim for others
+		System::set_block_number(41);
+		assert_ok!(MultiVestingModule::claim_for(Origin::signed(ALICE), BOB, DOT));
+		assert_eq!(locked(BOB, DOT), 0);
+		assert!(Tokens::locks(BOB, DOT).is_empty());
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, DOT));
+		assert_ok!(Tokens::transfer(Origin::signed(BOB), ALICE, DOT, 50));
+	});
+}
+
+#[test]
+fn claim_removes_finished_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DOT,
+			schedule(1, 0, 10, 1, 50)
+		));
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DOT,
+			schedule(1, 0, 10, 3, 50)
+		));
+
+		System::set_block_number(11);
+		assert_ok!(MultiVestingModule::claim(Origin::signed(BOB), DOT));
+		assert_eq!(
+			MultiVestingModule::vesting_schedules(BOB, DOT).into_inner(),
+			vec![schedule(1, 0, 10, 3, 50)]
+		);
+		assert_eq!(locked(BOB, DOT), 100);
+	});
+}
+
+#[test]
+fn force_vested_transfer_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let s = schedule(1, 0, 10, 2, 50);
+		assert_noop!(
+			MultiVestingModule::force_vested_transfer(Origin::signed(ALICE), CHARLIE, BOB, DOT, s),
+			BadOrigin
+		);
+		assert_ok!(MultiVestingModule::force_vested_transfer(
+			Origin::signed(Admin::get()),
+			CHARLIE,
+			BOB,
+			DOT,
+			s
+		));
+		System::assert_last_event(Event::MultiVestingModule(crate::Event::VestingScheduleAdded {
+			from: CHARLIE,
+			to: BOB,
+			currency_id: DOT,
+			vesting_schedule: s,
+		}));
+		assert_eq!(Tokens::free_balance(DOT, &CHARLIE), 900);
+		assert_eq!(locked(BOB, DOT), 100);
+	});
+}
+
+#[test]
+fn update_vesting_schedules_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DOT,
+			schedule(1, 0, 10, 2, 50)
+		));
+
+		assert_noop!(
+			MultiVestingModule::update_vesting_schedules(Origin::signed(ALICE), BOB, DOT, vec![]),
+			BadOrigin
+		);
+		assert_noop!(
+			MultiVestingModule::update_vesting_schedules(
+				Origin::signed(Admin::get()),
+				BOB,
+				DOT,
+				vec![schedule(1, 0, 10, 2, 51)]
+			),
+			Error::<Runtime>::InsufficientBalanceToLock
+		);
+		assert_noop!(
+			MultiVestingModule::update_vesting_schedules(
+				Origin::signed(Admin::get()),
+				BOB,
+				DOT,
+				vec![schedule(1, 0, 10, 2, 10); 4]
+			),
+			Error::<Runtime>::MaxVestingSchedulesExceeded
+		);
+
+		assert_ok!(MultiVestingModule::update_vesting_schedules(
+			Origin::signed(Admin::get()),
+			BOB,
+			DOT,
+			vec![schedule(1, 0, 20, 2, 20)]
+		));
+		System::assert_last_event(Event::MultiVestingModule(crate::Event::VestingSchedulesUpdated {
+			who: BOB,
+			currency_id: DOT,
+		}));
+		assert_eq!(locked(BOB, DOT), 40);
+
+		assert_ok!(MultiVestingModule::update_vesting_schedules(
+			Origin::signed(Admin::get()),
+			BOB,
+			DOT,
+			vec![]
+		));
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, DOT));
+		assert!(Tokens::locks(BOB, DOT).is_empty());
+	});
+}
+
+fn locked_at_blocks(who: AccountId, currency_id: CurrencyId, blocks: RangeInclusive<BlockNumber>) -> Vec<Balance> {
+	let now = System::block_number();
+	let locked = blocks
+		.map(|block_number| {
+			System::set_block_number(block_number);
+			MultiVestingModule::locked_balance(&who, currency_id)
+		})
+		.collect();
+	System::set_block_number(now);
+	locked
+}
+
+#[test]
+fn merge_schedules_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DOT,
+			schedule(1, 0, 10, 2, 50)
+		));
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DOT,
+			schedule(1, 10, 5, 4, 25)
+		));
+
+		assert_noop!(
+			MultiVestingModule::merge_schedules(Origin::signed(BOB), DOT, 0, 0),
+			Error::<Runtime>::InvalidScheduleIndex
+		);
+		assert_noop!(
+			MultiVestingModule::merge_schedules(Origin::signed(BOB), DOT, 0, 2),
+			Error::<Runtime>::InvalidScheduleIndex
+		);
+
+		System::set_block_number(6);
+		let before = locked_at_blocks(BOB, DOT, 6..=22);
+		assert_eq!(before, [vec![200; 5], vec![100; 5], vec![75; 5], vec![0; 2]].concat());
+
+		// locked 100 + 100 until 21, the end of both
+		assert_ok!(MultiVestingModule::merge_schedules(Origin::signed(BOB), DOT, 1, 0));
+		let merged = schedule(6, 0, 15, 1, 200);
+		System::assert_last_event(Event::MultiVestingModule(crate::Event::VestingSchedulesMerged {
+			who: BOB,
+			currency_id: DOT,
+			vesting_schedule: merged,
+		}));
+		assert_eq!(
+			MultiVestingModule::vesting_schedules(BOB, DOT).into_inner(),
+			vec![merged]
+		);
+		assert_eq!(locked(BOB, DOT), 200);
+
+		let after = locked_at_blocks(BOB, DOT, 6..=22);
+		assert_eq!(after, [vec![200; 15], vec![0; 2]].concat());
+		assert!(after.iter().zip(before.iter()).all(|(a, b)| a >= b));
+	});
+}
+
+#[test]
+fn merge_schedules_of_same_shape_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DOT,
+			schedule(1, 5, 3, 3, 10)
+		));
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DOT,
+			schedule(1, 5, 3, 3, 7)
+		));
+
+		System::set_block_number(2);
+		let before = locked_at_blocks(BOB, DOT, 2..=11);
+		assert_eq!(before, [vec![51; 4], vec![34; 1], vec![17; 3], vec![0; 2]].concat());
+
+		assert_ok!(MultiVestingModule::merge_schedules(Origin::signed(BOB), DOT, 0, 1));
+		assert_eq!(
+			MultiVestingModule::vesting_schedules(BOB, DOT).into_inner(),
+			vec![schedule(1, 5, 3, 3, 17)]
+		);
+		assert_eq!(locked_at_blocks(BOB, DOT, 2..=11), before);
+	});
+}
+
+#[test]
+fn merge_schedules_with_finished_schedule_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DOT,
+			schedule(1, 0, 1, 5, 10)
+		));
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DOT,
+			schedule(1, 0, 10, 2, 50)
+		));
+
+		// the first one ends at 6
+		System::set_block_number(8);
+		let before = locked_at_blocks(BOB, DOT, 8..=22);
+		assert_ok!(MultiVestingModule::merge_schedules(Origin::signed(BOB), DOT, 0, 1));
+		assert_eq!(
+			MultiVestingModule::vesting_schedules(BOB, DOT).into_inner(),
+			vec![schedule(1, 0, 10, 2, 50)]
+		);
+		assert_eq!(locked_at_blocks(BOB, DOT, 8..=22), before);
+		assert_eq!(locked(BOB, DOT), 100);
+
+		assert_ok!(MultiVestingModule::vested_transfer(
+			Origin::signed(ALICE),
+			BOB,
+			DOT,
+			schedule(1, 0, 1, 5, 10)
+		));
+
+		// nothing is locked by both
+		System::set_block_number(21);
+		assert_ok!(MultiVestingModule::merge_schedules(Origin::signed(BOB), DOT, 0, 1));
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, DOT));
+		assert_eq!(locked(BOB, DOT), 0);
+	});
+}
@@ -0,0 +1,106 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for module_multi_vesting
+//!
+//! These are placeholder weights, they were not generated by the benchmark CLI. Replace them
+//! with the results of the `module_multi_vesting` benchmarks.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for module_multi_vesting.
+pub trait WeightInfo {
+	fn claim(i: u32, ) -> Weight;
+	fn vested_transfer() -> Weight;
+	fn update_vesting_schedules(i: u32, ) -> Weight;
+	fn merge_schedules(i: u32, ) -> Weight;
+}
+
+/// Weights for module_multi_vesting using the Acala node and recommended hardware.
+pub struct AcalaWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
+	// Storage: MultiVesting VestingSchedules (r:1 w:1)
+	// Storage: Tokens Locks (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn claim(i: u32, ) -> Weight {
+		(31_204_000 as Weight)
+			.saturating_add((157_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: MultiVesting VestingSchedules (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: Tokens Locks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn vested_transfer() -> Weight {
+		(72_815_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: MultiVesting VestingSchedules (r:1 w:1)
+	// Storage: Tokens Locks (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn update_vesting_schedules(i: u32, ) -> Weight {
+		(33_519_000 as Weight)
+			.saturating_add((268_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: MultiVesting VestingSchedules (r:1 w:1)
+	// Storage: Tokens Locks (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	fn merge_schedules(i: u32, ) -> Weight {
+		(34_087_000 as Weight)
+			.saturating_add((173_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn claim(i: u32, ) -> Weight {
+		(31_204_000 as Weight)
+			.saturating_add((157_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn vested_transfer() -> Weight {
+		(72_815_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn update_vesting_schedules(i: u32, ) -> Weight {
+		(33_519_000 as Weight)
+			.saturating_add((268_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn merge_schedules(i: u32, ) -> Weight {
+		(34_087_000 as Weight)
+			.saturating_add((173_000 as Weight).saturating_mul(i as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
@@ -0,0 +1,723 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # NFT Loans Module
+//!
+//! ## Overview
+//!
+//! NFT loans module allows users to borrow stable currency against NFTs of the whitelisted
+//! classes. Every NFT of a class is valued at the floor price of the class, which is set by
+//! governance or the oracle operators. The positions are kept in the same way as the loans
+//! module: the collateral of a position is the number of deposited NFTs and the debit is kept in
+//! debit units. The debit value is the debit units times the debit exchange rate of the class,
+//! which compounds by the interest rate per second of the class. The accrued stability fee is
+//! issued to the surplus pool of the CDP treasury, the same as the CDP engine does.
+//!
+//! Anyone could liquidate an unsafe position, the debit value is transferred to the CDP treasury
+//! as bad debt and every NFT of the position is sold in an NFT auction, with the floor price as
+//! the reserve price. The NFT of an auction without bids is sent to `TreasuryAccount`, the
+//! `UpdateOrigin` could put it into a new NFT auction to cover the remaining bad debt.
+//!
+//! Only the classes in `CollateralClassFilter` could be whitelisted, their NFTs must stay
+//! transferable while deposited, e.g. not soulbound nor expirable.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	traits::{
+		tokens::nonfungibles::{Inspect, Transfer},
+		Contains, UnixTime,
+	},
+	transactional, PalletId,
+};
+use frame_system::pallet_prelude::*;
+use primitives::{Amount, Balance, CurrencyId, Position};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AccountIdConversion, Bounded, One, UniqueSaturatedInto, Zero},
+	ArithmeticError, FixedPointNumber, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+use support::{CDPTreasury, ExchangeRate, NFTAuctionManager, Rate, Ratio};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// Risk management params of an NFT class.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct CollateralClassParams {
+	/// Liquidation ratio, the position could be liquidated once its collateral ratio is below it.
+	pub liquidation_ratio: Ratio,
+	/// Required collateral ratio, the collateral ratio must be above it after borrowing or
+	/// withdrawing NFTs.
+	pub required_collateral_ratio: Ratio,
+	/// Liquidation penalty rate, charged on the debit when the position is liquidated.
+	pub liquidation_penalty: Rate,
+	/// Interest rate per second, the stability fee accrued on the debit of the class.
+	pub interest_rate_per_sec: Rate,
+	/// Hard cap of the total debit value of the class.
+	#[codec(compact)]
+	pub maximum_total_debit_value: Balance,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The class ID type of NFT.
+		type ClassId: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// The token ID type of NFT.
+		type TokenId: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// NFT used as collaterals.
+		type NFT: Transfer<Self::AccountId, CollectionId = Self::ClassId, ItemId = Self::TokenId>;
+
+		/// The classes could be whitelisted as collateral, their NFTs must always be
+		/// transferable.
+		type CollateralClassFilter: Contains<Self::ClassId>;
+
+		/// CDP treasury for issuing/burning stable currency and receiving bad debts.
+		type CDPTreasury: CDPTreasury<Self::AccountId, Balance = Balance, CurrencyId = CurrencyId>;
+
+		/// Auction manager to sell the NFTs of liquidated positions.
+		type AuctionManager: NFTAuctionManager<
+			Self::AccountId,
+			ClassId = Self::ClassId,
+			TokenId = Self::TokenId,
+			Balance = Balance,
+		>;
+
+		/// The origin which may update the collateral params of classes.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which may update the floor prices of classes.
+		type FloorPriceOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum number of NFTs deposited in a position.
+		#[pallet::constant]
+		type MaxCollateralsPerPosition: Get<u32>;
+
+		/// The debit exchange rate of a class before any interest accrues.
+		#[pallet::constant]
+		type DefaultDebitExchangeRate: Get<ExchangeRate>;
+
+		/// The account receives the NFTs of NFT auctions without bids, must be the same as the
+		/// `TreasuryAccount` of the auction manager.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Time used for accumulating the interest.
+		type UnixTime: UnixTime;
+
+		/// The NFT loans module id, keep all NFT collaterals.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The class is not whitelisted as collateral.
+		ClassNotWhitelisted,
+		/// The floor price of the class is not set.
+		NoFloorPrice,
+		/// The required collateral ratio is below the liquidation ratio.
+		InvalidCollateralParams,
+		/// The floor price is zero.
+		InvalidFloorPrice,
+		/// The caller does not own the NFT.
+		NoPermission,
+		/// The NFT is not deposited in the position of the caller.
+		CollateralNotFound,
+		/// The position has reached the maximum number of NFTs.
+		TooManyCollaterals,
+		/// The collateral ratio is below the required collateral ratio.
+		BelowRequiredCollateralRatio,
+		/// The collateral ratio is below the liquidation ratio.
+		BelowLiquidationRatio,
+		/// The total debit value of the class exceeds the hard cap.
+		ExceedDebitValueHardCap,
+		/// The position must be unsafe to be liquidated.
+		MustBeUnsafe,
+		/// The class still has outstanding debit.
+		ClassHasDebit,
+		/// Failed to convert between `Balance` and `Amount`.
+		AmountConvertFailed,
+		/// The NFTs of the class may become non-transferable.
+		ClassNotCollateralizable,
+		/// The NFT is not held by the treasury account.
+		NFTNotInTreasury,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The collateral params of a class are updated.
+		CollateralParamsUpdated {
+			class_id: T::ClassId,
+			params: Option<CollateralClassParams>,
+		},
+		/// The floor price of a class is updated.
+		FloorPriceUpdated {
+			class_id: T::ClassId,
+			floor_price: Option<Balance>,
+		},
+		/// An NFT is deposited as collateral.
+		NFTDeposited {
+			owner: T::AccountId,
+			class_id: T::ClassId,
+			token_id: T::TokenId,
+		},
+		/// An NFT is withdrawn from collaterals.
+		NFTWithdrawn {
+			owner: T::AccountId,
+			class_id: T::ClassId,
+			token_id: T::TokenId,
+		},
+		/// Position updated.
+		PositionUpdated {
+			owner: T::AccountId,
+			class_id: T::ClassId,
+			collateral_adjustment: Amount,
+			debit_adjustment: Amount,
+		},
+		/// Liquidated an unsafe position, its NFTs are put into auctions.
+		LiquidateUnsafePosition {
+			owner: T::AccountId,
+			class_id: T::ClassId,
+			collateral_count: u32,
+			bad_debt_value: Balance,
+			target_amount: Balance,
+		},
+		/// An NFT held by the treasury account is put into a new NFT auction.
+		NFTReauctioned {
+			class_id: T::ClassId,
+			token_id: T::TokenId,
+			target: Balance,
+			reserve_price: Balance,
+		},
+	}
+
+	/// The collateral params of the whitelisted classes.
+	///
+	/// CollateralParams: map ClassId => Option<CollateralClassParams>
+	#[pallet::storage]
+	#[pallet::getter(fn collateral_params)]
+	pub type CollateralParams<T: Config> = StorageMap<_, Twox64Concat, T::ClassId, CollateralClassParams, OptionQuery>;
+
+	/// The stable currency value of one NFT of the class.
+	///
+	/// FloorPrices: map ClassId => Option<Balance>
+	#[pallet::storage]
+	#[pallet::getter(fn floor_prices)]
+	pub type FloorPrices<T: Config> = StorageMap<_, Twox64Concat, T::ClassId, Balance, OptionQuery>;
+
+	/// The debit exchange rate of the classes, converts debit units to the debit value.
+	///
+	/// DebitExchangeRate: map ClassId => Option<ExchangeRate>
+	#[pallet::storage]
+	#[pallet::getter(fn debit_exchange_rate)]
+	pub type DebitExchangeRate<T: Config> = StorageMap<_, Twox64Concat, T::ClassId, ExchangeRate, OptionQuery>;
+
+	/// The unix time in seconds of the last interest accumulation.
+	///
+	/// LastAccumulationSecs: u64
+	#[pallet::storage]
+	#[pallet::getter(fn last_accumulation_secs)]
+	pub type LastAccumulationSecs<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The NFT collateralized debit positions, the collateral is the number of NFTs and the debit
+	/// is in debit units.
+	///
+	/// Positions: double_map ClassId, AccountId => Position
+	#[pallet::storage]
+	#[pallet::getter(fn positions)]
+	pub type Positions<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::ClassId, Twox64Concat, T::AccountId, Position, ValueQuery>;
+
+	/// The total positions of the class.
+	///
+	/// TotalPositions: map ClassId => Position
+	#[pallet::storage]
+	#[pallet::getter(fn total_positions)]
+	pub type TotalPositions<T: Config> = StorageMap<_, Twox64Concat, T::ClassId, Position, ValueQuery>;
+
+	/// The NFTs deposited in positions.
+	///
+	/// Collaterals: double_map (ClassId, AccountId), TokenId => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn collaterals)]
+	pub type Collaterals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, (T::ClassId, T::AccountId), Twox64Concat, T::TokenId, (), OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		/// Issue the interest of the whitelisted classes with debit to the CDP treasury, and
+		/// update their debit exchange rates.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			// `T::UnixTime::now()` is only available after the block #1
+			let now_as_secs: u64 = if now > One::one() {
+				T::UnixTime::now().as_secs()
+			} else {
+				Default::default()
+			};
+			T::WeightInfo::on_initialize(Self::accumulate_interest(now_as_secs, Self::last_accumulation_secs()))
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Update the collateral params of a class, `None` removes the class from the whitelist.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		#[pallet::weight(T::WeightInfo::set_collateral_params())]
+		#[transactional]
+		pub fn set_collateral_params(
+			origin: OriginFor<T>,
+			class_id: T::ClassId,
+			params: Option<CollateralClassParams>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			match params {
+				Some(ref params) => {
+					ensure!(
+						T::CollateralClassFilter::contains(&class_id),
+						Error::<T>::ClassNotCollateralizable
+					);
+					ensure!(
+						params.required_collateral_ratio >= params.liquidation_ratio,
+						Error::<T>::InvalidCollateralParams
+					);
+					CollateralParams::<T>::insert(class_id, params);
+				}
+				None => {
+					ensure!(
+						Self::total_positions(class_id).debit.is_zero(),
+						Error::<T>::ClassHasDebit
+					);
+					CollateralParams::<T>::remove(class_id);
+				}
+			}
+
+			Self::deposit_event(Event::CollateralParamsUpdated { class_id, params });
+			Ok(())
+		}
+
+		/// Update the floor price of a class.
+		///
+		/// The dispatch origin of this call must be `FloorPriceOrigin`.
+		#[pallet::weight(T::WeightInfo::set_floor_price())]
+		#[transactional]
+		pub fn set_floor_price(
+			origin: OriginFor<T>,
+			class_id: T::ClassId,
+			floor_price: Option<Balance>,
+		) -> DispatchResult {
+			T::FloorPriceOrigin::ensure_origin(origin)?;
+			ensure!(
+				floor_price.map_or(true, |price| !price.is_zero()),
+				Error::<T>::InvalidFloorPrice
+			);
+
+			FloorPrices::<T>::set(class_id, floor_price);
+			Self::deposit_event(Event::FloorPriceUpdated { class_id, floor_price });
+			Ok(())
+		}
+
+		/// Deposit an NFT of a whitelisted class as collateral.
+		#[pallet::weight(T::WeightInfo::deposit_nft())]
+		#[transactional]
+		pub fn deposit_nft(origin: OriginFor<T>, token: (T::ClassId, T::TokenId)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (class_id, token_id) = token;
+			ensure!(
+				CollateralParams::<T>::contains_key(class_id),
+				Error::<T>::ClassNotWhitelisted
+			);
+			ensure!(
+				T::CollateralClassFilter::contains(&class_id),
+				Error::<T>::ClassNotCollateralizable
+			);
+			ensure!(
+				T::NFT::owner(&class_id, &token_id) == Some(who.clone()),
+				Error::<T>::NoPermission
+			);
+			ensure!(
+				Self::positions(class_id, &who).collateral < Balance::from(T::MaxCollateralsPerPosition::get()),
+				Error::<T>::TooManyCollaterals
+			);
+
+			T::NFT::transfer(&class_id, &token_id, &Self::account_id())?;
+			Collaterals::<T>::insert((class_id, who.clone()), token_id, ());
+			Self::update_loan(&who, class_id, 1, 0)?;
+
+			Self::deposit_event(Event::NFTDeposited {
+				owner: who,
+				class_id,
+				token_id,
+			});
+			Ok(())
+		}
+
+		/// Withdraw a deposited NFT, the position must still be above the required collateral
+		/// ratio if it has debit.
+		#[pallet::weight(T::WeightInfo::withdraw_nft())]
+		#[transactional]
+		pub fn withdraw_nft(origin: OriginFor<T>, token: (T::ClassId, T::TokenId)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (class_id, token_id) = token;
+			Collaterals::<T>::take((class_id, who.clone()), token_id).ok_or(Error::<T>::CollateralNotFound)?;
+			Self::update_loan(&who, class_id, -1, 0)?;
+
+			let Position { collateral, debit } = Self::positions(class_id, &who);
+			if !debit.is_zero() {
+				Self::check_position_valid(class_id, collateral, debit, true)?;
+			}
+
+			T::NFT::transfer(&class_id, &token_id, &who)?;
+
+			Self::deposit_event(Event::NFTWithdrawn {
+				owner: who,
+				class_id,
+				token_id,
+			});
+			Ok(())
+		}
+
+		/// Borrow stable currency with a positive `debit_adjustment`, or repay it with a
+		/// negative one. The adjustment is in debit units.
+		#[pallet::weight(T::WeightInfo::adjust_debit())]
+		#[transactional]
+		pub fn adjust_debit(origin: OriginFor<T>, class_id: T::ClassId, debit_adjustment: Amount) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::update_loan(&who, class_id, 0, debit_adjustment)?;
+
+			let debit_value = Self::get_debit_value(class_id, Self::balance_try_from_amount_abs(debit_adjustment)?);
+			if debit_adjustment.is_positive() {
+				let params = Self::collateral_params(class_id).ok_or(Error::<T>::ClassNotWhitelisted)?;
+				ensure!(
+					Self::get_debit_value(class_id, Self::total_positions(class_id).debit)
+						<= params.maximum_total_debit_value,
+					Error::<T>::ExceedDebitValueHardCap
+				);
+
+				T::CDPTreasury::issue_debit(&who, debit_value, true)?;
+
+				let Position { collateral, debit } = Self::positions(class_id, &who);
+				Self::check_position_valid(class_id, collateral, debit, true)?;
+			} else if debit_adjustment.is_negative() {
+				T::CDPTreasury::burn_debit(&who, debit_value)?;
+			}
+
+			Ok(())
+		}
+
+		/// Liquidate an unsafe position. The debit value is transferred to the CDP treasury as
+		/// bad debt and every NFT of the position is sold in an NFT auction.
+		///
+		/// The dispatch origin of this call must be signed by anyone.
+		#[pallet::weight(T::WeightInfo::liquidate(T::MaxCollateralsPerPosition::get()))]
+		#[transactional]
+		pub fn liquidate(origin: OriginFor<T>, class_id: T::ClassId, who: T::AccountId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let Position { collateral, debit } = Self::positions(class_id, &who);
+			let params = Self::collateral_params(class_id).ok_or(Error::<T>::ClassNotWhitelisted)?;
+			ensure!(
+				!debit.is_zero()
+					&& Self::calculate_collateral_ratio(class_id, collateral, debit)? < params.liquidation_ratio,
+				Error::<T>::MustBeUnsafe
+			);
+
+			let tokens: Vec<T::TokenId> = Collaterals::<T>::drain_prefix((class_id, who.clone()))
+				.map(|(token_id, _)| token_id)
+				.collect();
+
+			let debit_value = Self::get_debit_value(class_id, debit);
+			T::CDPTreasury::on_system_debit(debit_value)?;
+			Self::update_loan(
+				&who,
+				class_id,
+				Self::amount_try_from_balance(collateral)?.saturating_neg(),
+				Self::amount_try_from_balance(debit)?.saturating_neg(),
+			)?;
+
+			// the NFTs are never sold below the floor price
+			let reserve_price = Self::floor_prices(class_id).ok_or(Error::<T>::NoFloorPrice)?;
+
+			// split the target evenly, the last auction takes the remainder
+			let target_amount = debit_value.saturating_add(params.liquidation_penalty.saturating_mul_int(debit_value));
+			let count = tokens.len() as u32;
+			let target_per_token = target_amount / Balance::from(count.max(1));
+			let mut remaining_target = target_amount;
+			for (index, token_id) in tokens.into_iter().enumerate() {
+				let target = if index as u32 + 1 == count {
+					remaining_target
+				} else {
+					target_per_token
+				};
+				remaining_target = remaining_target.saturating_sub(target);
+				T::AuctionManager::new_nft_auction(&who, (class_id, token_id), target, reserve_price)?;
+			}
+
+			Self::deposit_event(Event::LiquidateUnsafePosition {
+				owner: who,
+				class_id,
+				collateral_count: count,
+				bad_debt_value: debit_value,
+				target_amount,
+			});
+			Ok(())
+		}
+
+		/// Put an NFT held by the treasury account, after an NFT auction without bids, into a new
+		/// NFT auction to cover the remaining bad debt.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `token`: the NFT to sell.
+		/// - `target`: the target of the auction, the surplus over it is sent to the treasury
+		///   account.
+		/// - `reserve_price`: the minimum bid of the auction.
+		#[pallet::weight(T::WeightInfo::reauction_nft())]
+		#[transactional]
+		pub fn reauction_nft(
+			origin: OriginFor<T>,
+			token: (T::ClassId, T::TokenId),
+			#[pallet::compact] target: Balance,
+			#[pallet::compact] reserve_price: Balance,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			let (class_id, token_id) = token;
+			let treasury_account = T::TreasuryAccount::get();
+			ensure!(
+				T::NFT::owner(&class_id, &token_id) == Some(treasury_account.clone()),
+				Error::<T>::NFTNotInTreasury
+			);
+
+			T::AuctionManager::new_nft_auction(&treasury_account, token, target, reserve_price)?;
+
+			Self::deposit_event(Event::NFTReauctioned {
+				class_id,
+				token_id,
+				target,
+				reserve_price,
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	fn accumulate_interest(now_secs: u64, last_accumulation_secs: u64) -> u32 {
+		let mut count: u32 = 0;
+
+		if !now_secs.is_zero() {
+			let interval_secs = now_secs.saturating_sub(last_accumulation_secs);
+
+			for (class_id, params) in CollateralParams::<T>::iter() {
+				let rate_to_accumulate = Self::compound_interest_rate(params.interest_rate_per_sec, interval_secs);
+				let total_debits = Self::total_positions(class_id).debit;
+
+				if !rate_to_accumulate.is_zero() && !total_debits.is_zero() {
+					let debit_exchange_rate = Self::get_debit_exchange_rate(class_id);
+					let debit_exchange_rate_increment = debit_exchange_rate.saturating_mul(rate_to_accumulate);
+					let issued_stable_coin_balance = debit_exchange_rate_increment.saturating_mul_int(total_debits);
+
+					// issue stablecoin to surplus pool
+					match T::CDPTreasury::on_system_surplus(issued_stable_coin_balance) {
+						Ok(_) => {
+							// update exchange rate when issue success
+							DebitExchangeRate::<T>::insert(
+								class_id,
+								debit_exchange_rate.saturating_add(debit_exchange_rate_increment),
+							);
+						}
+						Err(e) => {
+							log::warn!(
+								target: "nft-loans",
+								"on_system_surplus: failed to on system surplus {:?}: {:?}. \
+								This is unexpected but should be safe",
+								issued_stable_coin_balance, e
+							);
+						}
+					}
+				}
+				count += 1;
+			}
+		}
+
+		// update last accumulation timestamp
+		LastAccumulationSecs::<T>::put(now_secs);
+		count
+	}
+
+	pub fn compound_interest_rate(rate_per_sec: Rate, secs: u64) -> Rate {
+		rate_per_sec
+			.saturating_add(Rate::one())
+			.saturating_pow(secs.unique_saturated_into())
+			.saturating_sub(Rate::one())
+	}
+
+	pub fn get_debit_exchange_rate(class_id: T::ClassId) -> ExchangeRate {
+		Self::debit_exchange_rate(class_id).unwrap_or_else(T::DefaultDebitExchangeRate::get)
+	}
+
+	/// The debit value of `debit` units of the class.
+	pub fn get_debit_value(class_id: T::ClassId, debit: Balance) -> Balance {
+		Self::get_debit_exchange_rate(class_id).saturating_mul_int(debit)
+	}
+
+	/// The collateral ratio of the position, the collateral is valued at the floor price and
+	/// `debit` is in debit units.
+	pub fn calculate_collateral_ratio(
+		class_id: T::ClassId,
+		collateral: Balance,
+		debit: Balance,
+	) -> Result<Ratio, Error<T>> {
+		let floor_price = Self::floor_prices(class_id).ok_or(Error::<T>::NoFloorPrice)?;
+		let collateral_value = floor_price.saturating_mul(collateral);
+		let debit_value = Self::get_debit_value(class_id, debit);
+		Ok(Ratio::checked_from_rational(collateral_value, debit_value).unwrap_or_else(Ratio::max_value))
+	}
+
+	/// Check the collateral ratio of the position is above the liquidation ratio, and above the
+	/// required collateral ratio if `check_required_ratio` is true.
+	pub fn check_position_valid(
+		class_id: T::ClassId,
+		collateral: Balance,
+		debit: Balance,
+		check_required_ratio: bool,
+	) -> DispatchResult {
+		let params = Self::collateral_params(class_id).ok_or(Error::<T>::ClassNotWhitelisted)?;
+		let collateral_ratio = Self::calculate_collateral_ratio(class_id, collateral, debit)?;
+
+		ensure!(
+			collateral_ratio >= params.liquidation_ratio,
+			Error::<T>::BelowLiquidationRatio
+		);
+		if check_required_ratio {
+			ensure!(
+				collateral_ratio >= params.required_collateral_ratio,
+				Error::<T>::BelowRequiredCollateralRatio
+			);
+		}
+		Ok(())
+	}
+
+	/// mutate records of collaterals and debits
+	fn update_loan(
+		who: &T::AccountId,
+		class_id: T::ClassId,
+		collateral_adjustment: Amount,
+		debit_adjustment: Amount,
+	) -> DispatchResult {
+		let collateral_balance = Self::balance_try_from_amount_abs(collateral_adjustment)?;
+		let debit_balance = Self::balance_try_from_amount_abs(debit_adjustment)?;
+
+		Positions::<T>::try_mutate_exists(class_id, who, |may_be_position| -> DispatchResult {
+			let mut p = may_be_position.take().unwrap_or_default();
+			let new_collateral = Self::apply_adjustment(p.collateral, collateral_adjustment, collateral_balance)?;
+			let new_debit = Self::apply_adjustment(p.debit, debit_adjustment, debit_balance)?;
+
+			// increase account ref if new position
+			if p.collateral.is_zero() && p.debit.is_zero() {
+				if frame_system::Pallet::<T>::inc_consumers(who).is_err() {
+					log::warn!(
+						"Warning: Attempt to introduce lock consumer reference, yet no providers. \
+						This is unexpected but should be safe."
+					);
+				}
+			}
+
+			p.collateral = new_collateral;
+			p.debit = new_debit;
+
+			if p.collateral.is_zero() && p.debit.is_zero() {
+				// decrease account ref if zero position
+				frame_system::Pallet::<T>::dec_consumers(who);
+
+				// remove position storage if zero position
+				*may_be_position = None;
+			} else {
+				*may_be_position = Some(p);
+			}
+
+			Ok(())
+		})?;
+
+		TotalPositions::<T>::try_mutate(class_id, |total_positions| -> DispatchResult {
+			total_positions.collateral =
+				Self::apply_adjustment(total_positions.collateral, collateral_adjustment, collateral_balance)?;
+			total_positions.debit = Self::apply_adjustment(total_positions.debit, debit_adjustment, debit_balance)?;
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::PositionUpdated {
+			owner: who.clone(),
+			class_id,
+			collateral_adjustment,
+			debit_adjustment,
+		});
+		Ok(())
+	}
+
+	fn apply_adjustment(
+		value: Balance,
+		adjustment: Amount,
+		adjustment_abs: Balance,
+	) -> Result<Balance, ArithmeticError> {
+		if adjustment.is_positive() {
+			value.checked_add(adjustment_abs).ok_or(ArithmeticError::Overflow)
+		} else {
+			value.checked_sub(adjustment_abs).ok_or(ArithmeticError::Underflow)
+		}
+	}
+
+	/// Convert `Balance` to `Amount`.
+	pub fn amount_try_from_balance(b: Balance) -> Result<Amount, Error<T>> {
+		TryInto::<Amount>::try_into(b).map_err(|_| Error::<T>::AmountConvertFailed)
+	}
+
+	/// Convert the absolute value of `Amount` to `Balance`.
+	pub fn balance_try_from_amount_abs(a: Amount) -> Result<Balance, Error<T>> {
+		TryInto::<Balance>::try_into(a.saturating_abs()).map_err(|_| Error::<T>::AmountConvertFailed)
+	}
+}
@@ -0,0 +1,289 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Mocks for the NFT loans module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{
+	construct_runtime, ord_parameter_types, parameter_types,
+	traits::{ConstU32, ConstU64, Everything, Nothing},
+};
+use frame_system::EnsureSignedBy;
+use orml_traits::parameter_type_with_key;
+use primitives::TokenSymbol;
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup, DispatchError};
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
+use support::{mocks::MockStableAsset, AuctionManager, SpecificJointsSwap};
+
+pub type AccountId = u128;
+pub type AuctionId = u32;
+pub type BlockNumber = u64;
+pub type ClassId = u32;
+pub type TokenId = u64;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CLASS_ID: ClassId = 0;
+pub const OTHER_CLASS_ID: ClassId = 1;
+pub const SOULBOUND_CLASS_ID: ClassId = 2;
+pub const AUSD: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+
+mod nft_loans {
+	pub use super::super::*;
+}
+
+impl frame_system::Config for Runtime {
+	type Origin = Origin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Call = Call;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = ConstU64<250>;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Event = Event;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type OnDust = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type DustRemovalWhitelist = Nothing;
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
+pub struct MockCollateralAuctionManager;
+impl AuctionManager<AccountId> for MockCollateralAuctionManager {
+	type CurrencyId = CurrencyId;
+	type Balance = Balance;
+	type AuctionId = AuctionId;
+
+	fn new_collateral_auction(
+		_refund_recipient: &AccountId,
+		_currency_id: Self::CurrencyId,
+		_amount: Self::Balance,
+		_target: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn cancel_auction(_id: Self::AuctionId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn get_total_target_in_auction() -> Self::Balance {
+		Default::default()
+	}
+
+	fn get_total_collateral_in_auction(_id: Self::CurrencyId) -> Self::Balance {
+		Default::default()
+	}
+}
+
+ord_parameter_types! {
+	pub const One: AccountId = 1;
+	pub const Oracle: AccountId = 9;
+}
+
+parameter_types! {
+	pub const GetStableCurrencyId: CurrencyId = AUSD;
+	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
+	pub TreasuryAccount: AccountId = PalletId(*b"aca/hztr").into_account_truncating();
+	pub AlternativeSwapPathJointList: Vec<Vec<CurrencyId>> = vec![];
+}
+
+impl cdp_treasury::Config for Runtime {
+	type Event = Event;
+	type Currency = Tokens;
+	type GetStableCurrencyId = GetStableCurrencyId;
+	type AuctionManagerHandler = MockCollateralAuctionManager;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type DEX = ();
+	type Swap = SpecificJointsSwap<(), AlternativeSwapPathJointList>;
+	type MaxAuctionsCount = ConstU32<10_000>;
+	type PalletId = CDPTreasuryPalletId;
+	type TreasuryAccount = TreasuryAccount;
+	type WeightInfo = ();
+	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
+}
+
+thread_local! {
+	static NFT_OWNERS: RefCell<BTreeMap<(ClassId, TokenId), AccountId>> = RefCell::new(BTreeMap::new());
+	static NFT_AUCTIONS: RefCell<Vec<(AccountId, (ClassId, TokenId), Balance, Balance)>> = RefCell::new(vec![]);
+}
+
+pub struct MockNFT;
+impl MockNFT {
+	pub fn mint(token: (ClassId, TokenId), owner: AccountId) {
+		NFT_OWNERS.with(|v| v.borrow_mut().insert(token, owner));
+	}
+}
+impl Inspect<AccountId> for MockNFT {
+	type ItemId = TokenId;
+	type CollectionId = ClassId;
+
+	fn owner(class: &ClassId, instance: &TokenId) -> Option<AccountId> {
+		NFT_OWNERS.with(|v| v.borrow().get(&(*class, *instance)).cloned())
+	}
+}
+impl Transfer<AccountId> for MockNFT {
+	fn transfer(class: &ClassId, instance: &TokenId, destination: &AccountId) -> DispatchResult {
+		NFT_OWNERS.with(|v| {
+			let mut owners = v.borrow_mut();
+			let owner = owners
+				.get_mut(&(*class, *instance))
+				.ok_or(DispatchError::Other("token not found"))?;
+			*owner = *destination;
+			Ok(())
+		})
+	}
+}
+
+pub struct MockNFTAuctionManager;
+impl MockNFTAuctionManager {
+	pub fn auctions() -> Vec<(AccountId, (ClassId, TokenId), Balance, Balance)> {
+		NFT_AUCTIONS.with(|v| v.borrow().clone())
+	}
+}
+impl NFTAuctionManager<AccountId> for MockNFTAuctionManager {
+	type ClassId = ClassId;
+	type TokenId = TokenId;
+	type Balance = Balance;
+
+	fn new_nft_auction(
+		refund_recipient: &AccountId,
+		token: (Self::ClassId, Self::TokenId),
+		target: Self::Balance,
+		reserve_price: Self::Balance,
+	) -> DispatchResult {
+		NFT_AUCTIONS.with(|v| v.borrow_mut().push((*refund_recipient, token, target, reserve_price)));
+		Ok(())
+	}
+
+	fn get_total_target_in_nft_auction() -> Self::Balance {
+		NFT_AUCTIONS.with(|v| v.borrow().iter().map(|(_, _, target, _)| target).sum())
+	}
+}
+
+pub struct MockCollateralClassFilter;
+impl Contains<ClassId> for MockCollateralClassFilter {
+	fn contains(class_id: &ClassId) -> bool {
+		*class_id != SOULBOUND_CLASS_ID
+	}
+}
+
+impl pallet_timestamp::Config for Runtime {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1_000>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const NFTLoansPalletId: PalletId = PalletId(*b"aca/nftl");
+	pub DefaultDebitExchangeRate: ExchangeRate = ExchangeRate::one();
+}
+
+impl Config for Runtime {
+	type Event = Event;
+	type ClassId = ClassId;
+	type TokenId = TokenId;
+	type NFT = MockNFT;
+	type CollateralClassFilter = MockCollateralClassFilter;
+	type CDPTreasury = CDPTreasuryModule;
+	type AuctionManager = MockNFTAuctionManager;
+	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type FloorPriceOrigin = EnsureSignedBy<Oracle, AccountId>;
+	type MaxCollateralsPerPosition = ConstU32<3>;
+	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
+	type TreasuryAccount = TreasuryAccount;
+	type UnixTime = Timestamp;
+	type PalletId = NFTLoansPalletId;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+		NFTLoansModule: nft_loans::{Pallet, Storage, Call, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+		CDPTreasuryModule: cdp_treasury::{Pallet, Storage, Call, Event<T>},
+		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+	}
+);
+
+#[derive(Default)]
+pub struct ExtBuilder;
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		let t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			for token_id in 0..4 {
+				MockNFT::mint((CLASS_ID, token_id), ALICE);
+			}
+			MockNFT::mint((OTHER_CLASS_ID, 0), ALICE);
+			MockNFT::mint((CLASS_ID, 4), BOB);
+		});
+		ext
+	}
+}
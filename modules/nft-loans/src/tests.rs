@@ -0,0 +1,350 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the NFT loans module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok, traits::OnInitialize};
+use mock::{Event, *};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::BadOrigin;
+
+fn class_params() -> CollateralClassParams {
+	CollateralClassParams {
+		liquidation_ratio: Ratio::saturating_from_rational(3, 2),
+		required_collateral_ratio: Ratio::saturating_from_rational(2, 1),
+		liquidation_penalty: Rate::saturating_from_rational(1, 10),
+		interest_rate_per_sec: Rate::zero(),
+		maximum_total_debit_value: 10_000,
+	}
+}
+
+fn setup_class() {
+	assert_ok!(NFTLoansModule::set_collateral_params(
+		Origin::signed(1),
+		CLASS_ID,
+		Some(class_params())
+	));
+	assert_ok!(NFTLoansModule::set_floor_price(
+		Origin::signed(9),
+		CLASS_ID,
+		Some(1_000)
+	));
+}
+
+#[test]
+fn set_collateral_params_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			NFTLoansModule::set_collateral_params(Origin::signed(BOB), CLASS_ID, Some(class_params())),
+			BadOrigin
+		);
+		assert_noop!(
+			NFTLoansModule::set_collateral_params(
+				Origin::signed(1),
+				CLASS_ID,
+				Some(CollateralClassParams {
+					required_collateral_ratio: Ratio::saturating_from_rational(1, 1),
+					..class_params()
+				})
+			),
+			Error::<Runtime>::InvalidCollateralParams
+		);
+		assert_noop!(
+			NFTLoansModule::set_collateral_params(Origin::signed(1), SOULBOUND_CLASS_ID, Some(class_params())),
+			Error::<Runtime>::ClassNotCollateralizable
+		);
+
+		assert_ok!(NFTLoansModule::set_collateral_params(
+			Origin::signed(1),
+			CLASS_ID,
+			Some(class_params())
+		));
+		System::assert_last_event(Event::NFTLoansModule(crate::Event::CollateralParamsUpdated {
+			class_id: CLASS_ID,
+			params: Some(class_params()),
+		}));
+		assert_eq!(NFTLoansModule::collateral_params(CLASS_ID), Some(class_params()));
+
+		TotalPositions::<Runtime>::mutate(CLASS_ID, |p| p.debit = 100);
+		assert_noop!(
+			NFTLoansModule::set_collateral_params(Origin::signed(1), CLASS_ID, None),
+			Error::<Runtime>::ClassHasDebit
+		);
+		TotalPositions::<Runtime>::remove(CLASS_ID);
+		assert_ok!(NFTLoansModule::set_collateral_params(Origin::signed(1), CLASS_ID, None));
+		assert_eq!(NFTLoansModule::collateral_params(CLASS_ID), None);
+	});
+}
+
+#[test]
+fn set_floor_price_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			NFTLoansModule::set_floor_price(Origin::signed(1), CLASS_ID, Some(1_000)),
+			BadOrigin
+		);
+		assert_noop!(
+			NFTLoansModule::set_floor_price(Origin::signed(9), CLASS_ID, Some(0)),
+			Error::<Runtime>::InvalidFloorPrice
+		);
+
+		assert_ok!(NFTLoansModule::set_floor_price(
+			Origin::signed(9),
+			CLASS_ID,
+			Some(1_000)
+		));
+		System::assert_last_event(Event::NFTLoansModule(crate::Event::FloorPriceUpdated {
+			class_id: CLASS_ID,
+			floor_price: Some(1_000),
+		}));
+		assert_eq!(NFTLoansModule::floor_prices(CLASS_ID), Some(1_000));
+
+		assert_ok!(NFTLoansModule::set_floor_price(Origin::signed(9), CLASS_ID, None));
+		assert_eq!(NFTLoansModule::floor_prices(CLASS_ID), None);
+	});
+}
+
+#[test]
+fn deposit_and_withdraw_nft_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_class();
+		assert_noop!(
+			NFTLoansModule::deposit_nft(Origin::signed(ALICE), (OTHER_CLASS_ID, 0)),
+			Error::<Runtime>::ClassNotWhitelisted
+		);
+		assert_noop!(
+			NFTLoansModule::deposit_nft(Origin::signed(ALICE), (CLASS_ID, 4)),
+			Error::<Runtime>::NoPermission
+		);
+
+		for token_id in 0..3 {
+			assert_ok!(NFTLoansModule::deposit_nft(Origin::signed(ALICE), (CLASS_ID, token_id)));
+		}
+		System::assert_last_event(Event::NFTLoansModule(crate::Event::NFTDeposited {
+			owner: ALICE,
+			class_id: CLASS_ID,
+			token_id: 2,
+		}));
+		assert_eq!(MockNFT::owner(&CLASS_ID, &0), Some(NFTLoansModule::account_id()));
+		assert_eq!(NFTLoansModule::collaterals((CLASS_ID, ALICE), 0), Some(()));
+		assert_eq!(NFTLoansModule::positions(CLASS_ID, ALICE).collateral, 3);
+		assert_eq!(NFTLoansModule::total_positions(CLASS_ID).collateral, 3);
+		assert_noop!(
+			NFTLoansModule::deposit_nft(Origin::signed(ALICE), (CLASS_ID, 3)),
+			Error::<Runtime>::TooManyCollaterals
+		);
+
+		assert_noop!(
+			NFTLoansModule::withdraw_nft(Origin::signed(BOB), (CLASS_ID, 0)),
+			Error::<Runtime>::CollateralNotFound
+		);
+		assert_ok!(NFTLoansModule::withdraw_nft(Origin::signed(ALICE), (CLASS_ID, 0)));
+		System::assert_last_event(Event::NFTLoansModule(crate::Event::NFTWithdrawn {
+			owner: ALICE,
+			class_id: CLASS_ID,
+			token_id: 0,
+		}));
+		assert_eq!(MockNFT::owner(&CLASS_ID, &0), Some(ALICE));
+		assert_eq!(NFTLoansModule::collaterals((CLASS_ID, ALICE), 0), None);
+		assert_eq!(NFTLoansModule::positions(CLASS_ID, ALICE).collateral, 2);
+	});
+}
+
+#[test]
+fn adjust_debit_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_class();
+		assert_ok!(NFTLoansModule::deposit_nft(Origin::signed(ALICE), (CLASS_ID, 0)));
+		assert_ok!(NFTLoansModule::deposit_nft(Origin::signed(ALICE), (CLASS_ID, 1)));
+
+		// collateral value is 2_000, the required collateral ratio is 200%
+		assert_noop!(
+			NFTLoansModule::adjust_debit(Origin::signed(ALICE), CLASS_ID, 1_001),
+			Error::<Runtime>::BelowRequiredCollateralRatio
+		);
+		assert_ok!(NFTLoansModule::adjust_debit(Origin::signed(ALICE), CLASS_ID, 1_000));
+		System::assert_last_event(Event::NFTLoansModule(crate::Event::PositionUpdated {
+			owner: ALICE,
+			class_id: CLASS_ID,
+			collateral_adjustment: 0,
+			debit_adjustment: 1_000,
+		}));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 1_000);
+		assert_eq!(NFTLoansModule::positions(CLASS_ID, ALICE).debit, 1_000);
+		assert_eq!(NFTLoansModule::total_positions(CLASS_ID).debit, 1_000);
+
+		assert_noop!(
+			NFTLoansModule::withdraw_nft(Origin::signed(ALICE), (CLASS_ID, 0)),
+			Error::<Runtime>::BelowLiquidationRatio
+		);
+
+		assert_ok!(NFTLoansModule::adjust_debit(Origin::signed(ALICE), CLASS_ID, -600));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 400);
+		assert_eq!(NFTLoansModule::positions(CLASS_ID, ALICE).debit, 400);
+		assert_ok!(NFTLoansModule::withdraw_nft(Origin::signed(ALICE), (CLASS_ID, 0)));
+
+		assert_noop!(
+			NFTLoansModule::adjust_debit(Origin::signed(ALICE), CLASS_ID, -401),
+			ArithmeticError::Underflow
+		);
+	});
+}
+
+#[test]
+fn adjust_debit_exceed_hard_cap() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_class();
+		assert_ok!(NFTLoansModule::set_collateral_params(
+			Origin::signed(1),
+			CLASS_ID,
+			Some(CollateralClassParams {
+				maximum_total_debit_value: 300,
+				..class_params()
+			})
+		));
+		assert_ok!(NFTLoansModule::deposit_nft(Origin::signed(ALICE), (CLASS_ID, 0)));
+		assert_ok!(NFTLoansModule::adjust_debit(Origin::signed(ALICE), CLASS_ID, 300));
+		assert_noop!(
+			NFTLoansModule::adjust_debit(Origin::signed(ALICE), CLASS_ID, 1),
+			Error::<Runtime>::ExceedDebitValueHardCap
+		);
+	});
+}
+
+#[test]
+fn liquidate_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_class();
+		for token_id in 0..3 {
+			assert_ok!(NFTLoansModule::deposit_nft(Origin::signed(ALICE), (CLASS_ID, token_id)));
+		}
+		assert_ok!(NFTLoansModule::adjust_debit(Origin::signed(ALICE), CLASS_ID, 1_000));
+		assert_noop!(
+			NFTLoansModule::liquidate(Origin::signed(BOB), CLASS_ID, ALICE),
+			Error::<Runtime>::MustBeUnsafe
+		);
+
+		// collateral value drops to 1_200, below the liquidation ratio of 150%
+		assert_ok!(NFTLoansModule::set_floor_price(Origin::signed(9), CLASS_ID, Some(400)));
+		assert_ok!(NFTLoansModule::liquidate(Origin::signed(BOB), CLASS_ID, ALICE));
+		System::assert_last_event(Event::NFTLoansModule(crate::Event::LiquidateUnsafePosition {
+			owner: ALICE,
+			class_id: CLASS_ID,
+			collateral_count: 3,
+			bad_debt_value: 1_000,
+			target_amount: 1_100,
+		}));
+
+		assert_eq!(CDPTreasuryModule::debit_pool(), 1_000);
+		assert_eq!(NFTLoansModule::positions(CLASS_ID, ALICE), Position::default());
+		assert_eq!(NFTLoansModule::total_positions(CLASS_ID), Position::default());
+		assert_eq!(Collaterals::<Runtime>::iter_prefix((CLASS_ID, ALICE)).count(), 0);
+		let auctions = MockNFTAuctionManager::auctions();
+		assert_eq!(auctions.len(), 3);
+		assert_eq!(auctions.iter().map(|(_, _, target, _)| target).sum::<Balance>(), 1_100);
+		assert!(auctions.iter().all(|(recipient, _, _, _)| *recipient == ALICE));
+		// the floor price is the reserve price
+		assert!(auctions.iter().all(|(_, _, _, reserve_price)| *reserve_price == 400));
+		assert_eq!(MockNFTAuctionManager::get_total_target_in_nft_auction(), 1_100);
+	});
+}
+
+#[test]
+fn accumulate_interest_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(NFTLoansModule::set_collateral_params(
+			Origin::signed(1),
+			CLASS_ID,
+			Some(CollateralClassParams {
+				interest_rate_per_sec: Rate::saturating_from_rational(1, 100),
+				..class_params()
+			})
+		));
+		assert_ok!(NFTLoansModule::set_floor_price(
+			Origin::signed(9),
+			CLASS_ID,
+			Some(1_000)
+		));
+		assert_ok!(NFTLoansModule::deposit_nft(Origin::signed(ALICE), (CLASS_ID, 0)));
+
+		// no interest accrues without debit
+		Timestamp::set_timestamp(10_000);
+		NFTLoansModule::on_initialize(2);
+		assert_eq!(NFTLoansModule::last_accumulation_secs(), 10);
+		assert_eq!(NFTLoansModule::debit_exchange_rate(CLASS_ID), None);
+
+		assert_ok!(NFTLoansModule::adjust_debit(Origin::signed(ALICE), CLASS_ID, 500));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 500);
+
+		Timestamp::set_timestamp(11_000);
+		NFTLoansModule::on_initialize(3);
+		assert_eq!(NFTLoansModule::last_accumulation_secs(), 11);
+		assert_eq!(
+			NFTLoansModule::debit_exchange_rate(CLASS_ID),
+			Some(ExchangeRate::saturating_from_rational(101, 100))
+		);
+		assert_eq!(CDPTreasuryModule::surplus_pool(), 5);
+		assert_eq!(NFTLoansModule::positions(CLASS_ID, ALICE).debit, 500);
+		assert_eq!(NFTLoansModule::get_debit_value(CLASS_ID, 500), 505);
+
+		// the debit is valued at the new exchange rate
+		assert_eq!(
+			NFTLoansModule::calculate_collateral_ratio(CLASS_ID, 1, 500).ok(),
+			Some(Ratio::saturating_from_rational(1_000, 505))
+		);
+		assert_ok!(NFTLoansModule::adjust_debit(Origin::signed(ALICE), CLASS_ID, -100));
+		assert_eq!(Tokens::free_balance(AUSD, &ALICE), 399);
+		assert_eq!(NFTLoansModule::positions(CLASS_ID, ALICE).debit, 400);
+	});
+}
+
+#[test]
+fn reauction_nft_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			NFTLoansModule::reauction_nft(Origin::signed(BOB), (CLASS_ID, 0), 1_000, 400),
+			BadOrigin
+		);
+		assert_noop!(
+			NFTLoansModule::reauction_nft(Origin::signed(1), (CLASS_ID, 0), 1_000, 400),
+			Error::<Runtime>::NFTNotInTreasury
+		);
+
+		// the NFT of an auction without bids is sent to the treasury account
+		assert_ok!(MockNFT::transfer(&CLASS_ID, &0, &TreasuryAccount::get()));
+		assert_ok!(NFTLoansModule::reauction_nft(
+			Origin::signed(1),
+			(CLASS_ID, 0),
+			1_000,
+			400
+		));
+		System::assert_last_event(Event::NFTLoansModule(crate::Event::NFTReauctioned {
+			class_id: CLASS_ID,
+			token_id: 0,
+			target: 1_000,
+			reserve_price: 400,
+		}));
+		assert_eq!(
+			MockNFTAuctionManager::auctions(),
+			vec![(TreasuryAccount::get(), (CLASS_ID, 0), 1_000, 400)]
+		);
+	});
+}
@@ -29,7 +29,7 @@ use sp_runtime::traits::{AccountIdConversion, StaticLookup, UniqueSaturatedInto}
 use sp_std::collections::btree_map::BTreeMap;
 
 pub use crate::*;
-use primitives::Balance;
+use primitives::{Balance, CurrencyId, TokenSymbol};
 
 pub struct Module<T: Config>(crate::Pallet<T>);
 
@@ -74,6 +74,21 @@ fn create_token_class<T: Config>(caller: T::AccountId) -> Result<T::AccountId, D
 	Ok(module_account)
 }
 
+fn mint_token<T: Config>(caller: T::AccountId, to: T::AccountId) -> Result<T::AccountId, DispatchErrorWithPostInfo> {
+	let module_account = create_token_class::<T>(caller)?;
+	crate::Pallet::<T>::mint(
+		RawOrigin::Signed(module_account.clone()).into(),
+		T::Lookup::unlookup(to),
+		0u32.into(),
+		vec![1],
+		test_attr(),
+		1,
+	)?;
+	Ok(module_account)
+}
+
+const STABLE_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
+
 benchmarks! {
 	// create NFT class
 	create_class {
@@ -148,6 +163,113 @@ benchmarks! {
 
 		let module_account = create_token_class::<T>(caller)?;
 	}: _(RawOrigin::Signed(module_account), 0u32.into(), Properties(ClassProperty::Transferable.into()))
+
+	approve {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let operator: T::AccountId = account("operator", 0, SEED);
+		mint_token::<T>(caller, to.clone())?;
+	}: _(RawOrigin::Signed(to), Some(T::Lookup::unlookup(operator)), (0u32.into(), 0u32.into()))
+
+	set_approval_for_all {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let operator: T::AccountId = account("operator", 0, SEED);
+		create_token_class::<T>(caller.clone())?;
+	}: _(RawOrigin::Signed(caller), 0u32.into(), T::Lookup::unlookup(operator), true)
+
+	transfer_from {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let operator: T::AccountId = account("operator", 0, SEED);
+		mint_token::<T>(caller.clone(), to.clone())?;
+		crate::Pallet::<T>::set_approval_for_all(RawOrigin::Signed(to.clone()).into(), 0u32.into(), T::Lookup::unlookup(operator.clone()), true)?;
+	}: _(RawOrigin::Signed(operator), T::Lookup::unlookup(to), T::Lookup::unlookup(caller), (0u32.into(), 0u32.into()))
+
+	set_royalty {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let module_account = create_token_class::<T>(caller.clone())?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), Some(RoyaltyInfo { beneficiary: caller, rate: T::MaxRoyalty::get() }))
+
+	list_fixed_price {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		mint_token::<T>(caller, to.clone())?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()), STABLE_CURRENCY_ID, dollar(1))
+
+	list_auction {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		mint_token::<T>(caller, to.clone())?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()), STABLE_CURRENCY_ID, dollar(1), 100u32.into())
+
+	cancel_listing {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		mint_token::<T>(caller, to.clone())?;
+		crate::Pallet::<T>::list_fixed_price(RawOrigin::Signed(to.clone()).into(), (0u32.into(), 0u32.into()), STABLE_CURRENCY_ID, dollar(1))?;
+	}: _(RawOrigin::Signed(to), (0u32.into(), 0u32.into()))
+
+	buy {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let module_account = mint_token::<T>(caller.clone(), to.clone())?;
+		crate::Pallet::<T>::set_royalty(RawOrigin::Signed(module_account).into(), 0u32.into(), Some(RoyaltyInfo { beneficiary: caller, rate: T::MaxRoyalty::get() }))?;
+		crate::Pallet::<T>::list_fixed_price(RawOrigin::Signed(to).into(), (0u32.into(), 0u32.into()), STABLE_CURRENCY_ID, dollar(1))?;
+		T::MultiCurrency::deposit(STABLE_CURRENCY_ID, &buyer, dollar(1))?;
+	}: _(RawOrigin::Signed(buyer), (0u32.into(), 0u32.into()), dollar(1))
+
+	bid {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		let bidder2: T::AccountId = account("bidder", 1, SEED);
+		mint_token::<T>(caller, to.clone())?;
+		crate::Pallet::<T>::list_auction(RawOrigin::Signed(to).into(), (0u32.into(), 0u32.into()), STABLE_CURRENCY_ID, dollar(1), 100u32.into())?;
+		T::MultiCurrency::deposit(STABLE_CURRENCY_ID, &bidder, dollar(1))?;
+		T::MultiCurrency::deposit(STABLE_CURRENCY_ID, &bidder2, dollar(2))?;
+		crate::Pallet::<T>::bid(RawOrigin::Signed(bidder).into(), (0u32.into(), 0u32.into()), dollar(1))?;
+	}: _(RawOrigin::Signed(bidder2), (0u32.into(), 0u32.into()), dollar(2))
+
+	settle_auction {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let bidder: T::AccountId = account("bidder", 0, SEED);
+		let module_account = mint_token::<T>(caller.clone(), to.clone())?;
+		crate::Pallet::<T>::set_royalty(RawOrigin::Signed(module_account).into(), 0u32.into(), Some(RoyaltyInfo { beneficiary: caller.clone(), rate: T::MaxRoyalty::get() }))?;
+		crate::Pallet::<T>::list_auction(RawOrigin::Signed(to).into(), (0u32.into(), 0u32.into()), STABLE_CURRENCY_ID, dollar(1), 100u32.into())?;
+		T::MultiCurrency::deposit(STABLE_CURRENCY_ID, &bidder, dollar(1))?;
+		crate::Pallet::<T>::bid(RawOrigin::Signed(bidder).into(), (0u32.into(), 0u32.into()), dollar(1))?;
+		frame_system::Pallet::<T>::set_block_number(1000u32.into());
+	}: _(RawOrigin::Signed(caller), (0u32.into(), 0u32.into()))
+
+	set_class_config {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let module_account = create_token_class::<T>(caller)?;
+	}: _(RawOrigin::Signed(module_account), 0u32.into(), ClassConfig { max_supply: Some(100u32.into()), token_lifetime: Some(100u32.into()) })
+
+	revoke {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let module_account = mint_token::<T>(caller, to.clone())?;
+		crate::Pallet::<T>::list_fixed_price(RawOrigin::Signed(to).into(), (0u32.into(), 0u32.into()), STABLE_CURRENCY_ID, dollar(1))?;
+		crate::Pallet::<T>::update_class_properties(RawOrigin::Signed(module_account.clone()).into(), 0u32.into(), Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Soulbound))?;
+	}: _(RawOrigin::Signed(module_account), (0u32.into(), 0u32.into()))
+
+	burn_expired {
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let to: T::AccountId = account("to", 0, SEED);
+		let module_account = create_token_class::<T>(caller)?;
+		crate::Pallet::<T>::set_class_config(RawOrigin::Signed(module_account.clone()).into(), 0u32.into(), ClassConfig { max_supply: None, token_lifetime: Some(10u32.into()) })?;
+		crate::Pallet::<T>::update_class_properties(RawOrigin::Signed(module_account.clone()).into(), 0u32.into(), Properties(ClassProperty::Transferable | ClassProperty::Mintable | ClassProperty::Expirable))?;
+		crate::Pallet::<T>::mint(RawOrigin::Signed(module_account).into(), T::Lookup::unlookup(to.clone()), 0u32.into(), vec![1], test_attr(), 1)?;
+		crate::Pallet::<T>::list_fixed_price(RawOrigin::Signed(to).into(), (0u32.into(), 0u32.into()), STABLE_CURRENCY_ID, dollar(1))?;
+		let expiry = crate::Pallet::<T>::token_expiries(ClassIdOf::<T>::from(0u32), TokenIdOf::<T>::from(0u32)).unwrap();
+		frame_system::Pallet::<T>::set_block_number(expiry);
+	}: {
+		let result = NftTask::<T>::BurnExpired { expiry }.dispatch(Weight::MAX);
+		assert_eq!(result.result, Ok(()));
+	}
 }
 
 #[cfg(test)]
@@ -161,6 +283,7 @@ mod mock {
 		traits::{ConstU128, ConstU32, ConstU64, Contains, InstanceFilter},
 		PalletId, RuntimeDebug,
 	};
+	use orml_traits::parameter_type_with_key;
 	use sp_core::{crypto::AccountId32, H256};
 	use sp_runtime::{
 		testing::Header,
@@ -263,6 +386,29 @@ mod mock {
 
 	parameter_types! {
 		pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
+		pub const MaxRoyalty: Permill = Permill::from_percent(10);
+	}
+
+	parameter_type_with_key! {
+		pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+			Default::default()
+		};
+	}
+
+	impl orml_tokens::Config for Runtime {
+		type Event = ();
+		type Balance = Balance;
+		type Amount = primitives::Amount;
+		type CurrencyId = CurrencyId;
+		type WeightInfo = ();
+		type ExistentialDeposits = ExistentialDeposits;
+		type OnDust = ();
+		type MaxLocks = ();
+		type MaxReserves = ();
+		type ReserveIdentifier = [u8; 8];
+		type DustRemovalWhitelist = frame_support::traits::Nothing;
+		type OnNewTokenAccount = ();
+		type OnKilledTokenAccount = ();
 	}
 
 	impl crate::Config for Runtime {
@@ -273,9 +419,20 @@ mod mock {
 		type DataDepositPerByte = ConstU128<10>;
 		type PalletId = NftPalletId;
 		type MaxAttributesBytes = ConstU32<2048>;
+		type MultiCurrency = Tokens;
+		type MaxRoyalty = MaxRoyalty;
+		type Task = ();
+		type IdleScheduler = MockIdleScheduler;
 		type WeightInfo = ();
 	}
 
+	pub struct MockIdleScheduler;
+	impl IdleScheduler<()> for MockIdleScheduler {
+		fn schedule(_task: ()) -> DispatchResult {
+			Ok(())
+		}
+	}
+
 	impl orml_nft::Config for Runtime {
 		type ClassId = u32;
 		type TokenId = u64;
@@ -300,6 +457,7 @@ mod mock {
 			Proxy: pallet_proxy::{Pallet, Call, Storage, Event<T>},
 			OrmlNFT: orml_nft::{Pallet, Storage, Config<T>},
 			NFT: nft::{Pallet, Call, Event<T>},
+			Tokens: orml_tokens::{Pallet, Storage, Config<T>},
 		}
 	);
 
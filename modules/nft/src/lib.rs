@@ -21,34 +21,39 @@
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
+use codec::FullCodec;
 use frame_support::{
+	log,
 	pallet_prelude::*,
 	require_transactional,
 	traits::{
 		tokens::nonfungibles::{Inspect, Mutate, Transfer},
-		Currency,
+		Contains, Currency,
 		ExistenceRequirement::{AllowDeath, KeepAlive},
 		NamedReservableCurrency,
 	},
 	transactional, PalletId,
 };
 use frame_system::pallet_prelude::*;
-use orml_traits::InspectExtended;
+use orml_traits::{InspectExtended, MultiCurrency, NamedMultiReservableCurrency};
 use primitives::{
 	nft::{Attributes, ClassProperty, NFTBalance, Properties, CID},
-	ReserveIdentifier,
+	task::{TaskPriority, TaskResult},
+	Balance, CurrencyId, ReserveIdentifier,
 };
 use scale_info::TypeInfo;
+use support::{DispatchableTask, IdleScheduler};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 use sp_runtime::{
 	traits::{AccountIdConversion, Hash, Saturating, StaticLookup, Zero},
-	DispatchResult, RuntimeDebug,
+	DispatchResult, Permill, RuntimeDebug,
 };
-use sp_std::prelude::*;
+use sp_std::{fmt::Debug, marker::PhantomData, prelude::*};
 
 pub mod benchmarking;
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -76,6 +81,48 @@ pub struct TokenData<Balance> {
 	pub attributes: Attributes,
 }
 
+/// The royalty of a class, paid to `beneficiary` on every marketplace sale.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct RoyaltyInfo<AccountId> {
+	/// The account receives the royalty
+	pub beneficiary: AccountId,
+	/// The royalty rate of the sale price
+	pub rate: Permill,
+}
+
+/// The sale type of a listing.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub enum ListingKind<AccountId, BlockNumber> {
+	/// Sell at a fixed price.
+	FixedPrice { price: Balance },
+	/// English auction, the highest bid when the auction ends wins.
+	Auction {
+		min_bid: Balance,
+		end: BlockNumber,
+		best_bid: Option<(AccountId, Balance)>,
+	},
+}
+
+/// A token listed on the marketplace.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, MaxEncodedLen, TypeInfo)]
+pub struct Listing<AccountId, BlockNumber> {
+	/// The owner of the token
+	pub seller: AccountId,
+	/// The currency to pay
+	pub currency_id: CurrencyId,
+	/// The sale type
+	pub kind: ListingKind<AccountId, BlockNumber>,
+}
+
+/// The configurable limits of a class.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, Default, MaxEncodedLen, TypeInfo)]
+pub struct ClassConfig<TokenId, BlockNumber> {
+	/// The max number of tokens ever minted in a `SupplyCapped` class, burned tokens included
+	pub max_supply: Option<TokenId>,
+	/// The number of blocks a token of an `Expirable` class lives after minted
+	pub token_lifetime: Option<BlockNumber>,
+}
+
 pub type TokenIdOf<T> = <T as orml_nft::Config>::TokenId;
 pub type ClassIdOf<T> = <T as orml_nft::Config>::ClassId;
 pub type BalanceOf<T> =
@@ -86,6 +133,7 @@ pub mod module {
 	use super::*;
 
 	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::Nft;
+	pub const BID_RESERVE_ID: ReserveIdentifier = ReserveIdentifier::NftBid;
 
 	#[pallet::config]
 	pub trait Config:
@@ -122,6 +170,24 @@ pub mod module {
 		#[pallet::constant]
 		type MaxAttributesBytes: Get<u32>;
 
+		/// Currency to pay for listed tokens.
+		type MultiCurrency: NamedMultiReservableCurrency<
+			Self::AccountId,
+			ReserveIdentifier = ReserveIdentifier,
+			CurrencyId = CurrencyId,
+			Balance = Balance,
+		>;
+
+		/// The maximum royalty rate of a class.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Permill>;
+
+		/// Dispatchable tasks
+		type Task: DispatchableTask + FullCodec + Debug + Clone + PartialEq + TypeInfo + From<NftTask<Self>>;
+
+		/// Idle scheduler to burn the expired tokens.
+		type IdleScheduler: IdleScheduler<Self::Task>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -151,6 +217,46 @@ pub mod module {
 		AttributesTooLarge,
 		/// The given token ID is not correct
 		IncorrectTokenId,
+		/// The royalty rate exceeds `MaxRoyalty`
+		RoyaltyTooHigh,
+		/// The token is listed on the marketplace
+		TokenListed,
+		/// The token is not listed on the marketplace
+		NotListed,
+		/// The listing is not a fixed price listing
+		NotFixedPrice,
+		/// The listing is not an auction
+		NotAuction,
+		/// The price is zero
+		ZeroPrice,
+		/// The auction duration is zero
+		ZeroDuration,
+		/// The price is higher than the max price of the buyer
+		PriceTooHigh,
+		/// The bid is lower than the minimum bid or the best bid
+		BidTooLow,
+		/// The auction has ended
+		AuctionEnded,
+		/// The auction has not ended yet
+		AuctionNotEnded,
+		/// The auction already has bids
+		AuctionHasBids,
+		/// The seller can't buy or bid for the own token
+		InvalidBuyer,
+		/// The reserved bid is less than expected
+		InsufficientReservedBalance,
+		/// The token of a soulbound class can't be transferred
+		Soulbound,
+		/// The class is not soulbound
+		NotSoulbound,
+		/// The minted tokens exceed the max supply of the class
+		SupplyCapExceeded,
+		/// The class config required by the class properties is not set
+		ClassConfigNotSet,
+		/// The token has expired
+		TokenExpired,
+		/// The token lifetime is zero
+		ZeroTokenLifetime,
 	}
 
 	#[pallet::event]
@@ -193,14 +299,177 @@ pub mod module {
 			owner: T::AccountId,
 			class_id: ClassIdOf<T>,
 		},
+		/// Approved an account to transfer the token, or cleared the approval.
+		Approval {
+			owner: T::AccountId,
+			approved: Option<T::AccountId>,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// Enabled or disabled an operator to transfer all tokens of the owner in the class.
+		ApprovalForAll {
+			owner: T::AccountId,
+			operator: T::AccountId,
+			class_id: ClassIdOf<T>,
+			approved: bool,
+		},
+		/// Updated the royalty of the class.
+		RoyaltyUpdated {
+			class_id: ClassIdOf<T>,
+			royalty: Option<RoyaltyInfo<T::AccountId>>,
+		},
+		/// Listed a token on the marketplace.
+		Listed {
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			listing: Listing<T::AccountId, T::BlockNumber>,
+		},
+		/// Cancelled a listing.
+		ListingCancelled {
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// Placed a bid in an auction.
+		BidPlaced {
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			bidder: T::AccountId,
+			amount: Balance,
+		},
+		/// Sold a listed token.
+		Sold {
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			currency_id: CurrencyId,
+			price: Balance,
+			royalty: Balance,
+		},
+		/// Updated the config of the class.
+		ClassConfigUpdated {
+			class_id: ClassIdOf<T>,
+			config: ClassConfig<TokenIdOf<T>, T::BlockNumber>,
+		},
+		/// Revoked a soulbound token by the class owner.
+		RevokedToken {
+			owner: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
+		/// Burned an expired token.
+		ExpiredToken {
+			owner: T::AccountId,
+			class_id: ClassIdOf<T>,
+			token_id: TokenIdOf<T>,
+		},
 	}
 
+	/// The approved account of the token.
+	///
+	/// TokenApprovals: double_map ClassId, TokenId => Option<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn token_approvals)]
+	pub type TokenApprovals<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Twox64Concat, TokenIdOf<T>, T::AccountId, OptionQuery>;
+
+	/// The operators could transfer all tokens of the owner in the class.
+	///
+	/// OperatorApprovals: double_map (AccountId, ClassId), AccountId => bool
+	#[pallet::storage]
+	#[pallet::getter(fn operator_approvals)]
+	pub type OperatorApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(T::AccountId, ClassIdOf<T>),
+		Blake2_128Concat,
+		T::AccountId,
+		bool,
+		ValueQuery,
+	>;
+
+	/// The royalty of the class.
+	///
+	/// ClassRoyalties: map ClassId => Option<RoyaltyInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn class_royalties)]
+	pub type ClassRoyalties<T: Config> =
+		StorageMap<_, Twox64Concat, ClassIdOf<T>, RoyaltyInfo<T::AccountId>, OptionQuery>;
+
+	/// The tokens listed on the marketplace.
+	///
+	/// Listings: double_map ClassId, TokenId => Option<Listing>
+	#[pallet::storage]
+	#[pallet::getter(fn listings)]
+	pub type Listings<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ClassIdOf<T>,
+		Twox64Concat,
+		TokenIdOf<T>,
+		Listing<T::AccountId, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// The max supply and the token lifetime of the class.
+	///
+	/// ClassConfigs: map ClassId => ClassConfig
+	#[pallet::storage]
+	#[pallet::getter(fn class_configs)]
+	pub type ClassConfigs<T: Config> =
+		StorageMap<_, Twox64Concat, ClassIdOf<T>, ClassConfig<TokenIdOf<T>, T::BlockNumber>, ValueQuery>;
+
+	/// The block number at which the token of an `Expirable` class expires.
+	///
+	/// TokenExpiries: double_map ClassId, TokenId => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn token_expiries)]
+	pub type TokenExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ClassIdOf<T>, Twox64Concat, TokenIdOf<T>, T::BlockNumber, OptionQuery>;
+
+	/// The tokens expiring at the block, burned by the idle scheduler once the block is reached.
+	///
+	/// ExpiringTokens: double_map BlockNumber, (ClassId, TokenId) => ()
+	#[pallet::storage]
+	pub type ExpiringTokens<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, (ClassIdOf<T>, TokenIdOf<T>), (), OptionQuery>;
+
+	/// The number of tokens of the account in the class.
+	///
+	/// ClassBalances: double_map AccountId, ClassId => NFTBalance
+	#[pallet::storage]
+	#[pallet::getter(fn class_balance)]
+	pub type ClassBalances<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, ClassIdOf<T>, NFTBalance, ValueQuery>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			// ExpiringTokens (r: 1)
+			if ExpiringTokens::<T>::iter_key_prefix(now).next().is_none() {
+				return T::DbWeight::get().reads(1);
+			}
+
+			if let Err(e) = T::IdleScheduler::schedule(NftTask::BurnExpired { expiry: now }.into()) {
+				log::error!(
+					target: "nft",
+					"Failed to schedule burning the tokens expired at {:?}: {:?}",
+					now,
+					e,
+				);
+			}
+			// IdleScheduler::NextTaskId (r: 1, w: 1)
+			// IdleScheduler::Tasks (w: 1)
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -337,6 +606,7 @@ pub mod module {
 			<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, data.deposit);
 
 			orml_nft::Pallet::<T>::destroy_class(&who, class_id)?;
+			ClassConfigs::<T>::remove(class_id);
 
 			// this should unresere proxy deposit
 			pallet_proxy::Pallet::<T>::remove_proxy_delegate(&who, dest.clone(), Default::default(), Zero::zero())?;
@@ -380,6 +650,255 @@ pub mod module {
 				Ok(())
 			})
 		}
+
+		/// Approve `operator` to transfer the token, or clear the approval with `None`. The
+		/// caller must be the owner or an approved operator for all of the owner.
+		///
+		/// - `operator`: the approved account
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::approve())]
+		#[transactional]
+		pub fn approve(
+			origin: OriginFor<T>,
+			operator: Option<<T::Lookup as StaticLookup>::Source>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let operator = operator.map(T::Lookup::lookup).transpose()?;
+			Self::do_approve(&who, operator, token)
+		}
+
+		/// Enable or disable `operator` to transfer all tokens of the caller in the class.
+		///
+		/// - `class_id`: the class ID
+		/// - `operator`: the operator account
+		/// - `approved`: enable or disable
+		#[pallet::weight(<T as Config>::WeightInfo::set_approval_for_all())]
+		#[transactional]
+		pub fn set_approval_for_all(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			operator: <T::Lookup as StaticLookup>::Source,
+			approved: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let operator = T::Lookup::lookup(operator)?;
+			Self::do_set_approval_for_all(&who, class_id, &operator, approved)
+		}
+
+		/// Transfer the token of `from` by the owner, the approved account or an operator.
+		///
+		/// - `from`: the token owner's account
+		/// - `to`: the receiver
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_from())]
+		#[transactional]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			from: <T::Lookup as StaticLookup>::Source,
+			to: <T::Lookup as StaticLookup>::Source,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let from = T::Lookup::lookup(from)?;
+			let to = T::Lookup::lookup(to)?;
+			Self::do_transfer_from(&who, &from, &to, token)
+		}
+
+		/// Set the royalty of the class, or remove it with `None`. Only the class owner could
+		/// set the royalty.
+		///
+		/// - `class_id`: the class ID
+		/// - `royalty`: the beneficiary and the rate of the royalty
+		#[pallet::weight(<T as Config>::WeightInfo::set_royalty())]
+		#[transactional]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			royalty: Option<RoyaltyInfo<T::AccountId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+
+			match royalty {
+				Some(ref royalty) => {
+					ensure!(royalty.rate <= T::MaxRoyalty::get(), Error::<T>::RoyaltyTooHigh);
+					ClassRoyalties::<T>::insert(class_id, royalty);
+				}
+				None => ClassRoyalties::<T>::remove(class_id),
+			}
+
+			Self::deposit_event(Event::RoyaltyUpdated { class_id, royalty });
+			Ok(())
+		}
+
+		/// List the token of the caller at a fixed price.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `currency_id`: the currency to pay
+		/// - `price`: the price of the token
+		#[pallet::weight(<T as Config>::WeightInfo::list_fixed_price())]
+		#[transactional]
+		pub fn list_fixed_price(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			currency_id: CurrencyId,
+			#[pallet::compact] price: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+			Self::do_list(&who, token, currency_id, ListingKind::FixedPrice { price })
+		}
+
+		/// List the token of the caller in an auction lasting `duration` blocks.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `currency_id`: the currency to pay
+		/// - `min_bid`: the minimum bid of the auction
+		/// - `duration`: the number of blocks the auction lasts
+		#[pallet::weight(<T as Config>::WeightInfo::list_auction())]
+		#[transactional]
+		pub fn list_auction(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			currency_id: CurrencyId,
+			#[pallet::compact] min_bid: Balance,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!min_bid.is_zero(), Error::<T>::ZeroPrice);
+			ensure!(!duration.is_zero(), Error::<T>::ZeroDuration);
+			let end = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			Self::do_list(
+				&who,
+				token,
+				currency_id,
+				ListingKind::Auction {
+					min_bid,
+					end,
+					best_bid: None,
+				},
+			)
+		}
+
+		/// Cancel the listing of the caller. An auction with bids can't be cancelled.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_listing())]
+		#[transactional]
+		pub fn cancel_listing(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_cancel_listing(&who, token)
+		}
+
+		/// Buy a token listed at a fixed price, the royalty is paid from the price.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `max_price`: the maximum price the caller accepts
+		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			#[pallet::compact] max_price: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_buy(&who, token, max_price)
+		}
+
+		/// Bid in an auction, the bid is reserved until outbid or the auction is settled.
+		///
+		/// - `token`: (class_id, token_id)
+		/// - `amount`: the bid amount
+		#[pallet::weight(<T as Config>::WeightInfo::bid())]
+		#[transactional]
+		pub fn bid(
+			origin: OriginFor<T>,
+			token: (ClassIdOf<T>, TokenIdOf<T>),
+			#[pallet::compact] amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_bid(&who, token, amount)
+		}
+
+		/// Settle an ended auction, the token is sold to the best bidder if any. Anyone could
+		/// settle.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::settle_auction())]
+		#[transactional]
+		pub fn settle_auction(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_settle_auction(token)
+		}
+
+		/// Set the max supply and the token lifetime of the class. The class properties must
+		/// contains ClassPropertiesMutable, or no token has been minted.
+		///
+		/// - `class_id`: the class ID
+		/// - `config`: the new class config
+		#[pallet::weight(<T as Config>::WeightInfo::set_class_config())]
+		#[transactional]
+		pub fn set_class_config(
+			origin: OriginFor<T>,
+			class_id: ClassIdOf<T>,
+			config: ClassConfig<TokenIdOf<T>, T::BlockNumber>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				class_info
+					.data
+					.properties
+					.0
+					.contains(ClassProperty::ClassPropertiesMutable)
+					|| class_info.total_issuance.is_zero(),
+				Error::<T>::Immutable
+			);
+			if let Some(max_supply) = config.max_supply {
+				ensure!(
+					orml_nft::Pallet::<T>::next_token_id(class_id) <= max_supply,
+					Error::<T>::SupplyCapExceeded
+				);
+			}
+			ensure!(
+				config.token_lifetime.map_or(true, |lifetime| !lifetime.is_zero()),
+				Error::<T>::ZeroTokenLifetime
+			);
+
+			ClassConfigs::<T>::insert(class_id, &config);
+
+			Self::deposit_event(Event::ClassConfigUpdated { class_id, config });
+			Ok(())
+		}
+
+		/// Revoke a soulbound token, the token is burned and the deposit is returned to the
+		/// holder. Only the class owner could revoke.
+		///
+		/// - `token`: (class_id, token_id)
+		#[pallet::weight(<T as Config>::WeightInfo::revoke())]
+		#[transactional]
+		pub fn revoke(origin: OriginFor<T>, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+			ensure!(who == class_info.owner, Error::<T>::NoPermission);
+			ensure!(
+				class_info.data.properties.0.contains(ClassProperty::Soulbound),
+				Error::<T>::NotSoulbound
+			);
+
+			let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+			Self::burn_token(&token_info.owner, token, token_info.data.deposit)?;
+
+			Self::deposit_event(Event::RevokedToken {
+				owner: token_info.owner,
+				class_id: token.0,
+				token_id: token.1,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -387,15 +906,17 @@ impl<T: Config> Pallet<T> {
 	#[require_transactional]
 	fn do_transfer(from: &T::AccountId, to: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
 		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
-		let data = class_info.data;
-		ensure!(
-			data.properties.0.contains(ClassProperty::Transferable),
-			Error::<T>::NonTransferable
-		);
+		Self::ensure_transferable(&class_info.data.properties, token)?;
 
 		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+		ensure!(!Listings::<T>::contains_key(token.0, token.1), Error::<T>::TokenListed);
 
 		orml_nft::Pallet::<T>::transfer(from, to, token)?;
+		TokenApprovals::<T>::remove(token.0, token.1);
+		if from != to {
+			ClassBalances::<T>::mutate(from, token.0, |balance| *balance = balance.saturating_sub(1));
+			ClassBalances::<T>::mutate(to, token.0, |balance| *balance = balance.saturating_add(1));
+		}
 
 		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, from, token_info.data.deposit);
 		<T as module::Config>::Currency::transfer(from, to, token_info.data.deposit, AllowDeath)?;
@@ -423,10 +944,24 @@ impl<T: Config> Pallet<T> {
 		let class_info = orml_nft::Pallet::<T>::classes(class_id).ok_or(Error::<T>::ClassIdNotFound)?;
 		ensure!(*who == class_info.owner, Error::<T>::NoPermission);
 
-		ensure!(
-			class_info.data.properties.0.contains(ClassProperty::Mintable),
-			Error::<T>::NonMintable
-		);
+		let properties = class_info.data.properties.0;
+		ensure!(properties.contains(ClassProperty::Mintable), Error::<T>::NonMintable);
+
+		let config = Self::class_configs(class_id);
+		if properties.contains(ClassProperty::SupplyCapped) {
+			let max_supply = config.max_supply.ok_or(Error::<T>::ClassConfigNotSet)?;
+			// token ids are never reused, so the next token id is the number of minted tokens
+			ensure!(
+				orml_nft::Pallet::<T>::next_token_id(class_id).saturating_add(quantity.into()) <= max_supply,
+				Error::<T>::SupplyCapExceeded
+			);
+		}
+		let expiry = if properties.contains(ClassProperty::Expirable) {
+			let token_lifetime = config.token_lifetime.ok_or(Error::<T>::ClassConfigNotSet)?;
+			Some(frame_system::Pallet::<T>::block_number().saturating_add(token_lifetime))
+		} else {
+			None
+		};
 
 		let data_deposit = Self::data_deposit(&metadata, &attributes)?;
 		let deposit = T::CreateTokenDeposit::get().saturating_add(data_deposit);
@@ -440,13 +975,16 @@ impl<T: Config> Pallet<T> {
 		let mut token_ids = Vec::with_capacity(quantity as usize);
 		let data = TokenData { deposit, attributes };
 		for _ in 0..quantity {
-			token_ids.push(orml_nft::Pallet::<T>::mint(
-				to,
-				class_id,
-				metadata.clone(),
-				data.clone(),
-			)?);
+			let token_id = orml_nft::Pallet::<T>::mint(to, class_id, metadata.clone(), data.clone())?;
+			if let Some(expiry) = expiry {
+				TokenExpiries::<T>::insert(class_id, token_id, expiry);
+				ExpiringTokens::<T>::insert(expiry, (class_id, token_id), ());
+			}
+			token_ids.push(token_id);
 		}
+		ClassBalances::<T>::mutate(to, class_id, |balance| {
+			*balance = balance.saturating_add(quantity.into())
+		});
 
 		Self::deposit_event(Event::MintedToken {
 			from: who.clone(),
@@ -467,10 +1005,9 @@ impl<T: Config> Pallet<T> {
 
 		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
 		ensure!(who == token_info.owner, Error::<T>::NoPermission);
+		ensure!(!Listings::<T>::contains_key(token.0, token.1), Error::<T>::TokenListed);
 
-		orml_nft::Pallet::<T>::burn(&who, token)?;
-
-		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, &who, token_info.data.deposit);
+		Self::burn_token(&who, token, token_info.data.deposit)?;
 
 		if let Some(remark) = remark {
 			let hash = T::Hashing::hash(&remark[..]);
@@ -491,6 +1028,336 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Burn the token of `owner`, cancel its listing and return the deposit.
+	fn burn_token(owner: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), deposit: BalanceOf<T>) -> DispatchResult {
+		if let Some(listing) = Listings::<T>::take(token.0, token.1) {
+			if let ListingKind::Auction {
+				best_bid: Some((bidder, amount)),
+				..
+			} = listing.kind
+			{
+				T::MultiCurrency::unreserve_named(&BID_RESERVE_ID, listing.currency_id, &bidder, amount);
+			}
+			Self::deposit_event(Event::ListingCancelled {
+				class_id: token.0,
+				token_id: token.1,
+			});
+		}
+
+		orml_nft::Pallet::<T>::burn(owner, token)?;
+		TokenApprovals::<T>::remove(token.0, token.1);
+		if let Some(expiry) = TokenExpiries::<T>::take(token.0, token.1) {
+			ExpiringTokens::<T>::remove(expiry, token);
+		}
+		ClassBalances::<T>::mutate(owner, token.0, |balance| *balance = balance.saturating_sub(1));
+
+		<T as module::Config>::Currency::unreserve_named(&RESERVE_ID, owner, deposit);
+		Ok(())
+	}
+
+	/// Burn the token if it has expired.
+	#[transactional]
+	fn burn_expired(token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+		Self::burn_token(&token_info.owner, token, token_info.data.deposit)?;
+
+		Self::deposit_event(Event::ExpiredToken {
+			owner: token_info.owner,
+			class_id: token.0,
+			token_id: token.1,
+		});
+		Ok(())
+	}
+
+	/// Returns true if the token has an expiry and it has passed.
+	pub fn is_expired(token: (ClassIdOf<T>, TokenIdOf<T>)) -> bool {
+		Self::token_expiries(token.0, token.1)
+			.map_or(false, |expiry| frame_system::Pallet::<T>::block_number() >= expiry)
+	}
+
+	fn ensure_transferable(properties: &Properties, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		ensure!(
+			properties.0.contains(ClassProperty::Transferable),
+			Error::<T>::NonTransferable
+		);
+		ensure!(!properties.0.contains(ClassProperty::Soulbound), Error::<T>::Soulbound);
+		ensure!(!Self::is_expired(token), Error::<T>::TokenExpired);
+		Ok(())
+	}
+
+	/// Returns true if `who` is the owner, the approved account or an operator of the owner.
+	pub fn is_approved_or_owner(who: &T::AccountId, owner: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) -> bool {
+		who == owner
+			|| Self::token_approvals(token.0, token.1).as_ref() == Some(who)
+			|| Self::operator_approvals((owner, token.0), who)
+	}
+
+	#[transactional]
+	pub fn do_approve(
+		who: &T::AccountId,
+		operator: Option<T::AccountId>,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+		let owner = token_info.owner;
+		ensure!(
+			*who == owner || Self::operator_approvals((&owner, token.0), who),
+			Error::<T>::NoPermission
+		);
+
+		match operator {
+			Some(ref operator) => TokenApprovals::<T>::insert(token.0, token.1, operator),
+			None => TokenApprovals::<T>::remove(token.0, token.1),
+		}
+
+		Self::deposit_event(Event::Approval {
+			owner,
+			approved: operator,
+			class_id: token.0,
+			token_id: token.1,
+		});
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_set_approval_for_all(
+		owner: &T::AccountId,
+		class_id: ClassIdOf<T>,
+		operator: &T::AccountId,
+		approved: bool,
+	) -> DispatchResult {
+		ensure!(
+			orml_nft::Pallet::<T>::classes(class_id).is_some(),
+			Error::<T>::ClassIdNotFound
+		);
+		ensure!(owner != operator, Error::<T>::NoPermission);
+
+		if approved {
+			OperatorApprovals::<T>::insert((owner, class_id), operator, true);
+		} else {
+			OperatorApprovals::<T>::remove((owner, class_id), operator);
+		}
+
+		Self::deposit_event(Event::ApprovalForAll {
+			owner: owner.clone(),
+			operator: operator.clone(),
+			class_id,
+			approved,
+		});
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_transfer_from(
+		who: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+	) -> DispatchResult {
+		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+		ensure!(*from == token_info.owner, Error::<T>::NoPermission);
+		ensure!(Self::is_approved_or_owner(who, from, token), Error::<T>::NoPermission);
+		Self::do_transfer(from, to, token)
+	}
+
+	/// The royalty beneficiary and the royalty amount of the sale price.
+	pub fn royalty_info(class_id: ClassIdOf<T>, sale_price: Balance) -> Option<(T::AccountId, Balance)> {
+		Self::class_royalties(class_id).map(|royalty| (royalty.beneficiary, royalty.rate.mul_floor(sale_price)))
+	}
+
+	#[transactional]
+	pub fn do_list(
+		who: &T::AccountId,
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		currency_id: CurrencyId,
+		kind: ListingKind<T::AccountId, T::BlockNumber>,
+	) -> DispatchResult {
+		let class_info = orml_nft::Pallet::<T>::classes(token.0).ok_or(Error::<T>::ClassIdNotFound)?;
+		Self::ensure_transferable(&class_info.data.properties, token)?;
+		let token_info = orml_nft::Pallet::<T>::tokens(token.0, token.1).ok_or(Error::<T>::TokenIdNotFound)?;
+		ensure!(*who == token_info.owner, Error::<T>::NoPermission);
+		ensure!(!Listings::<T>::contains_key(token.0, token.1), Error::<T>::TokenListed);
+
+		let listing = Listing {
+			seller: who.clone(),
+			currency_id,
+			kind,
+		};
+		Listings::<T>::insert(token.0, token.1, listing.clone());
+
+		Self::deposit_event(Event::Listed {
+			class_id: token.0,
+			token_id: token.1,
+			listing,
+		});
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_cancel_listing(who: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		let listing = Self::listings(token.0, token.1).ok_or(Error::<T>::NotListed)?;
+		ensure!(*who == listing.seller, Error::<T>::NoPermission);
+		if let ListingKind::Auction { best_bid, .. } = listing.kind {
+			ensure!(best_bid.is_none(), Error::<T>::AuctionHasBids);
+		}
+
+		Listings::<T>::remove(token.0, token.1);
+
+		Self::deposit_event(Event::ListingCancelled {
+			class_id: token.0,
+			token_id: token.1,
+		});
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_buy(buyer: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), max_price: Balance) -> DispatchResult {
+		let listing = Self::listings(token.0, token.1).ok_or(Error::<T>::NotListed)?;
+		let price = match listing.kind {
+			ListingKind::FixedPrice { price } => price,
+			_ => return Err(Error::<T>::NotFixedPrice.into()),
+		};
+		ensure!(*buyer != listing.seller, Error::<T>::InvalidBuyer);
+		ensure!(price <= max_price, Error::<T>::PriceTooHigh);
+
+		let royalty = match Self::royalty_info(token.0, price) {
+			Some((beneficiary, royalty)) => {
+				T::MultiCurrency::transfer(listing.currency_id, buyer, &beneficiary, royalty)?;
+				royalty
+			}
+			None => Zero::zero(),
+		};
+		T::MultiCurrency::transfer(
+			listing.currency_id,
+			buyer,
+			&listing.seller,
+			price.saturating_sub(royalty),
+		)?;
+
+		Self::do_sell(token, listing, buyer, price, royalty)
+	}
+
+	#[transactional]
+	pub fn do_bid(bidder: &T::AccountId, token: (ClassIdOf<T>, TokenIdOf<T>), amount: Balance) -> DispatchResult {
+		Listings::<T>::try_mutate(token.0, token.1, |maybe_listing| -> DispatchResult {
+			let listing = maybe_listing.as_mut().ok_or(Error::<T>::NotListed)?;
+			ensure!(*bidder != listing.seller, Error::<T>::InvalidBuyer);
+			let currency_id = listing.currency_id;
+			match listing.kind {
+				ListingKind::Auction {
+					min_bid,
+					end,
+					ref mut best_bid,
+				} => {
+					ensure!(
+						frame_system::Pallet::<T>::block_number() < end,
+						Error::<T>::AuctionEnded
+					);
+					ensure!(amount >= min_bid, Error::<T>::BidTooLow);
+					if let Some((last_bidder, last_amount)) = best_bid.take() {
+						ensure!(amount > last_amount, Error::<T>::BidTooLow);
+						T::MultiCurrency::unreserve_named(&BID_RESERVE_ID, currency_id, &last_bidder, last_amount);
+					}
+					T::MultiCurrency::reserve_named(&BID_RESERVE_ID, currency_id, bidder, amount)?;
+					*best_bid = Some((bidder.clone(), amount));
+					Ok(())
+				}
+				_ => Err(Error::<T>::NotAuction.into()),
+			}
+		})?;
+
+		Self::deposit_event(Event::BidPlaced {
+			class_id: token.0,
+			token_id: token.1,
+			bidder: bidder.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_settle_auction(token: (ClassIdOf<T>, TokenIdOf<T>)) -> DispatchResult {
+		let listing = Self::listings(token.0, token.1).ok_or(Error::<T>::NotListed)?;
+		let (end, best_bid) = match listing.kind {
+			ListingKind::Auction { end, ref best_bid, .. } => (end, best_bid.clone()),
+			_ => return Err(Error::<T>::NotAuction.into()),
+		};
+		ensure!(
+			frame_system::Pallet::<T>::block_number() >= end,
+			Error::<T>::AuctionNotEnded
+		);
+
+		match best_bid {
+			Some((bidder, price)) => {
+				let royalty = match Self::royalty_info(token.0, price) {
+					Some((beneficiary, royalty)) => {
+						Self::pay_reserved(listing.currency_id, &bidder, &beneficiary, royalty)?;
+						royalty
+					}
+					None => Zero::zero(),
+				};
+				Self::pay_reserved(
+					listing.currency_id,
+					&bidder,
+					&listing.seller,
+					price.saturating_sub(royalty),
+				)?;
+
+				Self::do_sell(token, listing, &bidder, price, royalty)
+			}
+			None => {
+				Listings::<T>::remove(token.0, token.1);
+				Self::deposit_event(Event::ListingCancelled {
+					class_id: token.0,
+					token_id: token.1,
+				});
+				Ok(())
+			}
+		}
+	}
+
+	/// Move the reserved bid of `from` to `to`.
+	fn pay_reserved(
+		currency_id: CurrencyId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		let remaining = T::MultiCurrency::repatriate_reserved_named(
+			&BID_RESERVE_ID,
+			currency_id,
+			from,
+			to,
+			amount,
+			frame_support::traits::BalanceStatus::Free,
+		)?;
+		ensure!(remaining.is_zero(), Error::<T>::InsufficientReservedBalance);
+		Ok(())
+	}
+
+	/// Remove the listing and transfer the paid token to the buyer.
+	fn do_sell(
+		token: (ClassIdOf<T>, TokenIdOf<T>),
+		listing: Listing<T::AccountId, T::BlockNumber>,
+		buyer: &T::AccountId,
+		price: Balance,
+		royalty: Balance,
+	) -> DispatchResult {
+		Listings::<T>::remove(token.0, token.1);
+		Self::do_transfer(&listing.seller, buyer, token)?;
+
+		Self::deposit_event(Event::Sold {
+			class_id: token.0,
+			token_id: token.1,
+			seller: listing.seller,
+			buyer: buyer.clone(),
+			currency_id: listing.currency_id,
+			price,
+			royalty,
+		});
+		Ok(())
+	}
+
 	fn data_deposit(metadata: &[u8], attributes: &Attributes) -> Result<BalanceOf<T>, DispatchError> {
 		// Addition can't overflow because we will be out of memory before that
 		let attributes_len = attributes.iter().fold(0, |acc, (k, v)| {
@@ -531,9 +1398,9 @@ impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
 		orml_nft::Pallet::<T>::classes(class).map(|c| c.owner)
 	}
 
-	fn can_transfer(class: &Self::CollectionId, _: &Self::ItemId) -> bool {
+	fn can_transfer(class: &Self::CollectionId, instance: &Self::ItemId) -> bool {
 		orml_nft::Pallet::<T>::classes(class).map_or(false, |class_info| {
-			class_info.data.properties.0.contains(ClassProperty::Transferable)
+			Self::ensure_transferable(&class_info.data.properties, (*class, *instance)).is_ok()
 		})
 	}
 }
@@ -571,3 +1438,88 @@ impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
 		Self::do_transfer(&owner, destination, (*class, *instance))
 	}
 }
+
+/// The classes whose tokens stay transferable: `Transferable`, neither `Soulbound` nor
+/// `Expirable`, and the properties can't be changed.
+pub struct PermanentlyTransferableClasses<T>(PhantomData<T>);
+impl<T: Config> Contains<ClassIdOf<T>> for PermanentlyTransferableClasses<T> {
+	fn contains(class_id: &ClassIdOf<T>) -> bool {
+		orml_nft::Pallet::<T>::classes(class_id).map_or(false, |class_info| {
+			let properties = class_info.data.properties.0;
+			properties.contains(ClassProperty::Transferable)
+				&& !properties.contains(ClassProperty::Soulbound)
+				&& !properties.contains(ClassProperty::Expirable)
+				&& !properties.contains(ClassProperty::ClassPropertiesMutable)
+		})
+	}
+}
+
+#[derive(Clone, RuntimeDebug, PartialEq, Encode, Decode, TypeInfo)]
+pub enum NftTask<T: Config> {
+	/// Burn the tokens of `Expirable` classes expired at the block.
+	BurnExpired { expiry: T::BlockNumber },
+}
+
+impl<T: Config> DispatchableTask for NftTask<T> {
+	fn dispatch(self, weight: Weight) -> TaskResult {
+		match self {
+			NftTask::BurnExpired { expiry } => {
+				let burn_weight = <T as Config>::WeightInfo::burn_expired();
+				let mut used_weight: Weight = 0;
+				let mut result = Ok(());
+
+				loop {
+					// ExpiringTokens (r: 1)
+					let read_weight = <T as frame_system::Config>::DbWeight::get().reads(1);
+					if used_weight.saturating_add(read_weight).saturating_add(burn_weight) > weight {
+						return TaskResult {
+							result,
+							used_weight,
+							finished: false,
+						};
+					}
+					used_weight = used_weight.saturating_add(read_weight);
+
+					let token = match ExpiringTokens::<T>::iter_key_prefix(expiry).next() {
+						Some(token) => token,
+						None => {
+							return TaskResult {
+								result,
+								used_weight,
+								finished: true,
+							}
+						}
+					};
+					// remove it first in case it fails to be burned
+					ExpiringTokens::<T>::remove(expiry, token);
+					if let Err(e) = Pallet::<T>::burn_expired(token) {
+						result = Err(e);
+					}
+					used_weight = used_weight.saturating_add(burn_weight);
+				}
+			}
+		}
+	}
+
+	fn priority(&self) -> TaskPriority {
+		match self {
+			// the expired tokens should be burned in time
+			NftTask::BurnExpired { .. } => TaskPriority::High,
+		}
+	}
+
+	fn max_weight(&self) -> Weight {
+		match self {
+			// at least one expired token is burned in a dispatch
+			NftTask::BurnExpired { .. } => <T as frame_system::Config>::DbWeight::get()
+				.reads(1)
+				.saturating_add(<T as Config>::WeightInfo::burn_expired()),
+		}
+	}
+}
+
+impl<T: Config> From<NftTask<T>> for () {
+	fn from(_task: NftTask<T>) -> Self {
+		unimplemented!()
+	}
+}
@@ -0,0 +1,97 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{ClassBalances, Config, Pallet};
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_runtime::traits::Saturating;
+use sp_std::marker::PhantomData;
+
+/// Migrate the NFT storage to v1.
+pub struct MigrateToV1<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+	fn on_runtime_upgrade() -> Weight {
+		v1::migrate::<T>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<(), &'static str> {
+		v1::pre_migrate::<T>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade() -> Result<(), &'static str> {
+		v1::post_migrate::<T>()
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Counts the tokens of each owner in each class into `ClassBalances`.
+	pub fn migrate<T: Config>() -> Weight {
+		let on_chain_storage_version = Pallet::<T>::on_chain_storage_version();
+		if on_chain_storage_version >= 1 {
+			log::warn!(
+				target: "nft",
+				"Attempted to apply migration to v1 but failed because storage version is {:?}",
+				on_chain_storage_version,
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: "nft", "Migrating nft to v1");
+
+		let mut count: Weight = 0;
+		for (owner, class_id, _) in orml_nft::TokensByOwner::<T>::iter_keys() {
+			ClassBalances::<T>::mutate(owner, class_id, |balance| *balance = balance.saturating_add(1));
+			count += 1;
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+		log::info!(target: "nft", "Completed nft migration to v1, {:?} tokens", count);
+
+		T::DbWeight::get().reads_writes(count.saturating_mul(2).saturating_add(1), count.saturating_add(1))
+	}
+
+	pub fn pre_migrate<T: Config>() -> Result<(), &'static str> {
+		assert!(
+			Pallet::<T>::on_chain_storage_version() < 1,
+			"nft already migrated to v1."
+		);
+		Ok(())
+	}
+
+	pub fn post_migrate<T: Config>() -> Result<(), &'static str> {
+		assert_eq!(
+			Pallet::<T>::on_chain_storage_version(),
+			1,
+			"nft storage version not set."
+		);
+		for (owner, class_id, _) in orml_nft::TokensByOwner::<T>::iter_keys() {
+			assert_eq!(
+				ClassBalances::<T>::get(&owner, class_id),
+				orml_nft::TokensByOwner::<T>::iter_prefix((&owner, class_id)).count() as u128,
+				"class balance mismatch."
+			);
+		}
+		Ok(())
+	}
+}
@@ -29,11 +29,13 @@ use frame_support::{
 };
 use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
-use primitives::{Amount, Balance, CurrencyId, ReserveIdentifier, TokenSymbol};
+use primitives::{
+	define_combined_task, task::TaskResult, Amount, Balance, BlockNumber, CurrencyId, ReserveIdentifier, TokenSymbol,
+};
 use sp_core::{crypto::AccountId32, H160, H256};
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup},
 };
 use support::mocks::MockAddressMapping;
 
@@ -155,18 +157,20 @@ impl orml_tokens::Config for Runtime {
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
 	type MaxLocks = ();
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
 	type DustRemovalWhitelist = Nothing;
 	type OnNewTokenAccount = ();
 	type OnKilledTokenAccount = ();
 }
 
 pub const NATIVE_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::ACA);
+pub const STABLE_CURRENCY_ID: CurrencyId = CurrencyId::Token(TokenSymbol::AUSD);
 
 parameter_types! {
 	pub const GetNativeCurrencyId: CurrencyId = NATIVE_CURRENCY_ID;
 	pub Erc20HoldingAccount: H160 = H160::from_low_u64_be(1);
+	pub TreasuryAccount: AccountId = AccountId::new([255u8; 32]);
 }
 
 impl module_currencies::Config for Runtime {
@@ -175,6 +179,7 @@ impl module_currencies::Config for Runtime {
 	type NativeCurrency = NativeCurrency;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type Erc20HoldingAccount = Erc20HoldingAccount;
+	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
@@ -183,8 +188,42 @@ impl module_currencies::Config for Runtime {
 	type OnDust = ();
 }
 
+define_combined_task! {
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum ScheduledTasks {
+		NftTask(NftTask<Runtime>),
+	}
+}
+
+pub struct MockBlockNumberProvider;
+
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = BlockNumber;
+
+	fn current_block_number() -> Self::BlockNumber {
+		Zero::zero()
+	}
+}
+
+impl module_idle_scheduler::Config for Runtime {
+	type Event = Event;
+	type WeightInfo = ();
+	type Task = ScheduledTasks;
+	type MinimumWeightRemainInBlock = ConstU64<0>;
+	type RelayChainBlockNumberProvider = MockBlockNumberProvider;
+	type DisableBlockThreshold = ConstU32<6>;
+	type MaxRetries = ConstU32<3>;
+	type RetryBackoff = ConstU64<10>;
+	type Currency = Balances;
+	type RecurringTaskDeposit = ConstU128<10>;
+	type RecurringTaskFilter = Nothing;
+	type MaxRecurringTasks = ConstU32<10>;
+	type MaxTaskHistory = ConstU32<10>;
+}
+
 parameter_types! {
 	pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
+	pub const MaxRoyalty: Permill = Permill::from_percent(10);
 }
 pub const CREATE_CLASS_DEPOSIT: u128 = 200;
 pub const CREATE_TOKEN_DEPOSIT: u128 = 100;
@@ -198,6 +237,10 @@ impl Config for Runtime {
 	type DataDepositPerByte = ConstU128<DATA_DEPOSIT_PER_BYTE>;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = ConstU32<MAX_ATTRIBUTES_BYTES>;
+	type MultiCurrency = Currency;
+	type MaxRoyalty = MaxRoyalty;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = ();
 }
 
@@ -229,11 +272,13 @@ construct_runtime!(
 		Utility: pallet_utility::{Pallet, Call, Event},
 		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
 		Currency: module_currencies::{Pallet, Call, Event<T>},
+		IdleScheduler: module_idle_scheduler::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
 pub const ALICE: AccountId = AccountId::new([1u8; 32]);
 pub const BOB: AccountId = AccountId::new([2u8; 32]);
+pub const CHARLIE: AccountId = AccountId::new([3u8; 32]);
 pub const CLASS_ID: <Runtime as orml_nft::Config>::ClassId = 0;
 pub const CLASS_ID_NOT_EXIST: <Runtime as orml_nft::Config>::ClassId = 1;
 pub const TOKEN_ID: <Runtime as orml_nft::Config>::TokenId = 0;
@@ -258,6 +303,12 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: vec![(BOB, STABLE_CURRENCY_ID, 1000), (CHARLIE, STABLE_CURRENCY_ID, 1000)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
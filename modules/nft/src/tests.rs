@@ -23,7 +23,7 @@
 use super::*;
 use frame_support::traits::Currency;
 use frame_support::{assert_noop, assert_ok};
-use mock::{Event, *};
+use mock::{Event, IdleScheduler, *};
 use orml_nft::TokenInfo;
 use primitives::Balance;
 use sp_runtime::{traits::BlakeTwo256, ArithmeticError};
@@ -48,6 +48,39 @@ fn test_attr(x: u8) -> Attributes {
 	attr
 }
 
+fn stable_free_balance(who: &AccountId) -> Balance {
+	<Tokens as MultiCurrency<AccountId>>::free_balance(STABLE_CURRENCY_ID, who)
+}
+
+fn stable_reserved_balance(who: &AccountId) -> Balance {
+	<Tokens as NamedMultiReservableCurrency<AccountId>>::reserved_balance_named(
+		&BID_RESERVE_ID,
+		STABLE_CURRENCY_ID,
+		who,
+	)
+}
+
+fn create_class_and_mint_to_alice() {
+	assert_ok!(NFTModule::create_class(
+		Origin::signed(ALICE),
+		vec![1],
+		Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+		Default::default(),
+	));
+	assert_ok!(Balances::deposit_into_existing(
+		&class_id_account(),
+		CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE
+	));
+	assert_ok!(NFTModule::mint(
+		Origin::signed(class_id_account()),
+		ALICE,
+		CLASS_ID,
+		vec![1],
+		Default::default(),
+		1
+	));
+}
+
 const TEST_ATTR_LEN: Balance = 7;
 
 #[test]
@@ -399,6 +432,40 @@ fn transfer_should_fail() {
 	});
 }
 
+#[test]
+fn class_balance_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let metadata = vec![1];
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(ALICE),
+			metadata.clone(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		assert_ok!(Balances::deposit_into_existing(
+			&class_id_account(),
+			2 * (CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE)
+		));
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_id_account()),
+			BOB,
+			CLASS_ID,
+			metadata,
+			Default::default(),
+			2
+		));
+		assert_eq!(NFTModule::class_balance(&BOB, CLASS_ID), 2);
+
+		assert_ok!(NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)));
+		assert_eq!(NFTModule::class_balance(&BOB, CLASS_ID), 1);
+		assert_eq!(NFTModule::class_balance(&ALICE, CLASS_ID), 1);
+
+		assert_ok!(NFTModule::burn(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+		assert_eq!(NFTModule::class_balance(&ALICE, CLASS_ID), 0);
+		assert_eq!(NFTModule::class_balance(&BOB, CLASS_ID_NOT_EXIST), 0);
+	});
+}
+
 #[test]
 fn burn_should_work() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -712,3 +779,729 @@ fn update_class_properties_should_work() {
 		);
 	});
 }
+
+#[test]
+fn approve_and_transfer_from_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_and_mint_to_alice();
+
+		assert_noop!(
+			NFTModule::transfer_from(Origin::signed(BOB), ALICE, BOB, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::approve(Origin::signed(BOB), Some(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(NFTModule::approve(
+			Origin::signed(ALICE),
+			Some(BOB),
+			(CLASS_ID, TOKEN_ID)
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::Approval {
+			owner: ALICE,
+			approved: Some(BOB),
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert_eq!(NFTModule::token_approvals(CLASS_ID, TOKEN_ID), Some(BOB));
+
+		assert_noop!(
+			NFTModule::transfer_from(Origin::signed(BOB), CHARLIE, BOB, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::transfer_from(
+			Origin::signed(BOB),
+			ALICE,
+			CHARLIE,
+			(CLASS_ID, TOKEN_ID)
+		));
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().owner,
+			CHARLIE
+		);
+		// the approval is cleared by the transfer
+		assert_eq!(NFTModule::token_approvals(CLASS_ID, TOKEN_ID), None);
+		assert_noop!(
+			NFTModule::transfer_from(Origin::signed(BOB), CHARLIE, BOB, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn set_approval_for_all_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_and_mint_to_alice();
+
+		assert_noop!(
+			NFTModule::set_approval_for_all(Origin::signed(ALICE), CLASS_ID_NOT_EXIST, BOB, true),
+			Error::<Runtime>::ClassIdNotFound
+		);
+		assert_noop!(
+			NFTModule::set_approval_for_all(Origin::signed(ALICE), CLASS_ID, ALICE, true),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(NFTModule::set_approval_for_all(
+			Origin::signed(ALICE),
+			CLASS_ID,
+			BOB,
+			true
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::ApprovalForAll {
+			owner: ALICE,
+			operator: BOB,
+			class_id: CLASS_ID,
+			approved: true,
+		}));
+		assert!(NFTModule::operator_approvals((ALICE, CLASS_ID), BOB));
+
+		// the operator could approve others
+		assert_ok!(NFTModule::approve(
+			Origin::signed(BOB),
+			Some(CHARLIE),
+			(CLASS_ID, TOKEN_ID)
+		));
+
+		assert_ok!(NFTModule::set_approval_for_all(
+			Origin::signed(ALICE),
+			CLASS_ID,
+			BOB,
+			false
+		));
+		assert!(!NFTModule::operator_approvals((ALICE, CLASS_ID), BOB));
+		assert_noop!(
+			NFTModule::transfer_from(Origin::signed(BOB), ALICE, BOB, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::transfer_from(
+			Origin::signed(CHARLIE),
+			ALICE,
+			BOB,
+			(CLASS_ID, TOKEN_ID)
+		));
+	});
+}
+
+#[test]
+fn set_royalty_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_and_mint_to_alice();
+		let royalty = RoyaltyInfo {
+			beneficiary: CHARLIE,
+			rate: Permill::from_percent(5),
+		};
+
+		assert_noop!(
+			NFTModule::set_royalty(Origin::signed(ALICE), CLASS_ID, Some(royalty.clone())),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::set_royalty(
+				Origin::signed(class_id_account()),
+				CLASS_ID,
+				Some(RoyaltyInfo {
+					beneficiary: CHARLIE,
+					rate: Permill::from_percent(11),
+				})
+			),
+			Error::<Runtime>::RoyaltyTooHigh
+		);
+
+		assert_ok!(NFTModule::set_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Some(royalty.clone())
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::RoyaltyUpdated {
+			class_id: CLASS_ID,
+			royalty: Some(royalty.clone()),
+		}));
+		assert_eq!(NFTModule::class_royalties(CLASS_ID), Some(royalty));
+		assert_eq!(NFTModule::royalty_info(CLASS_ID, 100), Some((CHARLIE, 5)));
+
+		assert_ok!(NFTModule::set_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			None
+		));
+		assert_eq!(NFTModule::royalty_info(CLASS_ID, 100), None);
+	});
+}
+
+#[test]
+fn fixed_price_listing_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_and_mint_to_alice();
+		assert_ok!(NFTModule::set_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Some(RoyaltyInfo {
+				beneficiary: CHARLIE,
+				rate: Permill::from_percent(5),
+			})
+		));
+
+		assert_noop!(
+			NFTModule::list_fixed_price(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), STABLE_CURRENCY_ID, 100),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::list_fixed_price(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID), STABLE_CURRENCY_ID, 0),
+			Error::<Runtime>::ZeroPrice
+		);
+		assert_ok!(NFTModule::list_fixed_price(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			STABLE_CURRENCY_ID,
+			100
+		));
+
+		// listed token can't be transferred or burned
+		assert_noop!(
+			NFTModule::transfer(Origin::signed(ALICE), BOB, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::TokenListed
+		);
+		assert_noop!(
+			NFTModule::burn(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::TokenListed
+		);
+		assert_noop!(
+			NFTModule::bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 100),
+			Error::<Runtime>::NotAuction
+		);
+		assert_noop!(
+			NFTModule::buy(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 99),
+			Error::<Runtime>::PriceTooHigh
+		);
+
+		assert_ok!(NFTModule::buy(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 100));
+		System::assert_last_event(Event::NFTModule(crate::Event::Sold {
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			seller: ALICE,
+			buyer: BOB,
+			currency_id: STABLE_CURRENCY_ID,
+			price: 100,
+			royalty: 5,
+		}));
+		assert_eq!(stable_free_balance(&BOB), 900);
+		assert_eq!(stable_free_balance(&CHARLIE), 1005);
+		assert_eq!(stable_free_balance(&ALICE), 95);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().owner,
+			BOB
+		);
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+		assert_eq!(reserved_balance(&BOB), CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE);
+
+		assert_noop!(
+			NFTModule::buy(Origin::signed(CHARLIE), (CLASS_ID, TOKEN_ID), 100),
+			Error::<Runtime>::NotListed
+		);
+	});
+}
+
+#[test]
+fn cancel_listing_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_and_mint_to_alice();
+		assert_noop!(
+			NFTModule::cancel_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NotListed
+		);
+		assert_ok!(NFTModule::list_fixed_price(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			STABLE_CURRENCY_ID,
+			100
+		));
+		assert_noop!(
+			NFTModule::list_fixed_price(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID), STABLE_CURRENCY_ID, 100),
+			Error::<Runtime>::TokenListed
+		);
+		assert_noop!(
+			NFTModule::buy(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID), 100),
+			Error::<Runtime>::InvalidBuyer
+		);
+		assert_noop!(
+			NFTModule::cancel_listing(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+
+		assert_ok!(NFTModule::cancel_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTModule(crate::Event::ListingCancelled {
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+		assert_ok!(NFTModule::transfer(Origin::signed(ALICE), BOB, (CLASS_ID, TOKEN_ID)));
+	});
+}
+
+#[test]
+fn auction_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_and_mint_to_alice();
+		assert_ok!(NFTModule::set_royalty(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			Some(RoyaltyInfo {
+				beneficiary: class_id_account(),
+				rate: Permill::from_percent(10),
+			})
+		));
+
+		assert_noop!(
+			NFTModule::list_auction(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID), STABLE_CURRENCY_ID, 100, 0),
+			Error::<Runtime>::ZeroDuration
+		);
+		assert_ok!(NFTModule::list_auction(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			STABLE_CURRENCY_ID,
+			100,
+			10
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::Listed {
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			listing: Listing {
+				seller: ALICE,
+				currency_id: STABLE_CURRENCY_ID,
+				kind: ListingKind::Auction {
+					min_bid: 100,
+					end: 11,
+					best_bid: None,
+				},
+			},
+		}));
+
+		assert_noop!(
+			NFTModule::buy(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 100),
+			Error::<Runtime>::NotFixedPrice
+		);
+		assert_noop!(
+			NFTModule::bid(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID), 100),
+			Error::<Runtime>::InvalidBuyer
+		);
+		assert_noop!(
+			NFTModule::bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 99),
+			Error::<Runtime>::BidTooLow
+		);
+
+		assert_ok!(NFTModule::bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 100));
+		System::assert_last_event(Event::NFTModule(crate::Event::BidPlaced {
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			bidder: BOB,
+			amount: 100,
+		}));
+		assert_eq!(stable_reserved_balance(&BOB), 100);
+
+		assert_noop!(
+			NFTModule::bid(Origin::signed(CHARLIE), (CLASS_ID, TOKEN_ID), 100),
+			Error::<Runtime>::BidTooLow
+		);
+		assert_ok!(NFTModule::bid(Origin::signed(CHARLIE), (CLASS_ID, TOKEN_ID), 200));
+		assert_eq!(stable_reserved_balance(&BOB), 0);
+		assert_eq!(stable_free_balance(&BOB), 1000);
+		assert_eq!(stable_reserved_balance(&CHARLIE), 200);
+
+		assert_noop!(
+			NFTModule::cancel_listing(Origin::signed(ALICE), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::AuctionHasBids
+		);
+		assert_noop!(
+			NFTModule::settle_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::AuctionNotEnded
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			NFTModule::bid(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), 300),
+			Error::<Runtime>::AuctionEnded
+		);
+
+		assert_ok!(NFTModule::settle_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTModule(crate::Event::Sold {
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+			seller: ALICE,
+			buyer: CHARLIE,
+			currency_id: STABLE_CURRENCY_ID,
+			price: 200,
+			royalty: 20,
+		}));
+		assert_eq!(stable_reserved_balance(&CHARLIE), 0);
+		assert_eq!(stable_free_balance(&CHARLIE), 800);
+		assert_eq!(stable_free_balance(&ALICE), 180);
+		assert_eq!(stable_free_balance(&class_id_account()), 20);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().owner,
+			CHARLIE
+		);
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+	});
+}
+
+#[test]
+fn settle_auction_without_bids_should_cancel_listing() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_and_mint_to_alice();
+		assert_ok!(NFTModule::list_auction(
+			Origin::signed(ALICE),
+			(CLASS_ID, TOKEN_ID),
+			STABLE_CURRENCY_ID,
+			100,
+			10
+		));
+
+		System::set_block_number(11);
+		assert_ok!(NFTModule::settle_auction(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		System::assert_last_event(Event::NFTModule(crate::Event::ListingCancelled {
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+		assert_eq!(
+			orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).unwrap().owner,
+			ALICE
+		);
+	});
+}
+
+fn create_class_with_properties(properties: Properties) {
+	assert_ok!(NFTModule::create_class(
+		Origin::signed(ALICE),
+		vec![1],
+		properties,
+		Default::default(),
+	));
+	assert_ok!(Balances::deposit_into_existing(
+		&class_id_account(),
+		10 * (CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE)
+	));
+}
+
+fn mint_to(to: AccountId, quantity: u32) -> DispatchResult {
+	NFTModule::mint(
+		Origin::signed(class_id_account()),
+		to,
+		CLASS_ID,
+		vec![1],
+		Default::default(),
+		quantity,
+	)
+}
+
+#[test]
+fn soulbound_token_should_not_be_transferred() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_with_properties(Properties(
+			ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable | ClassProperty::Soulbound,
+		));
+		assert_ok!(mint_to(BOB, 1));
+
+		assert_noop!(
+			NFTModule::transfer(Origin::signed(BOB), ALICE, (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::Soulbound
+		);
+		assert_noop!(
+			NFTModule::list_fixed_price(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), STABLE_CURRENCY_ID, 100),
+			Error::<Runtime>::Soulbound
+		);
+		assert!(!<NFTModule as Inspect<AccountId>>::can_transfer(&CLASS_ID, &TOKEN_ID));
+
+		// the holder could still burn it
+		assert_ok!(NFTModule::burn(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+	});
+}
+
+#[test]
+fn revoke_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_with_properties(Properties(ClassProperty::Mintable | ClassProperty::Soulbound));
+		assert_ok!(mint_to(BOB, 1));
+		assert_eq!(reserved_balance(&BOB), CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE);
+
+		assert_noop!(
+			NFTModule::revoke(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NoPermission
+		);
+		assert_noop!(
+			NFTModule::revoke(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID_NOT_EXIST)),
+			Error::<Runtime>::TokenIdNotFound
+		);
+
+		assert_ok!(NFTModule::revoke(
+			Origin::signed(class_id_account()),
+			(CLASS_ID, TOKEN_ID)
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::RevokedToken {
+			owner: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert!(orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).is_none());
+		assert_eq!(reserved_balance(&BOB), 0);
+		assert_eq!(free_balance(&BOB), CREATE_TOKEN_DEPOSIT + DATA_DEPOSIT_PER_BYTE);
+	});
+}
+
+#[test]
+fn revoke_should_fail_for_non_soulbound_class() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_and_mint_to_alice();
+		assert_noop!(
+			NFTModule::revoke(Origin::signed(class_id_account()), (CLASS_ID, TOKEN_ID)),
+			Error::<Runtime>::NotSoulbound
+		);
+	});
+}
+
+#[test]
+fn supply_capped_class_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_with_properties(Properties(ClassProperty::Mintable | ClassProperty::SupplyCapped));
+		assert_noop!(mint_to(BOB, 1), Error::<Runtime>::ClassConfigNotSet);
+
+		assert_noop!(
+			NFTModule::set_class_config(
+				Origin::signed(ALICE),
+				CLASS_ID,
+				ClassConfig {
+					max_supply: Some(2),
+					token_lifetime: None,
+				}
+			),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(NFTModule::set_class_config(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			ClassConfig {
+				max_supply: Some(2),
+				token_lifetime: None,
+			}
+		));
+		System::assert_last_event(Event::NFTModule(crate::Event::ClassConfigUpdated {
+			class_id: CLASS_ID,
+			config: ClassConfig {
+				max_supply: Some(2),
+				token_lifetime: None,
+			},
+		}));
+
+		assert_noop!(mint_to(BOB, 3), Error::<Runtime>::SupplyCapExceeded);
+		assert_ok!(mint_to(BOB, 2));
+		assert_noop!(mint_to(BOB, 1), Error::<Runtime>::SupplyCapExceeded);
+
+		// the class config is immutable after minting
+		assert_noop!(
+			NFTModule::set_class_config(
+				Origin::signed(class_id_account()),
+				CLASS_ID,
+				ClassConfig {
+					max_supply: Some(3),
+					token_lifetime: None,
+				}
+			),
+			Error::<Runtime>::Immutable
+		);
+	});
+}
+
+#[test]
+fn set_class_config_should_not_cap_below_total_issuance() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_with_properties(Properties(
+			ClassProperty::Mintable | ClassProperty::ClassPropertiesMutable | ClassProperty::SupplyCapped,
+		));
+		assert_ok!(NFTModule::set_class_config(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			ClassConfig {
+				max_supply: Some(5),
+				token_lifetime: None,
+			}
+		));
+		assert_ok!(mint_to(BOB, 3));
+
+		assert_noop!(
+			NFTModule::set_class_config(
+				Origin::signed(class_id_account()),
+				CLASS_ID,
+				ClassConfig {
+					max_supply: Some(2),
+					token_lifetime: None,
+				}
+			),
+			Error::<Runtime>::SupplyCapExceeded
+		);
+		assert_ok!(NFTModule::set_class_config(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			ClassConfig {
+				max_supply: Some(3),
+				token_lifetime: None,
+			}
+		));
+		assert_noop!(mint_to(BOB, 1), Error::<Runtime>::SupplyCapExceeded);
+	});
+}
+
+#[test]
+fn expired_token_should_be_burned_by_idle_scheduler() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_with_properties(Properties(
+			ClassProperty::Transferable | ClassProperty::Mintable | ClassProperty::Expirable,
+		));
+		assert_noop!(mint_to(BOB, 1), Error::<Runtime>::ClassConfigNotSet);
+		assert_ok!(NFTModule::set_class_config(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			ClassConfig {
+				max_supply: None,
+				token_lifetime: Some(10),
+			}
+		));
+
+		assert_ok!(mint_to(BOB, 1));
+		assert_eq!(NFTModule::token_expiries(CLASS_ID, TOKEN_ID), Some(11));
+		assert_eq!(ExpiringTokens::<Runtime>::get(11, (CLASS_ID, TOKEN_ID)), Some(()));
+		assert_ok!(NFTModule::list_auction(
+			Origin::signed(BOB),
+			(CLASS_ID, TOKEN_ID),
+			STABLE_CURRENCY_ID,
+			100,
+			20
+		));
+		assert_ok!(NFTModule::bid(Origin::signed(CHARLIE), (CLASS_ID, TOKEN_ID), 100));
+
+		// not expired yet
+		NFTModule::on_initialize(10);
+		assert_eq!(module_idle_scheduler::Tasks::<Runtime>::iter().count(), 0);
+
+		System::set_block_number(11);
+		NFTModule::on_initialize(11);
+		assert_eq!(module_idle_scheduler::Tasks::<Runtime>::iter().count(), 1);
+		assert_noop!(
+			NFTModule::list_fixed_price(Origin::signed(BOB), (CLASS_ID, TOKEN_ID), STABLE_CURRENCY_ID, 100),
+			Error::<Runtime>::TokenExpired
+		);
+		assert!(!<NFTModule as Inspect<AccountId>>::can_transfer(&CLASS_ID, &TOKEN_ID));
+
+		IdleScheduler::do_dispatch_tasks(0, 100_000_000_000);
+		System::assert_has_event(Event::NFTModule(crate::Event::ExpiredToken {
+			owner: BOB,
+			class_id: CLASS_ID,
+			token_id: TOKEN_ID,
+		}));
+		assert!(orml_nft::Pallet::<Runtime>::tokens(CLASS_ID, TOKEN_ID).is_none());
+		assert_eq!(NFTModule::token_expiries(CLASS_ID, TOKEN_ID), None);
+		assert_eq!(ExpiringTokens::<Runtime>::iter().count(), 0);
+		assert_eq!(NFTModule::listings(CLASS_ID, TOKEN_ID), None);
+		assert_eq!(module_idle_scheduler::Tasks::<Runtime>::iter().count(), 0);
+		assert_eq!(reserved_balance(&BOB), 0);
+		// the bid is refunded
+		assert_eq!(stable_reserved_balance(&CHARLIE), 0);
+		assert_eq!(stable_free_balance(&CHARLIE), 1000);
+	});
+}
+
+#[test]
+fn burned_token_should_not_be_scheduled_to_expire() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_with_properties(Properties(
+			ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable | ClassProperty::Expirable,
+		));
+		assert_ok!(NFTModule::set_class_config(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			ClassConfig {
+				max_supply: None,
+				token_lifetime: Some(10),
+			}
+		));
+		assert_ok!(mint_to(BOB, 1));
+		assert_ok!(NFTModule::burn(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		assert_eq!(NFTModule::token_expiries(CLASS_ID, TOKEN_ID), None);
+		assert_eq!(ExpiringTokens::<Runtime>::iter().count(), 0);
+
+		System::set_block_number(11);
+		NFTModule::on_initialize(11);
+		assert_eq!(module_idle_scheduler::Tasks::<Runtime>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn supply_cap_should_count_burned_tokens() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_with_properties(Properties(
+			ClassProperty::Mintable | ClassProperty::Burnable | ClassProperty::SupplyCapped,
+		));
+		assert_ok!(NFTModule::set_class_config(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			ClassConfig {
+				max_supply: Some(2),
+				token_lifetime: None,
+			}
+		));
+		assert_ok!(mint_to(BOB, 2));
+		assert_ok!(NFTModule::burn(Origin::signed(BOB), (CLASS_ID, TOKEN_ID)));
+		assert_noop!(mint_to(BOB, 1), Error::<Runtime>::SupplyCapExceeded);
+	});
+}
+
+#[test]
+fn expired_tokens_should_be_burned_in_batches() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_class_with_properties(Properties(ClassProperty::Mintable | ClassProperty::Expirable));
+		assert_noop!(
+			NFTModule::set_class_config(
+				Origin::signed(class_id_account()),
+				CLASS_ID,
+				ClassConfig {
+					max_supply: None,
+					token_lifetime: Some(0),
+				}
+			),
+			Error::<Runtime>::ZeroTokenLifetime
+		);
+		assert_ok!(NFTModule::set_class_config(
+			Origin::signed(class_id_account()),
+			CLASS_ID,
+			ClassConfig {
+				max_supply: None,
+				token_lifetime: Some(10),
+			}
+		));
+		assert_ok!(mint_to(BOB, 3));
+
+		System::set_block_number(11);
+		NFTModule::on_initialize(11);
+		let burn_weight = <() as WeightInfo>::burn_expired();
+		let read_weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+		assert_eq!(
+			NftTask::<Runtime>::BurnExpired { expiry: 11 }.max_weight(),
+			read_weight + burn_weight
+		);
+
+		// only enough weight to burn one token
+		let result = NftTask::<Runtime>::BurnExpired { expiry: 11 }.dispatch(read_weight + burn_weight);
+		assert_eq!(result.result, Ok(()));
+		assert!(!result.finished);
+		assert_eq!(ExpiringTokens::<Runtime>::iter().count(), 2);
+
+		let result = NftTask::<Runtime>::BurnExpired { expiry: 11 }.dispatch(Weight::MAX);
+		assert_eq!(result.result, Ok(()));
+		assert!(result.finished);
+		assert_eq!(ExpiringTokens::<Runtime>::iter().count(), 0);
+		assert_eq!(NFTModule::class_balance(&BOB, CLASS_ID), 0);
+	});
+}
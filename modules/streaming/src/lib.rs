@@ -0,0 +1,375 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Streaming Module
+//!
+//! ## Overview
+//!
+//! Stream any `CurrencyId` from the sender to the recipient at a per second rate between the
+//! start time and the stop time. The whole deposit of the stream is escrowed by a named reserve on
+//! the sender, the streamed amount accrues lazily and the recipient could withdraw it at any time.
+//! Either party could cancel the stream, the accrued amount is paid to the recipient and the rest
+//! is unreserved back to the sender.
+//!
+//! ERC20 tokens can't be streamed, as `module_currencies` doesn't support named reserves of them.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, traits::UnixTime, transactional};
+use frame_system::pallet_prelude::*;
+use orml_traits::{GetByKey, MultiCurrency, NamedMultiReservableCurrency};
+use primitives::{Balance, CurrencyId, ReserveIdentifier};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{StaticLookup, Zero},
+	ArithmeticError, RuntimeDebug,
+};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+pub type StreamId = u64;
+
+/// A token stream from `sender` to `recipient`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Stream<AccountId> {
+	/// The payer of the stream.
+	pub sender: AccountId,
+	/// The payee of the stream.
+	pub recipient: AccountId,
+	/// The streamed currency.
+	pub currency_id: CurrencyId,
+	/// The amount streamed per second.
+	pub rate_per_second: Balance,
+	/// The unix time in seconds the stream starts.
+	pub start_time: u64,
+	/// The unix time in seconds the stream stops.
+	pub stop_time: u64,
+	/// The total amount reserved from the sender.
+	pub deposit: Balance,
+	/// The amount already withdrawn by the recipient.
+	pub withdrawn: Balance,
+}
+
+impl<AccountId> Stream<AccountId> {
+	/// The amount streamed to the recipient until `now`, including the withdrawn amount.
+	pub fn streamed_amount(&self, now: u64) -> Balance {
+		let elapsed = now.min(self.stop_time).saturating_sub(self.start_time);
+		self.rate_per_second.saturating_mul(elapsed.into()).min(self.deposit)
+	}
+
+	/// The amount could be withdrawn by the recipient at `now`.
+	pub fn withdrawable_amount(&self, now: u64) -> Balance {
+		self.streamed_amount(now).saturating_sub(self.withdrawn)
+	}
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	pub const RESERVE_ID: ReserveIdentifier = ReserveIdentifier::Streaming;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency to reserve the deposit of streams.
+		type Currency: NamedMultiReservableCurrency<
+			Self::AccountId,
+			ReserveIdentifier = ReserveIdentifier,
+			CurrencyId = CurrencyId,
+			Balance = Balance,
+		>;
+
+		/// The minimum deposit of a stream.
+		type MinimumDeposit: GetByKey<CurrencyId, Balance>;
+
+		/// Time used for computing the streamed amount.
+		type UnixTime: UnixTime;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The sender and the recipient are the same account.
+		InvalidRecipient,
+		/// The stop time is not after the start time.
+		InvalidTimeRange,
+		/// The start time is in the past.
+		StartTimeInPast,
+		/// The rate per second is zero.
+		ZeroRate,
+		/// The deposit is below the minimum deposit.
+		DepositTooLow,
+		/// The stream does not exist.
+		StreamNotFound,
+		/// The caller is not a party of the stream.
+		NoPermission,
+		/// There is nothing to withdraw yet.
+		NothingToWithdraw,
+		/// No id is available for the new stream.
+		NoAvailableStreamId,
+		/// The reserved deposit of the sender is less than the stream requires.
+		ReservedBalanceTooLow,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Created a stream.
+		StreamCreated {
+			stream_id: StreamId,
+			sender: T::AccountId,
+			recipient: T::AccountId,
+			currency_id: CurrencyId,
+			rate_per_second: Balance,
+			start_time: u64,
+			stop_time: u64,
+			deposit: Balance,
+		},
+		/// The recipient withdrew from a stream.
+		Withdrawn {
+			stream_id: StreamId,
+			recipient: T::AccountId,
+			amount: Balance,
+		},
+		/// A stream is cancelled, `recipient_amount` is paid to the recipient and `sender_refund`
+		/// is refunded to the sender.
+		StreamCancelled {
+			stream_id: StreamId,
+			recipient_amount: Balance,
+			sender_refund: Balance,
+		},
+		/// A stream is finished and fully withdrawn.
+		StreamCompleted { stream_id: StreamId },
+	}
+
+	/// The next stream id.
+	///
+	/// NextStreamId: StreamId
+	#[pallet::storage]
+	#[pallet::getter(fn next_stream_id)]
+	pub type NextStreamId<T: Config> = StorageValue<_, StreamId, ValueQuery>;
+
+	/// The active streams.
+	///
+	/// Streams: map StreamId => Option<Stream>
+	#[pallet::storage]
+	#[pallet::getter(fn streams)]
+	pub type Streams<T: Config> = StorageMap<_, Twox64Concat, StreamId, Stream<T::AccountId>, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a stream from the caller to `recipient`, the whole deposit
+		/// `rate_per_second * (stop_time - start_time)` is reserved from the caller.
+		///
+		/// - `recipient`: the payee of the stream.
+		/// - `currency_id`: the streamed currency.
+		/// - `rate_per_second`: the amount streamed per second.
+		/// - `start_time`: the unix time in seconds the stream starts, can't be in the past.
+		/// - `stop_time`: the unix time in seconds the stream stops.
+		#[pallet::weight(T::WeightInfo::create_stream())]
+		#[transactional]
+		pub fn create_stream(
+			origin: OriginFor<T>,
+			recipient: <T::Lookup as StaticLookup>::Source,
+			currency_id: CurrencyId,
+			#[pallet::compact] rate_per_second: Balance,
+			start_time: u64,
+			stop_time: u64,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let recipient = T::Lookup::lookup(recipient)?;
+			Self::do_create_stream(sender, recipient, currency_id, rate_per_second, start_time, stop_time)?;
+			Ok(())
+		}
+
+		/// Withdraw all the streamed amount of the stream to the recipient. Only the recipient
+		/// could withdraw.
+		///
+		/// - `stream_id`: the stream to withdraw from.
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		#[transactional]
+		pub fn withdraw(origin: OriginFor<T>, stream_id: StreamId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_withdraw(&who, stream_id)?;
+			Ok(())
+		}
+
+		/// Cancel the stream, the streamed but not withdrawn amount is paid to the recipient and
+		/// the rest of the deposit is refunded to the sender. Either the sender or the recipient
+		/// could cancel.
+		///
+		/// - `stream_id`: the stream to cancel.
+		#[pallet::weight(T::WeightInfo::cancel_stream())]
+		#[transactional]
+		pub fn cancel_stream(origin: OriginFor<T>, stream_id: StreamId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_cancel_stream(&who, stream_id)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The current unix time in seconds.
+	fn now() -> u64 {
+		T::UnixTime::now().as_secs()
+	}
+
+	/// The amount the recipient could withdraw from the stream now.
+	pub fn withdrawable_amount(stream_id: StreamId) -> Balance {
+		Self::streams(stream_id).map_or(Zero::zero(), |stream| stream.withdrawable_amount(Self::now()))
+	}
+
+	fn do_create_stream(
+		sender: T::AccountId,
+		recipient: T::AccountId,
+		currency_id: CurrencyId,
+		rate_per_second: Balance,
+		start_time: u64,
+		stop_time: u64,
+	) -> Result<StreamId, DispatchError> {
+		ensure!(sender != recipient, Error::<T>::InvalidRecipient);
+		ensure!(stop_time > start_time, Error::<T>::InvalidTimeRange);
+		ensure!(start_time >= Self::now(), Error::<T>::StartTimeInPast);
+		ensure!(!rate_per_second.is_zero(), Error::<T>::ZeroRate);
+
+		let deposit = rate_per_second
+			.checked_mul((stop_time - start_time).into())
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			deposit >= T::MinimumDeposit::get(&currency_id),
+			Error::<T>::DepositTooLow
+		);
+
+		let stream_id = NextStreamId::<T>::try_mutate(|id| -> Result<StreamId, DispatchError> {
+			let current_id = *id;
+			*id = id.checked_add(1).ok_or(Error::<T>::NoAvailableStreamId)?;
+			Ok(current_id)
+		})?;
+
+		T::Currency::reserve_named(&RESERVE_ID, currency_id, &sender, deposit)?;
+		Streams::<T>::insert(
+			stream_id,
+			Stream {
+				sender: sender.clone(),
+				recipient: recipient.clone(),
+				currency_id,
+				rate_per_second,
+				start_time,
+				stop_time,
+				deposit,
+				withdrawn: Zero::zero(),
+			},
+		);
+
+		Self::deposit_event(Event::StreamCreated {
+			stream_id,
+			sender,
+			recipient,
+			currency_id,
+			rate_per_second,
+			start_time,
+			stop_time,
+			deposit,
+		});
+		Ok(stream_id)
+	}
+
+	fn do_withdraw(who: &T::AccountId, stream_id: StreamId) -> Result<Balance, DispatchError> {
+		let now = Self::now();
+		let mut stream = Self::streams(stream_id).ok_or(Error::<T>::StreamNotFound)?;
+		ensure!(*who == stream.recipient, Error::<T>::NoPermission);
+
+		let amount = stream.withdrawable_amount(now);
+		ensure!(!amount.is_zero(), Error::<T>::NothingToWithdraw);
+
+		Self::pay(&stream, &stream.recipient, amount)?;
+		stream.withdrawn = stream.withdrawn.saturating_add(amount);
+
+		Self::deposit_event(Event::Withdrawn {
+			stream_id,
+			recipient: stream.recipient.clone(),
+			amount,
+		});
+
+		if stream.withdrawn >= stream.deposit {
+			Streams::<T>::remove(stream_id);
+			Self::deposit_event(Event::StreamCompleted { stream_id });
+		} else {
+			Streams::<T>::insert(stream_id, stream);
+		}
+		Ok(amount)
+	}
+
+	fn do_cancel_stream(who: &T::AccountId, stream_id: StreamId) -> DispatchResult {
+		let stream = Self::streams(stream_id).ok_or(Error::<T>::StreamNotFound)?;
+		ensure!(
+			*who == stream.sender || *who == stream.recipient,
+			Error::<T>::NoPermission
+		);
+
+		let recipient_amount = stream.withdrawable_amount(Self::now());
+		let sender_refund = stream
+			.deposit
+			.saturating_sub(stream.withdrawn)
+			.saturating_sub(recipient_amount);
+
+		Self::pay(&stream, &stream.recipient, recipient_amount)?;
+		Self::pay(&stream, &stream.sender, sender_refund)?;
+		Streams::<T>::remove(stream_id);
+
+		Self::deposit_event(Event::StreamCancelled {
+			stream_id,
+			recipient_amount,
+			sender_refund,
+		});
+		Ok(())
+	}
+
+	/// Pay `amount` from the reserved deposit of the stream to `beneficiary`, the amount is only
+	/// unreserved if `beneficiary` is the sender.
+	fn pay(stream: &Stream<T::AccountId>, beneficiary: &T::AccountId, amount: Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(());
+		}
+		let remaining = T::Currency::unreserve_named(&RESERVE_ID, stream.currency_id, &stream.sender, amount);
+		ensure!(remaining.is_zero(), Error::<T>::ReservedBalanceTooLow);
+
+		// `repatriate_reserved_named` requires the beneficiary to exist for the native currency,
+		// unreserve and transfer instead.
+		if *beneficiary != stream.sender {
+			T::Currency::transfer(stream.currency_id, &stream.sender, beneficiary, amount)?;
+		}
+		Ok(())
+	}
+}
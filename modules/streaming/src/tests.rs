@@ -0,0 +1,206 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Unit tests for the streaming module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::{Event, *};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+
+fn set_now(secs: u64) {
+	Timestamp::set_timestamp(secs * 1_000);
+}
+
+fn reserved_deposit(who: AccountId) -> Balance {
+	Tokens::reserved_balance_named(&RESERVE_ID, DOT, &who)
+}
+
+fn create_stream(rate_per_second: Balance, start_time: u64, stop_time: u64) -> DispatchResult {
+	StreamingModule::create_stream(Origin::signed(ALICE), BOB, DOT, rate_per_second, start_time, stop_time)
+}
+
+#[test]
+fn streamed_amount_works() {
+	let stream = Stream {
+		sender: ALICE,
+		recipient: BOB,
+		currency_id: DOT,
+		rate_per_second: 2,
+		start_time: 100,
+		stop_time: 200,
+		deposit: 200,
+		withdrawn: 50,
+	};
+	assert_eq!(stream.streamed_amount(0), 0);
+	assert_eq!(stream.streamed_amount(100), 0);
+	assert_eq!(stream.streamed_amount(150), 100);
+	assert_eq!(stream.streamed_amount(200), 200);
+	assert_eq!(stream.streamed_amount(u64::MAX), 200);
+	assert_eq!(stream.withdrawable_amount(100), 0);
+	assert_eq!(stream.withdrawable_amount(150), 50);
+	assert_eq!(stream.withdrawable_amount(300), 150);
+}
+
+#[test]
+fn create_stream_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(create_stream(2, 1_010, 1_110));
+		System::assert_last_event(Event::StreamingModule(crate::Event::StreamCreated {
+			stream_id: 0,
+			sender: ALICE,
+			recipient: BOB,
+			currency_id: DOT,
+			rate_per_second: 2,
+			start_time: 1_010,
+			stop_time: 1_110,
+			deposit: 200,
+		}));
+		assert_eq!(
+			StreamingModule::streams(0),
+			Some(Stream {
+				sender: ALICE,
+				recipient: BOB,
+				currency_id: DOT,
+				rate_per_second: 2,
+				start_time: 1_010,
+				stop_time: 1_110,
+				deposit: 200,
+				withdrawn: 0,
+			})
+		);
+		assert_eq!(StreamingModule::next_stream_id(), 1);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 800);
+		assert_eq!(reserved_deposit(ALICE), 200);
+
+		// starts now
+		assert_ok!(create_stream(1, 1_000, 1_010));
+		assert_eq!(StreamingModule::next_stream_id(), 2);
+		assert_eq!(reserved_deposit(ALICE), 210);
+	});
+}
+
+#[test]
+fn create_stream_fails_if_invalid_params() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			StreamingModule::create_stream(Origin::signed(ALICE), ALICE, DOT, 2, 1_010, 1_110),
+			Error::<Runtime>::InvalidRecipient
+		);
+		assert_noop!(create_stream(2, 1_010, 1_010), Error::<Runtime>::InvalidTimeRange);
+		assert_noop!(create_stream(2, 999, 1_110), Error::<Runtime>::StartTimeInPast);
+		assert_noop!(create_stream(0, 1_010, 1_110), Error::<Runtime>::ZeroRate);
+		assert_noop!(create_stream(1, 1_010, 1_015), Error::<Runtime>::DepositTooLow);
+		assert_noop!(create_stream(u128::MAX, 1_010, 1_012), ArithmeticError::Overflow);
+		assert_noop!(
+			create_stream(100, 1_010, 1_110),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn withdraw_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(create_stream(2, 1_010, 1_110));
+
+		assert_noop!(
+			StreamingModule::withdraw(Origin::signed(BOB), 0),
+			Error::<Runtime>::NothingToWithdraw
+		);
+		assert_noop!(
+			StreamingModule::withdraw(Origin::signed(BOB), 1),
+			Error::<Runtime>::StreamNotFound
+		);
+
+		set_now(1_060);
+		assert_eq!(StreamingModule::withdrawable_amount(0), 100);
+		assert_noop!(
+			StreamingModule::withdraw(Origin::signed(ALICE), 0),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(StreamingModule::withdraw(Origin::signed(BOB), 0));
+		System::assert_last_event(Event::StreamingModule(crate::Event::Withdrawn {
+			stream_id: 0,
+			recipient: BOB,
+			amount: 100,
+		}));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 100);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 800);
+		assert_eq!(reserved_deposit(ALICE), 100);
+		assert_eq!(StreamingModule::streams(0).unwrap().withdrawn, 100);
+		assert_eq!(StreamingModule::withdrawable_amount(0), 0);
+
+		// the stream is removed once fully withdrawn
+		set_now(1_200);
+		assert_ok!(StreamingModule::withdraw(Origin::signed(BOB), 0));
+		System::assert_last_event(Event::StreamingModule(crate::Event::StreamCompleted { stream_id: 0 }));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 200);
+		assert_eq!(reserved_deposit(ALICE), 0);
+		assert_eq!(StreamingModule::streams(0), None);
+	});
+}
+
+#[test]
+fn cancel_stream_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(create_stream(2, 1_010, 1_110));
+
+		set_now(1_035);
+		assert_ok!(StreamingModule::withdraw(Origin::signed(BOB), 0));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 50);
+
+		set_now(1_060);
+		assert_noop!(
+			StreamingModule::cancel_stream(Origin::signed(CHARLIE), 0),
+			Error::<Runtime>::NoPermission
+		);
+		assert_ok!(StreamingModule::cancel_stream(Origin::signed(ALICE), 0));
+		System::assert_last_event(Event::StreamingModule(crate::Event::StreamCancelled {
+			stream_id: 0,
+			recipient_amount: 50,
+			sender_refund: 100,
+		}));
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 100);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 900);
+		assert_eq!(reserved_deposit(ALICE), 0);
+		assert_eq!(StreamingModule::streams(0), None);
+		assert_noop!(
+			StreamingModule::cancel_stream(Origin::signed(ALICE), 0),
+			Error::<Runtime>::StreamNotFound
+		);
+	});
+}
+
+#[test]
+fn recipient_cancel_before_start_refunds_all() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(create_stream(2, 1_010, 1_110));
+		assert_ok!(StreamingModule::cancel_stream(Origin::signed(BOB), 0));
+		System::assert_last_event(Event::StreamingModule(crate::Event::StreamCancelled {
+			stream_id: 0,
+			recipient_amount: 0,
+			sender_refund: 200,
+		}));
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 1_000);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 0);
+		assert_eq!(reserved_deposit(ALICE), 0);
+	});
+}
@@ -37,6 +37,16 @@ pub trait PrecompileCallerFilter {
 	fn is_allowed(caller: H160) -> bool;
 }
 
+/// Return true if the EVM call to `target` with `input` is paused.
+pub trait EvmCallFilter {
+	fn is_paused(target: &EvmAddress, input: &[u8]) -> bool;
+}
+impl EvmCallFilter for () {
+	fn is_paused(_target: &EvmAddress, _input: &[u8]) -> bool {
+		false
+	}
+}
+
 /// An abstraction of EVM for EVMBridge
 pub trait EVM<AccountId> {
 	type Balance: AtLeast32BitUnsigned + Copy + MaybeSerializeDeserialize + Default;
@@ -187,6 +197,18 @@ pub trait EVMAccountsManager<AccountId> {
 	fn claim_default_evm_address(account_id: &AccountId) -> Result<EvmAddress, DispatchError>;
 }
 
+/// Migrate the EVM account state when an account is relinked to another EvmAddress.
+pub trait EVMAccountMigration {
+	/// Migrate the EVM nonce of `from` into `to`.
+	fn migrate_nonce(from: &EvmAddress, to: &EvmAddress) -> DispatchResult;
+}
+
+impl EVMAccountMigration for () {
+	fn migrate_nonce(_from: &EvmAddress, _to: &EvmAddress) -> DispatchResult {
+		Ok(())
+	}
+}
+
 /// A mapping between `AccountId` and `EvmAddress`.
 pub trait AddressMapping<AccountId> {
 	/// Returns the AccountId used go generate the given EvmAddress.
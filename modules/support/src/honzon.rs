@@ -79,6 +79,21 @@ pub trait AuctionManager<AccountId> {
 	fn get_total_target_in_auction() -> Self::Balance;
 }
 
+/// An abstraction of auction manager for the NFT collaterals.
+pub trait NFTAuctionManager<AccountId> {
+	type ClassId;
+	type TokenId;
+	type Balance;
+
+	fn new_nft_auction(
+		refund_recipient: &AccountId,
+		token: (Self::ClassId, Self::TokenId),
+		target: Self::Balance,
+		reserve_price: Self::Balance,
+	) -> DispatchResult;
+	fn get_total_target_in_nft_auction() -> Self::Balance;
+}
+
 /// An abstraction of cdp treasury for Honzon Protocol.
 pub trait CDPTreasury<AccountId> {
 	type Balance;
@@ -17,14 +17,17 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::Rate;
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use primitives::CurrencyId;
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{DispatchResult, RuntimeDebug};
 use sp_std::prelude::*;
 
 /// PoolId for various rewards pools
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PoolId {
 	/// Rewards and shares pool for users who open CDP(CollateralCurrencyId)
 	Loans(CurrencyId),
@@ -48,6 +51,8 @@ pub trait IncentivesManager<AccountId, Balance, CurrencyId, PoolId> {
 	fn get_claim_reward_deduction_rate(pool_id: PoolId) -> Rate;
 	/// Gets the pending rewards for a pool, for an account
 	fn get_pending_rewards(pool_id: PoolId, who: AccountId, reward_currency: Vec<CurrencyId>) -> Vec<Balance>;
+	/// Gets the share amount of an account in a pool
+	fn get_shares(pool_id: PoolId, who: AccountId) -> Balance;
 }
 
 pub trait DEXIncentives<AccountId, CurrencyId, Balance> {
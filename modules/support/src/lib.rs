@@ -23,7 +23,10 @@
 
 use codec::FullCodec;
 use frame_support::pallet_prelude::{DispatchClass, Pays, Weight};
-use primitives::{task::TaskResult, Balance, CurrencyId, Multiplier, ReserveIdentifier};
+use primitives::{
+	task::{TaskPriority, TaskResult},
+	Balance, CurrencyId, Multiplier, ReserveIdentifier,
+};
 use sp_runtime::{
 	traits::CheckedDiv, transaction_validity::TransactionValidityError, DispatchError, DispatchResult, FixedU128,
 };
@@ -37,6 +40,8 @@ pub mod honzon;
 pub mod incentives;
 pub mod mocks;
 pub mod stable_asset;
+pub mod vault;
+pub mod vesting;
 
 pub use crate::dex::*;
 pub use crate::evm::*;
@@ -44,6 +49,8 @@ pub use crate::homa::*;
 pub use crate::honzon::*;
 pub use crate::incentives::*;
 pub use crate::stable_asset::*;
+pub use crate::vault::*;
+pub use crate::vesting::*;
 
 pub type Price = FixedU128;
 pub type ExchangeRate = FixedU128;
@@ -94,6 +101,19 @@ pub trait TransactionPayment<AccountId, Balance, NegativeImbalance> {
 	fn apply_multiplier_to_fee(fee: Balance, multiplier: Option<Multiplier>) -> Balance;
 }
 
+/// The dynamic base fee of calls, charged by the transaction fee path instead of the adjusted
+/// weight fee when it's higher.
+pub trait DynamicBaseFee<Call> {
+	/// The base fee per weight of `call`, `None` if the call isn't priced by a base fee.
+	fn base_fee_per_weight(call: &Call) -> Option<Multiplier>;
+}
+
+impl<Call> DynamicBaseFee<Call> for () {
+	fn base_fee_per_weight(_: &Call) -> Option<Multiplier> {
+		None
+	}
+}
+
 /// Used to interface with the Compound's Cash module
 pub trait CompoundCashTrait<Balance, Moment> {
 	fn set_future_yield(next_cash_yield: Balance, yield_index: u128, timestamp_effective: Moment) -> DispatchResult;
@@ -148,6 +168,17 @@ pub trait CallBuilder {
 /// Dispatchable tasks
 pub trait DispatchableTask {
 	fn dispatch(self, weight: Weight) -> TaskResult;
+
+	/// The priority of the task, higher priority tasks are dispatched first.
+	fn priority(&self) -> TaskPriority {
+		TaskPriority::Normal
+	}
+
+	/// The max weight used by one dispatch of the task, the task is not dispatched until enough
+	/// weight remains. Zero means the task can make progress with any weight.
+	fn max_weight(&self) -> Weight {
+		0
+	}
 }
 
 /// Idle scheduler trait
@@ -169,6 +200,23 @@ impl<Task> IdleScheduler<Task> for () {
 	}
 }
 
+/// Circuit breaker trait, the operations on a tripped key are paused until the circuit breaker
+/// is reset.
+pub trait CircuitBreaker<Key> {
+	/// Whether the circuit breaker of `key` is tripped.
+	fn is_tripped(key: &Key) -> bool;
+	/// Trip the circuit breaker of `key`, it's reset automatically after a period.
+	fn trip(key: &Key);
+}
+
+impl<Key> CircuitBreaker<Key> for () {
+	fn is_tripped(_key: &Key) -> bool {
+		false
+	}
+
+	fn trip(_key: &Key) {}
+}
+
 #[impl_trait_for_tuples::impl_for_tuples(30)]
 pub trait OnNewEra<EraIndex> {
 	fn on_new_era(era: EraIndex);
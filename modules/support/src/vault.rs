@@ -0,0 +1,70 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use primitives::currency::{AssetMetadata, VaultId};
+use sp_runtime::{DispatchError, DispatchResult};
+
+/// Tokenized vaults following the ERC-4626 interface, a vault takes deposits of the
+/// underlying currency and mints share tokens representing the claim on the underlying.
+pub trait VaultManager<AccountId, CurrencyId, Balance> {
+	/// The underlying currency of the vault.
+	fn asset(vault_id: VaultId) -> Option<CurrencyId>;
+	/// Total amount of the underlying currency managed by the vault.
+	fn total_assets(vault_id: VaultId) -> Balance;
+	/// Amount of shares exchanged for `assets`, without fees and rounding down.
+	fn convert_to_shares(vault_id: VaultId, assets: Balance) -> Balance;
+	/// Amount of assets exchanged for `shares`, without fees and rounding down.
+	fn convert_to_assets(vault_id: VaultId, shares: Balance) -> Balance;
+	/// Max amount of assets can be deposited for `receiver`.
+	fn max_deposit(vault_id: VaultId, receiver: &AccountId) -> Balance;
+	/// Max amount of shares can be minted for `receiver`.
+	fn max_mint(vault_id: VaultId, receiver: &AccountId) -> Balance;
+	/// Max amount of assets can be withdrawn by `owner`.
+	fn max_withdraw(vault_id: VaultId, owner: &AccountId) -> Balance;
+	/// Max amount of shares can be redeemed by `owner`.
+	fn max_redeem(vault_id: VaultId, owner: &AccountId) -> Balance;
+	/// Amount of shares minted by depositing `assets`.
+	fn preview_deposit(vault_id: VaultId, assets: Balance) -> Balance;
+	/// Amount of assets required for minting `shares`.
+	fn preview_mint(vault_id: VaultId, shares: Balance) -> Balance;
+	/// Amount of shares burned by withdrawing `assets`.
+	fn preview_withdraw(vault_id: VaultId, assets: Balance) -> Balance;
+	/// Amount of assets returned by redeeming `shares`.
+	fn preview_redeem(vault_id: VaultId, shares: Balance) -> Balance;
+	/// Deposit `assets` and mint shares to `who`, returns the minted shares.
+	fn deposit(who: &AccountId, vault_id: VaultId, assets: Balance) -> Result<Balance, DispatchError>;
+	/// Mint exactly `shares` to `who` by depositing assets, returns the deposited assets.
+	fn mint(who: &AccountId, vault_id: VaultId, shares: Balance) -> Result<Balance, DispatchError>;
+	/// Withdraw `assets` to `who` by burning shares, returns the burned shares.
+	fn withdraw(who: &AccountId, vault_id: VaultId, assets: Balance) -> Result<Balance, DispatchError>;
+	/// Redeem exactly `shares` of `who` for assets, returns the withdrawn assets.
+	fn redeem(who: &AccountId, vault_id: VaultId, shares: Balance) -> Result<Balance, DispatchError>;
+}
+
+/// Registry of the metadata of vault share tokens.
+pub trait VaultShareRegistry<Balance> {
+	/// Register the metadata of the share token of `vault_id`.
+	fn register_vault_share(vault_id: VaultId, metadata: &AssetMetadata<Balance>) -> DispatchResult;
+}
+
+#[cfg(feature = "std")]
+impl<Balance> VaultShareRegistry<Balance> for () {
+	fn register_vault_share(_: VaultId, _: &AssetMetadata<Balance>) -> DispatchResult {
+		Ok(())
+	}
+}
@@ -0,0 +1,100 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, HasCompact, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32Bit, AtLeast32BitUnsigned, UniqueSaturatedInto, Zero},
+	DispatchError, DispatchResult, RuntimeDebug,
+};
+
+/// The vesting schedule.
+///
+/// Benefits are granted gradually, `per_period` amount every `period` of blocks after `start`.
+/// Nothing can be claimed before the cliff, `start + cliff`.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct VestingSchedule<BlockNumber, Balance: HasCompact + MaxEncodedLen> {
+	/// Vesting starting block.
+	pub start: BlockNumber,
+	/// Number of blocks after `start` that nothing is vested.
+	pub cliff: BlockNumber,
+	/// Number of blocks between vest.
+	pub period: BlockNumber,
+	/// Number of vest.
+	pub period_count: u32,
+	/// Amount of tokens to release per vest.
+	#[codec(compact)]
+	pub per_period: Balance,
+}
+
+impl<BlockNumber: AtLeast32Bit + Copy, Balance: AtLeast32BitUnsigned + MaxEncodedLen + Copy>
+	VestingSchedule<BlockNumber, Balance>
+{
+	/// Returns the end of all periods, `None` if calculation overflows.
+	pub fn end(&self) -> Option<BlockNumber> {
+		// period * period_count + start
+		self.period
+			.checked_mul(&self.period_count.into())?
+			.checked_add(&self.start)
+	}
+
+	/// Returns the end of the cliff, `None` if calculation overflows.
+	pub fn cliff_end(&self) -> Option<BlockNumber> {
+		self.start.checked_add(&self.cliff)
+	}
+
+	/// Returns all locked amount, `None` if calculation overflows.
+	pub fn total_amount(&self) -> Option<Balance> {
+		self.per_period.checked_mul(&self.period_count.into())
+	}
+
+	/// Returns locked amount for a given `time`.
+	///
+	/// Note this func assumes schedule is a valid one(non-zero period and non-overflow total
+	/// amount), and it should be guaranteed by callers.
+	pub fn locked_amount(&self, time: BlockNumber) -> Balance {
+		if time < self.start.saturating_add(self.cliff) {
+			return self.per_period.saturating_mul(self.period_count.into());
+		}
+
+		// full = (time - start) / period
+		// unrealized = period_count - full
+		// per_period * unrealized
+		let full = time
+			.saturating_sub(self.start)
+			.checked_div(&self.period)
+			.unwrap_or_else(Zero::zero);
+		let unrealized = self.period_count.saturating_sub(full.unique_saturated_into());
+		self.per_period.saturating_mul(unrealized.into())
+	}
+}
+
+/// Vesting schedules of any currency.
+pub trait VestingManager<AccountId, CurrencyId, Balance: HasCompact + MaxEncodedLen, BlockNumber> {
+	/// Transfer the total amount of `schedule` from `from` to `to`, and lock it by the schedule.
+	fn vested_transfer(
+		from: &AccountId,
+		to: &AccountId,
+		currency_id: CurrencyId,
+		schedule: VestingSchedule<BlockNumber, Balance>,
+	) -> DispatchResult;
+	/// Unlock the vested amount of `who`, returns the amount still locked.
+	fn claim(who: &AccountId, currency_id: CurrencyId) -> Result<Balance, DispatchError>;
+	/// The amount of `who` locked by the vesting schedules at current block.
+	fn locked_balance(who: &AccountId, currency_id: CurrencyId) -> Balance;
+}
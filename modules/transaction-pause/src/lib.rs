@@ -26,8 +26,10 @@ use frame_support::{
 	transactional,
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::DispatchResult;
+use primitives::evm::EvmAddress;
+use sp_runtime::{traits::Saturating, DispatchResult};
 use sp_std::{prelude::*, vec::Vec};
+use support::{CircuitBreaker, EvmCallFilter};
 
 mod mock;
 mod tests;
@@ -36,6 +38,22 @@ pub mod weights;
 pub use module::*;
 pub use weights::WeightInfo;
 
+/// The function name to pause all calls of a pallet.
+pub const ALL_FUNCTIONS: &[u8] = b"*";
+
+/// The target can be paused.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum PauseTarget {
+	/// The calls of (PalletNameBytes, FunctionNameBytes), `ALL_FUNCTIONS` pauses all calls of the
+	/// pallet.
+	Transaction(Vec<u8>, Vec<u8>),
+	/// The EVM calls to the contract with the function selector, `None` pauses all calls to the
+	/// contract.
+	EvmCall(EvmAddress, Option<[u8; 4]>),
+	/// The operations on the encoded key of the circuit breaker.
+	CircuitBreaker(Vec<u8>),
+}
+
 #[frame_support::pallet]
 pub mod module {
 	use super::*;
@@ -47,6 +65,10 @@ pub mod module {
 		/// The origin which may set filter.
 		type UpdateOrigin: EnsureOrigin<Self::Origin>;
 
+		/// The period a tripped circuit breaker is reset after.
+		#[pallet::constant]
+		type CircuitBreakerDuration: Get<Self::BlockNumber>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -57,6 +79,8 @@ pub mod module {
 		CannotPause,
 		/// invalid character encoding
 		InvalidCharacter,
+		/// the expiry block is invalid
+		InvalidExpiry,
 	}
 
 	#[pallet::event]
@@ -72,6 +96,20 @@ pub mod module {
 			pallet_name_bytes: Vec<u8>,
 			function_name_bytes: Vec<u8>,
 		},
+		/// Paused EVM call
+		EvmCallPaused {
+			contract: EvmAddress,
+			selector: Option<[u8; 4]>,
+		},
+		/// Unpaused EVM call
+		EvmCallUnpaused {
+			contract: EvmAddress,
+			selector: Option<[u8; 4]>,
+		},
+		/// Circuit breaker tripped
+		CircuitBreakerTripped { key: Vec<u8> },
+		/// Circuit breaker reset
+		CircuitBreakerReset { key: Vec<u8> },
 	}
 
 	/// The paused transaction map
@@ -81,12 +119,52 @@ pub mod module {
 	#[pallet::getter(fn paused_transactions)]
 	pub type PausedTransactions<T: Config> = StorageMap<_, Twox64Concat, (Vec<u8>, Vec<u8>), (), OptionQuery>;
 
+	/// The paused EVM calls, `None` selector means all calls to the contract are paused.
+	///
+	/// PausedEvmCalls: double_map EvmAddress, Option<Selector> => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_evm_calls)]
+	pub type PausedEvmCalls<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, EvmAddress, Twox64Concat, Option<[u8; 4]>, (), OptionQuery>;
+
+	/// The tripped circuit breakers.
+	///
+	/// TrippedCircuitBreakers: map KeyBytes => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn tripped_circuit_breakers)]
+	pub type TrippedCircuitBreakers<T: Config> = StorageMap<_, Twox64Concat, Vec<u8>, (), OptionQuery>;
+
+	/// The block number the paused target is unpaused at.
+	///
+	/// PauseExpiries: map PauseTarget => Option<BlockNumber>
+	#[pallet::storage]
+	#[pallet::getter(fn pause_expiries)]
+	pub type PauseExpiries<T: Config> = StorageMap<_, Twox64Concat, PauseTarget, T::BlockNumber, OptionQuery>;
+
+	/// The paused targets to be unpaused at the block.
+	///
+	/// ExpiringPauses: double_map BlockNumber, PauseTarget => Option<()>
+	#[pallet::storage]
+	#[pallet::getter(fn expiring_pauses)]
+	pub type ExpiringPauses<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Twox64Concat, PauseTarget, (), OptionQuery>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let expired = ExpiringPauses::<T>::drain_prefix(now)
+				.map(|(target, _)| target)
+				.collect::<Vec<_>>();
+			for target in expired.iter() {
+				Self::do_unpause(target);
+			}
+			T::WeightInfo::on_initialize(expired.len() as u32)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -94,23 +172,8 @@ pub mod module {
 		#[transactional]
 		pub fn pause_transaction(origin: OriginFor<T>, pallet_name: Vec<u8>, function_name: Vec<u8>) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
-
-			// not allowed to pause calls of this pallet to ensure safe
-			let pallet_name_string = sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
-			ensure!(
-				pallet_name_string != <Self as PalletInfoAccess>::name(),
-				Error::<T>::CannotPause
-			);
-
-			PausedTransactions::<T>::mutate_exists((pallet_name.clone(), function_name.clone()), |maybe_paused| {
-				if maybe_paused.is_none() {
-					*maybe_paused = Some(());
-					Self::deposit_event(Event::TransactionPaused {
-						pallet_name_bytes: pallet_name,
-						function_name_bytes: function_name,
-					});
-				}
-			});
+			Self::ensure_can_pause(&pallet_name)?;
+			Self::do_pause(&PauseTarget::Transaction(pallet_name, function_name), None);
 			Ok(())
 		}
 
@@ -122,17 +185,181 @@ pub mod module {
 			function_name: Vec<u8>,
 		) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
-			if PausedTransactions::<T>::take((&pallet_name, &function_name)).is_some() {
-				Self::deposit_event(Event::TransactionUnpaused {
+			Self::do_unpause(&PauseTarget::Transaction(pallet_name, function_name));
+			Ok(())
+		}
+
+		/// Pause the transaction until the `expire_at` block, then it's unpaused automatically.
+		/// `ALL_FUNCTIONS` as `function_name` pauses all calls of the pallet.
+		#[pallet::weight(T::WeightInfo::pause_transaction_until())]
+		#[transactional]
+		pub fn pause_transaction_until(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+			expire_at: T::BlockNumber,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::ensure_can_pause(&pallet_name)?;
+			Self::ensure_valid_expiry(Some(expire_at))?;
+			Self::do_pause(&PauseTarget::Transaction(pallet_name, function_name), Some(expire_at));
+			Ok(())
+		}
+
+		/// Pause the EVM calls to `contract` with the function `selector`, or all calls to
+		/// `contract` if `selector` is `None`. The calls are unpaused automatically at the
+		/// `expire_at` block if it's set.
+		#[pallet::weight(T::WeightInfo::pause_evm_call())]
+		#[transactional]
+		pub fn pause_evm_call(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			selector: Option<[u8; 4]>,
+			expire_at: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_expiry(expire_at)?;
+			Self::do_pause(&PauseTarget::EvmCall(contract, selector), expire_at);
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::unpause_evm_call())]
+		#[transactional]
+		pub fn unpause_evm_call(
+			origin: OriginFor<T>,
+			contract: EvmAddress,
+			selector: Option<[u8; 4]>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_unpause(&PauseTarget::EvmCall(contract, selector));
+			Ok(())
+		}
+
+		/// Reset the tripped circuit breaker of the encoded `key` before it expires.
+		#[pallet::weight(T::WeightInfo::reset_circuit_breaker())]
+		#[transactional]
+		pub fn reset_circuit_breaker(origin: OriginFor<T>, key: Vec<u8>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_unpause(&PauseTarget::CircuitBreaker(key));
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Whether the EVM call to `contract` with `input` is paused.
+	pub fn is_evm_call_paused(contract: &EvmAddress, input: &[u8]) -> bool {
+		PausedEvmCalls::<T>::contains_key(contract, Option::<[u8; 4]>::None)
+			|| input
+				.get(0..4)
+				.and_then(|selector| <[u8; 4]>::try_from(selector).ok())
+				.map_or(false, |selector| {
+					PausedEvmCalls::<T>::contains_key(contract, Some(selector))
+				})
+	}
+
+	fn ensure_can_pause(pallet_name: &[u8]) -> DispatchResult {
+		// not allowed to pause calls of this pallet to ensure safe
+		let pallet_name_string = sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		ensure!(
+			pallet_name_string != <Self as PalletInfoAccess>::name(),
+			Error::<T>::CannotPause
+		);
+		Ok(())
+	}
+
+	fn ensure_valid_expiry(expire_at: Option<T::BlockNumber>) -> DispatchResult {
+		if let Some(expire_at) = expire_at {
+			ensure!(
+				expire_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+		}
+		Ok(())
+	}
+
+	/// Pause the target, it's unpaused at the `expire_at` block if set, otherwise it's paused
+	/// until unpaused explicitly.
+	fn do_pause(target: &PauseTarget, expire_at: Option<T::BlockNumber>) {
+		let newly_paused = match target {
+			PauseTarget::Transaction(pallet_name, function_name) => {
+				PausedTransactions::<T>::mutate_exists((pallet_name, function_name), |maybe_paused| {
+					maybe_paused.replace(()).is_none()
+				})
+			}
+			PauseTarget::EvmCall(contract, selector) => {
+				PausedEvmCalls::<T>::mutate_exists(contract, selector, |maybe_paused| {
+					maybe_paused.replace(()).is_none()
+				})
+			}
+			PauseTarget::CircuitBreaker(key) => {
+				TrippedCircuitBreakers::<T>::mutate_exists(key, |maybe_paused| maybe_paused.replace(()).is_none())
+			}
+		};
+
+		if let Some(old_expire_at) = PauseExpiries::<T>::take(target) {
+			ExpiringPauses::<T>::remove(old_expire_at, target);
+		}
+		if let Some(expire_at) = expire_at {
+			PauseExpiries::<T>::insert(target, expire_at);
+			ExpiringPauses::<T>::insert(expire_at, target, ());
+		}
+
+		if newly_paused {
+			Self::deposit_event(match target.clone() {
+				PauseTarget::Transaction(pallet_name, function_name) => Event::TransactionPaused {
 					pallet_name_bytes: pallet_name,
 					function_name_bytes: function_name,
-				});
-			};
-			Ok(())
+				},
+				PauseTarget::EvmCall(contract, selector) => Event::EvmCallPaused { contract, selector },
+				PauseTarget::CircuitBreaker(key) => Event::CircuitBreakerTripped { key },
+			});
+		}
+	}
+
+	fn do_unpause(target: &PauseTarget) {
+		let paused = match target {
+			PauseTarget::Transaction(pallet_name, function_name) => {
+				PausedTransactions::<T>::take((pallet_name, function_name)).is_some()
+			}
+			PauseTarget::EvmCall(contract, selector) => PausedEvmCalls::<T>::take(contract, selector).is_some(),
+			PauseTarget::CircuitBreaker(key) => TrippedCircuitBreakers::<T>::take(key).is_some(),
+		};
+
+		if let Some(expire_at) = PauseExpiries::<T>::take(target) {
+			ExpiringPauses::<T>::remove(expire_at, target);
+		}
+
+		if paused {
+			Self::deposit_event(match target.clone() {
+				PauseTarget::Transaction(pallet_name, function_name) => Event::TransactionUnpaused {
+					pallet_name_bytes: pallet_name,
+					function_name_bytes: function_name,
+				},
+				PauseTarget::EvmCall(contract, selector) => Event::EvmCallUnpaused { contract, selector },
+				PauseTarget::CircuitBreaker(key) => Event::CircuitBreakerReset { key },
+			});
 		}
 	}
 }
 
+impl<T: Config, Key: Encode> CircuitBreaker<Key> for Pallet<T> {
+	fn is_tripped(key: &Key) -> bool {
+		TrippedCircuitBreakers::<T>::contains_key(key.encode())
+	}
+
+	fn trip(key: &Key) {
+		let expire_at = frame_system::Pallet::<T>::block_number().saturating_add(T::CircuitBreakerDuration::get());
+		Self::do_pause(&PauseTarget::CircuitBreaker(key.encode()), Some(expire_at));
+	}
+}
+
+impl<T: Config> EvmCallFilter for Pallet<T> {
+	fn is_paused(target: &EvmAddress, input: &[u8]) -> bool {
+		Self::is_evm_call_paused(target, input)
+	}
+}
+
 pub struct PausedTransactionFilter<T>(sp_std::marker::PhantomData<T>);
 impl<T: Config> Contains<T::Call> for PausedTransactionFilter<T>
 where
@@ -144,5 +371,6 @@ where
 			pallet_name,
 		} = call.get_call_metadata();
 		PausedTransactions::<T>::contains_key((pallet_name.as_bytes(), function_name.as_bytes()))
+			|| PausedTransactions::<T>::contains_key((pallet_name.as_bytes(), ALL_FUNCTIONS))
 	}
 }
@@ -107,6 +107,7 @@ ord_parameter_types! {
 impl Config for Runtime {
 	type Event = Event;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type CircuitBreakerDuration = ConstU64<10>;
 	type WeightInfo = ();
 }
 
@@ -23,7 +23,9 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok};
 use mock::{Event, *};
+use sp_core::H160;
 use sp_runtime::traits::BadOrigin;
+use support::CircuitBreaker;
 
 const BALANCE_TRANSFER: &<Runtime as frame_system::Config>::Call =
 	&mock::Call::Balances(pallet_balances::Call::transfer { dest: ALICE, value: 10 });
@@ -32,6 +34,8 @@ const TOKENS_TRANSFER: &<Runtime as frame_system::Config>::Call = &mock::Call::T
 	currency_id: AUSD,
 	amount: 10,
 });
+const BALANCE_TRANSFER_KEEP_ALIVE: &<Runtime as frame_system::Config>::Call =
+	&mock::Call::Balances(pallet_balances::Call::transfer_keep_alive { dest: ALICE, value: 10 });
 
 #[test]
 fn pause_transaction_work() {
@@ -152,3 +156,206 @@ fn paused_transaction_filter_work() {
 		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
 	});
 }
+
+#[test]
+fn pause_all_functions_of_pallet_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				Origin::signed(1),
+				b"TransactionPause".to_vec(),
+				ALL_FUNCTIONS.to_vec()
+			),
+			Error::<Runtime>::CannotPause
+		);
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"Balances".to_vec(),
+			ALL_FUNCTIONS.to_vec()
+		));
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(PausedTransactionFilter::<Runtime>::contains(
+			BALANCE_TRANSFER_KEEP_ALIVE
+		));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+
+		assert_ok!(TransactionPause::unpause_transaction(
+			Origin::signed(1),
+			b"Balances".to_vec(),
+			ALL_FUNCTIONS.to_vec()
+		));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(
+			BALANCE_TRANSFER_KEEP_ALIVE
+		));
+	});
+}
+
+#[test]
+fn pause_transaction_until_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let target = PauseTarget::Transaction(b"Balances".to_vec(), b"transfer".to_vec());
+
+		assert_noop!(
+			TransactionPause::pause_transaction_until(
+				Origin::signed(5),
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				10
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			TransactionPause::pause_transaction_until(Origin::signed(1), b"Balances".to_vec(), b"transfer".to_vec(), 1),
+			Error::<Runtime>::InvalidExpiry
+		);
+
+		assert_ok!(TransactionPause::pause_transaction_until(
+			Origin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			10
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPaused {
+			pallet_name_bytes: b"Balances".to_vec(),
+			function_name_bytes: b"transfer".to_vec(),
+		}));
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert_eq!(TransactionPause::pause_expiries(&target), Some(10));
+		assert_eq!(TransactionPause::expiring_pauses(10, &target), Some(()));
+
+		// extend the pause
+		assert_ok!(TransactionPause::pause_transaction_until(
+			Origin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			20
+		));
+		assert_eq!(TransactionPause::pause_expiries(&target), Some(20));
+		assert_eq!(TransactionPause::expiring_pauses(10, &target), None);
+		assert_eq!(TransactionPause::expiring_pauses(20, &target), Some(()));
+
+		TransactionPause::on_initialize(10);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		TransactionPause::on_initialize(20);
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionUnpaused {
+			pallet_name_bytes: b"Balances".to_vec(),
+			function_name_bytes: b"transfer".to_vec(),
+		}));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+		assert_eq!(TransactionPause::pause_expiries(&target), None);
+		assert_eq!(TransactionPause::expiring_pauses(20, &target), None);
+
+		// pause without expiry cancels the scheduled unpause
+		assert_ok!(TransactionPause::pause_transaction_until(
+			Origin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			30
+		));
+		assert_ok!(TransactionPause::pause_transaction(
+			Origin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec()
+		));
+		assert_eq!(TransactionPause::pause_expiries(&target), None);
+		TransactionPause::on_initialize(30);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+	});
+}
+
+#[test]
+fn pause_and_unpause_evm_call_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let contract = H160::from_low_u64_be(0x1000);
+		let other_contract = H160::from_low_u64_be(0x1001);
+		let transfer_input = [0xa9, 0x05, 0x9c, 0xbb, 0x00];
+		let approve_input = [0x09, 0x5e, 0xa7, 0xb3, 0x00];
+
+		assert_noop!(
+			TransactionPause::pause_evm_call(Origin::signed(5), contract, None, None),
+			BadOrigin
+		);
+
+		assert_ok!(TransactionPause::pause_evm_call(
+			Origin::signed(1),
+			contract,
+			Some([0xa9, 0x05, 0x9c, 0xbb]),
+			None
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::EvmCallPaused {
+			contract,
+			selector: Some([0xa9, 0x05, 0x9c, 0xbb]),
+		}));
+		assert!(TransactionPause::is_evm_call_paused(&contract, &transfer_input));
+		assert!(!TransactionPause::is_evm_call_paused(&contract, &approve_input));
+		assert!(!TransactionPause::is_evm_call_paused(&contract, &[]));
+		assert!(!TransactionPause::is_evm_call_paused(&other_contract, &transfer_input));
+
+		assert_ok!(TransactionPause::pause_evm_call(
+			Origin::signed(1),
+			contract,
+			None,
+			Some(10)
+		));
+		assert!(TransactionPause::is_evm_call_paused(&contract, &approve_input));
+		assert!(TransactionPause::is_evm_call_paused(&contract, &[]));
+
+		TransactionPause::on_initialize(10);
+		System::assert_last_event(Event::TransactionPause(crate::Event::EvmCallUnpaused {
+			contract,
+			selector: None,
+		}));
+		assert!(!TransactionPause::is_evm_call_paused(&contract, &approve_input));
+		assert!(TransactionPause::is_evm_call_paused(&contract, &transfer_input));
+
+		assert_ok!(TransactionPause::unpause_evm_call(
+			Origin::signed(1),
+			contract,
+			Some([0xa9, 0x05, 0x9c, 0xbb])
+		));
+		assert!(!TransactionPause::is_evm_call_paused(&contract, &transfer_input));
+	});
+}
+
+#[test]
+fn circuit_breaker_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let key = (AUSD, 1u32);
+
+		assert!(!<TransactionPause as CircuitBreaker<_>>::is_tripped(&key));
+		<TransactionPause as CircuitBreaker<_>>::trip(&key);
+		System::assert_last_event(Event::TransactionPause(crate::Event::CircuitBreakerTripped {
+			key: key.encode(),
+		}));
+		assert!(<TransactionPause as CircuitBreaker<_>>::is_tripped(&key));
+		assert!(!<TransactionPause as CircuitBreaker<_>>::is_tripped(&(AUSD, 2u32)));
+		assert_eq!(
+			TransactionPause::pause_expiries(PauseTarget::CircuitBreaker(key.encode())),
+			Some(11)
+		);
+
+		// reset automatically after `CircuitBreakerDuration`
+		TransactionPause::on_initialize(11);
+		System::assert_last_event(Event::TransactionPause(crate::Event::CircuitBreakerReset {
+			key: key.encode(),
+		}));
+		assert!(!<TransactionPause as CircuitBreaker<_>>::is_tripped(&key));
+
+		<TransactionPause as CircuitBreaker<_>>::trip(&key);
+		assert_noop!(
+			TransactionPause::reset_circuit_breaker(Origin::signed(5), key.encode()),
+			BadOrigin
+		);
+		assert_ok!(TransactionPause::reset_circuit_breaker(Origin::signed(1), key.encode()));
+		assert!(!<TransactionPause as CircuitBreaker<_>>::is_tripped(&key));
+		assert_eq!(
+			TransactionPause::pause_expiries(PauseTarget::CircuitBreaker(key.encode())),
+			None
+		);
+	});
+}
@@ -40,23 +40,28 @@ use frame_support::{
 	BoundedVec, PalletId,
 };
 use frame_system::pallet_prelude::*;
+use nutsfinance_stable_asset::{traits::StableAsset, RedeemProportionResult};
 use orml_traits::MultiCurrency;
 use pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo;
 use pallet_transaction_payment_rpc_runtime_api::{FeeDetails, InclusionFee};
-use primitives::{Balance, CurrencyId, Multiplier, ReserveIdentifier};
+use primitives::{evm::EvmAddress, Balance, CurrencyId, Multiplier, ReserveIdentifier};
 use scale_info::TypeInfo;
 use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
 	traits::{
-		AccountIdConversion, Convert, DispatchInfoOf, One, PostDispatchInfoOf, SaturatedConversion, Saturating,
-		SignedExtension, Zero,
+		AccountIdConversion, Convert, DispatchInfoOf, Extrinsic as ExtrinsicT, ExtrinsicCall, One, PostDispatchInfoOf,
+		SaturatedConversion, Saturating, SignedExtension, Zero,
 	},
 	transaction_validity::{
 		InvalidTransaction, TransactionPriority, TransactionValidity, TransactionValidityError, ValidTransaction,
 	},
-	FixedPointNumber, FixedPointOperand, MultiSignature, Percent, Perquintill,
+	ArithmeticError, FixedPointNumber, FixedPointOperand, MultiSignature, Percent, Perquintill,
 };
 use sp_std::prelude::*;
-use support::{AggregatedSwapPath, BuyWeightRate, PriceProvider, Ratio, Swap, SwapLimit, TransactionPayment};
+use support::{
+	AddressMapping, AggregatedSwapPath, BuyWeightRate, DEXManager, DynamicBaseFee, PriceProvider, Ratio, Swap,
+	SwapLimit, TransactionPayment,
+};
 use xcm::opaque::latest::MultiLocation;
 
 mod mock;
@@ -71,6 +76,72 @@ type NegativeImbalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 type CallOf<T> = <T as Config>::Call;
 
+/// The target of calls whose transaction fee can be sponsored by a paymaster.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum SponsoredTarget {
+	/// All calls of the pallet with this index.
+	Pallet(u8),
+	/// EVM calls to this contract.
+	Contract(EvmAddress),
+}
+
+/// Get the sponsored target of a call.
+pub trait SponsoredTargetOf<Call> {
+	fn sponsored_target(call: &Call) -> Option<SponsoredTarget>;
+}
+
+/// The sponsored target is the pallet of the call, the first byte of the encoded call is the
+/// pallet index.
+impl<Call: Encode> SponsoredTargetOf<Call> for () {
+	fn sponsored_target(call: &Call) -> Option<SponsoredTarget> {
+		call.using_encoded(|encoded| encoded.first().copied())
+			.map(SponsoredTarget::Pallet)
+	}
+}
+
+/// The paymaster sponsors the transaction fee of calls to its targets.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PaymasterInfo<BlockNumber> {
+	/// The remaining budget of the transaction fee to sponsor.
+	pub budget: Balance,
+	/// The max count of sponsored transactions for every user in a period. Zero means unlimited.
+	pub max_txs_per_period: u32,
+	/// The length of the rate limit period.
+	pub period: BlockNumber,
+	/// The paymaster stops sponsoring since this block.
+	pub expire_at: BlockNumber,
+}
+
+/// The way to get the native asset to pay the transaction fee.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum FeePaymentPath {
+	/// Pay with the native asset directly.
+	Native,
+	/// Swap from the charge fee pool of the currency at `TokenExchangeRate`.
+	FeePool,
+	/// Swap from DEX by the path, which is the `AlternativeFeeSwapPath` of the account.
+	Dex(Vec<CurrencyId>),
+	/// Swap by `with_fee_currency` from DEX or stable asset pools.
+	Swap,
+	/// Redeem the liquidity token and swap the underlying currency by `with_fee_currency`.
+	LiquidityToken,
+	/// Sponsored by the paymaster of the call, nothing is charged from the account.
+	Paymaster,
+}
+
+/// The transaction fee to be charged in a currency.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct CurrencyFeeDetails {
+	/// The currency to pay the fee.
+	pub currency_id: CurrencyId,
+	/// The amount of `currency_id` to be charged, including the fee surplus.
+	pub fee: Balance,
+	/// The way to get the native asset to pay the fee.
+	pub path: FeePaymentPath,
+	/// Whether the balance of the account is enough to pay the fee.
+	pub sufficient: bool,
+}
+
 /// A struct to update the weight multiplier per block. It implements
 /// `Convert<Multiplier, Multiplier>`, meaning that it can convert the
 /// previous multiplier to the next one. This should be called on
@@ -306,6 +377,18 @@ pub mod module {
 		/// Swap
 		type Swap: Swap<Self::AccountId, Balance, CurrencyId>;
 
+		/// DEX to redeem the DEX share when it's used to pay fee.
+		type DEX: DEXManager<Self::AccountId, Balance, CurrencyId>;
+
+		/// Stable asset to redeem the stable asset pool token when it's used to pay fee.
+		type StableAsset: StableAsset<
+			AssetId = CurrencyId,
+			AtLeast64BitUnsigned = Balance,
+			Balance = Balance,
+			AccountId = Self::AccountId,
+			BlockNumber = Self::BlockNumber,
+		>;
+
 		/// When swap with DEX, the acceptable max slippage for the price from oracle.
 		#[pallet::constant]
 		type MaxSwapSlippageCompareToOracle: Get<Ratio>;
@@ -342,6 +425,22 @@ pub mod module {
 
 		/// The origin which change swap balance threshold or enable charge fee pool.
 		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Mapping from address to account id.
+		type AddressMapping: AddressMapping<Self::AccountId>;
+
+		/// Get the sponsored target of a call to match the paymaster.
+		type SponsoredTargetOf: SponsoredTargetOf<CallOf<Self>>;
+
+		/// The max count of targets of a paymaster.
+		#[pallet::constant]
+		type MaxSponsoredTargets: Get<u32>;
+
+		/// The dynamic base fee of calls, e.g. the EVM base fee of EVM calls.
+		type DynamicBaseFee: DynamicBaseFee<CallOf<Self>>;
+
+		/// Handler for the dynamic base fee part of the transaction fee, burned if set to `()`.
+		type OnDynamicBaseFee: OnUnbalanced<NegativeImbalanceOf<Self>>;
 	}
 
 	#[pallet::type_value]
@@ -363,6 +462,20 @@ pub mod module {
 		DexNotAvailable,
 		/// Charge fee pool is already exist
 		ChargeFeePoolAlreadyExisted,
+		/// The currency is not a liquidity token or its pool is not available
+		InvalidLiquidityToken,
+		/// The paymaster is already registered
+		PaymasterAlreadyExisted,
+		/// The paymaster is not found
+		PaymasterNotFound,
+		/// The paymaster info is invalid
+		InvalidPaymasterInfo,
+		/// Too many or no sponsored targets
+		InvalidSponsoredTargets,
+		/// The target is already sponsored by another paymaster
+		SponsoredTargetAlreadyExisted,
+		/// No permission to sponsor the target or manage the paymaster
+		NoPermission,
 	}
 
 	#[pallet::event]
@@ -400,6 +513,25 @@ pub mod module {
 			actual_tip: PalletBalanceOf<T>,
 			actual_surplus: PalletBalanceOf<T>,
 		},
+		/// The paymaster is registered.
+		PaymasterRegistered {
+			paymaster: T::AccountId,
+			targets: Vec<SponsoredTarget>,
+			info: PaymasterInfo<T::BlockNumber>,
+		},
+		/// The paymaster is updated.
+		PaymasterUpdated {
+			paymaster: T::AccountId,
+			info: PaymasterInfo<T::BlockNumber>,
+		},
+		/// The paymaster is removed.
+		PaymasterRemoved { paymaster: T::AccountId },
+		/// The transaction fee of `who` is sponsored by the paymaster.
+		TransactionSponsored {
+			paymaster: T::AccountId,
+			who: T::AccountId,
+			fee: PalletBalanceOf<T>,
+		},
 	}
 
 	/// The next fee multiplier.
@@ -449,6 +581,38 @@ pub mod module {
 	#[pallet::getter(fn swap_balance_threshold)]
 	pub type SwapBalanceThreshold<T: Config> = StorageMap<_, Twox64Concat, CurrencyId, Balance, ValueQuery>;
 
+	/// The paymasters.
+	///
+	/// Paymasters: map AccountId => Option<PaymasterInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn paymasters)]
+	pub type Paymasters<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, PaymasterInfo<T::BlockNumber>, OptionQuery>;
+
+	/// The targets sponsored by the paymaster.
+	///
+	/// PaymasterTargets: map AccountId => Vec<SponsoredTarget>
+	#[pallet::storage]
+	#[pallet::getter(fn paymaster_targets)]
+	pub type PaymasterTargets<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<SponsoredTarget, T::MaxSponsoredTargets>, ValueQuery>;
+
+	/// The paymaster of the sponsored target.
+	///
+	/// SponsoredTargets: map SponsoredTarget => Option<AccountId>
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_targets)]
+	pub type SponsoredTargets<T: Config> = StorageMap<_, Twox64Concat, SponsoredTarget, T::AccountId, OptionQuery>;
+
+	/// The start of the current rate limit period and the count of sponsored transactions in it,
+	/// for users of the paymaster.
+	///
+	/// PaymasterUsage: double_map (AccountId, AccountId) => (BlockNumber, u32)
+	#[pallet::storage]
+	#[pallet::getter(fn paymaster_usage)]
+	pub type PaymasterUsage<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, (T::BlockNumber, u32), ValueQuery>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -577,7 +741,14 @@ pub mod module {
 		/// sure the currency is exist in tx fee pool.
 		#[pallet::weight({
 			let dispatch_info = call.get_dispatch_info();
-			(T::WeightInfo::with_fee_currency().saturating_add(dispatch_info.weight), dispatch_info.class,)
+			let weight = if _currency_id.is_dex_share_currency_id()
+				|| matches!(_currency_id, CurrencyId::StableAssetPoolToken(_))
+			{
+				T::WeightInfo::with_fee_currency_liquidity_token()
+			} else {
+				T::WeightInfo::with_fee_currency()
+			};
+			(weight.saturating_add(dispatch_info.weight), dispatch_info.class,)
 		})]
 		pub fn with_fee_currency(
 			origin: OriginFor<T>,
@@ -617,6 +788,114 @@ pub mod module {
 			ensure_signed(origin.clone())?;
 			call.dispatch(origin)
 		}
+
+		/// Register the caller as paymaster to sponsor the transaction fee of calls to `targets`.
+		/// Contract targets can only be sponsored by the contract itself, and pallet targets can
+		/// only be registered by `UpdateOrigin` with `force_register_paymaster`.
+		///
+		/// - `targets`: the EVM contracts to sponsor.
+		/// - `budget`: the total transaction fee to sponsor.
+		/// - `max_txs_per_period`: the max sponsored transactions for every user in a period, zero
+		///   means unlimited.
+		/// - `period`: the length of the rate limit period.
+		/// - `expire_at`: the paymaster stops sponsoring since this block.
+		#[pallet::weight(<T as Config>::WeightInfo::register_paymaster(targets.len() as u32))]
+		#[transactional]
+		pub fn register_paymaster(
+			origin: OriginFor<T>,
+			targets: Vec<SponsoredTarget>,
+			#[pallet::compact] budget: Balance,
+			max_txs_per_period: u32,
+			period: T::BlockNumber,
+			expire_at: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				targets
+					.iter()
+					.all(|target| matches!(target, SponsoredTarget::Contract(_))),
+				Error::<T>::NoPermission
+			);
+			Self::do_register_paymaster(
+				&who,
+				targets,
+				PaymasterInfo {
+					budget,
+					max_txs_per_period,
+					period,
+					expire_at,
+				},
+			)
+		}
+
+		/// Update the budget, rate limit and expiry of the caller's paymaster.
+		#[pallet::weight(<T as Config>::WeightInfo::update_paymaster())]
+		#[transactional]
+		pub fn update_paymaster(
+			origin: OriginFor<T>,
+			#[pallet::compact] budget: Balance,
+			max_txs_per_period: u32,
+			period: T::BlockNumber,
+			expire_at: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_update_paymaster(
+				&who,
+				PaymasterInfo {
+					budget,
+					max_txs_per_period,
+					period,
+					expire_at,
+				},
+			)
+		}
+
+		/// Register `paymaster` to sponsor the transaction fee of calls to `targets`, including
+		/// pallet targets.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `paymaster`: the account pays the sponsored transaction fee.
+		/// - `targets`: the pallets and EVM contracts to sponsor.
+		/// - `budget`: the total transaction fee to sponsor.
+		/// - `max_txs_per_period`: the max sponsored transactions for every user in a period, zero
+		///   means unlimited.
+		/// - `period`: the length of the rate limit period.
+		/// - `expire_at`: the paymaster stops sponsoring since this block.
+		#[pallet::weight(<T as Config>::WeightInfo::register_paymaster(targets.len() as u32))]
+		#[transactional]
+		pub fn force_register_paymaster(
+			origin: OriginFor<T>,
+			paymaster: T::AccountId,
+			targets: Vec<SponsoredTarget>,
+			#[pallet::compact] budget: Balance,
+			max_txs_per_period: u32,
+			period: T::BlockNumber,
+			expire_at: T::BlockNumber,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			Self::do_register_paymaster(
+				&paymaster,
+				targets,
+				PaymasterInfo {
+					budget,
+					max_txs_per_period,
+					period,
+					expire_at,
+				},
+			)
+		}
+
+		/// Remove the paymaster, by the paymaster itself or `UpdateOrigin`.
+		#[pallet::weight(<T as Config>::WeightInfo::remove_paymaster(T::MaxSponsoredTargets::get()))]
+		#[transactional]
+		pub fn remove_paymaster(origin: OriginFor<T>, paymaster: T::AccountId) -> DispatchResult {
+			if T::UpdateOrigin::ensure_origin(origin.clone()).is_err() {
+				let who = ensure_signed(origin)?;
+				ensure!(who == paymaster, Error::<T>::NoPermission);
+			}
+			Self::do_remove_paymaster(&paymaster)
+		}
 	}
 }
 
@@ -633,7 +912,7 @@ where
 	///
 	/// All dispatchables must be annotated with weight and will have some
 	/// fee info. This function always returns.
-	pub fn query_info<Extrinsic: GetDispatchInfo>(
+	pub fn query_info<Extrinsic: GetDispatchInfo + ExtrinsicCall + ExtrinsicT<Call = CallOf<T>>>(
 		unchecked_extrinsic: Extrinsic,
 		len: u32,
 	) -> RuntimeDispatchInfo<PalletBalanceOf<T>>
@@ -649,7 +928,7 @@ where
 		// potential gain in the future.
 		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
 
-		let partial_fee = Self::compute_fee(len, &dispatch_info, 0u32.into());
+		let partial_fee = Self::compute_call_fee(len, unchecked_extrinsic.call(), &dispatch_info, 0u32.into());
 		let DispatchInfo { weight, class, .. } = dispatch_info;
 
 		RuntimeDispatchInfo {
@@ -660,12 +939,150 @@ where
 	}
 
 	/// Query the detailed fee of a given `call`.
-	pub fn query_fee_details<Extrinsic: GetDispatchInfo>(
+	pub fn query_fee_details<Extrinsic: GetDispatchInfo + ExtrinsicCall + ExtrinsicT<Call = CallOf<T>>>(
 		unchecked_extrinsic: Extrinsic,
 		len: u32,
 	) -> FeeDetails<PalletBalanceOf<T>> {
 		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
-		Self::compute_fee_details(len, &dispatch_info, 0u32.into())
+		Self::compute_call_fee_details(len, unchecked_extrinsic.call(), &dispatch_info, 0u32.into())
+	}
+
+	/// Query the fee of a given `call` that would be charged from `who` in the native asset and
+	/// each alternative fee currency: the `AlternativeFeeSwapPath` of `who`, the currencies
+	/// enabled charge fee pool and `currency_ids` paid by `with_fee_currency`.
+	pub fn query_fee_in_currencies<Extrinsic: GetDispatchInfo + ExtrinsicCall + ExtrinsicT<Call = CallOf<T>>>(
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+		who: &T::AccountId,
+		currency_ids: Vec<CurrencyId>,
+	) -> Vec<CurrencyFeeDetails> {
+		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
+		let call = unchecked_extrinsic.call();
+		let fee = Self::compute_call_fee(len, call, &dispatch_info, 0u32.into());
+		Self::compute_fee_in_currencies(who, call, fee, currency_ids)
+	}
+
+	/// Compute the fee of `call` in the native asset and each alternative fee currency of `who`.
+	/// The amounts follow `with_fee_currency` and `native_then_alternative_or_default`: the
+	/// native asset not enough for `fee` is swapped, with `AlternativeFeeSurplus` for
+	/// `DefaultFeeTokens` and the alternative fee swap path, or `CustomFeeSurplus` for other
+	/// tokens. Only the paymaster is returned if it sponsors the call, and the charge fee pools
+	/// without enough native asset or the currencies can't be swapped are skipped.
+	pub fn compute_fee_in_currencies(
+		who: &T::AccountId,
+		call: &CallOf<T>,
+		fee: PalletBalanceOf<T>,
+		currency_ids: Vec<CurrencyId>,
+	) -> Vec<CurrencyFeeDetails> {
+		let native_currency_id = T::NativeCurrencyId::get();
+		let reason = WithdrawReasons::TRANSACTION_PAYMENT;
+		if !fee.is_zero() && Self::sponsor_paymaster(who, call, fee, reason).is_some() {
+			return vec![CurrencyFeeDetails {
+				currency_id: native_currency_id,
+				fee,
+				path: FeePaymentPath::Paymaster,
+				sufficient: true,
+			}];
+		}
+
+		let native_not_enough = if fee.is_zero() {
+			None
+		} else {
+			Self::check_native_is_not_enough(who, fee, reason)
+		};
+		let mut fees = vec![CurrencyFeeDetails {
+			currency_id: native_currency_id,
+			fee,
+			path: FeePaymentPath::Native,
+			sufficient: native_not_enough.is_none(),
+		}];
+		if fee.is_zero() {
+			return fees;
+		}
+
+		let amount = native_not_enough.unwrap_or(fee);
+		let alternative_fee_amount = amount.saturating_add(T::AlternativeFeeSurplus::get().mul_ceil(fee));
+		let custom_fee_amount = amount.saturating_add(T::CustomFeeSurplus::get().mul_ceil(fee));
+		let can_withdraw = |currency_id: CurrencyId, supply_amount: Balance| {
+			T::MultiCurrency::ensure_can_withdraw(currency_id, who, supply_amount).is_ok()
+		};
+
+		if let Some(path) = AlternativeFeeSwapPath::<T>::get(who) {
+			if let (Some(currency_id), Some((supply_amount, _))) = (
+				path.first(),
+				T::DEX::get_swap_amount(&path, SwapLimit::ExactTarget(Balance::MAX, alternative_fee_amount)),
+			) {
+				fees.push(CurrencyFeeDetails {
+					currency_id: *currency_id,
+					fee: supply_amount,
+					path: FeePaymentPath::Dex(path.into_inner()),
+					sufficient: can_withdraw(*currency_id, supply_amount),
+				});
+			}
+		}
+
+		let default_fee_tokens = T::DefaultFeeTokens::get();
+		let mut pool_tokens = TokenExchangeRate::<T>::iter().collect::<Vec<_>>();
+		// keep the order of `native_then_alternative_or_default`, default fee tokens go first.
+		pool_tokens.sort_by_key(|(currency_id, _)| {
+			default_fee_tokens
+				.iter()
+				.position(|v| v == currency_id)
+				.unwrap_or(default_fee_tokens.len())
+		});
+		let native_existential_deposit = <T as Config>::Currency::minimum_balance();
+		for (currency_id, rate) in pool_tokens {
+			let native_amount = if default_fee_tokens.contains(&currency_id) {
+				alternative_fee_amount
+			} else {
+				custom_fee_amount
+			};
+			// the charge fee pool should keep alive after paying `native_amount`.
+			let pool_native = T::Currency::free_balance(&Self::sub_account_id(currency_id));
+			if pool_native < native_amount.saturating_add(native_existential_deposit) {
+				continue;
+			}
+			let supply_amount = rate.saturating_mul_int(native_amount);
+			fees.push(CurrencyFeeDetails {
+				currency_id,
+				fee: supply_amount,
+				path: FeePaymentPath::FeePool,
+				sufficient: can_withdraw(currency_id, supply_amount),
+			});
+		}
+
+		// `with_fee_currency` swaps the native asset not enough and the `CustomFeeSurplus` of it.
+		let with_fee_currency_amount = amount.saturating_add(T::CustomFeeSurplus::get().mul_ceil(amount));
+		for currency_id in currency_ids {
+			// the native asset and the charge fee pools are listed above.
+			if currency_id == native_currency_id || TokenExchangeRate::<T>::contains_key(currency_id) {
+				continue;
+			}
+			let maybe_supply = if currency_id.is_dex_share_currency_id()
+				|| matches!(currency_id, CurrencyId::StableAssetPoolToken(_))
+			{
+				Self::liquidity_token_redeem_share(currency_id, with_fee_currency_amount)
+					.ok()
+					.map(|(_, redeem_share, _)| (redeem_share, FeePaymentPath::LiquidityToken))
+			} else {
+				T::Swap::get_swap_amount(
+					currency_id,
+					native_currency_id,
+					SwapLimit::ExactTarget(Balance::MAX, with_fee_currency_amount),
+				)
+				.map(|(supply_amount, _)| (supply_amount, FeePaymentPath::Swap))
+			};
+			if let Some((supply_amount, path)) = maybe_supply {
+				fees.push(CurrencyFeeDetails {
+					currency_id,
+					fee: supply_amount,
+					path,
+					sufficient: can_withdraw(currency_id, supply_amount),
+				});
+			}
+		}
+
+		fees
 	}
 
 	/// Compute the fee details for a particular transaction.
@@ -674,7 +1091,35 @@ where
 		info: &DispatchInfoOf<CallOf<T>>,
 		tip: PalletBalanceOf<T>,
 	) -> FeeDetails<PalletBalanceOf<T>> {
-		Self::compute_fee_raw(len, info.weight, tip, info.pays_fee, info.class)
+		Self::compute_fee_raw(len, info.weight, tip, info.pays_fee, info.class, None)
+	}
+
+	/// Compute the fee details for a particular transaction of `call`, the dynamic base fee of
+	/// the call is charged instead of the adjusted weight fee if it's higher.
+	pub fn compute_call_fee_details(
+		len: u32,
+		call: &CallOf<T>,
+		info: &DispatchInfoOf<CallOf<T>>,
+		tip: PalletBalanceOf<T>,
+	) -> FeeDetails<PalletBalanceOf<T>> {
+		Self::compute_fee_raw(
+			len,
+			info.weight,
+			tip,
+			info.pays_fee,
+			info.class,
+			T::DynamicBaseFee::base_fee_per_weight(call),
+		)
+	}
+
+	/// Compute the final fee value for a particular transaction of `call`.
+	pub fn compute_call_fee(
+		len: u32,
+		call: &CallOf<T>,
+		info: &DispatchInfoOf<CallOf<T>>,
+		tip: PalletBalanceOf<T>,
+	) -> PalletBalanceOf<T> {
+		Self::compute_call_fee_details(len, call, info, tip).final_fee()
 	}
 
 	/// Compute the final fee value for a particular transaction.
@@ -717,6 +1162,7 @@ where
 			tip,
 			post_info.pays_fee(info),
 			info.class,
+			None,
 		)
 	}
 
@@ -739,6 +1185,7 @@ where
 		tip: PalletBalanceOf<T>,
 		pays_fee: Pays,
 		class: DispatchClass,
+		base_fee_per_weight: Option<Multiplier>,
 	) -> FeeDetails<PalletBalanceOf<T>> {
 		if pays_fee == Pays::Yes {
 			let len = <PalletBalanceOf<T>>::from(len);
@@ -751,7 +1198,11 @@ where
 			let unadjusted_weight_fee = Self::weight_to_fee(weight);
 			let multiplier = Self::next_fee_multiplier();
 			// final adjusted weight fee.
-			let adjusted_weight_fee = multiplier.saturating_mul_int(unadjusted_weight_fee);
+			let mut adjusted_weight_fee = multiplier.saturating_mul_int(unadjusted_weight_fee);
+			// the dynamic base fee of the call is charged instead if it's higher.
+			if let Some(base_fee_per_weight) = base_fee_per_weight {
+				adjusted_weight_fee = adjusted_weight_fee.max(Self::dynamic_base_fee(weight, base_fee_per_weight));
+			}
 
 			let base_fee = Self::weight_to_fee(T::BlockWeights::get().get(class).base_extrinsic);
 			FeeDetails {
@@ -770,6 +1221,12 @@ where
 		}
 	}
 
+	/// The dynamic base fee of `weight`.
+	fn dynamic_base_fee(weight: Weight, base_fee_per_weight: Multiplier) -> PalletBalanceOf<T> {
+		let capped_weight = weight.min(T::BlockWeights::get().max_block);
+		base_fee_per_weight.saturating_mul_int(capped_weight.saturated_into::<PalletBalanceOf<T>>())
+	}
+
 	pub fn weight_to_fee(weight: Weight) -> PalletBalanceOf<T> {
 		// cap the weight to the maximum defined in runtime, otherwise it will be the
 		// `Bounded` maximum of its data type, which is not desired.
@@ -800,8 +1257,9 @@ where
 	}
 
 	/// Determine the fee and surplus that should be withdraw from user. There are three kind call:
-	/// - TransactionPayment::with_fee_currency: swap with tx fee pool if token is enable charge fee
-	///   pool, else swap with dex.
+	/// - TransactionPayment::with_fee_currency: redeem and swap if token is DEX share or stable
+	///   asset pool token, swap with tx fee pool if token is enable charge fee pool, else swap with
+	///   dex.
 	/// - TransactionPayment::with_fee_path: swap with specific trading path.
 	/// - others call: first use native asset, if not enough use alternative, or else use default.
 	fn ensure_can_charge_fee_with_call(
@@ -861,7 +1319,12 @@ where
 				} else {
 					(fee.saturating_add(custom_fee_surplus), custom_fee_surplus)
 				};
-				if TokenExchangeRate::<T>::contains_key(currency_id) {
+				if currency_id.is_dex_share_currency_id() || matches!(currency_id, CurrencyId::StableAssetPoolToken(_))
+				{
+					// liquidity token, redeem part of it and swap the underlying currency.
+					Self::swap_from_liquidity_token(who, *currency_id, fee.saturating_add(custom_fee_surplus))
+						.map(|_| (who.clone(), custom_fee_surplus))
+				} else if TokenExchangeRate::<T>::contains_key(currency_id) {
 					// token in charge fee pool should have `TokenExchangeRate` info.
 					Self::swap_from_pool_or_dex(who, fee_amount, *currency_id).map(|_| (who.clone(), fee_surplus))
 				} else {
@@ -996,6 +1459,262 @@ where
 		Ok(())
 	}
 
+	/// Redeem a small amount of liquidity token `lp_currency_id` of `who`, and swap the withdrawn
+	/// underlying currency to `amount` of native asset. The remaining withdrawn assets are kept by
+	/// `who`.
+	#[transactional]
+	fn swap_from_liquidity_token(who: &T::AccountId, lp_currency_id: CurrencyId, amount: Balance) -> DispatchResult {
+		let native_currency_id = T::NativeCurrencyId::get();
+		let (total_share, redeem_share, currency_id) = Self::liquidity_token_redeem_share(lp_currency_id, amount)?;
+
+		let before = T::MultiCurrency::free_balance(currency_id, who);
+		// the withdrawn amounts are proportional to the redeemed share, use them as the minimum
+		// amounts to redeem.
+		match lp_currency_id {
+			CurrencyId::StableAssetPoolToken(pool_id) => {
+				let pool_info = T::StableAsset::pool(pool_id).ok_or(Error::<T>::InvalidLiquidityToken)?;
+				let RedeemProportionResult { amounts, .. } =
+					T::StableAsset::get_redeem_proportion_amount(&pool_info, redeem_share)
+						.ok_or(Error::<T>::InvalidLiquidityToken)?;
+				T::StableAsset::redeem_proportion(who, pool_id, redeem_share, amounts)?;
+			}
+			_ => {
+				let (currency_id_a, currency_id_b) = lp_currency_id
+					.split_dex_share_currency_id()
+					.ok_or(Error::<T>::InvalidLiquidityToken)?;
+				let proportion =
+					Ratio::checked_from_rational(redeem_share, total_share).ok_or(ArithmeticError::Overflow)?;
+				let (pool_a, pool_b) = T::DEX::get_liquidity_pool(currency_id_a, currency_id_b);
+				T::DEX::remove_liquidity(
+					who,
+					currency_id_a,
+					currency_id_b,
+					redeem_share,
+					proportion.saturating_mul_int(pool_a),
+					proportion.saturating_mul_int(pool_b),
+					false,
+				)?;
+			}
+		}
+		let withdrawn = T::MultiCurrency::free_balance(currency_id, who).saturating_sub(before);
+
+		if currency_id != native_currency_id {
+			T::Swap::swap(
+				who,
+				currency_id,
+				native_currency_id,
+				SwapLimit::ExactTarget(withdrawn, amount),
+			)?;
+		} else {
+			ensure!(withdrawn >= amount, Error::<T>::InvalidBalance);
+		}
+		Ok(())
+	}
+
+	/// The total share of liquidity token `lp_currency_id`, and the share to redeem with the
+	/// underlying currency to swap to `amount` of native asset. The underlying currency which
+	/// needs the least share to redeem is used.
+	fn liquidity_token_redeem_share(
+		lp_currency_id: CurrencyId,
+		amount: Balance,
+	) -> Result<(Balance, Balance, CurrencyId), DispatchError> {
+		let native_currency_id = T::NativeCurrencyId::get();
+		let (total_share, underlying) = Self::liquidity_token_underlying(lp_currency_id)?;
+
+		let (redeem_share, currency_id) = underlying
+			.into_iter()
+			.filter(|(_, withdrawn)| !withdrawn.is_zero())
+			.filter_map(|(currency_id, withdrawn)| {
+				let supply_amount = if currency_id == native_currency_id {
+					amount
+				} else {
+					T::Swap::get_swap_amount(
+						currency_id,
+						native_currency_id,
+						SwapLimit::ExactTarget(Balance::MAX, amount),
+					)?
+					.0
+				};
+				// round up to make sure the withdrawn amount is enough.
+				let share = multiply_by_rational(supply_amount, total_share, withdrawn)
+					.ok()?
+					.saturating_add(1);
+				Some((share, currency_id))
+			})
+			.min_by_key(|(share, _)| *share)
+			.ok_or(Error::<T>::DexNotAvailable)?;
+
+		Ok((total_share, redeem_share, currency_id))
+	}
+
+	/// The total share of liquidity token `lp_currency_id`, and the underlying currencies with
+	/// the amounts that would be withdrawn by redeeming all of the share.
+	fn liquidity_token_underlying(
+		lp_currency_id: CurrencyId,
+	) -> Result<(Balance, Vec<(CurrencyId, Balance)>), DispatchError> {
+		match lp_currency_id {
+			CurrencyId::StableAssetPoolToken(pool_id) => {
+				let pool_info = T::StableAsset::pool(pool_id).ok_or(Error::<T>::InvalidLiquidityToken)?;
+				ensure!(!pool_info.total_supply.is_zero(), Error::<T>::InvalidLiquidityToken);
+				let RedeemProportionResult { amounts, .. } =
+					T::StableAsset::get_redeem_proportion_amount(&pool_info, pool_info.total_supply)
+						.ok_or(Error::<T>::InvalidLiquidityToken)?;
+				Ok((
+					pool_info.total_supply,
+					pool_info.assets.into_iter().zip(amounts.into_iter()).collect(),
+				))
+			}
+			_ => {
+				let (currency_id_a, currency_id_b) = lp_currency_id
+					.split_dex_share_currency_id()
+					.ok_or(Error::<T>::InvalidLiquidityToken)?;
+				let total_share = T::MultiCurrency::total_issuance(lp_currency_id);
+				ensure!(!total_share.is_zero(), Error::<T>::InvalidLiquidityToken);
+				let (pool_a, pool_b) = T::DEX::get_liquidity_pool(currency_id_a, currency_id_b);
+				Ok((total_share, vec![(currency_id_a, pool_a), (currency_id_b, pool_b)]))
+			}
+		}
+	}
+
+	/// Register `who` as paymaster of `targets`.
+	pub fn do_register_paymaster(
+		who: &T::AccountId,
+		targets: Vec<SponsoredTarget>,
+		info: PaymasterInfo<T::BlockNumber>,
+	) -> DispatchResult {
+		ensure!(!Paymasters::<T>::contains_key(who), Error::<T>::PaymasterAlreadyExisted);
+		Self::ensure_valid_paymaster_info(&info)?;
+		ensure!(!targets.is_empty(), Error::<T>::InvalidSponsoredTargets);
+		let bounded_targets: BoundedVec<SponsoredTarget, T::MaxSponsoredTargets> = targets
+			.clone()
+			.try_into()
+			.map_err(|_| Error::<T>::InvalidSponsoredTargets)?;
+
+		for target in bounded_targets.iter() {
+			if let SponsoredTarget::Contract(contract) = target {
+				ensure!(
+					T::AddressMapping::get_account_id(contract) == *who,
+					Error::<T>::NoPermission
+				);
+			}
+			ensure!(
+				!SponsoredTargets::<T>::contains_key(target),
+				Error::<T>::SponsoredTargetAlreadyExisted
+			);
+			SponsoredTargets::<T>::insert(target, who);
+		}
+		PaymasterTargets::<T>::insert(who, bounded_targets);
+		Paymasters::<T>::insert(who, &info);
+
+		Self::deposit_event(Event::PaymasterRegistered {
+			paymaster: who.clone(),
+			targets,
+			info,
+		});
+		Ok(())
+	}
+
+	/// Update the info of paymaster `who`.
+	pub fn do_update_paymaster(who: &T::AccountId, info: PaymasterInfo<T::BlockNumber>) -> DispatchResult {
+		Self::ensure_valid_paymaster_info(&info)?;
+		Paymasters::<T>::try_mutate(who, |maybe_info| -> DispatchResult {
+			let old_info = maybe_info.as_mut().ok_or(Error::<T>::PaymasterNotFound)?;
+			*old_info = info.clone();
+			Ok(())
+		})?;
+
+		Self::deposit_event(Event::PaymasterUpdated {
+			paymaster: who.clone(),
+			info,
+		});
+		Ok(())
+	}
+
+	/// Remove the paymaster `who` and its sponsored targets.
+	pub fn do_remove_paymaster(who: &T::AccountId) -> DispatchResult {
+		ensure!(Paymasters::<T>::contains_key(who), Error::<T>::PaymasterNotFound);
+		for target in PaymasterTargets::<T>::take(who) {
+			SponsoredTargets::<T>::remove(target);
+		}
+		Paymasters::<T>::remove(who);
+		let _ = PaymasterUsage::<T>::clear_prefix(who, u32::MAX, None);
+
+		Self::deposit_event(Event::PaymasterRemoved { paymaster: who.clone() });
+		Ok(())
+	}
+
+	fn ensure_valid_paymaster_info(info: &PaymasterInfo<T::BlockNumber>) -> DispatchResult {
+		ensure!(
+			info.expire_at > frame_system::Pallet::<T>::block_number()
+				&& (info.max_txs_per_period.is_zero() || !info.period.is_zero()),
+			Error::<T>::InvalidPaymasterInfo
+		);
+		Ok(())
+	}
+
+	/// Get the paymaster sponsoring the `call` of `who`. The paymaster should be active, has
+	/// enough budget and native asset to pay `fee`, and `who` doesn't exceed the rate limit.
+	fn sponsor_paymaster(
+		who: &T::AccountId,
+		call: &CallOf<T>,
+		fee: PalletBalanceOf<T>,
+		reason: WithdrawReasons,
+	) -> Option<T::AccountId> {
+		let target = T::SponsoredTargetOf::sponsored_target(call)?;
+		let paymaster = SponsoredTargets::<T>::get(target)?;
+		let info = Paymasters::<T>::get(&paymaster)?;
+		let now = frame_system::Pallet::<T>::block_number();
+
+		if now >= info.expire_at || info.budget < fee {
+			return None;
+		}
+		if !info.max_txs_per_period.is_zero() {
+			let (period_start, count) = PaymasterUsage::<T>::get(&paymaster, who);
+			if now < period_start.saturating_add(info.period) && count >= info.max_txs_per_period {
+				return None;
+			}
+		}
+		if Self::check_native_is_not_enough(&paymaster, fee, reason).is_some() {
+			return None;
+		}
+		Some(paymaster)
+	}
+
+	/// Consume the budget of the paymaster and record the sponsored transaction of `who`.
+	fn on_sponsored(paymaster: &T::AccountId, who: &T::AccountId, fee: PalletBalanceOf<T>) {
+		if let Some(info) = Paymasters::<T>::mutate(paymaster, |maybe_info| {
+			maybe_info.as_mut().map(|info| {
+				info.budget = info.budget.saturating_sub(fee);
+				info.clone()
+			})
+		}) {
+			let now = frame_system::Pallet::<T>::block_number();
+			PaymasterUsage::<T>::mutate(paymaster, who, |(period_start, count)| {
+				if count.is_zero() || now >= period_start.saturating_add(info.period) {
+					*period_start = now;
+					*count = 1;
+				} else {
+					*count = count.saturating_add(1);
+				}
+			});
+		}
+
+		Self::deposit_event(Event::TransactionSponsored {
+			paymaster: paymaster.clone(),
+			who: who.clone(),
+			fee,
+		});
+	}
+
+	/// Return the `refund` of the sponsored transaction fee to the budget of the paymaster.
+	fn on_sponsor_refunded(paymaster: &T::AccountId, refund: PalletBalanceOf<T>) {
+		Paymasters::<T>::mutate(paymaster, |maybe_info| {
+			if let Some(info) = maybe_info {
+				info.budget = info.budget.saturating_add(refund);
+			}
+		});
+	}
+
 	/// The sub account derivated by `PalletId`.
 	fn sub_account_id(id: CurrencyId) -> T::AccountId {
 		T::PalletId::get().into_sub_account_truncating(id)
@@ -1176,15 +1895,26 @@ where
 			Option<NegativeImbalanceOf<T>>,
 			PalletBalanceOf<T>,
 			T::AccountId,
+			Option<Multiplier>,
+			bool,
 		),
 		TransactionValidityError,
 	> {
 		let tip = self.0;
-		let fee = Pallet::<T>::compute_fee(len as u32, info, tip);
+		let base_fee_per_weight = T::DynamicBaseFee::base_fee_per_weight(call);
+		let fee = Pallet::<T>::compute_fee_raw(
+			len as u32,
+			info.weight,
+			tip,
+			info.pays_fee,
+			info.class,
+			base_fee_per_weight,
+		)
+		.final_fee();
 
 		// Only mess with balances if fee is not zero.
 		if fee.is_zero() {
-			return Ok((fee, None, 0, who.clone()));
+			return Ok((fee, None, 0, who.clone(), base_fee_per_weight, false));
 		}
 
 		let reason = if tip.is_zero() {
@@ -1193,12 +1923,33 @@ where
 			WithdrawReasons::TRANSACTION_PAYMENT | WithdrawReasons::TIP
 		};
 
+		// the paymaster sponsoring the call pays the fee, the transaction with tip is not sponsored.
+		if tip.is_zero() {
+			if let Some(paymaster) = Pallet::<T>::sponsor_paymaster(who, call, fee, reason) {
+				return match <T as Config>::Currency::withdraw(&paymaster, fee, reason, ExistenceRequirement::KeepAlive)
+				{
+					Ok(imbalance) => {
+						Pallet::<T>::on_sponsored(&paymaster, who, fee);
+						Ok((fee, Some(imbalance), 0, paymaster, base_fee_per_weight, true))
+					}
+					Err(_) => Err(InvalidTransaction::Payment.into()),
+				};
+			}
+		}
+
 		let (payer, fee_surplus) = Pallet::<T>::ensure_can_charge_fee_with_call(who, fee, call, reason)
 			.map_err(|_| InvalidTransaction::Payment)?;
 
 		// withdraw native currency as fee, also consider surplus when swap from dex or pool.
 		match <T as Config>::Currency::withdraw(&payer, fee + fee_surplus, reason, ExistenceRequirement::KeepAlive) {
-			Ok(imbalance) => Ok((fee + fee_surplus, Some(imbalance), fee_surplus, payer)),
+			Ok(imbalance) => Ok((
+				fee + fee_surplus,
+				Some(imbalance),
+				fee_surplus,
+				payer,
+				base_fee_per_weight,
+				false,
+			)),
 			Err(_) => Err(InvalidTransaction::Payment.into()),
 		}
 	}
@@ -1297,6 +2048,8 @@ where
 		Option<NegativeImbalanceOf<T>>,
 		PalletBalanceOf<T>, // fee includes surplus
 		PalletBalanceOf<T>, // surplus
+		Option<Multiplier>, // dynamic base fee per weight
+		bool,               // sponsored by the paymaster
 	);
 
 	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
@@ -1310,7 +2063,7 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> TransactionValidity {
-		let (final_fee, _, _, _) = self.withdraw_fee(who, call, info, len)?;
+		let (final_fee, _, _, _, _, _) = self.withdraw_fee(who, call, info, len)?;
 		let tip = self.0;
 		Ok(ValidTransaction {
 			priority: Self::get_priority(info, len, tip, final_fee),
@@ -1325,8 +2078,9 @@ where
 		info: &DispatchInfoOf<Self::Call>,
 		len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		let (fee, imbalance, surplus, payer) = self.withdraw_fee(who, call, info, len)?;
-		Ok((self.0, payer, imbalance, fee, surplus))
+		let (fee, imbalance, surplus, payer, base_fee_per_weight, sponsored) =
+			self.withdraw_fee(who, call, info, len)?;
+		Ok((self.0, payer, imbalance, fee, surplus, base_fee_per_weight, sponsored))
 	}
 
 	fn post_dispatch(
@@ -1336,8 +2090,18 @@ where
 		len: usize,
 		_result: &DispatchResult,
 	) -> Result<(), TransactionValidityError> {
-		if let Some((tip, who, Some(payed), fee, surplus)) = pre {
-			let actual_fee = Pallet::<T>::compute_actual_fee(len as u32, info, post_info, tip);
+		if let Some((tip, who, Some(payed), fee, surplus, base_fee_per_weight, sponsored)) = pre {
+			let actual_weight = post_info.calc_actual_weight(info);
+			let pays_fee = post_info.pays_fee(info);
+			let actual_fee = Pallet::<T>::compute_fee_raw(
+				len as u32,
+				actual_weight,
+				tip,
+				pays_fee,
+				info.class,
+				base_fee_per_weight,
+			)
+			.final_fee();
 			let refund_fee = fee.saturating_sub(actual_fee);
 			let mut refund = refund_fee;
 			let mut actual_tip = tip;
@@ -1358,6 +2122,10 @@ where
 
 			let actual_payment = match <T as Config>::Currency::deposit_into_existing(&who, refund) {
 				Ok(refund_imbalance) => {
+					// the unused fee is returned to the budget of the paymaster.
+					if sponsored {
+						Pallet::<T>::on_sponsor_refunded(&who, refund);
+					}
 					// The refund cannot be larger than the up front payed max weight.
 					// `PostDispatchInfo::calc_unspent` guards against such a case.
 					match payed.offset(refund_imbalance) {
@@ -1372,6 +2140,16 @@ where
 			};
 			let (tip, fee) = actual_payment.split(actual_tip);
 
+			// the dynamic base fee goes to its own destination, the rest is distributed as usual.
+			let actual_base_fee = match base_fee_per_weight {
+				Some(base_fee_per_weight) if pays_fee == Pays::Yes => {
+					Pallet::<T>::dynamic_base_fee(actual_weight, base_fee_per_weight)
+				}
+				_ => Zero::zero(),
+			};
+			let (base_fee, fee) = fee.split(actual_base_fee);
+			<T as Config>::OnDynamicBaseFee::on_unbalanced(base_fee);
+
 			// distribute fee
 			<T as Config>::OnTransactionPayment::on_unbalanceds(Some(fee).into_iter().chain(Some(tip)));
 
@@ -153,6 +153,7 @@ impl module_currencies::Config for Runtime {
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type Erc20HoldingAccount = Erc20HoldingAccount;
+	type TreasuryAccount = KaruraTreasuryAccount;
 	type WeightInfo = ();
 	type AddressMapping = MockAddressMapping;
 	type EVMBridge = ();
@@ -192,6 +193,8 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureSignedBy<Zero, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU64<0>;
 	type OnLiquidityPoolUpdated = ();
+	type CircuitBreaker = ();
+	type CircuitBreakerExemptAccounts = Nothing;
 }
 
 impl module_aggregated_dex::Config for Runtime {
@@ -215,6 +218,7 @@ parameter_types! {
 thread_local! {
 	pub static TIP_UNBALANCED_AMOUNT: RefCell<u128> = RefCell::new(0);
 	pub static FEE_UNBALANCED_AMOUNT: RefCell<u128> = RefCell::new(0);
+	pub static BASE_FEE_UNBALANCED_AMOUNT: RefCell<u128> = RefCell::new(0);
 }
 
 pub struct DealWithFees;
@@ -229,6 +233,13 @@ impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for DealWithFees
 	}
 }
 
+pub struct DealWithBaseFee;
+impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for DealWithBaseFee {
+	fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Runtime>) {
+		BASE_FEE_UNBALANCED_AMOUNT.with(|a| *a.borrow_mut() += amount.peek());
+	}
+}
+
 thread_local! {
 	static RELATIVE_PRICE: RefCell<Option<Price>> = RefCell::new(Some(Price::one()));
 }
@@ -249,6 +260,22 @@ impl PriceProvider<CurrencyId> for MockPriceSource {
 	}
 }
 
+thread_local! {
+	static BASE_FEE_PER_WEIGHT: RefCell<Option<Multiplier>> = RefCell::new(None);
+}
+
+pub struct MockDynamicBaseFee;
+impl MockDynamicBaseFee {
+	pub fn set_base_fee_per_weight(base_fee_per_weight: Option<Multiplier>) {
+		BASE_FEE_PER_WEIGHT.with(|v| *v.borrow_mut() = base_fee_per_weight);
+	}
+}
+impl DynamicBaseFee<Call> for MockDynamicBaseFee {
+	fn base_fee_per_weight(_call: &Call) -> Option<Multiplier> {
+		BASE_FEE_PER_WEIGHT.with(|v| *v.borrow())
+	}
+}
+
 parameter_types! {
 	// DO NOT CHANGE THIS VALUE, AS IT EFFECT THE TESTCASES.
 	pub const FeePoolSize: Balance = 10_000;
@@ -283,6 +310,8 @@ impl Config for Runtime {
 	type TransactionByteFee = TransactionByteFee;
 	type FeeMultiplierUpdate = ();
 	type Swap = SpecificJointsSwap<DEXModule, AlternativeSwapPathJointList>;
+	type DEX = DEXModule;
+	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = MockPriceSource;
@@ -293,6 +322,11 @@ impl Config for Runtime {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type AddressMapping = MockAddressMapping;
+	type SponsoredTargetOf = ();
+	type MaxSponsoredTargets = ConstU32<2>;
+	type DynamicBaseFee = MockDynamicBaseFee;
+	type OnDynamicBaseFee = DealWithBaseFee;
 }
 
 thread_local! {
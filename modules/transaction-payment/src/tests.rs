@@ -27,19 +27,23 @@ use frame_support::{
 	weights::{DispatchClass, DispatchInfo, Pays},
 };
 use mock::{
-	AccountId, BlockWeights, Call, Currencies, DEXModule, ExtBuilder, FeePoolSize, MockPriceSource, Origin, Runtime,
-	System, TransactionPayment, ACA, ALICE, AUSD, BOB, CHARLIE, DAVE, DOT, FEE_UNBALANCED_AMOUNT, LDOT,
-	TIP_UNBALANCED_AMOUNT,
+	AccountId, BlockWeights, Call, Currencies, DEXModule, ExtBuilder, FeePoolSize, MockDynamicBaseFee, MockPriceSource,
+	Origin, Runtime, System, TransactionPayment, ACA, ALICE, AUSD, BASE_FEE_UNBALANCED_AMOUNT, BOB, CHARLIE, DAVE, DOT,
+	FEE_UNBALANCED_AMOUNT, LDOT, TIP_UNBALANCED_AMOUNT,
 };
 use orml_traits::{MultiCurrency, MultiLockableCurrency};
 use pallet_balances::ReserveData;
-use primitives::currency::*;
+use primitives::{currency::*, TradingPair};
+use sp_core::H160;
 use sp_io::TestExternalities;
 use sp_runtime::{
 	testing::TestXt,
-	traits::{One, UniqueSaturatedInto},
+	traits::{BadOrigin, One, UniqueSaturatedInto},
+};
+use support::{
+	mocks::MockAddressMapping, AddressMapping, BuyWeightRate, DEXManager, Price,
+	TransactionPayment as TransactionPaymentT,
 };
-use support::{BuyWeightRate, DEXManager, Price, TransactionPayment as TransactionPaymentT};
 use xcm::latest::prelude::*;
 use xcm::prelude::GeneralKey;
 
@@ -364,6 +368,49 @@ fn pre_post_dispatch_and_refund_native_is_enough() {
 	});
 }
 
+#[test]
+fn pre_post_dispatch_charge_dynamic_base_fee() {
+	builder_with_dex_and_fee_pool(false).execute_with(|| {
+		// the base fee is lower than the weight fee
+		MockDynamicBaseFee::set_base_fee_per_weight(Some(Multiplier::saturating_from_rational(1, 2)));
+		assert_eq!(TransactionPayment::compute_call_fee(23, &CALL, &INFO, 0), 23 * 2 + 1000);
+
+		// the base fee is charged instead of the weight fee
+		MockDynamicBaseFee::set_base_fee_per_weight(Some(Multiplier::saturating_from_integer(2)));
+		let fee = 23 * 2 + 2000;
+		assert_eq!(TransactionPayment::compute_call_fee(23, &CALL, &INFO, 0), fee);
+
+		let pre = ChargeTransactionPayment::<Runtime>::from(0)
+			.pre_dispatch(&ALICE, &CALL, &INFO, 23)
+			.unwrap();
+		assert_eq!(Currencies::free_balance(ACA, &ALICE), 100000 - fee);
+
+		// the base fee changed during dispatch doesn't affect the refund
+		MockDynamicBaseFee::set_base_fee_per_weight(None);
+		assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+			Some(pre),
+			&INFO,
+			&POST_INFO,
+			23,
+			&Ok(())
+		));
+
+		let actual_fee = 23 * 2 + 1600;
+		assert_eq!(Currencies::free_balance(ACA, &ALICE), 100000 - actual_fee);
+		// the base fee of the actual weight goes to the base fee handler
+		assert_eq!(BASE_FEE_UNBALANCED_AMOUNT.with(|a| *a.borrow()), 1600);
+		assert_eq!(FEE_UNBALANCED_AMOUNT.with(|a| *a.borrow()), 23 * 2);
+		System::assert_has_event(crate::mock::Event::TransactionPayment(
+			crate::Event::TransactionFeePaid {
+				who: ALICE,
+				actual_fee,
+				actual_tip: 0,
+				actual_surplus: 0,
+			},
+		));
+	});
+}
+
 #[test]
 fn pre_post_dispatch_and_refund_with_fee_currency_call_default_fee_tokens_work() {
 	// default fee token, and enabled by charge fee pool
@@ -866,8 +913,54 @@ fn charges_fee_when_validate_with_fee_currency_call_use_pool() {
 			50
 		));
 		assert_eq!(sub_dot_aca - fee_amount, Currencies::free_balance(ACA, &dot_acc));
-		assert_eq!(sub_dot_dot + fee_amount / 10, Currencies::free_balance(DOT, &dot_acc)); // 1 DOT = 10
-		                                                                            // ACA
+		assert_eq!(sub_dot_dot + fee_amount / 10, Currencies::free_balance(DOT, &dot_acc));
+		// 1 DOT = 10
+		// ACA
+	});
+}
+
+#[test]
+fn charges_fee_when_validate_with_fee_currency_call_use_dex_share() {
+	// Enable dex with Alice, and initialize tx charge fee pool
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		let lp_currency_id = TradingPair::from_currency_ids(LDOT, ACA)
+			.unwrap()
+			.dex_share_currency_id();
+		assert_ok!(Currencies::transfer(Origin::signed(ALICE), BOB, lp_currency_id, 1000));
+		let total_share = Currencies::total_issuance(lp_currency_id);
+
+		// BOB has no native asset, the ACA side of DEX share needs the least share to redeem.
+		let fee: Balance = 50 * 2 + 100 + 10;
+		let fee_surplus = fee + CustomFeeSurplus::get().mul_ceil(fee);
+		assert_eq!(315, fee_surplus);
+		let redeem_share = 315 * total_share / 1000 + 1;
+
+		assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).validate(
+			&BOB,
+			&with_fee_currency_call(lp_currency_id),
+			&INFO2,
+			50
+		));
+		let (pool_ldot, pool_aca) = DEXModule::get_liquidity_pool(LDOT, ACA);
+		let withdrawn_aca = 1000 - pool_aca;
+		assert!(withdrawn_aca >= 315);
+		assert_eq!(1000 - redeem_share, Currencies::free_balance(lp_currency_id, &BOB));
+		assert_eq!(100 - pool_ldot, Currencies::free_balance(LDOT, &BOB));
+		assert_eq!(withdrawn_aca - 305, Currencies::free_balance(ACA, &BOB));
+
+		// the DEX share of a pool without liquidity can't be used to pay fee.
+		let empty_lp_currency_id = TradingPair::from_currency_ids(DOT, ACA)
+			.unwrap()
+			.dex_share_currency_id();
+		assert_noop!(
+			ChargeTransactionPayment::<Runtime>::from(0).validate(
+				&BOB,
+				&with_fee_currency_call(empty_lp_currency_id),
+				&INFO2,
+				50
+			),
+			TransactionValidityError::Invalid(InvalidTransaction::Payment)
+		);
 	});
 }
 
@@ -1261,6 +1354,185 @@ fn charge_fee_by_default_fee_tokens_second_priority() {
 	});
 }
 
+#[test]
+fn compute_fee_in_currencies_works() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		let alternative_fee_swap_deposit: u128 =
+			<<Runtime as Config>::AlternativeFeeSwapDeposit as frame_support::traits::Get<u128>>::get();
+		assert_ok!(Currencies::update_balance(
+			Origin::root(),
+			BOB,
+			ACA,
+			alternative_fee_swap_deposit.try_into().unwrap(),
+		));
+		assert_ok!(TransactionPayment::set_alternative_fee_swap_path(
+			Origin::signed(BOB),
+			Some(vec![DOT, AUSD, ACA])
+		));
+		assert_ok!(<Currencies as MultiCurrency<_>>::transfer(DOT, &ALICE, &BOB, 300));
+
+		assert_eq!(
+			TransactionPayment::compute_fee_in_currencies(&BOB, &CALL, 0, vec![]),
+			vec![CurrencyFeeDetails {
+				currency_id: ACA,
+				fee: 0,
+				path: FeePaymentPath::Native,
+				sufficient: true,
+			}]
+		);
+
+		// native is not enough: fee=2000ACA, swap 2000+10(ED)=2010ACA and the surplus:
+		// alternative fee swap path: 2010+500=2510ACA, swap from dex with 51 DOT.
+		// AUSD (default fee token): 2010+500=2510ACA, 1 ACA = 10 AUSD.
+		// DOT (non-default fee token): 2010+1000=3010ACA, 10 ACA = 1 DOT.
+		assert_eq!(
+			TransactionPayment::compute_fee_in_currencies(&BOB, &CALL, 2000, vec![]),
+			vec![
+				CurrencyFeeDetails {
+					currency_id: ACA,
+					fee: 2000,
+					path: FeePaymentPath::Native,
+					sufficient: false,
+				},
+				CurrencyFeeDetails {
+					currency_id: DOT,
+					fee: 51,
+					path: FeePaymentPath::Dex(vec![DOT, AUSD, ACA]),
+					sufficient: true,
+				},
+				CurrencyFeeDetails {
+					currency_id: AUSD,
+					fee: 25100,
+					path: FeePaymentPath::FeePool,
+					sufficient: false,
+				},
+				CurrencyFeeDetails {
+					currency_id: DOT,
+					fee: 301,
+					path: FeePaymentPath::FeePool,
+					sufficient: false,
+				},
+			]
+		);
+
+		// native is enough, the alternative currencies pay the whole fee and the surplus.
+		assert_ok!(Currencies::update_balance(Origin::root(), BOB, ACA, 10000));
+		assert_ok!(Currencies::update_balance(Origin::root(), BOB, AUSD, 25000));
+		let fees = TransactionPayment::compute_fee_in_currencies(&BOB, &CALL, 2000, vec![]);
+		assert_eq!(
+			fees.iter()
+				.map(|details| (details.currency_id, details.fee, details.sufficient))
+				.collect::<Vec<_>>(),
+			vec![
+				(ACA, 2000, true),
+				(DOT, 51, true),
+				(AUSD, 25000, true),
+				(DOT, 300, true)
+			]
+		);
+	});
+}
+
+#[test]
+fn compute_fee_in_currencies_with_fee_currency_works() {
+	builder_with_dex_and_fee_pool(true).execute_with(|| {
+		let lp_currency_id = TradingPair::from_currency_ids(LDOT, ACA)
+			.unwrap()
+			.dex_share_currency_id();
+		let empty_lp_currency_id = TradingPair::from_currency_ids(DOT, ACA)
+			.unwrap()
+			.dex_share_currency_id();
+		assert_ok!(Currencies::transfer(Origin::signed(ALICE), BOB, lp_currency_id, 1000));
+		let total_share = Currencies::total_issuance(lp_currency_id);
+
+		// BOB has no native asset: fee=200ACA, swap 200+10(ED)=210ACA and the surplus:
+		// AUSD (default fee token): 210+50=260ACA, 1 ACA = 10 AUSD.
+		// DOT (non-default fee token): 210+100=310ACA, 10 ACA = 1 DOT.
+		// `with_fee_currency`: 210+105=315ACA, redeem the ACA side of DEX share or swap LDOT.
+		let redeem_share = 315 * total_share / 1000 + 1;
+		let (ldot_amount, _) =
+			<Runtime as Config>::Swap::get_swap_amount(LDOT, ACA, SwapLimit::ExactTarget(Balance::MAX, 315)).unwrap();
+		assert_eq!(
+			TransactionPayment::compute_fee_in_currencies(
+				&BOB,
+				&CALL,
+				200,
+				vec![ACA, AUSD, lp_currency_id, empty_lp_currency_id, LDOT]
+			),
+			vec![
+				CurrencyFeeDetails {
+					currency_id: ACA,
+					fee: 200,
+					path: FeePaymentPath::Native,
+					sufficient: false,
+				},
+				CurrencyFeeDetails {
+					currency_id: AUSD,
+					fee: 2600,
+					path: FeePaymentPath::FeePool,
+					sufficient: false,
+				},
+				CurrencyFeeDetails {
+					currency_id: DOT,
+					fee: 31,
+					path: FeePaymentPath::FeePool,
+					sufficient: false,
+				},
+				CurrencyFeeDetails {
+					currency_id: lp_currency_id,
+					fee: redeem_share,
+					path: FeePaymentPath::LiquidityToken,
+					sufficient: true,
+				},
+				CurrencyFeeDetails {
+					currency_id: LDOT,
+					fee: ldot_amount,
+					path: FeePaymentPath::Swap,
+					sufficient: false,
+				},
+			]
+		);
+
+		// the charge fee pool without enough native asset is skipped.
+		let dot_sub_account = Pallet::<Runtime>::sub_account_id(DOT);
+		let pool_native = Currencies::free_balance(ACA, &dot_sub_account);
+		assert_ok!(Currencies::update_balance(
+			Origin::root(),
+			dot_sub_account,
+			ACA,
+			-((pool_native - 300) as i128),
+		));
+		assert_eq!(
+			TransactionPayment::compute_fee_in_currencies(&BOB, &CALL, 200, vec![])
+				.iter()
+				.map(|details| details.currency_id)
+				.collect::<Vec<_>>(),
+			vec![ACA, AUSD]
+		);
+
+		// only the paymaster is returned if it sponsors the call.
+		let currencies_target = <() as SponsoredTargetOf<Call>>::sponsored_target(&CALL).unwrap();
+		assert_ok!(TransactionPayment::force_register_paymaster(
+			Origin::signed(ALICE),
+			CHARLIE,
+			vec![currencies_target],
+			4000,
+			0,
+			0,
+			100
+		));
+		assert_eq!(
+			TransactionPayment::compute_fee_in_currencies(&BOB, &CALL, 200, vec![LDOT]),
+			vec![CurrencyFeeDetails {
+				currency_id: ACA,
+				fee: 200,
+				path: FeePaymentPath::Paymaster,
+				sufficient: true,
+			}]
+		);
+	});
+}
+
 #[test]
 fn query_info_works() {
 	ExtBuilder::default()
@@ -2242,3 +2514,313 @@ fn with_fee_call_validation_works() {
 			);
 		});
 }
+
+#[test]
+fn register_update_and_remove_paymaster_works() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			let currencies_target = <() as SponsoredTargetOf<Call>>::sponsored_target(&CALL).unwrap();
+			let contract = H160::repeat_byte(1);
+			let contract_account = MockAddressMapping::get_account_id(&contract);
+			let info = PaymasterInfo {
+				budget: 10000,
+				max_txs_per_period: 1,
+				period: 10,
+				expire_at: 100,
+			};
+
+			assert_noop!(
+				TransactionPayment::register_paymaster(Origin::signed(CHARLIE), vec![], 10000, 1, 10, 100),
+				Error::<Runtime>::InvalidSponsoredTargets
+			);
+			// only `UpdateOrigin` can register the pallet targets.
+			assert_noop!(
+				TransactionPayment::register_paymaster(
+					Origin::signed(CHARLIE),
+					vec![currencies_target],
+					10000,
+					1,
+					10,
+					100
+				),
+				Error::<Runtime>::NoPermission
+			);
+			assert_noop!(
+				TransactionPayment::force_register_paymaster(
+					Origin::signed(CHARLIE),
+					CHARLIE,
+					vec![currencies_target],
+					10000,
+					1,
+					10,
+					100
+				),
+				BadOrigin
+			);
+			assert_noop!(
+				TransactionPayment::force_register_paymaster(
+					Origin::signed(ALICE),
+					CHARLIE,
+					vec![
+						SponsoredTarget::Pallet(100),
+						SponsoredTarget::Pallet(101),
+						SponsoredTarget::Pallet(102)
+					],
+					10000,
+					1,
+					10,
+					100
+				),
+				Error::<Runtime>::InvalidSponsoredTargets
+			);
+			assert_noop!(
+				TransactionPayment::force_register_paymaster(
+					Origin::signed(ALICE),
+					CHARLIE,
+					vec![currencies_target],
+					10000,
+					1,
+					0,
+					100
+				),
+				Error::<Runtime>::InvalidPaymasterInfo
+			);
+			assert_noop!(
+				TransactionPayment::force_register_paymaster(
+					Origin::signed(ALICE),
+					CHARLIE,
+					vec![currencies_target],
+					10000,
+					1,
+					10,
+					1
+				),
+				Error::<Runtime>::InvalidPaymasterInfo
+			);
+			assert_noop!(
+				TransactionPayment::register_paymaster(
+					Origin::signed(CHARLIE),
+					vec![SponsoredTarget::Contract(contract)],
+					10000,
+					1,
+					10,
+					100
+				),
+				Error::<Runtime>::NoPermission
+			);
+
+			assert_ok!(TransactionPayment::force_register_paymaster(
+				Origin::signed(ALICE),
+				CHARLIE,
+				vec![currencies_target],
+				10000,
+				1,
+				10,
+				100
+			));
+			System::assert_last_event(crate::mock::Event::TransactionPayment(
+				crate::Event::PaymasterRegistered {
+					paymaster: CHARLIE,
+					targets: vec![currencies_target],
+					info: info.clone(),
+				},
+			));
+			assert_eq!(Paymasters::<Runtime>::get(CHARLIE), Some(info));
+			assert_eq!(SponsoredTargets::<Runtime>::get(currencies_target), Some(CHARLIE));
+			assert_eq!(
+				PaymasterTargets::<Runtime>::get(CHARLIE).into_inner(),
+				vec![currencies_target]
+			);
+
+			assert_noop!(
+				TransactionPayment::force_register_paymaster(
+					Origin::signed(ALICE),
+					CHARLIE,
+					vec![SponsoredTarget::Pallet(100)],
+					10000,
+					1,
+					10,
+					100
+				),
+				Error::<Runtime>::PaymasterAlreadyExisted
+			);
+			assert_noop!(
+				TransactionPayment::force_register_paymaster(
+					Origin::signed(ALICE),
+					ALICE,
+					vec![currencies_target],
+					10000,
+					1,
+					10,
+					100
+				),
+				Error::<Runtime>::SponsoredTargetAlreadyExisted
+			);
+			// the contract sponsors the calls to itself.
+			assert_ok!(TransactionPayment::register_paymaster(
+				Origin::signed(contract_account.clone()),
+				vec![SponsoredTarget::Contract(contract)],
+				10000,
+				0,
+				0,
+				100
+			));
+			assert_eq!(
+				SponsoredTargets::<Runtime>::get(SponsoredTarget::Contract(contract)),
+				Some(contract_account.clone())
+			);
+
+			assert_noop!(
+				TransactionPayment::update_paymaster(Origin::signed(BOB), 5000, 0, 0, 200),
+				Error::<Runtime>::PaymasterNotFound
+			);
+			assert_ok!(TransactionPayment::update_paymaster(
+				Origin::signed(CHARLIE),
+				5000,
+				0,
+				0,
+				200
+			));
+			let new_info = PaymasterInfo {
+				budget: 5000,
+				max_txs_per_period: 0,
+				period: 0,
+				expire_at: 200,
+			};
+			System::assert_last_event(crate::mock::Event::TransactionPayment(crate::Event::PaymasterUpdated {
+				paymaster: CHARLIE,
+				info: new_info.clone(),
+			}));
+			assert_eq!(Paymasters::<Runtime>::get(CHARLIE), Some(new_info));
+
+			assert_noop!(
+				TransactionPayment::remove_paymaster(Origin::signed(BOB), CHARLIE),
+				Error::<Runtime>::NoPermission
+			);
+			assert_ok!(TransactionPayment::remove_paymaster(Origin::signed(CHARLIE), CHARLIE));
+			System::assert_last_event(crate::mock::Event::TransactionPayment(crate::Event::PaymasterRemoved {
+				paymaster: CHARLIE,
+			}));
+			// `UpdateOrigin` can remove any paymaster.
+			assert_ok!(TransactionPayment::remove_paymaster(
+				Origin::signed(ALICE),
+				contract_account.clone()
+			));
+			assert_eq!(Paymasters::<Runtime>::get(CHARLIE), None);
+			assert_eq!(SponsoredTargets::<Runtime>::get(currencies_target), None);
+			assert_eq!(PaymasterTargets::<Runtime>::get(CHARLIE).into_inner(), vec![]);
+			assert_eq!(
+				SponsoredTargets::<Runtime>::get(SponsoredTarget::Contract(contract)),
+				None
+			);
+			assert_noop!(
+				TransactionPayment::remove_paymaster(Origin::signed(CHARLIE), CHARLIE),
+				Error::<Runtime>::PaymasterNotFound
+			);
+		});
+}
+
+#[test]
+fn charges_fee_from_paymaster_works() {
+	ExtBuilder::default()
+		.one_hundred_thousand_for_alice_n_charlie()
+		.build()
+		.execute_with(|| {
+			let currencies_target = <() as SponsoredTargetOf<Call>>::sponsored_target(&CALL).unwrap();
+			assert_ok!(TransactionPayment::force_register_paymaster(
+				Origin::signed(ALICE),
+				CHARLIE,
+				vec![currencies_target],
+				4000,
+				1,
+				10,
+				30
+			));
+			// fee = len * byte_fee + weight
+			let fee = 10 * 2 + 1000;
+			assert_eq!(Currencies::free_balance(ACA, &BOB), 0);
+
+			// BOB has no native asset, the paymaster pays the fee.
+			let pre = ChargeTransactionPayment::<Runtime>::from(0)
+				.pre_dispatch(&BOB, &CALL, &INFO, 10)
+				.unwrap();
+			System::assert_last_event(crate::mock::Event::TransactionPayment(
+				crate::Event::TransactionSponsored {
+					paymaster: CHARLIE,
+					who: BOB,
+					fee,
+				},
+			));
+			assert_eq!(Currencies::free_balance(ACA, &CHARLIE), 100000 - fee);
+			assert_eq!(Paymasters::<Runtime>::get(CHARLIE).unwrap().budget, 4000 - fee);
+			assert_eq!(PaymasterUsage::<Runtime>::get(CHARLIE, BOB), (1, 1));
+
+			// the refund goes back to the paymaster and its budget.
+			assert_ok!(ChargeTransactionPayment::<Runtime>::post_dispatch(
+				Some(pre),
+				&INFO,
+				&POST_INFO,
+				10,
+				&Ok(())
+			));
+			assert_eq!(Currencies::free_balance(ACA, &CHARLIE), 100000 - fee + 200);
+			assert_eq!(Paymasters::<Runtime>::get(CHARLIE).unwrap().budget, 4000 - fee + 200);
+
+			// BOB exceeds the rate limit of the period, and can't pay by himself.
+			assert_noop!(
+				ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&BOB, &CALL, &INFO, 10),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+
+			// the transaction with tip is not sponsored.
+			assert_noop!(
+				ChargeTransactionPayment::<Runtime>::from(10).pre_dispatch(&DAVE, &CALL, &INFO, 10),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+
+			// the rate limit is for every user.
+			assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&DAVE, &CALL, &INFO, 10));
+			assert_eq!(
+				Paymasters::<Runtime>::get(CHARLIE).unwrap().budget,
+				4000 - fee * 2 + 200
+			);
+
+			// next period
+			System::set_block_number(11);
+			assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&BOB, &CALL, &INFO, 10));
+			assert_eq!(PaymasterUsage::<Runtime>::get(CHARLIE, BOB), (11, 1));
+			assert_eq!(
+				Paymasters::<Runtime>::get(CHARLIE).unwrap().budget,
+				4000 - fee * 3 + 200
+			);
+			assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&DAVE, &CALL, &INFO, 10));
+			assert_eq!(
+				Paymasters::<Runtime>::get(CHARLIE).unwrap().budget,
+				4000 - fee * 4 + 200
+			);
+
+			// the budget is not enough.
+			System::set_block_number(21);
+			assert_noop!(
+				ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&DAVE, &CALL, &INFO, 10),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+
+			// the paymaster is expired.
+			assert_ok!(TransactionPayment::update_paymaster(
+				Origin::signed(CHARLIE),
+				5000,
+				0,
+				0,
+				30
+			));
+			assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&DAVE, &CALL, &INFO, 10));
+			System::set_block_number(30);
+			assert_noop!(
+				ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&DAVE, &CALL, &INFO, 10),
+				TransactionValidityError::Invalid(InvalidTransaction::Payment)
+			);
+		});
+}
@@ -53,7 +53,11 @@ pub trait WeightInfo {
 	fn with_fee_path() -> Weight;
 	fn with_fee_aggregated_path() -> Weight;
 	fn with_fee_currency() -> Weight;
+	fn with_fee_currency_liquidity_token() -> Weight;
 	fn with_fee_paid_by() -> Weight;
+	fn register_paymaster(n: u32, ) -> Weight;
+	fn update_paymaster() -> Weight;
+	fn remove_paymaster(n: u32, ) -> Weight;
 }
 
 /// Weights for module_transaction_payment using the Acala node and recommended hardware.
@@ -102,6 +106,16 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 		(193_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 	}
+	// Storage: Dex TradingPairStatuses (r:2 w:0)
+	// Storage: Dex LiquidityPool (r:2 w:2)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Storage: System Account (r:2 w:2)
+	fn with_fee_currency_liquidity_token() -> Weight {
+		(248_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
 	fn with_fee_paid_by() -> Weight {
 		(193_000_000 as Weight)
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
@@ -113,6 +127,36 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: TransactionPayment Paymasters (r:1 w:1)
+	// Storage: TransactionPayment SponsoredTargets (r:1 w:1)
+	// Storage: TransactionPayment PaymasterTargets (r:0 w:1)
+	fn register_paymaster(n: u32, ) -> Weight {
+		(19_873_000 as Weight)
+			// Standard Error: 85_000
+			.saturating_add((3_412_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPayment Paymasters (r:1 w:1)
+	fn update_paymaster() -> Weight {
+		(14_268_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPayment Paymasters (r:1 w:1)
+	// Storage: TransactionPayment PaymasterTargets (r:1 w:1)
+	// Storage: TransactionPayment PaymasterUsage (r:0 w:1)
+	// Storage: TransactionPayment SponsoredTargets (r:0 w:1)
+	fn remove_paymaster(n: u32, ) -> Weight {
+		(21_734_000 as Weight)
+			// Standard Error: 74_000
+			.saturating_add((2_987_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -147,8 +191,33 @@ impl WeightInfo for () {
 		(193_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 	}
+	fn with_fee_currency_liquidity_token() -> Weight {
+		(248_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
 	fn with_fee_paid_by() -> Weight {
 		(193_000_000 as Weight)
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 	}
+	fn register_paymaster(n: u32, ) -> Weight {
+		(19_873_000 as Weight)
+			.saturating_add((3_412_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	fn update_paymaster() -> Weight {
+		(14_268_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_paymaster(n: u32, ) -> Weight {
+		(21_734_000 as Weight)
+			.saturating_add((2_987_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
 }
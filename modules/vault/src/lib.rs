@@ -0,0 +1,569 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! # Vault Module
+//!
+//! ## Overview
+//!
+//! Tokenized vaults following the ERC-4626 standard. A vault takes deposits of an underlying
+//! currency (e.g. DOT, LDOT or DEX shares) and mints `CurrencyId::VaultShare` tokens to the
+//! depositor. The share tokens are registered in the asset registry, so they can be transferred
+//! and traded on DEX like other tokens.
+//!
+//! The deposited assets are put to work by the `VaultStrategy` configured in the runtime, for
+//! example staking DEX shares to earn incentive rewards and compounding them. The share price
+//! grows with the yield of the strategy.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::{pallet_prelude::*, transactional, PalletId};
+use frame_system::pallet_prelude::*;
+use orml_traits::MultiCurrency;
+use primitives::{
+	currency::{AssetMetadata, VaultId},
+	Balance, CurrencyId,
+};
+use sp_core::U256;
+use sp_runtime::{
+	traits::{AccountIdConversion, One, Zero},
+	ArithmeticError,
+};
+use sp_std::boxed::Box;
+use support::{VaultManager, VaultShareRegistry};
+
+mod mock;
+mod tests;
+pub mod weights;
+
+pub use module::*;
+pub use weights::WeightInfo;
+
+/// The strategy which puts the underlying assets of vaults to work.
+pub trait VaultStrategy<AccountId> {
+	/// Amount of underlying invested by the strategy, not including the idle underlying in
+	/// `vault_account`.
+	fn invested_assets(vault_id: VaultId, vault_account: &AccountId, underlying: CurrencyId) -> Balance;
+	/// Invest `amount` of idle underlying in `vault_account`.
+	fn invest(vault_id: VaultId, vault_account: &AccountId, underlying: CurrencyId, amount: Balance) -> DispatchResult;
+	/// Divest `amount` of invested underlying back to `vault_account`.
+	fn divest(vault_id: VaultId, vault_account: &AccountId, underlying: CurrencyId, amount: Balance) -> DispatchResult;
+	/// Collect the yield and reinvest it.
+	fn harvest(vault_id: VaultId, vault_account: &AccountId, underlying: CurrencyId) -> DispatchResult;
+}
+
+/// Keep all the underlying idle in the vault account.
+impl<AccountId> VaultStrategy<AccountId> for () {
+	fn invested_assets(_: VaultId, _: &AccountId, _: CurrencyId) -> Balance {
+		Zero::zero()
+	}
+
+	fn invest(_: VaultId, _: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn divest(_: VaultId, _: &AccountId, _: CurrencyId, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn harvest(_: VaultId, _: &AccountId, _: CurrencyId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Information of a vault.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct VaultInfo {
+	/// The underlying currency of the vault.
+	pub underlying: CurrencyId,
+	/// The cap of total assets of the vault, no cap if `None`.
+	pub max_total_assets: Option<Balance>,
+}
+
+#[frame_support::pallet]
+pub mod module {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Currency for transfer underlying and mint vault shares.
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = CurrencyId, Balance = Balance>;
+
+		/// The strategy puts the underlying of vaults to work.
+		type Strategy: VaultStrategy<Self::AccountId>;
+
+		/// Registry of the metadata of vault shares.
+		type VaultShareRegistry: VaultShareRegistry<Balance>;
+
+		/// The vault module id, the underlying of vaults are kept in its sub accounts.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The origin which may create and update vaults.
+		type UpdateOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for the extrinsics in this module.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The vault does not exist.
+		VaultNotFound,
+		/// The underlying currency is not supported.
+		InvalidUnderlying,
+		/// The amount is zero or too small.
+		InvalidAmount,
+		/// The assets exceed the max deposit of the vault.
+		ExceedMaxDeposit,
+		/// The shares exceed the max mint of the vault.
+		ExceedMaxMint,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A vault is created.
+		VaultCreated {
+			vault_id: VaultId,
+			underlying: CurrencyId,
+			max_total_assets: Option<Balance>,
+		},
+		/// A vault is updated.
+		VaultUpdated {
+			vault_id: VaultId,
+			max_total_assets: Option<Balance>,
+		},
+		/// Deposited underlying and minted shares.
+		Deposited {
+			vault_id: VaultId,
+			who: T::AccountId,
+			assets: Balance,
+			shares: Balance,
+		},
+		/// Burned shares and withdrawn underlying.
+		Withdrawn {
+			vault_id: VaultId,
+			who: T::AccountId,
+			assets: Balance,
+			shares: Balance,
+		},
+		/// The yield of the vault is harvested.
+		Harvested { vault_id: VaultId, total_assets: Balance },
+	}
+
+	/// Next available vault id.
+	///
+	/// NextVaultId: VaultId
+	#[pallet::storage]
+	#[pallet::getter(fn next_vault_id)]
+	pub type NextVaultId<T: Config> = StorageValue<_, VaultId, ValueQuery>;
+
+	/// The vaults.
+	///
+	/// Vaults: map VaultId => Option<VaultInfo>
+	#[pallet::storage]
+	#[pallet::getter(fn vaults)]
+	pub type Vaults<T: Config> = StorageMap<_, Twox64Concat, VaultId, VaultInfo, OptionQuery>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a vault of `underlying` and register the metadata of its share token.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `underlying`: the underlying currency of the vault.
+		/// - `max_total_assets`: the cap of total assets of the vault, no cap if `None`.
+		/// - `metadata`: the metadata of the share token.
+		#[pallet::weight(T::WeightInfo::create_vault())]
+		#[transactional]
+		pub fn create_vault(
+			origin: OriginFor<T>,
+			underlying: CurrencyId,
+			max_total_assets: Option<Balance>,
+			metadata: Box<AssetMetadata<Balance>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(!underlying.is_vault_share_currency_id(), Error::<T>::InvalidUnderlying);
+
+			let vault_id = NextVaultId::<T>::try_mutate(|current| -> Result<VaultId, DispatchError> {
+				let id = *current;
+				*current = current.checked_add(One::one()).ok_or(ArithmeticError::Overflow)?;
+				Ok(id)
+			})?;
+
+			Vaults::<T>::insert(
+				vault_id,
+				VaultInfo {
+					underlying,
+					max_total_assets,
+				},
+			);
+			T::VaultShareRegistry::register_vault_share(vault_id, &metadata)?;
+
+			Self::deposit_event(Event::VaultCreated {
+				vault_id,
+				underlying,
+				max_total_assets,
+			});
+			Ok(())
+		}
+
+		/// Update the cap of total assets of the vault.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `vault_id`: the vault id.
+		/// - `max_total_assets`: the cap of total assets of the vault, no cap if `None`.
+		#[pallet::weight(T::WeightInfo::update_vault())]
+		#[transactional]
+		pub fn update_vault(
+			origin: OriginFor<T>,
+			vault_id: VaultId,
+			max_total_assets: Option<Balance>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			Vaults::<T>::try_mutate(vault_id, |maybe_vault| -> DispatchResult {
+				let vault = maybe_vault.as_mut().ok_or(Error::<T>::VaultNotFound)?;
+				vault.max_total_assets = max_total_assets;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::VaultUpdated {
+				vault_id,
+				max_total_assets,
+			});
+			Ok(())
+		}
+
+		/// Deposit `assets` of underlying and mint shares.
+		///
+		/// - `vault_id`: the vault id.
+		/// - `assets`: the amount of underlying to deposit.
+		#[pallet::weight(T::WeightInfo::deposit())]
+		#[transactional]
+		pub fn deposit(origin: OriginFor<T>, vault_id: VaultId, #[pallet::compact] assets: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_deposit(&who, vault_id, assets)?;
+			Ok(())
+		}
+
+		/// Mint exactly `shares` by depositing underlying.
+		///
+		/// - `vault_id`: the vault id.
+		/// - `shares`: the amount of shares to mint.
+		#[pallet::weight(T::WeightInfo::mint())]
+		#[transactional]
+		pub fn mint(origin: OriginFor<T>, vault_id: VaultId, #[pallet::compact] shares: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_mint(&who, vault_id, shares)?;
+			Ok(())
+		}
+
+		/// Withdraw `assets` of underlying by burning shares.
+		///
+		/// - `vault_id`: the vault id.
+		/// - `assets`: the amount of underlying to withdraw.
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		#[transactional]
+		pub fn withdraw(origin: OriginFor<T>, vault_id: VaultId, #[pallet::compact] assets: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_withdraw(&who, vault_id, assets)?;
+			Ok(())
+		}
+
+		/// Burn exactly `shares` for underlying.
+		///
+		/// - `vault_id`: the vault id.
+		/// - `shares`: the amount of shares to redeem.
+		#[pallet::weight(T::WeightInfo::redeem())]
+		#[transactional]
+		pub fn redeem(origin: OriginFor<T>, vault_id: VaultId, #[pallet::compact] shares: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_redeem(&who, vault_id, shares)?;
+			Ok(())
+		}
+
+		/// Collect the yield of the vault and reinvest it by the strategy.
+		///
+		/// The dispatch origin of this call must be `UpdateOrigin`.
+		///
+		/// - `vault_id`: the vault id.
+		#[pallet::weight(T::WeightInfo::harvest())]
+		#[transactional]
+		pub fn harvest(origin: OriginFor<T>, vault_id: VaultId) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let vault = Self::vaults(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+			T::Strategy::harvest(vault_id, &Self::account_id(vault_id), vault.underlying)?;
+
+			Self::deposit_event(Event::Harvested {
+				vault_id,
+				total_assets: Self::total_assets_of(vault_id, &vault),
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account keeps the underlying of the vault.
+	pub fn account_id(vault_id: VaultId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(vault_id)
+	}
+
+	fn total_assets_of(vault_id: VaultId, vault: &VaultInfo) -> Balance {
+		let vault_account = Self::account_id(vault_id);
+		T::Currency::free_balance(vault.underlying, &vault_account).saturating_add(T::Strategy::invested_assets(
+			vault_id,
+			&vault_account,
+			vault.underlying,
+		))
+	}
+
+	fn total_supply(vault_id: VaultId) -> Balance {
+		T::Currency::total_issuance(CurrencyId::VaultShare(vault_id))
+	}
+
+	/// Calculate `amount * (numerator + 1) / (denominator + 1)`. The virtual asset and share make
+	/// the donation to an empty vault unprofitable to inflate the share price.
+	fn convert(amount: Balance, numerator: Balance, denominator: Balance, round_up: bool) -> Balance {
+		let numerator = U256::from(numerator).saturating_add(U256::one());
+		let denominator = U256::from(denominator).saturating_add(U256::one());
+		let product = U256::from(amount).saturating_mul(numerator);
+		let result = if round_up {
+			product.saturating_add(denominator.saturating_sub(U256::one())) / denominator
+		} else {
+			product / denominator
+		};
+
+		TryInto::<Balance>::try_into(result).unwrap_or_else(|_| Balance::max_value())
+	}
+
+	fn shares_for_assets(vault_id: VaultId, vault: &VaultInfo, assets: Balance, round_up: bool) -> Balance {
+		Self::convert(
+			assets,
+			Self::total_supply(vault_id),
+			Self::total_assets_of(vault_id, vault),
+			round_up,
+		)
+	}
+
+	fn assets_for_shares(vault_id: VaultId, vault: &VaultInfo, shares: Balance, round_up: bool) -> Balance {
+		Self::convert(
+			shares,
+			Self::total_assets_of(vault_id, vault),
+			Self::total_supply(vault_id),
+			round_up,
+		)
+	}
+
+	fn max_deposit_of(vault_id: VaultId, vault: &VaultInfo) -> Balance {
+		vault.max_total_assets.map_or(Balance::max_value(), |max_total_assets| {
+			max_total_assets.saturating_sub(Self::total_assets_of(vault_id, vault))
+		})
+	}
+
+	fn max_mint_of(vault_id: VaultId, vault: &VaultInfo) -> Balance {
+		match vault.max_total_assets {
+			Some(_) => Self::shares_for_assets(vault_id, vault, Self::max_deposit_of(vault_id, vault), false),
+			None => Balance::max_value(),
+		}
+	}
+
+	pub fn do_deposit(who: &T::AccountId, vault_id: VaultId, assets: Balance) -> Result<Balance, DispatchError> {
+		let vault = Self::vaults(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+		ensure!(
+			assets <= Self::max_deposit_of(vault_id, &vault),
+			Error::<T>::ExceedMaxDeposit
+		);
+
+		let shares = Self::shares_for_assets(vault_id, &vault, assets, false);
+		Self::do_deposit_and_mint(who, vault_id, &vault, assets, shares)?;
+		Ok(shares)
+	}
+
+	pub fn do_mint(who: &T::AccountId, vault_id: VaultId, shares: Balance) -> Result<Balance, DispatchError> {
+		let vault = Self::vaults(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+		ensure!(shares <= Self::max_mint_of(vault_id, &vault), Error::<T>::ExceedMaxMint);
+
+		let assets = Self::assets_for_shares(vault_id, &vault, shares, true);
+		Self::do_deposit_and_mint(who, vault_id, &vault, assets, shares)?;
+		Ok(assets)
+	}
+
+	pub fn do_withdraw(who: &T::AccountId, vault_id: VaultId, assets: Balance) -> Result<Balance, DispatchError> {
+		let vault = Self::vaults(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+
+		let shares = Self::shares_for_assets(vault_id, &vault, assets, true);
+		Self::do_burn_and_withdraw(who, vault_id, &vault, assets, shares)?;
+		Ok(shares)
+	}
+
+	pub fn do_redeem(who: &T::AccountId, vault_id: VaultId, shares: Balance) -> Result<Balance, DispatchError> {
+		let vault = Self::vaults(vault_id).ok_or(Error::<T>::VaultNotFound)?;
+
+		let assets = Self::assets_for_shares(vault_id, &vault, shares, false);
+		Self::do_burn_and_withdraw(who, vault_id, &vault, assets, shares)?;
+		Ok(assets)
+	}
+
+	fn do_deposit_and_mint(
+		who: &T::AccountId,
+		vault_id: VaultId,
+		vault: &VaultInfo,
+		assets: Balance,
+		shares: Balance,
+	) -> DispatchResult {
+		ensure!(!assets.is_zero() && !shares.is_zero(), Error::<T>::InvalidAmount);
+
+		let vault_account = Self::account_id(vault_id);
+		T::Currency::transfer(vault.underlying, who, &vault_account, assets)?;
+		T::Strategy::invest(vault_id, &vault_account, vault.underlying, assets)?;
+		T::Currency::deposit(CurrencyId::VaultShare(vault_id), who, shares)?;
+
+		Self::deposit_event(Event::Deposited {
+			vault_id,
+			who: who.clone(),
+			assets,
+			shares,
+		});
+		Ok(())
+	}
+
+	fn do_burn_and_withdraw(
+		who: &T::AccountId,
+		vault_id: VaultId,
+		vault: &VaultInfo,
+		assets: Balance,
+		shares: Balance,
+	) -> DispatchResult {
+		ensure!(!assets.is_zero() && !shares.is_zero(), Error::<T>::InvalidAmount);
+
+		T::Currency::withdraw(CurrencyId::VaultShare(vault_id), who, shares)?;
+
+		let vault_account = Self::account_id(vault_id);
+		let idle = T::Currency::free_balance(vault.underlying, &vault_account);
+		if idle < assets {
+			T::Strategy::divest(vault_id, &vault_account, vault.underlying, assets.saturating_sub(idle))?;
+		}
+		T::Currency::transfer(vault.underlying, &vault_account, who, assets)?;
+
+		Self::deposit_event(Event::Withdrawn {
+			vault_id,
+			who: who.clone(),
+			assets,
+			shares,
+		});
+		Ok(())
+	}
+}
+
+impl<T: Config> VaultManager<T::AccountId, CurrencyId, Balance> for Pallet<T> {
+	fn asset(vault_id: VaultId) -> Option<CurrencyId> {
+		Self::vaults(vault_id).map(|vault| vault.underlying)
+	}
+
+	fn total_assets(vault_id: VaultId) -> Balance {
+		Self::vaults(vault_id).map_or(Zero::zero(), |vault| Self::total_assets_of(vault_id, &vault))
+	}
+
+	fn convert_to_shares(vault_id: VaultId, assets: Balance) -> Balance {
+		Self::vaults(vault_id).map_or(Zero::zero(), |vault| {
+			Self::shares_for_assets(vault_id, &vault, assets, false)
+		})
+	}
+
+	fn convert_to_assets(vault_id: VaultId, shares: Balance) -> Balance {
+		Self::vaults(vault_id).map_or(Zero::zero(), |vault| {
+			Self::assets_for_shares(vault_id, &vault, shares, false)
+		})
+	}
+
+	fn max_deposit(vault_id: VaultId, _receiver: &T::AccountId) -> Balance {
+		Self::vaults(vault_id).map_or(Zero::zero(), |vault| Self::max_deposit_of(vault_id, &vault))
+	}
+
+	fn max_mint(vault_id: VaultId, _receiver: &T::AccountId) -> Balance {
+		Self::vaults(vault_id).map_or(Zero::zero(), |vault| Self::max_mint_of(vault_id, &vault))
+	}
+
+	fn max_withdraw(vault_id: VaultId, owner: &T::AccountId) -> Balance {
+		Self::vaults(vault_id).map_or(Zero::zero(), |vault| {
+			let shares = T::Currency::free_balance(CurrencyId::VaultShare(vault_id), owner);
+			Self::assets_for_shares(vault_id, &vault, shares, false)
+		})
+	}
+
+	fn max_redeem(vault_id: VaultId, owner: &T::AccountId) -> Balance {
+		if Self::vaults(vault_id).is_some() {
+			T::Currency::free_balance(CurrencyId::VaultShare(vault_id), owner)
+		} else {
+			Zero::zero()
+		}
+	}
+
+	fn preview_deposit(vault_id: VaultId, assets: Balance) -> Balance {
+		Self::convert_to_shares(vault_id, assets)
+	}
+
+	fn preview_mint(vault_id: VaultId, shares: Balance) -> Balance {
+		Self::vaults(vault_id).map_or(Zero::zero(), |vault| {
+			Self::assets_for_shares(vault_id, &vault, shares, true)
+		})
+	}
+
+	fn preview_withdraw(vault_id: VaultId, assets: Balance) -> Balance {
+		Self::vaults(vault_id).map_or(Zero::zero(), |vault| {
+			Self::shares_for_assets(vault_id, &vault, assets, true)
+		})
+	}
+
+	fn preview_redeem(vault_id: VaultId, shares: Balance) -> Balance {
+		Self::convert_to_assets(vault_id, shares)
+	}
+
+	#[transactional]
+	fn deposit(who: &T::AccountId, vault_id: VaultId, assets: Balance) -> Result<Balance, DispatchError> {
+		Self::do_deposit(who, vault_id, assets)
+	}
+
+	#[transactional]
+	fn mint(who: &T::AccountId, vault_id: VaultId, shares: Balance) -> Result<Balance, DispatchError> {
+		Self::do_mint(who, vault_id, shares)
+	}
+
+	#[transactional]
+	fn withdraw(who: &T::AccountId, vault_id: VaultId, assets: Balance) -> Result<Balance, DispatchError> {
+		Self::do_withdraw(who, vault_id, assets)
+	}
+
+	#[transactional]
+	fn redeem(who: &T::AccountId, vault_id: VaultId, shares: Balance) -> Result<Balance, DispatchError> {
+		Self::do_redeem(who, vault_id, shares)
+	}
+}
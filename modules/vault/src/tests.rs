@@ -0,0 +1,220 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+//! Unit tests for the vault module.
+
+#![cfg(test)]
+
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_runtime::traits::BadOrigin;
+
+const SHARE: CurrencyId = CurrencyId::VaultShare(0);
+
+fn share_metadata() -> Box<AssetMetadata<Balance>> {
+	Box::new(AssetMetadata {
+		name: b"Vault DOT".to_vec(),
+		symbol: b"vDOT".to_vec(),
+		decimals: 10,
+		minimal_balance: 1,
+	})
+}
+
+fn create_dot_vault(max_total_assets: Option<Balance>) {
+	assert_ok!(VaultModule::create_vault(
+		Origin::signed(Admin::get()),
+		DOT,
+		max_total_assets,
+		share_metadata()
+	));
+}
+
+#[test]
+fn create_and_update_vault_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			VaultModule::create_vault(Origin::signed(ALICE), DOT, None, share_metadata()),
+			BadOrigin
+		);
+		assert_noop!(
+			VaultModule::create_vault(Origin::signed(Admin::get()), SHARE, None, share_metadata()),
+			Error::<Runtime>::InvalidUnderlying
+		);
+
+		create_dot_vault(None);
+		System::assert_last_event(
+			Event::VaultCreated {
+				vault_id: 0,
+				underlying: DOT,
+				max_total_assets: None,
+			}
+			.into(),
+		);
+		assert_eq!(VaultModule::next_vault_id(), 1);
+		assert_eq!(
+			VaultModule::vaults(0),
+			Some(VaultInfo {
+				underlying: DOT,
+				max_total_assets: None
+			})
+		);
+
+		assert_noop!(
+			VaultModule::update_vault(Origin::signed(ALICE), 0, Some(100)),
+			BadOrigin
+		);
+		assert_noop!(
+			VaultModule::update_vault(Origin::signed(Admin::get()), 1, Some(100)),
+			Error::<Runtime>::VaultNotFound
+		);
+		assert_ok!(VaultModule::update_vault(Origin::signed(Admin::get()), 0, Some(100)));
+		System::assert_last_event(
+			Event::VaultUpdated {
+				vault_id: 0,
+				max_total_assets: Some(100),
+			}
+			.into(),
+		);
+		assert_eq!(VaultModule::vaults(0).unwrap().max_total_assets, Some(100));
+	});
+}
+
+#[test]
+fn deposit_mint_withdraw_redeem_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			VaultModule::deposit(Origin::signed(ALICE), 0, 1_000),
+			Error::<Runtime>::VaultNotFound
+		);
+		create_dot_vault(None);
+		assert_noop!(
+			VaultModule::deposit(Origin::signed(ALICE), 0, 0),
+			Error::<Runtime>::InvalidAmount
+		);
+
+		// the first deposit mints shares 1:1
+		assert_ok!(VaultModule::deposit(Origin::signed(ALICE), 0, 1_000));
+		System::assert_last_event(
+			Event::Deposited {
+				vault_id: 0,
+				who: ALICE,
+				assets: 1_000,
+				shares: 1_000,
+			}
+			.into(),
+		);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 999_000);
+		assert_eq!(Tokens::free_balance(SHARE, &ALICE), 1_000);
+		assert_eq!(Tokens::free_balance(DOT, &MockStrategy::strategy_account(0)), 1_000);
+		assert_eq!(VaultModule::total_assets(0), 1_000);
+
+		// harvest 10% yield
+		assert_noop!(VaultModule::harvest(Origin::signed(ALICE), 0), BadOrigin);
+		assert_ok!(VaultModule::harvest(Origin::signed(Admin::get()), 0));
+		System::assert_last_event(
+			Event::Harvested {
+				vault_id: 0,
+				total_assets: 1_100,
+			}
+			.into(),
+		);
+		assert_eq!(VaultModule::convert_to_assets(0, 1_000), 1_099);
+
+		// shares are rounded down on deposit
+		assert_ok!(VaultModule::deposit(Origin::signed(BOB), 0, 1_100));
+		assert_eq!(Tokens::free_balance(SHARE, &BOB), 1_000);
+		assert_eq!(VaultModule::total_assets(0), 2_200);
+
+		// assets are rounded up on mint
+		assert_eq!(VaultModule::preview_mint(0, 100), 110);
+		assert_ok!(VaultModule::mint(Origin::signed(BOB), 0, 100));
+		System::assert_last_event(
+			Event::Deposited {
+				vault_id: 0,
+				who: BOB,
+				assets: 110,
+				shares: 100,
+			}
+			.into(),
+		);
+		assert_eq!(Tokens::free_balance(DOT, &BOB), 1_000_000 - 1_100 - 110);
+		assert_eq!(Tokens::free_balance(SHARE, &BOB), 1_100);
+
+		// assets are rounded down on redeem
+		assert_noop!(
+			VaultModule::redeem(Origin::signed(ALICE), 0, 1_001),
+			orml_tokens::Error::<Runtime>::BalanceTooLow
+		);
+		assert_ok!(VaultModule::redeem(Origin::signed(ALICE), 0, 1_000));
+		System::assert_last_event(
+			Event::Withdrawn {
+				vault_id: 0,
+				who: ALICE,
+				assets: 1_099,
+				shares: 1_000,
+			}
+			.into(),
+		);
+		assert_eq!(Tokens::free_balance(DOT, &ALICE), 999_000 + 1_099);
+		assert_eq!(Tokens::free_balance(SHARE, &ALICE), 0);
+		assert_eq!(VaultModule::total_assets(0), 1_211);
+
+		// shares are rounded up on withdraw
+		assert_eq!(VaultModule::preview_withdraw(0, 110), 100);
+		assert_ok!(VaultModule::withdraw(Origin::signed(BOB), 0, 110));
+		System::assert_last_event(
+			Event::Withdrawn {
+				vault_id: 0,
+				who: BOB,
+				assets: 110,
+				shares: 100,
+			}
+			.into(),
+		);
+		assert_eq!(Tokens::free_balance(SHARE, &BOB), 1_000);
+		assert_eq!(Tokens::total_issuance(SHARE), 1_000);
+		assert_eq!(VaultModule::total_assets(0), 1_101);
+		assert_eq!(VaultModule::max_withdraw(0, &BOB), 1_100);
+		assert_eq!(VaultModule::max_redeem(0, &BOB), 1_000);
+	});
+}
+
+#[test]
+fn max_total_assets_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		create_dot_vault(Some(1_500));
+		assert_eq!(VaultModule::max_deposit(0, &ALICE), 1_500);
+
+		assert_ok!(VaultModule::deposit(Origin::signed(ALICE), 0, 1_000));
+		assert_eq!(VaultModule::max_deposit(0, &ALICE), 500);
+		assert_eq!(VaultModule::max_mint(0, &ALICE), 500);
+		assert_noop!(
+			VaultModule::deposit(Origin::signed(ALICE), 0, 501),
+			Error::<Runtime>::ExceedMaxDeposit
+		);
+		assert_noop!(
+			VaultModule::mint(Origin::signed(ALICE), 0, 501),
+			Error::<Runtime>::ExceedMaxMint
+		);
+
+		assert_ok!(VaultModule::update_vault(Origin::signed(Admin::get()), 0, None));
+		assert_eq!(VaultModule::max_deposit(0, &ALICE), Balance::max_value());
+		assert_eq!(VaultModule::max_mint(0, &ALICE), Balance::max_value());
+		assert_ok!(VaultModule::deposit(Origin::signed(ALICE), 0, 501));
+	});
+}
@@ -437,11 +437,13 @@ where
 
 	let rpc_builder = {
 		let client = client.clone();
+		let backend = backend.clone();
 		let transaction_pool = transaction_pool.clone();
 
 		move |deny_unsafe, _| {
 			let deps = acala_rpc::FullDeps {
 				client: client.clone(),
+				backend: backend.clone(),
 				pool: transaction_pool.clone(),
 				deny_unsafe,
 				command_sink: None,
@@ -451,6 +453,14 @@ where
 		}
 	};
 
+	if parachain_config.offchain_worker.indexing_enabled {
+		task_manager.spawn_handle().spawn(
+			"evm-logs-indexer",
+			None,
+			acala_rpc::index_block_logs(client.clone(), backend.clone()),
+		);
+	}
+
 	if parachain_config.offchain_worker.enabled {
 		sc_service::build_offchain_workers(
 			&parachain_config,
@@ -712,6 +722,14 @@ where
 		warp_sync: None,
 	})?;
 
+	if config.offchain_worker.indexing_enabled {
+		task_manager.spawn_handle().spawn(
+			"evm-logs-indexer",
+			None,
+			acala_rpc::index_block_logs(client.clone(), backend.clone()),
+		);
+	}
+
 	if config.offchain_worker.enabled {
 		let offchain_workers = Arc::new(sc_offchain::OffchainWorkers::new_with_options(
 			client.clone(),
@@ -875,11 +893,13 @@ where
 
 	let rpc_extensions_builder = {
 		let client = client.clone();
+		let backend = backend.clone();
 		let transaction_pool = transaction_pool.clone();
 
 		move |deny_unsafe, _| {
 			let deps = acala_rpc::FullDeps {
 				client: client.clone(),
+				backend: backend.clone(),
 				pool: transaction_pool.clone(),
 				deny_unsafe,
 				command_sink: command_sink.clone(),
@@ -448,6 +448,7 @@ pub trait TokenInfo {
 pub type ForeignAssetId = u16;
 pub type Erc20Id = u32;
 pub type Lease = BlockNumber;
+pub type VaultId = u32;
 
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -458,6 +459,7 @@ pub enum DexShare {
 	LiquidCrowdloan(Lease),
 	ForeignAsset(ForeignAssetId),
 	StableAssetPoolToken(StableAssetPoolId),
+	VaultShare(VaultId),
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
@@ -470,6 +472,7 @@ pub enum CurrencyId {
 	StableAssetPoolToken(StableAssetPoolId),
 	LiquidCrowdloan(Lease),
 	ForeignAsset(ForeignAssetId),
+	VaultShare(VaultId),
 }
 
 impl CurrencyId {
@@ -493,6 +496,10 @@ impl CurrencyId {
 		matches!(self, CurrencyId::ForeignAsset(_))
 	}
 
+	pub fn is_vault_share_currency_id(&self) -> bool {
+		matches!(self, CurrencyId::VaultShare(_))
+	}
+
 	pub fn is_trading_pair_currency_id(&self) -> bool {
 		matches!(
 			self,
@@ -501,6 +508,7 @@ impl CurrencyId {
 				| CurrencyId::LiquidCrowdloan(_)
 				| CurrencyId::ForeignAsset(_)
 				| CurrencyId::StableAssetPoolToken(_)
+				| CurrencyId::VaultShare(_)
 		)
 	}
 
@@ -524,6 +532,7 @@ impl CurrencyId {
 			CurrencyId::StableAssetPoolToken(stable_asset_pool_id) => {
 				DexShare::StableAssetPoolToken(stable_asset_pool_id)
 			}
+			CurrencyId::VaultShare(vault_id) => DexShare::VaultShare(vault_id),
 			// Unsupported
 			CurrencyId::DexShare(..) => return None,
 		};
@@ -535,6 +544,7 @@ impl CurrencyId {
 			CurrencyId::StableAssetPoolToken(stable_asset_pool_id) => {
 				DexShare::StableAssetPoolToken(stable_asset_pool_id)
 			}
+			CurrencyId::VaultShare(vault_id) => DexShare::VaultShare(vault_id),
 			// Unsupported
 			CurrencyId::DexShare(..) => return None,
 		};
@@ -574,6 +584,9 @@ impl From<DexShare> for u32 {
 			DexShare::StableAssetPoolToken(stable_asset_pool_id) => {
 				bytes[..].copy_from_slice(&stable_asset_pool_id.to_be_bytes());
 			}
+			DexShare::VaultShare(vault_id) => {
+				bytes[..].copy_from_slice(&vault_id.to_be_bytes());
+			}
 		}
 		u32::from_be_bytes(bytes)
 	}
@@ -589,6 +602,7 @@ impl Into<CurrencyId> for DexShare {
 			DexShare::StableAssetPoolToken(stable_asset_pool_id) => {
 				CurrencyId::StableAssetPoolToken(stable_asset_pool_id)
 			}
+			DexShare::VaultShare(vault_id) => CurrencyId::VaultShare(vault_id),
 		}
 	}
 }
@@ -604,6 +618,7 @@ pub enum CurrencyIdType {
 	StableAsset,
 	LiquidCrowdloan,
 	ForeignAsset,
+	VaultShare,
 }
 
 #[derive(
@@ -616,6 +631,7 @@ pub enum DexShareType {
 	LiquidCrowdloan,
 	ForeignAsset,
 	StableAssetPoolToken,
+	VaultShare,
 }
 
 impl Into<DexShareType> for DexShare {
@@ -626,6 +642,7 @@ impl Into<DexShareType> for DexShare {
 			DexShare::LiquidCrowdloan(_) => DexShareType::LiquidCrowdloan,
 			DexShare::ForeignAsset(_) => DexShareType::ForeignAsset,
 			DexShare::StableAssetPoolToken(_) => DexShareType::StableAssetPoolToken,
+			DexShare::VaultShare(_) => DexShareType::VaultShare,
 		}
 	}
 }
@@ -639,6 +656,7 @@ pub enum AssetIds {
 	StableAssetId(StableAssetPoolId),
 	ForeignAssetId(ForeignAssetId),
 	NativeAssetId(CurrencyId),
+	VaultId(VaultId),
 }
 
 #[derive(Clone, Eq, PartialEq, RuntimeDebug, Encode, Decode, TypeInfo)]
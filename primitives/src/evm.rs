@@ -17,7 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-	currency::{CurrencyId, CurrencyIdType, DexShareType},
+	currency::{CurrencyId, CurrencyIdType, DexShareType, VaultId},
 	Balance, BlockNumber, Nonce,
 };
 use codec::{Decode, Encode};
@@ -75,6 +75,81 @@ pub struct BlockLimits {
 	pub max_gas_limit: u64,
 	/// Max storage limit
 	pub max_storage_limit: u32,
+	/// Base fee per gas, in EVM decimals
+	pub base_fee_per_gas: Balance,
+}
+
+/// The block limits returned by `EVMRuntimeRPCApi` before version 3.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LegacyBlockLimits {
+	/// Max gas limit
+	pub max_gas_limit: u64,
+	/// Max storage limit
+	pub max_storage_limit: u32,
+}
+
+/// Prefix of the offchain storage key of the EVM logs of a block.
+pub const EVM_LOGS_INDEX_PREFIX: &[u8] = b"acala:evm:logs:";
+
+/// Offchain storage key of the EVM logs of the block `block_hash`, blocks of different forks
+/// are indexed separately.
+pub fn evm_logs_index_key(block_hash: &[u8]) -> Vec<u8> {
+	let mut key = EVM_LOGS_INDEX_PREFIX.to_vec();
+	key.extend_from_slice(block_hash);
+	key
+}
+
+/// 2048 bits bloom filter of log addresses and topics, the same as Ethereum.
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub struct LogsBloom(pub [u8; 256]);
+
+impl Default for LogsBloom {
+	fn default() -> Self {
+		Self([0u8; 256])
+	}
+}
+
+impl LogsBloom {
+	/// Add `input` to the bloom filter.
+	pub fn accrue(&mut self, input: &[u8]) {
+		for (index, bit) in Self::bloom_bits(input) {
+			self.0[index] |= bit;
+		}
+	}
+
+	/// Add the address and topics of the `log` to the bloom filter.
+	pub fn accrue_log(&mut self, log: &Log) {
+		self.accrue(log.address.as_bytes());
+		log.topics.iter().for_each(|topic| self.accrue(topic.as_bytes()));
+	}
+
+	/// Check whether `input` may be in the bloom filter.
+	pub fn contains_input(&self, input: &[u8]) -> bool {
+		Self::bloom_bits(input).all(|(index, bit)| self.0[index] & bit == bit)
+	}
+
+	fn bloom_bits(input: &[u8]) -> impl Iterator<Item = (usize, u8)> {
+		let hash = sp_io::hashing::keccak_256(input);
+		(0..3).map(move |i| {
+			let bit = (((hash[i * 2] as usize) << 8) | hash[i * 2 + 1] as usize) & 2047;
+			(255 - bit / 8, 1u8 << (bit % 8))
+		})
+	}
+}
+
+/// EVM log with the index of the extrinsic which emitted it, `None` if it's not emitted by an
+/// extrinsic.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub struct IndexedLog {
+	pub extrinsic_index: Option<u32>,
+	pub log: Log,
+}
+
+/// EVM logs of a block written to the offchain storage.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug)]
+pub struct BlockLogs {
+	pub bloom: LogsBloom,
+	pub logs: Vec<IndexedLog>,
 }
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -112,6 +187,78 @@ pub struct EthereumTransactionMessage {
 	pub access_list: Vec<AccessListItem>,
 }
 
+/// A value of an EIP-712 typed data field.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum Eip712Value {
+	/// `uint256`
+	Uint(u128),
+	/// `int256`
+	Int(i128),
+	/// `bool`
+	Bool(bool),
+	/// `address`
+	Address(EvmAddress),
+	/// `bytes32`
+	Bytes32(H256),
+}
+
+impl Eip712Value {
+	/// The EIP-712 type name of the value.
+	pub fn type_name(&self) -> &'static str {
+		match self {
+			Eip712Value::Uint(_) => "uint256",
+			Eip712Value::Int(_) => "int256",
+			Eip712Value::Bool(_) => "bool",
+			Eip712Value::Address(_) => "address",
+			Eip712Value::Bytes32(_) => "bytes32",
+		}
+	}
+
+	/// The EIP-712 encoding of the value, a 32 bytes word.
+	pub fn encode_data(&self) -> [u8; 32] {
+		match self {
+			Eip712Value::Uint(value) => U256::from(*value).into(),
+			Eip712Value::Int(value) => {
+				// two's complement
+				let mut word = if value.is_negative() { [0xff; 32] } else { [0u8; 32] };
+				word[16..].copy_from_slice(&value.to_be_bytes());
+				word
+			}
+			Eip712Value::Bool(value) => U256::from(*value as u8).into(),
+			Eip712Value::Address(address) => H256::from(*address).into(),
+			Eip712Value::Bytes32(value) => value.0,
+		}
+	}
+}
+
+/// A named argument of a call rendered as EIP-712 typed data.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Eip712Arg {
+	/// The name of the argument.
+	pub name: Vec<u8>,
+	pub value: Eip712Value,
+}
+
+/// A runtime call rendered as EIP-712 typed data, signed by an Ethereum key.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct Eip712CallMessage {
+	pub chain_id: u64,
+	pub genesis: H256,
+	/// The runtime spec version the call is signed for.
+	pub spec_version: u32,
+	/// The name of the pallet.
+	pub pallet_name: Vec<u8>,
+	/// The name of the call.
+	pub call_name: Vec<u8>,
+	/// The arguments of the call, rendered as the fields of the `Args` struct.
+	pub args: Vec<Eip712Arg>,
+	pub nonce: Nonce,
+	pub tip: Balance,
+}
+
 /// Ethereum precompiles
 /// 0 - 0x0000000000000000000000000000000000000400
 /// Acala precompiles
@@ -126,6 +273,9 @@ pub const MIRRORED_NFT_ADDRESS_START: u64 = 0x2000000;
 pub const ERC20_HOLDING_ACCOUNT: EvmAddress = H160(hex_literal::hex!("000000000000000000ff00000000000000000000"));
 /// System contract address prefix
 pub const SYSTEM_CONTRACT_ADDRESS_PREFIX: [u8; 9] = [0u8; 9];
+/// Mirrored NFT address prefix, outside of the system contract addresses so it can't collide
+/// with the mirrored tokens and the network contracts.
+pub const MIRRORED_NFT_ADDRESS_PREFIX: [u8; 16] = hex!("0000000000000000ff00000000000000");
 
 #[rustfmt::skip]
 /// CurrencyId to H160([u8; 20]) bit encoding rule.
@@ -137,12 +287,14 @@ pub const SYSTEM_CONTRACT_ADDRESS_PREFIX: [u8; 9] = [0u8; 9];
 ///   ^^^^^^^^^^^^^^^^^^                       System contract address prefix
 ///                     ^^                     CurrencyId Type: 1-Token 2-DexShare 3-StableAsset
 ///                                                             4-LiquidCrowdloan
-///                                                             5-ForeignAsset(ignore Erc20, without the prefix of system contracts)
+///                                                             5-ForeignAsset 6-VaultShare
+///                                                             (ignore Erc20, without the prefix of system contracts)
 ///                                                             FF-Erc20 Holding Account
 ///                                         ^^ CurrencyId Type is 1-Token, Token
 ///                                   ^^^^^^^^ CurrencyId Type is 1-Token, NFT
 ///                       ^^                   CurrencyId Type is 2-DexShare, DexShare Left Type:
 ///                                                             0-Token 1-Erc20 2-LiquidCrowdloan 3-ForeignAsset 4-StableAsset
+///                                                             5-VaultShare
 ///                         ^^^^^^^^           CurrencyId Type is 2-DexShare, DexShare left field
 ///                                 ^^         CurrencyId Type is 2-DexShare, DexShare Right Type:
 ///                                                             the same as DexShare Left Type
@@ -150,6 +302,7 @@ pub const SYSTEM_CONTRACT_ADDRESS_PREFIX: [u8; 9] = [0u8; 9];
 ///                                   ^^^^^^^^ CurrencyId Type is 3-StableAsset, StableAssetPoolId
 ///                                   ^^^^^^^^ CurrencyId Type is 4-LiquidCrowdloan, Lease
 ///                                       ^^^^ CurrencyId Type is 5-ForeignAsset, ForeignAssetId
+///                                   ^^^^^^^^ CurrencyId Type is 6-VaultShare, VaultId
 
 /// Check if the given `address` is a system contract.
 ///
@@ -168,6 +321,46 @@ pub const H160_POSITION_DEXSHARE_RIGHT_FIELD: Range<usize> = 16..20;
 pub const H160_POSITION_STABLE_ASSET: Range<usize> = 16..20;
 pub const H160_POSITION_LIQUID_CROADLOAN: Range<usize> = 16..20;
 pub const H160_POSITION_FOREIGN_ASSET: Range<usize> = 18..20;
+pub const H160_POSITION_VAULT_SHARE: Range<usize> = 16..20;
+
+/// Generate the mirrored ERC-721 address of the NFT class.
+///
+/// The address is `MIRRORED_NFT_ADDRESS_PREFIX` with the `class_id` at `H160_POSITION_TOKEN_NFT`.
+pub fn mirrored_nft_address(class_id: u32) -> EvmAddress {
+	let mut address = EvmAddress::default();
+	address.0[..H160_POSITION_TOKEN_NFT.start].copy_from_slice(&MIRRORED_NFT_ADDRESS_PREFIX);
+	address.0[H160_POSITION_TOKEN_NFT].copy_from_slice(&class_id.to_be_bytes());
+	address
+}
+
+/// Decode the NFT class id from the mirrored ERC-721 address.
+pub fn decode_mirrored_nft_address(address: EvmAddress) -> Option<u32> {
+	if !address.as_bytes().starts_with(&MIRRORED_NFT_ADDRESS_PREFIX) {
+		return None;
+	}
+	Some(u32::from_be_bytes(
+		address.as_bytes()[H160_POSITION_TOKEN_NFT].try_into().ok()?,
+	))
+}
+
+/// Decode the vault id from the address of the vault share token.
+///
+/// The address is `MIRRORED_TOKENS_ADDRESS_START` with `CurrencyIdType::VaultShare` at
+/// `H160_POSITION_CURRENCY_ID_TYPE` and the vault id at `H160_POSITION_VAULT_SHARE`.
+pub fn decode_vault_share_address(address: EvmAddress) -> Option<VaultId> {
+	let bytes = address.as_bytes();
+	if !is_system_contract(address)
+		|| bytes[H160_POSITION_CURRENCY_ID_TYPE] != CurrencyIdType::VaultShare.into()
+		|| bytes[H160_POSITION_CURRENCY_ID_TYPE + 1..H160_POSITION_VAULT_SHARE.start]
+			.iter()
+			.any(|b| *b != 0)
+	{
+		return None;
+	}
+	Some(VaultId::from_be_bytes(
+		bytes[H160_POSITION_VAULT_SHARE].try_into().ok()?,
+	))
+}
 
 /// Generate the EvmAddress from CurrencyId so that evm contracts can call the erc20 contract.
 /// NOTE: Can not be used directly, need to check the erc20 is mapped.
@@ -205,6 +398,10 @@ impl TryFrom<CurrencyId> for EvmAddress {
 				address[H160_POSITION_CURRENCY_ID_TYPE] = CurrencyIdType::ForeignAsset.into();
 				address[H160_POSITION_FOREIGN_ASSET].copy_from_slice(&foreign_asset_id.to_be_bytes());
 			}
+			CurrencyId::VaultShare(vault_id) => {
+				address[H160_POSITION_CURRENCY_ID_TYPE] = CurrencyIdType::VaultShare.into();
+				address[H160_POSITION_VAULT_SHARE].copy_from_slice(&vault_id.to_be_bytes());
+			}
 		};
 
 		Ok(EvmAddress::from_slice(&address))
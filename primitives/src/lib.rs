@@ -187,6 +187,10 @@ pub enum ReserveIdentifier {
 	Nft,
 	TransactionPayment,
 	TransactionPaymentDeposit,
+	EvmAccountsSessionKey,
+	NftBid,
+	IdleScheduler,
+	Streaming,
 
 	// always the last, indicate number of variants
 	Count,
@@ -41,6 +41,12 @@ pub enum ClassProperty {
 	Mintable = 0b00000100,
 	/// Is class properties mutable
 	ClassPropertiesMutable = 0b00001000,
+	/// Is token bound to the holder, only the class owner could revoke it
+	Soulbound = 0b00010000,
+	/// Is token burned after the token lifetime of the class
+	Expirable = 0b00100000,
+	/// Is total issuance capped by the max supply of the class
+	SupplyCapped = 0b01000000,
 }
 
 #[derive(Clone, Copy, PartialEq, Default, RuntimeDebug)]
@@ -31,6 +31,28 @@ pub struct TaskResult {
 	pub finished: bool,
 }
 
+/// The dispatch priority of the idle scheduler tasks, higher priority tasks are dispatched first.
+#[derive(Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum TaskPriority {
+	High,
+	Normal,
+	Low,
+}
+
+impl Default for TaskPriority {
+	fn default() -> Self {
+		Self::Normal
+	}
+}
+
+impl TaskPriority {
+	/// All priorities, in the order of dispatch.
+	pub fn all() -> [TaskPriority; 3] {
+		[TaskPriority::High, TaskPriority::Normal, TaskPriority::Low]
+	}
+}
+
 #[macro_export]
 macro_rules! define_combined_task {
 	(
@@ -56,6 +78,22 @@ macro_rules! define_combined_task {
 					)*
 				}
 			}
+
+			fn priority(&self) -> $crate::task::TaskPriority {
+				match self {
+					$(
+						$combined_name::$task(t) => t.priority(),
+					)*
+				}
+			}
+
+			fn max_weight(&self) -> Weight {
+				match self {
+					$(
+						$combined_name::$task(t) => t.max_weight(),
+					)*
+				}
+			}
 		}
 
         $(
@@ -17,7 +17,10 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use crate::evm::{is_system_contract, EvmAddress, SYSTEM_CONTRACT_ADDRESS_PREFIX};
+use crate::evm::{
+	decode_mirrored_nft_address, decode_vault_share_address, is_system_contract, mirrored_nft_address, EvmAddress, Log,
+	LogsBloom, SYSTEM_CONTRACT_ADDRESS_PREFIX,
+};
 use frame_support::assert_ok;
 use sp_core::H160;
 use std::str::FromStr;
@@ -140,6 +143,19 @@ fn currency_id_try_into_evm_address_works() {
 		)),
 		Ok(EvmAddress::from_str("0x0000000000000000000204000000000400000000").unwrap())
 	);
+
+	assert_eq!(
+		EvmAddress::try_from(CurrencyId::DexShare(
+			DexShare::VaultShare(Default::default()),
+			DexShare::VaultShare(Default::default())
+		)),
+		Ok(EvmAddress::from_str("0x0000000000000000000205000000000500000000").unwrap())
+	);
+
+	assert_eq!(
+		EvmAddress::try_from(CurrencyId::VaultShare(1)),
+		Ok(EvmAddress::from_str("0x0000000000000000000600000000000000000001").unwrap())
+	);
 }
 
 #[test]
@@ -179,3 +195,82 @@ fn is_system_contract_works() {
 
 	assert!(!is_system_contract(bytes.into()));
 }
+
+#[test]
+fn mirrored_nft_address_works() {
+	assert_eq!(
+		mirrored_nft_address(0),
+		H160::from_str("0x0000000000000000ff0000000000000000000000").unwrap()
+	);
+	assert_eq!(
+		mirrored_nft_address(0x10),
+		H160::from_str("0x0000000000000000ff0000000000000000000010").unwrap()
+	);
+	assert_eq!(
+		mirrored_nft_address(u32::MAX),
+		H160::from_str("0x0000000000000000ff00000000000000ffffffff").unwrap()
+	);
+	assert!(!is_system_contract(mirrored_nft_address(0)));
+
+	assert_eq!(
+		decode_mirrored_nft_address(H160::from_str("0x0000000000000000ff0000000000000000000010").unwrap()),
+		Some(0x10)
+	);
+	// network contract
+	assert_eq!(
+		decode_mirrored_nft_address(H160::from_str("0x0000000000000000000100000000000002000010").unwrap()),
+		None
+	);
+	// token
+	assert_eq!(
+		decode_mirrored_nft_address(H160::from_str("0x0000000000000000000100000000000000000001").unwrap()),
+		None
+	);
+}
+
+#[test]
+fn decode_vault_share_address_works() {
+	assert_eq!(
+		EvmAddress::try_from(CurrencyId::VaultShare(0x10)),
+		Ok(H160::from_str("0x0000000000000000000600000000000000000010").unwrap())
+	);
+	assert_eq!(
+		decode_vault_share_address(H160::from_str("0x0000000000000000000600000000000000000010").unwrap()),
+		Some(0x10)
+	);
+	// token
+	assert_eq!(
+		decode_vault_share_address(H160::from_str("0x0000000000000000000100000000000000000001").unwrap()),
+		None
+	);
+	// not a system contract
+	assert_eq!(
+		decode_vault_share_address(H160::from_str("0x1000000000000000000600000000000000000010").unwrap()),
+		None
+	);
+	// dirty bytes
+	assert_eq!(
+		decode_vault_share_address(H160::from_str("0x0000000000000000000600000000000100000010").unwrap()),
+		None
+	);
+}
+
+#[test]
+fn logs_bloom_works() {
+	let address = H160::from_str("0x1000000000000000000000000000000000000001").unwrap();
+	let topic = sp_core::H256::repeat_byte(1);
+	let log = Log {
+		address,
+		topics: vec![topic],
+		data: vec![],
+	};
+
+	let mut bloom = LogsBloom::default();
+	assert!(!bloom.contains_input(address.as_bytes()));
+	assert!(!bloom.contains_input(topic.as_bytes()));
+
+	bloom.accrue_log(&log);
+	assert!(bloom.contains_input(address.as_bytes()));
+	assert!(bloom.contains_input(topic.as_bytes()));
+	assert!(!bloom.contains_input(H160::repeat_byte(2).as_bytes()));
+}
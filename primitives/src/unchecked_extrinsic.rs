@@ -16,7 +16,11 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{evm::EthereumTransactionMessage, signature::AcalaMultiSignature, to_bytes, Address, Balance};
+use crate::{
+	evm::{Eip712CallMessage, EthereumTransactionMessage},
+	signature::AcalaMultiSignature,
+	to_bytes, Address, Balance,
+};
 use codec::{Decode, Encode};
 use frame_support::{
 	log,
@@ -105,7 +109,8 @@ impl<Call, Extra, ConvertEthTx, StorageDepositPerByte, TxFeePerGas, Lookup, Chec
 where
 	Call: Encode + Member,
 	Extra: SignedExtension<AccountId = AccountId32>,
-	ConvertEthTx: Convert<(Call, Extra), Result<(EthereumTransactionMessage, Extra), InvalidTransaction>>,
+	ConvertEthTx: Convert<(Call, Extra), Result<(EthereumTransactionMessage, Extra), InvalidTransaction>>
+		+ Convert<(Call, Extra), Result<(Eip712CallMessage, Extra), InvalidTransaction>>,
 	CheckPayerTx: Convert<(Call, Extra), Result<(), InvalidTransaction>>,
 	StorageDepositPerByte: Get<Balance>,
 	TxFeePerGas: Get<Balance>,
@@ -122,7 +127,8 @@ where
 
 		match self.0.signature {
 			Some((addr, AcalaMultiSignature::Ethereum(sig), extra)) => {
-				let (eth_msg, eth_extra) = ConvertEthTx::convert((function.clone(), extra))?;
+				let (eth_msg, eth_extra): (EthereumTransactionMessage, _) =
+					ConvertEthTx::convert((function.clone(), extra))?;
 				log::trace!(
 					target: "evm", "Ethereum eth_msg: {:?}", eth_msg
 				);
@@ -171,7 +177,8 @@ where
 				})
 			}
 			Some((addr, AcalaMultiSignature::Eip1559(sig), extra)) => {
-				let (eth_msg, eth_extra) = ConvertEthTx::convert((function.clone(), extra))?;
+				let (eth_msg, eth_extra): (EthereumTransactionMessage, _) =
+					ConvertEthTx::convert((function.clone(), extra))?;
 				log::trace!(
 					target: "evm", "Eip1559 eth_msg: {:?}", eth_msg
 				);
@@ -215,7 +222,8 @@ where
 				})
 			}
 			Some((addr, AcalaMultiSignature::AcalaEip712(sig), extra)) => {
-				let (eth_msg, eth_extra) = ConvertEthTx::convert((function.clone(), extra))?;
+				let (eth_msg, eth_extra): (EthereumTransactionMessage, _) =
+					ConvertEthTx::convert((function.clone(), extra))?;
 				log::trace!(
 					target: "evm", "AcalaEip712 eth_msg: {:?}", eth_msg
 				);
@@ -234,6 +242,26 @@ where
 					function,
 				})
 			}
+			Some((addr, AcalaMultiSignature::AcalaEip712Call(sig), extra)) => {
+				let (call_msg, call_extra): (Eip712CallMessage, _) = ConvertEthTx::convert((function.clone(), extra))?;
+				log::trace!(
+					target: "evm", "AcalaEip712Call call_msg: {:?}", call_msg
+				);
+
+				let signer = verify_eip712_call_signature(call_msg, sig).ok_or(InvalidTransaction::BadProof)?;
+
+				let account_id = lookup.lookup(Address::Address20(signer.into()))?;
+				let expected_account_id = lookup.lookup(addr)?;
+
+				if account_id != expected_account_id {
+					return Err(InvalidTransaction::BadProof.into());
+				}
+
+				Ok(CheckedExtrinsic {
+					signed: Some((account_id, call_extra)),
+					function,
+				})
+			}
 			_ => self.0.check(lookup),
 		}
 	}
@@ -334,6 +362,54 @@ fn verify_eip712_signature(eth_msg: EthereumTransactionMessage, sig: [u8; 65]) -
 	recover_signer(&sig, &msg_hash)
 }
 
+fn verify_eip712_call_signature(call_msg: Eip712CallMessage, sig: [u8; 65]) -> Option<H160> {
+	let domain_hash = keccak256!("EIP712Domain(string name,string version,uint256 chainId,bytes32 salt)");
+
+	let mut domain_seperator_msg = domain_hash.to_vec();
+	domain_seperator_msg.extend_from_slice(keccak256!("Acala EVM")); // name
+	domain_seperator_msg.extend_from_slice(keccak256!("1")); // version
+	domain_seperator_msg.extend_from_slice(&to_bytes(call_msg.chain_id)); // chain id
+	domain_seperator_msg.extend_from_slice(call_msg.genesis.as_bytes()); // salt
+	let domain_separator = keccak_256(domain_seperator_msg.as_slice());
+
+	// the fields of `Args` are the arguments of the call
+	let mut args_type = b"Args(".to_vec();
+	for (i, arg) in call_msg.args.iter().enumerate() {
+		if i > 0 {
+			args_type.push(b',');
+		}
+		args_type.extend_from_slice(arg.value.type_name().as_bytes());
+		args_type.push(b' ');
+		args_type.extend_from_slice(&arg.name);
+	}
+	args_type.push(b')');
+
+	let mut args_data = keccak_256(&args_type).to_vec();
+	for arg in call_msg.args.iter() {
+		args_data.extend_from_slice(&arg.value.encode_data());
+	}
+
+	let mut call_type =
+		b"Call(string pallet,string call,Args args,uint256 nonce,uint256 tip,uint256 specVersion)".to_vec();
+	call_type.extend_from_slice(&args_type);
+
+	let mut call_msg_data = keccak_256(&call_type).to_vec();
+	call_msg_data.extend_from_slice(&keccak_256(call_msg.pallet_name.as_slice()));
+	call_msg_data.extend_from_slice(&keccak_256(call_msg.call_name.as_slice()));
+	call_msg_data.extend_from_slice(&keccak_256(args_data.as_slice()));
+	call_msg_data.extend_from_slice(&to_bytes(call_msg.nonce));
+	call_msg_data.extend_from_slice(&to_bytes(call_msg.tip));
+	call_msg_data.extend_from_slice(&to_bytes(call_msg.spec_version));
+
+	let mut msg = b"\x19\x01".to_vec();
+	msg.extend_from_slice(&domain_separator);
+	msg.extend_from_slice(&keccak_256(call_msg_data.as_slice()));
+
+	let msg_hash = keccak_256(msg.as_slice());
+
+	recover_signer(&sig, &msg_hash)
+}
+
 fn recover_sign_data(
 	eth_msg: &EthereumTransactionMessage,
 	ts_fee_per_gas: u128,
@@ -362,6 +438,7 @@ fn recover_sign_data(
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::evm::{Eip712Arg, Eip712Value};
 	use hex_literal::hex;
 	use module_evm_utility::ethereum::AccessListItem;
 	use sp_core::U256;
@@ -478,6 +555,61 @@ mod tests {
 		assert_ne!(verify_eip712_signature(new_msg, sign), sender);
 	}
 
+	#[test]
+	fn verify_eip712_call_should_works() {
+		let sender = Some(H160::from_str("0xbf0b5a4099f0bf6c8bc4252ebec548bae95602ea").unwrap());
+		let msg = Eip712CallMessage {
+			chain_id: 595,
+			genesis: H256::from_str("0xafb55f3937d1377c23b8f351315b2792f5d2753bb95420c191d2dc70ad7196e8").unwrap(),
+			spec_version: 2100,
+			pallet_name: b"Honzon".to_vec(),
+			call_name: b"adjust_loan".to_vec(),
+			args: vec![
+				Eip712Arg {
+					name: b"currency".to_vec(),
+					value: Eip712Value::Address(hex!("0000000000000000000100000000000000000002").into()),
+				},
+				Eip712Arg {
+					name: b"collateralAdjustment".to_vec(),
+					value: Eip712Value::Int(100),
+				},
+				Eip712Arg {
+					name: b"debitAdjustment".to_vec(),
+					value: Eip712Value::Int(-50),
+				},
+			],
+			nonce: 1,
+			tip: 2,
+		};
+		let sign = hex!("f973a0b87062c389d125d8199e803b832b6ac6bf7867a4f6cd87506060fc4c5868b28cb7df4dda1190269bfad18e2fd61d8979a7f2586a9b7dd7bb68f1c3b08f00");
+		assert_eq!(verify_eip712_call_signature(msg.clone(), sign), sender);
+
+		let mut new_msg = msg.clone();
+		new_msg.args[2].value = Eip712Value::Int(50);
+		assert_ne!(verify_eip712_call_signature(new_msg, sign), sender);
+
+		let mut new_msg = msg.clone();
+		new_msg.args[1].name = b"debitAdjustment".to_vec();
+		new_msg.args[2].name = b"collateralAdjustment".to_vec();
+		assert_ne!(verify_eip712_call_signature(new_msg, sign), sender);
+
+		let mut new_msg = msg.clone();
+		new_msg.call_name = b"adjust_loan_by_debit_value".to_vec();
+		assert_ne!(verify_eip712_call_signature(new_msg, sign), sender);
+
+		let mut new_msg = msg.clone();
+		new_msg.spec_version = 2101;
+		assert_ne!(verify_eip712_call_signature(new_msg, sign), sender);
+
+		let mut new_msg = msg.clone();
+		new_msg.nonce = 2;
+		assert_ne!(verify_eip712_call_signature(new_msg, sign), sender);
+
+		let mut new_msg = msg;
+		new_msg.tip = 3;
+		assert_ne!(verify_eip712_call_signature(new_msg, sign), sender);
+	}
+
 	#[test]
 	fn verify_eth_should_works() {
 		let msg = LegacyTransactionMessage {
@@ -40,15 +40,18 @@ use orml_oracle_rpc::{Oracle, OracleApiServer};
 use orml_tokens_rpc::{Tokens, TokensApiServer};
 
 /// module rpc
-pub use evm_rpc::{EVMApiServer, EVMRuntimeRPCApi, EVM};
+pub use evm_rpc::{index_block_logs, EVMApiServer, EVMRuntimeRPCApi, EvmLogs, EvmLogsApiServer, EVM};
+pub use incentives_rpc::{Incentives, IncentivesApiServer, IncentivesRuntimeApi};
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
 /// Full client dependencies.
-pub struct FullDeps<C, P> {
+pub struct FullDeps<C, P, BE> {
 	/// The client instance to use.
 	pub client: Arc<C>,
+	/// The backend instance to use.
+	pub backend: Arc<BE>,
 	/// Transaction pool instance.
 	pub pool: Arc<P>,
 	/// Whether to deny unsafe calls
@@ -58,7 +61,7 @@ pub struct FullDeps<C, P> {
 }
 
 /// Instantiate all Full RPC extensions.
-pub fn create_full<C, P>(deps: FullDeps<C, P>) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
+pub fn create_full<C, P, BE>(deps: FullDeps<C, P, BE>) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
 	C: ProvideRuntimeApi<Block> + sc_client_api::BlockBackend<Block>,
 	C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError>,
@@ -68,12 +71,15 @@ where
 	C::Api: orml_oracle_rpc::OracleRuntimeApi<Block, DataProviderId, CurrencyId, runtime_common::TimeStampedPrice>,
 	C::Api: orml_tokens_rpc::TokensRuntimeApi<Block, CurrencyId, Balance>,
 	C::Api: EVMRuntimeRPCApi<Block, Balance>,
+	C::Api: IncentivesRuntimeApi<Block, AccountId, Balance>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
+	BE: sc_client_api::Backend<Block> + Send + Sync + 'static,
 {
 	let mut module = RpcExtension::new(());
 	let FullDeps {
 		client,
+		backend,
 		pool,
 		deny_unsafe,
 		command_sink,
@@ -88,6 +94,8 @@ where
 	module.merge(Oracle::new(client.clone()).into_rpc())?;
 	module.merge(Tokens::new(client.clone()).into_rpc())?;
 	module.merge(EVM::new(client.clone(), deny_unsafe).into_rpc())?;
+	module.merge(EvmLogs::new(client.clone(), backend).into_rpc())?;
+	module.merge(Incentives::<_, _, Balance>::new(client.clone()).into_rpc())?;
 	module.merge(Dev::new(client, deny_unsafe).into_rpc())?;
 
 	if let Some(command_sink) = command_sink {
@@ -30,7 +30,7 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
-use codec::{Decode, DecodeLimit, Encode};
+use codec::{Decode, DecodeLimit, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -49,12 +49,17 @@ use sp_std::prelude::*;
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
-use frame_system::{EnsureRoot, RawOrigin};
+use frame_support::dispatch::{CallMetadata, GetCallMetadata};
+use frame_system::{EnsureRoot, EnsureSignedBy, RawOrigin};
 use module_asset_registry::{AssetIdMaps, EvmErc20InfoMapping};
 use module_cdp_engine::CollateralCurrencyIds;
+use module_cdp_treasury::CdpTreasuryTask;
 use module_currencies::BasicCurrencyAdapter;
+use module_dex_oracle::DexOracleTask;
 use module_evm::{runner::RunnerExtended, CallInfo, CreateInfo, EvmChainId, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
+use module_homa::HomaTask;
+use module_nft::NftTask;
 use module_relaychain::RelayChainCallBuilder;
 use module_support::{AssetIdMapping, DispatchableTask, PoolId};
 use module_transaction_payment::TargetedFeeAdjustment;
@@ -71,9 +76,9 @@ pub use frame_support::{
 	parameter_types,
 	traits::{
 		ConstBool, ConstU128, ConstU16, ConstU32, Contains, ContainsLengthBound, Currency as PalletCurrency,
-		EnsureOrigin, EqualPrivilegeOnly, Everything, Get, Imbalance, InstanceFilter, IsSubType, IsType,
-		KeyOwnerProofSystem, LockIdentifier, Nothing, OnRuntimeUpgrade, OnUnbalanced, Randomness, SortedMembers,
-		U128CurrencyToVote,
+		EitherOfDiverse, EnsureOrigin, EqualPrivilegeOnly, Everything, Get, Imbalance, InstanceFilter, IsInVec,
+		IsSubType, IsType, KeyOwnerProofSystem, LockIdentifier, OnRuntimeUpgrade, OnUnbalanced, Randomness,
+		SortedMembers, U128CurrencyToVote,
 	},
 	weights::{constants::RocksDbWeight, IdentityFee, Weight},
 	PalletId, RuntimeDebug, StorageValue,
@@ -90,7 +95,7 @@ use module_support::ExchangeRateProvider;
 use primitives::currency::AssetIds;
 pub use primitives::{
 	define_combined_task,
-	evm::{AccessListItem, BlockLimits, EstimateResourcesRequest, EthereumTransactionMessage},
+	evm::{AccessListItem, BlockLimits, Eip712CallMessage, EstimateResourcesRequest, EthereumTransactionMessage},
 	task::TaskResult,
 	unchecked_extrinsic::AcalaUncheckedExtrinsic,
 	AccountId, AccountIndex, Address, Amount, AuctionId, AuthoritysOriginId, Balance, BlockNumber, CurrencyId,
@@ -154,6 +159,7 @@ impl_opaque_keys! {
 parameter_types! {
 	pub const TreasuryPalletId: PalletId = PalletId(*b"aca/trsy");
 	pub const LoansPalletId: PalletId = PalletId(*b"aca/loan");
+	pub const NFTLoansPalletId: PalletId = PalletId(*b"aca/nftl");
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
 	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
@@ -161,6 +167,7 @@ parameter_types! {
 	pub const HonzonTreasuryPalletId: PalletId = PalletId(*b"aca/hztr");
 	pub const HomaTreasuryPalletId: PalletId = PalletId(*b"aca/hmtr");
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
+	pub const VaultPalletId: PalletId = PalletId(*b"aca/vaul");
 	pub const CollatorPotId: PalletId = PalletId(*b"aca/cpot");
 	// Treasury reserve
 	pub const TreasuryReservePalletId: PalletId = PalletId(*b"aca/reve");
@@ -176,6 +183,7 @@ parameter_types! {
 pub fn get_all_module_accounts() -> Vec<AccountId> {
 	vec![
 		LoansPalletId::get().into_account_truncating(),
+		NFTLoansPalletId::get().into_account_truncating(),
 		CDPEnginePalletId::get().into_account_truncating(),
 		CDPTreasuryPalletId::get().into_account_truncating(),
 		CollatorPotId::get().into_account_truncating(),
@@ -206,7 +214,8 @@ impl Contains<Call> for BaseCallFilter {
 			return true;
 		}
 
-		let is_paused = module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(call);
+		let is_paused = module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(call)
+			|| runtime_common::PausedEvmCallFilter::<Runtime>::contains(call);
 		if is_paused {
 			// no paused call
 			return false;
@@ -768,6 +777,10 @@ parameter_type_with_key! {
 				AssetIdMaps::<Runtime>::get_asset_metadata(AssetIds::ForeignAssetId(*foreign_asset_id)).
 					map_or(Balance::max_value(), |metatata| metatata.minimal_balance)
 			},
+			CurrencyId::VaultShare(vault_id) => {
+				AssetIdMaps::<Runtime>::get_asset_metadata(AssetIds::VaultId(*vault_id)).
+					map_or(Balance::max_value(), |metatata| metatata.minimal_balance)
+			},
 		}
 	};
 }
@@ -856,6 +869,7 @@ impl module_currencies::Config for Runtime {
 	type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type Erc20HoldingAccount = Erc20HoldingAccount;
+	type TreasuryAccount = AcalaTreasuryAccount;
 	type WeightInfo = weights::module_currencies::WeightInfo<Runtime>;
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
@@ -909,6 +923,26 @@ impl orml_vesting::Config for Runtime {
 	type BlockNumberProvider = RelaychainBlockNumberProvider<Runtime>;
 }
 
+impl module_multi_vesting::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type MinVestedTransfer =
+		ExistentialDepositsTimesOneHundred<GetNativeCurrencyId, NativeTokenExistentialDeposit, ExistentialDeposits>;
+	type UpdateOrigin = EnsureRootOrHalfGeneralCouncil;
+	type MaxVestingSchedules = ConstU32<100>;
+	type BlockNumberProvider = RelaychainBlockNumberProvider<Runtime>;
+	type WeightInfo = weights::module_multi_vesting::WeightInfo<Runtime>;
+}
+
+impl module_streaming::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type MinimumDeposit =
+		ExistentialDepositsTimesOneHundred<GetNativeCurrencyId, NativeTokenExistentialDeposit, ExistentialDeposits>;
+	type UnixTime = Timestamp;
+	type WeightInfo = weights::module_streaming::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
 	// Retry a scheduled item every 25 blocks (5 minute) until the preimage exists.
@@ -963,6 +997,10 @@ impl module_auction_manager::Config for Runtime {
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
+	type NFTClassId = u32;
+	type NFTTokenId = u64;
+	type NFT = NFT;
+	type TreasuryAccount = HonzonTreasuryAccount;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -975,6 +1013,25 @@ impl module_loans::Config for Runtime {
 	type OnUpdateLoan = module_incentives::OnUpdateLoan<Runtime>;
 }
 
+impl module_nft_loans::Config for Runtime {
+	type Event = Event;
+	type ClassId = u32;
+	type TokenId = u64;
+	type NFT = NFT;
+	type CollateralClassFilter = module_nft::PermanentlyTransferableClasses<Runtime>;
+	type CDPTreasury = CdpTreasury;
+	type AuctionManager = AuctionManager;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type FloorPriceOrigin =
+		EitherOfDiverse<EnsureRootOrHalfFinancialCouncil, EnsureSignedBy<OperatorMembershipAcala, AccountId>>;
+	type MaxCollateralsPerPosition = ConstU32<10>;
+	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
+	type TreasuryAccount = HonzonTreasuryAccount;
+	type UnixTime = Timestamp;
+	type PalletId = NFTLoansPalletId;
+	type WeightInfo = weights::module_nft_loans::WeightInfo<Runtime>;
+}
+
 impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
 where
 	Call: From<LocalCall>,
@@ -1097,6 +1154,7 @@ parameter_types! {
 	pub const GetExchangeFee: (u32, u32) = (3, 1000);	// 0.3%
 	pub const ExtendedProvisioningBlocks: BlockNumber = 2 * DAYS;
 	pub const TradingPathLimit: u32 = 4;
+	pub DexCircuitBreakerExemptAccounts: Vec<AccountId> = vec![CDPTreasuryPalletId::get().into_account_truncating()];
 }
 
 impl module_dex::Config for Runtime {
@@ -1111,6 +1169,8 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
 	type OnLiquidityPoolUpdated = ();
+	type CircuitBreaker = TransactionPause;
+	type CircuitBreakerExemptAccounts = IsInVec<DexCircuitBreakerExemptAccounts>;
 }
 
 impl module_aggregated_dex::Config for Runtime {
@@ -1165,6 +1225,7 @@ impl module_cdp_treasury::Config for Runtime {
 impl module_transaction_pause::Config for Runtime {
 	type Event = Event;
 	type UpdateOrigin = EnsureRootOrTwoThirdsGeneralCouncil;
+	type CircuitBreakerDuration = ConstU32<HOURS>;
 	type WeightInfo = weights::module_transaction_pause::WeightInfo<Runtime>;
 }
 
@@ -1203,6 +1264,8 @@ impl module_transaction_payment::Config for Runtime {
 	type TransactionByteFee = TransactionByteFee;
 	type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
 	type Swap = AcalaSwap;
+	type DEX = Dex;
+	type StableAsset = RebasedStableAsset;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
@@ -1213,6 +1276,18 @@ impl module_transaction_payment::Config for Runtime {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type AddressMapping = EvmAddressMapping<Runtime>;
+	type SponsoredTargetOf = runtime_common::EvmSponsoredTargetOf<Runtime>;
+	type MaxSponsoredTargets = ConstU32<10>;
+	type DynamicBaseFee = runtime_common::EvmBaseFee<Runtime>;
+	type OnDynamicBaseFee = Treasury;
+}
+
+parameter_types! {
+	pub SessionKeyDeposit: Balance = deposit(
+		1,
+		<module_evm_accounts::SessionKeyInfo<BlockNumber> as MaxEncodedLen>::max_encoded_len() as u32,
+	);
 }
 
 impl module_evm_accounts::Config for Runtime {
@@ -1220,7 +1295,16 @@ impl module_evm_accounts::Config for Runtime {
 	type Currency = Balances;
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type AccountMigration = EVM;
+	type RecoveryOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ChainId = EvmChainId<Runtime>;
+	type Call = Call;
+	type Currencies = Currencies;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type EvmCallTarget = runtime_common::EvmCallTarget<Runtime>;
+	type SessionCallFilter = runtime_common::SessionCallFilter;
+	type AccountCurrencies = runtime_common::AccountCurrencies<Runtime>;
+	type SessionKeyDeposit = SessionKeyDeposit;
 	type WeightInfo = weights::module_evm_accounts::WeightInfo<Runtime>;
 }
 
@@ -1243,7 +1327,22 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const GaugeEpochPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxEscrowLockPeriod: BlockNumber = 4 * 365 * DAYS;
+	pub MaxIncentivesBoost: Rate = Rate::saturating_from_rational(5, 2);
 	pub const EarnShareBooster: Permill = Permill::from_percent(30);
+	pub const MaxCampaignDuration: BlockNumber = 365 * DAYS;
+	pub CampaignDeposit: Balance = 10 * dollar(ACA);
+}
+
+parameter_type_with_key! {
+	pub MinCampaignRewardPerBlock: |currency_id: CurrencyId| -> Balance {
+		if *currency_id == GetNativeCurrencyId::get() {
+			NativeTokenExistentialDeposit::get()
+		} else {
+			ExistentialDeposits::get(currency_id)
+		}
+	};
 }
 
 impl module_incentives::Config for Runtime {
@@ -1259,12 +1358,32 @@ impl module_incentives::Config for Runtime {
 	type DEX = Dex;
 	type EmergencyShutdown = EmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type EpochPeriod = GaugeEpochPeriod;
+	type MaxLockPeriod = MaxEscrowLockPeriod;
+	type MaxGaugeVotes = ConstU32<16>;
+	type MaxBoost = MaxIncentivesBoost;
+	type MaxCampaigns = ConstU32<50>;
+	type MaxCampaignsPerPool = ConstU32<5>;
+	type MaxCampaignDuration = MaxCampaignDuration;
+	type MinCampaignRewardPerBlock = MinCampaignRewardPerBlock;
+	type CampaignDeposit = CampaignDeposit;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
 
+impl module_vault::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type Strategy = runtime_common::DexShareIncentivesStrategy<Runtime, AcalaSwap>;
+	type VaultShareRegistry = AssetRegistry;
+	type PalletId = VaultPalletId;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type WeightInfo = weights::module_vault::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub CreateClassDeposit: Balance = 50 * dollar(ACA);
 	pub CreateTokenDeposit: Balance = 20 * cent(ACA);
+	pub NftMaxRoyalty: Permill = Permill::from_percent(50);
 }
 
 impl module_nft::Config for Runtime {
@@ -1275,6 +1394,10 @@ impl module_nft::Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = ConstU32<2048>;
+	type MultiCurrency = Currencies;
+	type MaxRoyalty = NftMaxRoyalty;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_nft::WeightInfo<Runtime>;
 }
 
@@ -1406,6 +1529,17 @@ impl<I: From<Balance>> frame_support::traits::Get<I> for TxFeePerGas {
 	}
 }
 
+parameter_types! {
+	// half of the max gas limit of a normal dispatch
+	pub BaseFeeTargetGas: u64 = runtime_common::EvmLimits::<Runtime>::max_gas_limit() / 2;
+	// the weight fee of a gas, so the base fee never undercuts the regular transaction fee
+	pub MinBaseFeePerGas: Balance = <WeightToFee as frame_support::weights::WeightToFee>::weight_to_fee(
+		&<runtime_common::GasToWeight as sp_runtime::traits::Convert<u64, Weight>>::convert(1_000_000),
+	) / 1_000_000;
+	pub const AbandonedContractTimeout: BlockNumber = 180 * DAYS;
+	pub const CodeUpgradeDelay: BlockNumber = 7 * DAYS;
+}
+
 impl module_evm::Config for Runtime {
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type Currency = Balances;
@@ -1413,6 +1547,8 @@ impl module_evm::Config for Runtime {
 	type NewContractExtraBytes = NewContractExtraBytes;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = TxFeePerGas;
+	type BaseFeeTargetGas = BaseFeeTargetGas;
+	type MinBaseFeePerGas = MinBaseFeePerGas;
 	type Event = Event;
 	type PrecompilesType = AllPrecompiles<Self>;
 	type PrecompilesValue = PrecompilesValue;
@@ -1424,10 +1560,13 @@ impl module_evm::Config for Runtime {
 	type PublicationFee = PublicationFee;
 	type TreasuryAccount = AcalaTreasuryAccount;
 	type FreePublicationOrigin = EnsureRootOrHalfGeneralCouncil;
+	type AbandonedContractTimeout = AbandonedContractTimeout;
+	type CodeUpgradeDelay = CodeUpgradeDelay;
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type CallFilter = TransactionPause;
 	type WeightInfo = weights::module_evm::WeightInfo<Runtime>;
 }
 
@@ -1535,12 +1674,25 @@ define_combined_task! {
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 	pub enum ScheduledTasks {
 		EvmTask(EvmTask<Runtime>),
+		NftTask(NftTask<Runtime>),
+		DexOracleTask(DexOracleTask<Runtime>),
+		CdpTreasuryTask(CdpTreasuryTask<Runtime>),
+		HomaTask(HomaTask<Runtime>),
+	}
+}
+
+/// The recurring tasks can be registered by accounts, they only update states from the chain.
+pub struct RecurringTaskFilter;
+impl Contains<ScheduledTasks> for RecurringTaskFilter {
+	fn contains(task: &ScheduledTasks) -> bool {
+		matches!(task, ScheduledTasks::DexOracleTask(_) | ScheduledTasks::HomaTask(_))
 	}
 }
 
 parameter_types!(
 	// At least 2% of max block weight should remain before idle tasks are dispatched.
 	pub MinimumWeightRemainInBlock: Weight = RuntimeBlockWeights::get().max_block / 50;
+	pub RecurringTaskDeposit: Balance = 10 * dollar(ACA);
 );
 
 impl module_idle_scheduler::Config for Runtime {
@@ -1552,6 +1704,13 @@ impl module_idle_scheduler::Config for Runtime {
 	// Number of relay chain blocks produced with no parachain blocks finalized,
 	// once this number is reached idle scheduler is disabled as block production is slow
 	type DisableBlockThreshold = ConstU32<6>;
+	type MaxRetries = ConstU32<5>;
+	type RetryBackoff = ConstU32<{ 10 * MINUTES }>;
+	type Currency = Balances;
+	type RecurringTaskDeposit = RecurringTaskDeposit;
+	type RecurringTaskFilter = RecurringTaskFilter;
+	type MaxRecurringTasks = ConstU32<50>;
+	type MaxTaskHistory = ConstU32<10>;
 }
 
 pub struct EnsurePoolAssetId;
@@ -1640,6 +1799,8 @@ construct_runtime!(
 		Currencies: module_currencies = 12,
 		Vesting: orml_vesting = 13,
 		TransactionPayment: module_transaction_payment = 14,
+		MultiVesting: module_multi_vesting = 15,
+		Streaming: module_streaming = 16,
 
 		// Treasury
 		Treasury: pallet_treasury = 20,
@@ -1702,6 +1863,7 @@ construct_runtime!(
 		CdpTreasury: module_cdp_treasury = 103,
 		CdpEngine: module_cdp_engine = 104,
 		EmergencyShutdown: module_emergency_shutdown = 105,
+		NFTLoans: module_nft_loans = 106,
 
 		// Homa
 		Homa: module_homa = 116,
@@ -1711,6 +1873,7 @@ construct_runtime!(
 		Incentives: module_incentives = 120,
 		NFT: module_nft = 121,
 		AssetRegistry: module_asset_registry = 122,
+		Vault: module_vault = 123,
 
 		// Smart contracts
 		EVM: module_evm = 130,
@@ -1764,9 +1927,28 @@ pub type UncheckedExtrinsic = AcalaUncheckedExtrinsic<
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
+parameter_types! {
+	/// The ERC20 contracts and the accounts which reserved them at the legacy reserve addresses,
+	/// migrated to the holding account escrow by `module_currencies::migrations::MigrateToV1`.
+	/// The legacy reserves not listed are escrowed by `module_currencies` when they are used.
+	pub LegacyErc20Reserves: Vec<(H160, AccountId)> = vec![];
+}
+
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	(
+		module_evm::migrations::MigrateToV1<Runtime>,
+		module_currencies::migrations::MigrateToV1<Runtime, LegacyErc20Reserves>,
+		module_nft::migrations::MigrateToV1<Runtime>,
+		module_incentives::migrations::MigrateToV1<Runtime>,
+		module_idle_scheduler::migrations::MigrateToV1<Runtime, ConstU32<1_000>>,
+	),
+>;
 
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
@@ -1902,6 +2084,17 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::FeeCurrenciesApi<Block, AccountId> for Runtime {
+		fn query_fee_in_currencies(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			who: AccountId,
+			currency_ids: Vec<CurrencyId>,
+		) -> Vec<module_transaction_payment_rpc_runtime_api::CurrencyFeeDetails> {
+			TransactionPayment::query_fee_in_currencies(uxt, len, &who, currency_ids)
+		}
+	}
+
 	impl orml_oracle_rpc_runtime_api::OracleApi<
 		Block,
 		DataProviderId,
@@ -1937,14 +2130,41 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_incentives_rpc_runtime_api::IncentivesApi<Block, AccountId, Balance> for Runtime {
+		fn get_account_rewards(who: AccountId) -> Vec<module_incentives_rpc_runtime_api::PoolRewardsInfo<Balance>> {
+			Incentives::account_rewards(&who)
+				.into_iter()
+				.map(|(pool_id, shares, pending_rewards)| module_incentives_rpc_runtime_api::PoolRewardsInfo {
+					pool_id,
+					shares,
+					pending_rewards,
+					apr: runtime_common::IncentivesApr::<Runtime>::pool_apr(pool_id, 365 * DAYS),
+				})
+				.collect()
+		}
+
+		fn get_pool_apr(pool_id: PoolId) -> Option<Rate> {
+			runtime_common::IncentivesApr::<Runtime>::pool_apr(pool_id, 365 * DAYS)
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn block_limits() -> BlockLimits {
 			BlockLimits {
 				max_gas_limit: runtime_common::EvmLimits::<Runtime>::max_gas_limit(),
 				max_storage_limit: runtime_common::EvmLimits::<Runtime>::max_storage_limit(),
+				base_fee_per_gas: module_evm::convert_decimals_to_evm(EVM::base_fee_per_gas()),
 			}
 		}
 
+		fn base_fee_per_gas() -> Balance {
+			module_evm::convert_decimals_to_evm(EVM::base_fee_per_gas())
+		}
+
+		fn block_logs() -> primitives::evm::BlockLogs {
+			EVM::block_logs()
+		}
+
 		fn call(
 			from: H160,
 			to: H160,
@@ -2184,6 +2404,41 @@ impl Convert<(Call, SignedExtra), Result<(EthereumTransactionMessage, SignedExtr
 	}
 }
 
+impl Convert<(Call, SignedExtra), Result<(Eip712CallMessage, SignedExtra), InvalidTransaction>> for ConvertEthereumTx {
+	fn convert((call, extra): (Call, SignedExtra)) -> Result<(Eip712CallMessage, SignedExtra), InvalidTransaction> {
+		// only the calls whose arguments can be rendered are allowed, EVM calls and calls
+		// dispatching other calls are rejected
+		let args = runtime_common::eip712::eip712_call_args::<Runtime, EvmErc20InfoMapping<Runtime>>(&call)
+			.ok_or(InvalidTransaction::Call)?;
+
+		let (_, _, _, _, mortality, check_nonce, _, _, charge) = extra.clone();
+
+		if mortality != frame_system::CheckEra::from(sp_runtime::generic::Era::Immortal) {
+			// require immortal
+			return Err(InvalidTransaction::BadProof);
+		}
+
+		let CallMetadata {
+			pallet_name,
+			function_name,
+		} = call.get_call_metadata();
+
+		Ok((
+			Eip712CallMessage {
+				chain_id: EVM::chain_id(),
+				genesis: System::block_hash(0),
+				spec_version: VERSION.spec_version,
+				pallet_name: pallet_name.as_bytes().to_vec(),
+				call_name: function_name.as_bytes().to_vec(),
+				args,
+				nonce: check_nonce.nonce,
+				tip: charge.0,
+			},
+			extra,
+		))
+	}
+}
+
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
 pub struct PayerSignatureVerification;
 
@@ -73,4 +73,40 @@ impl<T: frame_system::Config> module_idle_scheduler::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// Storage: IdleScheduler Tasks (r:1 w:1)
+	// Storage: IdleScheduler DeadLetterTasks (r:1 w:1)
+	// Storage: IdleScheduler TaskQueues (r:0 w:3)
+	// Storage: IdleScheduler TaskRetries (r:0 w:1)
+	fn cancel_task() -> Weight {
+		(10_118_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: IdleScheduler RecurringTaskCount (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: ParachainSystem ValidationData (r:1 w:0)
+	// Storage: IdleScheduler NextTaskId (r:1 w:1)
+	// Storage: IdleScheduler RecurringTasks (r:0 w:1)
+	fn schedule_recurring_task() -> Weight {
+		(24_021_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: IdleScheduler RecurringTasks (r:1 w:1)
+	fn set_recurring_task_paused() -> Weight {
+		(8_652_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: IdleScheduler RecurringTasks (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: IdleScheduler RecurringTaskCount (r:1 w:1)
+	// Storage: IdleScheduler RecurringTaskHistory (r:0 w:1)
+	fn cancel_recurring_task() -> Weight {
+		(21_470_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
 }
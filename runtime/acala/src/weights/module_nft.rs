@@ -121,4 +121,129 @@ impl<T: frame_system::Config> module_nft::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: OrmlNFT Tokens (r:1 w:0)
+	// Storage: NFT OperatorApprovals (r:1 w:0)
+	// Storage: NFT TokenApprovals (r:0 w:1)
+	fn approve() -> Weight {
+		(21_318_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Storage: NFT OperatorApprovals (r:0 w:1)
+	fn set_approval_for_all() -> Weight {
+		(17_905_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Storage: NFT TokenApprovals (r:1 w:1)
+	// Storage: NFT OperatorApprovals (r:1 w:0)
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Storage: NFT Listings (r:1 w:0)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:2)
+	fn transfer_from() -> Weight {
+		(72_614_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Storage: NFT ClassRoyalties (r:0 w:1)
+	fn set_royalty() -> Weight {
+		(18_277_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Storage: OrmlNFT Tokens (r:1 w:0)
+	// Storage: NFT Listings (r:1 w:1)
+	fn list_fixed_price() -> Weight {
+		(24_862_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Storage: OrmlNFT Tokens (r:1 w:0)
+	// Storage: NFT Listings (r:1 w:1)
+	fn list_auction() -> Weight {
+		(25_471_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: NFT Listings (r:1 w:1)
+	fn cancel_listing() -> Weight {
+		(18_530_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: NFT Listings (r:1 w:1)
+	// Storage: NFT ClassRoyalties (r:1 w:0)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
+	// Storage: NFT TokenApprovals (r:0 w:1)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:2)
+	fn buy() -> Weight {
+		(118_035_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: NFT Listings (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	fn bid() -> Weight {
+		(45_126_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: NFT Listings (r:1 w:1)
+	// Storage: NFT ClassRoyalties (r:1 w:0)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: System Account (r:3 w:3)
+	// Storage: NFT TokenApprovals (r:0 w:1)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:2)
+	fn settle_auction() -> Weight {
+		(121_482_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Storage: NFT ClassConfigs (r:0 w:1)
+	fn set_class_config() -> Weight {
+		(19_104_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: OrmlNFT Classes (r:1 w:1)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Storage: NFT Listings (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: NFT TokenApprovals (r:0 w:1)
+	// Storage: NFT TokenExpiries (r:0 w:1)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:1)
+	fn revoke() -> Weight {
+		(49_728_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: NFT TokenExpiries (r:1 w:1)
+	// Storage: OrmlNFT Classes (r:1 w:1)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Storage: NFT Listings (r:1 w:1)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: NFT TokenApprovals (r:0 w:1)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:1)
+	fn burn_expired() -> Weight {
+		(47_316_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
 }
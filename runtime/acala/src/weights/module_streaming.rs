@@ -0,0 +1,62 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for module_streaming
+//!
+//! These are placeholder weights, they were not generated by the benchmark CLI. Replace them
+//! with the results of the `module_streaming` benchmarks.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_streaming.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_streaming::WeightInfo for WeightInfo<T> {
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Streaming NextStreamId (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens Reserves (r:1 w:1)
+	// Storage: Streaming Streams (r:0 w:1)
+	fn create_stream() -> Weight {
+		(48_512_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Streaming Streams (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: Tokens Reserves (r:1 w:1)
+	fn withdraw() -> Weight {
+		(51_307_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Streaming Streams (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: Tokens Reserves (r:1 w:1)
+	fn cancel_stream() -> Weight {
+		(55_894_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+}
@@ -0,0 +1,135 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Render runtime calls as EIP-712 typed data, so they can be signed by Ethereum wallets.
+//!
+//! Only the calls whose arguments are all rendered could be signed with EIP-712, the rest are
+//! rejected with `InvalidTransaction::Call`. The supported calls are:
+//!
+//! - `Currencies::transfer`: `dest`, `currency`, `amount`
+//! - `Currencies::transfer_native_currency`: `dest`, `amount`
+//! - `Homa::mint`: `amount`
+//! - `Homa::request_redeem`: `amount`, `allowFastMatch`
+//! - `Honzon::adjust_loan`: `currency`, `collateralAdjustment`, `debitAdjustment`
+//! - `Honzon::close_loan_has_debit_by_dex`: `currency`, `maxCollateralAmount`
+//!
+//! The calls dispatching other calls (batch, proxy...) and the EVM calls are never supported,
+//! the signer couldn't review what they dispatch.
+
+use frame_support::traits::IsSubType;
+use module_support::Erc20InfoMapping;
+use orml_traits::MultiCurrency;
+use primitives::{
+	evm::{Eip712Arg, Eip712Value},
+	AccountId, Balance, CurrencyId,
+};
+use sp_core::H256;
+use sp_runtime::traits::StaticLookup;
+use sp_std::{vec, vec::Vec};
+
+fn arg(name: &str, value: Eip712Value) -> Eip712Arg {
+	Eip712Arg {
+		name: name.as_bytes().to_vec(),
+		value,
+	}
+}
+
+fn currency_arg<Erc20Info: Erc20InfoMapping>(name: &str, currency_id: CurrencyId) -> Option<Eip712Arg> {
+	Erc20Info::encode_evm_address(currency_id).map(|address| arg(name, Eip712Value::Address(address)))
+}
+
+fn account_arg<Runtime: frame_system::Config<AccountId = AccountId>>(
+	name: &str,
+	dest: <Runtime::Lookup as StaticLookup>::Source,
+) -> Option<Eip712Arg> {
+	let who = Runtime::Lookup::lookup(dest).ok()?;
+	Some(arg(name, Eip712Value::Bytes32(H256(*who.as_ref()))))
+}
+
+/// Render the arguments of `call` as the fields of EIP-712 typed data.
+///
+/// Returns `None` if the call is not supported, see the module docs for the supported calls.
+pub fn eip712_call_args<Runtime, Erc20Info>(call: &<Runtime as frame_system::Config>::Call) -> Option<Vec<Eip712Arg>>
+where
+	Runtime: frame_system::Config<AccountId = AccountId>
+		+ module_currencies::Config
+		+ module_homa::Config
+		+ module_honzon::Config,
+	<Runtime as frame_system::Config>::Call: IsSubType<module_currencies::Call<Runtime>>
+		+ IsSubType<module_homa::Call<Runtime>>
+		+ IsSubType<module_honzon::Call<Runtime>>,
+	<<Runtime as module_currencies::Config>::MultiCurrency as MultiCurrency<AccountId>>::Balance: Into<Balance>,
+	Erc20Info: Erc20InfoMapping,
+{
+	if let Some(call) = IsSubType::<module_currencies::Call<Runtime>>::is_sub_type(call) {
+		return match call {
+			module_currencies::Call::transfer {
+				dest,
+				currency_id,
+				amount,
+			} => Some(vec![
+				account_arg::<Runtime>("dest", dest.clone())?,
+				currency_arg::<Erc20Info>("currency", *currency_id)?,
+				arg("amount", Eip712Value::Uint((*amount).into())),
+			]),
+			module_currencies::Call::transfer_native_currency { dest, amount } => Some(vec![
+				account_arg::<Runtime>("dest", dest.clone())?,
+				arg("amount", Eip712Value::Uint((*amount).into())),
+			]),
+			_ => None,
+		};
+	}
+
+	if let Some(call) = IsSubType::<module_homa::Call<Runtime>>::is_sub_type(call) {
+		return match call {
+			module_homa::Call::mint { amount } => Some(vec![arg("amount", Eip712Value::Uint(*amount))]),
+			module_homa::Call::request_redeem {
+				amount,
+				allow_fast_match,
+			} => Some(vec![
+				arg("amount", Eip712Value::Uint(*amount)),
+				arg("allowFastMatch", Eip712Value::Bool(*allow_fast_match)),
+			]),
+			_ => None,
+		};
+	}
+
+	if let Some(call) = IsSubType::<module_honzon::Call<Runtime>>::is_sub_type(call) {
+		return match call {
+			module_honzon::Call::adjust_loan {
+				currency_id,
+				collateral_adjustment,
+				debit_adjustment,
+			} => Some(vec![
+				currency_arg::<Erc20Info>("currency", *currency_id)?,
+				arg("collateralAdjustment", Eip712Value::Int(*collateral_adjustment)),
+				arg("debitAdjustment", Eip712Value::Int(*debit_adjustment)),
+			]),
+			module_honzon::Call::close_loan_has_debit_by_dex {
+				currency_id,
+				max_collateral_amount,
+			} => Some(vec![
+				currency_arg::<Erc20Info>("currency", *currency_id)?,
+				arg("maxCollateralAmount", Eip712Value::Uint(*max_collateral_amount)),
+			]),
+			_ => None,
+		};
+	}
+
+	None
+}
@@ -23,8 +23,9 @@
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
+	dispatch::GetCallMetadata,
 	parameter_types,
-	traits::{Contains, EitherOfDiverse, Get},
+	traits::{Contains, EitherOfDiverse, Get, IsSubType},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, WEIGHT_PER_MILLIS},
 		DispatchClass, Weight,
@@ -32,12 +33,18 @@ use frame_support::{
 	RuntimeDebug,
 };
 use frame_system::{limits, EnsureRoot};
-use module_evm::GenesisAccount;
-use orml_traits::GetByKey;
-use primitives::{evm::is_system_contract, Balance, CurrencyId, Nonce};
+use module_evm::{GenesisAccount, TransactionAction};
+use module_support::{DEXManager, IncentivesManager, PoolId, PriceProvider, SwapLimit};
+use module_transaction_payment::{SponsoredTarget, SponsoredTargetOf};
+use orml_traits::{GetByKey, MultiCurrency};
+use primitives::{currency::VaultId, evm::is_system_contract, Balance, CurrencyId, Multiplier, Nonce};
 use scale_info::TypeInfo;
 use sp_core::{Bytes, H160};
-use sp_runtime::{traits::Convert, transaction_validity::TransactionPriority, Perbill};
+use sp_runtime::{
+	traits::{Convert, Zero},
+	transaction_validity::TransactionPriority,
+	DispatchResult, FixedPointNumber, Perbill,
+};
 use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, prelude::*};
 use static_assertions::const_assert;
 
@@ -62,6 +69,7 @@ use std::str::FromStr;
 
 pub mod bench;
 pub mod check_nonce;
+pub mod eip712;
 pub mod precompile;
 pub mod xcm_impl;
 
@@ -359,6 +367,270 @@ where
 	}
 }
 
+/// Returns the contract of the EVM calls, used by the session keys of `module_evm_accounts`.
+pub struct EvmCallTarget<T>(PhantomData<T>);
+impl<T, Call> module_evm_accounts::EvmCallTarget<Call> for EvmCallTarget<T>
+where
+	T: module_evm::Config,
+	Call: IsSubType<module_evm::Call<T>>,
+{
+	fn evm_call_target(call: &Call) -> Option<H160> {
+		match call.is_sub_type()? {
+			module_evm::Call::eth_call {
+				action: TransactionAction::Call(target),
+				..
+			}
+			| module_evm::Call::call { target, .. }
+			| module_evm::Call::strict_call { target, .. } => Some(*target),
+			module_evm::Call::transfer_maintainer { contract, .. }
+			| module_evm::Call::publish_contract { contract }
+			| module_evm::Call::set_code { contract, .. }
+			| module_evm::Call::selfdestruct { contract }
+			| module_evm::Call::propose_code_upgrade { contract, .. } => Some(*contract),
+			_ => None,
+		}
+	}
+}
+
+/// Returns the currencies held by the account, used by the session keys of
+/// `module_evm_accounts`: the tokens of `orml_tokens` and the registered ERC20 tokens the account
+/// has a balance of.
+pub struct AccountCurrencies<T>(PhantomData<T>);
+impl<T> module_evm_accounts::AccountCurrencies<T::AccountId> for AccountCurrencies<T>
+where
+	T: orml_tokens::Config<CurrencyId = CurrencyId> + module_asset_registry::Config + module_currencies::Config,
+{
+	fn account_currencies(who: &T::AccountId, limit: u32) -> Vec<CurrencyId> {
+		let erc20_currencies = module_asset_registry::Erc20IdToAddress::<T>::iter_values()
+			.map(CurrencyId::Erc20)
+			.filter(|currency_id| {
+				!<module_currencies::Pallet<T> as MultiCurrency<T::AccountId>>::total_balance(*currency_id, who)
+					.is_zero()
+			});
+		orml_tokens::Accounts::<T>::iter_key_prefix(who)
+			.chain(erc20_currencies)
+			.take(limit as usize)
+			.collect()
+	}
+}
+
+/// The calls session keys of `module_evm_accounts` can dispatch. Calls of the pallets dispatching
+/// other calls are rejected since the session key scope only checks the outer call.
+pub struct SessionCallFilter;
+impl<Call: GetCallMetadata> Contains<Call> for SessionCallFilter {
+	fn contains(call: &Call) -> bool {
+		![
+			"Scheduler",
+			"Utility",
+			"Multisig",
+			"Proxy",
+			"Recovery",
+			"IdleScheduler",
+			"TransactionPayment",
+			"PolkadotXcm",
+			"Authority",
+			"Sudo",
+		]
+		.contains(&call.get_call_metadata().pallet_name)
+	}
+}
+
+/// Filters the EVM calls to the contracts or function selectors paused by
+/// `module_transaction_pause`.
+pub struct PausedEvmCallFilter<T>(PhantomData<T>);
+impl<T, Call> Contains<Call> for PausedEvmCallFilter<T>
+where
+	T: module_evm::Config + module_transaction_pause::Config,
+	Call: IsSubType<module_evm::Call<T>>,
+{
+	fn contains(call: &Call) -> bool {
+		match call.is_sub_type() {
+			Some(module_evm::Call::eth_call {
+				action: TransactionAction::Call(target),
+				input,
+				..
+			})
+			| Some(module_evm::Call::call { target, input, .. })
+			| Some(module_evm::Call::strict_call { target, input, .. }) => {
+				module_transaction_pause::Pallet::<T>::is_evm_call_paused(target, input)
+			}
+			_ => false,
+		}
+	}
+}
+
+/// Returns the sponsored target of calls for the paymasters of `module_transaction_payment`: the
+/// contract for EVM calls, otherwise the pallet of the call.
+pub struct EvmSponsoredTargetOf<T>(PhantomData<T>);
+impl<T, Call> SponsoredTargetOf<Call> for EvmSponsoredTargetOf<T>
+where
+	T: module_evm::Config,
+	Call: Encode + IsSubType<module_evm::Call<T>>,
+{
+	fn sponsored_target(call: &Call) -> Option<SponsoredTarget> {
+		match call.is_sub_type() {
+			Some(module_evm::Call::eth_call {
+				action: TransactionAction::Call(target),
+				..
+			})
+			| Some(module_evm::Call::call { target, .. })
+			| Some(module_evm::Call::strict_call { target, .. }) => Some(SponsoredTarget::Contract(*target)),
+			_ => <() as SponsoredTargetOf<Call>>::sponsored_target(call),
+		}
+	}
+}
+
+/// Returns the dynamic base fee of the EVM calls for `module_transaction_payment`, so the base
+/// fee is charged once by the transaction fee path.
+pub struct EvmBaseFee<T>(PhantomData<T>);
+impl<T, Call> module_support::DynamicBaseFee<Call> for EvmBaseFee<T>
+where
+	T: module_evm::Config,
+	Call: IsSubType<module_evm::Call<T>>,
+{
+	fn base_fee_per_weight(call: &Call) -> Option<Multiplier> {
+		match call.is_sub_type()? {
+			module_evm::Call::eth_call { .. }
+			| module_evm::Call::call { .. }
+			| module_evm::Call::strict_call { .. }
+			| module_evm::Call::create { .. }
+			| module_evm::Call::create2 { .. } => Some(module_evm::Pallet::<T>::base_fee_per_weight()),
+			_ => None,
+		}
+	}
+}
+
+/// The annual percentage rate of the incentives pools, used by the incentives runtime API.
+pub struct IncentivesApr<T>(PhantomData<T>);
+impl<T> IncentivesApr<T>
+where
+	T: module_incentives::Config + module_cdp_engine::Config + module_prices::Config,
+{
+	/// The value of the total shares of the pool. PoolId::Dex is valued by the price of the DEX
+	/// share, PoolId::Loans is valued by the debit value in stable currency.
+	pub fn pool_value(pool_id: PoolId) -> Option<Balance> {
+		let total_shares = module_incentives::Pallet::<T>::total_deposited_shares(&pool_id);
+		match pool_id {
+			PoolId::Dex(lp_currency_id) => module_prices::RealTimePriceProvider::<T>::get_price(lp_currency_id)
+				.map(|price| price.saturating_mul_int(total_shares)),
+			PoolId::Loans(currency_id) => {
+				let debit_value = module_cdp_engine::Pallet::<T>::get_debit_exchange_rate(currency_id)
+					.saturating_mul_int(total_shares);
+				module_prices::RealTimePriceProvider::<T>::get_price(
+					<T as module_incentives::Config>::StableCurrencyId::get(),
+				)
+				.map(|price| price.saturating_mul_int(debit_value))
+			}
+		}
+	}
+
+	pub fn pool_apr(pool_id: PoolId, blocks_per_year: T::BlockNumber) -> Option<Rate> {
+		module_incentives::Pallet::<T>::pool_apr::<module_prices::RealTimePriceProvider<T>>(
+			pool_id,
+			Self::pool_value(pool_id)?,
+			blocks_per_year,
+		)
+	}
+}
+
+/// Vault strategy for the DEX share underlying, stakes the DEX share in the incentives pool
+/// and compounds the claimed rewards into more DEX share on harvest. Other underlying are kept
+/// idle in the vault account.
+///
+/// Swaps of harvest have no slippage limit, so harvest must be restricted to trusted origins.
+pub struct DexShareIncentivesStrategy<T, Swap>(PhantomData<(T, Swap)>);
+impl<T, Swap> module_vault::VaultStrategy<T::AccountId> for DexShareIncentivesStrategy<T, Swap>
+where
+	T: module_incentives::Config,
+	Swap: module_support::Swap<T::AccountId, Balance, CurrencyId>,
+{
+	fn invested_assets(_: VaultId, vault_account: &T::AccountId, underlying: CurrencyId) -> Balance {
+		if underlying.is_dex_share_currency_id() {
+			<module_incentives::Pallet<T> as IncentivesManager<_, _, _, _>>::get_shares(
+				PoolId::Dex(underlying),
+				vault_account.clone(),
+			)
+		} else {
+			Zero::zero()
+		}
+	}
+
+	fn invest(_: VaultId, vault_account: &T::AccountId, underlying: CurrencyId, amount: Balance) -> DispatchResult {
+		if underlying.is_dex_share_currency_id() {
+			<module_incentives::Pallet<T> as IncentivesManager<_, _, _, _>>::deposit_dex_share(
+				vault_account,
+				underlying,
+				amount,
+			)
+		} else {
+			Ok(())
+		}
+	}
+
+	fn divest(_: VaultId, vault_account: &T::AccountId, underlying: CurrencyId, amount: Balance) -> DispatchResult {
+		if underlying.is_dex_share_currency_id() {
+			<module_incentives::Pallet<T> as IncentivesManager<_, _, _, _>>::withdraw_dex_share(
+				vault_account,
+				underlying,
+				amount,
+			)
+		} else {
+			Ok(())
+		}
+	}
+
+	fn harvest(_: VaultId, vault_account: &T::AccountId, underlying: CurrencyId) -> DispatchResult {
+		let (currency_id_a, currency_id_b) = match underlying.split_dex_share_currency_id() {
+			Some(pair) => pair,
+			None => return Ok(()),
+		};
+		let pool_id = PoolId::Dex(underlying);
+		<module_incentives::Pallet<T> as IncentivesManager<_, _, _, _>>::claim_rewards(vault_account.clone(), pool_id)?;
+
+		// swap the rewards to `currency_id_a`, the failed swaps are retried in the next harvest.
+		for (reward_currency_id, _) in module_incentives::IncentiveRewardAmounts::<T>::iter_prefix(pool_id) {
+			if reward_currency_id == currency_id_a || reward_currency_id == currency_id_b {
+				continue;
+			}
+			let amount = T::Currency::free_balance(reward_currency_id, vault_account);
+			if !amount.is_zero() {
+				let _ = Swap::swap(
+					vault_account,
+					reward_currency_id,
+					currency_id_a,
+					SwapLimit::ExactSupply(amount, Zero::zero()),
+				);
+			}
+		}
+
+		let half = T::Currency::free_balance(currency_id_a, vault_account) / 2;
+		if !half.is_zero() {
+			let _ = Swap::swap(
+				vault_account,
+				currency_id_a,
+				currency_id_b,
+				SwapLimit::ExactSupply(half, Zero::zero()),
+			);
+		}
+
+		let amount_a = T::Currency::free_balance(currency_id_a, vault_account);
+		let amount_b = T::Currency::free_balance(currency_id_b, vault_account);
+		if !amount_a.is_zero() && !amount_b.is_zero() {
+			T::DEX::add_liquidity(
+				vault_account,
+				currency_id_a,
+				currency_id_b,
+				amount_a,
+				amount_b,
+				Zero::zero(),
+				true,
+			)?;
+		}
+
+		Ok(())
+	}
+}
+
 #[cfg(feature = "std")]
 /// Returns `evm_genesis_accounts`
 pub fn evm_genesis(evm_accounts: Vec<H160>) -> BTreeMap<H160, GenesisAccount<Balance, Nonce>> {
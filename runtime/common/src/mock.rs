@@ -153,6 +153,13 @@ impl module_idle_scheduler::Config for TestRuntime {
 	type MinimumWeightRemainInBlock = ConstU64<0>;
 	type RelayChainBlockNumberProvider = MockBlockNumberProvider;
 	type DisableBlockThreshold = ConstU32<6>;
+	type MaxRetries = ConstU32<3>;
+	type RetryBackoff = ConstU64<10>;
+	type Currency = Balances;
+	type RecurringTaskDeposit = ConstU128<10>;
+	type RecurringTaskFilter = Nothing;
+	type MaxRecurringTasks = ConstU32<10>;
+	type MaxTaskHistory = ConstU32<10>;
 }
 
 pub struct GasToWeight;
@@ -189,7 +196,13 @@ impl module_evm_accounts::Config for TestRuntime {
 	type Currency = Balances;
 	type AddressMapping = EvmAddressMapping<TestRuntime>;
 	type TransferAll = Currencies;
+	type AccountMigration = EVM;
+	type RecoveryOrigin = frame_system::EnsureRoot<AccountId32>;
 	type ChainId = EvmChainId<TestRuntime>;
+	type Call = Call;
+	type Currencies = Currencies;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type EvmCallTarget = crate::EvmCallTarget<TestRuntime>;
 	type WeightInfo = ();
 }
 
@@ -200,6 +213,8 @@ impl module_evm::Config for TestRuntime {
 	type NewContractExtraBytes = ConstU32<100>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = ConstU128<20_000_000>;
+	type BaseFeeTargetGas = ConstU64<10_000_000>;
+	type MinBaseFeePerGas = ConstU128<0>;
 
 	type Event = Event;
 	type PrecompilesType = ();
@@ -213,11 +228,14 @@ impl module_evm::Config for TestRuntime {
 	type PublicationFee = ConstU128<200>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = frame_system::EnsureSignedBy<CouncilAccount, AccountId32>;
+	type AbandonedContractTimeout = ConstU64<100>;
+	type CodeUpgradeDelay = ConstU64<10>;
 
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = AuthorGiven;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type CallFilter = ();
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,847 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	input::{Input, InputPricer, InputT, Output},
+	target_gas_limit,
+};
+use crate::WeightToGas;
+use ethabi::Token;
+use frame_support::{log, traits::Get};
+use hex_literal::hex;
+use module_evm::{
+	precompiles::Precompile,
+	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
+	Context, ExitError, ExitReason, ExitRevert, ExitSucceed, Log,
+};
+use module_nft::WeightInfo;
+use module_support::{AddressMapping, ExecutionMode, InvokeContext, EVM};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::evm::decode_mirrored_nft_address;
+use sp_core::{H160, H256, U256};
+use sp_runtime::{traits::Convert, DispatchError, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// Transfer(address,address,uint256)
+pub const TRANSFER_EVENT_TOPIC: H256 = H256(hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"));
+/// Approval(address,address,uint256)
+pub const APPROVAL_EVENT_TOPIC: H256 = H256(hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925"));
+/// ApprovalForAll(address,address,bool)
+pub const APPROVAL_FOR_ALL_EVENT_TOPIC: H256 =
+	H256(hex!("17307eab39ab6107e8899845ad3d59bd9653f200f220920489ca2b5937696c31"));
+
+/// ERC-165 interface id.
+const ERC165_INTERFACE_ID: [u8; 4] = hex!("01ffc9a7");
+/// ERC-721 interface id.
+const ERC721_INTERFACE_ID: [u8; 4] = hex!("80ac58cd");
+/// ERC-721 metadata interface id.
+const ERC721_METADATA_INTERFACE_ID: [u8; 4] = hex!("5b5e139f");
+/// onERC721Received(address,address,uint256,bytes)
+const ON_ERC721_RECEIVED_SELECTOR: [u8; 4] = hex!("150b7a02");
+/// The gas limit of `onERC721Received` when the precompile is called without a gas limit.
+const ON_ERC721_RECEIVED_GAS_LIMIT: u64 = 100_000;
+/// The storage limit of `onERC721Received`, charged from the origin.
+const ON_ERC721_RECEIVED_STORAGE_LIMIT: u32 = 1_000;
+
+/// The mirrored NFT precompile, the ERC-721 interface of the NFT classes.
+///
+/// Every NFT class is mirrored at `mirrored_nft_address(class_id)`, the class is decoded from the
+/// called address and `msg.sender` is the caller.
+///
+/// Actions:
+///  - Name. Returns the class metadata.
+///  - Symbol.
+///  - TotalSupply.
+///  - BalanceOf. Rest `input` bytes: `owner`.
+///  - OwnerOf. Rest `input` bytes: `token_id`.
+///  - TokenURI. Rest `input` bytes: `token_id`. Returns the token metadata.
+///  - GetApproved. Rest `input` bytes: `token_id`.
+///  - IsApprovedForAll. Rest `input` bytes: `owner`, `operator`.
+///  - Approve. Rest `input` bytes: `approved`, `token_id`.
+///  - SetApprovalForAll. Rest `input` bytes: `operator`, `approved`.
+///  - TransferFrom. Rest `input` bytes: `from`, `to`, `token_id`.
+///  - SafeTransferFrom. Rest `input` bytes: `from`, `to`, `token_id`, (`data`). Calls
+///    `onERC721Received` of `to` if it's a contract, and reverts unless it's accepted.
+///  - SupportsInterface. Rest `input` bytes: `interface_id`.
+pub struct MirroredNFTPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	Name = "name()",
+	Symbol = "symbol()",
+	TotalSupply = "totalSupply()",
+	BalanceOf = "balanceOf(address)",
+	OwnerOf = "ownerOf(uint256)",
+	TokenURI = "tokenURI(uint256)",
+	GetApproved = "getApproved(uint256)",
+	IsApprovedForAll = "isApprovedForAll(address,address)",
+	Approve = "approve(address,uint256)",
+	SetApprovalForAll = "setApprovalForAll(address,bool)",
+	TransferFrom = "transferFrom(address,address,uint256)",
+	SafeTransferFrom = "safeTransferFrom(address,address,uint256)",
+	SafeTransferFromWithData = "safeTransferFrom(address,address,uint256,bytes)",
+	SupportsInterface = "supportsInterface(bytes4)",
+}
+
+impl<Runtime> Precompile for MirroredNFTPrecompile<Runtime>
+where
+	Runtime: module_evm::Config
+		+ module_prices::Config
+		+ module_nft::Config
+		+ orml_nft::Config<ClassId = u32, TokenId = u64>,
+{
+	fn execute(input: &[u8], target_gas: Option<u64>, context: &Context, is_static: bool) -> PrecompileResult {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			input,
+			target_gas_limit(target_gas),
+		);
+
+		let class_id = decode_mirrored_nft_address(context.address)
+			.filter(|class_id| orml_nft::Pallet::<Runtime>::classes(class_id).is_some())
+			.ok_or_else(|| PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: "invalid class id".into(),
+				cost: target_gas_limit(target_gas).unwrap_or_default(),
+			})?;
+
+		let gas_cost = Pricer::<Runtime>::cost(&input, class_id)?;
+
+		if let Some(gas_limit) = target_gas {
+			if gas_limit < gas_cost {
+				return Err(PrecompileFailure::Error {
+					exit_status: ExitError::OutOfGas,
+				});
+			}
+		}
+
+		let action = input.action()?;
+		let caller = Runtime::AddressMapping::get_account_id(&context.caller);
+
+		if is_static
+			&& matches!(
+				action,
+				Action::Approve
+					| Action::SetApprovalForAll
+					| Action::TransferFrom
+					| Action::SafeTransferFrom
+					| Action::SafeTransferFromWithData
+			) {
+			return Err(PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: "not allowed in static call".into(),
+				cost: target_gas_limit(target_gas).unwrap_or_default(),
+			});
+		}
+
+		log::debug!(target: "evm", "mirrored nft: {:?}, class_id: {:?}, caller: {:?}", action, class_id, caller);
+
+		let mut gas_used = gas_cost;
+		let mut logs = vec![];
+		let output = match action {
+			Action::Name => {
+				let metadata = orml_nft::Pallet::<Runtime>::classes(class_id)
+					.map(|class_info| class_info.metadata.to_vec())
+					.unwrap_or_default();
+				Output::encode_bytes(&metadata)
+			}
+			Action::Symbol => Output::encode_bytes(b"NFT"),
+			Action::TotalSupply => {
+				let total_issuance = orml_nft::Pallet::<Runtime>::classes(class_id)
+					.map(|class_info| class_info.total_issuance)
+					.unwrap_or_default();
+				Output::encode_uint(total_issuance)
+			}
+			Action::BalanceOf => {
+				let owner = input.account_id_at(1)?;
+				Output::encode_uint(module_nft::Pallet::<Runtime>::class_balance(&owner, class_id))
+			}
+			Action::OwnerOf => {
+				let token_id = input.u64_at(1)?;
+				let token_info = token_info::<Runtime>(class_id, token_id, target_gas)?;
+				Output::encode_address(evm_address_of::<Runtime>(&token_info.owner))
+			}
+			Action::TokenURI => {
+				let token_id = input.u64_at(1)?;
+				let token_info = token_info::<Runtime>(class_id, token_id, target_gas)?;
+				Output::encode_bytes(&token_info.metadata)
+			}
+			Action::GetApproved => {
+				let token_id = input.u64_at(1)?;
+				token_info::<Runtime>(class_id, token_id, target_gas)?;
+				let approved = module_nft::Pallet::<Runtime>::token_approvals(class_id, token_id)
+					.map(|a| evm_address_of::<Runtime>(&a))
+					.unwrap_or_default();
+				Output::encode_address(approved)
+			}
+			Action::IsApprovedForAll => {
+				let owner = input.account_id_at(1)?;
+				let operator = input.account_id_at(2)?;
+				Output::encode_bool(module_nft::Pallet::<Runtime>::operator_approvals(
+					(&owner, class_id),
+					&operator,
+				))
+			}
+			Action::Approve => {
+				let approved = input.evm_address_at(1)?;
+				let token_id = input.u64_at(2)?;
+				let owner = token_info::<Runtime>(class_id, token_id, target_gas)?.owner;
+
+				// approve the zero address to clear the approval
+				let operator = if approved.is_zero() {
+					None
+				} else {
+					Some(Runtime::AddressMapping::get_account_id(&approved))
+				};
+				module_nft::Pallet::<Runtime>::do_approve(&caller, operator, (class_id, token_id))
+					.map_err(|e| revert(e, target_gas))?;
+
+				logs.push(Log {
+					address: context.address,
+					topics: vec![
+						APPROVAL_EVENT_TOPIC,
+						H256::from(evm_address_of::<Runtime>(&owner)),
+						H256::from(approved),
+						H256::from_low_u64_be(token_id),
+					],
+					data: vec![],
+				});
+				vec![]
+			}
+			Action::SetApprovalForAll => {
+				let operator = input.evm_address_at(1)?;
+				let approved = input.bool_at(2)?;
+
+				module_nft::Pallet::<Runtime>::do_set_approval_for_all(
+					&caller,
+					class_id,
+					&Runtime::AddressMapping::get_account_id(&operator),
+					approved,
+				)
+				.map_err(|e| revert(e, target_gas))?;
+
+				logs.push(Log {
+					address: context.address,
+					topics: vec![
+						APPROVAL_FOR_ALL_EVENT_TOPIC,
+						H256::from(context.caller),
+						H256::from(operator),
+					],
+					data: Output::encode_bool(approved),
+				});
+				vec![]
+			}
+			Action::TransferFrom | Action::SafeTransferFrom | Action::SafeTransferFromWithData => {
+				let from = input.evm_address_at(1)?;
+				let to = input.evm_address_at(2)?;
+				let token_id = input.u64_at(3)?;
+
+				module_nft::Pallet::<Runtime>::do_transfer_from(
+					&caller,
+					&Runtime::AddressMapping::get_account_id(&from),
+					&Runtime::AddressMapping::get_account_id(&to),
+					(class_id, token_id),
+				)
+				.map_err(|e| revert(e, target_gas))?;
+
+				logs.push(Log {
+					address: context.address,
+					topics: vec![
+						TRANSFER_EVENT_TOPIC,
+						H256::from(from),
+						H256::from(to),
+						H256::from_low_u64_be(token_id),
+					],
+					data: vec![],
+				});
+
+				if action != Action::TransferFrom && module_evm::Pallet::<Runtime>::is_contract(&to) {
+					let data = if action == Action::SafeTransferFromWithData {
+						// `data` is the last param, after its offset and length
+						let data_len = input.u32_at(5)?;
+						input.bytes_at(6, data_len as usize)?
+					} else {
+						vec![]
+					};
+					let used_gas = check_on_erc721_received::<Runtime>(
+						context,
+						from,
+						to,
+						token_id,
+						data,
+						target_gas.map(|gas_limit| gas_limit.saturating_sub(gas_used)),
+					)?;
+					gas_used = gas_used.saturating_add(used_gas);
+				}
+				vec![]
+			}
+			Action::SupportsInterface => {
+				let interface_id = input.bytes_at(1, 4)?;
+				Output::encode_bool(
+					interface_id == ERC165_INTERFACE_ID
+						|| interface_id == ERC721_INTERFACE_ID
+						|| interface_id == ERC721_METADATA_INTERFACE_ID,
+				)
+			}
+		};
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			cost: gas_used,
+			output,
+			logs,
+		})
+	}
+}
+
+/// Call `onERC721Received` of the contract `to` and return the used gas. Reverts unless the
+/// contract returns the function selector.
+fn check_on_erc721_received<Runtime: module_evm::Config>(
+	context: &Context,
+	from: H160,
+	to: H160,
+	token_id: u64,
+	data: Vec<u8>,
+	gas_limit: Option<u64>,
+) -> Result<u64, PrecompileFailure> {
+	let not_receiver = |cost: u64| PrecompileFailure::Revert {
+		exit_status: ExitRevert::Reverted,
+		output: "ERC721: transfer to non ERC721Receiver implementer".into(),
+		cost,
+	};
+
+	let mut input = ON_ERC721_RECEIVED_SELECTOR.to_vec();
+	input.extend(ethabi::encode(&[
+		Token::Address(context.caller),
+		Token::Address(from),
+		Token::Uint(U256::from(token_id)),
+		Token::Bytes(data),
+	]));
+	let origin = <module_evm::Pallet<Runtime> as EVM<Runtime::AccountId>>::get_origin()
+		.map(|origin| evm_address_of::<Runtime>(&origin))
+		.unwrap_or(context.caller);
+
+	let info = <module_evm::Pallet<Runtime> as EVM<Runtime::AccountId>>::execute(
+		InvokeContext {
+			contract: to,
+			// the mirrored NFT is `msg.sender` of the receiver
+			sender: context.address,
+			origin,
+		},
+		input,
+		Default::default(),
+		gas_limit.unwrap_or(ON_ERC721_RECEIVED_GAS_LIMIT),
+		ON_ERC721_RECEIVED_STORAGE_LIMIT,
+		ExecutionMode::Execute,
+	)
+	.map_err(|_| not_receiver(gas_limit.unwrap_or_default()))?;
+
+	let used_gas = info.used_gas.try_into().unwrap_or(u64::MAX);
+	let accepted = matches!(info.exit_reason, ExitReason::Succeed(_))
+		&& info.value.len() == 32
+		&& info.value[..4] == ON_ERC721_RECEIVED_SELECTOR
+		&& info.value[4..].iter().all(|b| *b == 0);
+	if !accepted {
+		return Err(not_receiver(used_gas));
+	}
+	Ok(used_gas)
+}
+
+fn token_info<Runtime: orml_nft::Config<ClassId = u32, TokenId = u64>>(
+	class_id: u32,
+	token_id: u64,
+	target_gas: Option<u64>,
+) -> Result<orml_nft::TokenInfoOf<Runtime>, PrecompileFailure> {
+	orml_nft::Pallet::<Runtime>::tokens(class_id, token_id).ok_or_else(|| PrecompileFailure::Revert {
+		exit_status: ExitRevert::Reverted,
+		output: "ERC721: invalid token ID".into(),
+		cost: target_gas_limit(target_gas).unwrap_or_default(),
+	})
+}
+
+fn evm_address_of<Runtime: module_evm::Config>(who: &Runtime::AccountId) -> H160 {
+	Runtime::AddressMapping::get_evm_address(who)
+		.unwrap_or_else(|| Runtime::AddressMapping::get_default_evm_address(who))
+}
+
+fn revert(e: DispatchError, target_gas: Option<u64>) -> PrecompileFailure {
+	PrecompileFailure::Revert {
+		exit_status: ExitRevert::Reverted,
+		output: Into::<&str>::into(e).as_bytes().to_vec(),
+		cost: target_gas_limit(target_gas).unwrap_or_default(),
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config
+		+ module_prices::Config
+		+ module_nft::Config
+		+ orml_nft::Config<ClassId = u32, TokenId = u64>,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(
+		input: &Input<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>,
+		class_id: u32,
+	) -> Result<u64, PrecompileFailure> {
+		let action = input.action()?;
+
+		// OrmlNFT::Classes (r: 1)
+		let cost = WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(1));
+		let cost = cost.saturating_add(match action {
+			Action::Name | Action::Symbol | Action::TotalSupply | Action::SupportsInterface => 0,
+			Action::BalanceOf => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// NFT::ClassBalances (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::OwnerOf | Action::TokenURI => {
+				// OrmlNFT::Tokens (r: 1)
+				// EvmAccounts::EvmAddresses (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(2))
+			}
+			Action::GetApproved => {
+				// OrmlNFT::Tokens (r: 1)
+				// NFT::TokenApprovals (r: 1)
+				// EvmAccounts::EvmAddresses (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(3))
+			}
+			Action::IsApprovedForAll => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// NFT::OperatorApprovals (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Approve => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::approve();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::SetApprovalForAll => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::set_approval_for_all();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::TransferFrom | Action::SafeTransferFrom | Action::SafeTransferFromWithData => {
+				let cost = InputPricer::<Runtime>::read_accounts(3);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::transfer_from();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+		});
+
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{
+		alice, alice_evm_addr, bob, bob_evm_addr, new_test_ext, Balances, NFTModule, Origin, Test,
+	};
+	use frame_support::{assert_noop, assert_ok, traits::Currency};
+	use primitives::{
+		evm::mirrored_nft_address,
+		nft::{ClassProperty, Properties},
+	};
+	use sp_runtime::traits::AccountIdConversion;
+
+	type MirroredNFTPrecompile = super::MirroredNFTPrecompile<Test>;
+
+	fn mint_to_bob() {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(alice()),
+			b"Acala Punks".to_vec(),
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		let class_account = <Test as module_nft::Config>::PalletId::get().into_sub_account_truncating(0u32);
+		Balances::make_free_balance_be(&class_account, 1_000);
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_account),
+			bob(),
+			0,
+			b"ipfs://punk".to_vec(),
+			Default::default(),
+			2
+		));
+	}
+
+	#[test]
+	fn invalid_class_id_fails() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: mirrored_nft_address(0),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// totalSupply() -> 0x18160ddd
+			let input = hex! {"
+				18160ddd
+			"};
+			assert_noop!(
+				MirroredNFTPrecompile::execute(&input, Some(10_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid class id".into(),
+					cost: 9_000,
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn metadata_and_balance_works() {
+		new_test_ext().execute_with(|| {
+			mint_to_bob();
+			let context = Context {
+				address: mirrored_nft_address(0),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// name() -> 0x06fdde03
+			let input = hex! {"
+				06fdde03
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_bytes(b"Acala Punks"));
+
+			// totalSupply() -> 0x18160ddd
+			let input = hex! {"
+				18160ddd
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.output, Output::encode_uint(2u64));
+
+			// balanceOf(address) -> 0x70a08231
+			// owner
+			let input = hex! {"
+				70a08231
+				000000000000000000000000 1000000000000000000000000000000000000002
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.output, Output::encode_uint(2u128));
+
+			// ownerOf(uint256) -> 0x6352211e
+			// token_id
+			let input = hex! {"
+				6352211e
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.output, Output::encode_address(bob_evm_addr()));
+
+			// tokenURI(uint256) -> 0xc87b56dd
+			// token_id
+			let input = hex! {"
+				c87b56dd
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.output, Output::encode_bytes(b"ipfs://punk"));
+
+			// ownerOf(uint256) -> 0x6352211e
+			// token_id
+			let input = hex! {"
+				6352211e
+				00000000000000000000000000000000 00000000000000000000000000000002
+			"};
+			assert_noop!(
+				MirroredNFTPrecompile::execute(&input, Some(100_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "ERC721: invalid token ID".into(),
+					cost: 90_000,
+				}
+			);
+
+			// supportsInterface(bytes4) -> 0x01ffc9a7
+			// interface_id
+			let input = hex! {"
+				01ffc9a7
+				80ac58cd00000000000000000000000000000000000000000000000000000000
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.output, Output::encode_bool(true));
+
+			let input = hex! {"
+				01ffc9a7
+				ffffffff00000000000000000000000000000000000000000000000000000000
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.output, Output::encode_bool(false));
+		});
+	}
+
+	#[test]
+	fn approve_and_transfer_from_works() {
+		new_test_ext().execute_with(|| {
+			mint_to_bob();
+			let nft_address = mirrored_nft_address(0);
+			let bob_context = Context {
+				address: nft_address,
+				caller: bob_evm_addr(),
+				apparent_value: Default::default(),
+			};
+			let alice_context = Context {
+				address: nft_address,
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// safeTransferFrom(address,address,uint256) -> 0x42842e0e
+			// from
+			// to
+			// token_id
+			let transfer_input = hex! {"
+				42842e0e
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_noop!(
+				MirroredNFTPrecompile::execute(&transfer_input, Some(200_000), &alice_context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "NoPermission".into(),
+					cost: 180_000,
+				}
+			);
+
+			// approve(address,uint256) -> 0x095ea7b3
+			// approved
+			// token_id
+			let input = hex! {"
+				095ea7b3
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &bob_context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				res.logs,
+				vec![Log {
+					address: nft_address,
+					topics: vec![
+						APPROVAL_EVENT_TOPIC,
+						H256::from(bob_evm_addr()),
+						H256::from(alice_evm_addr()),
+						H256::from_low_u64_be(0),
+					],
+					data: vec![],
+				}]
+			);
+
+			// getApproved(uint256) -> 0x081812fc
+			// token_id
+			let input = hex! {"
+				081812fc
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &bob_context, false).unwrap();
+			assert_eq!(res.output, Output::encode_address(alice_evm_addr()));
+
+			let res = MirroredNFTPrecompile::execute(&transfer_input, None, &alice_context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				res.logs,
+				vec![Log {
+					address: nft_address,
+					topics: vec![
+						TRANSFER_EVENT_TOPIC,
+						H256::from(bob_evm_addr()),
+						H256::from(alice_evm_addr()),
+						H256::from_low_u64_be(0),
+					],
+					data: vec![],
+				}]
+			);
+			assert_eq!(orml_nft::Pallet::<Test>::tokens(0, 0).map(|t| t.owner), Some(alice()));
+
+			// setApprovalForAll(address,bool) -> 0xa22cb465
+			// operator
+			// approved
+			let input = hex! {"
+				a22cb465
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &bob_context, false).unwrap();
+			assert_eq!(
+				res.logs,
+				vec![Log {
+					address: nft_address,
+					topics: vec![
+						APPROVAL_FOR_ALL_EVENT_TOPIC,
+						H256::from(bob_evm_addr()),
+						H256::from(alice_evm_addr()),
+					],
+					data: Output::encode_bool(true),
+				}]
+			);
+
+			// isApprovedForAll(address,address) -> 0xe985e9c5
+			// owner
+			// operator
+			let input = hex! {"
+				e985e9c5
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &bob_context, false).unwrap();
+			assert_eq!(res.output, Output::encode_bool(true));
+
+			// transferFrom(address,address,uint256) -> 0x23b872dd
+			// from
+			// to
+			// token_id
+			let input = hex! {"
+				23b872dd
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, None, &alice_context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(orml_nft::Pallet::<Test>::tokens(0, 1).map(|t| t.owner), Some(alice()));
+		});
+	}
+
+	#[test]
+	fn static_call_rejects_mutations() {
+		new_test_ext().execute_with(|| {
+			mint_to_bob();
+			let context = Context {
+				address: mirrored_nft_address(0),
+				caller: bob_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// transferFrom(address,address,uint256) -> 0x23b872dd
+			// from
+			// to
+			// token_id
+			let input = hex! {"
+				23b872dd
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_noop!(
+				MirroredNFTPrecompile::execute(&input, Some(200_000), &context, true),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "not allowed in static call".into(),
+					cost: 180_000,
+				}
+			);
+
+			// setApprovalForAll(address,bool) -> 0xa22cb465
+			// operator
+			// approved
+			let input = hex! {"
+				a22cb465
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			assert_noop!(
+				MirroredNFTPrecompile::execute(&input, Some(200_000), &context, true),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "not allowed in static call".into(),
+					cost: 180_000,
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn safe_transfer_from_calls_receiver() {
+		new_test_ext().execute_with(|| {
+			mint_to_bob();
+			let context = Context {
+				address: mirrored_nft_address(0),
+				caller: bob_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// returns `onERC721Received.selector`
+			let receiver = H160::from(hex!("2000000000000000000000000000000000000001"));
+			module_evm::Pallet::<Test>::create_contract(
+				alice_evm_addr(),
+				receiver,
+				true,
+				hex!("63150b7a0260e01b60005260206000f3").to_vec(),
+			);
+			// returns nothing
+			let non_receiver = H160::from(hex!("2000000000000000000000000000000000000002"));
+			module_evm::Pallet::<Test>::create_contract(alice_evm_addr(), non_receiver, true, hex!("00").to_vec());
+
+			// safeTransferFrom(address,address,uint256,bytes) -> 0xb88d4fde
+			// from
+			// to
+			// token_id
+			// data offset
+			// data len
+			// data
+			let input = hex! {"
+				b88d4fde
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 2000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000080
+				00000000000000000000000000000000 00000000000000000000000000000002
+				abcd000000000000000000000000000000000000000000000000000000000000
+			"};
+			let res = MirroredNFTPrecompile::execute(&input, Some(1_000_000), &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				orml_nft::Pallet::<Test>::tokens(0, 0).map(|t| t.owner),
+				Some(<Test as module_evm::Config>::AddressMapping::get_account_id(&receiver))
+			);
+
+			// safeTransferFrom(address,address,uint256) -> 0x42842e0e
+			// from
+			// to
+			// token_id
+			let input = hex! {"
+				42842e0e
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 2000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			assert!(matches!(
+				MirroredNFTPrecompile::execute(&input, Some(1_000_000), &context, false),
+				Err(PrecompileFailure::Revert { output, .. }) if output == b"ERC721: transfer to non ERC721Receiver implementer".to_vec()
+			));
+		});
+	}
+}
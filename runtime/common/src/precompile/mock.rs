@@ -33,6 +33,7 @@ use frame_system::{offchain::SendTransactionTypes, EnsureRoot, EnsureSignedBy};
 use module_cdp_engine::CollateralCurrencyIds;
 use module_evm::{EvmChainId, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
+use module_nft::NftTask;
 use module_support::{
 	mocks::MockStableAsset, AddressMapping as AddressMappingT, AuctionManager, DEXIncentives, DispatchableTask,
 	EmergencyShutdown, ExchangeRate, ExchangeRateProvider, HomaSubAccountXcm, PoolId, PriceProvider, Rate,
@@ -138,8 +139,8 @@ impl orml_tokens::Config for Test {
 	type ExistentialDeposits = ExistentialDeposits;
 	type OnDust = ();
 	type MaxLocks = ();
-	type MaxReserves = ();
-	type ReserveIdentifier = [u8; 8];
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = ReserveIdentifier;
 	type DustRemovalWhitelist = Nothing;
 	type OnNewTokenAccount = ();
 	type OnKilledTokenAccount = ();
@@ -176,6 +177,7 @@ impl module_currencies::Config for Test {
 	type NativeCurrency = AdaptedBasicCurrency;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type Erc20HoldingAccount = Erc20HoldingAccount;
+	type TreasuryAccount = KaruraTreasuryAccount;
 	type WeightInfo = ();
 	type AddressMapping = EvmAddressMapping<Test>;
 	type EVMBridge = module_evm_bridge::EVMBridge<Test>;
@@ -201,6 +203,7 @@ define_combined_task! {
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 	pub enum ScheduledTasks {
 		EvmTask(EvmTask<Test>),
+		NftTask(NftTask<Test>),
 	}
 }
 
@@ -221,10 +224,18 @@ impl module_idle_scheduler::Config for Test {
 	type MinimumWeightRemainInBlock = ConstU64<0>;
 	type RelayChainBlockNumberProvider = MockBlockNumberProvider;
 	type DisableBlockThreshold = ConstU32<6>;
+	type MaxRetries = ConstU32<3>;
+	type RetryBackoff = ConstU32<10>;
+	type Currency = Balances;
+	type RecurringTaskDeposit = ConstU128<10>;
+	type RecurringTaskFilter = Nothing;
+	type MaxRecurringTasks = ConstU32<10>;
+	type MaxTaskHistory = ConstU32<10>;
 }
 
 parameter_types! {
 	pub const NftPalletId: PalletId = PalletId(*b"aca/aNFT");
+	pub const NftMaxRoyalty: Permill = Permill::from_percent(10);
 }
 impl module_nft::Config for Test {
 	type Event = Event;
@@ -234,6 +245,10 @@ impl module_nft::Config for Test {
 	type DataDepositPerByte = ConstU128<10>;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = ConstU32<2048>;
+	type MultiCurrency = Currencies;
+	type MaxRoyalty = NftMaxRoyalty;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = ();
 }
 
@@ -272,6 +287,8 @@ impl module_transaction_payment::Config for Test {
 	type TransactionByteFee = ConstU128<10>;
 	type FeeMultiplierUpdate = ();
 	type Swap = SpecificJointsSwap<DexModule, AlternativeSwapPathJointList>;
+	type DEX = DexModule;
+	type StableAsset = MockStableAsset<CurrencyId, Balance, AccountId, BlockNumber>;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Test>;
@@ -282,6 +299,11 @@ impl module_transaction_payment::Config for Test {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type AddressMapping = EvmAddressMapping<Test>;
+	type SponsoredTargetOf = crate::EvmSponsoredTargetOf<Test>;
+	type MaxSponsoredTargets = ConstU32<10>;
+	type DynamicBaseFee = ();
+	type OnDynamicBaseFee = ();
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -382,6 +404,8 @@ impl module_dex::Config for Test {
 	type ListingOrigin = EnsureSignedBy<ListingOrigin, AccountId>;
 	type ExtendedProvisioningBlocks = ConstU32<0>;
 	type OnLiquidityPoolUpdated = ();
+	type CircuitBreaker = ();
+	type CircuitBreakerExemptAccounts = Nothing;
 }
 
 parameter_types! {
@@ -573,6 +597,8 @@ impl module_evm::Config for Test {
 	type NewContractExtraBytes = ConstU32<100>;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = ConstU128<10>;
+	type BaseFeeTargetGas = ConstU64<10_000_000>;
+	type MinBaseFeePerGas = ConstU128<0>;
 	type Event = Event;
 	type PrecompilesType = AllPrecompiles<Self>;
 	type PrecompilesValue = PrecompilesValue;
@@ -584,10 +610,13 @@ impl module_evm::Config for Test {
 	type PublicationFee = ConstU128<200>;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureSignedBy<CouncilAccount, AccountId>;
+	type AbandonedContractTimeout = ConstU32<100>;
+	type CodeUpgradeDelay = ConstU32<10>;
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = ();
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type CallFilter = ();
 	type WeightInfo = ();
 }
 
@@ -597,6 +626,12 @@ impl module_evm_accounts::Config for Test {
 	type AddressMapping = EvmAddressMapping<Test>;
 	type ChainId = EvmChainId<Test>;
 	type TransferAll = ();
+	type AccountMigration = ();
+	type RecoveryOrigin = EnsureRoot<AccountId>;
+	type Call = Call;
+	type Currencies = Currencies;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type EvmCallTarget = crate::EvmCallTarget<Test>;
 	type WeightInfo = ();
 }
 
@@ -732,6 +767,10 @@ parameter_types! {
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
 }
 
+parameter_types! {
+	pub MaxBoost: Rate = Rate::one();
+}
+
 ord_parameter_types! {
 	pub const EarnShareBooster: Permill = Permill::from_percent(50);
 	pub const RewardsSource: AccountId = REWARDS_SOURCE;
@@ -750,6 +789,45 @@ impl module_incentives::Config for Test {
 	type DEX = DexModule;
 	type EmergencyShutdown = MockEmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type EpochPeriod = ConstU32<100>;
+	type MaxLockPeriod = ConstU32<1000>;
+	type MaxGaugeVotes = ConstU32<10>;
+	type MaxBoost = MaxBoost;
+	type MaxCampaigns = ConstU32<10>;
+	type MaxCampaignsPerPool = ConstU32<5>;
+	type MaxCampaignDuration = ConstU32<1000>;
+	type MinCampaignRewardPerBlock = ExistentialDeposits;
+	type CampaignDeposit = ConstU128<10>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const VaultPalletId: PalletId = PalletId(*b"aca/vaul");
+}
+
+impl module_vault::Config for Test {
+	type Event = Event;
+	type Currency = Currencies;
+	type Strategy = ();
+	type VaultShareRegistry = AssetRegistry;
+	type PalletId = VaultPalletId;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+parameter_type_with_key! {
+	pub MinVestedTransfer: |_currency_id: CurrencyId| -> Balance {
+		0
+	};
+}
+
+impl module_multi_vesting::Config for Test {
+	type Event = Event;
+	type Currency = Currencies;
+	type MinVestedTransfer = MinVestedTransfer;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type MaxVestingSchedules = ConstU32<100>;
+	type BlockNumberProvider = System;
 	type WeightInfo = ();
 }
 
@@ -829,6 +907,8 @@ frame_support::construct_runtime!(
 		Incentives: module_incentives,
 		Rewards: orml_rewards,
 		StableAsset: nutsfinance_stable_asset,
+		Vault: module_vault,
+		MultiVesting: module_multi_vesting,
 	}
 );
 
@@ -18,6 +18,8 @@
 
 //! The precompiles for EVM, includes standard Ethereum precompiles, and more:
 //! - MultiCurrency at address `H160::from_low_u64_be(1024)`.
+//! - MirroredNFT at `mirrored_nft_address(class_id)` for each NFT class, when NFT is active.
+//! - Vault at the address of each vault share token, when VAULT is active.
 
 #![allow(clippy::upper_case_acronyms)]
 
@@ -36,6 +38,7 @@ use module_evm::{
 	Context, ExitRevert,
 };
 use module_support::PrecompileCallerFilter as PrecompileCallerFilterT;
+use primitives::evm::{decode_mirrored_nft_address, decode_vault_share_address};
 use sp_core::H160;
 use sp_std::{collections::btree_set::BTreeSet, marker::PhantomData};
 
@@ -46,11 +49,15 @@ pub mod homa;
 pub mod honzon;
 pub mod incentives;
 pub mod input;
+pub mod mirrored_nft;
+pub mod multi_vesting;
 pub mod multicurrency;
 pub mod nft;
 pub mod oracle;
+pub mod paymaster;
 pub mod schedule;
 pub mod stable_asset;
+pub mod vault;
 
 use crate::SystemContractsFilter;
 pub use dex::DEXPrecompile;
@@ -59,11 +66,15 @@ pub use evm_accounts::EVMAccountsPrecompile;
 pub use homa::HomaPrecompile;
 pub use honzon::HonzonPrecompile;
 pub use incentives::IncentivesPrecompile;
+pub use mirrored_nft::MirroredNFTPrecompile;
+pub use multi_vesting::MultiVestingPrecompile;
 pub use multicurrency::MultiCurrencyPrecompile;
 pub use nft::NFTPrecompile;
 pub use oracle::OraclePrecompile;
+pub use paymaster::PaymasterPrecompile;
 pub use schedule::SchedulePrecompile;
 pub use stable_asset::StableAssetPrecompile;
+pub use vault::VaultPrecompile;
 
 pub const ECRECOVER: H160 = H160(hex!("0000000000000000000000000000000000000001"));
 pub const SHA256: H160 = H160(hex!("0000000000000000000000000000000000000002"));
@@ -92,6 +103,10 @@ pub const HOMA: H160 = H160(hex!("0000000000000000000000000000000000000407"));
 pub const EVM_ACCOUNTS: H160 = H160(hex!("0000000000000000000000000000000000000408"));
 pub const HONZON: H160 = H160(hex!("0000000000000000000000000000000000000409"));
 pub const INCENTIVES: H160 = H160(hex!("000000000000000000000000000000000000040a"));
+/// Activates the vault precompile at the vault share addresses, not callable itself.
+pub const VAULT: H160 = H160(hex!("000000000000000000000000000000000000040b"));
+pub const MULTI_VESTING: H160 = H160(hex!("000000000000000000000000000000000000040c"));
+pub const PAYMASTER: H160 = H160(hex!("000000000000000000000000000000000000040d"));
 
 pub fn target_gas_limit(target_gas: Option<u64>) -> Option<u64> {
 	target_gas.map(|x| x.saturating_div(10).saturating_mul(9)) // 90%
@@ -132,8 +147,11 @@ where
 				// STABLE_ASSET,
 				// HOMA,
 				EVM_ACCOUNTS,
+				PAYMASTER,
 				/* HONZON
-				 * INCENTIVES */
+				 * INCENTIVES
+				 * VAULT
+				 * MULTI_VESTING */
 			]),
 			_marker: Default::default(),
 		}
@@ -165,8 +183,11 @@ where
 				// STABLE_ASSET,
 				// HOMA,
 				EVM_ACCOUNTS,
+				PAYMASTER,
 				/* HONZON
-				 * INCENTIVES */
+				 * INCENTIVES
+				 * VAULT
+				 * MULTI_VESTING */
 			]),
 			_marker: Default::default(),
 		}
@@ -200,6 +221,9 @@ where
 				EVM_ACCOUNTS,
 				HONZON,
 				INCENTIVES,
+				VAULT,
+				MULTI_VESTING,
+				PAYMASTER,
 			]),
 			_marker: Default::default(),
 		}
@@ -211,6 +235,7 @@ where
 	R: module_evm::Config,
 	MultiCurrencyPrecompile<R>: Precompile,
 	NFTPrecompile<R>: Precompile,
+	MirroredNFTPrecompile<R>: Precompile,
 	EVMPrecompile<R>: Precompile,
 	EVMAccountsPrecompile<R>: Precompile,
 	OraclePrecompile<R>: Precompile,
@@ -220,6 +245,9 @@ where
 	HomaPrecompile<R>: Precompile,
 	HonzonPrecompile<R>: Precompile,
 	IncentivesPrecompile<R>: Precompile,
+	VaultPrecompile<R>: Precompile,
+	MultiVestingPrecompile<R>: Precompile,
+	PaymasterPrecompile<R>: Precompile,
 {
 	fn execute(
 		&self,
@@ -276,6 +304,20 @@ where
 		} else if address == SHA3_512 {
 			Some(Sha3FIPS512::execute(input, target_gas, context, is_static))
 		}
+		// Mirrored NFT classes are called by users directly
+		else if decode_mirrored_nft_address(address).is_some() {
+			Some(MirroredNFTPrecompile::<R>::execute(
+				input, target_gas, context, is_static,
+			))
+		}
+		// Vaults are called by users directly
+		else if decode_vault_share_address(address).is_some() {
+			Some(VaultPrecompile::<R>::execute(input, target_gas, context, is_static))
+		}
+		// Paymasters are managed by the contracts themselves
+		else if address == PAYMASTER {
+			Some(PaymasterPrecompile::<R>::execute(input, target_gas, context, is_static))
+		}
 		// Acala precompile
 		else {
 			if !SystemContractsFilter::is_allowed(context.caller) {
@@ -326,6 +368,10 @@ where
 				Some(IncentivesPrecompile::<R>::execute(
 					input, target_gas, context, is_static,
 				))
+			} else if address == MULTI_VESTING {
+				Some(MultiVestingPrecompile::<R>::execute(
+					input, target_gas, context, is_static,
+				))
 			} else {
 				None
 			}
@@ -339,7 +385,12 @@ where
 	}
 
 	fn is_precompile(&self, address: H160) -> bool {
-		self.active.contains(&address)
+		if self.active.contains(&address) {
+			return address != VAULT;
+		}
+
+		(self.active.contains(&NFT) && decode_mirrored_nft_address(address).is_some())
+			|| (self.active.contains(&VAULT) && decode_vault_share_address(address).is_some())
 	}
 }
 
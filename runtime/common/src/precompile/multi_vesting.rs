@@ -0,0 +1,327 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	input::{Input, InputPricer, InputT, Output},
+	target_gas_limit,
+};
+use crate::WeightToGas;
+use frame_support::{log, traits::Get};
+use module_evm::{
+	precompiles::Precompile,
+	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
+	Context, ExitError, ExitRevert, ExitSucceed,
+};
+use module_multi_vesting::WeightInfo;
+use module_support::{VestingManager, VestingSchedule};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use primitives::{Balance, CurrencyId};
+use sp_runtime::{traits::Convert, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The MultiVesting precompile
+///
+/// `input` data starts with `action`.
+///
+/// Actions:
+///  - VestedTransfer. Rest `input` bytes: `from`, `to`, `currency_id`, `start`, `cliff`, `period`,
+///    `period_count`, `per_period`.
+///  - Claim. Rest `input` bytes: `who`, `currency_id`.
+///  - LockedBalance. Rest `input` bytes: `who`, `currency_id`.
+pub struct MultiVestingPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	VestedTransfer = "vestedTransfer(address,address,address,uint32,uint32,uint32,uint32,uint256)",
+	Claim = "claim(address,address)",
+	LockedBalance = "lockedBalance(address,address)",
+}
+
+impl<Runtime> Precompile for MultiVestingPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_multi_vesting::Config + module_prices::Config,
+	module_multi_vesting::Pallet<Runtime>:
+		VestingManager<Runtime::AccountId, CurrencyId, Balance, <Runtime as frame_system::Config>::BlockNumber>,
+{
+	fn execute(input: &[u8], target_gas: Option<u64>, _context: &Context, _is_static: bool) -> PrecompileResult {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			input,
+			target_gas_limit(target_gas),
+		);
+
+		let gas_cost = Pricer::<Runtime>::cost(&input)?;
+
+		if let Some(gas_limit) = target_gas {
+			if gas_limit < gas_cost {
+				return Err(PrecompileFailure::Error {
+					exit_status: ExitError::OutOfGas,
+				});
+			}
+		}
+
+		let action = input.action()?;
+
+		let output = match action {
+			Action::VestedTransfer => {
+				let from = input.account_id_at(1)?;
+				let to = input.account_id_at(2)?;
+				let currency_id = input.currency_id_at(3)?;
+				let schedule = VestingSchedule {
+					start: input.u32_at(4)?.into(),
+					cliff: input.u32_at(5)?.into(),
+					period: input.u32_at(6)?.into(),
+					period_count: input.u32_at(7)?,
+					per_period: input.balance_at(8)?,
+				};
+
+				log::debug!(
+					target: "evm",
+					"multi_vesting: vested_transfer from: {:?}, to: {:?}, currency_id: {:?}, schedule: {:?}",
+					from, to, currency_id, schedule
+				);
+
+				<module_multi_vesting::Pallet<Runtime> as VestingManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Balance,
+					<Runtime as frame_system::Config>::BlockNumber,
+				>>::vested_transfer(&from, &to, currency_id, schedule)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Into::<&str>::into(e).as_bytes().to_vec(),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				vec![]
+			}
+			Action::Claim => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+
+				log::debug!(
+					target: "evm",
+					"multi_vesting: claim who: {:?}, currency_id: {:?}",
+					who, currency_id
+				);
+
+				let locked = <module_multi_vesting::Pallet<Runtime> as VestingManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Balance,
+					<Runtime as frame_system::Config>::BlockNumber,
+				>>::claim(&who, currency_id)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Into::<&str>::into(e).as_bytes().to_vec(),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				Output::encode_uint(locked)
+			}
+			Action::LockedBalance => {
+				let who = input.account_id_at(1)?;
+				let currency_id = input.currency_id_at(2)?;
+
+				let locked = <module_multi_vesting::Pallet<Runtime> as VestingManager<
+					Runtime::AccountId,
+					CurrencyId,
+					Balance,
+					<Runtime as frame_system::Config>::BlockNumber,
+				>>::locked_balance(&who, currency_id);
+
+				Output::encode_uint(locked)
+			}
+		};
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			cost: gas_cost,
+			output,
+			logs: Default::default(),
+		})
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_multi_vesting::Config + module_prices::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(
+		input: &Input<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>,
+	) -> Result<u64, PrecompileFailure> {
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::VestedTransfer => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				let weight = <Runtime as module_multi_vesting::Config>::WeightInfo::vested_transfer();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Claim => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_multi_vesting::Config>::WeightInfo::claim(
+					<Runtime as module_multi_vesting::Config>::MaxVestingSchedules::get(),
+				);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::LockedBalance => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// MultiVesting::VestingSchedules (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{alice, bob, new_test_ext, Currencies, Origin, System, Test, DOT};
+	use frame_support::{assert_noop, assert_ok};
+	use hex_literal::hex;
+	use orml_traits::MultiCurrency;
+
+	type MultiVestingPrecompile = super::MultiVestingPrecompile<Test>;
+
+	#[test]
+	fn vested_transfer_and_claim_works() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let context = Context {
+				address: Default::default(),
+				caller: Default::default(),
+				apparent_value: Default::default(),
+			};
+			assert_ok!(Currencies::update_balance(
+				Origin::root(),
+				alice(),
+				DOT,
+				1_000_000_000_000
+			));
+
+			// vestedTransfer(address,address,address,uint32,uint32,uint32,uint32,uint256) -> 0xa95b852e
+			// from
+			// to
+			// currency
+			// start
+			// cliff
+			// period
+			// period_count
+			// per_period
+			let input = hex! {"
+				a95b852e
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 0000000000000000000000000000000a
+				00000000000000000000000000000000 0000000000000000000000000000000a
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 0000000000000000000000003b9aca00
+			"};
+
+			let res = MultiVestingPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Currencies::free_balance(DOT, &bob()), 2_000_000_000);
+
+			// lockedBalance(address,address) -> 0x3419d6ef
+			// who
+			// currency
+			let input = hex! {"
+				3419d6ef
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			let res = MultiVestingPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint(2_000_000_000u128));
+
+			// claim(address,address) -> 0x21c0b342
+			// who
+			// currency
+			let input = hex! {"
+				21c0b342
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000002
+			"};
+
+			System::set_block_number(11);
+			let res = MultiVestingPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint(1_000_000_000u128));
+
+			System::set_block_number(21);
+			let res = MultiVestingPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint(0u128));
+			assert_ok!(Currencies::transfer(Origin::signed(bob()), alice(), DOT, 2_000_000_000));
+		});
+	}
+
+	#[test]
+	fn vested_transfer_fails_if_invalid_schedule() {
+		new_test_ext().execute_with(|| {
+			let context = Context {
+				address: Default::default(),
+				caller: Default::default(),
+				apparent_value: Default::default(),
+			};
+			assert_ok!(Currencies::update_balance(
+				Origin::root(),
+				alice(),
+				DOT,
+				1_000_000_000_000
+			));
+
+			// vestedTransfer(address,address,address,uint32,uint32,uint32,uint32,uint256) -> 0xa95b852e
+			// zero period
+			let input = hex! {"
+				a95b852e
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 0000000000000000000100000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 0000000000000000000000003b9aca00
+			"};
+
+			assert_noop!(
+				MultiVestingPrecompile::execute(&input, Some(200_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "ZeroVestingPeriod".into(),
+					cost: target_gas_limit(Some(200_000)).unwrap(),
+				}
+			);
+		});
+	}
+}
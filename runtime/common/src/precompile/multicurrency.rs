@@ -258,16 +258,19 @@ where
 
 	fn dex_share_read_cost(share: DexShare) -> u64 {
 		match share {
-			DexShare::Erc20(_) | DexShare::ForeignAsset(_) => WeightToGas::convert(Runtime::DbWeight::get().reads(1)),
+			DexShare::Erc20(_) | DexShare::ForeignAsset(_) | DexShare::VaultShare(_) => {
+				WeightToGas::convert(Runtime::DbWeight::get().reads(1))
+			}
 			_ => Self::BASE_COST,
 		}
 	}
 
 	fn erc20_info(currency_id: CurrencyId) -> u64 {
 		match currency_id {
-			CurrencyId::Erc20(_) | CurrencyId::StableAssetPoolToken(_) | CurrencyId::ForeignAsset(_) => {
-				WeightToGas::convert(Runtime::DbWeight::get().reads(1))
-			}
+			CurrencyId::Erc20(_)
+			| CurrencyId::StableAssetPoolToken(_)
+			| CurrencyId::ForeignAsset(_)
+			| CurrencyId::VaultShare(_) => WeightToGas::convert(Runtime::DbWeight::get().reads(1)),
 			CurrencyId::DexShare(symbol_0, symbol_1) => {
 				Self::dex_share_read_cost(symbol_0).saturating_add(Self::dex_share_read_cost(symbol_1))
 			}
@@ -17,24 +17,32 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{
-	input::{Input, InputT, Output},
+	input::{Input, InputPricer, InputT, Output},
 	target_gas_limit,
 };
+use crate::WeightToGas;
 use frame_support::{
 	log,
-	traits::tokens::nonfungibles::{Inspect, Transfer},
+	traits::{
+		tokens::nonfungibles::{Inspect, Transfer},
+		Get,
+	},
 };
 use module_evm::{
 	precompiles::Precompile,
 	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
 	Context, ExitError, ExitRevert, ExitSucceed,
 };
+use module_nft::{ListingKind, WeightInfo};
 use module_support::AddressMapping;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use orml_traits::InspectExtended;
 use primitives::nft::NFTBalance;
 use sp_core::H160;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{
+	traits::{Convert, Saturating},
+	DispatchError, RuntimeDebug,
+};
 use sp_std::{marker::PhantomData, prelude::*};
 
 /// The `NFT` impl precompile.
@@ -45,6 +53,19 @@ use sp_std::{marker::PhantomData, prelude::*};
 /// - Query balance. Rest `input` bytes: `account_id`.
 /// - Query owner. Rest `input` bytes: `class_id`, `token_id`.
 /// - Transfer. Rest `input`bytes: `from`, `to`, `class_id`, `token_id`.
+/// - Approve. Rest `input` bytes: `who`, `operator`, `class_id`, `token_id`.
+/// - Query approved. Rest `input` bytes: `class_id`, `token_id`.
+/// - Set approval for all. Rest `input` bytes: `who`, `class_id`, `operator`, `approved`.
+/// - Query approved for all. Rest `input` bytes: `owner`, `class_id`, `operator`.
+/// - Transfer from. Rest `input` bytes: `who`, `from`, `to`, `class_id`, `token_id`.
+/// - Query royalty info. Rest `input` bytes: `class_id`, `sale_price`.
+/// - List at fixed price. Rest `input` bytes: `who`, `class_id`, `token_id`, `currency_id`, `price`.
+/// - List in auction. Rest `input` bytes: `who`, `class_id`, `token_id`, `currency_id`, `min_bid`,
+///   `duration`.
+/// - Cancel listing. Rest `input` bytes: `who`, `class_id`, `token_id`.
+/// - Buy. Rest `input` bytes: `who`, `class_id`, `token_id`, `max_price`.
+/// - Bid. Rest `input` bytes: `who`, `class_id`, `token_id`, `amount`.
+/// - Settle auction. Rest `input` bytes: `who`, `class_id`, `token_id`.
 pub struct NFTPrecompile<R>(PhantomData<R>);
 
 #[module_evm_utility_macro::generate_function_selector]
@@ -54,11 +75,26 @@ pub enum Action {
 	QueryBalance = "balanceOf(address)",
 	QueryOwner = "ownerOf(uint256,uint256)",
 	Transfer = "transfer(address,address,uint256,uint256)",
+	Approve = "approve(address,address,uint256,uint256)",
+	GetApproved = "getApproved(uint256,uint256)",
+	SetApprovalForAll = "setApprovalForAll(address,uint256,address,bool)",
+	IsApprovedForAll = "isApprovedForAll(address,uint256,address)",
+	TransferFrom = "transferFrom(address,address,address,uint256,uint256)",
+	RoyaltyInfo = "royaltyInfo(uint256,uint256)",
+	ListFixedPrice = "listFixedPrice(address,uint256,uint256,address,uint256)",
+	ListAuction = "listAuction(address,uint256,uint256,address,uint256,uint32)",
+	CancelListing = "cancelListing(address,uint256,uint256)",
+	Buy = "buy(address,uint256,uint256,uint256)",
+	Bid = "bid(address,uint256,uint256,uint256)",
+	SettleAuction = "settleAuction(address,uint256,uint256)",
 }
 
 impl<Runtime> Precompile for NFTPrecompile<Runtime>
 where
-	Runtime: module_evm::Config + module_prices::Config + module_nft::Config,
+	Runtime: module_evm::Config
+		+ module_prices::Config
+		+ module_nft::Config
+		+ orml_nft::Config<ClassId = u32, TokenId = u64>,
 	module_nft::Pallet<Runtime>: InspectExtended<Runtime::AccountId, Balance = NFTBalance>
 		+ Inspect<Runtime::AccountId, ItemId = u64, CollectionId = u32>
 		+ Transfer<Runtime::AccountId>,
@@ -102,12 +138,9 @@ where
 
 				log::debug!(target: "evm", "nft: query_owner class_id: {:?}, token_id: {:?}", class_id, token_id);
 
-				let owner: H160 = if let Some(o) = module_nft::Pallet::<Runtime>::owner(&class_id, &token_id) {
-					Runtime::AddressMapping::get_evm_address(&o)
-						.unwrap_or_else(|| Runtime::AddressMapping::get_default_evm_address(&o))
-				} else {
-					Default::default()
-				};
+				let owner: H160 = module_nft::Pallet::<Runtime>::owner(&class_id, &token_id)
+					.map(|o| evm_address_of::<Runtime>(&o))
+					.unwrap_or_default();
 
 				Ok(PrecompileOutput {
 					exit_status: ExitSucceed::Returned,
@@ -139,10 +172,206 @@ where
 					logs: Default::default(),
 				})
 			}
+			Action::Approve => {
+				let who = input.account_id_at(1)?;
+				let operator = input.evm_address_at(2)?;
+				let class_id = input.u32_at(3)?;
+				let token_id = input.u64_at(4)?;
+
+				log::debug!(target: "evm", "nft: approve who: {:?}, operator: {:?}, class_id: {:?}, token_id: {:?}", who, operator, class_id, token_id);
+
+				// approve the zero address to clear the approval
+				let operator = if operator.is_zero() {
+					None
+				} else {
+					Some(Runtime::AddressMapping::get_account_id(&operator))
+				};
+				module_nft::Pallet::<Runtime>::do_approve(&who, operator, (class_id, token_id))
+					.map_err(|e| revert(e, target_gas))?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::GetApproved => {
+				let class_id = input.u32_at(1)?;
+				let token_id = input.u64_at(2)?;
+
+				log::debug!(target: "evm", "nft: get_approved class_id: {:?}, token_id: {:?}", class_id, token_id);
+
+				let approved: H160 = module_nft::Pallet::<Runtime>::token_approvals(class_id, token_id)
+					.map(|a| evm_address_of::<Runtime>(&a))
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_address(approved),
+					logs: Default::default(),
+				})
+			}
+			Action::SetApprovalForAll => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let operator = input.account_id_at(3)?;
+				let approved = input.bool_at(4)?;
+
+				log::debug!(target: "evm", "nft: set_approval_for_all who: {:?}, class_id: {:?}, operator: {:?}, approved: {:?}", who, class_id, operator, approved);
+
+				module_nft::Pallet::<Runtime>::do_set_approval_for_all(&who, class_id, &operator, approved)
+					.map_err(|e| revert(e, target_gas))?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::IsApprovedForAll => {
+				let owner = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let operator = input.account_id_at(3)?;
+
+				log::debug!(target: "evm", "nft: is_approved_for_all owner: {:?}, class_id: {:?}, operator: {:?}", owner, class_id, operator);
+
+				let approved = module_nft::Pallet::<Runtime>::operator_approvals((&owner, class_id), &operator);
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: Output::encode_bool(approved),
+					logs: Default::default(),
+				})
+			}
+			Action::TransferFrom => {
+				let who = input.account_id_at(1)?;
+				let from = input.account_id_at(2)?;
+				let to = input.account_id_at(3)?;
+				let class_id = input.u32_at(4)?;
+				let token_id = input.u64_at(5)?;
+
+				log::debug!(target: "evm", "nft: transfer_from who: {:?}, from: {:?}, to: {:?}, class_id: {:?}, token_id: {:?}", who, from, to, class_id, token_id);
+
+				module_nft::Pallet::<Runtime>::do_transfer_from(&who, &from, &to, (class_id, token_id))
+					.map_err(|e| revert(e, target_gas))?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::RoyaltyInfo => {
+				let class_id = input.u32_at(1)?;
+				let sale_price = input.balance_at(2)?;
+
+				log::debug!(target: "evm", "nft: royalty_info class_id: {:?}, sale_price: {:?}", class_id, sale_price);
+
+				let (beneficiary, royalty) = module_nft::Pallet::<Runtime>::royalty_info(class_id, sale_price)
+					.map(|(beneficiary, royalty)| (evm_address_of::<Runtime>(&beneficiary), royalty))
+					.unwrap_or_default();
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: [Output::encode_address(beneficiary), Output::encode_uint(royalty)].concat(),
+					logs: Default::default(),
+				})
+			}
+			Action::ListFixedPrice | Action::ListAuction => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let token_id = input.u64_at(3)?;
+				let currency_id = input.currency_id_at(4)?;
+				let price = input.balance_at(5)?;
+
+				log::debug!(target: "evm", "nft: {:?} who: {:?}, class_id: {:?}, token_id: {:?}, currency_id: {:?}, price: {:?}", action, who, class_id, token_id, currency_id, price);
+
+				let kind = if action == Action::ListFixedPrice {
+					ListingKind::FixedPrice { price }
+				} else {
+					let duration = input.u32_at(6)?;
+					ListingKind::Auction {
+						min_bid: price,
+						end: frame_system::Pallet::<Runtime>::block_number().saturating_add(duration.into()),
+						best_bid: None,
+					}
+				};
+				module_nft::Pallet::<Runtime>::do_list(&who, (class_id, token_id), currency_id, kind)
+					.map_err(|e| revert(e, target_gas))?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::CancelListing | Action::SettleAuction => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let token_id = input.u64_at(3)?;
+
+				log::debug!(target: "evm", "nft: {:?} who: {:?}, class_id: {:?}, token_id: {:?}", action, who, class_id, token_id);
+
+				if action == Action::CancelListing {
+					module_nft::Pallet::<Runtime>::do_cancel_listing(&who, (class_id, token_id))
+				} else {
+					module_nft::Pallet::<Runtime>::do_settle_auction((class_id, token_id))
+				}
+				.map_err(|e| revert(e, target_gas))?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
+			Action::Buy | Action::Bid => {
+				let who = input.account_id_at(1)?;
+				let class_id = input.u32_at(2)?;
+				let token_id = input.u64_at(3)?;
+				let amount = input.balance_at(4)?;
+
+				log::debug!(target: "evm", "nft: {:?} who: {:?}, class_id: {:?}, token_id: {:?}, amount: {:?}", action, who, class_id, token_id, amount);
+
+				if action == Action::Buy {
+					module_nft::Pallet::<Runtime>::do_buy(&who, (class_id, token_id), amount)
+				} else {
+					module_nft::Pallet::<Runtime>::do_bid(&who, (class_id, token_id), amount)
+				}
+				.map_err(|e| revert(e, target_gas))?;
+
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					cost: gas_cost,
+					output: vec![],
+					logs: Default::default(),
+				})
+			}
 		}
 	}
 }
 
+fn evm_address_of<Runtime: module_evm::Config>(who: &Runtime::AccountId) -> H160 {
+	Runtime::AddressMapping::get_evm_address(who)
+		.unwrap_or_else(|| Runtime::AddressMapping::get_default_evm_address(who))
+}
+
+fn revert(e: DispatchError, target_gas: Option<u64>) -> PrecompileFailure {
+	PrecompileFailure::Revert {
+		exit_status: ExitRevert::Reverted,
+		output: Into::<&str>::into(e).as_bytes().to_vec(),
+		cost: target_gas_limit(target_gas).unwrap_or_default(),
+	}
+}
+
 pub struct Pricer<R>(PhantomData<R>);
 
 impl<Runtime> Pricer<Runtime>
@@ -154,8 +383,303 @@ where
 	fn cost(
 		input: &Input<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>,
 	) -> Result<u64, PrecompileFailure> {
-		let _action = input.action()?;
-		// TODO: gas cost
-		Ok(Self::BASE_COST)
+		let action = input.action()?;
+
+		let cost = match action {
+			// TODO: gas cost
+			Action::QueryBalance | Action::QueryOwner | Action::Transfer => return Ok(Self::BASE_COST),
+			Action::GetApproved => {
+				// NFT::TokenApprovals (r: 1)
+				// EvmAccounts::EvmAddresses (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(2))
+			}
+			Action::IsApprovedForAll => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				// NFT::OperatorApprovals (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::RoyaltyInfo => {
+				// NFT::ClassRoyalties (r: 1)
+				// EvmAccounts::EvmAddresses (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(2))
+			}
+			Action::Approve => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::approve();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::SetApprovalForAll => {
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::set_approval_for_all();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::TransferFrom => {
+				let cost = InputPricer::<Runtime>::read_accounts(3);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::transfer_from();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::ListFixedPrice => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::list_fixed_price();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::ListAuction => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::list_auction();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::CancelListing => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::cancel_listing();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Buy => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::buy();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Bid => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::bid();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::SettleAuction => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_nft::Config>::WeightInfo::settle_auction();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{
+		alice, alice_evm_addr, bob, new_test_ext, Balances, Currencies, NFTModule, Origin, System, Test, AUSD,
+	};
+	use frame_support::{assert_noop, assert_ok, traits::Currency};
+	use hex_literal::hex;
+	use orml_traits::MultiCurrency;
+	use primitives::nft::{ClassProperty, Properties};
+	use sp_runtime::{traits::AccountIdConversion, Permill};
+
+	type NFTPrecompile = super::NFTPrecompile<Test>;
+
+	fn mint_to_bob() {
+		assert_ok!(NFTModule::create_class(
+			Origin::signed(alice()),
+			vec![1],
+			Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+			Default::default(),
+		));
+		let class_account = <Test as module_nft::Config>::PalletId::get().into_sub_account_truncating(0u32);
+		Balances::make_free_balance_be(&class_account, 1_000);
+		assert_ok!(NFTModule::mint(
+			Origin::signed(class_account),
+			bob(),
+			0,
+			vec![1],
+			Default::default(),
+			1
+		));
+	}
+
+	#[test]
+	fn approve_and_transfer_from_works() {
+		new_test_ext().execute_with(|| {
+			mint_to_bob();
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// transferFrom(address,address,address,uint256,uint256) -> 0x10f02113
+			// who
+			// from
+			// to
+			// class_id
+			// token_id
+			let transfer_from = hex! {"
+				10f02113
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			assert_noop!(
+				NFTPrecompile::execute(&transfer_from, Some(200_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "NoPermission".into(),
+					cost: 200_000,
+				}
+			);
+
+			// approve(address,address,uint256,uint256) -> 0x4dc5ecb3
+			// who
+			// operator
+			// class_id
+			// token_id
+			let input = hex! {"
+				4dc5ecb3
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			let res = NFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+
+			// getApproved(uint256,uint256) -> 0x5b8ef74e
+			// class_id
+			// token_id
+			let get_approved = hex! {"
+				5b8ef74e
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+			"};
+			let res = NFTPrecompile::execute(&get_approved, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_address(alice_evm_addr()));
+
+			let res = NFTPrecompile::execute(&transfer_from, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(NFTModule::owner(&0, &0), Some(alice()));
+
+			let res = NFTPrecompile::execute(&get_approved, None, &context, false).unwrap();
+			assert_eq!(res.output, Output::encode_address(H160::zero()));
+		});
+	}
+
+	#[test]
+	fn set_approval_for_all_works() {
+		new_test_ext().execute_with(|| {
+			mint_to_bob();
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// setApprovalForAll(address,uint256,address,bool) -> 0xce5e58a1
+			// who
+			// class_id
+			// operator
+			// approved
+			let input = hex! {"
+				ce5e58a1
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000001
+			"};
+			let res = NFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+
+			// isApprovedForAll(address,uint256,address) -> 0x73fc1a0f
+			// owner
+			// class_id
+			// operator
+			let input = hex! {"
+				73fc1a0f
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			let res = NFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_bool(true));
+		});
+	}
+
+	#[test]
+	fn royalty_and_fixed_price_listing_works() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			mint_to_bob();
+			let class_account = <Test as module_nft::Config>::PalletId::get().into_sub_account_truncating(0u32);
+			assert_ok!(NFTModule::set_royalty(
+				Origin::signed(class_account),
+				0,
+				Some(module_nft::RoyaltyInfo {
+					beneficiary: alice(),
+					rate: Permill::from_percent(10),
+				})
+			));
+			let context = Context {
+				address: Default::default(),
+				caller: alice_evm_addr(),
+				apparent_value: Default::default(),
+			};
+
+			// royaltyInfo(uint256,uint256) -> 0x2a55205a
+			// class_id
+			// sale_price
+			let input = hex! {"
+				2a55205a
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 000000000000000000000000000003e8
+			"};
+			let res = NFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				res.output,
+				[Output::encode_address(alice_evm_addr()), Output::encode_uint(100u128)].concat()
+			);
+
+			// listFixedPrice(address,uint256,uint256,address,uint256) -> 0x9defa2d0
+			// who
+			// class_id
+			// token_id
+			// currency_id
+			// price
+			let input = hex! {"
+				9defa2d0
+				000000000000000000000000 1000000000000000000000000000000000000002
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+				000000000000000000000000 0000000000000000000100000000000000000001
+				00000000000000000000000000000000 000000000000000000000000000003e8
+			"};
+			let res = NFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+
+			let alice_ausd = Currencies::free_balance(AUSD, &alice());
+
+			// buy(address,uint256,uint256,uint256) -> 0x1622dbe4
+			// who
+			// class_id
+			// token_id
+			// max_price
+			let input = hex! {"
+				1622dbe4
+				000000000000000000000000 1000000000000000000000000000000000000001
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 000000000000000000000000000003e8
+			"};
+			let res = NFTPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(NFTModule::owner(&0, &0), Some(alice()));
+			// alice pays 1000 and receives the royalty of 100
+			assert_eq!(Currencies::free_balance(AUSD, &alice()), alice_ausd - 900);
+			assert_eq!(Currencies::free_balance(AUSD, &bob()), 900);
+		});
 	}
 }
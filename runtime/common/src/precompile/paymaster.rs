@@ -0,0 +1,344 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	input::{Input, InputPricer, InputT, Output},
+	target_gas_limit,
+};
+use crate::WeightToGas;
+use frame_support::{log, traits::Get};
+use module_evm::{
+	precompiles::Precompile,
+	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
+	Context, ExitError, ExitRevert, ExitSucceed,
+};
+use module_support::AddressMapping;
+use module_transaction_payment::{PaymasterInfo, SponsoredTarget, WeightInfo};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sp_runtime::{
+	traits::{Convert, UniqueSaturatedInto},
+	RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The Paymaster precompile
+///
+/// `input` data starts with `action`. The calling contract registers, updates and removes the
+/// paymaster sponsoring the calls to itself.
+///
+/// Actions:
+///  - RegisterPaymaster. Rest `input` bytes: `budget`, `max_txs_per_period`, `period`, `expire_at`.
+///  - UpdatePaymaster. Rest `input` bytes: `budget`, `max_txs_per_period`, `period`, `expire_at`.
+///  - RemovePaymaster.
+///  - GetPaymaster. Rest `input` bytes: `contract`.
+pub struct PaymasterPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	RegisterPaymaster = "registerPaymaster(uint256,uint32,uint32,uint32)",
+	UpdatePaymaster = "updatePaymaster(uint256,uint32,uint32,uint32)",
+	RemovePaymaster = "removePaymaster()",
+	GetPaymaster = "getPaymaster(address)",
+}
+
+impl<Runtime> Precompile for PaymasterPrecompile<Runtime>
+where
+	Runtime: module_evm::Config + module_transaction_payment::Config + module_prices::Config,
+{
+	fn execute(input: &[u8], target_gas: Option<u64>, context: &Context, _is_static: bool) -> PrecompileResult {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			input,
+			target_gas_limit(target_gas),
+		);
+
+		let gas_cost = Pricer::<Runtime>::cost(&input)?;
+
+		if let Some(gas_limit) = target_gas {
+			if gas_limit < gas_cost {
+				return Err(PrecompileFailure::Error {
+					exit_status: ExitError::OutOfGas,
+				});
+			}
+		}
+
+		let action = input.action()?;
+
+		let output = match action {
+			Action::RegisterPaymaster => {
+				let contract = context.caller;
+				let who = Runtime::AddressMapping::get_account_id(&contract);
+				let info = PaymasterInfo {
+					budget: input.balance_at(1)?,
+					max_txs_per_period: input.u32_at(2)?,
+					period: input.u32_at(3)?.into(),
+					expire_at: input.u32_at(4)?.into(),
+				};
+
+				log::debug!(
+					target: "evm",
+					"paymaster: register_paymaster contract: {:?}, info: {:?}",
+					contract, info
+				);
+
+				module_transaction_payment::Pallet::<Runtime>::do_register_paymaster(
+					&who,
+					vec![SponsoredTarget::Contract(contract)],
+					info,
+				)
+				.map_err(|e| PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: Into::<&str>::into(e).as_bytes().to_vec(),
+					cost: target_gas_limit(target_gas).unwrap_or_default(),
+				})?;
+
+				vec![]
+			}
+			Action::UpdatePaymaster => {
+				let who = Runtime::AddressMapping::get_account_id(&context.caller);
+				let info = PaymasterInfo {
+					budget: input.balance_at(1)?,
+					max_txs_per_period: input.u32_at(2)?,
+					period: input.u32_at(3)?.into(),
+					expire_at: input.u32_at(4)?.into(),
+				};
+
+				log::debug!(
+					target: "evm",
+					"paymaster: update_paymaster who: {:?}, info: {:?}",
+					who, info
+				);
+
+				module_transaction_payment::Pallet::<Runtime>::do_update_paymaster(&who, info).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Into::<&str>::into(e).as_bytes().to_vec(),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					}
+				})?;
+
+				vec![]
+			}
+			Action::RemovePaymaster => {
+				let who = Runtime::AddressMapping::get_account_id(&context.caller);
+
+				log::debug!(
+					target: "evm",
+					"paymaster: remove_paymaster who: {:?}",
+					who
+				);
+
+				module_transaction_payment::Pallet::<Runtime>::do_remove_paymaster(&who).map_err(|e| {
+					PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: Into::<&str>::into(e).as_bytes().to_vec(),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					}
+				})?;
+
+				vec![]
+			}
+			Action::GetPaymaster => {
+				let who = input.account_id_at(1)?;
+
+				let (budget, max_txs_per_period, period, expire_at): (u128, u32, u128, u128) =
+					module_transaction_payment::Pallet::<Runtime>::paymasters(&who)
+						.map(|info| {
+							(
+								info.budget,
+								info.max_txs_per_period,
+								info.period.unique_saturated_into(),
+								info.expire_at.unique_saturated_into(),
+							)
+						})
+						.unwrap_or_default();
+
+				Output::encode_uint_tuple::<u128>(vec![budget, max_txs_per_period.into(), period, expire_at])
+			}
+		};
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			cost: gas_cost,
+			output,
+			logs: Default::default(),
+		})
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_evm::Config + module_transaction_payment::Config + module_prices::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(
+		input: &Input<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>,
+	) -> Result<u64, PrecompileFailure> {
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::RegisterPaymaster => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_transaction_payment::Config>::WeightInfo::register_paymaster(1);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::UpdatePaymaster => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_transaction_payment::Config>::WeightInfo::update_paymaster();
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::RemovePaymaster => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				let weight = <Runtime as module_transaction_payment::Config>::WeightInfo::remove_paymaster(1);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::GetPaymaster => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// TransactionPayment::Paymasters (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{new_test_ext, System, Test, TransactionPayment};
+	use frame_support::assert_noop;
+	use hex_literal::hex;
+	use module_support::AddressMapping;
+	use sp_core::H160;
+
+	type PaymasterPrecompile = super::PaymasterPrecompile<Test>;
+
+	#[test]
+	fn register_update_and_remove_paymaster_works() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			let contract = H160::from(hex!("2000000000000000000000000000000000000001"));
+			let paymaster = <Test as module_evm::Config>::AddressMapping::get_account_id(&contract);
+			// the contract calls the precompile for itself.
+			let context = Context {
+				address: Default::default(),
+				caller: contract,
+				apparent_value: Default::default(),
+			};
+
+			// registerPaymaster(uint256,uint32,uint32,uint32) -> 0xf46ffa56
+			// budget
+			// max_txs_per_period
+			// period
+			// expire_at
+			let input = hex! {"
+				f46ffa56
+				00000000000000000000000000000000 000000000000000000000000000f4240
+				00000000000000000000000000000000 00000000000000000000000000000002
+				00000000000000000000000000000000 0000000000000000000000000000000a
+				00000000000000000000000000000000 00000000000000000000000000000064
+			"};
+
+			let res = PaymasterPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(
+				TransactionPayment::paymasters(&paymaster),
+				Some(PaymasterInfo {
+					budget: 1_000_000,
+					max_txs_per_period: 2,
+					period: 10,
+					expire_at: 100,
+				})
+			);
+			assert_eq!(
+				TransactionPayment::sponsored_targets(SponsoredTarget::Contract(contract)),
+				Some(paymaster.clone())
+			);
+
+			assert_noop!(
+				PaymasterPrecompile::execute(&input, Some(200_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "PaymasterAlreadyExisted".into(),
+					cost: target_gas_limit(Some(200_000)).unwrap(),
+				}
+			);
+
+			// updatePaymaster(uint256,uint32,uint32,uint32) -> 0xd4edf7a2
+			// budget
+			// max_txs_per_period
+			// period
+			// expire_at
+			let input = hex! {"
+				d4edf7a2
+				00000000000000000000000000000000 000000000000000000000000000186a0
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 00000000000000000000000000000000
+				00000000000000000000000000000000 000000000000000000000000000000c8
+			"};
+
+			let res = PaymasterPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+
+			// getPaymaster(address) -> 0x168bc90f
+			// contract
+			let get_input = hex! {"
+				168bc90f
+				000000000000000000000000 2000000000000000000000000000000000000001
+			"};
+
+			let res = PaymasterPrecompile::execute(&get_input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint_tuple::<u128>(vec![100_000, 0, 0, 200]));
+
+			// removePaymaster() -> 0x3b4ea73d
+			let input = hex! {"
+				3b4ea73d
+			"};
+
+			let res = PaymasterPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(TransactionPayment::paymasters(&paymaster), None);
+			assert_eq!(
+				TransactionPayment::sponsored_targets(SponsoredTarget::Contract(contract)),
+				None
+			);
+
+			let res = PaymasterPrecompile::execute(&get_input, None, &context, false).unwrap();
+			assert_eq!(res.output, Output::encode_uint_tuple::<u128>(vec![0, 0, 0, 0]));
+
+			assert_noop!(
+				PaymasterPrecompile::execute(&input, Some(200_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "PaymasterNotFound".into(),
+					cost: target_gas_limit(Some(200_000)).unwrap(),
+				}
+			);
+		});
+	}
+}
@@ -0,0 +1,685 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{
+	input::{Input, InputPricer, InputT, Output},
+	target_gas_limit,
+};
+use crate::WeightToGas;
+use frame_support::{log, traits::Get};
+use hex_literal::hex;
+use module_currencies::WeightInfo as _;
+use module_evm::{
+	precompiles::Precompile,
+	runner::state::{PrecompileFailure, PrecompileOutput, PrecompileResult},
+	Context, ExitError, ExitRevert, ExitSucceed, Log,
+};
+use module_support::{AddressMapping, Erc20InfoMapping, VaultManager};
+use module_vault::WeightInfo;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use orml_traits::MultiCurrency as MultiCurrencyT;
+use primitives::{currency::VaultId, evm::decode_vault_share_address, Balance, CurrencyId};
+use sp_core::H256;
+use sp_runtime::{traits::Convert, DispatchError, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// Deposit(address,address,uint256,uint256)
+pub const DEPOSIT_EVENT_TOPIC: H256 = H256(hex!("dcbc1c05240f31ff3ad067ef1ee35ce4997762752e3a095284754544f4c709d7"));
+/// Withdraw(address,address,address,uint256,uint256)
+pub const WITHDRAW_EVENT_TOPIC: H256 = H256(hex!("fbde797d201c681b91056529119e0b02407c7bb96a4a2c75c01fc9667232c8db"));
+
+/// The Vault precompile, the ERC-4626 interface of the vaults.
+///
+/// Every vault is served at the address of its share token, the vault is decoded from the called
+/// address and `msg.sender` is the caller. Share allowances are not supported, so `owner` of
+/// Withdraw and Redeem must be the caller.
+///
+/// Actions:
+///  - TotalSupply. Total issuance of the share token.
+///  - BalanceOf. Rest `input` bytes: `owner`.
+///  - Asset.
+///  - TotalAssets.
+///  - ConvertToShares. Rest `input` bytes: `assets`.
+///  - ConvertToAssets. Rest `input` bytes: `shares`.
+///  - MaxDeposit. Rest `input` bytes: `receiver`.
+///  - MaxMint. Rest `input` bytes: `receiver`.
+///  - MaxWithdraw. Rest `input` bytes: `owner`.
+///  - MaxRedeem. Rest `input` bytes: `owner`.
+///  - PreviewDeposit. Rest `input` bytes: `assets`.
+///  - PreviewMint. Rest `input` bytes: `shares`.
+///  - PreviewWithdraw. Rest `input` bytes: `assets`.
+///  - PreviewRedeem. Rest `input` bytes: `shares`.
+///  - Deposit. Rest `input` bytes: `assets`, `receiver`.
+///  - Mint. Rest `input` bytes: `shares`, `receiver`.
+///  - Withdraw. Rest `input` bytes: `assets`, `receiver`, `owner`.
+///  - Redeem. Rest `input` bytes: `shares`, `receiver`, `owner`.
+pub struct VaultPrecompile<R>(PhantomData<R>);
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	TotalSupply = "totalSupply()",
+	BalanceOf = "balanceOf(address)",
+	Asset = "asset()",
+	TotalAssets = "totalAssets()",
+	ConvertToShares = "convertToShares(uint256)",
+	ConvertToAssets = "convertToAssets(uint256)",
+	MaxDeposit = "maxDeposit(address)",
+	MaxMint = "maxMint(address)",
+	MaxWithdraw = "maxWithdraw(address)",
+	MaxRedeem = "maxRedeem(address)",
+	PreviewDeposit = "previewDeposit(uint256)",
+	PreviewMint = "previewMint(uint256)",
+	PreviewWithdraw = "previewWithdraw(uint256)",
+	PreviewRedeem = "previewRedeem(uint256)",
+	Deposit = "deposit(uint256,address)",
+	Mint = "mint(uint256,address)",
+	Withdraw = "withdraw(uint256,address,address)",
+	Redeem = "redeem(uint256,address,address)",
+}
+
+type VaultOf<Runtime> = module_vault::Pallet<Runtime>;
+type CurrenciesOf<Runtime> = module_currencies::Pallet<Runtime>;
+
+impl<Runtime> Precompile for VaultPrecompile<Runtime>
+where
+	Runtime: module_currencies::Config + module_evm::Config + module_vault::Config + module_prices::Config,
+	VaultOf<Runtime>: VaultManager<Runtime::AccountId, CurrencyId, Balance>,
+	CurrenciesOf<Runtime>: MultiCurrencyT<Runtime::AccountId, CurrencyId = CurrencyId, Balance = Balance>,
+{
+	fn execute(input: &[u8], target_gas: Option<u64>, context: &Context, is_static: bool) -> PrecompileResult {
+		let input = Input::<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>::new(
+			input,
+			target_gas_limit(target_gas),
+		);
+
+		let vault_id = decode_vault_share_address(context.address)
+			.filter(|vault_id| VaultOf::<Runtime>::asset(*vault_id).is_some())
+			.ok_or_else(|| PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: "invalid vault".into(),
+				cost: target_gas_limit(target_gas).unwrap_or_default(),
+			})?;
+
+		let gas_cost = Pricer::<Runtime>::cost(&input)?;
+
+		if let Some(gas_limit) = target_gas {
+			if gas_limit < gas_cost {
+				return Err(PrecompileFailure::Error {
+					exit_status: ExitError::OutOfGas,
+				});
+			}
+		}
+
+		let action = input.action()?;
+		let caller = Runtime::AddressMapping::get_account_id(&context.caller);
+
+		if is_static
+			&& matches!(
+				action,
+				Action::Deposit | Action::Mint | Action::Withdraw | Action::Redeem
+			) {
+			return Err(PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: "not allowed in static call".into(),
+				cost: target_gas_limit(target_gas).unwrap_or_default(),
+			});
+		}
+
+		let share = CurrencyId::VaultShare(vault_id);
+		let mut logs = vec![];
+		let output = match action {
+			Action::TotalSupply => {
+				let value = CurrenciesOf::<Runtime>::total_issuance(share);
+				Output::encode_uint(value)
+			}
+			Action::BalanceOf => {
+				let owner = input.account_id_at(1)?;
+				let value = CurrenciesOf::<Runtime>::total_balance(share, &owner);
+				Output::encode_uint(value)
+			}
+			Action::Asset => {
+				let address = VaultOf::<Runtime>::asset(vault_id)
+					.and_then(<Runtime as module_prices::Config>::Erc20InfoMapping::encode_evm_address)
+					.unwrap_or_default();
+				Output::encode_address(address)
+			}
+			Action::TotalAssets => {
+				let value = VaultOf::<Runtime>::total_assets(vault_id);
+				Output::encode_uint(value)
+			}
+			Action::ConvertToShares => {
+				let assets = input.balance_at(1)?;
+				let value = VaultOf::<Runtime>::convert_to_shares(vault_id, assets);
+				Output::encode_uint(value)
+			}
+			Action::ConvertToAssets => {
+				let shares = input.balance_at(1)?;
+				let value = VaultOf::<Runtime>::convert_to_assets(vault_id, shares);
+				Output::encode_uint(value)
+			}
+			Action::MaxDeposit => {
+				let receiver = input.account_id_at(1)?;
+				let value = VaultOf::<Runtime>::max_deposit(vault_id, &receiver);
+				Output::encode_uint(value)
+			}
+			Action::MaxMint => {
+				let receiver = input.account_id_at(1)?;
+				let value = VaultOf::<Runtime>::max_mint(vault_id, &receiver);
+				Output::encode_uint(value)
+			}
+			Action::MaxWithdraw => {
+				let owner = input.account_id_at(1)?;
+				let value = VaultOf::<Runtime>::max_withdraw(vault_id, &owner);
+				Output::encode_uint(value)
+			}
+			Action::MaxRedeem => {
+				let owner = input.account_id_at(1)?;
+				let value = VaultOf::<Runtime>::max_redeem(vault_id, &owner);
+				Output::encode_uint(value)
+			}
+			Action::PreviewDeposit => {
+				let assets = input.balance_at(1)?;
+				let value = VaultOf::<Runtime>::preview_deposit(vault_id, assets);
+				Output::encode_uint(value)
+			}
+			Action::PreviewMint => {
+				let shares = input.balance_at(1)?;
+				let value = VaultOf::<Runtime>::preview_mint(vault_id, shares);
+				Output::encode_uint(value)
+			}
+			Action::PreviewWithdraw => {
+				let assets = input.balance_at(1)?;
+				let value = VaultOf::<Runtime>::preview_withdraw(vault_id, assets);
+				Output::encode_uint(value)
+			}
+			Action::PreviewRedeem => {
+				let shares = input.balance_at(1)?;
+				let value = VaultOf::<Runtime>::preview_redeem(vault_id, shares);
+				Output::encode_uint(value)
+			}
+			Action::Deposit | Action::Mint => {
+				let amount = input.balance_at(1)?;
+				let receiver = input.evm_address_at(2)?;
+
+				log::debug!(
+					target: "evm",
+					"vault: {:?}, vault_id: {:?}, caller: {:?}, receiver: {:?}, amount: {:?}",
+					action, vault_id, &caller, receiver, amount
+				);
+
+				let (assets, shares) = if action == Action::Deposit {
+					let shares = <VaultOf<Runtime> as VaultManager<_, _, _>>::deposit(&caller, vault_id, amount)
+						.map_err(|e| revert(e, target_gas))?;
+					(amount, shares)
+				} else {
+					let assets = <VaultOf<Runtime> as VaultManager<_, _, _>>::mint(&caller, vault_id, amount)
+						.map_err(|e| revert(e, target_gas))?;
+					(assets, amount)
+				};
+
+				let receiver_account = Runtime::AddressMapping::get_account_id(&receiver);
+				if receiver_account != caller {
+					<CurrenciesOf<Runtime> as MultiCurrencyT<_>>::transfer(share, &caller, &receiver_account, shares)
+						.map_err(|e| revert(e, target_gas))?;
+				}
+
+				logs.push(Log {
+					address: context.address,
+					topics: vec![DEPOSIT_EVENT_TOPIC, H256::from(context.caller), H256::from(receiver)],
+					data: Output::encode_uint_tuple(vec![assets, shares]),
+				});
+
+				Output::encode_uint(if action == Action::Deposit { shares } else { assets })
+			}
+			Action::Withdraw | Action::Redeem => {
+				let amount = input.balance_at(1)?;
+				let receiver = input.evm_address_at(2)?;
+				let owner = input.evm_address_at(3)?;
+
+				log::debug!(
+					target: "evm",
+					"vault: {:?}, vault_id: {:?}, caller: {:?}, receiver: {:?}, owner: {:?}, amount: {:?}",
+					action, vault_id, &caller, receiver, owner, amount
+				);
+
+				if owner != context.caller {
+					return Err(PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "owner is not the caller".into(),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					});
+				}
+
+				let (assets, shares) = if action == Action::Withdraw {
+					let shares = <VaultOf<Runtime> as VaultManager<_, _, _>>::withdraw(&caller, vault_id, amount)
+						.map_err(|e| revert(e, target_gas))?;
+					(amount, shares)
+				} else {
+					let assets = <VaultOf<Runtime> as VaultManager<_, _, _>>::redeem(&caller, vault_id, amount)
+						.map_err(|e| revert(e, target_gas))?;
+					(assets, amount)
+				};
+
+				let receiver_account = Runtime::AddressMapping::get_account_id(&receiver);
+				if receiver_account != caller {
+					let asset = VaultOf::<Runtime>::asset(vault_id).ok_or_else(|| PrecompileFailure::Revert {
+						exit_status: ExitRevert::Reverted,
+						output: "invalid vault".into(),
+						cost: target_gas_limit(target_gas).unwrap_or_default(),
+					})?;
+					<CurrenciesOf<Runtime> as MultiCurrencyT<_>>::transfer(asset, &caller, &receiver_account, assets)
+						.map_err(|e| revert(e, target_gas))?;
+				}
+
+				logs.push(Log {
+					address: context.address,
+					topics: vec![
+						WITHDRAW_EVENT_TOPIC,
+						H256::from(context.caller),
+						H256::from(receiver),
+						H256::from(owner),
+					],
+					data: Output::encode_uint_tuple(vec![assets, shares]),
+				});
+
+				Output::encode_uint(if action == Action::Withdraw { shares } else { assets })
+			}
+		};
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			cost: gas_cost,
+			output,
+			logs,
+		})
+	}
+}
+
+fn revert(e: DispatchError, target_gas: Option<u64>) -> PrecompileFailure {
+	PrecompileFailure::Revert {
+		exit_status: ExitRevert::Reverted,
+		output: Into::<&str>::into(e).as_bytes().to_vec(),
+		cost: target_gas_limit(target_gas).unwrap_or_default(),
+	}
+}
+
+struct Pricer<R>(PhantomData<R>);
+
+impl<Runtime> Pricer<Runtime>
+where
+	Runtime: module_currencies::Config + module_evm::Config + module_vault::Config + module_prices::Config,
+{
+	const BASE_COST: u64 = 200;
+
+	fn cost(
+		input: &Input<Action, Runtime::AccountId, Runtime::AddressMapping, Runtime::Erc20InfoMapping>,
+	) -> Result<u64, PrecompileFailure> {
+		let action = input.action()?;
+
+		let cost: u64 = match action {
+			Action::TotalSupply => {
+				// Vault::Vaults (r: 1)
+				// Tokens::TotalIssuance (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(2))
+			}
+			Action::BalanceOf => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// Vault::Vaults (r: 1)
+				// Tokens::Accounts (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(2);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Asset => {
+				// Vault::Vaults (r: 1)
+				// AssetRegistry::Erc20IdToAddress (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(2))
+			}
+			Action::TotalAssets
+			| Action::ConvertToShares
+			| Action::ConvertToAssets
+			| Action::PreviewDeposit
+			| Action::PreviewMint
+			| Action::PreviewWithdraw
+			| Action::PreviewRedeem => {
+				// Vault::Vaults (r: 1)
+				// Tokens::Accounts (r: 1)
+				// Tokens::TotalIssuance (r: 1)
+				// Strategy invested assets (r: 1)
+				WeightToGas::convert(<Runtime as frame_system::Config>::DbWeight::get().reads(4))
+			}
+			Action::MaxDeposit | Action::MaxMint | Action::MaxWithdraw | Action::MaxRedeem => {
+				let cost = InputPricer::<Runtime>::read_accounts(1);
+				// Vault::Vaults (r: 1)
+				// Tokens::Accounts (r: 2)
+				// Tokens::TotalIssuance (r: 1)
+				// Strategy invested assets (r: 1)
+				let weight = <Runtime as frame_system::Config>::DbWeight::get().reads(5);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Deposit | Action::Mint => {
+				// caller and receiver
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				let weight = if action == Action::Deposit {
+					<Runtime as module_vault::Config>::WeightInfo::deposit()
+				} else {
+					<Runtime as module_vault::Config>::WeightInfo::mint()
+				};
+				// transfer the shares to the receiver
+				let weight = weight
+					.saturating_add(<Runtime as module_currencies::Config>::WeightInfo::transfer_non_native_currency());
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+			Action::Withdraw | Action::Redeem => {
+				// caller and receiver, owner is the caller
+				let cost = InputPricer::<Runtime>::read_accounts(2);
+				let weight = if action == Action::Withdraw {
+					<Runtime as module_vault::Config>::WeightInfo::withdraw()
+				} else {
+					<Runtime as module_vault::Config>::WeightInfo::redeem()
+				};
+				// transfer the assets to the receiver
+				let weight = weight.saturating_add(
+					<Runtime as module_currencies::Config>::WeightInfo::transfer_native_currency()
+						.max(<Runtime as module_currencies::Config>::WeightInfo::transfer_non_native_currency()),
+				);
+
+				cost.saturating_add(WeightToGas::convert(weight))
+			}
+		};
+		Ok(Self::BASE_COST.saturating_add(cost))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::precompile::mock::{
+		alice, alice_evm_addr, bob, bob_evm_addr, new_test_ext, Currencies, Origin, Test, Vault, DOT,
+	};
+	use frame_support::{assert_noop, assert_ok};
+	use hex_literal::hex;
+	use primitives::{currency::AssetMetadata, evm::EvmAddress};
+
+	type VaultPrecompile = super::VaultPrecompile<Test>;
+
+	fn create_vault() {
+		assert_ok!(Vault::create_vault(
+			Origin::root(),
+			DOT,
+			None,
+			Box::new(AssetMetadata {
+				name: b"Vault DOT".to_vec(),
+				symbol: b"vDOT".to_vec(),
+				decimals: 10,
+				minimal_balance: 1,
+			})
+		));
+		assert_ok!(Currencies::update_balance(
+			Origin::root(),
+			alice(),
+			DOT,
+			1_000_000_000_000
+		));
+	}
+
+	fn vault_context() -> Context {
+		Context {
+			address: EvmAddress::try_from(CurrencyId::VaultShare(0)).unwrap(),
+			caller: alice_evm_addr(),
+			apparent_value: Default::default(),
+		}
+	}
+
+	#[test]
+	fn asset_works() {
+		new_test_ext().execute_with(|| {
+			create_vault();
+			let context = vault_context();
+
+			// asset() -> 0x38d52e0f
+			let input = hex! {"38d52e0f"};
+
+			let res = VaultPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_address(EvmAddress::try_from(DOT).unwrap()));
+
+			// vault not exists
+			let context = Context {
+				address: EvmAddress::try_from(CurrencyId::VaultShare(1)).unwrap(),
+				..vault_context()
+			};
+			assert_noop!(
+				VaultPrecompile::execute(&input, Some(100_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid vault".into(),
+					cost: target_gas_limit(Some(100_000)).unwrap(),
+				}
+			);
+
+			// not a vault share
+			let context = Context {
+				address: EvmAddress::try_from(DOT).unwrap(),
+				..vault_context()
+			};
+			assert_noop!(
+				VaultPrecompile::execute(&input, Some(100_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "invalid vault".into(),
+					cost: target_gas_limit(Some(100_000)).unwrap(),
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn deposit_and_redeem_works() {
+		new_test_ext().execute_with(|| {
+			create_vault();
+			let context = vault_context();
+
+			// deposit(uint256,address) -> 0x6e553f65
+			// assets
+			// receiver
+			let input = hex! {"
+				6e553f65
+				00000000000000000000000000000000 0000000000000000000000003b9aca00
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			let res = VaultPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint(1_000_000_000u128));
+			assert_eq!(
+				res.logs,
+				vec![Log {
+					address: context.address,
+					topics: vec![
+						DEPOSIT_EVENT_TOPIC,
+						H256::from(alice_evm_addr()),
+						H256::from(alice_evm_addr()),
+					],
+					data: Output::encode_uint_tuple(vec![1_000_000_000u128, 1_000_000_000u128]),
+				}]
+			);
+
+			// totalAssets() -> 0x01e1d114
+			let input = hex! {"01e1d114"};
+
+			let res = VaultPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint(1_000_000_000u128));
+
+			// totalSupply() -> 0x18160ddd
+			let input = hex! {"18160ddd"};
+
+			let res = VaultPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint(1_000_000_000u128));
+
+			// maxRedeem(address) -> 0xd905777e
+			// owner
+			let input = hex! {"
+				d905777e
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			let res = VaultPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint(1_000_000_000u128));
+
+			// previewRedeem(uint256) -> 0x4cdad506
+			// shares
+			let input = hex! {"
+				4cdad506
+				00000000000000000000000000000000 0000000000000000000000001dcd6500
+			"};
+
+			let res = VaultPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint(500_000_000u128));
+
+			// redeem(uint256,address,address) -> 0xba087652
+			// shares
+			// receiver
+			// owner
+			let input = hex! {"
+				ba087652
+				00000000000000000000000000000000 0000000000000000000000001dcd6500
+				000000000000000000000000 1000000000000000000000000000000000000002
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			let dot_balance = Currencies::free_balance(DOT, &bob());
+			let res = VaultPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint(500_000_000u128));
+			assert_eq!(
+				res.logs,
+				vec![Log {
+					address: context.address,
+					topics: vec![
+						WITHDRAW_EVENT_TOPIC,
+						H256::from(alice_evm_addr()),
+						H256::from(bob_evm_addr()),
+						H256::from(alice_evm_addr()),
+					],
+					data: Output::encode_uint_tuple(vec![500_000_000u128, 500_000_000u128]),
+				}]
+			);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::VaultShare(0), &alice()),
+				500_000_000
+			);
+			assert_eq!(Currencies::free_balance(DOT, &bob()), dot_balance + 500_000_000);
+
+			// balanceOf(address) -> 0x70a08231
+			// owner
+			let input = hex! {"
+				70a08231
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+
+			let res = VaultPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint(500_000_000u128));
+		});
+	}
+
+	#[test]
+	fn deposit_to_receiver_works() {
+		new_test_ext().execute_with(|| {
+			create_vault();
+			let context = vault_context();
+
+			// deposit(uint256,address) -> 0x6e553f65
+			// assets
+			// receiver
+			let input = hex! {"
+				6e553f65
+				00000000000000000000000000000000 0000000000000000000000003b9aca00
+				000000000000000000000000 1000000000000000000000000000000000000002
+			"};
+
+			let res = VaultPrecompile::execute(&input, None, &context, false).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(Currencies::free_balance(CurrencyId::VaultShare(0), &alice()), 0);
+			assert_eq!(
+				Currencies::free_balance(CurrencyId::VaultShare(0), &bob()),
+				1_000_000_000
+			);
+
+			// withdraw(uint256,address,address) -> 0xb460af94
+			// assets
+			// receiver
+			// owner is not the caller
+			let input = hex! {"
+				b460af94
+				00000000000000000000000000000000 0000000000000000000000003b9aca00
+				000000000000000000000000 1000000000000000000000000000000000000001
+				000000000000000000000000 1000000000000000000000000000000000000002
+			"};
+			assert_noop!(
+				VaultPrecompile::execute(&input, Some(10_000_000), &context, false),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "owner is not the caller".into(),
+					cost: target_gas_limit(Some(10_000_000)).unwrap(),
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn static_call_rejects_mutations() {
+		new_test_ext().execute_with(|| {
+			create_vault();
+			let context = vault_context();
+
+			// deposit(uint256,address) -> 0x6e553f65
+			// assets
+			// receiver
+			let input = hex! {"
+				6e553f65
+				00000000000000000000000000000000 0000000000000000000000003b9aca00
+				000000000000000000000000 1000000000000000000000000000000000000001
+			"};
+			assert_noop!(
+				VaultPrecompile::execute(&input, Some(10_000_000), &context, true),
+				PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "not allowed in static call".into(),
+					cost: target_gas_limit(Some(10_000_000)).unwrap(),
+				}
+			);
+
+			// totalAssets() -> 0x01e1d114
+			let input = hex! {"01e1d114"};
+
+			let res = VaultPrecompile::execute(&input, None, &context, true).unwrap();
+			assert_eq!(res.exit_status, ExitSucceed::Returned);
+			assert_eq!(res.output, Output::encode_uint(0u128));
+		});
+	}
+}
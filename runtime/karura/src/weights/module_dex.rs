@@ -62,6 +62,14 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: Dex CircuitBreakers (r:0 w:1)
+	// Storage: Dex ReferencePrices (r:0 w:1)
+	fn set_circuit_breaker() -> Weight {
+		(24_113_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
 	// Storage: Dex TradingPairStatuses (r:1 w:1)
 	// Storage: Tokens TotalIssuance (r:1 w:0)
 	// Storage: Dex ProvisioningPool (r:1 w:0)
@@ -161,7 +169,10 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
 	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	// Storage: TransactionPause TrippedCircuitBreakers (r:1 w:0)
 	// Storage: Dex LiquidityPool (r:1 w:1)
+	// Storage: Dex CircuitBreakers (r:1 w:0)
+	// Storage: Dex ReferencePrices (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Tokens Accounts (r:2 w:2)
 	fn swap_with_exact_supply(u: u32, ) -> Weight {
@@ -169,13 +180,16 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			// Standard Error: 124_000
 			.saturating_add((10_028_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
 	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	// Storage: TransactionPause TrippedCircuitBreakers (r:1 w:0)
 	// Storage: Dex LiquidityPool (r:1 w:1)
+	// Storage: Dex CircuitBreakers (r:1 w:0)
+	// Storage: Dex ReferencePrices (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Tokens Accounts (r:2 w:2)
 	fn swap_with_exact_target(u: u32, ) -> Weight {
@@ -183,9 +197,9 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			// Standard Error: 486_000
 			.saturating_add((7_425_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
 	// Storage: Dex TradingPairStatuses (r:1 w:0)
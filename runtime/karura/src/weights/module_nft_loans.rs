@@ -0,0 +1,137 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for module_nft_loans
+//!
+//! These are placeholder weights, they were not generated by the benchmark CLI. Replace them
+//! with the results of the `module_nft_loans` benchmarks.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_nft_loans.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_nft_loans::WeightInfo for WeightInfo<T> {
+	// Storage: NFTLoans CollateralParams (r:1 w:0)
+	// Storage: NFTLoans TotalPositions (r:1 w:0)
+	// Storage: NFTLoans DebitExchangeRate (r:1 w:1)
+	// Storage: CdpTreasury DebitPool (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: NFTLoans LastAccumulationSecs (r:0 w:1)
+	fn on_initialize(c: u32, ) -> Weight {
+		(18_000_000 as Weight)
+			.saturating_add((24_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: NFTLoans TotalPositions (r:1 w:0)
+	// Storage: NFTLoans CollateralParams (r:0 w:1)
+	fn set_collateral_params() -> Weight {
+		(19_385_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: NFTLoans FloorPrices (r:0 w:1)
+	fn set_floor_price() -> Weight {
+		(16_021_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: NFTLoans CollateralParams (r:1 w:0)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Storage: NFTLoans Positions (r:1 w:1)
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Storage: NFT Listings (r:1 w:0)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NFTLoans TotalPositions (r:1 w:1)
+	// Storage: NFT TokenApprovals (r:0 w:1)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:2)
+	// Storage: NFTLoans Collaterals (r:0 w:1)
+	fn deposit_nft() -> Weight {
+		(71_224_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: NFTLoans Collaterals (r:1 w:1)
+	// Storage: NFTLoans Positions (r:1 w:1)
+	// Storage: NFTLoans TotalPositions (r:1 w:1)
+	// Storage: NFTLoans CollateralParams (r:1 w:0)
+	// Storage: NFTLoans FloorPrices (r:1 w:0)
+	// Storage: OrmlNFT Classes (r:1 w:0)
+	// Storage: OrmlNFT Tokens (r:1 w:1)
+	// Storage: NFT Listings (r:1 w:0)
+	// Storage: Balances Reserves (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	// Storage: NFT TokenApprovals (r:0 w:1)
+	// Storage: OrmlNFT TokensByOwner (r:0 w:2)
+	fn withdraw_nft() -> Weight {
+		(78_450_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: NFTLoans CollateralParams (r:1 w:0)
+	// Storage: NFTLoans Positions (r:1 w:1)
+	// Storage: NFTLoans TotalPositions (r:1 w:1)
+	// Storage: NFTLoans FloorPrices (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: CdpTreasury DebitPool (r:1 w:0)
+	fn adjust_debit() -> Weight {
+		(58_993_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: NFTLoans Positions (r:1 w:1)
+	// Storage: NFTLoans CollateralParams (r:1 w:0)
+	// Storage: NFTLoans FloorPrices (r:1 w:0)
+	// Storage: CdpTreasury DebitPool (r:1 w:1)
+	// Storage: NFTLoans TotalPositions (r:1 w:1)
+	// Storage: NFTLoans Collaterals (r:1 w:1)
+	// Storage: AuctionManager TotalTargetInNFTAuction (r:1 w:1)
+	// Storage: Auction AuctionsIndex (r:1 w:1)
+	// Storage: Auction Auctions (r:0 w:1)
+	// Storage: Auction AuctionEndTime (r:0 w:1)
+	// Storage: AuctionManager NFTAuctions (r:0 w:1)
+	fn liquidate(n: u32, ) -> Weight {
+		(60_117_000 as Weight)
+			.saturating_add((21_464_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: OrmlNFT Tokens (r:1 w:0)
+	// Storage: AuctionManager TotalTargetInNFTAuction (r:1 w:1)
+	// Storage: Auction AuctionsIndex (r:1 w:1)
+	// Storage: Auction Auctions (r:0 w:1)
+	// Storage: Auction AuctionEndTime (r:0 w:1)
+	// Storage: AuctionManager NFTAuctions (r:0 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reauction_nft() -> Weight {
+		(35_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+}
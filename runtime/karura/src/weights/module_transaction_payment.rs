@@ -95,6 +95,16 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Dex TradingPairStatuses (r:2 w:0)
+	// Storage: Dex LiquidityPool (r:2 w:2)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Storage: System Account (r:2 w:2)
+	fn with_fee_currency_liquidity_token() -> Weight {
+		(78_034_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
 	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
 	fn with_fee_aggregated_path() -> Weight {
 		(7_104_000 as Weight)
@@ -114,4 +124,34 @@ impl<T: frame_system::Config> module_transaction_payment::WeightInfo for WeightI
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: TransactionPayment Paymasters (r:1 w:1)
+	// Storage: TransactionPayment SponsoredTargets (r:1 w:1)
+	// Storage: TransactionPayment PaymasterTargets (r:0 w:1)
+	fn register_paymaster(n: u32, ) -> Weight {
+		(19_873_000 as Weight)
+			// Standard Error: 85_000
+			.saturating_add((3_412_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
+	// Storage: TransactionPayment Paymasters (r:1 w:1)
+	fn update_paymaster() -> Weight {
+		(14_268_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: TransactionPayment Paymasters (r:1 w:1)
+	// Storage: TransactionPayment PaymasterTargets (r:1 w:1)
+	// Storage: TransactionPayment PaymasterUsage (r:0 w:1)
+	// Storage: TransactionPayment SponsoredTargets (r:0 w:1)
+	fn remove_paymaster(n: u32, ) -> Weight {
+		(21_734_000 as Weight)
+			// Standard Error: 74_000
+			.saturating_add((2_987_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
+	}
 }
@@ -0,0 +1,103 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Weights for module_vault
+//!
+//! These are placeholder weights, they were not generated by the benchmark CLI. Replace them
+//! with the results of the `module_vault` benchmarks.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for module_vault.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> module_vault::WeightInfo for WeightInfo<T> {
+	// Storage: Vault NextVaultId (r:1 w:1)
+	// Storage: AssetRegistry AssetMetadatas (r:1 w:1)
+	// Storage: Vault Vaults (r:0 w:1)
+	fn create_vault() -> Weight {
+		(32_118_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Vault Vaults (r:1 w:1)
+	fn update_vault() -> Weight {
+		(18_905_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	fn deposit() -> Weight {
+		(98_214_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	fn mint() -> Weight {
+		(99_530_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	fn withdraw() -> Weight {
+		(101_268_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	fn redeem() -> Weight {
+		(100_847_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:8 w:8)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Storage: Incentives PendingMultiRewards (r:1 w:1)
+	// Storage: Incentives ClaimRewardDeductionRates (r:1 w:0)
+	// Storage: Incentives IncentiveRewardAmounts (r:2 w:0)
+	// Storage: Dex TradingPairStatuses (r:2 w:0)
+	// Storage: Dex LiquidityPool (r:2 w:2)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	fn harvest() -> Weight {
+		(254_317_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(20 as Weight))
+			.saturating_add(T::DbWeight::get().writes(14 as Weight))
+	}
+}
@@ -20,12 +20,16 @@ use super::utils::{dollar, inject_liquidity, LIQUID, NATIVE, STABLECOIN, STAKING
 use crate::{AccountId, Currencies, CurrencyId, Dex, Event, ExtendedProvisioningBlocks, Runtime, System};
 use frame_benchmarking::{account, whitelisted_caller};
 use frame_system::RawOrigin;
-use module_dex::TradingPairStatus;
+use module_dex::{CircuitBreakerParameters, TradingPairStatus};
+use module_support::Ratio;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 use primitives::TradingPair;
 use runtime_common::{BNC, RENBTC, VSKSM};
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::{
+	traits::{One, UniqueSaturatedInto},
+	FixedPointNumber,
+};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -61,6 +65,15 @@ runtime_benchmarks! {
 		assert_last_event(module_dex::Event::DisableTradingPair{trading_pair}.into());
 	}
 
+	// set the circuit breaker of a trading pair
+	set_circuit_breaker {
+		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
+		let parameters = CircuitBreakerParameters { threshold: Ratio::saturating_from_rational(20, 100), window: 10 };
+	}: _(RawOrigin::Root, trading_pair.first(), trading_pair.second(), Some(parameters))
+	verify {
+		assert_last_event(module_dex::Event::CircuitBreakerUpdated{trading_pair, parameters: Some(parameters)}.into());
+	}
+
 	// list a Provisioning trading pair
 	list_provisioning {
 		let trading_pair = TradingPair::from_currency_ids(STABLECOIN, NATIVE).unwrap();
@@ -254,6 +267,10 @@ runtime_benchmarks! {
 			}
 		}
 
+		for i in 0 .. path.len() - 1 {
+			Dex::set_circuit_breaker(RawOrigin::Root.into(), path[i], path[i + 1], Some(CircuitBreakerParameters { threshold: Ratio::one(), window: 10 }))?;
+		}
+
 		<Currencies as MultiCurrencyExtended<_>>::update_balance(path[0], &taker, (10_000 * dollar(path[0])).unique_saturated_into())?;
 	}: swap_with_exact_supply(RawOrigin::Signed(taker.clone()), path.clone(), 100 * dollar(path[0]), 0)
 	verify {
@@ -282,6 +299,10 @@ runtime_benchmarks! {
 			}
 		}
 
+		for i in 0 .. path.len() - 1 {
+			Dex::set_circuit_breaker(RawOrigin::Root.into(), path[i], path[i + 1], Some(CircuitBreakerParameters { threshold: Ratio::one(), window: 10 }))?;
+		}
+
 		<Currencies as MultiCurrencyExtended<_>>::update_balance(path[0], &taker, (10_000 * dollar(path[0])).unique_saturated_into())?;
 	}: swap_with_exact_target(RawOrigin::Signed(taker.clone()), path.clone(), 10 * dollar(path[path.len() - 1]), 100 * dollar(path[0]))
 	verify {
@@ -16,10 +16,10 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, Event, EvmAccounts, Origin, Runtime, System, EVM};
+use crate::{AbandonedContractTimeout, AccountId, Event, EvmAccounts, Origin, Runtime, System, EVM};
 
 use super::utils::{dollar, set_balance, NATIVE};
-use frame_support::dispatch::DispatchError;
+use frame_support::{dispatch::DispatchError, traits::Get};
 use frame_system::RawOrigin;
 use module_evm::MaxCodeSize;
 use module_support::AddressMapping;
@@ -244,6 +244,14 @@ runtime_benchmarks! {
 
 		whitelist_account!(alice_account);
 	}: _(RawOrigin::Signed(alice_account_id()), contract)
+
+	remove_abandoned_contract {
+		let alice_account = alice_account_id();
+
+		set_balance(NATIVE, &alice_account, 1_000_000 * dollar(NATIVE));
+		let contract = deploy_contract(alice_account_id())?;
+		System::set_block_number(1 + AbandonedContractTimeout::get());
+	}: _(RawOrigin::Root, contract)
 }
 
 #[cfg(test)]
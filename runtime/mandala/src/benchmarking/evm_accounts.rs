@@ -16,13 +16,17 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, EvmAccounts, Runtime};
+use crate::{AccountId, Call, CurrencyId, EvmAccounts, Runtime, System};
 
 use super::utils::{dollar, set_balance, NATIVE};
 use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::PalletInfoAccess;
 use frame_system::RawOrigin;
+use module_evm_accounts::{EvmAddressMapping, MaxSessionTrackedCurrencies, SessionKeyScope};
+use module_support::AddressMapping;
 use orml_benchmarking::runtime_benchmarks;
 use sp_io::hashing::keccak_256;
+use sp_std::prelude::*;
 
 const SEED: u32 = 0;
 
@@ -54,6 +58,56 @@ runtime_benchmarks! {
 	claim_default_account {
 		let caller = whitelisted_caller();
 	}: _(RawOrigin::Signed(caller))
+
+	relink_account {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &bob_account_id(), 1_000 * dollar(NATIVE));
+		let old_address = EvmAccounts::eth_address(&alice());
+		let new_address = EvmAccounts::eth_address(&bob());
+		EvmAccounts::claim_account(RawOrigin::Signed(caller.clone()).into(), old_address, EvmAccounts::eth_sign(&alice(), &caller))?;
+		let old_signature = EvmAccounts::eth_sign_relink(&alice(), &caller, &old_address, &new_address, 100u32);
+		let new_signature = EvmAccounts::eth_sign_relink(&bob(), &caller, &old_address, &new_address, 100u32);
+	}: _(RawOrigin::Signed(caller), new_address, 100u32, Some(old_signature), new_signature)
+
+	unlink_account {
+		let caller: AccountId = whitelisted_caller();
+		let address = EvmAccounts::eth_address(&alice());
+		EvmAccounts::claim_account(RawOrigin::Signed(caller.clone()).into(), address, EvmAccounts::eth_sign(&alice(), &caller))?;
+		let signature = EvmAccounts::eth_sign_unlink(&alice(), &caller, &address, 100u32);
+	}: _(RawOrigin::Signed(caller), 100u32, Some(signature))
+
+	force_unlink_account {
+		let who: AccountId = account("who", 0, SEED);
+		EvmAccounts::claim_default_account(RawOrigin::Signed(who.clone()).into())?;
+	}: _(RawOrigin::Root, who)
+
+	add_session_key {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+	}: _(RawOrigin::Signed(caller), EvmAccounts::eth_address(&alice()), SessionKeyScope::default(), 100u32)
+
+	remove_session_key {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+		EvmAccounts::add_session_key(RawOrigin::Signed(caller.clone()).into(), EvmAccounts::eth_address(&alice()), SessionKeyScope::default(), 100u32)?;
+	}: _(RawOrigin::Signed(caller), EvmAccounts::eth_address(&alice()))
+
+	session_call {
+		// the native currency is always tracked
+		let c in 1 .. MaxSessionTrackedCurrencies::get();
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 1_000 * dollar(NATIVE));
+		for i in 1..c {
+			set_balance(CurrencyId::LiquidCrowdloan(i), &caller, 1_000 * dollar(NATIVE));
+		}
+		let session_key = EvmAccounts::eth_address(&alice());
+		let scope = SessionKeyScope {
+			pallets: vec![<System as PalletInfoAccess>::index() as u8].try_into().unwrap(),
+			..Default::default()
+		};
+		EvmAccounts::add_session_key(RawOrigin::Signed(caller.clone()).into(), session_key, scope, 100u32)?;
+		let call = Box::new(Call::System(frame_system::Call::remark { remark: vec![] }));
+	}: _(RawOrigin::Signed(EvmAddressMapping::<Runtime>::get_account_id(&session_key)), caller, call)
 }
 
 #[cfg(test)]
@@ -19,7 +19,7 @@
 use crate::{EvmTask, IdleScheduler, Origin, Runtime, ScheduledTasks, H160};
 use frame_support::traits::{OnIdle, OnInitialize};
 use orml_benchmarking::runtime_benchmarks;
-use primitives::task::TaskResult;
+use primitives::task::{TaskPriority, TaskResult};
 
 runtime_benchmarks! {
 	{ Runtime, module_idle_scheduler}
@@ -37,16 +37,39 @@ runtime_benchmarks! {
 	clear_tasks {
 		let dummy_hash = [0; 20];
 		let call = ScheduledTasks::EvmTask(EvmTask::Remove{caller: H160::from(&dummy_hash), contract: H160::from(&dummy_hash), maintainer: H160::from(&dummy_hash)});
-		IdleScheduler::schedule_task(Origin::root(), call)?;
-		let completed_tasks = vec![(0, TaskResult{ result: Ok(()), used_weight: 0, finished: true })];
+		IdleScheduler::schedule_task(Origin::root(), call, None)?;
+		let dispatched_tasks = vec![(0, TaskPriority::Low, TaskResult{ result: Ok(()), used_weight: 0, finished: true })];
 	}: {
-		IdleScheduler::remove_completed_tasks(completed_tasks);
+		IdleScheduler::on_tasks_dispatched(1, dispatched_tasks);
 	}
 
 	schedule_task {
 		let dummy_hash = [0; 20];
 		let call = ScheduledTasks::EvmTask(EvmTask::Remove{caller: H160::from(&dummy_hash), contract: H160::from(&dummy_hash), maintainer: H160::from(&dummy_hash)});
-	}: _(Origin::root(), call)
+	}: _(Origin::root(), call, Some(TaskPriority::High))
+
+	cancel_task {
+		let dummy_hash = [0; 20];
+		let call = ScheduledTasks::EvmTask(EvmTask::Remove{caller: H160::from(&dummy_hash), contract: H160::from(&dummy_hash), maintainer: H160::from(&dummy_hash)});
+		IdleScheduler::schedule_task(Origin::root(), call, None)?;
+	}: _(Origin::root(), 0)
+
+	schedule_recurring_task {
+		let dummy_hash = [0; 20];
+		let call = ScheduledTasks::EvmTask(EvmTask::Remove{caller: H160::from(&dummy_hash), contract: H160::from(&dummy_hash), maintainer: H160::from(&dummy_hash)});
+	}: _(Origin::root(), call, 10)
+
+	set_recurring_task_paused {
+		let dummy_hash = [0; 20];
+		let call = ScheduledTasks::EvmTask(EvmTask::Remove{caller: H160::from(&dummy_hash), contract: H160::from(&dummy_hash), maintainer: H160::from(&dummy_hash)});
+		IdleScheduler::schedule_recurring_task(Origin::root(), call, 10)?;
+	}: _(Origin::root(), 0, true)
+
+	cancel_recurring_task {
+		let dummy_hash = [0; 20];
+		let call = ScheduledTasks::EvmTask(EvmTask::Remove{caller: H160::from(&dummy_hash), contract: H160::from(&dummy_hash), maintainer: H160::from(&dummy_hash)});
+		IdleScheduler::schedule_recurring_task(Origin::root(), call, 10)?;
+	}: _(Origin::root(), 0)
 }
 
 #[cfg(test)]
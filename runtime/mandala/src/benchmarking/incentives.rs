@@ -16,7 +16,9 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AccountId, AccumulatePeriod, Currencies, CurrencyId, Incentives, Rate, Rewards, Runtime, System};
+use crate::{
+	AccountId, AccumulatePeriod, Currencies, CurrencyId, GaugeEpochPeriod, Incentives, Rate, Rewards, Runtime, System,
+};
 
 use super::{
 	get_benchmarking_collateral_currency_ids,
@@ -28,6 +30,7 @@ use frame_system::RawOrigin;
 use module_support::PoolId;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
+use sp_runtime::Permill;
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -122,6 +125,119 @@ runtime_benchmarks! {
 			updates.push((PoolId::Loans(currency_id), Rate::default()));
 		}
 	}: _(RawOrigin::Root, updates)
+
+	on_initialize_epoch {
+		let e in 0 .. get_benchmarking_collateral_currency_ids().len() as u32;
+		let c in 0 .. get_benchmarking_collateral_currency_ids().len() as u32;
+		let currency_ids = get_benchmarking_collateral_currency_ids();
+		let block_number = GaugeEpochPeriod::get();
+
+		Incentives::update_gauge_reward_amount(RawOrigin::Root.into(), 100 * dollar(NATIVE))?;
+		// the gauge votes expire at the epoch boundary
+		for i in 0 .. e {
+			let voter: AccountId = account("expired_voter", i, SEED);
+			set_balance(NATIVE, &voter, 100 * dollar(NATIVE));
+			Incentives::escrow_lock(RawOrigin::Signed(voter.clone()).into(), 100 * dollar(NATIVE), block_number)?;
+			Incentives::vote_gauges(RawOrigin::Signed(voter).into(), vec![(PoolId::Loans(currency_ids[i as usize]), Permill::one())])?;
+		}
+		for i in 0 .. c {
+			let voter: AccountId = account("voter", i, SEED);
+			set_balance(NATIVE, &voter, 100 * dollar(NATIVE));
+			Incentives::escrow_lock(RawOrigin::Signed(voter.clone()).into(), 100 * dollar(NATIVE), 2 * block_number)?;
+			Incentives::vote_gauges(RawOrigin::Signed(voter).into(), vec![(PoolId::Loans(currency_ids[i as usize]), Permill::one())])?;
+		}
+
+		System::set_block_number(block_number);
+	}: {
+		Incentives::on_initialize(System::block_number());
+	}
+
+	escrow_lock {
+		let caller: AccountId = whitelisted_caller();
+		let block_number = GaugeEpochPeriod::get();
+		let votes = get_benchmarking_collateral_currency_ids()
+			.into_iter()
+			.map(|currency_id| (PoolId::Loans(currency_id), Permill::from_percent(1)))
+			.collect::<Vec<_>>();
+
+		set_balance(NATIVE, &caller, 200 * dollar(NATIVE));
+		Incentives::escrow_lock(RawOrigin::Signed(caller.clone()).into(), 100 * dollar(NATIVE), block_number)?;
+		Incentives::vote_gauges(RawOrigin::Signed(caller.clone()).into(), votes)?;
+	}: _(RawOrigin::Signed(caller), 100 * dollar(NATIVE), 2 * block_number)
+
+	escrow_unlock {
+		let caller: AccountId = whitelisted_caller();
+		let block_number = GaugeEpochPeriod::get();
+
+		set_balance(NATIVE, &caller, 100 * dollar(NATIVE));
+		Incentives::escrow_lock(RawOrigin::Signed(caller.clone()).into(), 100 * dollar(NATIVE), block_number)?;
+		System::set_block_number(block_number);
+	}: _(RawOrigin::Signed(caller))
+
+	vote_gauges {
+		let c in 0 .. get_benchmarking_collateral_currency_ids().len() as u32;
+		let caller: AccountId = whitelisted_caller();
+		let currency_ids = get_benchmarking_collateral_currency_ids();
+		let mut votes = vec![];
+
+		for i in 0 .. c {
+			votes.push((PoolId::Loans(currency_ids[i as usize]), Permill::from_percent(1)));
+		}
+
+		set_balance(NATIVE, &caller, 100 * dollar(NATIVE));
+		Incentives::escrow_lock(RawOrigin::Signed(caller.clone()).into(), 100 * dollar(NATIVE), GaugeEpochPeriod::get())?;
+		Incentives::vote_gauges(RawOrigin::Signed(caller.clone()).into(), votes.clone())?;
+	}: _(RawOrigin::Signed(caller), votes)
+
+	update_gauge_reward_amount {
+	}: _(RawOrigin::Root, 100 * dollar(NATIVE))
+
+	kick {
+		let caller: AccountId = whitelisted_caller();
+		let native_stablecoin_lp = CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap();
+		set_balance(native_stablecoin_lp, &caller, 10_000 * dollar(STABLECOIN));
+		set_balance(NATIVE, &caller, 100 * dollar(NATIVE));
+		Incentives::deposit_dex_share(
+			RawOrigin::Signed(caller.clone()).into(),
+			native_stablecoin_lp,
+			10_000 * dollar(STABLECOIN)
+		)?;
+		Incentives::escrow_lock(RawOrigin::Signed(caller.clone()).into(), 100 * dollar(NATIVE), GaugeEpochPeriod::get())?;
+	}: _(RawOrigin::Signed(caller.clone()), caller.clone(), PoolId::Dex(native_stablecoin_lp))
+
+	on_initialize_campaigns {
+		let c in 0 .. get_benchmarking_collateral_currency_ids().len() as u32;
+		let currency_ids = get_benchmarking_collateral_currency_ids();
+		let creator: AccountId = account("creator", 0, SEED);
+		let block_number = AccumulatePeriod::get();
+		set_balance(NATIVE, &creator, 10_000 * dollar(NATIVE));
+
+		for i in 0 .. c {
+			let pool_id = PoolId::Loans(currency_ids[i as usize]);
+			Incentives::create_campaign(RawOrigin::Signed(creator.clone()).into(), pool_id.clone(), NATIVE, 100 * dollar(NATIVE), 0, 2 * block_number)?;
+			orml_rewards::PoolInfos::<Runtime>::mutate(pool_id, |pool_info| {
+				pool_info.total_shares += 100;
+			});
+		}
+
+		System::set_block_number(block_number);
+	}: {
+		Incentives::on_initialize(System::block_number());
+	}
+
+	create_campaign {
+		let caller: AccountId = whitelisted_caller();
+		set_balance(NATIVE, &caller, 10_000 * dollar(NATIVE));
+	}: _(RawOrigin::Signed(caller), PoolId::Loans(STAKING), NATIVE, 100 * dollar(NATIVE), 0, AccumulatePeriod::get())
+
+	refund_campaign {
+		let caller: AccountId = whitelisted_caller();
+		let block_number = AccumulatePeriod::get();
+		set_balance(NATIVE, &caller, 10_000 * dollar(NATIVE));
+		Incentives::create_campaign(RawOrigin::Signed(caller.clone()).into(), PoolId::Loans(STAKING), NATIVE, 100 * dollar(NATIVE), 0, block_number)?;
+		System::set_block_number(block_number);
+		Incentives::on_initialize(block_number);
+	}: _(RawOrigin::Signed(caller), 0)
 }
 
 #[cfg(test)]
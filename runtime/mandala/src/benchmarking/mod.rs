@@ -42,12 +42,16 @@ pub mod homa;
 pub mod honzon;
 pub mod idle_scheduler;
 pub mod incentives;
+pub mod multi_vesting;
+pub mod nft_loans;
 pub mod nominees_election;
 pub mod nutsfinance_stable_asset;
 pub mod prices;
 pub mod session_manager;
+pub mod streaming;
 pub mod transaction_pause;
 pub mod transaction_payment;
+pub mod vault;
 
 // orml benchmarking
 pub mod auction;
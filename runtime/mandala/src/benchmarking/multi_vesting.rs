@@ -0,0 +1,145 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{dollar, lookup_of_account, set_balance, LIQUID, NATIVE};
+use crate::{AccountId, Balance, BlockNumber, Currencies, MultiVesting, Runtime, System};
+
+use sp_std::prelude::*;
+
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+
+use module_support::VestingSchedule;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::{GetByKey, MultiCurrency};
+
+pub type Schedule = VestingSchedule<BlockNumber, Balance>;
+
+const SEED: u32 = 0;
+
+fn schedule() -> Schedule {
+	Schedule {
+		start: 0,
+		cliff: 2,
+		period: 2,
+		period_count: 3,
+		per_period: <Runtime as module_multi_vesting::Config>::MinVestedTransfer::get(&LIQUID),
+	}
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_multi_vesting }
+
+	vested_transfer {
+		let schedule = schedule();
+
+		let from: AccountId = whitelisted_caller();
+		set_balance(LIQUID, &from, schedule.total_amount().unwrap());
+		set_balance(NATIVE, &from, dollar(NATIVE));
+
+		let to: AccountId = account("to", 0, SEED);
+		let to_lookup = lookup_of_account(to.clone());
+	}: _(RawOrigin::Signed(from), to_lookup, LIQUID, schedule)
+	verify {
+		assert_eq!(
+			<Currencies as MultiCurrency<_>>::total_balance(LIQUID, &to),
+			schedule.total_amount().unwrap()
+		);
+	}
+
+	claim {
+		let i in 1 .. <Runtime as module_multi_vesting::Config>::MaxVestingSchedules::get();
+
+		let mut schedule = schedule();
+
+		let from: AccountId = account("from", 0, SEED);
+		set_balance(LIQUID, &from, schedule.total_amount().unwrap() * i as u128);
+		set_balance(NATIVE, &from, dollar(NATIVE));
+
+		let to: AccountId = whitelisted_caller();
+		let to_lookup = lookup_of_account(to.clone());
+
+		for _ in 0..i {
+			schedule.start = i;
+			MultiVesting::vested_transfer(RawOrigin::Signed(from.clone()).into(), to_lookup.clone(), LIQUID, schedule)?;
+		}
+		System::set_block_number(schedule.end().unwrap() + 1u32);
+	}: _(RawOrigin::Signed(to.clone()), LIQUID)
+	verify {
+		assert_eq!(
+			<Currencies as MultiCurrency<_>>::free_balance(LIQUID, &to),
+			schedule.total_amount().unwrap() * i as u128,
+		);
+	}
+
+	update_vesting_schedules {
+		let i in 1 .. <Runtime as module_multi_vesting::Config>::MaxVestingSchedules::get();
+
+		let mut schedule = schedule();
+
+		let to: AccountId = account("to", 0, SEED);
+		set_balance(LIQUID, &to, schedule.total_amount().unwrap() * i as u128);
+		let to_lookup = lookup_of_account(to.clone());
+
+		let mut schedules = vec![];
+		for _ in 0..i {
+			schedule.start = i;
+			schedules.push(schedule);
+		}
+	}: _(RawOrigin::Root, to_lookup, LIQUID, schedules)
+	verify {
+		assert_eq!(
+			MultiVesting::vesting_schedules(&to, LIQUID).len(),
+			i as usize
+		);
+	}
+
+	merge_schedules {
+		let i in 2 .. <Runtime as module_multi_vesting::Config>::MaxVestingSchedules::get();
+
+		let mut schedule = schedule();
+
+		let from: AccountId = account("from", 0, SEED);
+		set_balance(LIQUID, &from, schedule.total_amount().unwrap() * i as u128);
+		set_balance(NATIVE, &from, dollar(NATIVE));
+
+		let to: AccountId = whitelisted_caller();
+		let to_lookup = lookup_of_account(to.clone());
+
+		for _ in 0..i {
+			schedule.start = i;
+			MultiVesting::vested_transfer(RawOrigin::Signed(from.clone()).into(), to_lookup.clone(), LIQUID, schedule)?;
+		}
+	}: _(RawOrigin::Signed(to.clone()), LIQUID, 0, i - 1)
+	verify {
+		assert_eq!(
+			MultiVesting::vesting_schedules(&to, LIQUID).len(),
+			i as usize - 1
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,171 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{dollar, lookup_of_account, set_balance, NATIVE, STABLECOIN};
+use crate::{AccountId, HonzonTreasuryAccount, NFTLoans, NftPalletId, Runtime, Timestamp, MILLISECS_PER_BLOCK, NFT};
+
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_support::traits::{Get, OnInitialize};
+use frame_system::RawOrigin;
+use module_nft_loans::CollateralClassParams;
+use module_support::{Rate, Ratio};
+use orml_benchmarking::runtime_benchmarks;
+use primitives::nft::{ClassProperty, Properties};
+use sp_runtime::{traits::AccountIdConversion, DispatchError, FixedPointNumber};
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn class_params() -> CollateralClassParams {
+	CollateralClassParams {
+		liquidation_ratio: Ratio::saturating_from_rational(150, 100),
+		required_collateral_ratio: Ratio::saturating_from_rational(200, 100),
+		liquidation_penalty: Rate::saturating_from_rational(10, 100),
+		interest_rate_per_sec: Rate::saturating_from_rational(1, 1_000_000_000),
+		maximum_total_debit_value: 1_000_000 * dollar(STABLECOIN),
+	}
+}
+
+// create a whitelisted class and mint `quantity` tokens to `owner`
+fn create_collateral_class(owner: &AccountId, quantity: u32) -> Result<u32, DispatchError> {
+	let class_id = orml_nft::Pallet::<Runtime>::next_class_id();
+	set_balance(NATIVE, owner, 1_000 * dollar(NATIVE));
+	NFT::create_class(
+		RawOrigin::Signed(owner.clone()).into(),
+		vec![1],
+		Properties(ClassProperty::Transferable | ClassProperty::Burnable | ClassProperty::Mintable),
+		Default::default(),
+	)
+	.map_err(|e| e.error)?;
+
+	let class_account: AccountId = NftPalletId::get().into_sub_account_truncating(class_id);
+	set_balance(NATIVE, &class_account, 1_000 * dollar(NATIVE));
+	NFT::mint(
+		RawOrigin::Signed(class_account).into(),
+		lookup_of_account(owner.clone()),
+		class_id,
+		vec![1],
+		Default::default(),
+		quantity,
+	)?;
+
+	NFTLoans::set_collateral_params(RawOrigin::Root.into(), class_id, Some(class_params()))?;
+	NFTLoans::set_floor_price(RawOrigin::Root.into(), class_id, Some(100 * dollar(STABLECOIN)))?;
+	Ok(class_id)
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_nft_loans }
+
+	on_initialize {
+		let c in 0 .. 10;
+		let owner: AccountId = account("owner", 0, SEED);
+		for _ in 0..c {
+			let class_id = create_collateral_class(&owner, 1)?;
+			NFTLoans::deposit_nft(RawOrigin::Signed(owner.clone()).into(), (class_id, 0))?;
+			NFTLoans::adjust_debit(
+				RawOrigin::Signed(owner.clone()).into(),
+				class_id,
+				(100 * dollar(STABLECOIN)) as i128,
+			)?;
+		}
+		Timestamp::set_timestamp(MILLISECS_PER_BLOCK);
+		NFTLoans::on_initialize(2);
+	}: {
+		Timestamp::set_timestamp(MILLISECS_PER_BLOCK * 2);
+		NFTLoans::on_initialize(3);
+	}
+
+	set_collateral_params {
+	}: _(RawOrigin::Root, 0, Some(class_params()))
+
+	set_floor_price {
+	}: _(RawOrigin::Root, 0, Some(100 * dollar(STABLECOIN)))
+
+	deposit_nft {
+		let caller: AccountId = whitelisted_caller();
+		let class_id = create_collateral_class(&caller, 1)?;
+	}: _(RawOrigin::Signed(caller.clone()), (class_id, 0))
+	verify {
+		assert_eq!(NFTLoans::positions(class_id, &caller).collateral, 1);
+	}
+
+	// withdraw an NFT from a position with debit, worst case
+	withdraw_nft {
+		let caller: AccountId = whitelisted_caller();
+		let class_id = create_collateral_class(&caller, 2)?;
+		NFTLoans::deposit_nft(RawOrigin::Signed(caller.clone()).into(), (class_id, 0))?;
+		NFTLoans::deposit_nft(RawOrigin::Signed(caller.clone()).into(), (class_id, 1))?;
+		NFTLoans::adjust_debit(
+			RawOrigin::Signed(caller.clone()).into(),
+			class_id,
+			(10 * dollar(STABLECOIN)) as i128,
+		)?;
+	}: _(RawOrigin::Signed(caller.clone()), (class_id, 0))
+	verify {
+		assert_eq!(NFTLoans::positions(class_id, &caller).collateral, 1);
+	}
+
+	adjust_debit {
+		let caller: AccountId = whitelisted_caller();
+		let class_id = create_collateral_class(&caller, 1)?;
+		NFTLoans::deposit_nft(RawOrigin::Signed(caller.clone()).into(), (class_id, 0))?;
+	}: _(RawOrigin::Signed(caller.clone()), class_id, (10 * dollar(STABLECOIN)) as i128)
+	verify {
+		assert_eq!(NFTLoans::positions(class_id, &caller).debit, 10 * dollar(STABLECOIN));
+	}
+
+	liquidate {
+		let n in 1 .. <Runtime as module_nft_loans::Config>::MaxCollateralsPerPosition::get();
+		let caller: AccountId = whitelisted_caller();
+		let owner: AccountId = account("owner", 0, SEED);
+		let class_id = create_collateral_class(&owner, n)?;
+		for token_id in 0..n {
+			NFTLoans::deposit_nft(RawOrigin::Signed(owner.clone()).into(), (class_id, token_id.into()))?;
+		}
+		NFTLoans::adjust_debit(
+			RawOrigin::Signed(owner.clone()).into(),
+			class_id,
+			(n as u128 * 500 * dollar(STABLECOIN)) as i128,
+		)?;
+		NFTLoans::set_floor_price(RawOrigin::Root.into(), class_id, Some(50 * dollar(STABLECOIN)))?;
+	}: _(RawOrigin::Signed(caller), class_id, owner.clone())
+	verify {
+		assert_eq!(NFTLoans::positions(class_id, &owner).collateral, 0);
+	}
+
+	reauction_nft {
+		let owner: AccountId = account("owner", 0, SEED);
+		let class_id = create_collateral_class(&owner, 1)?;
+		set_balance(NATIVE, &HonzonTreasuryAccount::get(), dollar(NATIVE));
+		NFT::transfer(
+			RawOrigin::Signed(owner).into(),
+			lookup_of_account(HonzonTreasuryAccount::get()),
+			(class_id, 0),
+		)?;
+	}: _(RawOrigin::Root, (class_id, 0), 100 * dollar(STABLECOIN), 50 * dollar(STABLECOIN))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
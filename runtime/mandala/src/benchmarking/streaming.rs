@@ -0,0 +1,101 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::utils::{dollar, lookup_of_account, set_balance, LIQUID, NATIVE};
+use crate::{AccountId, Currencies, Runtime, Streaming, Timestamp};
+
+use sp_std::prelude::*;
+
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::{GetByKey, MultiCurrency};
+
+const SEED: u32 = 0;
+
+fn rate_per_second() -> u128 {
+	<Runtime as module_streaming::Config>::MinimumDeposit::get(&LIQUID)
+}
+
+fn create_stream(sender: &AccountId, recipient: &AccountId) -> Result<(), &'static str> {
+	set_balance(LIQUID, sender, rate_per_second() * 100);
+	set_balance(NATIVE, sender, dollar(NATIVE));
+	Timestamp::set_timestamp(10_000);
+	Streaming::create_stream(
+		RawOrigin::Signed(sender.clone()).into(),
+		lookup_of_account(recipient.clone()),
+		LIQUID,
+		rate_per_second(),
+		10,
+		110,
+	)?;
+	Ok(())
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_streaming }
+
+	create_stream {
+		let sender: AccountId = whitelisted_caller();
+		let recipient: AccountId = account("recipient", 0, SEED);
+		set_balance(LIQUID, &sender, rate_per_second() * 100);
+		Timestamp::set_timestamp(10_000);
+	}: _(RawOrigin::Signed(sender.clone()), lookup_of_account(recipient), LIQUID, rate_per_second(), 10, 110)
+	verify {
+		assert_eq!(
+			<Currencies as MultiCurrency<_>>::free_balance(LIQUID, &sender),
+			0
+		);
+	}
+
+	withdraw {
+		let sender: AccountId = account("sender", 0, SEED);
+		let recipient: AccountId = whitelisted_caller();
+		create_stream(&sender, &recipient)?;
+		Timestamp::set_timestamp(60_000);
+	}: _(RawOrigin::Signed(recipient.clone()), 0)
+	verify {
+		assert_eq!(
+			<Currencies as MultiCurrency<_>>::free_balance(LIQUID, &recipient),
+			rate_per_second() * 50
+		);
+	}
+
+	cancel_stream {
+		let sender: AccountId = whitelisted_caller();
+		let recipient: AccountId = account("recipient", 0, SEED);
+		create_stream(&sender, &recipient)?;
+		Timestamp::set_timestamp(60_000);
+	}: _(RawOrigin::Signed(sender.clone()), 0)
+	verify {
+		assert_eq!(
+			<Currencies as MultiCurrency<_>>::free_balance(LIQUID, &recipient),
+			rate_per_second() * 50
+		);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -16,10 +16,15 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Origin, Runtime, TransactionPause};
+use crate::{Origin, Runtime, System, TransactionPause};
 
+use codec::Encode;
+use frame_support::traits::OnInitialize;
 use frame_system::RawOrigin;
+use module_evm::EvmAddress;
+use module_support::CircuitBreaker;
 use orml_benchmarking::runtime_benchmarks;
+use sp_std::prelude::*;
 
 runtime_benchmarks! {
 	{ Runtime, module_transaction_pause }
@@ -30,6 +35,33 @@ runtime_benchmarks! {
 	unpause_transaction {
 		TransactionPause::pause_transaction(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec())?;
 	}: _(RawOrigin::Root, b"Balances".to_vec(), b"transfer".to_vec())
+
+	pause_transaction_until {
+		TransactionPause::pause_transaction_until(Origin::root(), b"Balances".to_vec(), b"transfer".to_vec(), 10)?;
+	}: _(RawOrigin::Root, b"Balances".to_vec(), b"transfer".to_vec(), 20)
+
+	pause_evm_call {
+		TransactionPause::pause_evm_call(Origin::root(), EvmAddress::default(), Some([1u8; 4]), Some(10))?;
+	}: _(RawOrigin::Root, EvmAddress::default(), Some([1u8; 4]), Some(20))
+
+	unpause_evm_call {
+		TransactionPause::pause_evm_call(Origin::root(), EvmAddress::default(), Some([1u8; 4]), Some(10))?;
+	}: _(RawOrigin::Root, EvmAddress::default(), Some([1u8; 4]))
+
+	reset_circuit_breaker {
+		let key = b"circuit breaker".to_vec();
+		<TransactionPause as CircuitBreaker<Vec<u8>>>::trip(&key);
+	}: _(RawOrigin::Root, key.encode())
+
+	on_initialize {
+		let c in 0 .. 100;
+		System::set_block_number(1);
+		for i in 0 .. c {
+			TransactionPause::pause_transaction_until(Origin::root(), b"Balances".to_vec(), i.to_be_bytes().to_vec(), 2)?;
+		}
+	}: {
+		TransactionPause::on_initialize(2);
+	}
 }
 
 #[cfg(test)]
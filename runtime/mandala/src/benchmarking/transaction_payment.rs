@@ -18,17 +18,22 @@
 
 use super::utils::{dollar, inject_liquidity, set_balance, LIQUID, NATIVE, STABLECOIN, STAKING};
 use crate::{
-	AccountId, AssetRegistry, Balance, Currencies, CurrencyId, Dex, Event, NativeTokenExistentialDeposit, Origin,
+	AccountId, AssetRegistry, Balance, Call, Currencies, CurrencyId, Dex, Event, NativeTokenExistentialDeposit, Origin,
 	Runtime, StableAsset, System, TransactionPayment, TreasuryPalletId,
 };
 use frame_benchmarking::{account, whitelisted_caller};
-use frame_support::{assert_ok, traits::OnFinalize};
+use frame_support::{
+	assert_ok,
+	traits::{Get, OnFinalize},
+	weights::GetDispatchInfo,
+};
 use frame_system::RawOrigin;
 use module_support::{AggregatedSwapPath, DEXManager, Ratio, SwapLimit};
+use module_transaction_payment::{ChargeTransactionPayment, SponsoredTarget};
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::MultiCurrency;
 use primitives::currency::AssetMetadata;
-use sp_runtime::traits::{AccountIdConversion, One};
+use sp_runtime::traits::{AccountIdConversion, One, SignedExtension};
 use sp_std::prelude::*;
 
 const SEED: u32 = 0;
@@ -207,6 +212,32 @@ runtime_benchmarks! {
 		}.into());
 	}: _(RawOrigin::Signed(caller.clone()), STABLECOIN, call)
 
+	// the fee is paid by redeeming the DEX share and swapping the withdrawn currency to native.
+	with_fee_currency_liquidity_token {
+		System::set_block_number(1);
+
+		let funder: AccountId = account("funder", 0, SEED);
+		inject_liquidity(funder, STABLECOIN, NATIVE, 1_000 * dollar(STABLECOIN), 10_000 * dollar(NATIVE), false)?;
+
+		let caller: AccountId = whitelisted_caller();
+		let lp_currency_id = CurrencyId::join_dex_share_currency_id(STABLECOIN, NATIVE).unwrap();
+		inject_liquidity(caller.clone(), STABLECOIN, NATIVE, 100 * dollar(STABLECOIN), 1_000 * dollar(NATIVE), false)?;
+		let share = Currencies::free_balance(lp_currency_id, &caller);
+		let native_balance = Currencies::free_balance(NATIVE, &caller);
+		<Currencies as MultiCurrency<_>>::withdraw(NATIVE, &caller, native_balance)?;
+
+		let call: Call = module_transaction_payment::Call::with_fee_currency {
+			currency_id: lp_currency_id,
+			call: Box::new(frame_system::Call::remark { remark: vec![] }.into()),
+		}.into();
+		let info = call.get_dispatch_info();
+	}: {
+		assert_ok!(ChargeTransactionPayment::<Runtime>::from(0).pre_dispatch(&caller, &call, &info, 0));
+	}
+	verify {
+		assert!(Currencies::free_balance(lp_currency_id, &caller) < share);
+	}
+
 	with_fee_aggregated_path {
 		System::set_block_number(1);
 
@@ -231,6 +262,34 @@ runtime_benchmarks! {
 		let signature = sp_runtime::MultiSignature::Sr25519(sp_core::sr25519::Signature([0u8; 64]));
 	}: _(RawOrigin::Signed(caller.clone()), call, payer, signature)
 
+	register_paymaster {
+		let n in 1 .. <Runtime as module_transaction_payment::Config>::MaxSponsoredTargets::get();
+		let caller: AccountId = whitelisted_caller();
+		let targets = (0..n).map(|i| SponsoredTarget::Pallet(i as u8)).collect::<Vec<_>>();
+	}: force_register_paymaster(RawOrigin::Root, caller.clone(), targets, dollar(NATIVE), 10, 100, 1000)
+	verify {
+		assert!(TransactionPayment::paymasters(&caller).is_some());
+		assert_eq!(TransactionPayment::paymaster_targets(&caller).len(), n as usize);
+	}
+
+	update_paymaster {
+		let caller: AccountId = whitelisted_caller();
+		TransactionPayment::force_register_paymaster(RawOrigin::Root.into(), caller.clone(), vec![SponsoredTarget::Pallet(0)], dollar(NATIVE), 10, 100, 1000)?;
+	}: _(RawOrigin::Signed(caller.clone()), 2 * dollar(NATIVE), 20, 100, 2000)
+	verify {
+		assert_eq!(TransactionPayment::paymasters(&caller).unwrap().budget, 2 * dollar(NATIVE));
+	}
+
+	remove_paymaster {
+		let n in 1 .. <Runtime as module_transaction_payment::Config>::MaxSponsoredTargets::get();
+		let caller: AccountId = whitelisted_caller();
+		let targets = (0..n).map(|i| SponsoredTarget::Pallet(i as u8)).collect::<Vec<_>>();
+		TransactionPayment::force_register_paymaster(RawOrigin::Root.into(), caller.clone(), targets, dollar(NATIVE), 10, 100, 1000)?;
+	}: _(RawOrigin::Signed(caller.clone()), caller.clone())
+	verify {
+		assert!(TransactionPayment::paymasters(&caller).is_none());
+	}
+
 	on_finalize {
 	}: {
 		TransactionPayment::on_finalize(System::block_number());
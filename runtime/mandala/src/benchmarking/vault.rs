@@ -0,0 +1,120 @@
+// This file is part of Acala.
+
+// Copyright (C) 2020-2022 Acala Foundation.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountId, Currencies, CurrencyId, Incentives, Rewards, Runtime, Vault};
+
+use super::utils::{dollar, inject_liquidity, set_balance, NATIVE, STABLECOIN};
+use frame_benchmarking::{account, whitelisted_caller};
+use frame_system::RawOrigin;
+use module_support::PoolId;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+use primitives::currency::AssetMetadata;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+
+fn lp_currency_id() -> CurrencyId {
+	CurrencyId::join_dex_share_currency_id(NATIVE, STABLECOIN).unwrap()
+}
+
+fn create_vault() -> Result<(), &'static str> {
+	Vault::create_vault(
+		RawOrigin::Root.into(),
+		lp_currency_id(),
+		None,
+		Box::new(AssetMetadata {
+			name: b"Vault LP ACA-AUSD".to_vec(),
+			symbol: b"vLP_ACA_AUSD".to_vec(),
+			decimals: 12,
+			minimal_balance: 0,
+		}),
+	)?;
+	Ok(())
+}
+
+runtime_benchmarks! {
+	{ Runtime, module_vault }
+
+	create_vault {
+	}: _(
+		RawOrigin::Root,
+		lp_currency_id(),
+		None,
+		Box::new(AssetMetadata {
+			name: b"Vault LP ACA-AUSD".to_vec(),
+			symbol: b"vLP_ACA_AUSD".to_vec(),
+			decimals: 12,
+			minimal_balance: 0,
+		})
+	)
+
+	update_vault {
+		create_vault()?;
+	}: _(RawOrigin::Root, 0, Some(1_000_000 * dollar(STABLECOIN)))
+
+	deposit {
+		let caller: AccountId = whitelisted_caller();
+		create_vault()?;
+		set_balance(lp_currency_id(), &caller, 10_000 * dollar(STABLECOIN));
+	}: _(RawOrigin::Signed(caller), 0, 10_000 * dollar(STABLECOIN))
+
+	mint {
+		let caller: AccountId = whitelisted_caller();
+		create_vault()?;
+		set_balance(lp_currency_id(), &caller, 10_000 * dollar(STABLECOIN));
+	}: _(RawOrigin::Signed(caller), 0, 5_000 * dollar(STABLECOIN))
+
+	withdraw {
+		let caller: AccountId = whitelisted_caller();
+		create_vault()?;
+		set_balance(lp_currency_id(), &caller, 10_000 * dollar(STABLECOIN));
+		Vault::deposit(RawOrigin::Signed(caller.clone()).into(), 0, 10_000 * dollar(STABLECOIN))?;
+	}: _(RawOrigin::Signed(caller), 0, 5_000 * dollar(STABLECOIN))
+
+	redeem {
+		let caller: AccountId = whitelisted_caller();
+		create_vault()?;
+		set_balance(lp_currency_id(), &caller, 10_000 * dollar(STABLECOIN));
+		Vault::deposit(RawOrigin::Signed(caller.clone()).into(), 0, 10_000 * dollar(STABLECOIN))?;
+	}: _(RawOrigin::Signed(caller), 0, 5_000 * dollar(STABLECOIN))
+
+	harvest {
+		let caller: AccountId = whitelisted_caller();
+		let maker: AccountId = account("maker", 0, SEED);
+		let pool_id = PoolId::Dex(lp_currency_id());
+		inject_liquidity(maker, NATIVE, STABLECOIN, 10_000 * dollar(NATIVE), 10_000 * dollar(STABLECOIN), false)?;
+
+		create_vault()?;
+		set_balance(lp_currency_id(), &caller, 10_000 * dollar(STABLECOIN));
+		Vault::deposit(RawOrigin::Signed(caller).into(), 0, 10_000 * dollar(STABLECOIN))?;
+
+		Incentives::update_incentive_rewards(RawOrigin::Root.into(), vec![(pool_id.clone(), vec![(NATIVE, 100 * dollar(NATIVE))])])?;
+		Currencies::deposit(NATIVE, &Incentives::account_id(), 100 * dollar(NATIVE))?;
+		Rewards::accumulate_reward(&pool_id, NATIVE, 100 * dollar(NATIVE))?;
+	}: _(RawOrigin::Root, 0)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::benchmarking::utils::tests::new_test_ext;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -30,16 +30,16 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
-use codec::{Decode, DecodeLimit, Encode};
+use codec::{Decode, DecodeLimit, Encode, MaxEncodedLen};
 use cumulus_pallet_parachain_system::RelaychainBlockNumberProvider;
 use frame_support::pallet_prelude::InvalidTransaction;
 pub use frame_support::{
 	construct_runtime, log, parameter_types,
 	traits::{
 		ConstBool, ConstU128, ConstU16, ConstU32, Contains, ContainsLengthBound, Currency as PalletCurrency,
-		EnsureOrigin, EqualPrivilegeOnly, Everything, Get, Imbalance, InstanceFilter, IsSubType, IsType,
-		KeyOwnerProofSystem, LockIdentifier, Nothing, OnRuntimeUpgrade, OnUnbalanced, Randomness, SortedMembers,
-		U128CurrencyToVote, WithdrawReasons,
+		EitherOfDiverse, EnsureOrigin, EqualPrivilegeOnly, Everything, Get, Imbalance, InstanceFilter, IsInVec,
+		IsSubType, IsType, KeyOwnerProofSystem, LockIdentifier, OnRuntimeUpgrade, OnUnbalanced, Randomness,
+		SortedMembers, U128CurrencyToVote, WithdrawReasons,
 	},
 	weights::{
 		constants::{BlockExecutionWeight, RocksDbWeight, WEIGHT_PER_SECOND},
@@ -47,13 +47,18 @@ pub use frame_support::{
 	},
 	PalletId, RuntimeDebug, StorageValue,
 };
-use frame_system::{EnsureRoot, RawOrigin};
+use frame_support::dispatch::{CallMetadata, GetCallMetadata};
+use frame_system::{EnsureRoot, EnsureSignedBy, RawOrigin};
 use hex_literal::hex;
 use module_asset_registry::{AssetIdMaps, EvmErc20InfoMapping};
 use module_cdp_engine::CollateralCurrencyIds;
+use module_cdp_treasury::CdpTreasuryTask;
 use module_currencies::{BasicCurrencyAdapter, Currency};
+use module_dex_oracle::DexOracleTask;
 use module_evm::{runner::RunnerExtended, CallInfo, CreateInfo, EvmChainId, EvmTask};
 use module_evm_accounts::EvmAddressMapping;
+use module_homa::HomaTask;
+use module_nft::NftTask;
 use module_relaychain::RelayChainCallBuilder;
 use module_support::{AssetIdMapping, DispatchableTask, ExchangeRateProvider, PoolId};
 use module_transaction_payment::TargetedFeeAdjustment;
@@ -66,7 +71,7 @@ use orml_traits::{
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 use primitives::{
 	define_combined_task,
-	evm::{AccessListItem, EthereumTransactionMessage},
+	evm::{AccessListItem, Eip712CallMessage, EthereumTransactionMessage},
 	task::TaskResult,
 	unchecked_extrinsic::AcalaUncheckedExtrinsic,
 };
@@ -162,6 +167,7 @@ impl_opaque_keys! {
 parameter_types! {
 	pub const TreasuryPalletId: PalletId = PalletId(*b"aca/trsy");
 	pub const LoansPalletId: PalletId = PalletId(*b"aca/loan");
+	pub const NFTLoansPalletId: PalletId = PalletId(*b"aca/nftl");
 	pub const DEXPalletId: PalletId = PalletId(*b"aca/dexm");
 	pub const CDPTreasuryPalletId: PalletId = PalletId(*b"aca/cdpt");
 	pub const CDPEnginePalletId: PalletId = PalletId(*b"aca/cdpe");
@@ -169,6 +175,7 @@ parameter_types! {
 	pub const HomaPalletId: PalletId = PalletId(*b"aca/homa");
 	pub const HomaTreasuryPalletId: PalletId = PalletId(*b"aca/hmtr");
 	pub const IncentivesPalletId: PalletId = PalletId(*b"aca/inct");
+	pub const VaultPalletId: PalletId = PalletId(*b"aca/vaul");
 	pub const CollatorPotId: PalletId = PalletId(*b"aca/cpot");
 	// Treasury reserve
 	pub const TreasuryReservePalletId: PalletId = PalletId(*b"aca/reve");
@@ -191,6 +198,7 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		CDPEnginePalletId::get().into_account_truncating(),
 		TreasuryPalletId::get().into_account_truncating(),
 		LoansPalletId::get().into_account_truncating(),
+		NFTLoansPalletId::get().into_account_truncating(),
 		DEXPalletId::get().into_account_truncating(),
 		CDPTreasuryPalletId::get().into_account_truncating(),
 		HonzonTreasuryPalletId::get().into_account_truncating(),
@@ -214,6 +222,7 @@ pub struct BaseCallFilter;
 impl Contains<Call> for BaseCallFilter {
 	fn contains(call: &Call) -> bool {
 		!module_transaction_pause::PausedTransactionFilter::<Runtime>::contains(call)
+			&& !runtime_common::PausedEvmCallFilter::<Runtime>::contains(call)
 			&& !matches!(call, Call::Democracy(pallet_democracy::Call::propose { .. }),)
 	}
 }
@@ -840,6 +849,10 @@ parameter_type_with_key! {
 				AssetIdMaps::<Runtime>::get_asset_metadata(AssetIds::ForeignAssetId(*foreign_asset_id)).
 					map_or(Balance::max_value(), |metatata| metatata.minimal_balance)
 			},
+			CurrencyId::VaultShare(vault_id) => {
+				AssetIdMaps::<Runtime>::get_asset_metadata(AssetIds::VaultId(*vault_id)).
+					map_or(Balance::max_value(), |metatata| metatata.minimal_balance)
+			},
 		}
 	};
 }
@@ -916,6 +929,7 @@ impl module_currencies::Config for Runtime {
 	type NativeCurrency = BasicCurrencyAdapter<Runtime, Balances, Amount, BlockNumber>;
 	type GetNativeCurrencyId = GetNativeCurrencyId;
 	type Erc20HoldingAccount = Erc20HoldingAccount;
+	type TreasuryAccount = TreasuryAccount;
 	type WeightInfo = weights::module_currencies::WeightInfo<Runtime>;
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type EVMBridge = module_evm_bridge::EVMBridge<Runtime>;
@@ -960,6 +974,26 @@ impl orml_vesting::Config for Runtime {
 	type BlockNumberProvider = RelaychainBlockNumberProvider<Runtime>;
 }
 
+impl module_multi_vesting::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type MinVestedTransfer =
+		ExistentialDepositsTimesOneHundred<GetNativeCurrencyId, NativeTokenExistentialDeposit, ExistentialDeposits>;
+	type UpdateOrigin = EnsureRootOrHalfGeneralCouncil;
+	type MaxVestingSchedules = ConstU32<100>;
+	type BlockNumberProvider = RelaychainBlockNumberProvider<Runtime>;
+	type WeightInfo = weights::module_multi_vesting::WeightInfo<Runtime>;
+}
+
+impl module_streaming::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type MinimumDeposit =
+		ExistentialDepositsTimesOneHundred<GetNativeCurrencyId, NativeTokenExistentialDeposit, ExistentialDeposits>;
+	type UnixTime = Timestamp;
+	type WeightInfo = weights::module_streaming::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
 	// Retry a scheduled item every 25 blocks (5 minute) until the preimage exists.
@@ -1014,6 +1048,10 @@ impl module_auction_manager::Config for Runtime {
 	type PriceSource = module_prices::PriorityLockedPriceProvider<Runtime>;
 	type UnsignedPriority = runtime_common::AuctionManagerUnsignedPriority;
 	type EmergencyShutdown = EmergencyShutdown;
+	type NFTClassId = u32;
+	type NFTTokenId = u64;
+	type NFT = NFT;
+	type TreasuryAccount = HonzonTreasuryAccount;
 	type WeightInfo = weights::module_auction_manager::WeightInfo<Runtime>;
 }
 
@@ -1026,6 +1064,25 @@ impl module_loans::Config for Runtime {
 	type OnUpdateLoan = module_incentives::OnUpdateLoan<Runtime>;
 }
 
+impl module_nft_loans::Config for Runtime {
+	type Event = Event;
+	type ClassId = u32;
+	type TokenId = u64;
+	type NFT = NFT;
+	type CollateralClassFilter = module_nft::PermanentlyTransferableClasses<Runtime>;
+	type CDPTreasury = CdpTreasury;
+	type AuctionManager = AuctionManager;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type FloorPriceOrigin =
+		EitherOfDiverse<EnsureRootOrHalfFinancialCouncil, EnsureSignedBy<OperatorMembershipAcala, AccountId>>;
+	type MaxCollateralsPerPosition = ConstU32<10>;
+	type DefaultDebitExchangeRate = DefaultDebitExchangeRate;
+	type TreasuryAccount = HonzonTreasuryAccount;
+	type UnixTime = Timestamp;
+	type PalletId = NFTLoansPalletId;
+	type WeightInfo = weights::module_nft_loans::WeightInfo<Runtime>;
+}
+
 impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
 where
 	Call: From<LocalCall>,
@@ -1156,6 +1213,7 @@ parameter_types! {
 	];
 	pub const ExtendedProvisioningBlocks: BlockNumber = 2 * DAYS;
 	pub const TradingPathLimit: u32 = 4;
+	pub DexCircuitBreakerExemptAccounts: Vec<AccountId> = vec![CDPTreasuryPalletId::get().into_account_truncating()];
 	pub AlternativeSwapPathJointList: Vec<Vec<CurrencyId>> = vec![
 		vec![GetStakingCurrencyId::get()],
 		vec![GetStableCurrencyId::get()],
@@ -1175,6 +1233,8 @@ impl module_dex::Config for Runtime {
 	type ListingOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
 	type OnLiquidityPoolUpdated = ();
+	type CircuitBreaker = TransactionPause;
+	type CircuitBreakerExemptAccounts = IsInVec<DexCircuitBreakerExemptAccounts>;
 }
 
 impl module_aggregated_dex::Config for Runtime {
@@ -1223,6 +1283,7 @@ impl module_cdp_treasury::Config for Runtime {
 impl module_transaction_pause::Config for Runtime {
 	type Event = Event;
 	type UpdateOrigin = EnsureRootOrThreeFourthsGeneralCouncil;
+	type CircuitBreakerDuration = ConstU32<HOURS>;
 	type WeightInfo = weights::module_transaction_pause::WeightInfo<Runtime>;
 }
 
@@ -1268,6 +1329,8 @@ impl module_transaction_payment::Config for Runtime {
 	type TransactionByteFee = TransactionByteFee;
 	type FeeMultiplierUpdate = TargetedFeeAdjustment<Self, TargetBlockFullness, AdjustmentVariable, MinimumMultiplier>;
 	type Swap = AcalaSwap;
+	type DEX = Dex;
+	type StableAsset = RebasedStableAsset;
 	type MaxSwapSlippageCompareToOracle = MaxSwapSlippageCompareToOracle;
 	type TradingPathLimit = TradingPathLimit;
 	type PriceSource = module_prices::RealTimePriceProvider<Runtime>;
@@ -1278,6 +1341,11 @@ impl module_transaction_payment::Config for Runtime {
 	type CustomFeeSurplus = CustomFeeSurplus;
 	type AlternativeFeeSurplus = AlternativeFeeSurplus;
 	type DefaultFeeTokens = DefaultFeeTokens;
+	type AddressMapping = EvmAddressMapping<Runtime>;
+	type SponsoredTargetOf = runtime_common::EvmSponsoredTargetOf<Runtime>;
+	type MaxSponsoredTargets = ConstU32<10>;
+	type DynamicBaseFee = runtime_common::EvmBaseFee<Runtime>;
+	type OnDynamicBaseFee = Treasury;
 }
 
 parameter_types! {
@@ -1298,12 +1366,28 @@ impl module_earning::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub SessionKeyDeposit: Balance = deposit(
+		1,
+		<module_evm_accounts::SessionKeyInfo<BlockNumber> as MaxEncodedLen>::max_encoded_len() as u32,
+	);
+}
+
 impl module_evm_accounts::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
 	type AddressMapping = EvmAddressMapping<Runtime>;
 	type TransferAll = Currencies;
+	type AccountMigration = EVM;
+	type RecoveryOrigin = EnsureRootOrHalfGeneralCouncil;
 	type ChainId = EvmChainId<Runtime>;
+	type Call = Call;
+	type Currencies = Currencies;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type EvmCallTarget = runtime_common::EvmCallTarget<Runtime>;
+	type SessionCallFilter = runtime_common::SessionCallFilter;
+	type AccountCurrencies = runtime_common::AccountCurrencies<Runtime>;
+	type SessionKeyDeposit = SessionKeyDeposit;
 	type WeightInfo = weights::module_evm_accounts::WeightInfo<Runtime>;
 }
 
@@ -1326,7 +1410,22 @@ impl orml_rewards::Config for Runtime {
 
 parameter_types! {
 	pub const AccumulatePeriod: BlockNumber = MINUTES;
+	pub const GaugeEpochPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxEscrowLockPeriod: BlockNumber = 4 * 365 * DAYS;
+	pub MaxIncentivesBoost: Rate = Rate::saturating_from_rational(5, 2);
 	pub const EarnShareBooster: Permill = Permill::from_percent(30);
+	pub const MaxCampaignDuration: BlockNumber = 365 * DAYS;
+	pub CampaignDeposit: Balance = 10 * dollar(ACA);
+}
+
+parameter_type_with_key! {
+	pub MinCampaignRewardPerBlock: |currency_id: CurrencyId| -> Balance {
+		if *currency_id == GetNativeCurrencyId::get() {
+			NativeTokenExistentialDeposit::get()
+		} else {
+			ExistentialDeposits::get(currency_id)
+		}
+	};
 }
 
 impl module_incentives::Config for Runtime {
@@ -1342,9 +1441,28 @@ impl module_incentives::Config for Runtime {
 	type DEX = Dex;
 	type EmergencyShutdown = EmergencyShutdown;
 	type PalletId = IncentivesPalletId;
+	type EpochPeriod = GaugeEpochPeriod;
+	type MaxLockPeriod = MaxEscrowLockPeriod;
+	type MaxGaugeVotes = ConstU32<16>;
+	type MaxBoost = MaxIncentivesBoost;
+	type MaxCampaigns = ConstU32<50>;
+	type MaxCampaignsPerPool = ConstU32<5>;
+	type MaxCampaignDuration = MaxCampaignDuration;
+	type MinCampaignRewardPerBlock = MinCampaignRewardPerBlock;
+	type CampaignDeposit = CampaignDeposit;
 	type WeightInfo = weights::module_incentives::WeightInfo<Runtime>;
 }
 
+impl module_vault::Config for Runtime {
+	type Event = Event;
+	type Currency = Currencies;
+	type Strategy = runtime_common::DexShareIncentivesStrategy<Runtime, AcalaSwap>;
+	type VaultShareRegistry = AssetRegistry;
+	type PalletId = VaultPalletId;
+	type UpdateOrigin = EnsureRootOrHalfFinancialCouncil;
+	type WeightInfo = weights::module_vault::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const GetLiquidCurrencyId: CurrencyId = LDOT;
 	pub const GetStakingCurrencyId: CurrencyId = DOT;
@@ -1430,6 +1548,7 @@ impl module_nominees_election::Config for Runtime {
 parameter_types! {
 	pub CreateClassDeposit: Balance = 20 * dollar(ACA);
 	pub CreateTokenDeposit: Balance = 2 * dollar(ACA);
+	pub NftMaxRoyalty: Permill = Permill::from_percent(50);
 }
 
 impl module_nft::Config for Runtime {
@@ -1440,6 +1559,10 @@ impl module_nft::Config for Runtime {
 	type DataDepositPerByte = DataDepositPerByte;
 	type PalletId = NftPalletId;
 	type MaxAttributesBytes = ConstU32<2048>;
+	type MultiCurrency = Currencies;
+	type MaxRoyalty = NftMaxRoyalty;
+	type Task = ScheduledTasks;
+	type IdleScheduler = IdleScheduler;
 	type WeightInfo = weights::module_nft::WeightInfo<Runtime>;
 }
 
@@ -1631,6 +1754,17 @@ impl<I: From<Balance>> frame_support::traits::Get<I> for TxFeePerGas {
 	}
 }
 
+parameter_types! {
+	// half of the max gas limit of a normal dispatch
+	pub BaseFeeTargetGas: u64 = runtime_common::EvmLimits::<Runtime>::max_gas_limit() / 2;
+	// the weight fee of a gas, so the base fee never undercuts the regular transaction fee
+	pub MinBaseFeePerGas: Balance = <WeightToFee as frame_support::weights::WeightToFee>::weight_to_fee(
+		&<runtime_common::GasToWeight as sp_runtime::traits::Convert<u64, Weight>>::convert(1_000_000),
+	) / 1_000_000;
+	pub const AbandonedContractTimeout: BlockNumber = 180 * DAYS;
+	pub const CodeUpgradeDelay: BlockNumber = 7 * DAYS;
+}
+
 #[cfg(feature = "with-ethereum-compatibility")]
 static LONDON_CONFIG: module_evm_utility::evm::Config = module_evm_utility::evm::Config::london();
 
@@ -1641,6 +1775,8 @@ impl module_evm::Config for Runtime {
 	type NewContractExtraBytes = NewContractExtraBytes;
 	type StorageDepositPerByte = StorageDepositPerByte;
 	type TxFeePerGas = TxFeePerGas;
+	type BaseFeeTargetGas = BaseFeeTargetGas;
+	type MinBaseFeePerGas = MinBaseFeePerGas;
 	type Event = Event;
 	type PrecompilesType = AllPrecompiles<Self>;
 	type PrecompilesValue = PrecompilesValue;
@@ -1652,10 +1788,13 @@ impl module_evm::Config for Runtime {
 	type PublicationFee = PublicationFee;
 	type TreasuryAccount = TreasuryAccount;
 	type FreePublicationOrigin = EnsureRootOrHalfGeneralCouncil;
+	type AbandonedContractTimeout = AbandonedContractTimeout;
+	type CodeUpgradeDelay = CodeUpgradeDelay;
 	type Runner = module_evm::runner::stack::Runner<Self>;
 	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
 	type Task = ScheduledTasks;
 	type IdleScheduler = IdleScheduler;
+	type CallFilter = TransactionPause;
 	type WeightInfo = weights::module_evm::WeightInfo<Runtime>;
 
 	#[cfg(feature = "with-ethereum-compatibility")]
@@ -1777,12 +1916,25 @@ define_combined_task! {
 	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
 	pub enum ScheduledTasks {
 		EvmTask(EvmTask<Runtime>),
+		NftTask(NftTask<Runtime>),
+		DexOracleTask(DexOracleTask<Runtime>),
+		CdpTreasuryTask(CdpTreasuryTask<Runtime>),
+		HomaTask(HomaTask<Runtime>),
+	}
+}
+
+/// The recurring tasks can be registered by accounts, they only update states from the chain.
+pub struct RecurringTaskFilter;
+impl Contains<ScheduledTasks> for RecurringTaskFilter {
+	fn contains(task: &ScheduledTasks) -> bool {
+		matches!(task, ScheduledTasks::DexOracleTask(_) | ScheduledTasks::HomaTask(_))
 	}
 }
 
 parameter_types!(
 	// At least 2% of max block weight should remain before idle tasks are dispatched.
 	pub MinimumWeightRemainInBlock: Weight = RuntimeBlockWeights::get().max_block / 50;
+	pub RecurringTaskDeposit: Balance = dollar(ACA);
 );
 
 impl module_idle_scheduler::Config for Runtime {
@@ -1794,6 +1946,13 @@ impl module_idle_scheduler::Config for Runtime {
 	// Number of relay chain blocks produced with no parachain blocks finalized,
 	// once this number is reached idle scheduler is disabled as block production is slow
 	type DisableBlockThreshold = ConstU32<6>;
+	type MaxRetries = ConstU32<5>;
+	type RetryBackoff = ConstU32<{ 10 * MINUTES }>;
+	type Currency = Balances;
+	type RecurringTaskDeposit = RecurringTaskDeposit;
+	type RecurringTaskFilter = RecurringTaskFilter;
+	type MaxRecurringTasks = ConstU32<50>;
+	type MaxTaskHistory = ConstU32<10>;
 }
 
 impl cumulus_pallet_aura_ext::Config for Runtime {}
@@ -1855,6 +2014,41 @@ impl Convert<(Call, SignedExtra), Result<(EthereumTransactionMessage, SignedExtr
 	}
 }
 
+impl Convert<(Call, SignedExtra), Result<(Eip712CallMessage, SignedExtra), InvalidTransaction>> for ConvertEthereumTx {
+	fn convert((call, extra): (Call, SignedExtra)) -> Result<(Eip712CallMessage, SignedExtra), InvalidTransaction> {
+		// only the calls whose arguments can be rendered are allowed, EVM calls and calls
+		// dispatching other calls are rejected
+		let args = runtime_common::eip712::eip712_call_args::<Runtime, EvmErc20InfoMapping<Runtime>>(&call)
+			.ok_or(InvalidTransaction::Call)?;
+
+		let (_, _, _, _, mortality, check_nonce, _, _, charge) = extra.clone();
+
+		if mortality != frame_system::CheckEra::from(sp_runtime::generic::Era::Immortal) {
+			// require immortal
+			return Err(InvalidTransaction::BadProof);
+		}
+
+		let CallMetadata {
+			pallet_name,
+			function_name,
+		} = call.get_call_metadata();
+
+		Ok((
+			Eip712CallMessage {
+				chain_id: EVM::chain_id(),
+				genesis: System::block_hash(0),
+				spec_version: VERSION.spec_version,
+				pallet_name: pallet_name.as_bytes().to_vec(),
+				call_name: function_name.as_bytes().to_vec(),
+				args,
+				nonce: check_nonce.nonce,
+				tip: charge.0,
+			},
+			extra,
+		))
+	}
+}
+
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug)]
 pub struct PayerSignatureVerification;
 
@@ -1917,9 +2111,28 @@ pub type UncheckedExtrinsic = AcalaUncheckedExtrinsic<
 pub type SignedPayload = generic::SignedPayload<Call, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, Call, SignedExtra>;
+parameter_types! {
+	/// The ERC20 contracts and the accounts which reserved them at the legacy reserve addresses,
+	/// migrated to the holding account escrow by `module_currencies::migrations::MigrateToV1`.
+	/// The legacy reserves not listed are escrowed by `module_currencies` when they are used.
+	pub LegacyErc20Reserves: Vec<(H160, AccountId)> = vec![];
+}
+
 /// Executive: handles dispatch to the various modules.
-pub type Executive =
-	frame_executive::Executive<Runtime, Block, frame_system::ChainContext<Runtime>, Runtime, AllPalletsWithSystem, ()>;
+pub type Executive = frame_executive::Executive<
+	Runtime,
+	Block,
+	frame_system::ChainContext<Runtime>,
+	Runtime,
+	AllPalletsWithSystem,
+	(
+		module_evm::migrations::MigrateToV1<Runtime>,
+		module_currencies::migrations::MigrateToV1<Runtime, LegacyErc20Reserves>,
+		module_nft::migrations::MigrateToV1<Runtime>,
+		module_incentives::migrations::MigrateToV1<Runtime>,
+		module_idle_scheduler::migrations::MigrateToV1<Runtime, ConstU32<1_000>>,
+	),
+>;
 
 construct_runtime!(
 	pub enum Runtime where
@@ -1940,6 +2153,8 @@ construct_runtime!(
 		Currencies: module_currencies = 12,
 		Vesting: orml_vesting = 13,
 		TransactionPayment: module_transaction_payment = 14,
+		MultiVesting: module_multi_vesting = 15,
+		Streaming: module_streaming = 16,
 
 		// Treasury
 		Treasury: pallet_treasury = 20,
@@ -1994,6 +2209,7 @@ construct_runtime!(
 		CdpTreasury: module_cdp_treasury = 123,
 		CdpEngine: module_cdp_engine = 124,
 		EmergencyShutdown: module_emergency_shutdown = 125,
+		NFTLoans: module_nft_loans = 126,
 
 		// Homa
 		NomineesElection: module_nominees_election = 131,
@@ -2004,6 +2220,7 @@ construct_runtime!(
 		Incentives: module_incentives = 140,
 		NFT: module_nft = 141,
 		AssetRegistry: module_asset_registry = 142,
+		Vault: module_vault = 143,
 
 		// Ecosystem modules
 		RenVmBridge: ecosystem_renvm_bridge = 150,
@@ -2084,6 +2301,10 @@ mod benches {
 		[orml_oracle, benchmarking::oracle]
 		[nutsfinance_stable_asset, benchmarking::nutsfinance_stable_asset]
 		[module_idle_scheduler, benchmarking::idle_scheduler]
+		[module_multi_vesting, benchmarking::multi_vesting]
+		[module_streaming, benchmarking::streaming]
+		[module_nft_loans, benchmarking::nft_loans]
+		[module_vault, benchmarking::vault]
 	);
 }
 
@@ -2186,6 +2407,17 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_transaction_payment_rpc_runtime_api::FeeCurrenciesApi<Block, AccountId> for Runtime {
+		fn query_fee_in_currencies(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			who: AccountId,
+			currency_ids: Vec<CurrencyId>,
+		) -> Vec<module_transaction_payment_rpc_runtime_api::CurrencyFeeDetails> {
+			TransactionPayment::query_fee_in_currencies(uxt, len, &who, currency_ids)
+		}
+	}
+
 	impl orml_oracle_rpc_runtime_api::OracleApi<
 		Block,
 		DataProviderId,
@@ -2221,14 +2453,41 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl module_incentives_rpc_runtime_api::IncentivesApi<Block, AccountId, Balance> for Runtime {
+		fn get_account_rewards(who: AccountId) -> Vec<module_incentives_rpc_runtime_api::PoolRewardsInfo<Balance>> {
+			Incentives::account_rewards(&who)
+				.into_iter()
+				.map(|(pool_id, shares, pending_rewards)| module_incentives_rpc_runtime_api::PoolRewardsInfo {
+					pool_id,
+					shares,
+					pending_rewards,
+					apr: runtime_common::IncentivesApr::<Runtime>::pool_apr(pool_id, 365 * DAYS),
+				})
+				.collect()
+		}
+
+		fn get_pool_apr(pool_id: PoolId) -> Option<Rate> {
+			runtime_common::IncentivesApr::<Runtime>::pool_apr(pool_id, 365 * DAYS)
+		}
+	}
+
 	impl module_evm_rpc_runtime_api::EVMRuntimeRPCApi<Block, Balance> for Runtime {
 		fn block_limits() -> BlockLimits {
 			BlockLimits {
 				max_gas_limit: runtime_common::EvmLimits::<Runtime>::max_gas_limit(),
 				max_storage_limit: runtime_common::EvmLimits::<Runtime>::max_storage_limit(),
+				base_fee_per_gas: module_evm::convert_decimals_to_evm(EVM::base_fee_per_gas()),
 			}
 		}
 
+		fn base_fee_per_gas() -> Balance {
+			module_evm::convert_decimals_to_evm(EVM::base_fee_per_gas())
+		}
+
+		fn block_logs() -> primitives::evm::BlockLogs {
+			EVM::block_logs()
+		}
+
 		fn call(
 			from: H160,
 			to: H160,
@@ -2419,6 +2678,7 @@ mod tests {
 	use frame_support::dispatch::DispatchInfo;
 	use frame_system::offchain::CreateSignedTransaction;
 	use module_support::AddressMapping;
+	use primitives::evm::{Eip712Arg, Eip712Value, EvmAddress};
 	use sp_runtime::traits::SignedExtension;
 
 	#[test]
@@ -2510,7 +2770,11 @@ mod tests {
 			expected_extra.5.mark_as_ethereum_tx(30);
 
 			assert_eq!(
-				ConvertEthereumTx::convert((call.clone(), extra.clone())).unwrap(),
+				<ConvertEthereumTx as Convert<_, Result<(EthereumTransactionMessage, SignedExtra), _>>>::convert((
+					call.clone(),
+					extra.clone()
+				))
+				.unwrap(),
 				(
 					EthereumTransactionMessage {
 						nonce: 3, // evm::account.nonce
@@ -2569,6 +2833,99 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn convert_eip712_call() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let call = Call::Currencies(module_currencies::Call::transfer {
+				dest: sp_runtime::MultiAddress::Id(sp_runtime::AccountId32::from([9; 32])),
+				currency_id: DOT,
+				amount: 100,
+			});
+
+			let extra: SignedExtra = (
+				frame_system::CheckNonZeroSender::<Runtime>::new(),
+				frame_system::CheckSpecVersion::<Runtime>::new(),
+				frame_system::CheckTxVersion::<Runtime>::new(),
+				frame_system::CheckGenesis::<Runtime>::new(),
+				frame_system::CheckEra::<Runtime>::from(generic::Era::Immortal),
+				runtime_common::CheckNonce::<Runtime>::from(3),
+				frame_system::CheckWeight::<Runtime>::new(),
+				module_evm::SetEvmOrigin::<Runtime>::new(),
+				module_transaction_payment::ChargeTransactionPayment::<Runtime>::from(5),
+			);
+
+			assert_eq!(
+				<ConvertEthereumTx as Convert<_, Result<(Eip712CallMessage, SignedExtra), _>>>::convert((
+					call.clone(),
+					extra.clone()
+				))
+				.unwrap(),
+				(
+					Eip712CallMessage {
+						chain_id: 0,
+						genesis: sp_core::H256::default(),
+						spec_version: VERSION.spec_version,
+						pallet_name: b"Currencies".to_vec(),
+						call_name: b"transfer".to_vec(),
+						args: vec![
+							Eip712Arg {
+								name: b"dest".to_vec(),
+								value: Eip712Value::Bytes32(sp_core::H256([9; 32])),
+							},
+							Eip712Arg {
+								name: b"currency".to_vec(),
+								value: Eip712Value::Address(EvmAddress::try_from(DOT).unwrap()),
+							},
+							Eip712Arg {
+								name: b"amount".to_vec(),
+								value: Eip712Value::Uint(100),
+							},
+						],
+						nonce: 3, // system::account.nonce
+						tip: 5,
+					},
+					extra.clone()
+				)
+			);
+
+			// require immortal
+			let mut mortal_extra = extra.clone();
+			mortal_extra.4 = frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(64, 0));
+			assert_eq!(
+				<ConvertEthereumTx as Convert<_, Result<(Eip712CallMessage, SignedExtra), _>>>::convert((
+					call.clone(),
+					mortal_extra
+				)),
+				Err(InvalidTransaction::BadProof)
+			);
+
+			// nested calls are not rendered
+			let batch = Call::Utility(pallet_utility::Call::batch { calls: vec![call] });
+			assert_eq!(
+				<ConvertEthereumTx as Convert<_, Result<(Eip712CallMessage, SignedExtra), _>>>::convert((
+					batch,
+					extra.clone()
+				)),
+				Err(InvalidTransaction::BadProof)
+			);
+
+			// evm calls are not allowed
+			let eth_call = Call::EVM(module_evm::Call::eth_call {
+				action: module_evm::TransactionAction::Create,
+				input: vec![0x01],
+				value: 0,
+				gas_limit: 21_000,
+				storage_limit: 1_000,
+				valid_until: 30,
+				access_list: vec![],
+			});
+			assert_eq!(
+				<ConvertEthereumTx as Convert<_, Result<(Eip712CallMessage, SignedExtra), _>>>::convert((eth_call, extra)),
+				Err(InvalidTransaction::BadProof)
+			);
+		});
+	}
+
 	fn new_test_ext() -> sp_io::TestExternalities {
 		let t = frame_system::GenesisConfig::default()
 			.build_storage::<Runtime>()
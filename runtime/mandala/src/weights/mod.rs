@@ -33,12 +33,16 @@ pub mod module_evm_accounts;
 pub mod module_homa;
 pub mod module_honzon;
 pub mod module_incentives;
+pub mod module_multi_vesting;
 pub mod module_nft;
+pub mod module_nft_loans;
 pub mod module_nominees_election;
 pub mod module_prices;
 pub mod module_session_manager;
+pub mod module_streaming;
 pub mod module_transaction_pause;
 pub mod module_transaction_payment;
+pub mod module_vault;
 
 pub mod orml_auction;
 pub mod orml_authority;
@@ -58,6 +58,12 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Dex CircuitBreakers (r:0 w:1)
+	// Storage: Dex ReferencePrices (r:0 w:1)
+	fn set_circuit_breaker() -> Weight {
+		(11_874_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	// Storage: Dex TradingPairStatuses (r:1 w:1)
 	// Storage: Tokens TotalIssuance (r:1 w:0)
 	// Storage: Dex ProvisioningPool (r:1 w:0)
@@ -154,7 +160,10 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(11 as Weight))
 	}
 	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	// Storage: TransactionPause TrippedCircuitBreakers (r:1 w:0)
 	// Storage: Dex LiquidityPool (r:1 w:1)
+	// Storage: Dex CircuitBreakers (r:1 w:0)
+	// Storage: Dex ReferencePrices (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Tokens Accounts (r:2 w:2)
 	fn swap_with_exact_supply(u: u32, ) -> Weight {
@@ -162,12 +171,15 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			// Standard Error: 140_000
 			.saturating_add((9_293_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
 	// Storage: Dex TradingPairStatuses (r:1 w:0)
+	// Storage: TransactionPause TrippedCircuitBreakers (r:1 w:0)
 	// Storage: Dex LiquidityPool (r:1 w:1)
+	// Storage: Dex CircuitBreakers (r:1 w:0)
+	// Storage: Dex ReferencePrices (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Tokens Accounts (r:2 w:2)
 	fn swap_with_exact_target(u: u32, ) -> Weight {
@@ -175,9 +187,9 @@ impl<T: frame_system::Config> module_dex::WeightInfo for WeightInfo<T> {
 			// Standard Error: 139_000
 			.saturating_add((9_633_000 as Weight).saturating_mul(u as Weight))
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
-			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(u as Weight)))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(u as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(u as Weight)))
 	}
 	fn refund_provision() -> Weight {
 		(105_716_000 as Weight)
@@ -52,8 +52,8 @@ impl<T: frame_system::Config> module_evm_accounts::WeightInfo for WeightInfo<T>
 	// Storage: System Account (r:1 w:0)
 	fn claim_account() -> Weight {
 		(194_615_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
 	// Storage: EvmAccounts EvmAddresses (r:1 w:1)
 	// Storage: EvmAccounts Accounts (r:0 w:1)
@@ -62,4 +62,48 @@ impl<T: frame_system::Config> module_evm_accounts::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	fn add_session_key() -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn remove_session_key() -> Weight {
+		(39_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn session_call(c: u32, ) -> Weight {
+		(38_000_000 as Weight)
+			// Standard Error: 4_000
+			.saturating_add((6_120_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: EvmAccounts EvmAddresses (r:1 w:1)
+	// Storage: EvmAccounts Accounts (r:1 w:2)
+	// Storage: EvmAccounts ConsentNonces (r:1 w:1)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: System Account (r:1 w:0)
+	// Storage: EVM Accounts (r:2 w:1)
+	fn relink_account() -> Weight {
+		(664_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: EvmAccounts EvmAddresses (r:1 w:1)
+	// Storage: EvmAccounts Accounts (r:0 w:1)
+	// Storage: EvmAccounts ConsentNonces (r:1 w:1)
+	fn unlink_account() -> Weight {
+		(340_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: EvmAccounts EvmAddresses (r:1 w:1)
+	// Storage: EvmAccounts Accounts (r:0 w:1)
+	fn force_unlink_account() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
@@ -112,4 +112,103 @@ impl<T: frame_system::Config> module_incentives::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
 	}
+	// Storage: Incentives GaugePointChanges (r:2 w:1)
+	// Storage: Incentives GaugeRewardAmount (r:1 w:0)
+	// Storage: Incentives GaugePoints (r:2 w:1)
+	// Storage: Incentives IncentiveRewardAmounts (r:2 w:2)
+	fn on_initialize_epoch(e: u32, c: u32, ) -> Weight {
+		(9_412_000 as Weight)
+			// Standard Error: 98_000
+			.saturating_add((6_954_000 as Weight).saturating_mul(e as Weight))
+			// Standard Error: 321_000
+			.saturating_add((12_873_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: Incentives EscrowLocks (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens Locks (r:1 w:1)
+	// Storage: Incentives GaugeVotes (r:1 w:0)
+	// Storage: Incentives GaugePoints (r:1 w:1)
+	// Storage: Incentives GaugePointChanges (r:1 w:2)
+	// Storage: Incentives TotalEscrowLocked (r:1 w:1)
+	fn escrow_lock() -> Weight {
+		(61_275_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Incentives EscrowLocks (r:1 w:1)
+	// Storage: Tokens Locks (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Incentives TotalEscrowLocked (r:1 w:1)
+	// Storage: Incentives GaugeVotes (r:0 w:1)
+	fn escrow_unlock() -> Weight {
+		(38_106_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Incentives EscrowLocks (r:1 w:0)
+	// Storage: Incentives GaugeVotes (r:1 w:1)
+	// Storage: Incentives GaugePoints (r:2 w:2)
+	// Storage: Incentives GaugePointChanges (r:2 w:2)
+	fn vote_gauges(c: u32, ) -> Weight {
+		(24_318_000 as Weight)
+			// Standard Error: 206_000
+			.saturating_add((8_265_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((4 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: Incentives GaugeRewardAmount (r:0 w:1)
+	fn update_gauge_reward_amount() -> Weight {
+		(4_711_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Incentives DexDepositedShares (r:1 w:1)
+	// Storage: Incentives TotalDexDepositedShares (r:1 w:1)
+	// Storage: Incentives EscrowLocks (r:1 w:0)
+	// Storage: Incentives TotalEscrowLocked (r:1 w:0)
+	// Storage: Rewards SharesAndWithdrawnRewards (r:1 w:1)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	// Storage: Incentives PendingMultiRewards (r:1 w:1)
+	fn kick() -> Weight {
+		(36_524_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Storage: Incentives Campaigns (r:1 w:1)
+	// Storage: Rewards PoolInfos (r:1 w:1)
+	fn on_initialize_campaigns(c: u32, ) -> Weight {
+		(3_215_000 as Weight)
+			// Standard Error: 465_000
+			.saturating_add((18_624_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	// Storage: Incentives CampaignCount (r:1 w:1)
+	// Storage: Incentives NextCampaignId (r:1 w:1)
+	// Storage: Incentives PoolCampaignCount (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Incentives Campaigns (r:0 w:1)
+	fn create_campaign() -> Weight {
+		(49_390_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Incentives Campaigns (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Incentives CampaignCount (r:1 w:1)
+	// Storage: Incentives PoolCampaignCount (r:1 w:1)
+	fn refund_campaign() -> Weight {
+		(43_270_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
 }
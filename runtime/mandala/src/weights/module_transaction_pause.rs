@@ -58,4 +58,51 @@ impl<T: frame_system::Config> module_transaction_pause::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause ExpiringPauses (r:0 w:1)
+	fn pause_transaction_until() -> Weight {
+		(21_478_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: TransactionPause PausedEvmCalls (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause ExpiringPauses (r:0 w:1)
+	fn pause_evm_call() -> Weight {
+		(20_854_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: TransactionPause PausedEvmCalls (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause ExpiringPauses (r:0 w:1)
+	fn unpause_evm_call() -> Weight {
+		(19_632_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: unknown [0x3a7472616e73616374696f6e5f6c6576656c3a] (r:1 w:1)
+	// Storage: TransactionPause TrippedCircuitBreakers (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	// Storage: TransactionPause ExpiringPauses (r:0 w:1)
+	fn reset_circuit_breaker() -> Weight {
+		(19_215_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: TransactionPause ExpiringPauses (r:1 w:1)
+	// Storage: TransactionPause PausedTransactions (r:1 w:1)
+	// Storage: TransactionPause PauseExpiries (r:1 w:1)
+	fn on_initialize(c: u32, ) -> Weight {
+		(3_152_000 as Weight)
+			// Standard Error: 321_000
+			.saturating_add((12_847_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes((4 as Weight).saturating_mul(c as Weight)))
+	}
 }